@@ -9,6 +9,8 @@ pub enum BackendOpt {
     Verilog,
     Xilinx,
     XilinxXml,
+    Intel,
+    IntelHwTcl,
     Mlir,
     Resources,
     Sexp,
@@ -25,6 +27,8 @@ fn backends() -> Vec<(&'static str, BackendOpt)> {
         ("verilog", BackendOpt::Verilog),
         ("xilinx", BackendOpt::Xilinx),
         ("xilinx-xml", BackendOpt::XilinxXml),
+        ("intel", BackendOpt::Intel),
+        ("intel-hw-tcl", BackendOpt::IntelHwTcl),
         ("calyx", BackendOpt::Calyx),
         ("mlir", BackendOpt::Mlir),
         ("resources", BackendOpt::Resources),
@@ -73,6 +77,8 @@ impl ToString for BackendOpt {
             Self::Verilog => "verilog",
             Self::Xilinx => "xilinx",
             Self::XilinxXml => "xilinx-xml",
+            Self::Intel => "intel",
+            Self::IntelHwTcl => "intel-hw-tcl",
             Self::Yxi => "yxi",
             Self::Calyx => "calyx",
             Self::Firrtl => "firrtl",
@@ -0,0 +1,151 @@
+//! Descriptions of Avalon memory-mapped interfaces.
+use vast::v05::ast as v;
+
+/// Direction of a signal from the perspective of the component that owns the
+/// interface.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SignalDirection {
+    Input,
+    Output,
+}
+
+/// A single signal of an Avalon-MM interface.
+#[derive(Clone, Debug)]
+pub(crate) struct AvalonSignal {
+    /// The role of the signal, for example `address` or `waitrequest`.
+    pub role: &'static str,
+    pub direction: SignalDirection,
+    pub width: u64,
+}
+
+/// Whether an interface responds to or initiates transfers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum InterfaceKind {
+    /// Responds to transfers (an Avalon slave).
+    Agent,
+    /// Initiates transfers (an Avalon master).
+    Host,
+}
+
+/// An Avalon memory-mapped interface.
+#[derive(Clone, Debug)]
+pub(crate) struct AvalonInterface {
+    /// Name of the interface. All of its ports are prefixed with it.
+    pub name: String,
+    pub kind: InterfaceKind,
+    pub signals: Vec<AvalonSignal>,
+}
+
+fn signal(
+    role: &'static str,
+    direction: SignalDirection,
+    width: u64,
+) -> AvalonSignal {
+    AvalonSignal {
+        role,
+        direction,
+        width,
+    }
+}
+
+impl AvalonInterface {
+    /// An agent without wait states that returns read data one cycle after
+    /// the read is requested.
+    pub fn agent(name: &str, address_width: u64, data_width: u64) -> Self {
+        use SignalDirection::*;
+        Self {
+            name: name.to_string(),
+            kind: InterfaceKind::Agent,
+            signals: vec![
+                signal("address", Input, address_width),
+                signal("read", Input, 1),
+                signal("readdata", Output, data_width),
+                signal("write", Input, 1),
+                signal("writedata", Input, data_width),
+            ],
+        }
+    }
+
+    /// A host that issues single-word transfers using byte addresses.
+    pub fn host(name: &str, address_width: u64, data_width: u64) -> Self {
+        use SignalDirection::*;
+        Self {
+            name: name.to_string(),
+            kind: InterfaceKind::Host,
+            signals: vec![
+                signal("address", Output, address_width),
+                signal("read", Output, 1),
+                signal("readdata", Input, data_width),
+                signal("readdatavalid", Input, 1),
+                signal("write", Output, 1),
+                signal("writedata", Output, data_width),
+                signal("byteenable", Output, data_width / 8),
+                signal("waitrequest", Input, 1),
+            ],
+        }
+    }
+
+    /// Returns the name of the port implementing `role`.
+    pub fn port(&self, role: &str) -> String {
+        assert!(
+            self.signals.iter().any(|s| s.role == role),
+            "Avalon interface `{}' has no `{}' signal",
+            self.name,
+            role
+        );
+        format!("{}_{}", self.name, role)
+    }
+
+    /// Returns an iterator over the roles and names of all the ports in this
+    /// interface.
+    pub fn ports(&self) -> impl Iterator<Item = (&'static str, String)> + '_ {
+        self.signals
+            .iter()
+            .map(move |s| (s.role, format!("{}_{}", self.name, s.role)))
+    }
+
+    /// Add the ports of this interface to a module.
+    pub fn add_ports_to(&self, module: &mut v::Module) {
+        for s in &self.signals {
+            let name = format!("{}_{}", self.name, s.role);
+            match s.direction {
+                SignalDirection::Input => module.add_input(&name, s.width),
+                SignalDirection::Output => module.add_output(&name, s.width),
+            }
+        }
+    }
+
+    /// Generate the Platform Designer declaration of this interface.
+    pub fn hw_tcl(&self, clock: &str, reset: &str) -> String {
+        let name = &self.name;
+        let end = match self.kind {
+            InterfaceKind::Agent => "end",
+            InterfaceKind::Host => "start",
+        };
+        let mut out = format!("add_interface {name} avalon {end}\n");
+        let mut properties = vec![
+            ("addressUnits", "SYMBOLS"),
+            ("associatedClock", clock),
+            ("associatedReset", reset),
+        ];
+        if self.kind == InterfaceKind::Agent {
+            properties.push(("readLatency", "1"));
+        }
+        for (property, value) in properties {
+            out +=
+                &format!("set_interface_property {name} {property} {value}\n");
+        }
+        for s in &self.signals {
+            let direction = match s.direction {
+                SignalDirection::Input => "Input",
+                SignalDirection::Output => "Output",
+            };
+            out += &format!(
+                "add_interface_port {name} {name}_{role} {role} {direction} {width}\n",
+                role = s.role,
+                width = s.width
+            );
+        }
+        out
+    }
+}
@@ -0,0 +1,158 @@
+use super::avalon::AvalonInterface;
+use crate::xilinx::axi_address_space::{AddressSpace, Flags};
+use vast::v05::ast as v;
+
+/// Width of the addresses of the control and status registers.
+pub(crate) const ADDRESS_WIDTH: u64 = 12;
+/// Width of the control and status registers.
+pub(crate) const DATA_WIDTH: u64 = 32;
+
+/// Byte address of the register holding the lower half of the base address
+/// of the `idx`th memory. The upper half is stored at the next word.
+pub(crate) fn memory_address_offset(idx: usize) -> usize {
+    0x10 + (idx * 8)
+}
+
+/// Human readable description of the control and status registers.
+pub(crate) fn register_map(memories: &[String]) -> Vec<(usize, String)> {
+    let mut map = vec![
+        (0x0, "CONTROL: bit 0 starts the kernel, bit 1 is set when it is done, bit 2 is set when it is idle".to_string()),
+        (0x4, "GIE: bit 0 enables the interrupt".to_string()),
+        (0x8, "ISR: bit 0 is set when the kernel is done and cleared on read".to_string()),
+    ];
+    for (idx, memory) in memories.iter().enumerate() {
+        let offset = memory_address_offset(idx);
+        map.push((offset, format!("base address of `{}' (low)", memory)));
+        map.push((offset + 4, format!("base address of `{}' (high)", memory)));
+    }
+    map
+}
+
+/// Generate the control and status register block. It is accessed through
+/// an Avalon agent with ports prefixed by `avs`.
+pub(crate) fn control_module(name: &str, memories: &[String]) -> v::Module {
+    let mut module = v::Module::new(name);
+
+    module.add_input("ACLK", 1);
+    module.add_input("ARESET", 1);
+
+    for memory in memories {
+        module.add_output(memory, 64);
+    }
+    module.add_output("ap_start", 1);
+    module.add_input("ap_done", 1);
+    module.add_output("irq", 1);
+
+    let avs = AvalonInterface::agent("avs", ADDRESS_WIDTH, DATA_WIDTH);
+    avs.add_ports_to(&mut module);
+    let address = avs.port("address");
+    let read: v::Expr = avs.port("read").into();
+    let write: v::Expr = avs.port("write").into();
+    let writedata = avs.port("writedata");
+
+    // define the address space of the control interface
+    let mut addr_space = AddressSpace::new(ADDRESS_WIDTH, DATA_WIDTH)
+        .address(
+            0x0,
+            "CONTROL",
+            vec![
+                (
+                    0..1,
+                    "int_ap_start",
+                    0..1,
+                    Flags::default().write().clear_on_handshake("ap_done"),
+                ),
+                (
+                    1..2,
+                    "int_ap_done",
+                    0..1,
+                    Flags::default()
+                        .read("ap_done")
+                        .clear_on_read(read.clone(), &address),
+                ),
+                (
+                    2..3,
+                    "int_ap_idle",
+                    0..1,
+                    Flags::default().read("ap_done").idle(),
+                ),
+            ],
+        )
+        .address(
+            0x4,
+            "GIE",
+            vec![(0..1, "int_gie", 0..1, Flags::default().write())],
+        )
+        .address(
+            0x8,
+            "ISR",
+            vec![(
+                0..1,
+                "int_isr_done",
+                0..1,
+                Flags::default()
+                    .read("ap_done")
+                    .clear_on_read(read.clone(), &address),
+            )],
+        );
+    for (idx, memory) in memories.iter().enumerate() {
+        let addr_name = format!("addr_{}", memory);
+        addr_space.add_address(
+            memory_address_offset(idx),
+            &format!("{}_0", memory),
+            vec![(0..32, &addr_name, 0..32, Flags::default().write())],
+        );
+        addr_space.add_address(
+            memory_address_offset(idx) + 4,
+            &format!("{}_1", memory),
+            vec![(0..32, &addr_name, 32..64, Flags::default().write())],
+        );
+    }
+
+    // reads are answered in the cycle after they are requested
+    module.add_decl(v::Decl::new_reg("rdata", DATA_WIDTH));
+    addr_space.output_to_bus(&mut module, read, &address, "rdata");
+    module.add_stmt(v::Parallel::Assign(
+        avs.port("readdata").into(),
+        "rdata".into(),
+    ));
+
+    addr_space.internal_registers(&mut module);
+
+    // register logic
+    module.add_stmt(v::Parallel::Assign(
+        "ap_start".into(),
+        "int_ap_start".into(),
+    ));
+    module.add_stmt(v::Parallel::Assign(
+        "irq".into(),
+        v::Expr::new_bit_and("int_gie", "int_isr_done"),
+    ));
+    for name in ["CONTROL", "GIE", "ISR"] {
+        addr_space.register_logic(
+            &mut module,
+            write.clone(),
+            name,
+            &address,
+            &writedata,
+        );
+    }
+
+    for memory in memories {
+        module.add_stmt(v::Parallel::Assign(
+            memory.as_str().into(),
+            format!("addr_{}", memory).into(),
+        ));
+        for part in ["0", "1"] {
+            addr_space.register_logic(
+                &mut module,
+                write.clone(),
+                &format!("{}_{}", memory, part),
+                &address,
+                &writedata,
+            );
+        }
+    }
+
+    module
+}
@@ -0,0 +1,102 @@
+use super::{control_avalon, csr_interface, memory_interface, CLOCK, RESET};
+use crate::traits::Backend;
+use calyx_ir as ir;
+use calyx_utils::CalyxResult;
+
+/// Backend that generates the `_hw.tcl` file that Platform Designer uses to
+/// instantiate the interface generated by the `intel` backend as a component.
+///
+/// The generated component expects the interface to be in `toplevel.v` and
+/// the kernel, as generated by the `verilog` backend, to be in `main.sv`.
+#[derive(Default)]
+pub struct IntelHwTclBackend;
+
+impl Backend for IntelHwTclBackend {
+    fn name(&self) -> &'static str {
+        "intel-hw-tcl"
+    }
+
+    fn validate(_ctx: &ir::Context) -> CalyxResult<()> {
+        Ok(())
+    }
+
+    fn link_externs(
+        _lib: &ir::Context,
+        _write: &mut calyx_utils::OutputFile,
+    ) -> CalyxResult<()> {
+        Ok(())
+    }
+
+    fn emit(
+        prog: &ir::Context,
+        file: &mut calyx_utils::OutputFile,
+    ) -> CalyxResult<()> {
+        let toplevel = super::entrypoint(prog);
+        let memories = super::avalon_memories(toplevel)?;
+        let names: Vec<String> =
+            memories.iter().map(|m| m.name.clone()).collect();
+        let name = format!("calyx_{}", toplevel.name);
+
+        let mut out = String::new();
+        out += "package require -exact qsys 18.0\n\n";
+
+        // module properties
+        for (property, value) in [
+            ("NAME", name.as_str()),
+            ("DISPLAY_NAME", name.as_str()),
+            ("VERSION", "1.0"),
+            ("GROUP", "Calyx"),
+            ("AUTHOR", "Calyx"),
+            ("INTERNAL", "false"),
+            ("EDITABLE", "false"),
+        ] {
+            out += &format!("set_module_property {property} {value}\n");
+        }
+
+        // files implementing the component
+        out += "\nadd_fileset QUARTUS_SYNTH QUARTUS_SYNTH \"\" \"\"\n";
+        out += "set_fileset_property QUARTUS_SYNTH TOP_LEVEL Toplevel\n";
+        out += "add_fileset_file toplevel.v VERILOG PATH toplevel.v TOP_LEVEL_FILE\n";
+        out += "add_fileset_file main.sv SYSTEM_VERILOG PATH main.sv\n";
+
+        // clock and reset
+        out += &format!(
+            r#"
+add_interface clock clock end
+set_interface_property clock clockRate 0
+add_interface_port clock {CLOCK} clk Input 1
+
+add_interface reset reset end
+set_interface_property reset associatedClock clock
+set_interface_property reset synchronousEdges DEASSERT
+add_interface_port reset {RESET} reset_n Input 1
+"#
+        );
+
+        // control and status registers
+        out += "\n# Control and status registers (byte offsets):\n";
+        for (offset, description) in control_avalon::register_map(&names) {
+            out += &format!("#   0x{:02x}: {}\n", offset, description);
+        }
+        out += &csr_interface().hw_tcl("clock", "reset");
+        out += r#"
+add_interface irq interrupt end
+set_interface_property irq associatedAddressablePoint csr
+set_interface_property irq associatedClock clock
+set_interface_property irq associatedReset reset
+add_interface_port irq irq irq Output 1
+"#;
+
+        // memory hosts
+        for (idx, mem) in memories.iter().enumerate() {
+            out += &format!(
+                "\n# Transfers `{}' ({} elements of {} bits)\n",
+                mem.name, mem.size, mem.width
+            );
+            out += &memory_interface(idx, mem.width).hw_tcl("clock", "reset");
+        }
+
+        write!(file.get_write(), "{}", out)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+use super::avalon::AvalonInterface;
+use crate::xilinx::{
+    fsm,
+    memory_axi::{incr_addr, module_mode_fsm},
+    utils::cond_non_blk_assign,
+};
+use calyx_utils as utils;
+use vast::v05::ast as v;
+
+/// Width of the byte addresses issued by the memory controllers.
+pub(crate) const ADDRESS_WIDTH: u64 = 64;
+
+/// Generate a memory controller that copies a memory from the host into a
+/// local BRAM through an Avalon host interface, exposes the BRAM to the
+/// kernel, and finally writes the BRAM back to the host.
+///
+/// Every transfer moves exactly one element of the memory so the data width
+/// of the Avalon interface is the width of the memory.
+pub(crate) fn memory_module(
+    idx: usize,
+    data_width: u64,
+    memory_size: u64,
+    addr_width: u64,
+) -> v::Module {
+    let mut module =
+        v::Module::new(&format!("Memory_controller_avalon_{}", idx));
+
+    module.add_input("ACLK", 1);
+    module.add_input("ARESET", 1);
+
+    let avm = AvalonInterface::host("avm", ADDRESS_WIDTH, data_width);
+    avm.add_ports_to(&mut module);
+
+    module.add_input("BASE_ADDRESS", ADDRESS_WIDTH);
+    module.add_input("COPY_FROM_HOST", 1);
+    module.add_output("COPY_FROM_HOST_DONE", 1);
+    module.add_input("SEND_TO_HOST", 1);
+    module.add_output("SEND_TO_HOST_DONE", 1);
+
+    // BRAM interface
+    module.add_input("WRITE_DATA", data_width);
+    module.add_output("READ_DATA", data_width);
+    module.add_input("ADDR", addr_width);
+    module.add_input("WE", 1);
+    module.add_output("DONE", 1);
+
+    // internal signals
+    module.add_decl(v::Decl::new_wire("copy_done", 1));
+    module.add_stmt(v::Parallel::Assign(
+        "copy_done".into(),
+        v::Expr::new_eq("copy_addr_offset", memory_size as i32),
+    ));
+    module.add_decl(v::Decl::new_wire("send_done", 1));
+    module.add_stmt(v::Parallel::Assign(
+        "send_done".into(),
+        v::Expr::new_eq("send_addr_offset", memory_size as i32),
+    ));
+
+    let mode_fsm = module_mode_fsm(&mut module);
+
+    let readdatavalid: v::Expr = avm.port("readdatavalid").into();
+    let waitrequest: v::Expr = avm.port("waitrequest").into();
+
+    // only one read is in flight at a time: a read is pending from the cycle
+    // it is accepted until its data is returned
+    module.add_decl(v::Decl::new_reg("read_pending", 1));
+    module.add_stmt(cond_non_blk_assign(
+        "ACLK",
+        "read_pending",
+        vec![
+            (Some("ARESET".into()), 0.into()),
+            (Some(readdatavalid.clone()), 0.into()),
+            (
+                Some(v::Expr::new_logical_and(
+                    avm.port("read"),
+                    v::Expr::new_not(waitrequest.clone()),
+                )),
+                1.into(),
+            ),
+        ],
+    ));
+    module.add_stmt(v::Parallel::Assign(
+        avm.port("read").into(),
+        v::Expr::new_logical_and(
+            v::Expr::new_logical_and(
+                mode_fsm.state_is("copy"),
+                v::Expr::new_not("copy_done"),
+            ),
+            v::Expr::new_not("read_pending"),
+        ),
+    ));
+    // writes are accepted in the first cycle where waitrequest is low
+    let write_accepted = v::Expr::new_logical_and(
+        avm.port("write"),
+        v::Expr::new_not(waitrequest),
+    );
+    module.add_stmt(v::Parallel::Assign(
+        avm.port("write").into(),
+        v::Expr::new_logical_and(
+            mode_fsm.state_is("send"),
+            v::Expr::new_not("send_done"),
+        ),
+    ));
+
+    // add 1 so offset can count up to memory size inclusively
+    let offset_width = utils::bits_needed_for(memory_size) + 1;
+    module.add_decl(v::Decl::new_reg("copy_addr_offset", offset_width));
+    incr_addr(
+        &mut module,
+        mode_fsm.state_is("copy"),
+        "copy_addr_offset",
+        readdatavalid.clone(),
+    );
+    module.add_decl(v::Decl::new_reg("send_addr_offset", offset_width));
+    incr_addr(
+        &mut module,
+        mode_fsm.state_is("send"),
+        "send_addr_offset",
+        write_accepted,
+    );
+
+    // addresses are byte addressed which means addresses are computed as
+    // base + (offset << shift_by)
+    let shift_by = (data_width / 8).trailing_zeros() as i32;
+    for (shift, offset) in [
+        ("copy_shift", "copy_addr_offset"),
+        ("send_shift", "send_addr_offset"),
+    ] {
+        module.add_decl(v::Decl::new_wire(shift, ADDRESS_WIDTH));
+        let mut concat = v::ExprConcat::default();
+        concat.add_expr(offset);
+        concat.add_expr(v::Expr::new_repeat(
+            ADDRESS_WIDTH - offset_width,
+            v::Expr::new_ulit_bin(1, "0"),
+        ));
+        module.add_stmt(v::Parallel::Assign(
+            shift.into(),
+            v::Expr::new_shift_left(concat, shift_by),
+        ));
+    }
+    module.add_stmt(v::Parallel::Assign(
+        avm.port("address").into(),
+        v::Expr::new_mux(
+            mode_fsm.state_is("send"),
+            v::Expr::new_add("BASE_ADDRESS", "send_shift"),
+            v::Expr::new_add("BASE_ADDRESS", "copy_shift"),
+        ),
+    ));
+    module.add_stmt(v::Parallel::Assign(
+        avm.port("writedata").into(),
+        "bram_read_data".into(),
+    ));
+    module.add_stmt(v::Parallel::Assign(
+        avm.port("byteenable").into(),
+        v::Expr::new_repeat(data_width / 8, v::Expr::new_ulit_bin(1, "1")),
+    ));
+
+    bram_logic(
+        idx,
+        &mut module,
+        &mode_fsm,
+        readdatavalid,
+        &avm.port("readdata"),
+        data_width,
+        addr_width,
+    );
+    module.add_stmt(v::Parallel::Assign(
+        "READ_DATA".into(),
+        "bram_read_data".into(),
+    ));
+
+    module
+}
+
+fn bram_logic(
+    idx: usize,
+    module: &mut v::Module,
+    mode_fsm: &fsm::LinearFsm,
+    readdatavalid: v::Expr,
+    readdata: &str,
+    data_width: u64,
+    addr_width: u64,
+) {
+    module.add_decl(v::Decl::new_wire("bram_addr", addr_width));
+    module.add_decl(v::Decl::new_wire("bram_write_data", data_width));
+    module.add_decl(v::Decl::new_wire("bram_we", 1));
+    module.add_decl(v::Decl::new_wire("bram_read_data", data_width));
+    module.add_decl(v::Decl::new_wire("bram_done", 1));
+    let mut ram_instance =
+        v::Instance::new("bram", &format!("SINGLE_PORT_BRAM_{}", idx));
+    ram_instance.connect_ref("ACLK", "ACLK");
+    ram_instance.connect_ref("ADDR", "bram_addr");
+    ram_instance.connect_ref("Din", "bram_write_data");
+    ram_instance.connect_ref("WE", "bram_we");
+    ram_instance.connect_ref("Dout", "bram_read_data");
+    ram_instance.connect_ref("Done", "bram_done");
+    module.add_instance(ram_instance);
+    module.add_stmt(v::Parallel::Assign("DONE".into(), "bram_done".into()));
+
+    let copying =
+        v::Expr::new_logical_and(readdatavalid, mode_fsm.state_is("copy"));
+
+    // bram address logic
+    let copy_address =
+        v::Expr::new_slice("copy_addr_offset", (addr_width - 1) as i32, 0);
+    let send_address =
+        v::Expr::new_slice("send_addr_offset", (addr_width - 1) as i32, 0);
+    let mux_address = v::Expr::new_mux(
+        copying.clone(),
+        copy_address,
+        v::Expr::new_mux(
+            mode_fsm.state_is("bram"),
+            "ADDR",
+            v::Expr::new_mux(mode_fsm.state_is("send"), send_address, 0),
+        ),
+    );
+    module.add_stmt(v::Parallel::Assign("bram_addr".into(), mux_address));
+
+    // bram write enable
+    let mux_we = v::Expr::new_mux(
+        copying.clone(),
+        1,
+        v::Expr::new_mux(mode_fsm.state_is("bram"), "WE", 0),
+    );
+    module.add_stmt(v::Parallel::Assign("bram_we".into(), mux_we));
+
+    // bram write data
+    let mux_data = v::Expr::new_mux(
+        copying,
+        readdata,
+        v::Expr::new_mux(mode_fsm.state_is("bram"), "WRITE_DATA", 0),
+    );
+    module.add_stmt(v::Parallel::Assign("bram_write_data".into(), mux_data));
+}
@@ -0,0 +1,93 @@
+//! Backend for generating Avalon interfaces and Platform Designer components
+//! for Intel FPGAs
+mod avalon;
+mod control_avalon;
+mod hw_tcl;
+mod memory_avalon;
+mod toplevel;
+
+pub use hw_tcl::IntelHwTclBackend;
+pub use toplevel::IntelInterfaceBackend;
+
+use avalon::AvalonInterface;
+use calyx_ir as ir;
+use calyx_utils::{CalyxResult, Error};
+
+/// Name of the clock port of the generated component.
+const CLOCK: &str = "clk";
+/// Name of the active-low reset port of the generated component.
+const RESET: &str = "resetn";
+
+/// The agent interface used by the host to access the control and status
+/// registers.
+fn csr_interface() -> AvalonInterface {
+    AvalonInterface::agent(
+        "csr",
+        control_avalon::ADDRESS_WIDTH,
+        control_avalon::DATA_WIDTH,
+    )
+}
+
+/// The host interface used to transfer the `idx`th memory.
+fn memory_interface(idx: usize, data_width: u64) -> AvalonInterface {
+    AvalonInterface::host(
+        &format!("m{}", idx),
+        memory_avalon::ADDRESS_WIDTH,
+        data_width,
+    )
+}
+
+/// An `@external` memory that is transferred through an Avalon host.
+struct AvalonMemory {
+    name: String,
+    width: u64,
+    size: u64,
+    idx_size: u64,
+}
+
+/// Collects the `@external` memories of the entrypoint and checks that they
+/// can be transferred through an Avalon host interface.
+fn avalon_memories(comp: &ir::Component) -> CalyxResult<Vec<AvalonMemory>> {
+    let cells = ir::utils::external_memories_cells(comp);
+    if cells.is_empty() {
+        return Err(Error::misc(
+                "Program has no memories marked with attribute @external.".to_owned() +
+                " Please make sure that at least one memory is marked as @external."));
+    }
+
+    cells
+        .iter()
+        .map(|cell| {
+            let cell = cell.borrow();
+            if !cell.is_primitive(Some("comb_mem_d1")) {
+                return Err(Error::misc(format!(
+                    "cell `{}' marked with `@external' but is not a comb_mem_d1. The Avalon generator currently only supports `comb_mem_d1'",
+                    cell.name()
+                )));
+            }
+            let param = |name: &str| cell.get_parameter(name).unwrap();
+            let width = param("WIDTH");
+            if !(8..=1024).contains(&width) || !width.is_power_of_two() {
+                return Err(Error::misc(format!(
+                    "memory `{}' has width {}. The Avalon generator requires memory widths to be a power of two between 8 and 1024",
+                    cell.name(),
+                    width
+                )));
+            }
+            Ok(AvalonMemory {
+                name: cell.name().to_string(),
+                width,
+                size: param("SIZE"),
+                idx_size: param("IDX_SIZE"),
+            })
+        })
+        .collect()
+}
+
+/// Returns the entrypoint of the program.
+fn entrypoint(prog: &ir::Context) -> &ir::Component {
+    prog.components
+        .iter()
+        .find(|c| c.name == prog.entrypoint)
+        .unwrap()
+}
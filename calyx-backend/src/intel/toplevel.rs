@@ -0,0 +1,207 @@
+use super::{
+    control_avalon, csr_interface, memory_avalon, memory_interface,
+    AvalonMemory, CLOCK, RESET,
+};
+use crate::traits::Backend;
+use crate::xilinx::{memory_axi::bram, toplevel::host_transfer_fsm};
+use calyx_ir as ir;
+use calyx_utils::CalyxResult;
+use vast::v05::ast as v;
+
+/// A backend that generates the Avalon interfacing for a Calyx program.
+#[derive(Default)]
+pub struct IntelInterfaceBackend;
+
+impl Backend for IntelInterfaceBackend {
+    fn name(&self) -> &'static str {
+        "intel-avalon"
+    }
+
+    fn validate(_ctx: &ir::Context) -> CalyxResult<()> {
+        Ok(())
+    }
+
+    fn link_externs(
+        _lib: &ir::Context,
+        _write: &mut calyx_utils::OutputFile,
+    ) -> CalyxResult<()> {
+        Ok(())
+    }
+
+    fn emit(
+        prog: &ir::Context,
+        file: &mut calyx_utils::OutputFile,
+    ) -> CalyxResult<()> {
+        let toplevel = super::entrypoint(prog);
+        let memories = super::avalon_memories(toplevel)?;
+        let names: Vec<String> =
+            memories.iter().map(|m| m.name.clone()).collect();
+
+        let mut modules = vec![top_level(toplevel.name, &memories)];
+        for (i, mem) in memories.iter().enumerate() {
+            modules.push(bram(
+                &format!("SINGLE_PORT_BRAM_{}", i),
+                mem.width,
+                mem.size,
+                mem.idx_size,
+            ))
+        }
+
+        modules.push(control_avalon::control_module("Control_avalon", &names));
+
+        for (i, mem) in memories.iter().enumerate() {
+            modules.push(memory_avalon::memory_module(
+                i,
+                mem.width,
+                mem.size,
+                mem.idx_size,
+            ))
+        }
+
+        let module_string = modules
+            .into_iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        write!(
+            file.get_write(),
+            r#"`default_nettype none
+/* verilator lint_off DECLFILENAME */
+{}`default_nettype wire"#,
+            module_string,
+        )?;
+
+        Ok(())
+    }
+}
+
+fn top_level(kernel: ir::Id, memories: &[AvalonMemory]) -> v::Module {
+    let names: Vec<String> = memories.iter().map(|m| m.name.clone()).collect();
+    let mut module = v::Module::new("Toplevel");
+
+    // add system signals
+    module.add_input(CLOCK, 1);
+    module.add_input(RESET, 1);
+    module.add_output("irq", 1);
+
+    // avalon control and status registers
+    let csr = csr_interface();
+    csr.add_ports_to(&mut module);
+
+    // add an avalon host for each external memory
+    for (idx, mem) in memories.iter().enumerate() {
+        memory_interface(idx, mem.width).add_ports_to(&mut module);
+    }
+
+    // wires
+    module.add_stmt(v::Decl::new_wire("ap_start", 1));
+    module.add_stmt(v::Decl::new_wire("ap_done", 1));
+    for mem in &names {
+        module.add_stmt(v::Decl::new_wire(mem, 64));
+    }
+
+    // reset
+    module.add_stmt(v::Decl::new_wire("reset", 1));
+    module
+        .add_stmt(v::Parallel::Assign("reset".into(), v::Expr::new_not(RESET)));
+
+    // instantiate control and status registers
+    let mut control_instance =
+        v::Instance::new("inst_control_avalon", "Control_avalon");
+    control_instance.connect("ACLK", CLOCK);
+    control_instance.connect("ARESET", "reset");
+    for mem in &names {
+        control_instance.connect_ref(mem, mem);
+    }
+    control_instance.connect("ap_start", "ap_start");
+    control_instance.connect("ap_done", "ap_done");
+    control_instance.connect("irq", "irq");
+    for (role, port) in csr.ports() {
+        control_instance.connect_ref(&format!("avs_{}", role), &port);
+    }
+    module.add_instance(control_instance);
+
+    // and some wires for each memory
+    for mem in &names {
+        module.add_decl(v::Decl::new_wire(&format!("{}_copy", mem), 1));
+        module.add_decl(v::Decl::new_wire(&format!("{}_copy_done", mem), 1));
+        module.add_decl(v::Decl::new_wire(&format!("{}_send", mem), 1));
+        module.add_decl(v::Decl::new_wire(&format!("{}_send_done", mem), 1));
+    }
+    host_transfer_fsm(&mut module, &names, CLOCK);
+
+    // instantiate memory controllers
+    for (idx, mem) in memories.iter().enumerate() {
+        let name = &mem.name;
+        let write_data = format!("{}_write_data", name);
+        let read_data = format!("{}_read_data", name);
+        let addr0 = format!("{}_addr0", name);
+        let write_en = format!("{}_write_en", name);
+        let done = format!("{}_done", name);
+        module.add_decl(v::Decl::new_wire(&write_data, mem.width));
+        module.add_decl(v::Decl::new_wire(&read_data, mem.width));
+        module.add_decl(v::Decl::new_wire(&addr0, mem.idx_size));
+        module.add_decl(v::Decl::new_wire(&write_en, 1));
+        module.add_decl(v::Decl::new_wire(&done, 1));
+
+        let mut memory_instance = v::Instance::new(
+            &format!("inst_mem_controller_avalon_{}", idx),
+            &format!("Memory_controller_avalon_{}", idx),
+        );
+        memory_instance.connect("ACLK", CLOCK);
+        memory_instance.connect(
+            "ARESET",
+            v::Expr::new_logical_or("reset", "memories_sent"),
+        );
+        for (role, port) in memory_interface(idx, mem.width).ports() {
+            memory_instance.connect_ref(&format!("avm_{}", role), &port);
+        }
+        memory_instance.connect_ref("BASE_ADDRESS", name);
+        memory_instance
+            .connect_ref("COPY_FROM_HOST", &format!("{}_copy", name));
+        memory_instance
+            .connect_ref("COPY_FROM_HOST_DONE", &format!("{}_copy_done", name));
+        memory_instance.connect_ref("SEND_TO_HOST", &format!("{}_send", name));
+        memory_instance
+            .connect_ref("SEND_TO_HOST_DONE", &format!("{}_send_done", name));
+
+        memory_instance.connect_ref("WRITE_DATA", &write_data);
+        memory_instance.connect_ref("READ_DATA", &read_data);
+        memory_instance.connect_ref("ADDR", &addr0);
+        memory_instance.connect_ref("WE", &write_en);
+        memory_instance.connect_ref("DONE", &done);
+        module.add_instance(memory_instance);
+    }
+
+    // instantiate kernel
+    let mut kernel_instance = v::Instance::new("kernel_inst", kernel.as_ref());
+    module.add_decl(v::Decl::new_wire("kernel_start", 1));
+    module.add_decl(v::Decl::new_wire("kernel_done", 1));
+    kernel_instance.connect_ref("clk", CLOCK);
+    kernel_instance.connect_ref("go", "kernel_start");
+    kernel_instance
+        .connect("reset", v::Expr::new_logical_or("reset", "memories_sent"));
+    kernel_instance.connect_ref("done", "kernel_done");
+    for mem in &names {
+        let read_data = format!("{}_read_data", mem);
+        let done = format!("{}_done", mem);
+        let addr0 = format!("{}_addr0", mem);
+        let write_data = format!("{}_write_data", mem);
+        let write_en = format!("{}_write_en", mem);
+        kernel_instance.connect_ref(&read_data, &read_data);
+        kernel_instance.connect_ref(&done, &done);
+        kernel_instance.connect_ref(&addr0, &addr0);
+        kernel_instance.connect_ref(&write_data, &write_data);
+        kernel_instance.connect_ref(&write_en, &write_en);
+    }
+    module.add_instance(kernel_instance);
+
+    // done signal
+    module.add_stmt(v::Parallel::Assign(
+        "ap_done".into(),
+        "memories_sent".into(),
+    ));
+
+    module
+}
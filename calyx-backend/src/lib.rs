@@ -2,6 +2,7 @@
 mod backend_opt;
 mod firrtl;
 mod primitive_uses;
+mod source_map;
 mod traits;
 mod verilog;
 mod yxi;
@@ -9,6 +10,7 @@ mod yxi;
 pub use backend_opt::BackendOpt;
 pub use firrtl::FirrtlBackend;
 pub use primitive_uses::PrimitiveUsesBackend;
+pub use source_map::record_source_groups;
 pub use traits::Backend;
 pub use verilog::VerilogBackend;
 pub use yxi::YxiBackend;
@@ -30,3 +32,6 @@ pub use sexp::SexpBackend;
 
 #[cfg(feature = "xilinx")]
 pub mod xilinx;
+
+#[cfg(feature = "intel")]
+pub mod intel;
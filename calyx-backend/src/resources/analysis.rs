@@ -0,0 +1,431 @@
+//! Estimates the resources and the critical path of a single component.
+use super::cost_model::{CostModel, Resources};
+use calyx_ir::{self as ir, RRC};
+use calyx_utils::CalyxResult;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// The longest combinational path in a component.
+#[derive(Serialize, Clone)]
+pub struct CriticalPath {
+    /// Estimated delay of the path, including the setup time of the
+    /// register at its end.
+    pub delay: f64,
+    /// The ports on the path, starting with the source.
+    pub path: Vec<String>,
+}
+
+/// Cost of a primitive cell.
+pub struct CellCost {
+    pub name: ir::Id,
+    pub params: ir::Binding,
+    pub external: bool,
+    /// `None` if the cost model has no entry for the primitive.
+    pub resources: Option<Resources>,
+}
+
+/// How a cell propagates signals from its inputs to its outputs.
+enum CellTiming {
+    /// Outputs depend combinationally on the inputs with the given delay.
+    Comb(f64),
+    /// Outputs are driven by registers.
+    Seq,
+    /// The cell is a constant.
+    Const,
+}
+
+/// An assignment driving a port.
+struct Driver {
+    src: RRC<ir::Port>,
+    /// Ports read by the guard.
+    guard_ports: Vec<RRC<ir::Port>>,
+    /// Number of LUT levels needed to compute the guard.
+    guard_levels: u64,
+    /// True if the assignment is unguarded.
+    unguarded: bool,
+}
+
+/// Arrival time of a signal at a port and the port it came from.
+#[derive(Clone)]
+struct Arrival {
+    delay: f64,
+    pred: Option<ir::Canonical>,
+}
+
+pub struct ComponentAnalysis {
+    /// Resources used by the primitives in the component.
+    pub primitives: Resources,
+    /// Resources used by guards and multiplexers in the component.
+    pub logic: Resources,
+    pub cells: Vec<CellCost>,
+    pub critical_path: Option<CriticalPath>,
+}
+
+impl ComponentAnalysis {
+    pub fn new(comp: &ir::Component, model: &CostModel) -> CalyxResult<Self> {
+        let mut primitives = Resources::default();
+        let mut cells = vec![];
+        let mut timing = HashMap::new();
+        for cell_ref in comp.cells.iter() {
+            let cell = cell_ref.borrow();
+            let cell_timing = match &cell.prototype {
+                ir::CellType::Primitive {
+                    name,
+                    param_binding,
+                    is_comb,
+                    ..
+                } => {
+                    let cost =
+                        model.primitive(*name, param_binding, *is_comb)?;
+                    let external =
+                        cell.get_attribute(ir::BoolAttr::External).is_some();
+                    let resources = cost.as_ref().map(|c| c.resources);
+                    if let (Some(res), false) = (resources, external) {
+                        primitives += res;
+                    }
+                    cells.push(CellCost {
+                        name: *name,
+                        params: (**param_binding).clone(),
+                        external,
+                        resources,
+                    });
+                    match cost.and_then(|c| c.comb_delay) {
+                        Some(delay) => CellTiming::Comb(delay),
+                        // Unknown combinational primitives are assumed to be
+                        // free.
+                        None if *is_comb => CellTiming::Comb(0.0),
+                        None => CellTiming::Seq,
+                    }
+                }
+                ir::CellType::Constant { .. } => CellTiming::Const,
+                ir::CellType::Component { .. }
+                | ir::CellType::ThisComponent => CellTiming::Seq,
+            };
+            timing.insert(cell.name(), cell_timing);
+        }
+        // The ports of the component are timed like the ports of a register:
+        // paths start at its inputs and end at its outputs.
+        timing.insert(comp.signature.borrow().name(), CellTiming::Seq);
+
+        let mut analysis = LogicAnalysis {
+            model,
+            comp,
+            timing,
+            drivers: HashMap::new(),
+            guards: HashMap::new(),
+            arrivals: HashMap::new(),
+            visiting: HashSet::new(),
+        };
+        comp.iter_assignments(|assign| analysis.add_assignment(assign));
+        comp.iter_static_assignments(|assign| analysis.add_assignment(assign));
+
+        let logic = analysis.logic();
+        let critical_path = analysis.critical_path();
+        Ok(ComponentAnalysis {
+            primitives,
+            logic,
+            cells,
+            critical_path,
+        })
+    }
+}
+
+struct LogicAnalysis<'a> {
+    model: &'a CostModel,
+    comp: &'a ir::Component,
+    timing: HashMap<ir::Id, CellTiming>,
+    /// Assignments driving each port.
+    drivers: HashMap<ir::Canonical, (RRC<ir::Port>, Vec<Driver>)>,
+    /// Number of LUTs needed for each distinct guard.
+    guards: HashMap<String, u64>,
+    arrivals: HashMap<ir::Canonical, Arrival>,
+    /// Ports whose arrival time is being computed. Used to break
+    /// combinational loops.
+    visiting: HashSet<ir::Canonical>,
+}
+
+impl LogicAnalysis<'_> {
+    fn add_assignment<T>(&mut self, assign: &ir::Assignment<T>)
+    where
+        T: ToString + Eq,
+    {
+        let guard = &assign.guard;
+        let unguarded = matches!(**guard, ir::Guard::True);
+        let (leaves, comparisons) = self.guard_inputs(guard);
+        let guard_levels = comparisons
+            .iter()
+            .map(|bits| self.model.levels_for(*bits))
+            .max()
+            .unwrap_or(0)
+            + self.model.levels_for(leaves);
+        if !unguarded {
+            let luts = self.model.luts_for(leaves)
+                + comparisons
+                    .iter()
+                    .map(|bits| self.model.luts_for(*bits))
+                    .sum::<u64>();
+            self.guards.insert(ir::Printer::guard_str(guard), luts);
+        }
+        let dst = assign.dst.borrow().canonical();
+        self.drivers
+            .entry(dst)
+            .or_insert_with(|| (assign.dst.clone(), vec![]))
+            .1
+            .push(Driver {
+                src: assign.src.clone(),
+                guard_ports: guard.all_ports(),
+                guard_levels,
+                unguarded,
+            });
+    }
+
+    /// Returns the number of single-bit signals combined by the guard and
+    /// the number of bits compared by each comparison in it.
+    fn guard_inputs<T>(&self, guard: &ir::Guard<T>) -> (u64, Vec<u64>) {
+        match guard {
+            ir::Guard::True => (0, vec![]),
+            ir::Guard::Port(_) | ir::Guard::Info(_) => (1, vec![]),
+            ir::Guard::Not(g) => self.guard_inputs(g),
+            ir::Guard::And(l, r) | ir::Guard::Or(l, r) => {
+                let (l_leaves, mut l_cmps) = self.guard_inputs(l);
+                let (r_leaves, r_cmps) = self.guard_inputs(r);
+                l_cmps.extend(r_cmps);
+                (l_leaves + r_leaves, l_cmps)
+            }
+            ir::Guard::CompOp(_, l, r) => {
+                let width = l.borrow().width;
+                let is_const = |p: &RRC<ir::Port>| {
+                    matches!(
+                        self.timing.get(&p.borrow().get_parent_name()),
+                        Some(CellTiming::Const)
+                    )
+                };
+                let bits = if is_const(l) || is_const(r) {
+                    width
+                } else {
+                    2 * width
+                };
+                (1, vec![bits])
+            }
+        }
+    }
+
+    /// Number of inputs of the multiplexer selecting between `drivers`.
+    fn mux_inputs(&self, width: u64, drivers: &[Driver]) -> u64 {
+        if drivers.len() == 1 && drivers[0].unguarded {
+            return 0;
+        }
+        // Single-bit ports driven by constants only need the guards.
+        let all_const = drivers.iter().all(|d| {
+            matches!(
+                self.timing.get(&d.src.borrow().get_parent_name()),
+                Some(CellTiming::Const)
+            )
+        });
+        if width == 1 && all_const {
+            drivers.len() as u64
+        } else {
+            2 * drivers.len() as u64
+        }
+    }
+
+    /// Resources used by guards and multiplexers.
+    fn logic(&self) -> Resources {
+        let guard_luts: u64 = self.guards.values().sum();
+        let mux_luts: u64 = self
+            .drivers
+            .values()
+            .map(|(dst, drivers)| {
+                let width = dst.borrow().width;
+                let inputs = self.mux_inputs(width, drivers);
+                if width == 1 || inputs <= drivers.len() as u64 {
+                    self.model.luts_for(inputs)
+                } else {
+                    width * self.model.luts_for(inputs)
+                }
+            })
+            .sum();
+        Resources {
+            lut: (guard_luts + mux_luts) as f64,
+            ..Default::default()
+        }
+    }
+
+    /// Compute the time at which the signal on `port` becomes stable.
+    fn arrival(&mut self, port: &RRC<ir::Port>) -> Arrival {
+        let canon = port.borrow().canonical();
+        if let Some(arrival) = self.arrivals.get(&canon) {
+            return arrival.clone();
+        }
+        if !self.visiting.insert(canon.clone()) {
+            log::warn!(
+                "Combinational loop through `{canon}' in component `{}'",
+                self.comp.name
+            );
+            return Arrival {
+                delay: 0.0,
+                pred: None,
+            };
+        }
+
+        let arrival = self.compute_arrival(port);
+        self.visiting.remove(&canon);
+        self.arrivals.insert(canon, arrival.clone());
+        arrival
+    }
+
+    fn compute_arrival(&mut self, port: &RRC<ir::Port>) -> Arrival {
+        let (is_output, parent) = {
+            let p = port.borrow();
+            (p.direction == ir::Direction::Output, p.parent.clone())
+        };
+        if is_output {
+            if let ir::PortParent::Cell(cell) = parent {
+                let cell = cell.upgrade();
+                let cell = cell.borrow();
+                match self.timing.get(&cell.name()) {
+                    Some(CellTiming::Const) | None => {
+                        return Arrival {
+                            delay: 0.0,
+                            pred: None,
+                        }
+                    }
+                    Some(CellTiming::Seq) => {
+                        // This includes the inputs of the component, which
+                        // are assumed to come from registers.
+                        return Arrival {
+                            delay: self.model.logic.clock_to_out,
+                            pred: None,
+                        };
+                    }
+                    Some(&CellTiming::Comb(delay)) => {
+                        let inputs = cell
+                            .ports()
+                            .iter()
+                            .filter(|p| {
+                                p.borrow().direction == ir::Direction::Input
+                            })
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        drop(cell);
+                        return inputs
+                            .iter()
+                            .map(|p| {
+                                let a = self.arrival(p);
+                                Arrival {
+                                    delay: a.delay + delay,
+                                    pred: Some(p.borrow().canonical()),
+                                }
+                            })
+                            .max_by(|a, b| a.delay.total_cmp(&b.delay))
+                            .unwrap_or(Arrival { delay, pred: None });
+                    }
+                }
+            }
+        }
+
+        // Ports driven by assignments.
+        let canon = port.borrow().canonical();
+        let Some((_, drivers)) = self.drivers.get(&canon) else {
+            return Arrival {
+                delay: 0.0,
+                pred: None,
+            };
+        };
+        let width = port.borrow().width;
+        let mux_levels = self.model.levels_for(self.mux_inputs(width, drivers));
+        let sources = drivers
+            .iter()
+            .map(|d| {
+                (
+                    d.src.clone(),
+                    d.guard_ports.clone(),
+                    d.guard_levels as f64 * self.model.logic.lut_delay,
+                )
+            })
+            .collect::<Vec<_>>();
+        let logic = &self.model.logic;
+        let wire_delay = logic.net_delay + mux_levels as f64 * logic.lut_delay;
+        let mut best = Arrival {
+            delay: 0.0,
+            pred: None,
+        };
+        for (src, guard_ports, guard_delay) in sources {
+            let mut candidates = vec![(self.arrival(&src), src)];
+            for gp in guard_ports {
+                let mut a = self.arrival(&gp);
+                a.delay += guard_delay;
+                candidates.push((a, gp));
+            }
+            for (a, p) in candidates {
+                let delay = a.delay + wire_delay;
+                if best.pred.is_none() || delay > best.delay {
+                    best = Arrival {
+                        delay,
+                        pred: Some(p.borrow().canonical()),
+                    };
+                }
+            }
+        }
+        best
+    }
+
+    /// Find the longest path that ends in a register or an output of the
+    /// component.
+    fn critical_path(&mut self) -> Option<CriticalPath> {
+        let endpoints = self
+            .drivers
+            .values()
+            .map(|(dst, _)| dst.clone())
+            .filter(|dst| {
+                let p = dst.borrow();
+                match &p.parent {
+                    ir::PortParent::Cell(cell) => matches!(
+                        self.timing.get(&cell.upgrade().borrow().name()),
+                        Some(CellTiming::Seq)
+                    ),
+                    _ => false,
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut best: Option<(f64, ir::Canonical)> = None;
+        for port in endpoints {
+            let arrival = self.arrival(&port);
+            let canon = port.borrow().canonical();
+            let better = match &best {
+                None => true,
+                Some((delay, c)) => {
+                    arrival.delay > *delay
+                        || (arrival.delay == *delay && canon < *c)
+                }
+            };
+            if better {
+                best = Some((arrival.delay, canon));
+            }
+        }
+        let (delay, end) = best?;
+        // Paths can start or end at the ports of the component, which are
+        // timed like registers because the design around the component is
+        // assumed to register them. Print them by their bare name, the way
+        // they are written in the wires of the component.
+        let sig = self.comp.signature.borrow().name();
+        let name = |port: &ir::Canonical| {
+            if port.cell == sig {
+                port.port.to_string()
+            } else {
+                port.to_string()
+            }
+        };
+        let mut path = vec![name(&end)];
+        let mut curr = self.arrivals.get(&end).and_then(|a| a.pred.clone());
+        while let Some(port) = curr {
+            path.push(name(&port));
+            curr = self.arrivals.get(&port).and_then(|a| a.pred.clone());
+        }
+        path.reverse();
+        Some(CriticalPath {
+            delay: ((delay + self.model.logic.setup) * 1000.0).round() / 1000.0,
+            path,
+        })
+    }
+}
@@ -0,0 +1,455 @@
+//! Cost models that describe the resources and delays of primitives on a
+//! particular FPGA family.
+//!
+//! A cost model is a TOML file with the following structure:
+//! ```toml
+//! name = "ultrascale-plus"
+//!
+//! [logic]
+//! lut_inputs = 6      # Number of inputs of a LUT
+//! lut_delay = 0.15    # Delay through a LUT (ns)
+//! net_delay = 0.35    # Delay of a net between two cells (ns)
+//! clock_to_out = 0.3  # Clock-to-out delay of a register (ns)
+//! setup = 0.1         # Setup time of a register (ns)
+//!
+//! [primitives.std_add]
+//! lut = "WIDTH"
+//! delay = "0.3 + 0.015 * WIDTH"
+//! ```
+//! Each primitive may define `lut`, `ff`, `dsp`, `bram`, and `delay`. The
+//! values are numbers or expressions over the parameters of the primitive
+//! with `+`, `-`, `*`, `/`, parentheses, and the functions `ceil`, `floor`,
+//! `log2`, `min`, and `max`. Missing entries default to zero.
+//!
+//! `delay` is the delay from any input to any output of the primitive. It
+//! is only used for combinational primitives; the outputs of other
+//! primitives are driven by registers. A primitive can override this by
+//! setting `combinational = true`, which is needed for primitives like
+//! `comb_mem_d1` that have a clock but combinational read ports.
+use calyx_ir as ir;
+use calyx_utils::{CalyxResult, Error};
+use serde::{de, Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Cost model used when none is provided.
+const DEFAULT_MODEL: &str = include_str!("ultrascale-plus.toml");
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CostModel {
+    /// Name of the FPGA family described by this model.
+    pub name: String,
+    /// Costs of the logic generated for guards and multiplexers.
+    pub logic: LogicModel,
+    /// Costs of each primitive.
+    #[serde(default)]
+    primitives: HashMap<String, PrimitiveModel>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LogicModel {
+    /// Number of inputs of a LUT.
+    pub lut_inputs: u64,
+    /// Delay through a single LUT.
+    pub lut_delay: f64,
+    /// Delay of a net connecting two cells.
+    pub net_delay: f64,
+    /// Delay from the clock edge to the output of a register.
+    pub clock_to_out: f64,
+    /// Setup time of a register.
+    pub setup: f64,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct PrimitiveModel {
+    #[serde(default)]
+    lut: Expr,
+    #[serde(default)]
+    ff: Expr,
+    #[serde(default)]
+    dsp: Expr,
+    #[serde(default)]
+    bram: Expr,
+    #[serde(default)]
+    delay: Expr,
+    combinational: Option<bool>,
+}
+
+/// Resources used by some hardware.
+#[derive(Serialize, Default, Clone, Copy)]
+pub struct Resources {
+    pub lut: f64,
+    pub ff: f64,
+    pub dsp: f64,
+    pub bram: f64,
+}
+
+impl Resources {
+    /// Round each resource to two decimal places for reporting.
+    pub fn rounded(self) -> Self {
+        let round = |x: f64| (x * 100.0).round() / 100.0;
+        Resources {
+            lut: round(self.lut),
+            ff: round(self.ff),
+            dsp: round(self.dsp),
+            bram: round(self.bram),
+        }
+    }
+}
+
+impl std::ops::Add for Resources {
+    type Output = Resources;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl std::ops::AddAssign for Resources {
+    fn add_assign(&mut self, rhs: Self) {
+        self.lut += rhs.lut;
+        self.ff += rhs.ff;
+        self.dsp += rhs.dsp;
+        self.bram += rhs.bram;
+    }
+}
+
+impl std::ops::Mul<f64> for Resources {
+    type Output = Resources;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Resources {
+            lut: self.lut * rhs,
+            ff: self.ff * rhs,
+            dsp: self.dsp * rhs,
+            bram: self.bram * rhs,
+        }
+    }
+}
+
+/// The cost of a primitive instance under a [CostModel].
+pub struct PrimitiveCost {
+    pub resources: Resources,
+    /// The delay from inputs to outputs if the primitive is combinational.
+    pub comb_delay: Option<f64>,
+}
+
+impl CostModel {
+    /// Load the cost model at `path`, or the default model if there is none.
+    pub fn load(path: Option<&Path>) -> CalyxResult<Self> {
+        let (contents, source) = match path {
+            Some(path) => (
+                std::fs::read_to_string(path).map_err(|err| {
+                    Error::invalid_file(format!(
+                        "Failed to read cost model `{}': {err}",
+                        path.display()
+                    ))
+                })?,
+                path.display().to_string(),
+            ),
+            None => (DEFAULT_MODEL.to_string(), "<default>".to_string()),
+        };
+        let model: CostModel = toml::from_str(&contents).map_err(|err| {
+            Error::misc(format!("Malformed cost model `{source}': {err}"))
+        })?;
+        if model.logic.lut_inputs < 2 {
+            return Err(Error::misc(format!(
+                "Malformed cost model `{source}': `lut_inputs' must be at least 2"
+            )));
+        }
+        Ok(model)
+    }
+
+    /// Compute the cost of an instance of primitive `name` with the given
+    /// parameters. Returns `None` if the model doesn't describe the primitive.
+    pub fn primitive(
+        &self,
+        name: ir::Id,
+        params: &ir::Binding,
+        is_comb: bool,
+    ) -> CalyxResult<Option<PrimitiveCost>> {
+        let Some(model) = self.primitives.get(name.as_ref()) else {
+            return Ok(None);
+        };
+        let env: HashMap<&str, f64> = params
+            .iter()
+            .map(|(param, val)| (param.as_ref(), *val as f64))
+            .collect();
+        let eval = |expr: &Expr, field: &str| {
+            expr.eval(&env).map_err(|err| {
+                Error::misc(format!(
+                    "Failed to compute `{field}' for primitive `{name}': {err}"
+                ))
+            })
+        };
+        let resources = Resources {
+            lut: eval(&model.lut, "lut")?,
+            ff: eval(&model.ff, "ff")?,
+            dsp: eval(&model.dsp, "dsp")?,
+            bram: eval(&model.bram, "bram")?,
+        };
+        let comb_delay = if model.combinational.unwrap_or(is_comb) {
+            Some(eval(&model.delay, "delay")?)
+        } else {
+            None
+        };
+        Ok(Some(PrimitiveCost {
+            resources,
+            comb_delay,
+        }))
+    }
+
+    /// Number of LUTs needed to combine `inputs` signals into one.
+    pub fn luts_for(&self, inputs: u64) -> u64 {
+        if inputs <= 1 {
+            0
+        } else {
+            (inputs - 1).div_ceil(self.logic.lut_inputs - 1)
+        }
+    }
+
+    /// Number of LUT levels needed to combine `inputs` signals into one.
+    pub fn levels_for(&self, inputs: u64) -> u64 {
+        let mut levels = 0;
+        let mut reach = 1;
+        while reach < inputs {
+            reach *= self.logic.lut_inputs;
+            levels += 1;
+        }
+        levels
+    }
+}
+
+/// An arithmetic expression over the parameters of a primitive.
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Num(f64),
+    Param(String),
+    Neg(Box<Expr>),
+    Bin(char, Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+}
+
+impl Default for Expr {
+    fn default() -> Self {
+        Expr::Num(0.0)
+    }
+}
+
+impl Expr {
+    fn eval(&self, env: &HashMap<&str, f64>) -> Result<f64, String> {
+        Ok(match self {
+            Expr::Num(n) => *n,
+            Expr::Param(p) => *env
+                .get(p.as_str())
+                .ok_or_else(|| format!("unknown parameter `{p}'"))?,
+            Expr::Neg(e) => -e.eval(env)?,
+            Expr::Bin(op, l, r) => {
+                let (l, r) = (l.eval(env)?, r.eval(env)?);
+                match op {
+                    '+' => l + r,
+                    '-' => l - r,
+                    '*' => l * r,
+                    '/' => l / r,
+                    _ => unreachable!(),
+                }
+            }
+            Expr::Call(func, args) => {
+                let args = args
+                    .iter()
+                    .map(|arg| arg.eval(env))
+                    .collect::<Result<Vec<_>, _>>()?;
+                match (func.as_str(), args.as_slice()) {
+                    ("ceil", [x]) => x.ceil(),
+                    ("floor", [x]) => x.floor(),
+                    ("log2", [x]) => x.log2(),
+                    ("min", [x, y]) => x.min(*y),
+                    ("max", [x, y]) => x.max(*y),
+                    _ => {
+                        return Err(format!(
+                            "unknown function `{func}' with {} argument(s)",
+                            args.len()
+                        ))
+                    }
+                }
+            }
+        })
+    }
+
+    fn parse(input: &str) -> Result<Expr, String> {
+        let mut parser = ExprParser {
+            chars: input.chars().filter(|c| !c.is_whitespace()).collect(),
+            pos: 0,
+        };
+        let expr = parser.sum()?;
+        if parser.pos != parser.chars.len() {
+            return Err(format!(
+                "unexpected `{}' in `{input}'",
+                parser.chars[parser.pos]
+            ));
+        }
+        Ok(expr)
+    }
+}
+
+/// Recursive descent parser for [Expr].
+struct ExprParser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl ExprParser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(format!("expected `{c}'"))
+        }
+    }
+
+    fn sum(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.product()?;
+        while let Some(op @ ('+' | '-')) = self.peek() {
+            self.pos += 1;
+            lhs = Expr::Bin(op, Box::new(lhs), Box::new(self.product()?));
+        }
+        Ok(lhs)
+    }
+
+    fn product(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.atom()?;
+        while let Some(op @ ('*' | '/')) = self.peek() {
+            self.pos += 1;
+            lhs = Expr::Bin(op, Box::new(lhs), Box::new(self.atom()?));
+        }
+        Ok(lhs)
+    }
+
+    fn atom(&mut self) -> Result<Expr, String> {
+        match self.peek() {
+            Some('-') => {
+                self.pos += 1;
+                Ok(Expr::Neg(Box::new(self.atom()?)))
+            }
+            Some('(') => {
+                self.pos += 1;
+                let expr = self.sum()?;
+                self.expect(')')?;
+                Ok(expr)
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => {
+                let start = self.pos;
+                while matches!(self.peek(), Some(c) if c.is_ascii_digit() || c == '.')
+                {
+                    self.pos += 1;
+                }
+                let num: String = self.chars[start..self.pos].iter().collect();
+                num.parse()
+                    .map(Expr::Num)
+                    .map_err(|_| format!("malformed number `{num}'"))
+            }
+            Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+                let start = self.pos;
+                while matches!(self.peek(), Some(c) if c.is_ascii_alphanumeric() || c == '_')
+                {
+                    self.pos += 1;
+                }
+                let name: String = self.chars[start..self.pos].iter().collect();
+                if self.peek() != Some('(') {
+                    return Ok(Expr::Param(name));
+                }
+                self.pos += 1;
+                let mut args = vec![self.sum()?];
+                while self.peek() == Some(',') {
+                    self.pos += 1;
+                    args.push(self.sum()?);
+                }
+                self.expect(')')?;
+                Ok(Expr::Call(name, args))
+            }
+            Some(c) => Err(format!("unexpected `{c}'")),
+            None => Err("unexpected end of expression".to_string()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Expr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct ExprVisitor;
+
+        impl<'de> de::Visitor<'de> for ExprVisitor {
+            type Value = Expr;
+
+            fn expecting(
+                &self,
+                f: &mut std::fmt::Formatter,
+            ) -> std::fmt::Result {
+                write!(f, "a number or an expression")
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Expr, E> {
+                Ok(Expr::Num(v as f64))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Expr, E> {
+                Ok(Expr::Num(v as f64))
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Expr, E> {
+                Ok(Expr::Num(v))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Expr, E> {
+                Expr::parse(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(ExprVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Expr;
+    use std::collections::HashMap;
+
+    fn eval(expr: &str, env: &[(&str, f64)]) -> f64 {
+        let env: HashMap<&str, f64> = env.iter().copied().collect();
+        Expr::parse(expr).unwrap().eval(&env).unwrap()
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(eval("1 + 2 * 3 - 4 / 2", &[]), 5.0);
+        assert_eq!(eval("(1 + 2) * -3", &[]), -9.0);
+    }
+
+    #[test]
+    fn params_and_functions() {
+        let env = [("WIDTH", 32.0), ("SIZE", 1024.0)];
+        assert_eq!(eval("ceil(WIDTH * SIZE / 36864)", &env), 1.0);
+        assert_eq!(eval("max(log2(WIDTH), 6)", &env), 6.0);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(Expr::parse("1 +").is_err());
+        assert!(Expr::parse("ceil(1").is_err());
+        assert!(Expr::parse("WIDTH)").is_err());
+        let env = HashMap::new();
+        assert!(Expr::parse("WIDTH").unwrap().eval(&env).is_err());
+        assert!(Expr::parse("sqrt(4)").unwrap().eval(&env).is_err());
+    }
+}
@@ -0,0 +1,208 @@
+//! Resource estimation backend for the Calyx compiler.
+//! Transforms an [`ir::Context`](crate::ir::Context) into a JSON report that
+//! estimates the LUTs, FFs, DSPs, and BRAMs used by the generated hardware
+//! and the delay of its critical path.
+//!
+//! Costs of primitives come from a [CostModel] which can be provided with
+//! `--resource-model`. The report also covers the logic that implements
+//! guards and multiplexers, which includes the FSMs generated by the
+//! compiler when the program is compiled before running this backend.
+mod analysis;
+mod cost_model;
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::traits::Backend;
+use analysis::{ComponentAnalysis, CriticalPath};
+use calyx_ir as ir;
+use calyx_utils::{CalyxResult, OutputFile};
+use cost_model::{CostModel, Resources};
+use serde::Serialize;
+
+#[derive(Default)]
+pub struct ResourcesBackend;
+
+impl Backend for ResourcesBackend {
+    fn name(&self) -> &'static str {
+        "resources"
+    }
+
+    /// OK to run this analysis on any Calyx program
+    fn validate(_ctx: &ir::Context) -> CalyxResult<()> {
+        Ok(())
+    }
+
+    /// Don't need to take care of this for this pass
+    fn link_externs(
+        _ctx: &ir::Context,
+        _file: &mut OutputFile,
+    ) -> CalyxResult<()> {
+        Ok(())
+    }
+
+    fn emit(ctx: &ir::Context, file: &mut OutputFile) -> CalyxResult<()> {
+        let model = CostModel::load(ctx.bc.resource_model.as_deref())?;
+        let report = Report::new(ctx, &model)?;
+        serde_json::to_writer_pretty(file.get_write(), &report)?;
+        Ok(())
+    }
+}
+
+/// The resource estimate for an entire program.
+#[derive(Serialize)]
+struct Report {
+    /// Name of the cost model used for the estimate.
+    model: String,
+    /// Resources used by the program, excluding `@external` memories.
+    total: Resources,
+    /// The longest combinational path in any component.
+    critical_path: Option<ComponentPath>,
+    /// Estimates for each component used by the program.
+    components: Vec<ComponentReport>,
+    /// Number of instances of each primitive in the program.
+    primitives: Vec<PrimitiveReport>,
+    /// Primitives without an entry in the cost model.
+    unknown_primitives: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct ComponentPath {
+    component: String,
+    #[serde(flatten)]
+    path: CriticalPath,
+}
+
+#[derive(Serialize)]
+struct ComponentReport {
+    name: String,
+    /// Number of times the component is instantiated by the program.
+    instances: u64,
+    /// Resources used by the primitives of a single instance.
+    primitives: Resources,
+    /// Resources used by the guards and multiplexers of a single instance.
+    logic: Resources,
+    critical_path: Option<CriticalPath>,
+}
+
+/// A primitive with its parameters and whether it is `@external`.
+type PrimitiveKey = (ir::Id, Vec<(ir::Id, u64)>, bool);
+
+#[derive(Serialize)]
+struct PrimitiveReport {
+    name: String,
+    parameters: BTreeMap<String, u64>,
+    external: bool,
+    count: u64,
+    /// Resources used by a single instance.
+    resources: Resources,
+}
+
+impl Report {
+    fn new(ctx: &ir::Context, model: &CostModel) -> CalyxResult<Self> {
+        let instances = count_instances(ctx);
+        let mut total = Resources::default();
+        let mut components = vec![];
+        let mut critical_path: Option<ComponentPath> = None;
+        let mut primitives: HashMap<PrimitiveKey, _> = HashMap::new();
+        let mut unknown = vec![];
+
+        for comp in &ctx.components {
+            let Some(&count) = instances.get(&comp.name) else {
+                continue;
+            };
+            let analysis = ComponentAnalysis::new(comp, model)?;
+            total += (analysis.primitives + analysis.logic) * count as f64;
+
+            for prim in &analysis.cells {
+                let key = (prim.name, prim.params.to_vec(), prim.external);
+                let (num, _) =
+                    primitives.entry(key).or_insert((0, prim.resources));
+                *num += count;
+                if prim.resources.is_none() && !unknown.contains(&prim.name) {
+                    log::warn!(
+                        "Cost model `{}' has no entry for primitive `{}'",
+                        model.name,
+                        prim.name
+                    );
+                    unknown.push(prim.name);
+                }
+            }
+
+            if let Some(path) = &analysis.critical_path {
+                let longer = match &critical_path {
+                    None => true,
+                    Some(cp) => cp.path.delay < path.delay,
+                };
+                if longer {
+                    critical_path = Some(ComponentPath {
+                        component: comp.name.to_string(),
+                        path: path.clone(),
+                    });
+                }
+            }
+
+            components.push(ComponentReport {
+                name: comp.name.to_string(),
+                instances: count,
+                primitives: analysis.primitives.rounded(),
+                logic: analysis.logic.rounded(),
+                critical_path: analysis.critical_path,
+            });
+        }
+
+        let mut primitives = primitives
+            .into_iter()
+            .map(|((name, params, external), (count, resources))| {
+                PrimitiveReport {
+                    name: name.to_string(),
+                    parameters: params
+                        .into_iter()
+                        .map(|(p, v)| (p.to_string(), v))
+                        .collect(),
+                    external,
+                    count,
+                    resources: resources.unwrap_or_default().rounded(),
+                }
+            })
+            .collect::<Vec<_>>();
+        primitives.sort_by(|a, b| {
+            (&a.name, &a.parameters, a.external).cmp(&(
+                &b.name,
+                &b.parameters,
+                b.external,
+            ))
+        });
+        components.sort_by(|a, b| a.name.cmp(&b.name));
+        let mut unknown_primitives = unknown
+            .into_iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>();
+        unknown_primitives.sort();
+
+        Ok(Report {
+            model: model.name.clone(),
+            total: total.rounded(),
+            critical_path,
+            components,
+            primitives,
+            unknown_primitives,
+        })
+    }
+}
+
+/// Counts the number of times each component is instantiated in the program
+/// with entrypoint `ctx.entrypoint`.
+fn count_instances(ctx: &ir::Context) -> HashMap<ir::Id, u64> {
+    let mut instances = HashMap::new();
+    let mut worklist = vec![(ctx.entrypoint, 1)];
+    while let Some((name, count)) = worklist.pop() {
+        *instances.entry(name).or_insert(0) += count;
+        let comp = ctx.components.iter().find(|c| c.name == name).unwrap();
+        for cell in comp.cells.iter() {
+            if let ir::CellType::Component { name } = &cell.borrow().prototype {
+                worklist.push((*name, count));
+            }
+        }
+    }
+    instances
+}
@@ -0,0 +1,259 @@
+//! Maps the signals generated by the Verilog backend back to the Calyx
+//! program they were compiled from.
+//!
+//! Assignments keep the position of the wire they were parsed from through the
+//! compilation pipeline. Since groups are compiled away before the backend
+//! runs, [record_source_groups] tags every assignment with its group before
+//! the passes run.
+use calyx_ir as ir;
+use calyx_utils::WithPos;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+
+/// A span in a Calyx source file. Lines and columns start at 1, and columns
+/// count characters.
+#[derive(Serialize, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Location {
+    file: String,
+    line: usize,
+    column: usize,
+    end_line: usize,
+    end_column: usize,
+    /// Byte offset of the start of the span
+    start: usize,
+    /// Byte offset of the end of the span
+    end: usize,
+    /// The group whose body contains the location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<String>,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)?;
+        if let Some(group) = &self.group {
+            write!(f, " {}", group)?;
+        }
+        Ok(())
+    }
+}
+
+/// Computes the [Location] of IR nodes.
+pub(crate) struct Locator<'a> {
+    /// Offsets of the start of each line of the source files seen so far
+    line_starts: HashMap<String, Vec<usize>>,
+    /// Names of the groups assignments were defined in
+    groups: &'a [ir::Id],
+}
+
+impl<'a> Locator<'a> {
+    pub fn new(ctx: &'a ir::Context) -> Self {
+        Locator {
+            line_starts: HashMap::new(),
+            groups: &ctx.source_groups,
+        }
+    }
+
+    /// The location of the node with the attributes `attrs` or `None` if its
+    /// position is unknown.
+    pub fn locate(&mut self, attrs: &ir::Attributes) -> Option<Location> {
+        let pos = attrs.copy_span().into_option()?;
+        let (file, start, end) = pos.get_location();
+        let source = pos.get_source();
+        let line_starts =
+            self.line_starts.entry(file.to_string()).or_insert_with(|| {
+                std::iter::once(0)
+                    .chain(source.match_indices('\n').map(|(idx, _)| idx + 1))
+                    .collect()
+            });
+        let line_col = |offset: usize| {
+            let offset = offset.min(source.len());
+            let line = line_starts.partition_point(|&idx| idx <= offset);
+            let column =
+                source[line_starts[line - 1]..offset].chars().count() + 1;
+            (line, column)
+        };
+        let (line, column) = line_col(start);
+        let (end_line, end_column) = line_col(end);
+        let group = attrs
+            .get(ir::InternalAttr::GROUP)
+            .and_then(|idx| self.groups.get(idx as usize))
+            .map(|group| group.to_string());
+        Some(Location {
+            file: file.to_string(),
+            line,
+            column,
+            end_line,
+            end_column,
+            start,
+            end,
+            group,
+        })
+    }
+}
+
+/// Tags every assignment in a group of `ctx` with the
+/// [ir::InternalAttr::GROUP] attribute so that its group is still known after
+/// the group is compiled away.
+pub fn record_source_groups(ctx: &mut ir::Context) {
+    let groups = &mut ctx.source_groups;
+    for comp in &ctx.components {
+        for group in comp.get_groups().iter() {
+            let mut group = group.borrow_mut();
+            let name = group.name();
+            let attrs = group.assignments.iter_mut().map(|a| &mut a.attributes);
+            tag_group(groups, name, attrs);
+        }
+        for group in comp.get_static_groups().iter() {
+            let mut group = group.borrow_mut();
+            let name = group.name();
+            let attrs = group.assignments.iter_mut().map(|a| &mut a.attributes);
+            tag_group(groups, name, attrs);
+        }
+        for group in comp.comb_groups.iter() {
+            let mut group = group.borrow_mut();
+            let name = group.name();
+            let attrs = group.assignments.iter_mut().map(|a| &mut a.attributes);
+            tag_group(groups, name, attrs);
+        }
+    }
+}
+
+fn tag_group<'a>(
+    groups: &mut Vec<ir::Id>,
+    name: ir::Id,
+    attrs: impl Iterator<Item = &'a mut ir::Attributes>,
+) {
+    let idx = groups.len() as u64;
+    groups.push(name);
+    for attrs in attrs {
+        if !attrs.has(ir::InternalAttr::GROUP) {
+            attrs.insert(ir::InternalAttr::GROUP, idx);
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+enum SignalKind {
+    /// Instance of a cell
+    Cell,
+    /// Register storing the state of an FSM generated by the compiler
+    Fsm,
+    /// Wire computing the guard of assignments
+    Guard,
+}
+
+/// A signal in the generated Verilog.
+#[derive(Serialize)]
+struct Signal {
+    kind: SignalKind,
+    /// Name of the primitive or component instantiated by a cell
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    ty: Option<String>,
+    /// Where the cell is defined
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<Location>,
+    /// Groups whose assignments are guarded by the signal
+    #[serde(skip_serializing_if = "BTreeSet::is_empty")]
+    groups: BTreeSet<String>,
+    /// Locations of the assignments that use a guard wire
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    assignments: Vec<Location>,
+}
+
+/// Signals generated for a single component.
+#[derive(Serialize)]
+pub(crate) struct ComponentMap {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<Location>,
+    signals: BTreeMap<String, Signal>,
+}
+
+impl ComponentMap {
+    /// Records the cell instances and FSM registers of `comp`.
+    pub fn new(comp: &ir::Component, locator: &mut Locator) -> Self {
+        let mut signals = BTreeMap::new();
+        for cell_ref in comp.cells.iter() {
+            let cell = cell_ref.borrow();
+            let Some(ty) = cell.type_name() else {
+                continue;
+            };
+            // FSM registers are generated by the passes that compile control
+            let kind = if cell.attributes.has(ir::BoolAttr::Generated)
+                && cell.is_primitive(Some("std_reg"))
+                && cell.name().as_ref().starts_with("fsm")
+            {
+                SignalKind::Fsm
+            } else {
+                SignalKind::Cell
+            };
+            signals.insert(
+                cell.name().to_string(),
+                Signal {
+                    kind,
+                    ty: Some(ty.to_string()),
+                    location: locator.locate(&cell.attributes),
+                    groups: BTreeSet::new(),
+                    assignments: Vec::new(),
+                },
+            );
+        }
+
+        // An FSM register enables the groups whose assignments read it.
+        for asgn in &comp.continuous_assignments {
+            let Some(group) =
+                locator.locate(&asgn.attributes).and_then(|loc| loc.group)
+            else {
+                continue;
+            };
+            for port in asgn.guard.all_ports() {
+                let cell = port.borrow().cell_parent();
+                let name = cell.borrow().name();
+                if let Some(signal) = signals.get_mut(name.as_ref()) {
+                    if signal.kind == SignalKind::Fsm {
+                        signal.groups.insert(group.clone());
+                    }
+                }
+            }
+        }
+
+        ComponentMap {
+            name: comp.name.to_string(),
+            location: locator.locate(&comp.attributes),
+            signals,
+        }
+    }
+
+    /// Records that the guard wire `name` guards an assignment at `location`.
+    pub fn add_guard(&mut self, name: String, location: Location) {
+        let signal = self.signals.entry(name).or_insert_with(|| Signal {
+            kind: SignalKind::Guard,
+            ty: None,
+            location: None,
+            groups: BTreeSet::new(),
+            assignments: Vec::new(),
+        });
+        if let Some(group) = &location.group {
+            signal.groups.insert(group.clone());
+        }
+        if !signal.assignments.contains(&location) {
+            signal.assignments.push(location);
+        }
+    }
+}
+
+/// Sidecar file mapping the signals generated by the Verilog backend to the
+/// Calyx source.
+#[derive(Serialize, Default)]
+pub(crate) struct SourceMap {
+    components: Vec<ComponentMap>,
+}
+
+impl SourceMap {
+    pub fn add(&mut self, component: ComponentMap) {
+        self.components.push(component);
+    }
+}
@@ -3,6 +3,7 @@
 //! Transforms an [`ir::Context`](crate::ir::Context) into a formatted string that represents a
 //! valid SystemVerilog program.
 
+use crate::source_map::{ComponentMap, Locator, SourceMap};
 use crate::traits::Backend;
 use calyx_ir::{self as ir, Control, FlatGuard, Group, Guard, GuardRef, RRC};
 use calyx_utils::{CalyxResult, Error, OutputFile};
@@ -132,14 +133,17 @@ impl Backend for VerilogBackend {
 
     fn emit(ctx: &ir::Context, file: &mut OutputFile) -> CalyxResult<()> {
         let out = &mut file.get_write();
+        let mut locator = Locator::new(ctx);
+        let mut source_map =
+            ctx.bc.source_map.as_ref().map(|_| SourceMap::default());
         let comps = ctx.components.iter().try_for_each(|comp| {
             // Time the generation of the component.
             let time = Instant::now();
             let out = emit_component(
                 comp,
-                ctx.bc.synthesis_mode,
-                ctx.bc.enable_verification,
-                ctx.bc.flat_assign,
+                &ctx.bc,
+                &mut locator,
+                source_map.as_mut(),
                 out,
             );
             log::info!("Generated `{}` in {:?}", comp.name, time.elapsed());
@@ -151,7 +155,20 @@ impl Backend for VerilogBackend {
                 "File not found: {}",
                 file.as_path_string()
             ))
-        })
+        })?;
+
+        if let (Some(path), Some(source_map)) = (&ctx.bc.source_map, source_map)
+        {
+            let sidecar = File::create(path).map_err(|err| {
+                Error::write_error(format!(
+                    "Failed to create {}: {}",
+                    path.display(),
+                    err
+                ))
+            })?;
+            serde_json::to_writer_pretty(sidecar, &source_map)?;
+        }
+        Ok(())
     }
 }
 
@@ -225,11 +242,18 @@ fn emit_prim_inline<F: io::Write>(
 
 fn emit_component<F: io::Write>(
     comp: &ir::Component,
-    synthesis_mode: bool,
-    enable_verification: bool,
-    flat_assign: bool,
+    bc: &ir::BackendConf,
+    locator: &mut Locator,
+    source_map: Option<&mut SourceMap>,
     f: &mut F,
 ) -> io::Result<()> {
+    let ir::BackendConf {
+        synthesis_mode,
+        enable_verification,
+        flat_assign,
+        source_comments,
+        ..
+    } = *bc;
     writeln!(f, "module {}(", comp.name)?;
 
     let sig = comp.signature.borrow();
@@ -312,6 +336,26 @@ fn emit_component<F: io::Write>(
         })
         .collect();
 
+    if let Some(source_map) = source_map {
+        let mut component_map = ComponentMap::new(comp, locator);
+        if flat_assign {
+            for (dst, flat_asgns) in &grouped_asgns {
+                let asgns = &map[&dst.borrow().canonical()].1;
+                for (asgn, (_, guard)) in asgns.iter().zip(flat_asgns) {
+                    if let (false, Some(location)) =
+                        (guard.is_true(), locator.locate(&asgn.attributes))
+                    {
+                        component_map.add_guard(
+                            VerilogGuardRef(*guard).to_string(),
+                            location,
+                        );
+                    }
+                }
+            }
+        }
+        source_map.add(component_map);
+    }
+
     if flat_assign {
         // Emit "flattened" assignments as ANF statements.
         // Emit Verilog for the flattened guards.
@@ -323,6 +367,13 @@ fn emit_component<F: io::Write>(
 
         // Emit assignments using these guards.
         for (dst, asgns) in &grouped_asgns {
+            if source_comments {
+                emit_source_comments(
+                    &map[&dst.borrow().canonical()].1,
+                    locator,
+                    f,
+                )?;
+            }
             emit_assignment_flat(dst, asgns, f)?;
 
             if enable_verification {
@@ -341,6 +392,13 @@ fn emit_component<F: io::Write>(
 
         // Emit nested assignments.
         for (dst, asgns) in grouped_asgns {
+            if source_comments {
+                emit_source_comments(
+                    &map[&dst.borrow().canonical()].1,
+                    locator,
+                    f,
+                )?;
+            }
             let stmt =
                 v::Stmt::new_parallel(emit_assignment(dst, &asgns, &pool));
             writeln!(f, "{stmt}")?;
@@ -364,6 +422,20 @@ fn emit_component<F: io::Write>(
     Ok(())
 }
 
+/// Emits a `// calyx: <file>:<line> <group>` comment for each source location
+/// of `assignments`.
+fn emit_source_comments<F: io::Write>(
+    assignments: &[&ir::Assignment<Nothing>],
+    locator: &mut Locator,
+    f: &mut F,
+) -> io::Result<()> {
+    assignments
+        .iter()
+        .filter_map(|asgn| locator.locate(&asgn.attributes))
+        .unique()
+        .try_for_each(|location| writeln!(f, "// calyx: {location}"))
+}
+
 fn wire_decls(cell: &ir::Cell) -> Vec<(String, u64, ir::Direction)> {
     cell.ports
         .iter()
@@ -1,4 +1,3 @@
-use super::axi::AxiChannel;
 use std::{collections::BTreeMap, ops::Range};
 use vast::v05::ast as v;
 
@@ -10,8 +9,8 @@ pub(crate) struct Flags {
     /// The string holds the name of the internal register.
     read: Option<String>,
     /// Clear the value of the internal register when the given
-    /// channel reads.
-    clear_on_read: Option<(AxiChannel, String)>,
+    /// handshake reads this address.
+    clear_on_read: Option<(v::Expr, String)>,
     /// Clear the internal register when there is a successful
     /// handshake on this channel.
     // XXX(nathanielnrn): might not be a good name? used for things that aren't just
@@ -37,13 +36,13 @@ impl Flags {
     /// Builder style function for setting the `clear_on_read` flag.
     pub(crate) fn clear_on_read<S>(
         mut self,
-        axi_channel: AxiChannel,
+        handshake: v::Expr,
         int_addr: S,
     ) -> Self
     where
         S: ToString,
     {
-        self.clear_on_read = Some((axi_channel, int_addr.to_string()));
+        self.clear_on_read = Some((handshake, int_addr.to_string()));
         self
     }
 
@@ -316,10 +315,11 @@ impl AddressSpace {
                     (Some("ARESET".into()), 0.into()),
                     (Some(port.as_str().into()), 1.into()),
                 ];
-                if let Some((channel, addr_reg)) = &meaning.flags.clear_on_read
+                if let Some((handshake, addr_reg)) =
+                    &meaning.flags.clear_on_read
                 {
                     let cond = v::Expr::new_logical_and(
-                        channel.handshake(),
+                        handshake.clone(),
                         v::Expr::new_eq(addr_reg.as_str(), addr.address as i32),
                     );
                     branches.push((Some(cond), 0.into()));
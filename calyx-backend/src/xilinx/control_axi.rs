@@ -42,7 +42,7 @@ fn axi_address_space(
                     0..1,
                     Flags::default()
                         .read("ap_done")
-                        .clear_on_read(axi.read_data.clone(), "raddr"),
+                        .clear_on_read(axi.read_data.handshake(), "raddr"),
                 ),
                 (
                     2..3,
@@ -1,6 +1,7 @@
 use super::{
     axi::{AxiChannel, AxiInterface, ChannelDirection},
     fsm,
+    memory_config::MemoryConfig,
 };
 use calyx_utils as utils;
 use std::rc::Rc;
@@ -8,7 +9,7 @@ use vast::v05::ast as v;
 
 /// Represents the interface for a AXI master that controls
 /// a memory with an AXI slave interface.
-pub trait MemoryInterface {
+pub(crate) trait MemoryInterface {
     fn memory_channels(
         address_width: u64,
         data_width: u64,
@@ -16,11 +17,8 @@ pub trait MemoryInterface {
     ) -> Self;
     fn memory_module(
         name: &str,
-        bus_data_width: u64,
+        memory: &MemoryConfig,
         bus_addr_width: u64,
-        data_width: u64,
-        memory_size: u64,
-        addr_width: u64,
     ) -> v::Module;
 }
 
@@ -94,19 +92,24 @@ impl MemoryInterface for AxiInterface {
 
     fn memory_module(
         name: &str,
-        bus_data_width: u64,
+        memory: &MemoryConfig,
         bus_addr_width: u64,
-        data_width: u64,
-        memory_size: u64,
-        addr_width: u64,
     ) -> v::Module {
+        if !memory.configured {
+            return single_beat_memory_module(name, memory, bus_addr_width);
+        }
+        let data_width = memory.width;
+        let bus_data_width = memory.bus_data_width;
+        let memory_size = memory.size;
+        let addr_width = memory.idx_size;
         let mut module = v::Module::new(name);
 
         module.add_input("ACLK", 1);
         module.add_input("ARESET", 1);
 
         // add axi interface ports
-        let axi4 = AxiInterface::memory_channels(bus_addr_width, 512, "");
+        let axi4 =
+            AxiInterface::memory_channels(bus_addr_width, bus_data_width, "");
         axi4.add_ports_to(&mut module);
 
         module.add_input("BASE_ADDRESS", bus_addr_width);
@@ -128,43 +131,48 @@ impl MemoryInterface for AxiInterface {
             "copy_done".into(),
             v::Expr::new_eq("copy_addr_offset", memory_size as i32),
         ));
+        // the memory has been sent once every write burst is acknowledged
         module.add_decl(v::Decl::new_wire("send_done", 1));
         module.add_stmt(v::Parallel::Assign(
             "send_done".into(),
-            v::Expr::new_eq("send_addr_offset", memory_size as i32),
+            v::Expr::new_eq("write_responses", memory.bursts() as i32),
         ));
 
         // module mode fsm
         let mode_fsm = module_mode_fsm(&mut module);
-        // module.add_stmt(v::Parallel::Assign(
-        //     "SEND_TO_HOST_DONE".into(),
-        //     v::Expr::new_mux(mode_fsm.state_is("send"), "send_done", 0),
-        // ));
 
-        // count the number of read transactions we've received
-        module.add_decl(v::Decl::new_reg(
-            "read_txn_count",
-            utils::bits_needed_for(bus_data_width / data_width),
-        ));
-        module.add_stmt(super::utils::cond_non_blk_assign(
-            "ACLK",
-            "read_txn_count",
-            vec![
-                (Some("ARESET".into()), 0.into()),
-                (
-                    Some(axi4.read_data.handshake()),
-                    v::Expr::new_add("read_txn_count", 1),
-                ),
-            ],
-        ));
+        // Each beat transfers a single element. Elements narrower than the bus
+        // use narrow transfers which place the element in the byte lanes
+        // selected by its address.
+        let lanes = bus_data_width / data_width;
+        // add 1 so offset can count up to memory size inclusively
+        let offset_width = utils::bits_needed_for(memory_size) + 1;
+        let lane = |offset: &str| -> Option<v::Expr> {
+            let lane_bits = lanes.trailing_zeros() as u64;
+            if lanes == 1 {
+                None
+            } else if lane_bits >= offset_width {
+                Some(offset.into())
+            } else {
+                Some(v::Expr::new_slice(offset, (lane_bits - 1) as i32, 0))
+            }
+        };
 
         // bram reading / writing logic
+        let copy_data = match lane("copy_addr_offset") {
+            Some(lane) => v::Expr::new_index_slice(
+                &axi4.read_data.get("DATA"),
+                v::Expr::new_mul(lane, data_width as i32),
+                data_width as u32,
+            ),
+            None => axi4.read_data.get("DATA").into(),
+        };
         bram_logic(
             name,
             &axi4,
             &mut module,
             &mode_fsm,
-            "read_txn_count".into(),
+            copy_data,
             data_width,
             addr_width,
         );
@@ -173,16 +181,23 @@ impl MemoryInterface for AxiInterface {
             "bram_read_data".into(),
         ));
 
-        // add 1 so offset can count up to memory size inclusively
-        let offset_width = utils::bits_needed_for(memory_size) + 1;
-
-        // synchronise channels
-        let read_controller = axi4
-            .read_address
-            .then(&axi4.read_data)
-            .prefix("r")
-            .trigger(mode_fsm.next_state_is("copy"));
-        read_controller.emit(&mut module);
+        // read bursts copy elements from the host into the bram
+        address_logic(
+            &mut module,
+            &axi4.read_address,
+            "read",
+            mode_fsm.state_is("copy"),
+            v::Expr::new_logical_and(
+                axi4.read_data.handshake(),
+                axi4.read_data.get("LAST"),
+            ),
+            memory,
+            bus_addr_width,
+        );
+        module.add_stmt(v::Parallel::Assign(
+            axi4.read_data.ready().into(),
+            mode_fsm.state_is("copy"),
+        ));
 
         // increment copy address offset
         module.add_decl(v::Decl::new_reg("copy_addr_offset", offset_width));
@@ -193,54 +208,25 @@ impl MemoryInterface for AxiInterface {
             axi4.read_data.handshake(),
         );
 
-        // addresses are byte addressed which means addresses are computed as
-        // base + (offset << shift_by)
-        // TODO(nathanielnrn): Fix the burst size and shifting values based on
-        // pynq(?) input? Or memory size? unclear.
-        let shift_by = 2;
-        let burst_size = v::Expr::new_ulit_dec(
-            3,
-            &utils::bits_needed_for(data_width / 8).to_string(),
+        // write bursts send elements from the bram to the host
+        address_logic(
+            &mut module,
+            &axi4.write_address,
+            "write",
+            mode_fsm.state_is("send"),
+            axi4.write_response.handshake(),
+            memory,
+            bus_addr_width,
         );
-        //AxBURST corresponds to type of burst as follows:
-        // 0b00: Fixed
-        // 0b01 (default): Incr
-        // 0b10: Wrap
-        // 0b11: Reserved
-        let burst_type = v::Expr::new_ulit_bin(2, "01");
-
-        module.add_stmt(axi4.read_address.assign("ID", 0));
-
-        //add reg to store value of shift to circumvent order of operations
-        //issue on bit shift
-        let copy_shift = "copy_shift";
-        module.add_decl(v::Decl::new_wire(copy_shift, bus_addr_width));
-        let mut concat = v::ExprConcat::default();
-        concat.add_expr("copy_addr_offset");
-        concat.add_expr(v::Expr::new_repeat(
-            bus_addr_width - offset_width,
-            v::Expr::new_ulit_bin(1, "0"),
-        ));
+
+        // only send data for bursts whose address has been issued
         module.add_stmt(v::Parallel::Assign(
-            copy_shift.into(),
-            v::Expr::new_shift_left(concat, shift_by),
+            axi4.write_data.ready().into(),
+            v::Expr::new_logical_and(
+                mode_fsm.state_is("send"),
+                v::Expr::new_lt("send_addr_offset", "write_issued"),
+            ),
         ));
-        module.add_stmt(
-            axi4.read_address
-                .assign("ADDR", v::Expr::new_add("BASE_ADDRESS", copy_shift)),
-        );
-        // Currently we do not utilize burst capabilities, therefore AxLEN is 0
-        module.add_stmt(axi4.read_address.assign("LEN", 0));
-        module.add_stmt(axi4.read_address.assign("SIZE", burst_size.clone()));
-        module.add_stmt(axi4.read_address.assign("BURST", burst_type.clone()));
-
-        let write_controller = axi4
-            .write_address
-            .then(&axi4.write_data)
-            .then(&axi4.write_response)
-            .prefix("w")
-            .trigger(mode_fsm.next_state_is("send"));
-        write_controller.emit(&mut module);
 
         // increment send address offset
         module.add_decl(v::Decl::new_reg("send_addr_offset", offset_width));
@@ -248,68 +234,455 @@ impl MemoryInterface for AxiInterface {
             &mut module,
             mode_fsm.state_is("send"),
             "send_addr_offset",
-            axi4.write_response.handshake(),
-        );
-
-        module.add_stmt(axi4.write_address.assign("ID", 0));
-        // assign shift to a wire to circumvent `vast` order of operations issues
-        //we shift to convert offset to byte length
-        let send_shift = "send_shift";
-        module.add_decl(v::Decl::new_wire(send_shift, bus_addr_width));
-        let mut concat = v::ExprConcat::default();
-        concat.add_expr("send_addr_offset");
-        concat.add_expr(v::Expr::new_repeat(
-            bus_addr_width - offset_width,
-            v::Expr::new_ulit_bin(1, "0"),
-        ));
-        module.add_stmt(v::Parallel::Assign(
-            send_shift.into(),
-            v::Expr::new_shift_left(concat, shift_by),
-        ));
-
-        module.add_stmt(
-            axi4.write_address
-                .assign("ADDR", v::Expr::new_add("BASE_ADDRESS", send_shift)),
+            axi4.write_data.handshake(),
         );
-        // Currently we do not utilize burst capabilities, therefore AxLEN is 0
-        module.add_stmt(axi4.write_address.assign("LEN", 0));
-        module.add_stmt(axi4.write_address.assign("SIZE", burst_size));
-        module.add_stmt(axi4.write_address.assign("BURST", burst_type));
 
         // write data channel
         module.add_stmt(axi4.write_data.assign("ID", 0));
-        let mut concat = v::ExprConcat::default();
-        concat.add_expr("bram_read_data");
-        concat.add_expr(v::Expr::new_repeat(
-            (bus_data_width / data_width) - 1,
-            v::Expr::new_ulit_bin(32, "0"),
+        match lane("send_addr_offset") {
+            Some(lane) => {
+                let mut concat = v::ExprConcat::default();
+                concat.add_expr("bram_read_data");
+                concat.add_expr(v::Expr::new_repeat(
+                    bus_data_width - data_width,
+                    v::Expr::new_ulit_bin(1, "0"),
+                ));
+                module.add_stmt(axi4.write_data.assign(
+                    "DATA",
+                    v::Expr::new_shift_left(
+                        concat,
+                        v::Expr::new_mul(lane.clone(), data_width as i32),
+                    ),
+                ));
+                let mut concat = v::ExprConcat::default();
+                concat.add_expr(v::Expr::new_repeat(
+                    data_width / 8,
+                    v::Expr::new_ulit_bin(1, "1"),
+                ));
+                concat.add_expr(v::Expr::new_repeat(
+                    (bus_data_width - data_width) / 8,
+                    v::Expr::new_ulit_bin(1, "0"),
+                ));
+                //divided by 8 because WSTRB bits refer to entire bytes
+                module.add_stmt(axi4.write_data.assign(
+                    "STRB",
+                    v::Expr::new_shift_left(
+                        concat,
+                        v::Expr::new_mul(lane, (data_width / 8) as i32),
+                    ),
+                ));
+            }
+            None => {
+                module
+                    .add_stmt(axi4.write_data.assign("DATA", "bram_read_data"));
+                module.add_stmt(axi4.write_data.assign(
+                    "STRB",
+                    v::Expr::new_repeat(
+                        bus_data_width / 8,
+                        v::Expr::new_ulit_bin(1, "1"),
+                    ),
+                ));
+            }
+        }
+        // the last beat of a burst is either the last beat of a full burst or
+        // the last element of the memory
+        let burst_len = memory.burst_len;
+        let last_element =
+            v::Expr::new_eq("send_addr_offset", (memory_size - 1) as i32);
+        let last: v::Expr = if burst_len == 1 {
+            1.into()
+        } else if burst_len > memory_size {
+            // the memory is transferred in a single burst
+            last_element
+        } else {
+            let end_of_burst = v::Expr::new_eq(
+                v::Expr::new_slice(
+                    "send_addr_offset",
+                    (burst_len.trailing_zeros() - 1) as i32,
+                    0,
+                ),
+                (burst_len - 1) as i32,
+            );
+            if !memory.partial_burst() {
+                end_of_burst
+            } else {
+                v::Expr::new_logical_or(end_of_burst, last_element)
+            }
+        };
+        module.add_stmt(axi4.write_data.assign("LAST", last));
+
+        // count the write responses we've received
+        module.add_stmt(v::Parallel::Assign(
+            axi4.write_response.ready().into(),
+            mode_fsm.state_is("send"),
         ));
-        // used for alignment issues where host reads from WDATA at offset if
-        // AWADDR is not alligned with data-bus-width
-        let wdata = v::Expr::new_shift_left(
-            concat,
-            v::Expr::new_mul("send_addr_offset", data_width as i32),
-        );
-        module.add_stmt(axi4.write_data.assign("DATA", wdata));
-        let mut concat = v::ExprConcat::default();
-        concat.add_expr(v::Expr::new_ulit_hex(4, "F"));
-        concat.add_expr(v::Expr::new_repeat(
-            (bus_data_width / (8 * 4)) - 1,
-            v::Expr::new_ulit_hex(4, "0"),
+        module.add_decl(v::Decl::new_reg(
+            "write_responses",
+            utils::bits_needed_for(memory.bursts()) + 1,
         ));
-        let wstrb = v::Expr::new_shift_left(
-            concat,
-            //divided by 8 because WSTRB bits refer to entire bytes
-            v::Expr::new_mul("send_addr_offset", (data_width / 8) as i32),
+        incr_addr(
+            &mut module,
+            mode_fsm.state_is("send"),
+            "write_responses",
+            axi4.write_response.handshake(),
         );
-        module.add_stmt(axi4.write_data.assign("STRB", wstrb));
-        module.add_stmt(axi4.write_data.assign("LAST", 1));
 
         module
     }
 }
 
-fn module_mode_fsm(module: &mut v::Module) -> fsm::LinearFsm {
+/// Generates the original memory controller used for memories without any
+/// `@axi_*` attributes. It issues a single-beat transaction per element and
+/// waits for each one to complete before starting the next.
+fn single_beat_memory_module(
+    name: &str,
+    memory: &MemoryConfig,
+    bus_addr_width: u64,
+) -> v::Module {
+    let data_width = memory.width;
+    let bus_data_width = memory.bus_data_width;
+    let memory_size = memory.size;
+    let addr_width = memory.idx_size;
+    let mut module = v::Module::new(name);
+
+    module.add_input("ACLK", 1);
+    module.add_input("ARESET", 1);
+
+    // add axi interface ports
+    let axi4 =
+        AxiInterface::memory_channels(bus_addr_width, bus_data_width, "");
+    axi4.add_ports_to(&mut module);
+
+    module.add_input("BASE_ADDRESS", bus_addr_width);
+    module.add_input("COPY_FROM_HOST", 1);
+    module.add_output("COPY_FROM_HOST_DONE", 1);
+    module.add_input("SEND_TO_HOST", 1);
+    module.add_output("SEND_TO_HOST_DONE", 1);
+
+    // BRAM interface
+    module.add_input("WRITE_DATA", data_width);
+    module.add_output("READ_DATA", data_width);
+    module.add_input("ADDR", addr_width);
+    module.add_input("WE", 1);
+    module.add_output("DONE", 1);
+
+    // internal signals
+    module.add_decl(v::Decl::new_wire("copy_done", 1));
+    module.add_stmt(v::Parallel::Assign(
+        "copy_done".into(),
+        v::Expr::new_eq("copy_addr_offset", memory_size as i32),
+    ));
+    module.add_decl(v::Decl::new_wire("send_done", 1));
+    module.add_stmt(v::Parallel::Assign(
+        "send_done".into(),
+        v::Expr::new_eq("send_addr_offset", memory_size as i32),
+    ));
+
+    // module mode fsm
+    let mode_fsm = module_mode_fsm(&mut module);
+
+    // count the number of read transactions we've received
+    module.add_decl(v::Decl::new_reg(
+        "read_txn_count",
+        utils::bits_needed_for(bus_data_width / data_width),
+    ));
+    module.add_stmt(super::utils::cond_non_blk_assign(
+        "ACLK",
+        "read_txn_count",
+        vec![
+            (Some("ARESET".into()), 0.into()),
+            (
+                Some(axi4.read_data.handshake()),
+                v::Expr::new_add("read_txn_count", 1),
+            ),
+        ],
+    ));
+
+    // bram reading / writing logic
+    let copy_data = v::Expr::new_index_slice(
+        &axi4.read_data.get("DATA"),
+        v::Expr::new_mul("read_txn_count", 32),
+        data_width as u32, // bram data width
+    );
+    bram_logic(
+        name,
+        &axi4,
+        &mut module,
+        &mode_fsm,
+        copy_data,
+        data_width,
+        addr_width,
+    );
+    module.add_stmt(v::Parallel::Assign(
+        "READ_DATA".into(),
+        "bram_read_data".into(),
+    ));
+
+    // add 1 so offset can count up to memory size inclusively
+    let offset_width = utils::bits_needed_for(memory_size) + 1;
+
+    // synchronise channels
+    let read_controller = axi4
+        .read_address
+        .then(&axi4.read_data)
+        .prefix("r")
+        .trigger(mode_fsm.next_state_is("copy"));
+    read_controller.emit(&mut module);
+
+    // increment copy address offset
+    module.add_decl(v::Decl::new_reg("copy_addr_offset", offset_width));
+    incr_addr(
+        &mut module,
+        mode_fsm.state_is("copy"),
+        "copy_addr_offset",
+        axi4.read_data.handshake(),
+    );
+
+    // addresses are byte addressed which means addresses are computed as
+    // base + (offset << shift_by)
+    // TODO(nathanielnrn): Fix the burst size and shifting values based on
+    // pynq(?) input? Or memory size? unclear.
+    let shift_by = 2;
+    let burst_size = v::Expr::new_ulit_dec(
+        3,
+        &utils::bits_needed_for(data_width / 8).to_string(),
+    );
+    //AxBURST corresponds to type of burst as follows:
+    // 0b00: Fixed
+    // 0b01 (default): Incr
+    // 0b10: Wrap
+    // 0b11: Reserved
+    let burst_type = v::Expr::new_ulit_bin(2, "01");
+
+    module.add_stmt(axi4.read_address.assign("ID", 0));
+
+    //add reg to store value of shift to circumvent order of operations
+    //issue on bit shift
+    let copy_shift = "copy_shift";
+    module.add_decl(v::Decl::new_wire(copy_shift, bus_addr_width));
+    let mut concat = v::ExprConcat::default();
+    concat.add_expr("copy_addr_offset");
+    concat.add_expr(v::Expr::new_repeat(
+        bus_addr_width - offset_width,
+        v::Expr::new_ulit_bin(1, "0"),
+    ));
+    module.add_stmt(v::Parallel::Assign(
+        copy_shift.into(),
+        v::Expr::new_shift_left(concat, shift_by),
+    ));
+    module.add_stmt(
+        axi4.read_address
+            .assign("ADDR", v::Expr::new_add("BASE_ADDRESS", copy_shift)),
+    );
+    // Currently we do not utilize burst capabilities, therefore AxLEN is 0
+    module.add_stmt(axi4.read_address.assign("LEN", 0));
+    module.add_stmt(axi4.read_address.assign("SIZE", burst_size.clone()));
+    module.add_stmt(axi4.read_address.assign("BURST", burst_type.clone()));
+
+    let write_controller = axi4
+        .write_address
+        .then(&axi4.write_data)
+        .then(&axi4.write_response)
+        .prefix("w")
+        .trigger(mode_fsm.next_state_is("send"));
+    write_controller.emit(&mut module);
+
+    // increment send address offset
+    module.add_decl(v::Decl::new_reg("send_addr_offset", offset_width));
+    incr_addr(
+        &mut module,
+        mode_fsm.state_is("send"),
+        "send_addr_offset",
+        axi4.write_response.handshake(),
+    );
+
+    module.add_stmt(axi4.write_address.assign("ID", 0));
+    // assign shift to a wire to circumvent `vast` order of operations issues
+    //we shift to convert offset to byte length
+    let send_shift = "send_shift";
+    module.add_decl(v::Decl::new_wire(send_shift, bus_addr_width));
+    let mut concat = v::ExprConcat::default();
+    concat.add_expr("send_addr_offset");
+    concat.add_expr(v::Expr::new_repeat(
+        bus_addr_width - offset_width,
+        v::Expr::new_ulit_bin(1, "0"),
+    ));
+    module.add_stmt(v::Parallel::Assign(
+        send_shift.into(),
+        v::Expr::new_shift_left(concat, shift_by),
+    ));
+
+    module.add_stmt(
+        axi4.write_address
+            .assign("ADDR", v::Expr::new_add("BASE_ADDRESS", send_shift)),
+    );
+    // Currently we do not utilize burst capabilities, therefore AxLEN is 0
+    module.add_stmt(axi4.write_address.assign("LEN", 0));
+    module.add_stmt(axi4.write_address.assign("SIZE", burst_size));
+    module.add_stmt(axi4.write_address.assign("BURST", burst_type));
+
+    // write data channel
+    module.add_stmt(axi4.write_data.assign("ID", 0));
+    let mut concat = v::ExprConcat::default();
+    concat.add_expr("bram_read_data");
+    concat.add_expr(v::Expr::new_repeat(
+        (bus_data_width / data_width) - 1,
+        v::Expr::new_ulit_bin(32, "0"),
+    ));
+    // used for alignment issues where host reads from WDATA at offset if
+    // AWADDR is not alligned with data-bus-width
+    let wdata = v::Expr::new_shift_left(
+        concat,
+        v::Expr::new_mul("send_addr_offset", data_width as i32),
+    );
+    module.add_stmt(axi4.write_data.assign("DATA", wdata));
+    let mut concat = v::ExprConcat::default();
+    concat.add_expr(v::Expr::new_ulit_hex(4, "F"));
+    concat.add_expr(v::Expr::new_repeat(
+        (bus_data_width / (8 * 4)) - 1,
+        v::Expr::new_ulit_hex(4, "0"),
+    ));
+    let wstrb = v::Expr::new_shift_left(
+        concat,
+        //divided by 8 because WSTRB bits refer to entire bytes
+        v::Expr::new_mul("send_addr_offset", (data_width / 8) as i32),
+    );
+    module.add_stmt(axi4.write_data.assign("STRB", wstrb));
+    module.add_stmt(axi4.write_data.assign("LAST", 1));
+
+    module
+}
+
+/// Generates the logic that issues bursts on the address channel `channel`
+/// while `active` holds. The registers it defines are prefixed with `prefix`:
+/// `{prefix}_issued` is the index of the first element of the next burst and
+/// `{prefix}_outstanding` is the number of bursts that have been issued but
+/// not completed, which happens whenever `completed` holds.
+fn address_logic(
+    module: &mut v::Module,
+    channel: &AxiChannel,
+    prefix: &str,
+    active: v::Expr,
+    completed: v::Expr,
+    memory: &MemoryConfig,
+    bus_addr_width: u64,
+) {
+    let memory_size = memory.size;
+    let burst_len = memory.burst_len;
+    let issued = format!("{}_issued", prefix);
+    let outstanding = format!("{}_outstanding", prefix);
+    let issue = format!("{}_issue", prefix);
+    let complete = format!("{}_complete", prefix);
+
+    // add 1 so offset can count up to memory size inclusively
+    let offset_width = utils::bits_needed_for(memory_size) + 1;
+    module.add_decl(v::Decl::new_reg(&issued, offset_width));
+    module.add_decl(v::Decl::new_reg(
+        &outstanding,
+        utils::bits_needed_for(memory.outstanding) + 1,
+    ));
+    module.add_decl(v::Decl::new_wire(&issue, 1));
+    module.add_stmt(v::Parallel::Assign(
+        issue.as_str().into(),
+        channel.handshake(),
+    ));
+    module.add_decl(v::Decl::new_wire(&complete, 1));
+    module.add_stmt(v::Parallel::Assign(complete.as_str().into(), completed));
+
+    // issue bursts until the whole memory is covered while limiting the
+    // number of bursts in flight
+    module.add_stmt(v::Parallel::Assign(
+        channel.ready().into(),
+        v::Expr::new_logical_and(
+            v::Expr::new_logical_and(
+                active.clone(),
+                v::Expr::new_lt(issued.as_str(), memory_size as i32),
+            ),
+            v::Expr::new_lt(outstanding.as_str(), memory.outstanding as i32),
+        ),
+    ));
+    module.add_stmt(super::utils::cond_non_blk_assign(
+        "ACLK",
+        outstanding.as_str(),
+        vec![
+            (Some("ARESET".into()), 0.into()),
+            (
+                Some(v::Expr::new_logical_and(
+                    issue.as_str(),
+                    v::Expr::new_not(complete.as_str()),
+                )),
+                v::Expr::new_add(outstanding.as_str(), 1),
+            ),
+            (
+                Some(v::Expr::new_logical_and(
+                    v::Expr::new_not(issue.as_str()),
+                    complete.as_str(),
+                )),
+                v::Expr::new_sub(outstanding.as_str(), 1),
+            ),
+        ],
+    ));
+
+    // addresses are byte addressed which means addresses are computed as
+    // base + (offset << shift_by)
+    let shift_by = (memory.width / 8).trailing_zeros();
+    //add wire to store value of shift to circumvent order of operations
+    //issue on bit shift
+    let shift = format!("{}_shift", prefix);
+    module.add_decl(v::Decl::new_wire(&shift, bus_addr_width));
+    let mut concat = v::ExprConcat::default();
+    concat.add_expr(issued.as_str());
+    concat.add_expr(v::Expr::new_repeat(
+        bus_addr_width - offset_width,
+        v::Expr::new_ulit_bin(1, "0"),
+    ));
+    module.add_stmt(v::Parallel::Assign(
+        shift.as_str().into(),
+        v::Expr::new_shift_left(concat, shift_by as i32),
+    ));
+
+    module.add_stmt(channel.assign("ID", 0));
+    module.add_stmt(
+        channel.assign("ADDR", v::Expr::new_add("BASE_ADDRESS", shift)),
+    );
+    // AxLEN is the number of beats in the burst minus one. Only the last
+    // burst can be shorter than the burst length.
+    let len: v::Expr = if !memory.partial_burst() {
+        ((burst_len - 1) as i32).into()
+    } else {
+        let remaining = v::Expr::new_sub(memory_size as i32, issued.as_str());
+        v::Expr::new_mux(
+            v::Expr::new_lt(remaining.clone(), burst_len as i32),
+            v::Expr::new_sub(remaining, 1),
+            (burst_len - 1) as i32,
+        )
+    };
+    module.add_stmt(channel.assign("LEN", len));
+    // advance by the number of beats in the issued burst
+    let next = if !memory.partial_burst() {
+        v::Expr::new_add(issued.as_str(), burst_len as i32)
+    } else {
+        v::Expr::new_add(
+            v::Expr::new_add(issued.as_str(), channel.get("LEN")),
+            1,
+        )
+    };
+    incr_addr_by(module, active, &issued, issue.as_str().into(), next);
+    // AxSIZE is the number of bytes in each beat, log2 encoded
+    module.add_stmt(
+        channel.assign("SIZE", v::Expr::new_ulit_dec(3, &shift_by.to_string())),
+    );
+    //AxBURST corresponds to type of burst as follows:
+    // 0b00: Fixed
+    // 0b01 (default): Incr
+    // 0b10: Wrap
+    // 0b11: Reserved
+    module.add_stmt(channel.assign("BURST", v::Expr::new_ulit_bin(2, "01")));
+}
+
+/// Generates the FSM that tracks whether a memory controller is copying data
+/// from the host, acting as a BRAM, or sending data back to the host.
+pub(crate) fn module_mode_fsm(module: &mut v::Module) -> fsm::LinearFsm {
     // states:
     //  0: idle, start when COPY_TO_HOST
     //  1: copy to host, trans when
@@ -330,7 +703,7 @@ fn bram_logic(
     axi4: &AxiInterface,
     module: &mut v::Module,
     mode_fsm: &fsm::LinearFsm,
-    txn_count: v::Expr,
+    copy_data: v::Expr,
     data_width: u64,
     addr_width: u64,
 ) {
@@ -386,11 +759,6 @@ fn bram_logic(
     module.add_stmt(v::Parallel::Assign("bram_we".into(), mux_we));
 
     // bram write data
-    let copy_data: v::Expr = v::Expr::new_index_slice(
-        &axi4.read_data.get("DATA"),
-        v::Expr::new_mul(txn_count, 32),
-        data_width as u32, // bram data width
-    );
     let bram_data: v::Expr = "WRITE_DATA".into();
     let mux_data = v::Expr::new_mux(
         v::Expr::new_logical_and(
@@ -403,21 +771,38 @@ fn bram_logic(
     module.add_stmt(v::Parallel::Assign("bram_write_data".into(), mux_data));
 }
 
-fn incr_addr(
+/// Increments `offset_reg` whenever `condition` holds in the mode described by
+/// `mode_condition` and resets it to zero outside of that mode.
+pub(crate) fn incr_addr(
+    module: &mut v::Module,
+    mode_condition: v::Expr,
+    offset_reg: &str,
+    condition: v::Expr,
+) {
+    incr_addr_by(
+        module,
+        mode_condition,
+        offset_reg,
+        condition,
+        v::Expr::new_add(offset_reg, 1),
+    )
+}
+
+/// Like [incr_addr] but updates `offset_reg` to `next` instead of
+/// incrementing it.
+fn incr_addr_by(
     module: &mut v::Module,
     mode_condition: v::Expr,
     offset_reg: &str,
     condition: v::Expr,
+    next: v::Expr,
 ) {
     let mut always = v::ParallelProcess::new_always();
     always.set_event(v::Sequential::new_posedge("ACLK"));
 
     let mut mode_if = v::SequentialIfElse::new(mode_condition);
     let mut ifelse = v::SequentialIfElse::new(condition);
-    ifelse.add_seq(v::Sequential::new_nonblk_assign(
-        offset_reg,
-        v::Expr::new_add(offset_reg, 1),
-    ));
+    ifelse.add_seq(v::Sequential::new_nonblk_assign(offset_reg, next));
     ifelse.set_else(v::Sequential::new_nonblk_assign(offset_reg, offset_reg));
 
     mode_if.add_seq(ifelse);
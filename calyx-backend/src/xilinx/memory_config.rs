@@ -0,0 +1,148 @@
+//! Per-memory configuration of the AXI interfaces.
+use calyx_ir as ir;
+use calyx_utils::{CalyxResult, Error};
+
+/// Default width of the AXI data bus of a memory.
+const DEFAULT_BUS_DATA_WIDTH: u64 = 512;
+/// Supported widths of the AXI data bus of a memory.
+const BUS_DATA_WIDTHS: [u64; 3] = [64, 128, 512];
+/// AXI bursts may not cross a 4KB address boundary.
+const BURST_BOUNDARY: u64 = 4096;
+
+/// An `@external` memory of the toplevel component along with the parameters
+/// of the AXI interface used to transfer it.
+///
+/// The interface parameters are read from the following attributes on the
+/// memory cell:
+/// - `@axi_data_width`: width of the data bus, one of 64, 128, or 512
+///   (default 512).
+/// - `@axi_burst_len`: maximum number of beats in a burst. Must be a power of
+///   two no larger than 256 (default 1).
+/// - `@axi_outstanding`: maximum number of bursts that may be in flight on each
+///   of the read and write channels (default 1).
+///
+/// Memories without any of these attributes keep the original interface,
+/// which transfers one element per transaction over a 512-bit bus.
+pub(crate) struct MemoryConfig {
+    pub name: String,
+    /// Width of the elements of the memory.
+    pub width: u64,
+    /// Number of elements in the memory.
+    pub size: u64,
+    /// Width of the address port of the memory.
+    pub idx_size: u64,
+    pub bus_data_width: u64,
+    pub burst_len: u64,
+    pub outstanding: u64,
+    /// Whether any of the `@axi_*` attributes is present on the memory.
+    pub configured: bool,
+}
+
+impl MemoryConfig {
+    /// Number of bursts needed to transfer the memory in one direction.
+    pub fn bursts(&self) -> u64 {
+        self.size.div_ceil(self.burst_len)
+    }
+
+    /// Whether the last burst transfers fewer elements than the burst length.
+    pub fn partial_burst(&self) -> bool {
+        // the burst length is a power of two
+        self.size & (self.burst_len - 1) != 0
+    }
+}
+
+/// Collects the `@external` memories of `comp` and the parameters of the AXI
+/// interfaces used to transfer them.
+pub(crate) fn axi_memories(
+    comp: &ir::Component,
+) -> CalyxResult<Vec<MemoryConfig>> {
+    let cells = ir::utils::external_memories_cells(comp);
+    if cells.is_empty() {
+        return Err(Error::misc(
+                "Program has no memories marked with attribute @external.".to_owned() +
+                " Please make sure that at least one memory is marked as @external."));
+    }
+    cells
+        .iter()
+        .map(|cell| memory_config(&cell.borrow()))
+        .collect()
+}
+
+/// Width of the AXI data bus of the `@external` memory `cell`.
+pub(crate) fn bus_data_width(cell: &ir::Cell) -> CalyxResult<u64> {
+    if is_configured(cell) {
+        memory_config(cell).map(|config| config.bus_data_width)
+    } else {
+        Ok(DEFAULT_BUS_DATA_WIDTH)
+    }
+}
+
+fn is_configured(cell: &ir::Cell) -> bool {
+    [
+        ir::NumAttr::AxiDataWidth,
+        ir::NumAttr::AxiBurstLen,
+        ir::NumAttr::AxiOutstanding,
+    ]
+    .into_iter()
+    .any(|attr| cell.attributes.has(attr))
+}
+
+fn memory_config(cell: &ir::Cell) -> CalyxResult<MemoryConfig> {
+    let name = cell.name();
+    if !cell.is_primitive(Some("comb_mem_d1")) {
+        return Err(Error::misc(format!("cell `{name}' marked with `@external' but is not a comb_mem_d1. The AXI generator currently only supports `comb_mem_d1'")));
+    }
+    let param = |p: &str| cell.get_parameter(p).unwrap();
+    let config = MemoryConfig {
+        name: name.to_string(),
+        width: param("WIDTH"),
+        size: param("SIZE"),
+        idx_size: param("IDX_SIZE"),
+        bus_data_width: cell
+            .attributes
+            .get(ir::NumAttr::AxiDataWidth)
+            .unwrap_or(DEFAULT_BUS_DATA_WIDTH),
+        burst_len: cell.attributes.get(ir::NumAttr::AxiBurstLen).unwrap_or(1),
+        outstanding: cell
+            .attributes
+            .get(ir::NumAttr::AxiOutstanding)
+            .unwrap_or(1),
+        configured: is_configured(cell),
+    };
+    if !config.configured {
+        return Ok(config);
+    }
+
+    if !BUS_DATA_WIDTHS.contains(&config.bus_data_width) {
+        return Err(Error::misc(format!(
+            "memory `{name}' has @axi_data_width({}). Supported AXI data widths are 64, 128, and 512",
+            config.bus_data_width
+        )));
+    }
+    // Each beat transfers one element using a narrow transfer which requires
+    // the element to fit within the bus and be a power of two bytes wide.
+    if config.width < 8
+        || !config.width.is_power_of_two()
+        || config.width > config.bus_data_width
+    {
+        return Err(Error::misc(format!(
+            "memory `{name}' has width {}. The AXI generator requires memory widths to be a power of two between 8 and the AXI data width ({})",
+            config.width, config.bus_data_width
+        )));
+    }
+    if !config.burst_len.is_power_of_two()
+        || config.burst_len > 256
+        || config.burst_len * (config.width / 8) > BURST_BOUNDARY
+    {
+        return Err(Error::misc(format!(
+            "memory `{name}' has @axi_burst_len({}). Burst lengths must be a power of two no larger than 256 and a burst must not transfer more than {BURST_BOUNDARY} bytes",
+            config.burst_len
+        )));
+    }
+    if config.outstanding == 0 {
+        return Err(Error::misc(format!(
+            "memory `{name}' has @axi_outstanding(0). At least one transaction must be allowed to be in flight"
+        )));
+    }
+    Ok(config)
+}
@@ -1,11 +1,12 @@
 //! Backend for generating synthesiable code for Xilinx FPGAs
 mod axi;
-mod axi_address_space;
+pub(crate) mod axi_address_space;
 mod control_axi;
-mod fsm;
-mod memory_axi;
-mod toplevel;
-mod utils;
+pub(crate) mod fsm;
+pub(crate) mod memory_axi;
+mod memory_config;
+pub(crate) mod toplevel;
+pub(crate) mod utils;
 mod xml;
 
 pub use toplevel::XilinxInterfaceBackend;
@@ -1,11 +1,15 @@
 use super::{
-    axi, control_axi::ControlInterface, fsm, memory_axi::bram,
-    memory_axi::MemoryInterface, utils,
+    axi,
+    control_axi::ControlInterface,
+    fsm,
+    memory_axi::bram,
+    memory_axi::MemoryInterface,
+    memory_config::{axi_memories, MemoryConfig},
+    utils,
 };
 use crate::traits::Backend;
 use calyx_ir as ir;
-use calyx_ir::utils::GetMemInfo;
-use calyx_utils::{CalyxResult, Error};
+use calyx_utils::CalyxResult;
 use vast::v05::ast as v;
 
 /// A backend that generates the Xilinx interfacing for a Calyx program.
@@ -38,22 +42,17 @@ impl Backend for XilinxInterfaceBackend {
             .find(|c| c.name == prog.entrypoint)
             .unwrap();
 
-        let memories = ir::utils::external_memories_names(toplevel);
-        if memories.is_empty() {
-            return Err(Error::misc(
-                    "Program has no memories marked with attribute @external.".to_owned() +
-                    " Please make sure that at least one memory is marked as @external."));
-        }
-
-        let mem_info = toplevel.get_mem_info();
+        let memories = axi_memories(toplevel)?;
+        let names: Vec<String> =
+            memories.iter().map(|m| m.name.clone()).collect();
 
-        let mut modules = vec![top_level(toplevel)];
-        for (i, mem) in mem_info.iter().enumerate() {
+        let mut modules = vec![top_level(&memories)];
+        for (i, mem) in memories.iter().enumerate() {
             modules.push(bram(
                 &format!("SINGLE_PORT_BRAM_{}", i),
                 mem.width,
                 mem.size,
-                mem.idx_sizes[0],
+                mem.idx_size,
             ))
         }
 
@@ -62,17 +61,14 @@ impl Backend for XilinxInterfaceBackend {
             // XXX(nathanielnrn) seems like these should be hard coded for our controller
             12,
             32,
-            &memories,
+            &names,
         ));
 
-        for (i, mem) in mem_info.iter().enumerate() {
+        for (i, mem) in memories.iter().enumerate() {
             modules.push(axi::AxiInterface::memory_module(
                 &format!("Memory_controller_axi_{}", i),
-                512,
+                mem,
                 64,
-                mem.width,
-                mem.size,
-                mem.idx_sizes[0],
             ))
         }
 
@@ -94,21 +90,9 @@ impl Backend for XilinxInterfaceBackend {
     }
 }
 
-// Gets all memory cells in top level marked external.
-//Panics if not all memories are 1-d
-fn external_1d_memories_cells(comp: &ir::Component) -> Vec<ir::RRC<ir::Cell>> {
-    let memories = ir::utils::external_memories_cells(comp);
-    for memory in memories.iter() {
-        if !memory.borrow().is_primitive(Some("comb_mem_d1")) {
-            panic!("cell `{}' marked with `@external' but is not a comb_mem_d1. The AXI generator currently only supports `comb_mem_d1'", memory.borrow().name())
-        }
-    }
-    memories
-}
-
-fn top_level(toplevel: &ir::Component) -> v::Module {
-    let memories = &ir::utils::external_memories_names(toplevel);
-    let mem_info = &external_1d_memories_cells(toplevel).get_mem_info();
+fn top_level(mem_info: &[MemoryConfig]) -> v::Module {
+    let memories: &Vec<String> =
+        &mem_info.iter().map(|m| m.name.clone()).collect();
     assert!(!memories.is_empty()); // At least 1 memory should exist within the toplevel
     let mut module = v::Module::new("Toplevel");
 
@@ -130,9 +114,13 @@ fn top_level(toplevel: &ir::Component) -> v::Module {
     axi4.add_ports_to(&mut module);
 
     // add an axi interface for each external memory
-    for (idx, _mem) in memories.iter().enumerate() {
-        axi::AxiInterface::memory_channels(64, 512, &format!("m{}_axi_", idx))
-            .add_ports_to(&mut module);
+    for (idx, mem) in mem_info.iter().enumerate() {
+        axi::AxiInterface::memory_channels(
+            64,
+            mem.bus_data_width,
+            &format!("m{}_axi_", idx),
+        )
+        .add_ports_to(&mut module);
     }
 
     // wires
@@ -179,7 +167,7 @@ fn top_level(toplevel: &ir::Component) -> v::Module {
         module.add_decl(v::Decl::new_wire(&format!("{}_send", mem), 1));
         module.add_decl(v::Decl::new_wire(&format!("{}_send_done", mem), 1));
     }
-    host_transfer_fsm(&mut module, memories);
+    host_transfer_fsm(&mut module, memories, "ap_clk");
 
     // instantiate memory controllers
     let base_master_axi_interface =
@@ -193,7 +181,7 @@ fn top_level(toplevel: &ir::Component) -> v::Module {
         let width = mem_info[idx].width;
         module.add_decl(v::Decl::new_wire(&write_data, width));
         module.add_decl(v::Decl::new_wire(&read_data, width));
-        module.add_decl(v::Decl::new_wire(&addr0, mem_info[idx].idx_sizes[0]));
+        module.add_decl(v::Decl::new_wire(&addr0, mem_info[idx].idx_size));
         module.add_decl(v::Decl::new_wire(&write_en, 1));
         module.add_decl(v::Decl::new_wire(&done, 1));
 
@@ -272,7 +260,13 @@ fn top_level(toplevel: &ir::Component) -> v::Module {
     module
 }
 
-fn host_transfer_fsm(module: &mut v::Module, memories: &[String]) {
+/// Generates the FSM that copies memories from the host, runs the kernel, and
+/// sends the memories back to the host.
+pub(crate) fn host_transfer_fsm(
+    module: &mut v::Module,
+    memories: &[String],
+    clock: &str,
+) {
     module.add_decl(v::Decl::new_wire("memories_copied", 1));
     module.add_decl(v::Decl::new_reg("memories_sent", 1));
     module.add_stmt(v::Parallel::Assign(
@@ -298,14 +292,14 @@ fn host_transfer_fsm(module: &mut v::Module, memories: &[String]) {
         .iter()
         .map(|mem| format!("{}_send", mem).into())
         .collect();
-    let fsm = fsm::LinearFsm::new("host_txn_", "ap_clk", "reset")
+    let fsm = fsm::LinearFsm::new("host_txn_", clock, "reset")
         .state("idle", &[], "ap_start") // idle state
         .state("copy", &copy_start_assigns, "memories_copied") // copy memory state
         .state("run_kernel", &["kernel_start".into()], "kernel_done") // run kernel state
         .state("send", &send_start_assigns, "memories_sent"); // send memory to host state
 
     let mut parallel = v::ParallelProcess::new_always();
-    parallel.set_event(v::Sequential::new_posedge("ap_clk"));
+    parallel.set_event(v::Sequential::new_posedge(clock));
 
     let mut ifelse = v::SequentialIfElse::new(fsm.state_is("send"));
     ifelse.add_seq(v::Sequential::new_nonblk_assign(
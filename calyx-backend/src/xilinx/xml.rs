@@ -1,3 +1,4 @@
+use super::memory_config::bus_data_width;
 use crate::traits::Backend;
 use calyx_ir as ir;
 use calyx_utils::CalyxResult;
@@ -149,7 +150,9 @@ impl Backend for XilinxXmlBackend {
             host_size: "0x4",
         }];
 
-        let memories: Vec<(String, String)> = toplevel
+        // the AXI interfaces of the memories must match the ones generated
+        // by the `xilinx` backend
+        let memories: Vec<(String, String, u64)> = toplevel
             .cells
             .iter()
             .filter(|cell_ref| {
@@ -160,27 +163,24 @@ impl Backend for XilinxXmlBackend {
             })
             .enumerate()
             .map(|(i, cell_ref)| {
-                (cell_ref.borrow().name().to_string(), format!("m{}_axi", i))
+                let cell = cell_ref.borrow();
+                Ok((
+                    cell.name().to_string(),
+                    format!("m{}_axi", i),
+                    bus_data_width(&cell)?,
+                ))
             })
-            .collect();
-        // make the lifetime of the &str long enough
-        let memories_ref: Vec<(&str, &str)> = memories
-            .iter()
-            .map(|(x, y)| (x.as_ref(), y.as_ref()))
-            .collect();
+            .collect::<CalyxResult<_>>()?;
         let offsets: Vec<String> = (0..memories.len())
             .map(|i| format!("{:#x}", 0x18 + (8 * i)))
             .collect();
 
-        for (i, (name, axi_name)) in memories_ref.iter().enumerate() {
+        for (i, (name, axi_name, data_width)) in memories.iter().enumerate() {
             ports.push(Port {
                 name: axi_name,
                 mode: "master",
                 range: "0xFFFFFFFFFFFFFFFF",
-                // Width should match the bus data width of memory modules
-                // described in hardware, for example see
-                // https://github.com/calyxir/calyx/blob/c2b12a0fe6b1ee3aaaae0c66e7c4619ee6c82614/src/backend/xilinx/toplevel.rs#L58
-                data_width: 512,
+                data_width: *data_width,
                 port_type: "addressable",
                 base: "0x0",
             });
@@ -0,0 +1,531 @@
+//! Formatter for Calyx programs.
+//!
+//! Unlike `ir::Printer`, the formatter works on the concrete syntax tree that
+//! the tree-sitter grammar of the language server builds, so it keeps
+//! comments, attributes and the order of everything in the file. Comments are
+//! trivia: they are printed before the token that follows them, on the line of
+//! the previous token if they were on that line in the source and on their own
+//! line otherwise.
+//!
+//! The formatter only ever changes the whitespace between tokens. Definitions
+//! and statements go on their own line, blocks are indented by two spaces, the
+//! arguments of an `invoke` are put on their own lines the same way the
+//! printer does it, and signatures that don't fit in [WIDTH] columns put each
+//! port on its own line. The `=` of consecutive cell definitions and
+//! assignments and the widths of the ports of a broken signature are aligned.
+use calyx_utils::{CalyxResult, Error};
+use std::collections::HashMap;
+use tree_sitter::{Node, Parser, Tree};
+
+/// The number of columns that a signature can use before its ports are put on
+/// their own lines.
+pub const WIDTH: usize = 80;
+
+const INDENT: &str = "  ";
+
+/// Nodes that are printed exactly like they are written in the source.
+fn is_atomic(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        "ident"
+            | "number"
+            | "string"
+            | "literal"
+            | "primitive_blob"
+            | "metadata"
+            | "comment"
+    )
+}
+
+/// Nodes whose `{ .. }` contains definitions or statements.
+fn is_block(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        "component"
+            | "extern"
+            | "bundle"
+            | "cells"
+            | "cell_for"
+            | "wires"
+            | "wires_for"
+            | "group"
+            | "control"
+            | "seq"
+            | "par"
+            | "block"
+            | "for_stmt"
+    )
+}
+
+/// Nodes that are aligned with the nodes of the same kind around them.
+fn is_aligned(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        "cell_assignment"
+            | "wire_assignment"
+            | "invoke_arg"
+            | "io_port"
+            | "bundle_field"
+    )
+}
+
+/// The syntax nodes of `list` that aren't comments.
+fn items(list: Node) -> Vec<Node> {
+    let mut cursor = list.walk();
+    let items = list
+        .named_children(&mut cursor)
+        .filter(|n| n.kind() != "comment")
+        .collect();
+    items
+}
+
+/// Whether `prev` and `next` are separated by a space on the same line.
+fn spaced(prev: Node, next: Node) -> bool {
+    let parent = |n: Node| n.parent().map(|p| p.kind()).unwrap_or_default();
+    let in_attrs =
+        |n: Node| matches!(parent(n), "attributes" | "latency_annotation");
+    // `%[0:2]`
+    if parent(prev) == "static_timing" && parent(next) == "static_timing" {
+        return false;
+    }
+    match (prev.kind(), next.kind()) {
+        (_, "," | ";" | ")" | "]" | "." | ".." | ":") => false,
+        ("(" | "[" | "." | ".." | "@" | "!", _) => false,
+        // `<"toplevel"=1>` and `static<1>`
+        ("=", _) | (_, "=") if parent(prev) == "attribute" => false,
+        ("<", _) if in_attrs(prev) => false,
+        (_, "<" | ">") if in_attrs(next) => false,
+        // calls, holes and parameters
+        ("ident" | ")" | "]", "(" | "[") => false,
+        (">", "(" | "[") if in_attrs(prev) => false,
+        _ => true,
+    }
+}
+
+/// The alignment of an item in a run of aligned items.
+#[derive(Clone, Copy)]
+struct Align {
+    /// The token that is aligned
+    token: usize,
+    /// The width of what comes before the aligned token in the widest line of
+    /// the run
+    prefix: usize,
+    /// The width of the widest line of the run
+    width: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Layout {
+    Inline,
+    /// Opens a block or a list whose items go on their own lines
+    Open,
+    /// Closes a block or list that was opened with [Layout::Open]
+    Close,
+}
+
+struct Formatter<'a, 't> {
+    src: &'a str,
+    /// The comments of the source, in order
+    comments: Vec<Node<'t>>,
+    /// The first comment that hasn't been printed
+    next_comment: usize,
+    out: String,
+    line: String,
+    depth: usize,
+    /// The last token that was printed
+    prev: Option<Node<'t>>,
+    /// The source line of the last token or comment that was printed
+    last_row: usize,
+    /// Whether nothing has been printed since a block was opened
+    block_start: bool,
+    /// The next token starts a new line
+    break_next: bool,
+    /// The next token follows a comment on the same line
+    after_comment: bool,
+    /// The ports of the signature that is being printed go on their own lines
+    break_ports: bool,
+    /// The alignment of the items of the lists that have been visited
+    runs: HashMap<usize, Align>,
+    /// The alignment of the item that starts the next line
+    pending: Option<Align>,
+    /// The aligned token of the current line and the column where it starts
+    align: Option<(usize, usize)>,
+    /// The column where the comments at the end of the current line start
+    comment_col: Option<usize>,
+}
+
+impl<'a, 't> Formatter<'a, 't> {
+    fn new(src: &'a str, tree: &'t Tree) -> Self {
+        let mut comments = vec![];
+        collect_comments(tree.root_node(), &mut comments);
+        Formatter {
+            src,
+            comments,
+            next_comment: 0,
+            out: String::new(),
+            line: String::new(),
+            depth: 0,
+            prev: None,
+            last_row: 0,
+            block_start: false,
+            break_next: false,
+            after_comment: false,
+            break_ports: false,
+            runs: HashMap::new(),
+            pending: None,
+            align: None,
+            comment_col: None,
+        }
+    }
+
+    fn text(&self, node: Node) -> &'a str {
+        &self.src[node.byte_range()]
+    }
+
+    /// The column at the end of the current line.
+    fn column(&self) -> usize {
+        self.line
+            .rsplit('\n')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .count()
+    }
+
+    /// Pad the current line with spaces up to `col`, with at least one space.
+    fn pad(&mut self, col: usize) {
+        self.line.push(' ');
+        while self.column() < col {
+            self.line.push(' ');
+        }
+    }
+
+    fn newline(&mut self) {
+        self.out.push_str(self.line.trim_end());
+        self.out.push('\n');
+        self.line.clear();
+        self.align = None;
+        self.comment_col = None;
+    }
+
+    /// Whether the source has an empty line between the last line that was
+    /// printed and `row`.
+    fn blank_before(&self, row: usize) -> bool {
+        !self.out.is_empty() && !self.block_start && row > self.last_row + 1
+    }
+
+    /// The tokens of `node` without its comments.
+    fn leaves(node: Node<'t>, out: &mut Vec<Node<'t>>) {
+        if node.kind() == "comment" {
+            return;
+        }
+        if is_atomic(node) || node.child_count() == 0 {
+            out.push(node);
+            return;
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            Self::leaves(child, out);
+        }
+    }
+
+    /// The width of `tokens` when they are printed on one line.
+    fn width(&self, tokens: &[Node]) -> usize {
+        let mut width = 0;
+        for (idx, tok) in tokens.iter().enumerate() {
+            if idx > 0 && spaced(tokens[idx - 1], *tok) {
+                width += 1;
+            }
+            width += self.text(*tok).chars().count();
+        }
+        width
+    }
+
+    /// Whether there are comments inside of `node`.
+    fn has_comment(&self, node: Node) -> bool {
+        let idx = self
+            .comments
+            .partition_point(|c| c.start_byte() < node.start_byte());
+        self.comments
+            .get(idx)
+            .is_some_and(|c| c.end_byte() <= node.end_byte())
+    }
+
+    /// Whether there is an empty line between the bytes `start` and `end` of
+    /// the source.
+    fn has_blank_line(&self, start: usize, end: usize) -> bool {
+        let lines: Vec<_> = self.src[start..end].split('\n').collect();
+        lines.len() > 2
+            && lines[1..lines.len() - 1]
+                .iter()
+                .any(|l| l.trim().is_empty())
+    }
+
+    /// Align the items in `list` with the consecutive items of the same kind
+    /// around them. An empty line ends a run of aligned items.
+    fn align(&mut self, list: Node<'t>) {
+        let mut run: Vec<Node> = vec![];
+        for item in items(list) {
+            let joins = run.last().is_some_and(|last| {
+                last.kind() == item.kind()
+                    && !self.has_blank_line(last.end_byte(), item.start_byte())
+            });
+            if !joins {
+                self.add_run(&run);
+                run.clear();
+            }
+            if is_aligned(item) && !self.has_comment(item) {
+                run.push(item);
+            } else {
+                self.add_run(&run);
+                run.clear();
+            }
+        }
+        self.add_run(&run);
+    }
+
+    fn add_run(&mut self, run: &[Node<'t>]) {
+        let lines: Vec<_> = run
+            .iter()
+            .filter_map(|item| {
+                let mut tokens = vec![];
+                Self::leaves(*item, &mut tokens);
+                // ports and fields align their width, the rest their `=`
+                let at = match item.kind() {
+                    "io_port" | "bundle_field" => {
+                        tokens.iter().position(|t| t.kind() == ":")? + 1
+                    }
+                    _ => tokens.iter().position(|t| t.kind() == "=")?,
+                };
+                let comma =
+                    item.next_sibling().is_some_and(|n| n.kind() == ",");
+                let prefix = self.width(&tokens[..at]);
+                let rest = self.width(&tokens[at..]) + usize::from(comma);
+                Some((item.id(), tokens[at].id(), prefix, rest))
+            })
+            .collect();
+        let prefix = lines.iter().map(|l| l.2).max().unwrap_or_default();
+        let rest = lines.iter().map(|l| l.3).max().unwrap_or_default();
+        for (item, token, _, _) in lines {
+            let align = Align {
+                token,
+                prefix,
+                width: prefix + 1 + rest,
+            };
+            self.runs.insert(item, align);
+        }
+    }
+
+    /// Print the comments before the byte `pos` of the source.
+    fn comments_before(&mut self, pos: usize) {
+        while let Some(&comment) = self.comments.get(self.next_comment) {
+            if comment.start_byte() >= pos {
+                break;
+            }
+            self.next_comment += 1;
+            let text = self.text(comment);
+            let row = comment.start_position().row;
+            let trailing = !self.line.is_empty() && row == self.last_row;
+            if trailing {
+                match self.comment_col {
+                    Some(col) => self.pad(col),
+                    None => self.line.push(' '),
+                }
+            } else {
+                if !self.line.is_empty() {
+                    self.newline();
+                }
+                if self.blank_before(row) {
+                    self.out.push('\n');
+                }
+                self.line.push_str(&INDENT.repeat(self.depth));
+            }
+            self.line.push_str(text);
+            self.last_row = comment.end_position().row;
+            self.block_start = false;
+            if trailing && text.starts_with("/*") {
+                self.after_comment = true;
+            } else {
+                self.break_next = true;
+            }
+        }
+    }
+
+    fn token(&mut self, tok: Node<'t>, layout: Layout) {
+        self.comments_before(tok.start_byte());
+        if layout == Layout::Close {
+            self.depth -= 1;
+            self.break_next = !self.block_start;
+        }
+        let row = tok.start_position().row;
+        let aligned = self.align.filter(|(token, _)| *token == tok.id());
+        if self.break_next || self.line.is_empty() {
+            if !self.line.is_empty() {
+                self.newline();
+            }
+            if layout != Layout::Close && self.blank_before(row) {
+                self.out.push('\n');
+            }
+            self.line.push_str(&INDENT.repeat(self.depth));
+            if let Some(align) = self.pending.take() {
+                let col = self.column();
+                self.align = Some((align.token, col + align.prefix + 1));
+                self.comment_col = Some(col + align.width + 1);
+            }
+        } else if let Some((_, col)) = aligned {
+            self.pad(col);
+        } else if layout == Layout::Close
+            || !(self.after_comment
+                || self.prev.is_some_and(|prev| spaced(prev, tok)))
+        {
+            // an empty block, or no space between the tokens
+        } else {
+            self.line.push(' ');
+        }
+        self.line.push_str(self.text(tok));
+
+        self.prev = Some(tok);
+        self.last_row = tok.end_position().row;
+        self.block_start = false;
+        self.break_next = false;
+        self.after_comment = false;
+        if layout == Layout::Open {
+            self.depth += 1;
+            self.block_start = true;
+            self.break_next = true;
+        }
+    }
+
+    /// Whether the item `node` starts a new line.
+    fn starts_line(&self, node: Node) -> bool {
+        if !node.is_named() {
+            return false;
+        }
+        match node.kind() {
+            "import" | "component" | "primitive" | "extern" | "bundle"
+            | "bundle_field" | "metadata" | "cells" | "cell_assignment"
+            | "cell_for" | "wires" | "group" | "wire_assignment"
+            | "wires_for" | "control" | "stmt" | "invoke_arg" => true,
+            "io_port" => self.break_ports,
+            _ => false,
+        }
+    }
+
+    /// Whether `list` is a list of arguments or ports that go on their own
+    /// lines.
+    fn is_broken(&self, list: Node) -> bool {
+        match list.kind() {
+            "invoke_args" => !items(list).is_empty(),
+            "io_port_list" => self.break_ports && !items(list).is_empty(),
+            _ => false,
+        }
+    }
+
+    fn layout(&self, tok: Node) -> Layout {
+        let Some(parent) = tok.parent() else {
+            return Layout::Inline;
+        };
+        match tok.kind() {
+            "{" if is_block(parent) => Layout::Open,
+            "}" if is_block(parent) => Layout::Close,
+            "(" if self.is_broken(parent) => Layout::Open,
+            ")" if self.is_broken(parent) => Layout::Close,
+            _ => Layout::Inline,
+        }
+    }
+
+    fn node(&mut self, node: Node<'t>) {
+        if node.kind() == "comment" {
+            return;
+        }
+        if self.starts_line(node) {
+            self.break_next = true;
+            self.pending = self.runs.remove(&node.id());
+        }
+        if is_atomic(node) || node.child_count() == 0 {
+            let layout = self.layout(node);
+            return self.token(node, layout);
+        }
+        match node.kind() {
+            "signature" => {
+                let mut tokens = vec![];
+                Self::leaves(node, &mut tokens);
+                // leave room for the ` {` or `;` after the signature
+                self.break_ports = self.has_comment(node)
+                    || self.column() + 1 + self.width(&tokens) + 2 > WIDTH;
+            }
+            "io_port_list" if self.break_ports => self.align(node),
+            "cells_inner" | "cell_for" | "wires_inner" | "wires_for"
+            | "group" | "bundle" | "invoke_args" => self.align(node),
+            _ => (),
+        }
+        let mut cursor = node.walk();
+        let children: Vec<_> = node.children(&mut cursor).collect();
+        for child in children {
+            self.node(child);
+        }
+        if node.kind() == "signature" {
+            self.break_ports = false;
+        }
+    }
+}
+
+/// The comments in `node`, except for the ones in text that is copied as-is.
+fn collect_comments<'t>(node: Node<'t>, out: &mut Vec<Node<'t>>) {
+    if node.kind() == "comment" {
+        out.push(node);
+    } else if !is_atomic(node) {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            collect_comments(child, out);
+        }
+    }
+}
+
+/// The first syntax error in `node`.
+fn first_error(node: Node) -> Option<Node> {
+    if node.is_error() || node.is_missing() {
+        return Some(node);
+    }
+    if !node.has_error() {
+        return None;
+    }
+    let mut cursor = node.walk();
+    let children: Vec<_> = node.children(&mut cursor).collect();
+    children.into_iter().find_map(first_error)
+}
+
+/// Format the Calyx program `src`.
+pub fn format_source(src: &str) -> CalyxResult<String> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(tree_sitter_calyx::language())
+        .map_err(|err| {
+            Error::misc(format!("Cannot load the grammar: {err}"))
+        })?;
+    let tree = parser
+        .parse(src, None)
+        .ok_or_else(|| Error::misc("Cannot parse the program"))?;
+    let root = tree.root_node();
+    if let Some(err) = first_error(root) {
+        let pos = err.start_position();
+        let msg = if err.is_missing() {
+            format!("missing `{}`", err.kind())
+        } else {
+            "syntax error".to_string()
+        };
+        return Err(Error::misc(format!(
+            "Cannot format the program, {msg} on line {} column {}",
+            pos.row + 1,
+            pos.column + 1
+        )));
+    }
+
+    let mut f = Formatter::new(src, &tree);
+    f.node(root);
+    f.comments_before(src.len());
+    if !f.line.is_empty() {
+        f.newline();
+    }
+    Ok(f.out)
+}
@@ -1,8 +1,9 @@
 //! Abstract Syntax Tree for Calyx
 use super::parser;
-use crate::{Attributes, PortDef, Primitive};
+use crate::{Attributes, Bundle, BundlePort, PortDef, Primitive};
 use atty::Stream;
 use calyx_utils::{CalyxResult, Error, GPosIdx, Id};
+use linked_hash_map::LinkedHashMap;
 use std::{num::NonZeroU64, path::PathBuf};
 
 /// Corresponds to an individual Calyx file.
@@ -14,6 +15,8 @@ pub struct NamespaceDef {
     pub components: Vec<ComponentDef>,
     /// Extern statements and any primitive declarations in them.
     pub externs: Vec<(Option<String>, Vec<Primitive>)>,
+    /// Bundle definitions.
+    pub bundles: Vec<Bundle>,
     /// Optional opaque metadata
     pub metadata: Option<String>,
 }
@@ -43,20 +46,21 @@ impl NamespaceDef {
 }
 
 /// AST statement for defining components.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct ComponentDef {
     /// Name of the component.
     pub name: Id,
+    /// Parameters of a generic component. Empty for all other components.
+    pub params: Vec<Id>,
     /// Defines input and output ports along with their attributes.
-    pub signature: Vec<PortDef<u64>>,
-    /// List of instantiated sub-components
-    pub cells: Vec<Cell>,
-    /// List of groups
-    pub groups: Vec<Group>,
-    /// List of StaticGroups
-    pub static_groups: Vec<StaticGroup>,
-    /// List of continuous assignments
-    pub continuous_assignments: Vec<Wire>,
+    pub signature: Vec<PortDef<WidthExpr>>,
+    /// Ports in the signature whose type is a bundle.
+    pub bundle_ports: Vec<BundlePort>,
+    /// Definitions in the `cells` section, in source order
+    pub cells: Vec<CellDef>,
+    /// Groups, static groups, and continuous assignments in the `wires`
+    /// section, in source order
+    pub wires: Vec<WiresDef>,
     /// Single control statement for this component.
     pub control: Control,
     /// Attributes attached to this component
@@ -72,18 +76,18 @@ impl ComponentDef {
         name: S,
         is_comb: bool,
         latency: Option<NonZeroU64>,
-        signature: Vec<PortDef<u64>>,
+        signature: Vec<PortDef<WidthExpr>>,
     ) -> Self
     where
         S: Into<Id>,
     {
         Self {
             name: name.into(),
+            params: Vec::new(),
             signature,
+            bundle_ports: Vec::new(),
             cells: Vec::new(),
-            groups: Vec::new(),
-            static_groups: Vec::new(),
-            continuous_assignments: Vec::new(),
+            wires: Vec::new(),
             control: Control::empty(),
             attributes: Attributes::default(),
             is_comb,
@@ -92,9 +96,135 @@ impl ComponentDef {
     }
 }
 
+/// A width or parameter written in a component: a number, a parameter of the
+/// component, or arithmetic over them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WidthExpr {
+    Const(u64),
+    Param(Id),
+    Add(Box<WidthExpr>, Box<WidthExpr>),
+    Sub(Box<WidthExpr>, Box<WidthExpr>),
+    Mul(Box<WidthExpr>, Box<WidthExpr>),
+    Div(Box<WidthExpr>, Box<WidthExpr>),
+}
+
+impl WidthExpr {
+    /// Compute the value of the expression given the values of the
+    /// parameters.
+    pub fn eval(&self, bindings: &LinkedHashMap<Id, u64>) -> CalyxResult<u64> {
+        let (l, r, op) = match self {
+            WidthExpr::Const(value) => return Ok(*value),
+            WidthExpr::Param(param) => {
+                return bindings.get(param).copied().ok_or_else(|| {
+                    Error::undefined(*param, "parameter".to_string())
+                })
+            }
+            WidthExpr::Add(l, r) => (l, r, "+"),
+            WidthExpr::Sub(l, r) => (l, r, "-"),
+            WidthExpr::Mul(l, r) => (l, r, "*"),
+            WidthExpr::Div(l, r) => (l, r, "/"),
+        };
+        let (lv, rv) = (l.eval(bindings)?, r.eval(bindings)?);
+        let value = match self {
+            WidthExpr::Add(..) => lv.checked_add(rv),
+            WidthExpr::Sub(..) => lv.checked_sub(rv),
+            WidthExpr::Mul(..) => lv.checked_mul(rv),
+            _ => lv.checked_div(rv),
+        };
+        value.ok_or_else(|| {
+            Error::malformed_structure(format!(
+                "Cannot compute `{self}' because `{lv} {op} {rv}' is not a valid width"
+            ))
+        })
+    }
+}
+
+impl WidthExpr {
+    /// Replace the parameters that are bound in `bindings` with their
+    /// expressions.
+    pub(crate) fn bind(
+        &self,
+        bindings: &LinkedHashMap<Id, &WidthExpr>,
+    ) -> Self {
+        let bin = |l: &WidthExpr, r: &WidthExpr| {
+            (Box::new(l.bind(bindings)), Box::new(r.bind(bindings)))
+        };
+        match self {
+            WidthExpr::Const(_) => self.clone(),
+            WidthExpr::Param(param) => bindings
+                .get(param)
+                .map_or_else(|| self.clone(), |&e| e.clone()),
+            WidthExpr::Add(l, r) => {
+                let (l, r) = bin(l, r);
+                WidthExpr::Add(l, r)
+            }
+            WidthExpr::Sub(l, r) => {
+                let (l, r) = bin(l, r);
+                WidthExpr::Sub(l, r)
+            }
+            WidthExpr::Mul(l, r) => {
+                let (l, r) = bin(l, r);
+                WidthExpr::Mul(l, r)
+            }
+            WidthExpr::Div(l, r) => {
+                let (l, r) = bin(l, r);
+                WidthExpr::Div(l, r)
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for WidthExpr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // operands that are themselves arithmetic are put in parentheses
+        let operand = |e: &WidthExpr| match e {
+            WidthExpr::Const(_) | WidthExpr::Param(_) => e.to_string(),
+            _ => format!("({e})"),
+        };
+        match self {
+            WidthExpr::Const(value) => write!(f, "{value}"),
+            WidthExpr::Param(param) => write!(f, "{param}"),
+            WidthExpr::Add(l, r) => {
+                write!(f, "{} + {}", operand(l), operand(r))
+            }
+            WidthExpr::Sub(l, r) => {
+                write!(f, "{} - {}", operand(l), operand(r))
+            }
+            WidthExpr::Mul(l, r) => {
+                write!(f, "{} * {}", operand(l), operand(r))
+            }
+            WidthExpr::Div(l, r) => {
+                write!(f, "{} / {}", operand(l), operand(r))
+            }
+        }
+    }
+}
+
+impl From<u64> for WidthExpr {
+    fn from(value: u64) -> Self {
+        WidthExpr::Const(value)
+    }
+}
+
+impl PortDef<WidthExpr> {
+    /// Compute the width of the port given the values of the parameters of
+    /// its component.
+    pub fn resolve(
+        &self,
+        bindings: &LinkedHashMap<Id, u64>,
+    ) -> CalyxResult<PortDef<u64>> {
+        Ok(PortDef::new(
+            self.name(),
+            self.width.eval(bindings)?,
+            self.direction.clone(),
+            self.attributes.clone(),
+        ))
+    }
+}
+
 /// Statement that refers to a port on a subcomponent.
 /// This is distinct from a `Portdef` which defines a port.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Port {
     /// Refers to the port named `port` on the subcomponent
     /// `component`.
@@ -113,7 +243,7 @@ pub enum Port {
 // AST for wire guard expressions
 // ===================================
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum NumType {
     Decimal,
     Binary,
@@ -122,7 +252,7 @@ pub enum NumType {
 }
 
 /// Custom bitwidth numbers
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct BitNum {
     pub width: u64,
     pub num_type: NumType,
@@ -132,7 +262,7 @@ pub struct BitNum {
 
 /// Atomic operations used in guard conditions and RHS of the
 /// guarded assignments.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum Atom {
     /// Accessing a particular port on a component.
     Port(Port),
@@ -141,7 +271,7 @@ pub enum Atom {
 }
 
 /// The AST for GuardExprs
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum GuardExpr {
     // Logical operations
     And(Box<GuardExpr>, Box<GuardExpr>),
@@ -155,7 +285,7 @@ pub enum GuardExpr {
 pub type CompGuard = (GuardComp, Atom, Atom);
 
 /// The AST for StaticGuardExprs
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum StaticGuardExpr {
     And(Box<StaticGuardExpr>, Box<StaticGuardExpr>),
     Or(Box<StaticGuardExpr>, Box<StaticGuardExpr>),
@@ -166,7 +296,7 @@ pub enum StaticGuardExpr {
 }
 
 /// Possible comparison operators for guards.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub enum GuardComp {
     Eq,
     Neq,
@@ -177,14 +307,14 @@ pub enum GuardComp {
 }
 
 /// Guards `expr` using the optional guard condition `guard`.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Guard {
     pub guard: Option<GuardExpr>,
     pub expr: Atom,
 }
 
 /// Guards `expr` using the optional guard condition `guard`.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct StaticGuard {
     pub guard: Option<StaticGuardExpr>,
     pub expr: Atom,
@@ -195,16 +325,16 @@ pub struct StaticGuard {
 // ===================================
 
 /// Prototype of the cell definition
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Proto {
     /// Name of the primitive.
     pub name: Id,
-    /// Parameter binding for primitives
-    pub params: Vec<u64>,
+    /// Parameter binding for primitives and generic components
+    pub params: Vec<WidthExpr>,
 }
 
 /// The Cell AST nodes.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Cell {
     /// Name of the cell.
     pub name: Id,
@@ -222,7 +352,7 @@ impl Cell {
     pub fn from(
         name: Id,
         proto: Id,
-        params: Vec<u64>,
+        params: Vec<WidthExpr>,
         attributes: Attributes,
         reference: bool,
     ) -> Cell {
@@ -238,7 +368,7 @@ impl Cell {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Group {
     pub name: Id,
     pub wires: Vec<Wire>,
@@ -246,7 +376,7 @@ pub struct Group {
     pub is_comb: bool,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct StaticGroup {
     pub name: Id,
     pub wires: Vec<StaticWire>,
@@ -255,7 +385,7 @@ pub struct StaticGroup {
 }
 
 /// Data for the `->` structure statement.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Wire {
     /// Source of the wire.
     pub src: Guard,
@@ -268,7 +398,7 @@ pub struct Wire {
 }
 
 /// Data for the `->` structure statement.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct StaticWire {
     /// Source of the wire.
     pub src: StaticGuard,
@@ -280,10 +410,45 @@ pub struct StaticWire {
     pub attributes: Attributes,
 }
 
+/// A `for` loop that repeats `body` for every value of `var` from `start` up
+/// to, but not including, `end`. Loops are expanded by
+/// [ComponentDef::elaborate].
+#[derive(Clone, Debug)]
+pub struct ForLoop<T> {
+    /// The loop variable
+    pub var: Id,
+    /// First value of the loop variable
+    pub start: WidthExpr,
+    /// Bound of the loop variable
+    pub end: WidthExpr,
+    /// Definitions repeated by the loop
+    pub body: Vec<T>,
+    /// Attributes attached to this loop
+    pub attributes: Attributes,
+}
+
+/// A definition in the `cells` section. [ComponentDef::elaborate] replaces
+/// every `for` loop with the cells it defines.
+#[derive(Clone, Debug)]
+pub enum CellDef {
+    Cell(Cell),
+    For(ForLoop<CellDef>),
+}
+
+/// A definition in the `wires` section. [ComponentDef::elaborate] replaces
+/// every `for` loop with the definitions it repeats.
+#[derive(Clone, Debug)]
+pub enum WiresDef {
+    Wire(Wire),
+    Group(Group),
+    StaticGroup(StaticGroup),
+    For(ForLoop<WiresDef>),
+}
+
 /// Control AST nodes.
 /// Since enables and static enables are indistinguishable to the AST, there
 /// is single Control Enum for both Static and Dynamic Control
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 #[allow(clippy::large_enum_variant)]
 pub enum Control {
     /// Represents sequential composition of control statements.
@@ -434,6 +599,9 @@ pub enum Control {
         /// Attributes
         attributes: Attributes,
     },
+    /// Compile-time `for` loop. Its statements are repeated in the enclosing
+    /// `seq` or `par` once for every iteration.
+    For(ForLoop<Control>),
 }
 
 impl Control {
@@ -458,6 +626,7 @@ impl Control {
             Control::StaticIf { attributes, .. } => attributes,
             Control::StaticRepeat { attributes, .. } => attributes,
             Control::StaticInvoke { attributes, .. } => attributes,
+            Control::For(ForLoop { attributes, .. }) => attributes,
         }
     }
 }
@@ -114,6 +114,15 @@ pub enum NumAttr {
     /// dynamic.
     /// Therefore, we only place if we can *guarantee* the interval of the component.
     Interval,
+    #[strum(serialize = "axi_data_width")]
+    /// Width of the AXI data bus used to transfer an external memory
+    AxiDataWidth,
+    #[strum(serialize = "axi_burst_len")]
+    /// Maximum number of beats in each AXI burst that transfers an external memory
+    AxiBurstLen,
+    #[strum(serialize = "axi_outstanding")]
+    /// Maximum number of AXI bursts in flight while transferring an external memory
+    AxiOutstanding,
 }
 impl From<NumAttr> for Attribute {
     fn from(attr: NumAttr) -> Self {
@@ -139,6 +148,9 @@ pub enum InternalAttr {
     LOOP,
     START,
     END,
+    /// The group an assignment was defined in, as an index into
+    /// `ir::Context::source_groups`
+    GROUP,
 }
 impl From<InternalAttr> for Attribute {
     fn from(attr: InternalAttr) -> Self {
@@ -1,5 +1,5 @@
 use super::Attributes;
-use crate::Attribute;
+use crate::{ast::WidthExpr, Attribute};
 use calyx_utils::{CalyxResult, Error, GetName, Id};
 use linked_hash_map::LinkedHashMap;
 use smallvec::SmallVec;
@@ -89,9 +89,98 @@ impl GetName for Primitive {
     }
 }
 
+/// Definition of a bundle: a named group of ports that can be used as the type
+/// of a port in the signature of a component.
+///
+/// # Example
+/// ```text
+/// bundle axis[WIDTH] {
+///   data: WIDTH;
+///   valid: 1;
+///   flip ready: 1;
+/// }
+/// ```
+///
+/// The input port `in: axis(32)` is replaced by the input ports `in_data` and
+/// `in_valid` and the output port `in_ready`.
+#[derive(Clone, Debug)]
+pub struct Bundle {
+    /// Name of this bundle.
+    pub name: Id,
+    /// Parameters for this bundle.
+    pub params: Vec<Id>,
+    /// The fields of this bundle. Fields with [Direction::Output] are
+    /// flipped: they have the opposite direction of the port using the
+    /// bundle.
+    pub fields: Vec<PortDef<WidthExpr>>,
+    /// Key-value attributes for this bundle.
+    pub attributes: Attributes,
+}
+
+impl Bundle {
+    /// The ports that replace `port`, whose type is this bundle with the
+    /// arguments `args`. Each field `f` becomes a port named `{port}_{f}`.
+    pub fn flatten(
+        &self,
+        port: &PortDef<WidthExpr>,
+        args: &[WidthExpr],
+    ) -> CalyxResult<Vec<PortDef<WidthExpr>>> {
+        if self.params.len() != args.len() {
+            let msg = format!(
+               "bundle `{}` requires {} parameters but port `{}` provides {} parameters",
+               self.name,
+               self.params.len(),
+               port.name(),
+               args.len(),
+            );
+            return Err(Error::malformed_structure(msg));
+        }
+        let bindings = self
+            .params
+            .iter()
+            .copied()
+            .zip(args.iter())
+            .collect::<LinkedHashMap<_, _>>();
+        Ok(self
+            .fields
+            .iter()
+            .map(|field| {
+                let direction = match field.direction {
+                    Direction::Input => port.direction.clone(),
+                    _ => port.direction.reverse(),
+                };
+                PortDef::new(
+                    format!("{}_{}", port.name(), field.name()),
+                    field.width.bind(&bindings),
+                    direction,
+                    field.attributes.clone(),
+                )
+            })
+            .collect())
+    }
+}
+
+/// A port in the signature of a component whose type is a bundle. Until it is
+/// flattened, the signature contains a port with the name of the bundle as
+/// its width in its place.
+#[derive(Clone, Debug)]
+pub struct BundlePort {
+    /// Name of the port.
+    pub name: Id,
+    /// Name of the bundle.
+    pub bundle: Id,
+    /// Arguments for the parameters of the bundle.
+    pub args: Vec<WidthExpr>,
+    /// Direction of the port.
+    pub direction: Direction,
+    /// Names of the ports that replaced this port when it was flattened.
+    pub fields: Vec<Id>,
+}
+
 /// Definition of a port parameterized by a width type.
 /// Ports on Primitives can be parameteris and use [Width].
-/// Ports on Components cannot be parameterized and therefore use `u64`.
+/// Ports on Components use [crate::ast::WidthExpr] so that generic
+/// components can compute their widths from their parameters.
 #[derive(Clone, Debug)]
 pub struct PortDef<W> {
     /// The name of the port.
@@ -0,0 +1,500 @@
+//! Elaboration of `for` loops.
+//!
+//! A `for` loop is a template: its body is copied once for every value of the
+//! loop variable, and names in the copies like `pe_{i}` are replaced with the
+//! value of the expression in braces (`pe_0`, `pe_1`, ...). The copies keep
+//! the positions of the template so errors point back into the loop body.
+use crate::ast::{
+    Atom, Cell, CellDef, ComponentDef, Control, ForLoop, Group, GuardExpr,
+    Port, StaticGroup, StaticGuardExpr, StaticWire, WidthExpr, Wire, WiresDef,
+};
+use crate::{parser::CalyxParser, Attributes};
+use calyx_utils::{CalyxResult, Diagnostics, Error, Id};
+use linked_hash_map::LinkedHashMap;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::mem;
+
+impl WidthExpr {
+    /// Replace the parameters that have a value in `bindings` with it.
+    fn substitute(&mut self, bindings: &LinkedHashMap<Id, u64>) {
+        match self {
+            WidthExpr::Const(_) => (),
+            WidthExpr::Param(param) => {
+                if let Some(value) = bindings.get(param) {
+                    *self = WidthExpr::Const(*value);
+                }
+            }
+            WidthExpr::Add(l, r)
+            | WidthExpr::Sub(l, r)
+            | WidthExpr::Mul(l, r)
+            | WidthExpr::Div(l, r) => {
+                l.substitute(bindings);
+                r.substitute(bindings);
+            }
+        }
+    }
+}
+
+impl Port {
+    fn collect_names<'a>(&'a mut self, names: &mut Vec<&'a mut Id>) {
+        match self {
+            Port::Comp { component, port } => names.extend([component, port]),
+            Port::This { port } => names.push(port),
+            Port::Hole { group, name } => names.extend([group, name]),
+        }
+    }
+}
+
+impl Atom {
+    fn collect_names<'a>(&'a mut self, names: &mut Vec<&'a mut Id>) {
+        if let Atom::Port(port) = self {
+            port.collect_names(names)
+        }
+    }
+}
+
+impl GuardExpr {
+    fn collect_names<'a>(&'a mut self, names: &mut Vec<&'a mut Id>) {
+        match self {
+            GuardExpr::And(l, r) | GuardExpr::Or(l, r) => {
+                l.collect_names(names);
+                r.collect_names(names);
+            }
+            GuardExpr::Not(e) => e.collect_names(names),
+            GuardExpr::CompOp((_, l, r)) => {
+                l.collect_names(names);
+                r.collect_names(names);
+            }
+            GuardExpr::Atom(atom) => atom.collect_names(names),
+        }
+    }
+}
+
+impl StaticGuardExpr {
+    fn collect_names<'a>(&'a mut self, names: &mut Vec<&'a mut Id>) {
+        match self {
+            StaticGuardExpr::And(l, r) | StaticGuardExpr::Or(l, r) => {
+                l.collect_names(names);
+                r.collect_names(names);
+            }
+            StaticGuardExpr::Not(e) => e.collect_names(names),
+            StaticGuardExpr::CompOp((_, l, r)) => {
+                l.collect_names(names);
+                r.collect_names(names);
+            }
+            StaticGuardExpr::Atom(atom) => atom.collect_names(names),
+            StaticGuardExpr::StaticInfo(_) => (),
+        }
+    }
+}
+
+struct Elaborator {
+    /// Values of the parameters of the component and of the variables of the
+    /// enclosing loops
+    env: LinkedHashMap<Id, u64>,
+    diag: Diagnostics,
+    /// Location and message of the errors reported so far
+    reported: HashSet<(String, usize, usize, String)>,
+}
+
+impl Elaborator {
+    /// Report `err` unless the same error was reported by an earlier
+    /// iteration of a loop.
+    fn err(&mut self, err: Error) {
+        let (file, start, end) = err.location();
+        let key = (file.to_string(), start, end, err.message());
+        if self.reported.insert(key) {
+            self.diag.err(err);
+        }
+    }
+
+    /// Replace every `{expr}` in `name` with the value of `expr`.
+    fn interpolate(&self, name: Id) -> CalyxResult<Id> {
+        let mut rest = name.as_ref();
+        if !rest.contains('{') {
+            return Ok(name);
+        }
+        let mut out = String::new();
+        while let Some(start) = rest.find('{') {
+            // the grammar guarantees that the brace is closed
+            let end = start + rest[start..].find('}').unwrap();
+            let expr = CalyxParser::parse_interpolation(&rest[start + 1..end])?;
+            write!(out, "{}{}", &rest[..start], expr.eval(&self.env)?).unwrap();
+            rest = &rest[end + 1..];
+        }
+        out.push_str(rest);
+        Ok(out.into())
+    }
+
+    /// Interpolate `names`, which are used by the definition with `attrs`.
+    fn names<'a>(
+        &mut self,
+        names: impl IntoIterator<Item = &'a mut Id>,
+        attrs: &Attributes,
+    ) {
+        for name in names {
+            match self.interpolate(*name) {
+                Ok(new) => *name = new,
+                Err(err) => self.err(err.with_pos(attrs)),
+            }
+        }
+    }
+
+    /// Call `f` on every definition in the body of `l` once for every value
+    /// of the loop variable.
+    fn expand<T: Clone>(
+        &mut self,
+        l: ForLoop<T>,
+        mut f: impl FnMut(&mut Self, T),
+    ) {
+        let range = l
+            .start
+            .eval(&self.env)
+            .and_then(|start| Ok(start..l.end.eval(&self.env)?));
+        let range = match range {
+            Ok(range) => range,
+            Err(err) => {
+                self.err(err.with_pos(&l.attributes));
+                return;
+            }
+        };
+        // the loop variable shadows parameters and outer loop variables
+        let outer = self.env.get(&l.var).copied();
+        for value in range {
+            self.env.insert(l.var, value);
+            for def in &l.body {
+                f(self, def.clone());
+            }
+        }
+        match outer {
+            Some(value) => self.env.insert(l.var, value),
+            None => self.env.remove(&l.var),
+        };
+    }
+
+    fn cell(&mut self, mut cell: Cell) -> Cell {
+        self.names([&mut cell.name], &cell.attributes);
+        for param in &mut cell.prototype.params {
+            param.substitute(&self.env);
+        }
+        cell
+    }
+
+    /// Elaborate a definition in the `cells` section. The cells of `for`
+    /// loops are added to the list.
+    fn cell_def(&mut self, def: CellDef, out: &mut Vec<CellDef>) {
+        match def {
+            CellDef::Cell(cell) => out.push(CellDef::Cell(self.cell(cell))),
+            CellDef::For(l) => {
+                self.expand(l, |elab, def| elab.cell_def(def, out))
+            }
+        }
+    }
+
+    fn wire(&mut self, mut wire: Wire) -> Wire {
+        let mut names = vec![];
+        wire.dest.collect_names(&mut names);
+        if let Some(guard) = &mut wire.src.guard {
+            guard.collect_names(&mut names);
+        }
+        wire.src.expr.collect_names(&mut names);
+        self.names(names, &wire.attributes);
+        wire
+    }
+
+    fn static_wire(&mut self, mut wire: StaticWire) -> StaticWire {
+        let mut names = vec![];
+        wire.dest.collect_names(&mut names);
+        if let Some(guard) = &mut wire.src.guard {
+            guard.collect_names(&mut names);
+        }
+        wire.src.expr.collect_names(&mut names);
+        self.names(names, &wire.attributes);
+        wire
+    }
+
+    fn group(&mut self, mut group: Group) -> Group {
+        self.names([&mut group.name], &group.attributes);
+        group.wires = group.wires.into_iter().map(|w| self.wire(w)).collect();
+        group
+    }
+
+    fn static_group(&mut self, mut group: StaticGroup) -> StaticGroup {
+        self.names([&mut group.name], &group.attributes);
+        group.wires = group
+            .wires
+            .into_iter()
+            .map(|w| self.static_wire(w))
+            .collect();
+        group
+    }
+
+    /// Elaborate a definition in the `wires` section. The definitions of
+    /// `for` loops are added to the list.
+    fn wires_def(&mut self, def: WiresDef, out: &mut Vec<WiresDef>) {
+        match def {
+            WiresDef::Wire(wire) => out.push(WiresDef::Wire(self.wire(wire))),
+            WiresDef::Group(group) => {
+                out.push(WiresDef::Group(self.group(group)))
+            }
+            WiresDef::StaticGroup(group) => {
+                out.push(WiresDef::StaticGroup(self.static_group(group)))
+            }
+            WiresDef::For(l) => {
+                self.expand(l, |elab, def| elab.wires_def(def, out))
+            }
+        }
+    }
+
+    /// Elaborate the statements of a `seq` or `par`. The statements of `for`
+    /// loops are added to the list.
+    fn stmts(&mut self, stmts: Vec<Control>, is_static: bool) -> Vec<Control> {
+        let mut out = Vec::with_capacity(stmts.len());
+        for stmt in stmts {
+            self.stmt(stmt, is_static, &mut out);
+        }
+        out
+    }
+
+    fn stmt(&mut self, con: Control, is_static: bool, out: &mut Vec<Control>) {
+        match con {
+            Control::For(l) => {
+                self.expand(l, |elab, stmt| elab.stmt(stmt, is_static, out))
+            }
+            con => out.push(self.control(con, is_static)),
+        }
+    }
+
+    fn invoke_args(&mut self, args: &mut [(Id, Atom)], attrs: &Attributes) {
+        let mut names = vec![];
+        for (port, atom) in args {
+            names.push(port);
+            atom.collect_names(&mut names);
+        }
+        self.names(names, attrs);
+    }
+
+    /// Elaborate a control statement. `is_static` is true if it is part of a
+    /// static control statement.
+    fn control(&mut self, con: Control, is_static: bool) -> Control {
+        match con {
+            Control::Seq { stmts, attributes } => Control::Seq {
+                stmts: self.stmts(stmts, false),
+                attributes,
+            },
+            Control::Par { stmts, attributes } => Control::Par {
+                stmts: self.stmts(stmts, false),
+                attributes,
+            },
+            Control::StaticSeq {
+                stmts,
+                attributes,
+                latency,
+            } => Control::StaticSeq {
+                stmts: self.stmts(stmts, true),
+                attributes,
+                latency,
+            },
+            Control::StaticPar {
+                stmts,
+                attributes,
+                latency,
+            } => Control::StaticPar {
+                stmts: self.stmts(stmts, true),
+                attributes,
+                latency,
+            },
+            Control::If {
+                mut port,
+                mut cond,
+                tbranch,
+                fbranch,
+                attributes,
+            } => {
+                let mut names = vec![];
+                port.collect_names(&mut names);
+                names.extend(&mut cond);
+                self.names(names, &attributes);
+                Control::If {
+                    port,
+                    cond,
+                    tbranch: Box::new(self.control(*tbranch, false)),
+                    fbranch: Box::new(self.control(*fbranch, false)),
+                    attributes,
+                }
+            }
+            Control::StaticIf {
+                mut port,
+                tbranch,
+                fbranch,
+                attributes,
+                latency,
+            } => {
+                let mut names = vec![];
+                port.collect_names(&mut names);
+                self.names(names, &attributes);
+                Control::StaticIf {
+                    port,
+                    tbranch: Box::new(self.control(*tbranch, true)),
+                    fbranch: Box::new(self.control(*fbranch, true)),
+                    attributes,
+                    latency,
+                }
+            }
+            Control::While {
+                mut port,
+                mut cond,
+                body,
+                attributes,
+            } => {
+                let mut names = vec![];
+                port.collect_names(&mut names);
+                names.extend(&mut cond);
+                self.names(names, &attributes);
+                Control::While {
+                    port,
+                    cond,
+                    body: Box::new(self.control(*body, false)),
+                    attributes,
+                }
+            }
+            Control::Repeat {
+                num_repeats,
+                body,
+                attributes,
+            } => Control::Repeat {
+                num_repeats,
+                body: Box::new(self.control(*body, false)),
+                attributes,
+            },
+            Control::StaticRepeat {
+                num_repeats,
+                body,
+                attributes,
+            } => Control::StaticRepeat {
+                num_repeats,
+                body: Box::new(self.control(*body, true)),
+                attributes,
+            },
+            Control::Enable {
+                mut comp,
+                attributes,
+            } => {
+                self.names([&mut comp], &attributes);
+                Control::Enable { comp, attributes }
+            }
+            Control::Invoke {
+                mut comp,
+                mut inputs,
+                mut outputs,
+                attributes,
+                mut comb_group,
+                mut ref_cells,
+            } => {
+                self.names([&mut comp], &attributes);
+                self.invoke_args(&mut inputs, &attributes);
+                self.invoke_args(&mut outputs, &attributes);
+                self.names(&mut comb_group, &attributes);
+                self.names(
+                    ref_cells.iter_mut().flat_map(|(l, r)| [l, r]),
+                    &attributes,
+                );
+                Control::Invoke {
+                    comp,
+                    inputs,
+                    outputs,
+                    attributes,
+                    comb_group,
+                    ref_cells,
+                }
+            }
+            Control::StaticInvoke {
+                mut comp,
+                mut inputs,
+                mut outputs,
+                attributes,
+                mut ref_cells,
+                mut comb_group,
+                latency,
+            } => {
+                self.names([&mut comp], &attributes);
+                self.invoke_args(&mut inputs, &attributes);
+                self.invoke_args(&mut outputs, &attributes);
+                self.names(&mut comb_group, &attributes);
+                self.names(
+                    ref_cells.iter_mut().flat_map(|(l, r)| [l, r]),
+                    &attributes,
+                );
+                Control::StaticInvoke {
+                    comp,
+                    inputs,
+                    outputs,
+                    attributes,
+                    ref_cells,
+                    comb_group,
+                    latency,
+                }
+            }
+            Control::Empty { attributes } => Control::Empty { attributes },
+            // a loop that is not in a `seq` or `par` runs its iterations in
+            // sequence
+            Control::For(l) => {
+                let attributes = l.attributes.clone();
+                let stmts = self.stmts(vec![Control::For(l)], is_static);
+                if is_static {
+                    Control::StaticSeq {
+                        stmts,
+                        attributes,
+                        latency: None,
+                    }
+                } else {
+                    Control::Seq { stmts, attributes }
+                }
+            }
+        }
+    }
+}
+
+impl ComponentDef {
+    /// Expand the `for` loops of the component and replace every `{expr}` in
+    /// its names with the value of `expr`. `bindings` are the values of the
+    /// parameters of the component.
+    pub fn elaborate(
+        &mut self,
+        bindings: &LinkedHashMap<Id, u64>,
+    ) -> CalyxResult<()> {
+        let mut elab = Elaborator {
+            env: bindings.clone(),
+            diag: Diagnostics::default(),
+            reported: HashSet::new(),
+        };
+        for pd in &self.signature {
+            if pd.name().as_ref().contains('{') {
+                elab.diag.err(
+                    Error::malformed_structure(format!(
+                        "Port `{}' of a component cannot be named using `{{..}}'",
+                        pd.name()
+                    ))
+                    .with_pos(&self.attributes),
+                );
+            }
+        }
+
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for def in mem::take(&mut self.cells) {
+            elab.cell_def(def, &mut cells);
+        }
+        self.cells = cells;
+
+        let mut wires = Vec::with_capacity(self.wires.len());
+        for def in mem::take(&mut self.wires) {
+            elab.wires_def(def, &mut wires);
+        }
+        self.wires = wires;
+
+        let control = mem::replace(&mut self.control, Control::empty());
+        self.control = elab.control(control, self.latency.is_some());
+        elab.diag.into_result()
+    }
+}
@@ -10,6 +10,7 @@ pub mod parser;
 mod attribute;
 mod attributes;
 mod common;
+mod elaborate;
 mod lib_sig;
 mod workspace;
 
@@ -20,6 +21,6 @@ pub use attribute::{
     Attribute, BoolAttr, InternalAttr, NumAttr, DEPRECATED_ATTRIBUTES,
 };
 pub use attributes::{Attributes, GetAttributes};
-pub use common::{Direction, PortDef, Primitive, Width};
+pub use common::{Bundle, BundlePort, Direction, PortDef, Primitive, Width};
 pub use lib_sig::{LibrarySignatures, PrimitiveInfo};
 pub use workspace::Workspace;
@@ -2,12 +2,15 @@
 
 //! Parser for Calyx programs.
 use super::ast::{
-    self, BitNum, Control, GuardComp as GC, GuardExpr, NumType, StaticGuardExpr,
+    self, BitNum, Control, GuardComp as GC, GuardExpr, NumType,
+    StaticGuardExpr, WidthExpr,
 };
 use super::Attributes;
-use crate::{Attribute, Direction, PortDef, Primitive, Width};
+use crate::{
+    Attribute, Bundle, BundlePort, Direction, PortDef, Primitive, Width,
+};
 use calyx_utils::{self, CalyxResult, Id};
-use calyx_utils::{FileIdx, GPosIdx, GlobalPositionTable};
+use calyx_utils::{FileIdx, GPosIdx, GlobalPositionTable, PositionTable};
 use pest::pratt_parser::{Assoc, Op, PrattParser};
 use pest_consume::{match_nodes, Error, Parser};
 use std::fs;
@@ -39,6 +42,12 @@ lazy_static::lazy_static! {
     PrattParser::new()
         .op(Op::infix(Rule::guard_or, Assoc::Left))
         .op(Op::infix(Rule::guard_and, Assoc::Left));
+    static ref WIDTH_PRATT: PrattParser<Rule> =
+    PrattParser::new()
+        .op(Op::infix(Rule::width_add, Assoc::Left)
+            | Op::infix(Rule::width_sub, Assoc::Left))
+        .op(Op::infix(Rule::width_mul, Assoc::Left)
+            | Op::infix(Rule::width_div, Assoc::Left));
 }
 
 #[derive(Parser)]
@@ -48,17 +57,26 @@ pub struct CalyxParser;
 impl CalyxParser {
     /// Parse a Calyx program into an AST representation.
     pub fn parse_file(path: &Path) -> CalyxResult<ast::NamespaceDef> {
-        let time = std::time::Instant::now();
         let content = &fs::read(path).map_err(|err| {
             calyx_utils::Error::invalid_file(format!(
                 "Failed to read {}: {err}",
                 path.to_string_lossy(),
             ))
         })?;
-        // Add a new file to the position table
         let string_content = std::str::from_utf8(content)?.to_string();
+        Self::parse_source(path, string_content)
+    }
+
+    /// Parse `source` as the contents of the file at `path`, which need not match what is on
+    /// disk.
+    pub fn parse_source(
+        path: &Path,
+        source: String,
+    ) -> CalyxResult<ast::NamespaceDef> {
+        let time = std::time::Instant::now();
+        // Add a new file to the position table
         let file = GlobalPositionTable::as_mut()
-            .add_file(path.to_string_lossy().to_string(), string_content);
+            .add_file(path.to_string_lossy().to_string(), source);
         let user_data = UserData { file };
         let content = GlobalPositionTable::as_ref().get_source(file);
         // Parse the file
@@ -123,6 +141,22 @@ impl CalyxParser {
         Ok(out)
     }
 
+    /// Parse the expression in an interpolation `{expr}` in a name. The
+    /// expression was already checked when the name was parsed.
+    pub(crate) fn parse_interpolation(src: &str) -> CalyxResult<WidthExpr> {
+        let user_data = UserData {
+            file: PositionTable::UNKNOWN_FILE,
+        };
+        let to_err = |e: Error<Rule>| {
+            calyx_utils::Error::misc(format!("Failed to parse `{src}`: {e}"))
+        };
+        let inputs =
+            CalyxParser::parse_with_userdata(Rule::width_expr, src, user_data)
+                .map_err(to_err)?;
+        let input = inputs.single().map_err(to_err)?;
+        CalyxParser::width_expr(input).map_err(to_err)
+    }
+
     fn get_span(node: &Node) -> GPosIdx {
         let ud = node.user_data();
         let sp = node.as_span();
@@ -134,6 +168,30 @@ impl CalyxParser {
         GPosIdx(pos)
     }
 
+    /// A cell or a `for` loop in the `cells` section.
+    #[allow(clippy::result_large_err)]
+    fn cell_def(input: Node) -> ParseResult<ast::CellDef> {
+        match input.as_rule() {
+            Rule::cell => Self::cell(input).map(ast::CellDef::Cell),
+            Rule::cell_for => Self::cell_for(input).map(ast::CellDef::For),
+            _ => unreachable!(),
+        }
+    }
+
+    /// A definition or a `for` loop in the `wires` section.
+    #[allow(clippy::result_large_err)]
+    fn wires_def(input: Node) -> ParseResult<ast::WiresDef> {
+        match input.as_rule() {
+            Rule::wire => Self::wire(input).map(ast::WiresDef::Wire),
+            Rule::group => Self::group(input).map(ast::WiresDef::Group),
+            Rule::static_group => {
+                Self::static_group(input).map(ast::WiresDef::StaticGroup)
+            }
+            Rule::wires_for => Self::wires_for(input).map(ast::WiresDef::For),
+            _ => unreachable!(),
+        }
+    }
+
     #[allow(clippy::result_large_err)]
     fn guard_expr_helper(
         ud: UserData,
@@ -184,6 +242,90 @@ impl CalyxParser {
             })
             .parse(pairs)
     }
+
+    #[allow(clippy::result_large_err)]
+    fn width_expr_helper(
+        ud: UserData,
+        pairs: pest::iterators::Pairs<Rule>,
+    ) -> ParseResult<WidthExpr> {
+        WIDTH_PRATT
+            .map_primary(|primary| match primary.as_rule() {
+                Rule::width_atom => Self::width_atom(Node::new_with_user_data(
+                    primary,
+                    ud.clone(),
+                )),
+                x => unreachable!("Unexpected rule {:?} for width_expr", x),
+            })
+            .map_infix(|lhs, op, rhs| {
+                let (lhs, rhs) = (Box::new(lhs?), Box::new(rhs?));
+                Ok(match op.as_rule() {
+                    Rule::width_add => WidthExpr::Add(lhs, rhs),
+                    Rule::width_sub => WidthExpr::Sub(lhs, rhs),
+                    Rule::width_mul => WidthExpr::Mul(lhs, rhs),
+                    Rule::width_div => WidthExpr::Div(lhs, rhs),
+                    _ => unreachable!(),
+                })
+            })
+            .parse(pairs)
+    }
+
+    /// The ports of a signature given as `(name, type, attributes)`. A port
+    /// whose type is a bundle is represented by a port with the name of the
+    /// bundle as its width until it is flattened.
+    fn io_ports(
+        ports: Vec<(Id, PortType, Attributes)>,
+        direction: Direction,
+    ) -> Signature {
+        let mut bundle_ports = Vec::new();
+        let sig = ports
+            .into_iter()
+            .map(|(name, ty, attributes)| {
+                let width = match ty {
+                    PortType::Width(width) => width,
+                    PortType::Bundle(bundle, args) => {
+                        bundle_ports.push(BundlePort {
+                            name,
+                            bundle,
+                            args,
+                            direction: direction.clone(),
+                            fields: Vec::new(),
+                        });
+                        WidthExpr::Param(bundle)
+                    }
+                };
+                PortDef::new(name, width, direction.clone(), attributes)
+            })
+            .collect();
+        (sig, bundle_ports)
+    }
+
+    /// Primitives only support a number or a parameter as the width of their
+    /// ports.
+    #[allow(clippy::result_large_err)]
+    fn primitive_signature(
+        input: &Node,
+        (sig, bundle_ports): Signature,
+    ) -> ParseResult<Vec<PortDef<Width>>> {
+        if let Some(bp) = bundle_ports.first() {
+            return Err(input.error(format!(
+                "Port `{}' of a primitive cannot have a bundle type",
+                bp.name
+            )));
+        }
+        sig.into_iter()
+            .map(|pd| {
+                let width = match pd.width {
+                    WidthExpr::Const(value) => Width::Const { value },
+                    WidthExpr::Param(value) => Width::Param { value },
+                    _ => Err(input.error(format!(
+                        "Port `{}' of a primitive must have a number or a parameter as its width",
+                        pd.name()
+                    )))?,
+                };
+                Ok(PortDef::new(pd.name(), width, pd.direction, pd.attributes))
+            })
+            .collect()
+    }
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -191,8 +333,19 @@ enum ExtOrComp {
     Ext((Option<String>, Vec<Primitive>)),
     Comp(ComponentDef),
     PrimInline(Primitive),
+    Bundle(Bundle),
+}
+
+/// The type of a port in a signature
+enum PortType {
+    Width(WidthExpr),
+    /// A bundle with its arguments
+    Bundle(Id, Vec<WidthExpr>),
 }
 
+/// The ports of a signature and the ones among them that have a bundle type
+type Signature = (Vec<PortDef<WidthExpr>>, Vec<BundlePort>);
+
 #[pest_consume::parser]
 impl CalyxParser {
     fn EOI(_input: Node) -> ParseResult<()> {
@@ -378,14 +531,14 @@ impl CalyxParser {
         ))
     }
 
-    fn block_char(input: Node) -> ParseResult<&str> {
+    fn block_body(input: Node) -> ParseResult<&str> {
         Ok(input.as_str())
     }
 
     fn block_string(input: Node) -> ParseResult<String> {
         Ok(match_nodes!(
             input.into_children();
-            [block_char(c)..] => c.collect::<String>().trim().to_string()
+            [block_body(b)] => b.trim().to_string()
         ))
     }
 
@@ -431,96 +584,106 @@ impl CalyxParser {
         ))
     }
 
-    fn args(input: Node) -> ParseResult<Vec<u64>> {
+    fn width_atom(input: Node) -> ParseResult<WidthExpr> {
+        Ok(match_nodes!(
+            input.into_children();
+            [bitwidth(value)] => WidthExpr::Const(value),
+            [identifier(param)] => WidthExpr::Param(param),
+            [width_expr(expr)] => expr,
+        ))
+    }
+
+    fn width_expr(input: Node) -> ParseResult<WidthExpr> {
+        let ud = input.user_data().clone();
+        Self::width_expr_helper(ud, input.into_pair().into_inner())
+    }
+
+    fn args(input: Node) -> ParseResult<Vec<WidthExpr>> {
         Ok(match_nodes!(
             input.into_children();
-            [bitwidth(bw)..] => bw.collect(),
+            [width_expr(args)..] => args.collect(),
             [] => vec![]
         ))
     }
 
-    fn io_port(input: Node) -> ParseResult<(Id, Width, Attributes)> {
+    fn bundle_type(input: Node) -> ParseResult<(Id, Vec<WidthExpr>)> {
         Ok(match_nodes!(
             input.into_children();
-            [at_attributes(attrs), identifier(id), bitwidth(value)] =>
-                (id, Width::Const { value }, attrs),
-            [at_attributes(attrs), identifier(id), identifier(value)] =>
-                (id, Width::Param { value }, attrs)
+            [identifier(bundle), args(args)] => (bundle, args)
         ))
     }
 
-    fn inputs(input: Node) -> ParseResult<Vec<PortDef<Width>>> {
+    fn io_port(input: Node) -> ParseResult<(Id, PortType, Attributes)> {
         Ok(match_nodes!(
             input.into_children();
-            [io_port((name, width, attributes))] => {
-                let pd = PortDef::new(
-                    name, width, Direction::Input, attributes
-                );
-                vec![pd]
-            },
-            [io_port((name, width, attributes)), comma(_), inputs(rest)] => {
-                let pd = PortDef::new(
-                    name, width, Direction::Input, attributes
-                );
-                let mut v = vec![pd];
+            [at_attributes(attrs), identifier(id), width_expr(width)] =>
+                (id, PortType::Width(width), attrs),
+            [at_attributes(attrs), identifier(id), bundle_type((bundle, args))] =>
+                (id, PortType::Bundle(bundle, args), attrs)
+        ))
+    }
+
+    fn inputs(input: Node) -> ParseResult<Vec<(Id, PortType, Attributes)>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [io_port(port)] => vec![port],
+            [io_port(port), comma(_), inputs(rest)] => {
+                let mut v = vec![port];
                 v.extend(rest);
                 v
             }
         ))
     }
 
-    fn outputs(input: Node) -> ParseResult<Vec<PortDef<Width>>> {
+    fn outputs(input: Node) -> ParseResult<Vec<(Id, PortType, Attributes)>> {
         Ok(match_nodes!(
             input.into_children();
-            [io_port((name, width, attributes))] => {
-                let pd = PortDef::new(
-                    name, width, Direction::Output, attributes
-                );
-                vec![pd]
-            },
-            [io_port((name, width, attributes)), comma(_), outputs(rest)] => {
-                let pd = PortDef::new(
-                    name, width, Direction::Output, attributes
-                );
-                let mut v = vec![pd];
+            [io_port(port)] => vec![port],
+            [io_port(port), comma(_), outputs(rest)] => {
+                let mut v = vec![port];
                 v.extend(rest);
                 v
             }
         ))
     }
 
-    fn signature(input: Node) -> ParseResult<Vec<PortDef<Width>>> {
+    fn signature(input: Node) -> ParseResult<Signature> {
         Ok(match_nodes!(
             input.into_children();
             // NOTE(rachit): We expect the signature to be extended to have `go`,
             // `done`, `reset,`, and `clk`.
-            [] => Vec::with_capacity(4),
-            [inputs(ins)] =>  ins ,
-            [outputs(outs)] =>  outs ,
+            [] => (Vec::with_capacity(4), Vec::new()),
+            [inputs(ins)] => Self::io_ports(ins, Direction::Input),
+            [outputs(outs)] => Self::io_ports(outs, Direction::Output),
             [inputs(ins), outputs(outs)] => {
-                ins.into_iter().chain(outs.into_iter()).collect()
+                let (mut sig, mut bundle_ports) =
+                    Self::io_ports(ins, Direction::Input);
+                let (outs, out_bundles) =
+                    Self::io_ports(outs, Direction::Output);
+                sig.extend(outs);
+                bundle_ports.extend(out_bundles);
+                (sig, bundle_ports)
             },
         ))
     }
 
     // ==============Primitives=====================
-    fn sig_with_params(
-        input: Node,
-    ) -> ParseResult<(Vec<Id>, Vec<PortDef<Width>>)> {
+    fn sig_with_params(input: Node) -> ParseResult<(Vec<Id>, Signature)> {
         Ok(match_nodes!(
             input.into_children();
             [params(p), signature(s)] => (p, s),
             [signature(s)] => (vec![], s),
         ))
     }
+
     fn primitive(input: Node) -> ParseResult<Primitive> {
         let span = Self::get_span(&input);
         Ok(match_nodes!(
-            input.into_children();
+            input.clone().into_children();
             [name_with_attribute((name, attrs)), sig_with_params((p, s))] => Primitive {
                 name,
                 params: p,
-                signature: s,
+                signature: Self::primitive_signature(&input, s)?,
                 attributes: attrs.add_span(span),
                 is_comb: false,
                 latency: None,
@@ -529,7 +692,7 @@ impl CalyxParser {
             [comb_or_static(cs_res), name_with_attribute((name, attrs)), sig_with_params((p, s))] => Primitive {
                 name,
                 params: p,
-                signature: s,
+                signature: Self::primitive_signature(&input, s)?,
                 attributes: attrs.add_span(span),
                 is_comb: cs_res.is_none(),
                 latency: cs_res,
@@ -538,6 +701,15 @@ impl CalyxParser {
         ))
     }
 
+    // ================ For loops =====================
+    fn for_range(input: Node) -> ParseResult<(Id, WidthExpr, WidthExpr)> {
+        Ok(match_nodes!(
+            input.into_children();
+            [identifier(var), width_expr(start), width_expr(end)] =>
+                (var, start, end)
+        ))
+    }
+
     // ================ Cells =====================
     fn cell_without_semi(input: Node) -> ParseResult<ast::Cell> {
         let span = Self::get_span(&input);
@@ -559,11 +731,22 @@ impl CalyxParser {
         )
     }
 
-    fn cells(input: Node) -> ParseResult<Vec<ast::Cell>> {
-        Ok(match_nodes!(
-                input.into_children();
-                [cell(cells)..] => cells.collect()
-        ))
+    fn cell_for(input: Node) -> ParseResult<ast::ForLoop<ast::CellDef>> {
+        let span = Self::get_span(&input);
+        let mut children = input.into_children();
+        let (var, start, end) = Self::for_range(children.next().unwrap())?;
+        let body = children.map(Self::cell_def).collect::<ParseResult<_>>()?;
+        Ok(ast::ForLoop {
+            var,
+            start,
+            end,
+            body,
+            attributes: Attributes::default().add_span(span),
+        })
+    }
+
+    fn cells(input: Node) -> ParseResult<Vec<ast::CellDef>> {
+        input.into_children().map(Self::cell_def).collect()
     }
 
     // ================ Wires =====================
@@ -775,24 +958,22 @@ impl CalyxParser {
         ))
     }
 
-    fn connections(
-        input: Node,
-    ) -> ParseResult<(Vec<ast::Wire>, Vec<ast::Group>, Vec<ast::StaticGroup>)>
-    {
-        let mut wires = Vec::new();
-        let mut groups = Vec::new();
-        let mut static_groups = Vec::new();
-        for node in input.into_children() {
-            match node.as_rule() {
-                Rule::wire => wires.push(Self::wire(node)?),
-                Rule::group => groups.push(Self::group(node)?),
-                Rule::static_group => {
-                    static_groups.push(Self::static_group(node)?)
-                }
-                _ => unreachable!(),
-            }
-        }
-        Ok((wires, groups, static_groups))
+    fn wires_for(input: Node) -> ParseResult<ast::ForLoop<ast::WiresDef>> {
+        let span = Self::get_span(&input);
+        let mut children = input.into_children();
+        let (var, start, end) = Self::for_range(children.next().unwrap())?;
+        let body = children.map(Self::wires_def).collect::<ParseResult<_>>()?;
+        Ok(ast::ForLoop {
+            var,
+            start,
+            end,
+            body,
+            attributes: Attributes::default().add_span(span),
+        })
+    }
+
+    fn connections(input: Node) -> ParseResult<Vec<ast::WiresDef>> {
+        input.into_children().map(Self::wires_def).collect()
     }
 
     // ================ Control program =====================
@@ -1045,6 +1226,22 @@ impl CalyxParser {
         ))
     }
 
+    fn for_stmt(input: Node) -> ParseResult<ast::Control> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [for_range((var, start, end)), stmt(stmt)..] => {
+                ast::Control::For(ast::ForLoop {
+                    var,
+                    start,
+                    end,
+                    body: stmt.collect(),
+                    attributes: Attributes::default().add_span(span),
+                })
+            }
+        ))
+    }
+
     fn stmt(input: Node) -> ParseResult<ast::Control> {
         Ok(match_nodes!(
             input.into_children();
@@ -1060,6 +1257,7 @@ impl CalyxParser {
             [static_if_stmt(data)] => data,
             [while_stmt(data)] => data,
             [repeat_stmt(data)] => data,
+            [for_stmt(data)] => data,
         ))
     }
 
@@ -1096,33 +1294,20 @@ impl CalyxParser {
             [
                 comb_or_static(cs_res),
                 name_with_attribute((name, attributes)),
-                signature(sig),
+                sig_with_params((params, (sig, bundle_ports))),
                 cells(cells),
-                connections(connections)
+                connections(wires)
             ] => {
                 if cs_res.is_some() {
                     Err(input.error("Static Component must have defined control"))?;
                 }
-                let (continuous_assignments, groups, static_groups) = connections;
-                let sig = sig.into_iter().map(|pd| {
-                    if let Width::Const { value } = pd.width {
-                        Ok(PortDef::new(
-                            pd.name(),
-                            value,
-                            pd.direction,
-                            pd.attributes
-                        ))
-                    } else {
-                        Err(input.error("Components cannot use parameters"))
-                    }
-                }).collect::<Result<_, _>>()?;
                 Ok(ComponentDef {
                     name,
+                    params,
                     signature: sig,
+                    bundle_ports,
                     cells,
-                    groups,
-                    static_groups,
-                    continuous_assignments,
+                    wires,
                     control: Control::empty(),
                     attributes: attributes.add_span(span),
                     is_comb: true,
@@ -1131,31 +1316,18 @@ impl CalyxParser {
             },
             [
                 name_with_attribute((name, attributes)),
-                signature(sig),
+                sig_with_params((params, (sig, bundle_ports))),
                 cells(cells),
-                connections(connections),
+                connections(wires),
                 control(control)
             ] => {
-                let (continuous_assignments, groups, static_groups) = connections;
-                let sig = sig.into_iter().map(|pd| {
-                    if let Width::Const { value } = pd.width {
-                        Ok(PortDef::new(
-                            pd.name(),
-                            value,
-                            pd.direction,
-                            pd.attributes
-                        ))
-                    } else {
-                        Err(input.error("Components cannot use parameters"))
-                    }
-                }).collect::<Result<_, _>>()?;
                 Ok(ComponentDef {
                     name,
+                    params,
                     signature: sig,
+                    bundle_ports,
                     cells,
-                    groups,
-                    static_groups,
-                    continuous_assignments,
+                    wires,
                     control,
                     attributes: attributes.add_span(span),
                     is_comb: false,
@@ -1165,31 +1337,18 @@ impl CalyxParser {
             [
                 comb_or_static(cs_res),
                 name_with_attribute((name, attributes)),
-                signature(sig),
+                sig_with_params((params, (sig, bundle_ports))),
                 cells(cells),
-                connections(connections),
+                connections(wires),
                 control(control),
             ] => {
-                let (continuous_assignments, groups, static_groups) = connections;
-                let sig = sig.into_iter().map(|pd| {
-                    if let Width::Const { value } = pd.width {
-                        Ok(PortDef::new(
-                            pd.name(),
-                            value,
-                            pd.direction,
-                            pd.attributes
-                        ))
-                    } else {
-                        Err(input.error("Components cannot use parameters"))
-                    }
-                }).collect::<Result<_, _>>()?;
                 Ok(ComponentDef {
                     name,
+                    params,
                     signature: sig,
+                    bundle_ports,
                     cells,
-                    groups,
-                    static_groups,
-                    continuous_assignments,
+                    wires,
                     control,
                     attributes: attributes.add_span(span),
                     is_comb: cs_res.is_none(),
@@ -1199,6 +1358,40 @@ impl CalyxParser {
         )
     }
 
+    // ================ Bundles =====================
+    fn flip(_input: Node) -> ParseResult<()> {
+        Ok(())
+    }
+
+    fn bundle_field(input: Node) -> ParseResult<PortDef<WidthExpr>> {
+        Ok(match_nodes!(
+            input.into_children();
+            [at_attributes(attrs), identifier(name), width_expr(width)] =>
+                PortDef::new(name, width, Direction::Input, attrs),
+            [at_attributes(attrs), flip(_), identifier(name), width_expr(width)] =>
+                PortDef::new(name, width, Direction::Output, attrs),
+        ))
+    }
+
+    fn bundle(input: Node) -> ParseResult<Bundle> {
+        let span = Self::get_span(&input);
+        Ok(match_nodes!(
+            input.into_children();
+            [name_with_attribute((name, attrs)), params(params), bundle_field(fields)..] => Bundle {
+                name,
+                params,
+                fields: fields.collect(),
+                attributes: attrs.add_span(span),
+            },
+            [name_with_attribute((name, attrs)), bundle_field(fields)..] => Bundle {
+                name,
+                params: Vec::new(),
+                fields: fields.collect(),
+                attributes: attrs.add_span(span),
+            },
+        ))
+    }
+
     fn imports(input: Node) -> ParseResult<Vec<String>> {
         Ok(match_nodes!(
             input.into_children();
@@ -1216,12 +1409,12 @@ impl CalyxParser {
     fn prim_inline(input: Node) -> ParseResult<Primitive> {
         let span = Self::get_span(&input);
         Ok(match_nodes!(
-            input.into_children();
+            input.clone().into_children();
             [name_with_attribute((name, attrs)), sig_with_params((p, s)), block_string(b)] => {
             Primitive {
                 name,
                 params: p,
-                signature: s,
+                signature: Self::primitive_signature(&input, s)?,
                 attributes: attrs.add_span(span),
                 is_comb: false,
                 latency: None,
@@ -1230,7 +1423,7 @@ impl CalyxParser {
             [comb_or_static(cs_res), name_with_attribute((name, attrs)), sig_with_params((p, s)), block_string(b)] => Primitive {
                 name,
                 params: p,
-                signature: s,
+                signature: Self::primitive_signature(&input, s)?,
                 attributes: attrs.add_span(span),
                 is_comb: cs_res.is_none(),
                 latency: cs_res,
@@ -1245,6 +1438,7 @@ impl CalyxParser {
             [component(comp)] => ExtOrComp::Comp(comp),
             [ext(ext)] => ExtOrComp::Ext(ext),
             [prim_inline(prim_inline)] => ExtOrComp::PrimInline(prim_inline),
+            [bundle(bundle)] => ExtOrComp::Bundle(bundle),
         ))
     }
 
@@ -1283,12 +1477,14 @@ impl CalyxParser {
                         imports,
                         components: Vec::new(),
                         externs: Vec::new(),
+                        bundles: Vec::new(),
                         metadata: if m != *"" { Some(m) } else { None }
                     };
                 for m in mixed {
                     match m {
                         ExtOrComp::Ext(ext) => namespace.externs.push(ext),
                         ExtOrComp::Comp(comp) => namespace.components.push(comp),
+                        ExtOrComp::Bundle(bundle) => namespace.bundles.push(bundle),
                         ExtOrComp::PrimInline(prim) => {
                             if let Some((_, prim_inlines)) = namespace.externs.iter_mut().find(|(filename, _)| filename.is_none()) {
                                 prim_inlines.push(prim)
@@ -1307,12 +1503,14 @@ impl CalyxParser {
                         imports,
                         components: Vec::new(),
                         externs: Vec::new(),
+                        bundles: Vec::new(),
                         metadata: None
                     };
                 for m in mixed {
                     match m {
                         ExtOrComp::Ext(ext) => namespace.externs.push(ext),
                         ExtOrComp::Comp(comp) => namespace.components.push(comp),
+                        ExtOrComp::Bundle(bundle) => namespace.bundles.push(bundle),
                         ExtOrComp::PrimInline(prim) => {
                             if let Some((_, prim_inlines)) = namespace.externs.iter_mut().find(|(filename, _)| filename.is_none()) {
                                 prim_inlines.push(prim)
@@ -2,7 +2,7 @@ use super::{
     ast::{ComponentDef, NamespaceDef},
     parser,
 };
-use crate::LibrarySignatures;
+use crate::{Bundle, LibrarySignatures};
 use calyx_utils::{CalyxResult, Error};
 use std::{
     collections::HashSet,
@@ -46,6 +46,8 @@ pub struct Workspace {
     pub declarations: Vec<ComponentDef>,
     /// Absolute path to extern definitions and primitives defined by them.
     pub lib: LibrarySignatures,
+    /// Bundle definitions from all the files.
+    pub bundles: Vec<Bundle>,
     /// Original import statements present in the top-level file.
     pub original_imports: Vec<String>,
     /// Optional opaque metadata attached to the top-level file
@@ -146,6 +148,22 @@ impl Workspace {
         )
     }
 
+    /// Construct the Workspace for the file at `file` using `source` as its contents instead of
+    /// reading it from disk. Imports are still read from disk.
+    pub fn construct_from_source(
+        file: &Path,
+        source: String,
+        lib_path: &Path,
+    ) -> CalyxResult<Self> {
+        let ns = parser::CalyxParser::parse_source(file, source)?;
+        Self::construct_from_namespace::<false>(
+            ns,
+            Self::get_parent(file),
+            vec![],
+            lib_path,
+        )
+    }
+
     /// Construct the Workspace using the given [NamespaceDef] and ignore all
     /// imported dependencies.
     pub fn construct_shallow(
@@ -213,6 +231,8 @@ impl Workspace {
             }
         }
 
+        self.bundles.extend(ns.bundles);
+
         // Add components defined by this namespace to either components or
         // declarations
         if !is_source && shallow {
@@ -247,7 +267,22 @@ impl Workspace {
             .as_ref()
             .map(|p| Self::get_parent(p))
             .unwrap_or_else(|| PathBuf::from("."));
+        Self::construct_from_namespace::<SHALLOW>(
+            ns,
+            parent_path,
+            files,
+            lib_path,
+        )
+    }
 
+    /// Construct the Workspace from the namespace of the first file, which lives in
+    /// `parent_path`, along with the other source `files` and all of their dependencies.
+    fn construct_from_namespace<const SHALLOW: bool>(
+        ns: NamespaceDef,
+        parent_path: PathBuf,
+        files: Vec<PathBuf>,
+        lib_path: &Path,
+    ) -> CalyxResult<Self> {
         // Set of current dependencies and whether they are considered source files.
         let mut dependencies: Vec<(PathBuf, bool)> =
             files.into_iter().map(|p| (p, true)).collect();
@@ -1,7 +1,7 @@
 use super::{
-    Assignment, Attribute, Attributes, BoolAttr, Builder, Cell, CellType,
-    CombGroup, Control, Direction, GetName, Group, Id, NumAttr, PortDef,
-    StaticGroup, RRC,
+    Assignment, Attribute, Attributes, BoolAttr, Builder, BundlePort, Cell,
+    CellType, CombGroup, Control, Direction, GetName, Group, Id, NumAttr,
+    PortDef, StaticGroup, RRC,
 };
 use crate::guard::StaticTiming;
 use crate::Nothing;
@@ -52,6 +52,10 @@ pub struct Component {
     pub is_comb: bool,
     /// (Optional) latency of component, if it is static
     pub latency: Option<NonZeroU64>,
+    /// Ports of the source signature whose type was a bundle. The printer
+    /// uses them to print the signature with the bundles.
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub bundle_ports: Vec<BundlePort>,
 
     ///// Internal structures
     /// Namegenerator that contains the names currently defined in this
@@ -141,6 +145,7 @@ impl Component {
             // converting from NonZeroU64 to u64. May want to keep permanently as NonZeroU64
             // in the future, but rn it's probably easier to keep as u64
             latency,
+            bundle_ports: vec![],
         }
     }
 
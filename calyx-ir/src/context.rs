@@ -1,8 +1,10 @@
 //! An IR context. This is the top-level object for an IR and contains all information
 //! need to transform, lower, an emit a program.
 //! Passes usually have transform/analyze the components in the IR.
-use super::{Component, Id};
+use super::{Bundle, Component, Id};
 use calyx_frontend::LibrarySignatures;
+use calyx_utils::Diagnostics;
+use std::path::PathBuf;
 
 /// Configuration information for the backends.
 #[derive(Default)]
@@ -16,6 +18,15 @@ pub struct BackendConf {
     /// [FIRRTL backend only] Emit extmodule declarations for primtives
     /// for use with SystemVerilog implementations
     pub emit_primitive_extmodules: bool,
+    /// [Resources backend only] Path to a TOML cost model used instead of
+    /// the default one
+    pub resource_model: Option<PathBuf>,
+    /// [Verilog backend only] Emit comments with the source location of
+    /// assignments
+    pub source_comments: bool,
+    /// [Verilog backend only] Path of a JSON file mapping the generated
+    /// signals to the source program
+    pub source_map: Option<PathBuf>,
 }
 
 /// The IR Context that represents an entire Calyx program with all of its
@@ -25,6 +36,8 @@ pub struct Context {
     pub components: Vec<Component>,
     /// Library definitions imported by the program.
     pub lib: LibrarySignatures,
+    /// Bundle definitions used by the signatures of the components.
+    pub bundles: Vec<Bundle>,
     /// Entrypoint for the program
     pub entrypoint: Id,
     /// Configuration flags for backends.
@@ -34,6 +47,13 @@ pub struct Context {
     pub extra_opts: Vec<String>,
     /// An optional opaque metadata string which is used by Cider
     pub metadata: Option<String>,
+    /// Names of the groups that assignments were defined in, indexed by the
+    /// [super::InternalAttr::GROUP] attribute of the assignments. Only recorded when
+    /// the backend emits source locations.
+    pub source_groups: Vec<Id>,
+    /// Errors and warnings found by the passes that check the program. The
+    /// errors are reported together once all of those passes have run.
+    pub diagnostics: Diagnostics,
 }
 
 impl Context {
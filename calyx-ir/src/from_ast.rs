@@ -1,13 +1,15 @@
 use super::{
-    Assignment, Attributes, BackendConf, Builder, Cell, CellType, Component,
-    Context, Control, Direction, GetAttributes, Guard, Id, Invoke,
-    LibrarySignatures, Port, PortDef, StaticControl, StaticInvoke,
+    Assignment, Attributes, BackendConf, Builder, Bundle, BundlePort, Cell,
+    CellType, Component, Context, Control, Direction, GetAttributes, Guard, Id,
+    Invoke, LibrarySignatures, Port, PortDef, StaticControl, StaticInvoke,
     RESERVED_NAMES, RRC,
 };
+use crate::monomorphize::monomorphize;
 use crate::{Nothing, PortComp, StaticTiming};
 use calyx_frontend::{ast, BoolAttr, NumAttr, Workspace};
-use calyx_utils::{CalyxResult, Error, GPosIdx, WithPos};
+use calyx_utils::{CalyxResult, Diagnostics, Error, GPosIdx, WithPos};
 use itertools::Itertools;
+use linked_hash_map::LinkedHashMap;
 
 use std::collections::{HashMap, HashSet};
 use std::num::NonZeroU64;
@@ -147,6 +149,97 @@ fn check_valid_port(
     Ok(())
 }
 
+/// Replace the ports of `comp` whose type is a bundle with a port for every
+/// field of the bundle. A port written `p: b` without arguments is a bundle
+/// port if `b` is a bundle and not a parameter of the component.
+fn flatten_bundles(
+    comp: &mut ast::ComponentDef,
+    bundles: &HashMap<Id, &Bundle>,
+) -> CalyxResult<()> {
+    let mut diag = Diagnostics::default();
+    let mut signature = Vec::with_capacity(comp.signature.len());
+    // The flattened ports, with the bundle port and field they come from
+    let mut flattened = vec![];
+    for pd in std::mem::take(&mut comp.signature) {
+        let name = pd.name();
+        let bundle = match &pd.width {
+            ast::WidthExpr::Param(b) => *b,
+            _ => {
+                signature.push(pd);
+                continue;
+            }
+        };
+        let idx = match comp.bundle_ports.iter().position(|bp| bp.name == name)
+        {
+            Some(idx) => idx,
+            None if bundles.contains_key(&bundle)
+                && !comp.params.contains(&bundle) =>
+            {
+                comp.bundle_ports.push(BundlePort {
+                    name,
+                    bundle,
+                    args: vec![],
+                    direction: pd.direction.clone(),
+                    fields: vec![],
+                });
+                comp.bundle_ports.len() - 1
+            }
+            None => {
+                signature.push(pd);
+                continue;
+            }
+        };
+        let bp = &mut comp.bundle_ports[idx];
+        let Some(def) = bundles.get(&bundle) else {
+            diag.err(
+                Error::undefined(bundle, "bundle".to_string())
+                    .with_pos(&comp.attributes),
+            );
+            continue;
+        };
+        match def.flatten(&pd, &bp.args) {
+            Ok(ports) => {
+                bp.fields = ports.iter().map(|pd| pd.name()).collect();
+                flattened.extend(
+                    bp.fields
+                        .iter()
+                        .zip(&def.fields)
+                        .map(|(port, field)| (*port, name, field.name())),
+                );
+                signature.extend(ports);
+            }
+            Err(err) => diag.err(err.with_pos(&comp.attributes)),
+        }
+    }
+    // A flattened port can have the same name as another port
+    let mut reported = HashSet::new();
+    for (port, bundle_port, field) in flattened {
+        let count = signature.iter().filter(|pd| pd.name() == port).count();
+        if count > 1 && reported.insert(port) {
+            let msg = format!(
+                "Port `{port}` for field `{field}` of bundle port `{bundle_port}` has the same name as another port of component `{}`",
+                comp.name
+            );
+            diag.err(
+                Error::malformed_structure(msg).with_pos(&comp.attributes),
+            );
+        }
+    }
+    comp.signature = signature;
+    diag.into_result()
+}
+
+/// The signature of a component whose widths are all known.
+fn resolve_signature(
+    comp: &ast::ComponentDef,
+) -> CalyxResult<Vec<PortDef<u64>>> {
+    comp.signature
+        .iter()
+        .map(|pd| pd.resolve(&LinkedHashMap::new()))
+        .collect::<CalyxResult<_>>()
+        .map_err(|err| err.with_pos(&comp.attributes))
+}
+
 /// Validates a component signature to make sure there are not duplicate ports.
 fn check_signature(pds: &[PortDef<u64>]) -> CalyxResult<()> {
     let mut ports: HashSet<Id> = HashSet::new();
@@ -177,7 +270,10 @@ fn check_signature(pds: &[PortDef<u64>]) -> CalyxResult<()> {
 }
 
 /// Construct an IR representation using a parsed AST and command line options.
-pub fn ast_to_ir(mut workspace: Workspace) -> CalyxResult<Context> {
+/// Reports every independent error in the program instead of stopping at the
+/// first one.
+pub fn ast_to_ir(workspace: Workspace) -> CalyxResult<Context> {
+    let mut diag = Diagnostics::default();
     let prims = workspace.lib.signatures().collect_vec();
     let mut all_names: HashSet<&Id> =
         HashSet::with_capacity(workspace.components.len() + prims.len());
@@ -188,13 +284,41 @@ pub fn ast_to_ir(mut workspace: Workspace) -> CalyxResult<Context> {
 
     for bound in prim_names.chain(comp_names) {
         if all_names.contains(bound) {
-            return Err(Error::already_bound(
+            diag.err(Error::already_bound(
                 *bound,
                 "component or primitive".to_string(),
             ));
         }
         all_names.insert(bound);
     }
+    let all_names = all_names.into_iter().copied().collect();
+
+    let mut bundles: HashMap<Id, &Bundle> =
+        HashMap::with_capacity(workspace.bundles.len());
+    for bundle in &workspace.bundles {
+        if let Some(prev) = bundles.insert(bundle.name, bundle) {
+            let prev = prev
+                .attributes
+                .copy_span()
+                .into_option()
+                .map(|s| s.format("Previous definition"));
+            diag.err(
+                Error::already_bound(bundle.name, "bundle".to_string())
+                    .with_pos(&bundle.attributes)
+                    .with_post_msg(prev),
+            );
+        }
+    }
+    let mut components = workspace.components;
+    let mut declarations = workspace.declarations;
+    for comp in components.iter_mut().chain(declarations.iter_mut()) {
+        diag.accumulate(flatten_bundles(comp, &bundles));
+    }
+    diag.take_errors()?;
+
+    // Instantiate generic components so that every width is known
+    let (components, declarations) =
+        monomorphize(components, declarations, all_names)?;
 
     // Build the signature context
     let mut sig_ctx = SigCtx {
@@ -203,28 +327,24 @@ pub fn ast_to_ir(mut workspace: Workspace) -> CalyxResult<Context> {
     };
 
     // Add declarations to context
-    for comp in workspace
-        .declarations
-        .iter_mut()
-        .chain(workspace.components.iter_mut())
-    {
-        let sig = &mut comp.signature;
-        check_signature(&*sig)?;
+    for comp in declarations.iter().chain(components.iter()) {
+        let Some(mut sig) = diag.accumulate(resolve_signature(comp)) else {
+            continue;
+        };
+        diag.accumulate(check_signature(&sig));
         // extend the signature if the component does not have the @nointerface attribute.
         if !comp.attributes.has(BoolAttr::NoInterface) && !comp.is_comb {
-            Component::extend_signature(sig);
+            Component::extend_signature(&mut sig);
         }
-        sig_ctx
-            .comp_sigs
-            .insert(comp.name, (sig.clone(), comp.latency));
+        sig_ctx.comp_sigs.insert(comp.name, (sig, comp.latency));
     }
 
     // building components from `ast::ComponentDef`s to `ir::Component`
-    let comps: Vec<Component> = workspace
-        .components
+    let comps: Vec<Component> = components
         .into_iter()
-        .map(|comp| build_component(comp, &mut sig_ctx))
-        .collect::<Result<_, _>>()?;
+        .filter_map(|comp| diag.accumulate(build_component(comp, &mut sig_ctx)))
+        .collect();
+    diag.take_errors()?;
 
     // Find the entrypoint for the program.
     let entrypoint = comps
@@ -237,10 +357,13 @@ pub fn ast_to_ir(mut workspace: Workspace) -> CalyxResult<Context> {
     Ok(Context {
         components: comps,
         lib: sig_ctx.lib,
+        bundles: workspace.bundles,
         bc: BackendConf::default(),
         entrypoint,
         extra_opts: vec![],
         metadata: workspace.metadata,
+        source_groups: vec![],
+        diagnostics: Diagnostics::default(),
     })
 }
 
@@ -248,17 +371,24 @@ fn validate_component(
     comp: &ast::ComponentDef,
     sig_ctx: &SigCtx,
 ) -> CalyxResult<()> {
+    let mut diag = Diagnostics::default();
     let mut cells: HashMap<Id, GPosIdx> = HashMap::new();
     let mut groups: HashMap<Id, GPosIdx> = HashMap::new();
 
-    for cell in &comp.cells {
+    for def in &comp.cells {
+        let ast::CellDef::Cell(cell) = def else {
+            unreachable!("for loops are elaborated before building the IR")
+        };
         let attrs = &cell.attributes;
         if let Some(pos) = cells.get(&cell.name) {
             let prev =
                 pos.into_option().map(|s| s.format("Previous definition"));
-            return Err(Error::already_bound(cell.name, "cell".to_string())
-                .with_pos(attrs)
-                .with_post_msg(prev));
+            diag.err(
+                Error::already_bound(cell.name, "cell".to_string())
+                    .with_pos(attrs)
+                    .with_post_msg(prev),
+            );
+            continue;
         }
         cells.insert(cell.name, cell.attributes.copy_span());
 
@@ -267,42 +397,46 @@ fn validate_component(
         if sig_ctx.lib.find_primitive(proto_name).is_none()
             && !sig_ctx.comp_sigs.contains_key(&proto_name)
         {
-            return Err(Error::undefined(
-                proto_name,
-                "primitive or component".to_string(),
-            )
-            .with_pos(attrs));
+            diag.err(
+                Error::undefined(
+                    proto_name,
+                    "primitive or component".to_string(),
+                )
+                .with_pos(attrs),
+            );
         }
     }
 
-    for group in &comp.groups {
+    let comp_groups = comp.wires.iter().filter_map(|def| match def {
+        ast::WiresDef::Group(group) => Some(group),
+        _ => None,
+    });
+    for group in comp_groups {
         let name = &group.name;
         let attrs = &group.attributes;
         if let Some(pos) = groups.get(name) {
             let prev =
                 pos.into_option().map(|s| s.format("Previous definition"));
-            return Err(Error::already_bound(*name, "group".to_string())
-                .with_pos(attrs)
-                .with_post_msg(prev));
-        }
-        if let Some(pos) = cells.get(name) {
-            let prev =
-                pos.into_option().map(|s| s.format("Previous definition"));
-            return Err(Error::already_bound(*name, "cell".to_string())
-                .with_pos(attrs)
-                .with_post_msg(prev));
+            diag.err(
+                Error::already_bound(*name, "group".to_string())
+                    .with_pos(attrs)
+                    .with_post_msg(prev),
+            );
+            continue;
         }
         if let Some(pos) = cells.get(name) {
             let prev =
                 pos.into_option().map(|s| s.format("Previous definition"));
-            return Err(Error::already_bound(*name, "cell".to_string())
-                .with_pos(attrs)
-                .with_post_msg(prev));
+            diag.err(
+                Error::already_bound(*name, "cell".to_string())
+                    .with_pos(attrs)
+                    .with_post_msg(prev),
+            );
         }
         groups.insert(*name, group.attributes.copy_span());
     }
 
-    Ok(())
+    diag.into_result()
 }
 
 /// Build an `ir::component::Component` using an `frontend::ast::ComponentDef`.
@@ -310,12 +444,14 @@ fn build_component(
     comp: ast::ComponentDef,
     sig_ctx: &mut SigCtx,
 ) -> CalyxResult<Component> {
-    // Validate the component before building it.
+    // Validate the component before building it. Everything else refers to
+    // the cells and groups by name, so stop if their definitions are broken.
     validate_component(&comp, sig_ctx)?;
+    let mut diag = Diagnostics::default();
 
     let mut ir_component = Component::new(
         comp.name,
-        comp.signature,
+        sig_ctx.comp_sigs[&comp.name].0.clone(),
         !comp.attributes.has(BoolAttr::NoInterface) && !comp.is_comb,
         comp.is_comb,
         // we may change latency from None to Some(inferred latency)
@@ -328,29 +464,54 @@ fn build_component(
 
     // For each ast::Cell, add a Cell that contains all the
     // required information.
-    comp.cells
-        .into_iter()
-        .try_for_each(|cell| add_cell(cell, sig_ctx, &mut builder))?;
+    for def in comp.cells {
+        let ast::CellDef::Cell(cell) = def else {
+            unreachable!("for loops are elaborated before building the IR")
+        };
+        diag.accumulate(add_cell(cell, sig_ctx, &mut builder));
+    }
+    diag.take_errors()?;
+
+    let mut groups = Vec::new();
+    let mut static_groups = Vec::new();
+    let mut continuous_assignments = Vec::new();
+    for def in comp.wires {
+        match def {
+            ast::WiresDef::Group(g) => groups.push(g),
+            ast::WiresDef::StaticGroup(g) => static_groups.push(g),
+            ast::WiresDef::Wire(wire) => continuous_assignments.push(wire),
+            ast::WiresDef::For(_) => {
+                unreachable!("for loops are elaborated before building the IR")
+            }
+        }
+    }
 
-    comp.groups
-        .into_iter()
-        .try_for_each(|g| add_group(g, &mut builder))?;
+    // Groups, assignments, and the control program can be checked
+    // independently of each other.
+    for g in groups {
+        diag.accumulate(add_group(g, &mut builder));
+    }
 
-    comp.static_groups
-        .into_iter()
-        .try_for_each(|g| add_static_group(g, &mut builder))?;
+    for g in static_groups {
+        diag.accumulate(add_static_group(g, &mut builder));
+    }
 
-    let continuous_assignments =
-        build_assignments(comp.continuous_assignments, &mut builder)?;
-    builder.component.continuous_assignments = continuous_assignments;
+    if let Some(continuous_assignments) =
+        diag.accumulate(build_assignments(continuous_assignments, &mut builder))
+    {
+        builder.component.continuous_assignments = continuous_assignments;
+    }
 
     // Build the Control ast using ast::Control.
-    let control =
-        super::rrc(build_control(comp.control, sig_ctx, &mut builder)?);
-
-    builder.component.control = control;
+    if let Some(control) =
+        diag.accumulate(build_control(comp.control, sig_ctx, &mut builder))
+    {
+        builder.component.control = super::rrc(control);
+    }
+    diag.into_result()?;
 
     ir_component.attributes = comp.attributes;
+    ir_component.bundle_ports = comp.bundle_ports;
 
     // Add reserved names to the component's namegenerator so future conflicts
     // don't occur
@@ -370,8 +531,15 @@ fn add_cell(
     let proto_name = cell.prototype.name;
 
     let res = if sig_ctx.lib.find_primitive(proto_name).is_some() {
+        let params = cell
+            .prototype
+            .params
+            .iter()
+            .map(|param| param.eval(&LinkedHashMap::new()))
+            .collect::<CalyxResult<Vec<_>>>()
+            .map_err(|e| e.with_pos(&cell.attributes))?;
         let c = builder
-            .try_add_primitive(cell.name, proto_name, &cell.prototype.params)
+            .try_add_primitive(cell.name, proto_name, &params)
             .map_err(|e| e.with_pos(&cell.attributes))?;
         c.borrow_mut().set_reference(cell.reference);
         c
@@ -569,31 +737,44 @@ fn build_static_assignment(
     Ok(assign)
 }
 
+/// Build all the assignments, reporting every one that is malformed.
 fn build_assignments(
     assigns: Vec<ast::Wire>,
     builder: &mut Builder,
 ) -> CalyxResult<Vec<Assignment<Nothing>>> {
-    assigns
+    let mut diag = Diagnostics::default();
+    let assigns = assigns
         .into_iter()
-        .map(|w| {
+        .filter_map(|w| {
             let attrs = w.attributes.clone();
-            build_assignment(w, builder).map_err(|err| err.with_pos(&attrs))
+            diag.accumulate(
+                build_assignment(w, builder)
+                    .map_err(|err| err.with_pos(&attrs)),
+            )
         })
-        .collect::<CalyxResult<Vec<_>>>()
+        .collect();
+    diag.into_result()?;
+    Ok(assigns)
 }
 
+/// Build all the static assignments, reporting every one that is malformed.
 fn build_static_assignments(
     assigns: Vec<ast::StaticWire>,
     builder: &mut Builder,
 ) -> CalyxResult<Vec<Assignment<StaticTiming>>> {
-    assigns
+    let mut diag = Diagnostics::default();
+    let assigns = assigns
         .into_iter()
-        .map(|w| {
+        .filter_map(|w| {
             let attrs = w.attributes.clone();
-            build_static_assignment(w, builder)
-                .map_err(|err| err.with_pos(&attrs))
+            diag.accumulate(
+                build_static_assignment(w, builder)
+                    .map_err(|err| err.with_pos(&attrs)),
+            )
         })
-        .collect::<CalyxResult<Vec<_>>>()
+        .collect();
+    diag.into_result()?;
+    Ok(assigns)
 }
 
 /// Transform an ast::GuardExpr to an ir::Guard.
@@ -952,6 +1133,9 @@ fn build_static_control(
             *emp.get_mut_attributes() = attributes;
             emp
         }
+        ast::Control::For(_) => {
+            unreachable!("for loops are elaborated before building the IR")
+        }
     };
     Ok(sc)
 }
@@ -1222,6 +1406,9 @@ fn build_control(
             );
             Control::Static(s?)
         }
+        ast::Control::For(_) => {
+            unreachable!("for loops are elaborated before building the IR")
+        }
         ast::Control::Par { stmts, attributes } => {
             let mut p = Control::par(
                 stmts
@@ -44,14 +44,17 @@ pub use structure::{
 
 // Re-export types from the frontend.
 pub use calyx_frontend::{
-    Attribute, Attributes, BoolAttr, Direction, GetAttributes, InternalAttr,
-    LibrarySignatures, NumAttr, PortDef, Primitive, PrimitiveInfo, Width,
-    DEPRECATED_ATTRIBUTES,
+    Attribute, Attributes, BoolAttr, Bundle, BundlePort, Direction,
+    GetAttributes, InternalAttr, LibrarySignatures, NumAttr, PortDef,
+    Primitive, PrimitiveInfo, Width, DEPRECATED_ATTRIBUTES,
 };
 
 /// Module to transform AST programs into IR.
 pub mod from_ast;
 
+/// Instantiation of generic components.
+mod monomorphize;
+
 /// Convinience macros for constructing IR nodes.
 mod macros;
 
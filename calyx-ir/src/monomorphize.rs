@@ -0,0 +1,243 @@
+//! Instantiation of generic components.
+//!
+//! A generic component like `component add[W](left: W, right: W) -> (out: W)`
+//! cannot be turned into an IR component because its widths depend on how it
+//! is used. Monomorphization creates a copy of the component for every set of
+//! parameters it is instantiated with, named after the parameters (`add_32`
+//! for `add(32)`), and points the cells at the copies.
+use calyx_frontend::{ast, Attributes, BoolAttr};
+use calyx_utils::{CalyxResult, Diagnostics, Error, GPosIdx, Id, WithPos};
+use itertools::Itertools;
+use linked_hash_map::LinkedHashMap;
+use std::collections::{HashMap, HashSet};
+
+struct Monomorphizer {
+    /// The generic components, by name
+    generics: HashMap<Id, ast::ComponentDef>,
+    /// The instance created for every use of a generic component
+    instances: HashMap<(Id, Vec<u64>), Id>,
+    /// Names of all the components and primitives
+    names: HashSet<Id>,
+    /// Generic components that are currently being instantiated
+    stack: Vec<Id>,
+    /// The components to compile, in the order they were instantiated
+    out: Vec<ast::ComponentDef>,
+}
+
+impl Monomorphizer {
+    /// Compute the widths of `comp` using the values of its parameters and
+    /// instantiate the generic components it uses. `site` is the cell that
+    /// instantiates `comp` if it is generic.
+    fn resolve(
+        &mut self,
+        mut comp: ast::ComponentDef,
+        bindings: &LinkedHashMap<Id, u64>,
+        site: GPosIdx,
+    ) -> CalyxResult<ast::ComponentDef> {
+        let mut diag = Diagnostics::default();
+        let site = site.into_option();
+        let instantiated_here = |err: Error, pos: &Attributes| {
+            err.with_pos(pos).with_post_msg(
+                site.map(|site| site.format("Instantiated here")),
+            )
+        };
+        comp.elaborate(bindings).map_err(|err| {
+            Error::multiple(
+                err.into_errors()
+                    .into_iter()
+                    .map(|err| {
+                        err.with_post_msg(
+                            site.map(|site| site.format("Instantiated here")),
+                        )
+                    })
+                    .collect(),
+            )
+        })?;
+        for pd in &mut comp.signature {
+            match pd.width.eval(bindings) {
+                Ok(width) => pd.width = width.into(),
+                Err(err) => diag.err(instantiated_here(err, &comp.attributes)),
+            }
+        }
+        // errors in the arguments of a bundle are reported for the ports it
+        // was flattened into
+        for bp in &mut comp.bundle_ports {
+            if let Ok(args) = bp
+                .args
+                .iter()
+                .map(|arg| arg.eval(bindings))
+                .collect::<CalyxResult<Vec<_>>>()
+            {
+                bp.args = args.into_iter().map(ast::WidthExpr::from).collect();
+            }
+        }
+        for def in &mut comp.cells {
+            let ast::CellDef::Cell(cell) = def else {
+                unreachable!("for loops were elaborated above")
+            };
+            let params = match cell
+                .prototype
+                .params
+                .iter()
+                .map(|param| param.eval(bindings))
+                .collect::<CalyxResult<Vec<_>>>()
+            {
+                Ok(params) => params,
+                Err(err) => {
+                    diag.err(instantiated_here(err, &cell.attributes));
+                    continue;
+                }
+            };
+            if self.generics.contains_key(&cell.prototype.name) {
+                let instance =
+                    self.instantiate(cell.prototype.name, params, cell);
+                if let Some(instance) = diag.accumulate(instance) {
+                    cell.prototype.name = instance;
+                    cell.prototype.params = vec![];
+                }
+            } else {
+                cell.prototype.params =
+                    params.into_iter().map(ast::WidthExpr::from).collect();
+            }
+        }
+        diag.into_result()?;
+        Ok(comp)
+    }
+
+    /// Return the name of the instance of the generic component `name` with
+    /// the parameters `params`, creating it if needed.
+    fn instantiate(
+        &mut self,
+        name: Id,
+        params: Vec<u64>,
+        cell: &ast::Cell,
+    ) -> CalyxResult<Id> {
+        if self.stack.contains(&name) {
+            let msg = format!("component `{name}` instantiates itself");
+            return Err(
+                Error::malformed_structure(msg).with_pos(&cell.attributes)
+            );
+        }
+        let key = (name, params);
+        if let Some(instance) = self.instances.get(&key) {
+            return Ok(*instance);
+        }
+        let (name, params) = key;
+
+        let generic = &self.generics[&name];
+        if generic.params.len() != params.len() {
+            let msg = format!(
+                "component `{}` requires {} parameters but instantiation provides {} parameters",
+                name,
+                generic.params.len(),
+                params.len(),
+            );
+            return Err(
+                Error::malformed_structure(msg).with_pos(&cell.attributes)
+            );
+        }
+
+        let instance = Id::from(format!("{name}_{}", params.iter().join("_")));
+        if !self.names.insert(instance) {
+            return Err(Error::already_bound(
+                instance,
+                "component or primitive".to_string(),
+            )
+            .with_pos(&cell.attributes)
+            .with_post_msg(Some(format!(
+                "The instance of `{name}` with these parameters is named `{instance}`"
+            ))));
+        }
+
+        let bindings = generic
+            .params
+            .iter()
+            .copied()
+            .zip(params.iter().copied())
+            .collect::<LinkedHashMap<_, _>>();
+        let mut comp = generic.clone();
+        comp.name = instance;
+        comp.params = vec![];
+
+        self.stack.push(name);
+        let comp = self.resolve(comp, &bindings, cell.attributes.copy_span());
+        self.stack.pop();
+        let comp = match comp {
+            Ok(comp) => comp,
+            Err(err) => {
+                // Other uses with the same parameters report the error again.
+                self.names.remove(&instance);
+                return Err(err);
+            }
+        };
+        self.instances.insert((name, params), instance);
+        self.out.push(comp);
+        Ok(instance)
+    }
+}
+
+/// Instantiate the generic components used by `components` and compute all
+/// widths. Returns the components to compile, including the instances, and
+/// the declarations. Neither of them contains generic components.
+///
+/// `names` are the names of all the components and primitives.
+pub(crate) fn monomorphize(
+    components: Vec<ast::ComponentDef>,
+    declarations: Vec<ast::ComponentDef>,
+    names: HashSet<Id>,
+) -> CalyxResult<(Vec<ast::ComponentDef>, Vec<ast::ComponentDef>)> {
+    let (generic_comps, components): (Vec<_>, Vec<_>) = components
+        .into_iter()
+        .partition(|comp| !comp.params.is_empty());
+    let (generic_decls, declarations): (Vec<_>, Vec<_>) = declarations
+        .into_iter()
+        .partition(|comp| !comp.params.is_empty());
+
+    if let Some(entry) = generic_comps.iter().find(|comp| {
+        comp.name == "main" || comp.attributes.has(BoolAttr::TopLevel)
+    }) {
+        return Err(Error::malformed_structure(format!(
+            "entrypoint `{}` cannot have parameters",
+            entry.name
+        ))
+        .with_pos(&entry.attributes));
+    }
+
+    let mut mono = Monomorphizer {
+        generics: generic_comps
+            .into_iter()
+            .chain(generic_decls)
+            .map(|comp| (comp.name, comp))
+            .collect(),
+        instances: HashMap::new(),
+        names,
+        stack: vec![],
+        out: vec![],
+    };
+    let mut diag = Diagnostics::default();
+    let no_params = LinkedHashMap::new();
+    for comp in components {
+        let comp = mono.resolve(comp, &no_params, GPosIdx::UNKNOWN);
+        if let Some(comp) = diag.accumulate(comp) {
+            mono.out.push(comp);
+        }
+    }
+    diag.into_result()?;
+
+    // Declarations are not compiled so only their signatures matter.
+    let declarations = declarations
+        .into_iter()
+        .map(|mut comp| {
+            for pd in &mut comp.signature {
+                let width = pd
+                    .width
+                    .eval(&no_params)
+                    .map_err(|err| err.with_pos(&comp.attributes))?;
+                pd.width = width.into();
+            }
+            Ok(comp)
+        })
+        .collect::<CalyxResult<_>>()?;
+
+    Ok((mono.out, declarations))
+}
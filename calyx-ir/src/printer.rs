@@ -4,6 +4,7 @@
 use crate::{self as ir, RRC};
 use calyx_frontend::PrimitiveInfo;
 use itertools::Itertools;
+use std::collections::HashSet;
 use std::io;
 use std::path::Path;
 use std::rc::Rc;
@@ -59,6 +60,48 @@ impl Printer {
             .join(", ")
     }
 
+    /// Formats the ports of a component signature, replacing the ports that
+    /// a bundle port was flattened into with the bundle port. A bundle port
+    /// is printed with the `direction` it was declared with, even if all its
+    /// fields are flipped.
+    fn format_sig_ports(
+        ports: &[RRC<ir::Port>],
+        bundle_ports: &[&ir::BundlePort],
+        direction: ir::Direction,
+    ) -> String {
+        let bundle_str = |bp: &ir::BundlePort| {
+            if bp.args.is_empty() {
+                format!("{}: {}", bp.name, bp.bundle)
+            } else {
+                format!(
+                    "{}: {}({})",
+                    bp.name,
+                    bp.bundle,
+                    bp.args.iter().map(|arg| arg.to_string()).join(", ")
+                )
+            }
+        };
+        let mut printed = HashSet::new();
+        let mut out = vec![];
+        for p in ports {
+            let name = p.borrow().name;
+            match bundle_ports.iter().find(|bp| bp.fields.contains(&name)) {
+                Some(bp) => {
+                    if bp.direction == direction && printed.insert(bp.name) {
+                        out.push(bundle_str(bp));
+                    }
+                }
+                None => out.push(Self::format_ports(&[Rc::clone(p)])),
+            }
+        }
+        for bp in bundle_ports {
+            if bp.direction == direction && printed.insert(bp.name) {
+                out.push(bundle_str(bp));
+            }
+        }
+        out.join(", ")
+    }
+
     pub fn format_port_def<W: std::fmt::Display>(
         port_defs: &[&ir::PortDef<W>],
     ) -> String {
@@ -102,6 +145,10 @@ impl Printer {
             }
         }
 
+        for bundle in &ctx.bundles {
+            ir::Printer::write_bundle(bundle, f)?;
+        }
+
         for comp in &ctx.components {
             ir::Printer::write_component(comp, f)?;
             writeln!(f)?
@@ -177,6 +224,38 @@ impl Printer {
         }
     }
 
+    /// Formats and writes a bundle definition.
+    pub fn write_bundle<F: io::Write>(
+        bundle: &ir::Bundle,
+        f: &mut F,
+    ) -> io::Result<()> {
+        write!(
+            f,
+            "bundle {}{}",
+            bundle.name,
+            Self::format_attributes(&bundle.attributes)
+        )?;
+        if !bundle.params.is_empty() {
+            write!(f, "[{}]", bundle.params.iter().join(", "))?;
+        }
+        writeln!(f, " {{")?;
+        for field in &bundle.fields {
+            let flip = if field.direction == ir::Direction::Output {
+                "flip "
+            } else {
+                ""
+            };
+            writeln!(
+                f,
+                "  {}{flip}{}: {};",
+                Self::format_at_attributes(&field.attributes),
+                field.name(),
+                field.width
+            )?;
+        }
+        writeln!(f, "}}")
+    }
+
     /// Formats and writes the Component to the formatter.
     pub fn write_component<F: io::Write>(
         comp: &ir::Component,
@@ -189,6 +268,13 @@ impl Printer {
                 matches!(p.borrow().direction, ir::Direction::Output)
             });
 
+        // bundle ports whose fields are all still in the signature
+        let bundle_ports = comp
+            .bundle_ports
+            .iter()
+            .filter(|bp| bp.fields.iter().all(|f| sig.find(*f).is_some()))
+            .collect_vec();
+
         let pre = if comp.is_comb {
             "comb ".to_string()
         } else if comp.latency.is_some() {
@@ -203,8 +289,16 @@ impl Printer {
             pre,
             comp.name.id,
             Self::format_attributes(&comp.attributes),
-            Self::format_ports(&inputs),
-            Self::format_ports(&outputs),
+            Self::format_sig_ports(
+                &inputs,
+                &bundle_ports,
+                ir::Direction::Input
+            ),
+            Self::format_sig_ports(
+                &outputs,
+                &bundle_ports,
+                ir::Direction::Output
+            ),
         )?;
 
         // Add the cells
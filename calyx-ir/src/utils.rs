@@ -25,6 +25,8 @@ pub fn external_memories_cells(comp: &Component) -> Vec<RRC<Cell>> {
 pub struct MemInfo {
     pub width: u64,
     pub size: u64,
+    //size of each dimension, outermost first
+    pub dimension_sizes: Vec<u64>,
     //idx port width, in case size is ambiguous
     pub idx_sizes: Vec<u64>,
 }
@@ -40,46 +42,35 @@ impl GetMemInfo for Vec<RRC<Cell>> {
         self.iter()
               .map(|cr| {
                   let mem = cr.borrow();
-                  let mem_size: u64;
                   let mut idx_sizes: Vec<u64> = Vec::new();
-                  let idx_count: u64;
-                  match mem.prototype.get_name().unwrap().as_ref() {
+                  let dimension_sizes: Vec<u64> = match mem.prototype.get_name().unwrap().as_ref() {
                       "comb_mem_d1" | "seq_mem_d1" => {
-                        mem_size = mem.get_parameter("SIZE").unwrap();
-                        idx_count = 1;
+                        vec![mem.get_parameter("SIZE").unwrap()]
                       }
                       "comb_mem_d2" | "seq_mem_d2" => {
-                        mem_size = mem.get_parameter("D0_SIZE").unwrap()
-                              * mem.get_parameter("D1_SIZE").unwrap();
-                        idx_count = 2;
-                    }
-                    "comb_mem_d3" | "seq_mem_d3" => {
-                        mem_size = mem.get_parameter("D0_SIZE").unwrap()
-                        * mem.get_parameter("D1_SIZE").unwrap()
-                        * mem.get_parameter("D2_SIZE").unwrap();
-                        idx_count = 3;
-                    }
-                    "comb_mem_d4" | "seq_mem_d4" => {
-                        mem_size = mem.get_parameter("D0_SIZE").unwrap()
-                        * mem.get_parameter("D1_SIZE").unwrap()
-                        * mem.get_parameter("D2_SIZE").unwrap()
-                        * mem.get_parameter("D3_SIZE").unwrap();
-                        idx_count = 4;
+                        (0..2).map(|i| mem.get_parameter(format!("D{}_SIZE", i)).unwrap()).collect()
+                      }
+                      "comb_mem_d3" | "seq_mem_d3" => {
+                        (0..3).map(|i| mem.get_parameter(format!("D{}_SIZE", i)).unwrap()).collect()
+                      }
+                      "comb_mem_d4" | "seq_mem_d4" => {
+                        (0..4).map(|i| mem.get_parameter(format!("D{}_SIZE", i)).unwrap()).collect()
                       }
                       _ => {
                           panic!("cell `{}' marked with `@external' but is not a memory primitive.", mem.name())
                       }
                   };
-                  if idx_count == 1 {
+                  if dimension_sizes.len() == 1 {
                     idx_sizes.push(mem.get_parameter("IDX_SIZE").unwrap());
                   } else {
-                    for i in 1..idx_count {
+                    for i in 0..dimension_sizes.len() {
                         idx_sizes.push(mem.get_parameter(format!("D{}_IDX_SIZE",i)).unwrap());
                     }
                   }
                   MemInfo {
                       width: mem.get_parameter("WIDTH").unwrap(),
-                      size: mem_size,
+                      size: dimension_sizes.iter().product(),
+                      dimension_sizes,
                       idx_sizes
                   }
               })
@@ -92,3 +83,35 @@ impl GetMemInfo for Component {
         external_memories_cells(self).get_mem_info()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rrc, CellType, Id};
+
+    #[test]
+    fn mem_info_has_every_index_size() {
+        let param_binding = [
+            ("WIDTH", 32),
+            ("D0_SIZE", 8),
+            ("D1_SIZE", 4),
+            ("D0_IDX_SIZE", 3),
+            ("D1_IDX_SIZE", 2),
+        ]
+        .into_iter()
+        .map(|(param, val)| (Id::from(param), val))
+        .collect();
+        let mem = Cell::new(
+            Id::from("mem"),
+            CellType::Primitive {
+                name: Id::from("comb_mem_d2"),
+                param_binding: Box::new(param_binding),
+                is_comb: false,
+                latency: None,
+            },
+        );
+        let info = vec![rrc(mem)].get_mem_info();
+        assert_eq!(info[0].size, 32);
+        assert_eq!(info[0].idx_sizes, vec![3, 2]);
+    }
+}
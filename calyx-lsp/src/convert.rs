@@ -26,6 +26,14 @@ impl Point {
     pub fn zero() -> Self {
         Self(ts::Point { row: 0, column: 0 })
     }
+
+    /// Translate a `byte_offset` into `text` into a `Point`.
+    pub fn from_offset(text: &str, byte_offset: usize) -> Option<Self> {
+        let before = text.get(..byte_offset)?;
+        let row = before.matches('\n').count();
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Some(Self::new(row, byte_offset - line_start))
+    }
 }
 
 impl From<Point> for ts::Point {
@@ -68,7 +76,7 @@ impl PartialOrd<Point> for Point {
 }
 
 /// Crate local Range representing a region between two points
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Range {
     start: Point,
     end: Point,
@@ -1,38 +1,417 @@
-use std::path::Path;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 
+use calyx_ir::{self as ir, GetAttributes};
+use calyx_opt::passes::{
+    Canonicalize, Papercut, StaticInference, SynthesisPapercut, WellFormed,
+};
+use calyx_opt::traversal::Visitor;
+use calyx_utils::{CalyxResult, Error, Warning, WithPos};
 use resolve_path::PathResolveExt;
+use serde::{Deserialize, Serialize};
+use tower_lsp::lsp_types as lspt;
+
+use crate::convert::{Point, Range};
 
 pub struct Diagnostic;
 
-/// A Calyx error message
-#[derive(Debug)]
+/// A Calyx error or warning message
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CalyxError {
-    #[allow(unused)]
     pub file_name: String,
     pub pos_start: usize,
     pub pos_end: usize,
     pub msg: String,
+    pub severity: lspt::DiagnosticSeverity,
 }
 
-impl Diagnostic {
-    /// Run the `calyx` compiler on `path` with libraries at `lib_path`
-    pub fn did_save(path: &Path, lib_path: &Path) -> Vec<CalyxError> {
-        calyx_frontend::Workspace::construct(
-            &Some(path.to_path_buf()),
-            lib_path.resolve().as_ref(),
-        )
-        .and_then(calyx_ir::from_ast::ast_to_ir)
-        // TODO: call well-formed pass
-        .map(|_| vec![])
-        .unwrap_or_else(|e| {
-            let (file_name, pos_start, pos_end) = e.location();
-            let msg = e.message();
-            vec![CalyxError {
+impl CalyxError {
+    /// One diagnostic for each of the errors that make up `err`.
+    fn from_error(
+        err: Error,
+        severity: lspt::DiagnosticSeverity,
+    ) -> impl Iterator<Item = Self> {
+        err.into_errors().into_iter().map(move |err| {
+            let (file_name, pos_start, pos_end) = err.location();
+            Self {
                 file_name: file_name.to_string(),
                 pos_start,
                 pos_end,
-                msg,
-            }]
+                msg: err.message(),
+                severity,
+            }
+        })
+    }
+
+    fn from_warning(warning: &Warning) -> Self {
+        let (file_name, pos_start, pos_end) = warning.location();
+        Self {
+            file_name: file_name.to_string(),
+            pos_start,
+            pos_end,
+            msg: warning.message(),
+            severity: lspt::DiagnosticSeverity::WARNING,
+        }
+    }
+
+    /// Convert to an LSP diagnostic for the file at `path` with contents
+    /// `text`. Messages about other files, or without a position, go at the
+    /// start of the file.
+    fn into_lsp(self, path: &Path, text: &str) -> lspt::Diagnostic {
+        let located = if Path::new(&self.file_name) == path {
+            Point::from_offset(text, self.pos_start).and_then(|start| {
+                Point::from_offset(text, self.pos_end)
+                    .map(|end| Range::new(start, end))
+            })
+        } else {
+            None
+        };
+        let (range, message) = match located {
+            Some(range) => (range, self.msg),
+            None if self.file_name == "unknown" => (Range::zero(), self.msg),
+            None => {
+                (Range::zero(), format!("{}: {}", self.file_name, self.msg))
+            }
+        };
+        lsp_diagnostic(range, self.severity, message)
+    }
+}
+
+fn lsp_diagnostic(
+    range: Range,
+    severity: lspt::DiagnosticSeverity,
+    message: String,
+) -> lspt::Diagnostic {
+    lspt::Diagnostic {
+        range: range.into(),
+        severity: Some(severity),
+        code: None,
+        code_description: None,
+        source: Some("calyx".to_string()),
+        message,
+        related_information: None,
+        tags: None,
+        data: None,
+    }
+}
+
+/// The argument that makes the server check a single program instead, see
+/// [Diagnostic::check_in_child].
+pub const CHECK_ARG: &str = "--check";
+
+/// A latency that the compiler inferred for a group or control statement
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Latency {
+    pub file_name: String,
+    pub pos_start: usize,
+    pub pos_end: usize,
+    /// The latency, written like the attribute or annotation it comes from
+    pub label: String,
+}
+
+impl Latency {
+    fn new(attrs: &ir::Attributes, label: String) -> Self {
+        let span = attrs.copy_span();
+        let (file_name, pos_start, pos_end) = span.get_location();
+        Self {
+            file_name: file_name.to_string(),
+            pos_start,
+            pos_end,
+            label,
+        }
+    }
+}
+
+/// The results of running the compiler on a program
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Analysis {
+    pub errors: Vec<CalyxError>,
+    pub latencies: Vec<Latency>,
+}
+
+impl Diagnostic {
+    /// Run the `calyx` compiler's validation passes on `path`, whose current
+    /// contents are `text`, with libraries at `lib_path`. If the program is
+    /// valid, also infer the latencies of its groups and control.
+    pub fn check(path: &Path, text: &str, lib_path: &Path) -> Analysis {
+        let ctx = calyx_frontend::Workspace::construct_from_source(
+            path,
+            text.to_string(),
+            lib_path.resolve().as_ref(),
+        )
+        .and_then(calyx_ir::from_ast::ast_to_ir);
+        let (errors, ctx) = Self::validate(ctx);
+        let latencies = match ctx {
+            Some(mut ctx) => match StaticInference::do_pass_default(&mut ctx) {
+                Ok(_) => Self::latencies(&ctx),
+                Err(_) => vec![],
+            },
+            None => vec![],
+        };
+        Analysis { errors, latencies }
+    }
+
+    /// Run [Diagnostic::check] in a new process of this server. The compiler
+    /// keeps the source of every program it reads in a global table that is
+    /// never freed and is not safe to use from several threads, so the server
+    /// itself never runs it.
+    pub fn check_in_child(
+        path: &Path,
+        text: &str,
+        lib_path: &Path,
+    ) -> Analysis {
+        let run = || -> std::io::Result<Analysis> {
+            let mut child = Command::new(std::env::current_exe()?)
+                .arg(CHECK_ARG)
+                .arg(path)
+                .arg(lib_path)
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .spawn()?;
+            // Dropping the handle closes the child's stdin.
+            if let Some(mut stdin) = child.stdin.take() {
+                stdin.write_all(text.as_bytes())?;
+            }
+            let output = child.wait_with_output()?;
+            Ok(serde_json::from_slice(&output.stdout)?)
+        };
+        run().unwrap_or_default()
+    }
+
+    /// The other side of [Diagnostic::check_in_child]: check the program on
+    /// stdin and print the analysis to stdout.
+    pub fn check_stdin(path: &Path, lib_path: &Path) -> std::io::Result<()> {
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text)?;
+        let analysis = Self::check(path, &text, lib_path);
+        serde_json::to_writer(std::io::stdout(), &analysis)?;
+        Ok(())
+    }
+
+    /// Run the validation passes on the program. Returns the program if it is
+    /// valid.
+    fn validate(
+        ctx: CalyxResult<ir::Context>,
+    ) -> (Vec<CalyxError>, Option<ir::Context>) {
+        use lspt::DiagnosticSeverity as Sev;
+
+        let mut ctx = match ctx {
+            Ok(ctx) => ctx,
+            Err(e) => {
+                return (CalyxError::from_error(e, Sev::ERROR).collect(), None)
+            }
+        };
+
+        // These passes only inspect the program and record what they find in
+        // the context, so each of them runs even if an earlier one found an
+        // error.
+        let mut diags = vec![];
+        if let Err(e) = WellFormed::do_pass_default(&mut ctx) {
+            diags.extend(CalyxError::from_error(e, Sev::ERROR));
+        }
+        if let Err(e) = Papercut::do_pass_default(&mut ctx) {
+            diags.extend(CalyxError::from_error(e, Sev::ERROR));
+        }
+        if let Err(e) = ctx.diagnostics.take_errors() {
+            diags.extend(CalyxError::from_error(e, Sev::ERROR));
+        }
+        // Synthesis papercuts only matter for programs that will be
+        // synthesized, so they are warnings here.
+        if let Err(e) = SynthesisPapercut::do_pass_default(&mut ctx)
+            .and_then(|_| ctx.diagnostics.take_errors())
+        {
+            diags.extend(CalyxError::from_error(e, Sev::WARNING));
+        }
+        diags.extend(
+            ctx.diagnostics
+                .take_warnings()
+                .iter()
+                .map(CalyxError::from_warning),
+        );
+        // Canonicalize changes the program, so only run it on valid programs.
+        if diags.iter().any(|d| d.severity == Sev::ERROR) {
+            return (diags, None);
+        }
+        if let Err(e) = Canonicalize::do_pass_default(&mut ctx) {
+            diags.extend(CalyxError::from_error(e, Sev::ERROR));
+            return (diags, None);
+        }
+        (diags, Some(ctx))
+    }
+
+    /// Collect the latencies that static inference found for every group and
+    /// control statement.
+    fn latencies(ctx: &ir::Context) -> Vec<Latency> {
+        let mut out = vec![];
+        for comp in &ctx.components {
+            for group in comp.groups.iter() {
+                let group = group.borrow();
+                if let Some(n) = group.attributes.get(ir::NumAttr::Promotable) {
+                    out.push(Latency::new(
+                        &group.attributes,
+                        format!("@promotable({n})"),
+                    ));
+                }
+            }
+            control_latencies(&comp.control.borrow(), &mut out);
+        }
+        out.retain(|l| l.file_name != "unknown");
+        out
+    }
+}
+
+fn control_latencies(control: &ir::Control, out: &mut Vec<Latency>) {
+    let attrs = control.get_attributes();
+    if let Some(n) = attrs.get(ir::NumAttr::Promotable) {
+        out.push(Latency::new(attrs, format!("@promotable({n})")));
+    }
+    match control {
+        ir::Control::Seq(ir::Seq { stmts, .. })
+        | ir::Control::Par(ir::Par { stmts, .. }) => {
+            stmts.iter().for_each(|c| control_latencies(c, out))
+        }
+        ir::Control::If(ir::If {
+            tbranch, fbranch, ..
+        }) => {
+            control_latencies(tbranch, out);
+            control_latencies(fbranch, out);
+        }
+        ir::Control::While(ir::While { body, .. })
+        | ir::Control::Repeat(ir::Repeat { body, .. }) => {
+            control_latencies(body, out)
+        }
+        ir::Control::Static(sc) => static_latencies(sc, out),
+        ir::Control::Invoke(_)
+        | ir::Control::Enable(_)
+        | ir::Control::Empty(_) => {}
+    }
+}
+
+fn static_latencies(control: &ir::StaticControl, out: &mut Vec<Latency>) {
+    if !matches!(control, ir::StaticControl::Empty(_)) {
+        out.push(Latency::new(
+            control.get_attributes(),
+            format!("static<{}>", control.get_latency()),
+        ));
+    }
+    match control {
+        ir::StaticControl::Seq(ir::StaticSeq { stmts, .. })
+        | ir::StaticControl::Par(ir::StaticPar { stmts, .. }) => {
+            stmts.iter().for_each(|c| static_latencies(c, out))
+        }
+        ir::StaticControl::If(ir::StaticIf {
+            tbranch, fbranch, ..
+        }) => {
+            static_latencies(tbranch, out);
+            static_latencies(fbranch, out);
+        }
+        ir::StaticControl::Repeat(ir::StaticRepeat { body, .. }) => {
+            static_latencies(body, out)
+        }
+        ir::StaticControl::Enable(_)
+        | ir::StaticControl::Invoke(_)
+        | ir::StaticControl::Empty(_) => {}
+    }
+}
+
+/// The state of a document that its diagnostics depend on. Diagnostics are
+/// computed from a snapshot so that the document can keep changing meanwhile.
+pub struct Snapshot {
+    pub path: PathBuf,
+    pub text: String,
+    pub lib_path: PathBuf,
+    /// Syntax errors found by tree-sitter
+    pub syntax_errors: Vec<(Range, String)>,
+    /// Assignments between ports of different widths, found without running
+    /// the compiler
+    pub width_warnings: Vec<(Range, String)>,
+}
+
+/// What the compiler found out about a snapshot of a document
+#[derive(Debug, Default)]
+pub struct Analyzed {
+    pub diagnostics: Vec<lspt::Diagnostic>,
+    /// The inferred latencies in the document, with where they apply
+    pub latencies: Vec<(lspt::Range, String)>,
+}
+
+impl Snapshot {
+    /// Run the compiler on the snapshot.
+    pub async fn analyze(&self) -> Analyzed {
+        let path = self.path.clone();
+        let text = self.text.clone();
+        let lib_path = self.lib_path.clone();
+        let syntax_errors = self.syntax_errors.clone();
+        // The compiler is slow, so keep it off the async runtime's threads.
+        tokio::task::spawn_blocking(move || {
+            let analysis = Diagnostic::check_in_child(&path, &text, &lib_path);
+            // The compiler can't say where a syntax error is, so show the
+            // ones that tree-sitter found instead. The tree-sitter grammar
+            // doesn't cover all of Calyx, so only do that when the compiler
+            // agrees that there is one.
+            let unlocated = analysis.errors.iter().any(|d| {
+                d.severity == lspt::DiagnosticSeverity::ERROR
+                    && d.file_name == "unknown"
+            });
+            let diagnostics = if unlocated && !syntax_errors.is_empty() {
+                syntax_errors
+                    .into_iter()
+                    .map(|(range, msg)| {
+                        lsp_diagnostic(
+                            range,
+                            lspt::DiagnosticSeverity::ERROR,
+                            msg,
+                        )
+                    })
+                    .collect()
+            } else {
+                analysis
+                    .errors
+                    .into_iter()
+                    .map(|diag| diag.into_lsp(&path, &text))
+                    .collect()
+            };
+            let latencies = analysis
+                .latencies
+                .into_iter()
+                .filter(|l| Path::new(&l.file_name) == path)
+                .filter_map(|l| {
+                    let start = Point::from_offset(&text, l.pos_start)?;
+                    let end = Point::from_offset(&text, l.pos_end)?;
+                    Some((Range::new(start, end).into(), l.label))
+                })
+                .collect();
+            Analyzed {
+                diagnostics,
+                latencies,
+            }
         })
+        .await
+        .unwrap_or_default()
+    }
+
+    /// Combine the width warnings of this snapshot with the diagnostics from
+    /// the compiler. The compiler rejects every assignment with mismatched
+    /// widths that it finds and reports it at the whole assignment, so leave
+    /// out our warning for assignments that it already reports.
+    pub fn diagnostics(
+        &self,
+        compiler: &[lspt::Diagnostic],
+    ) -> Vec<lspt::Diagnostic> {
+        let reported =
+            |d: &lspt::Diagnostic| compiler.iter().any(|c| c.range == d.range);
+        self.width_warnings
+            .iter()
+            .map(|(range, msg)| {
+                lsp_diagnostic(
+                    range.clone(),
+                    lspt::DiagnosticSeverity::WARNING,
+                    msg.clone(),
+                )
+            })
+            .filter(|d| !reported(d))
+            .chain(compiler.iter().cloned())
+            .collect()
     }
 }
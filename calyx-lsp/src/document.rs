@@ -12,7 +12,7 @@ use tree_sitter as ts;
 use crate::convert::{Contains, Point, Range};
 use crate::log;
 use crate::ts_utils::ParentUntil;
-use crate::{tree_sitter_calyx, Config};
+use crate::Config;
 
 pub struct Document {
     pub url: lspt::Url,
@@ -77,7 +77,7 @@ impl Document {
     /// Create an empty document for `url`.
     pub fn new(url: lspt::Url) -> Self {
         let mut parser = ts::Parser::new();
-        parser.set_language(unsafe { tree_sitter_calyx() }).unwrap();
+        parser.set_language(tree_sitter_calyx::language()).unwrap();
         Self {
             url,
             text: String::new(),
@@ -110,20 +110,34 @@ impl Document {
         self.tree.as_ref().map(|t| t.root_node())
     }
 
-    /// Translate a `byte_offset` into a `Point`.
-    pub fn byte_to_point(&self, byte_offset: usize) -> Option<Point> {
-        if byte_offset == 0 {
-            Some(Point::zero())
-        } else if byte_offset < self.text.len() {
-            let portion = &self.text[..byte_offset];
-            let lines = portion.lines();
-            let line_num = lines.clone().count();
-            let res = lines.last().map(|l| Point::new(line_num - 1, l.len()));
-            log::stdout!("{byte_offset} -> {res:?}");
-            res
-        } else {
-            None
+    /// The current text of the document.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Find the syntax errors in the document, in the order they appear.
+    pub fn syntax_errors(&self) -> Vec<(Range, String)> {
+        let mut errors = vec![];
+        let mut stack: Vec<ts::Node> = self
+            .root_node()
+            .filter(|root| root.has_error())
+            .into_iter()
+            .collect();
+        while let Some(node) = stack.pop() {
+            if node.is_missing() {
+                errors.push((
+                    Range::from(node),
+                    format!("missing `{}`", node.kind()),
+                ));
+            } else if node.is_error() {
+                errors.push((Range::from(node), "syntax error".to_string()));
+            } else if node.has_error() {
+                let mut cursor = node.walk();
+                let children: Vec<_> = node.children(&mut cursor).collect();
+                stack.extend(children.into_iter().rev());
+            }
         }
+        errors
     }
 
     /// Compile `pattern` into a treesit query, run the query,
@@ -136,7 +150,7 @@ impl Document {
         // create the struct that manages query state
         let mut cursor = ts::QueryCursor::new();
         // create the query from the passed in pattern
-        let lang = unsafe { tree_sitter_calyx() };
+        let lang = tree_sitter_calyx::language();
         let query = ts::Query::new(lang, pattern)
             .unwrap_or_else(|err| panic!("Invalid Query:\n{}", err.message));
         // grab the @ capture names so that we can map idxes back to names
@@ -5,31 +5,34 @@ mod document;
 mod goto_definition;
 mod log;
 mod query_result;
+mod references;
+mod symbols;
 mod ts_utils;
+mod widths;
 
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
-use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
 
 use convert::{Point, Range};
-use diagnostic::Diagnostic;
+use diagnostic::{Analyzed, Snapshot};
 use document::Document;
 use goto_definition::DefinitionProvider;
 use query_result::QueryResult;
+use references::{ReferenceProvider, Symbol};
+use regex::Regex;
 use serde::Deserialize;
+use symbols::SymbolProvider;
 use tower_lsp::lsp_types::{self as lspt, Url};
 use tower_lsp::{jsonrpc, Client, LanguageServer, LspService, Server};
-use tree_sitter as ts;
+use widths::{Signatures, WidthProvider};
 
 use crate::completion::CompletionProvider;
 use crate::log::Debug;
 
-extern "C" {
-    /// Bind the tree-sitter parser to something that we can use in Rust
-    fn tree_sitter_calyx() -> ts::Language;
-}
-
 #[derive(Debug, Deserialize, Default)]
 struct Config {
     #[serde(rename = "calyxLsp")]
@@ -50,6 +53,9 @@ impl Default for CalyxLspConfig {
     }
 }
 
+/// How long a document has to stop changing before we update its diagnostics.
+const DIAGNOSTICS_DELAY: Duration = Duration::from_millis(300);
+
 /// Data for the Calyx Language Server
 struct Backend {
     /// Connection to the client that is used for sending data
@@ -58,6 +64,51 @@ struct Backend {
     open_docs: RwLock<HashMap<lspt::Url, document::Document>>,
     /// Server configuration
     config: RwLock<Config>,
+    /// The latest version of each document that changed
+    versions: Arc<Mutex<HashMap<lspt::Url, i32>>>,
+    /// What the compiler last found out about each document
+    analyses: Arc<Mutex<HashMap<lspt::Url, Analyzed>>>,
+    /// Whether the client wants to hear when inlay hints change
+    refresh_hints: AtomicBool,
+}
+
+/// Runs the compiler on documents in the background and publishes the
+/// results
+struct Analyzer {
+    client: Client,
+    versions: Arc<Mutex<HashMap<lspt::Url, i32>>>,
+    analyses: Arc<Mutex<HashMap<lspt::Url, Analyzed>>>,
+    refresh_hints: bool,
+}
+
+impl Analyzer {
+    /// The diagnostics the compiler last found for document `url`.
+    fn compiler_diagnostics(&self, url: &lspt::Url) -> Vec<lspt::Diagnostic> {
+        self.analyses
+            .lock()
+            .unwrap()
+            .get(url)
+            .map(|a| a.diagnostics.clone())
+            .unwrap_or_default()
+    }
+
+    /// Run the compiler on `snapshot` of document `url`, and publish the
+    /// diagnostics it finds.
+    async fn analyze_and_publish(
+        &self,
+        url: lspt::Url,
+        snapshot: Snapshot,
+        version: Option<i32>,
+    ) {
+        let analyzed = snapshot.analyze().await;
+        let diags = snapshot.diagnostics(&analyzed.diagnostics);
+        self.analyses.lock().unwrap().insert(url.clone(), analyzed);
+        self.client.publish_diagnostics(url, diags, version).await;
+        // the inferred latencies may have changed
+        if self.refresh_hints {
+            let _ = self.client.inlay_hint_refresh().await;
+        }
+    }
 }
 
 impl Backend {
@@ -66,6 +117,9 @@ impl Backend {
             client,
             open_docs: RwLock::new(HashMap::default()),
             config: RwLock::new(Config::default()),
+            versions: Arc::default(),
+            analyses: Arc::default(),
+            refresh_hints: AtomicBool::new(false),
         }
     }
 
@@ -125,46 +179,175 @@ impl Backend {
             .map(|mut map| map.get_mut(url).map(updater));
     }
 
-    /// Publish diagnostics for document `url`.
-    async fn publish_diagnostics(&self, url: &lspt::Url) {
-        // TODO: factor the bulk of this method somewhere else
+    /// The port widths of every component and primitive that document `url`
+    /// can see.
+    fn signatures(&self, url: &lspt::Url) -> Signatures {
+        let mut sigs = Signatures::new();
+        for url in self.import_closure(url).iter().rev() {
+            if let Some(widths) =
+                self.read_document(url, |doc| Some(doc.port_widths()))
+            {
+                sigs.extend(widths);
+            }
+        }
+        sigs
+    }
+
+    /// Take a snapshot of document `url` to compute its diagnostics from.
+    fn snapshot(&self, url: &lspt::Url) -> Option<Snapshot> {
         let lib_path: PathBuf =
             self.config.read().unwrap().calyx_lsp.library_paths[0]
                 .to_string()
                 .into();
-        let diags = self
-            .read_document(url, |doc| {
-                Some(
-                    Diagnostic::did_save(
-                        &url.to_file_path().unwrap(),
-                        &lib_path,
+        let sigs = self.signatures(url);
+        self.read_document(url, |doc| {
+            Some(Snapshot {
+                path: url.to_file_path().ok()?,
+                text: doc.text().to_string(),
+                lib_path: lib_path.clone(),
+                syntax_errors: doc.syntax_errors(),
+                width_warnings: doc.width_mismatches(&sigs),
+            })
+        })
+    }
+
+    /// Run the compiler on document `url` and publish its diagnostics.
+    async fn publish_diagnostics(&self, url: &lspt::Url) {
+        if let Some(snapshot) = self.snapshot(url) {
+            self.analyzer()
+                .analyze_and_publish(url.clone(), snapshot, None)
+                .await;
+        }
+    }
+
+    /// Publish diagnostics for version `version` of document `url`. Warnings
+    /// that don't need the compiler are published right away, but we wait
+    /// until the document has stopped changing for a moment to run the
+    /// compiler, so that we don't run it on every keystroke.
+    fn publish_diagnostics_later(&self, url: lspt::Url, version: i32) {
+        self.versions.lock().unwrap().insert(url.clone(), version);
+        let Some(snapshot) = self.snapshot(&url) else {
+            return;
+        };
+        let analyzer = self.analyzer();
+        tokio::spawn(async move {
+            let diags =
+                snapshot.diagnostics(&analyzer.compiler_diagnostics(&url));
+            analyzer
+                .client
+                .publish_diagnostics(url.clone(), diags, Some(version))
+                .await;
+
+            tokio::time::sleep(DIAGNOSTICS_DELAY).await;
+            let latest =
+                analyzer.versions.lock().unwrap().get(&url) == Some(&version);
+            if latest {
+                analyzer
+                    .analyze_and_publish(url, snapshot, Some(version))
+                    .await;
+            }
+        });
+    }
+
+    /// The state needed to analyze documents in the background
+    fn analyzer(&self) -> Analyzer {
+        Analyzer {
+            client: self.client.clone(),
+            versions: Arc::clone(&self.versions),
+            analyses: Arc::clone(&self.analyses),
+            refresh_hints: self.refresh_hints.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Find the documents that `url` imports, directly or through other
+    /// imports, including `url` itself.
+    fn import_closure(&self, url: &lspt::Url) -> Vec<lspt::Url> {
+        let config = self.config.read().unwrap();
+        let mut closure = vec![url.clone()];
+        let mut i = 0;
+        while i < closure.len() {
+            let imports = self
+                .read_and_open(&closure[i], |doc| {
+                    Some(doc.resolved_imports(&config).collect::<Vec<_>>())
+                })
+                .unwrap_or_default();
+            for url in imports
+                .into_iter()
+                .filter_map(|p| Url::from_file_path(p).ok())
+            {
+                if !closure.contains(&url) {
+                    closure.push(url);
+                }
+            }
+            i += 1;
+        }
+        closure
+    }
+
+    /// Find the symbol at `point` in document `url`, and every reference to it
+    /// in the documents that can see it.
+    fn find_references(
+        &self,
+        url: &lspt::Url,
+        point: &Point,
+        include_definition: bool,
+    ) -> Option<(Symbol, Vec<lspt::Location>)> {
+        let symbol = self.read_document(url, |doc| {
+            doc.symbol_at_point(point).map(|(_, symbol)| symbol)
+        })?;
+
+        let docs = if symbol.is_local() {
+            vec![url.clone()]
+        } else {
+            // components are global, so look through every open document and
+            // what it imports for the ones that can see the definition
+            let closure = self.import_closure(url);
+            let defined_in = closure.iter().find(|u| {
+                self.read_document(u, |doc| {
+                    doc.defines_component(symbol.component()).then_some(())
+                })
+                .is_some()
+            });
+            match defined_in {
+                Some(def) => {
+                    let open: Vec<_> = self
+                        .open_docs
+                        .read()
+                        .unwrap()
+                        .keys()
+                        .cloned()
+                        .collect();
+                    closure
+                        .iter()
+                        .chain(open.iter().filter(|u| !closure.contains(u)))
+                        .filter(|u| self.import_closure(u).contains(def))
+                        .cloned()
+                        .collect()
+                }
+                None => vec![url.clone()],
+            }
+        };
+
+        let locations = docs
+            .into_iter()
+            .flat_map(|url| {
+                self.read_document(&url, |doc| {
+                    Some(
+                        doc.references(&symbol, include_definition)
+                            .into_iter()
+                            .map(|node| {
+                                lspt::Location::new(
+                                    url.clone(),
+                                    Range::from(node).into(),
+                                )
+                            })
+                            .collect::<Vec<_>>(),
                     )
-                    .into_iter()
-                    .filter_map(|diag| {
-                        doc.byte_to_point(diag.pos_start).and_then(|s| {
-                            doc.byte_to_point(diag.pos_end)
-                                .map(|e| (Range::new(s, e), diag.msg))
-                        })
-                    })
-                    .map(|(range, message)| lspt::Diagnostic {
-                        range: range.into(),
-                        severity: Some(lspt::DiagnosticSeverity::ERROR),
-                        code: None,
-                        code_description: None,
-                        source: Some("calyx".to_string()),
-                        message,
-                        related_information: None,
-                        tags: None,
-                        data: None,
-                    })
-                    .inspect(|diag| log::stdout!("{diag:#?}"))
-                    .collect(),
-                )
+                })
+                .unwrap_or_default()
             })
-            .unwrap_or_default();
-        self.client
-            .publish_diagnostics(url.clone(), diags, None)
-            .await;
+            .collect();
+        Some((symbol, locations))
     }
 
     /// Publish diagnostics for every open file.
@@ -182,9 +365,16 @@ impl LanguageServer for Backend {
     /// LSP method: 'initialize'
     async fn initialize(
         &self,
-        _ip: lspt::InitializeParams,
+        ip: lspt::InitializeParams,
     ) -> jsonrpc::Result<lspt::InitializeResult> {
         Debug::init("init");
+        let refresh_hints = ip
+            .capabilities
+            .workspace
+            .and_then(|w| w.inlay_hint)
+            .and_then(|h| h.refresh_support)
+            .unwrap_or(false);
+        self.refresh_hints.store(refresh_hints, Ordering::Relaxed);
         Ok(lspt::InitializeResult {
             server_info: None,
             capabilities: lspt::ServerCapabilities {
@@ -215,9 +405,23 @@ impl LanguageServer for Backend {
                     work_done_progress_options: Default::default(),
                     completion_item: None,
                 }),
+                document_symbol_provider: Some(lspt::OneOf::Left(true)),
+                workspace_symbol_provider: Some(lspt::OneOf::Left(true)),
+                folding_range_provider: Some(
+                    lspt::FoldingRangeProviderCapability::Simple(true),
+                ),
+                references_provider: Some(lspt::OneOf::Left(true)),
+                rename_provider: Some(lspt::OneOf::Right(
+                    lspt::RenameOptions {
+                        prepare_provider: Some(true),
+                        work_done_progress_options: Default::default(),
+                    },
+                )),
                 hover_provider: Some(lspt::HoverProviderCapability::Simple(
-                    false,
+                    true,
                 )),
+                inlay_hint_provider: Some(lspt::OneOf::Left(true)),
+                document_formatting_provider: Some(lspt::OneOf::Left(true)),
                 ..Default::default()
             },
         })
@@ -293,6 +497,11 @@ impl LanguageServer for Backend {
                 doc.parse_whole_text(&event.text);
             }
         });
+        #[cfg(feature = "diagnostics")]
+        self.publish_diagnostics_later(
+            params.text_document.uri,
+            params.text_document.version,
+        );
     }
 
     /// LSP method: 'textDocument/didSave'
@@ -363,6 +572,209 @@ impl LanguageServer for Backend {
             }))
     }
 
+    /// LSP method: 'textDocument/references'
+    /// Called when the client requests every reference to the symbol at a
+    /// point in the file.
+    async fn references(
+        &self,
+        params: lspt::ReferenceParams,
+    ) -> jsonrpc::Result<Option<Vec<lspt::Location>>> {
+        let url = &params.text_document_position.text_document.uri;
+        let point: Point = params.text_document_position.position.into();
+        Ok(self
+            .find_references(url, &point, params.context.include_declaration)
+            .map(|(_, locations)| locations))
+    }
+
+    /// LSP method: 'textDocument/prepareRename'
+    /// Called before a rename to check that there is something to rename at
+    /// a point in the file.
+    async fn prepare_rename(
+        &self,
+        params: lspt::TextDocumentPositionParams,
+    ) -> jsonrpc::Result<Option<lspt::PrepareRenameResponse>> {
+        let point: Point = params.position.into();
+        Ok(self.read_document(&params.text_document.uri, |doc| {
+            doc.symbol_at_point(&point).map(|(node, _)| {
+                lspt::PrepareRenameResponse::Range(Range::from(node).into())
+            })
+        }))
+    }
+
+    /// LSP method: 'textDocument/rename'
+    /// Called when the client renames the symbol at a point in the file. We
+    /// rename its definition and every reference to it.
+    async fn rename(
+        &self,
+        params: lspt::RenameParams,
+    ) -> jsonrpc::Result<Option<lspt::WorkspaceEdit>> {
+        let url = &params.text_document_position.text_document.uri;
+        let point: Point = params.text_document_position.position.into();
+        let new_name = params.new_name;
+        if !Regex::new(r"^[a-zA-Z_]+[a-zA-Z0-9_\-]*$")
+            .unwrap()
+            .is_match(&new_name)
+        {
+            return Err(jsonrpc::Error::invalid_params(format!(
+                "`{new_name}` is not a valid identifier"
+            )));
+        }
+        Ok(self
+            .find_references(url, &point, true)
+            .map(|(_, locations)| {
+                let mut changes: HashMap<lspt::Url, Vec<lspt::TextEdit>> =
+                    HashMap::default();
+                for loc in locations {
+                    changes
+                        .entry(loc.uri)
+                        .or_default()
+                        .push(lspt::TextEdit::new(loc.range, new_name.clone()));
+                }
+                lspt::WorkspaceEdit::new(changes)
+            }))
+    }
+
+    /// LSP method: 'textDocument/documentSymbol'
+    /// Called when the client requests the outline of a file.
+    async fn document_symbol(
+        &self,
+        params: lspt::DocumentSymbolParams,
+    ) -> jsonrpc::Result<Option<lspt::DocumentSymbolResponse>> {
+        Ok(self.read_document(&params.text_document.uri, |doc| {
+            Some(lspt::DocumentSymbolResponse::Nested(doc.document_symbols()))
+        }))
+    }
+
+    /// LSP method: 'workspace/symbol'
+    /// Called when the client searches for a symbol. We search every open
+    /// file and everything that it imports.
+    async fn symbol(
+        &self,
+        params: lspt::WorkspaceSymbolParams,
+    ) -> jsonrpc::Result<Option<Vec<lspt::SymbolInformation>>> {
+        let open: Vec<_> =
+            self.open_docs.read().unwrap().keys().cloned().collect();
+        let mut docs: Vec<lspt::Url> = vec![];
+        for url in open {
+            for url in self.import_closure(&url) {
+                if !docs.contains(&url) {
+                    docs.push(url);
+                }
+            }
+        }
+        Ok(Some(
+            docs.iter()
+                .flat_map(|url| {
+                    self.read_document(url, |doc| {
+                        Some(doc.symbol_information())
+                    })
+                    .unwrap_or_default()
+                })
+                .filter(|sym| symbols::matches_query(&sym.name, &params.query))
+                .collect(),
+        ))
+    }
+
+    /// LSP method: 'textDocument/foldingRange'
+    /// Called when the client requests the regions of a file that can be
+    /// folded.
+    async fn folding_range(
+        &self,
+        params: lspt::FoldingRangeParams,
+    ) -> jsonrpc::Result<Option<Vec<lspt::FoldingRange>>> {
+        Ok(self.read_document(&params.text_document.uri, |doc| {
+            Some(doc.folding_ranges())
+        }))
+    }
+
+    /// LSP method: 'textDocument/hover'
+    /// Called when the client hovers over a point in the file. We describe
+    /// the widths of the cell or port there, and the latency the compiler
+    /// inferred for the innermost group or control statement around it.
+    async fn hover(
+        &self,
+        params: lspt::HoverParams,
+    ) -> jsonrpc::Result<Option<lspt::Hover>> {
+        let url = &params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+        let point: Point = position.into();
+        let sigs = self.signatures(url);
+        let width =
+            self.read_document(url, |doc| doc.describe_at_point(&sigs, &point));
+        let latency = self.analyses.lock().unwrap().get(url).and_then(|a| {
+            a.latencies
+                .iter()
+                .filter(|(range, _)| {
+                    range.start <= position && position < range.end
+                })
+                .min_by_key(|(range, _)| {
+                    (range.end.line - range.start.line, range.end.character)
+                })
+                .map(|(_, label)| format!("inferred latency: `{label}`"))
+        });
+        let text = width.into_iter().chain(latency).collect::<Vec<_>>();
+        Ok((!text.is_empty()).then(|| lspt::Hover {
+            contents: lspt::HoverContents::Markup(lspt::MarkupContent {
+                kind: lspt::MarkupKind::Markdown,
+                value: text.join("\n\n"),
+            }),
+            range: None,
+        }))
+    }
+
+    /// LSP method: 'textDocument/inlayHint'
+    /// Called when the client requests inlay hints for a range of the file.
+    /// We show the width of every `cell.port` in an assignment, and the
+    /// latencies that the compiler inferred.
+    async fn inlay_hint(
+        &self,
+        params: lspt::InlayHintParams,
+    ) -> jsonrpc::Result<Option<Vec<lspt::InlayHint>>> {
+        let url = &params.text_document.uri;
+        let range = params.range;
+        let sigs = self.signatures(url);
+        let mut hints = self
+            .read_document(url, |doc| Some(doc.width_hints(&sigs)))
+            .unwrap_or_default();
+        if let Some(analyzed) = self.analyses.lock().unwrap().get(url) {
+            hints.extend(analyzed.latencies.iter().map(|(range, label)| {
+                lspt::InlayHint {
+                    position: range.start,
+                    label: lspt::InlayHintLabel::String(label.clone()),
+                    kind: None,
+                    text_edits: None,
+                    tooltip: None,
+                    padding_left: None,
+                    padding_right: Some(true),
+                    data: None,
+                }
+            }));
+        }
+        hints.retain(|h| range.start <= h.position && h.position <= range.end);
+        Ok(Some(hints))
+    }
+
+    /// LSP method: 'textDocument/formatting'
+    /// Called when the client asks to format a file. We replace the whole
+    /// file with its formatted text, and leave files that don't parse alone.
+    async fn formatting(
+        &self,
+        params: lspt::DocumentFormattingParams,
+    ) -> jsonrpc::Result<Option<Vec<lspt::TextEdit>>> {
+        Ok(self.read_document(&params.text_document.uri, |doc| {
+            let text = doc.text();
+            let formatted = calyx_fmt::format_source(text).ok()?;
+            if formatted == text {
+                return Some(vec![]);
+            }
+            let end = Point::from_offset(text, text.len())?;
+            Some(vec![lspt::TextEdit::new(
+                lspt::Range::new(lspt::Position::new(0, 0), end.into()),
+                formatted,
+            )])
+        }))
+    }
+
     /// LSP method: 'shutdown'
     async fn shutdown(&self) -> jsonrpc::Result<()> {
         log::stdout!("shutdown");
@@ -372,6 +784,21 @@ impl LanguageServer for Backend {
 
 #[tokio::main]
 async fn main() {
+    // The server runs the compiler in a child process of itself.
+    let args: Vec<String> = std::env::args().collect();
+    if let [_, arg, path, lib_path] = &args[..] {
+        if arg == diagnostic::CHECK_ARG {
+            if let Err(e) = diagnostic::Diagnostic::check_stdin(
+                path.as_ref(),
+                lib_path.as_ref(),
+            ) {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+            return;
+        }
+    }
+
     let stdin = tokio::io::stdin();
     let stdout = tokio::io::stdout();
 
@@ -0,0 +1,180 @@
+use tree_sitter as ts;
+
+use crate::{
+    convert::Point,
+    document::Document,
+    ts_utils::{first_ident, ParentUntil},
+};
+
+/// A named thing in a Calyx program that can be referred to from several
+/// places.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Symbol {
+    /// A cell of `component`
+    Cell { component: String, name: String },
+    /// A group of `component`
+    Group { component: String, name: String },
+    /// A port in the signature of `component`
+    Port { component: String, name: String },
+    /// A component or primitive
+    Component(String),
+}
+
+impl Symbol {
+    /// The name of the symbol as it is written in the program.
+    pub fn name(&self) -> &str {
+        match self {
+            Symbol::Cell { name, .. }
+            | Symbol::Group { name, .. }
+            | Symbol::Port { name, .. }
+            | Symbol::Component(name) => name,
+        }
+    }
+
+    /// Cells and groups can only be referred to in the file that defines
+    /// their component. Components and their ports can be referred to from
+    /// any file that imports them.
+    pub fn is_local(&self) -> bool {
+        matches!(self, Symbol::Cell { .. } | Symbol::Group { .. })
+    }
+
+    /// The component that needs to be defined for this symbol to exist.
+    pub fn component(&self) -> &str {
+        match self {
+            Symbol::Cell { component, .. }
+            | Symbol::Group { component, .. }
+            | Symbol::Port { component, .. }
+            | Symbol::Component(component) => component,
+        }
+    }
+}
+
+pub trait ReferenceProvider {
+    /// Find the symbol that the identifier at `point` refers to.
+    fn symbol_at_point(&self, point: &Point) -> Option<(ts::Node<'_>, Symbol)>;
+
+    /// Find every identifier in the document that refers to `symbol`, and
+    /// the identifier that defines it if `include_definition` is set.
+    fn references(
+        &self,
+        symbol: &Symbol,
+        include_definition: bool,
+    ) -> Vec<ts::Node<'_>>;
+
+    /// Check whether this document defines `component`.
+    fn defines_component(&self, component: &str) -> bool;
+}
+
+/// Check whether the identifier `node` is where its symbol is defined
+fn is_definition(node: ts::Node) -> bool {
+    node.parent().is_some_and(|p| {
+        matches!(
+            p.kind(),
+            "component" | "primitive" | "cell_assignment" | "group" | "io_port"
+        )
+    })
+}
+
+impl Document {
+    /// Find the symbol that the identifier `node` refers to.
+    fn symbol_of(&self, node: ts::Node) -> Option<Symbol> {
+        let parent = node.parent()?;
+        let name = self.node_text(&node).to_string();
+        let first = first_ident(parent) == Some(node);
+        let enclosing = || {
+            let comp = node.parent_until_names(&["component", "primitive"])?;
+            Some(self.node_text(&first_ident(comp)?).to_string())
+        };
+        let cell = |component: String, name: String| {
+            Some(Symbol::Cell { component, name })
+        };
+        let group = |component: String, name: String| {
+            Some(Symbol::Group { component, name })
+        };
+        let port = |component: String, name: String| {
+            Some(Symbol::Port { component, name })
+        };
+
+        match parent.kind() {
+            "component" | "primitive" | "instantiation" => {
+                Some(Symbol::Component(name))
+            }
+            "cell_assignment" => cell(enclosing()?, name),
+            "group" | "enable" => group(enclosing()?, name),
+            // the name of a port comes before the `:`, its width after
+            "io_port" if node.next_sibling()?.kind() == ":" => {
+                port(enclosing()?, name)
+            }
+            "port" if parent.named_child_count() == 1 => {
+                port(enclosing()?, name)
+            }
+            "port" if first => cell(enclosing()?, name),
+            "port" => {
+                let cell = self.node_text(&first_ident(parent)?);
+                port(self.cell_type(&enclosing()?, cell)?, name)
+            }
+            "hole" if first => group(enclosing()?, name),
+            "port_with" => group(enclosing()?, name),
+            "invoke" if first => cell(enclosing()?, name),
+            "invoke" => group(enclosing()?, name),
+            // `invoke x(p = ...)` refers to port `p` of `x`
+            "invoke_arg" if first => {
+                let invoke = parent.parent_until_names(&["invoke"])?;
+                let cell = self.node_text(&first_ident(invoke)?);
+                port(self.cell_type(&enclosing()?, cell)?, name)
+            }
+            // `invoke x[r = c]` binds cell `c` to the ref cell `r` of `x`
+            "invoke_ref_arg" if first => {
+                let invoke = parent.parent_until_names(&["invoke"])?;
+                let cell = self.node_text(&first_ident(invoke)?);
+                let component = self.cell_type(&enclosing()?, cell)?;
+                Some(Symbol::Cell { component, name })
+            }
+            "invoke_ref_arg" => cell(enclosing()?, name),
+            _ => None,
+        }
+    }
+
+    /// Find the name of the component that `cell` of `component` instantiates.
+    fn cell_type(&self, component: &str, cell: &str) -> Option<String> {
+        self.components.get(component)?.cells.get(cell).cloned()
+    }
+
+    /// Find every identifier in the document.
+    fn idents(&self) -> Vec<ts::Node<'_>> {
+        self.root_node()
+            .into_iter()
+            .flat_map(|root| self.captures(root, "(ident) @id")["id"].clone())
+            .collect()
+    }
+}
+
+impl ReferenceProvider for Document {
+    fn symbol_at_point(&self, point: &Point) -> Option<(ts::Node<'_>, Symbol)> {
+        // the cursor is often just after the identifier it is on
+        let before = (point.column() > 0)
+            .then(|| Point::new(point.row(), point.column() - 1));
+        std::iter::once(point.clone())
+            .chain(before)
+            .filter_map(|p| self.node_at_point(&p))
+            .find(|node| node.kind() == "ident")
+            .and_then(|node| self.symbol_of(node).map(|sym| (node, sym)))
+    }
+
+    fn references(
+        &self,
+        symbol: &Symbol,
+        include_definition: bool,
+    ) -> Vec<ts::Node<'_>> {
+        self.idents()
+            .into_iter()
+            .filter(|node| self.node_text(node) == symbol.name())
+            .filter(|node| include_definition || !is_definition(*node))
+            .filter(|node| self.symbol_of(*node).as_ref() == Some(symbol))
+            .collect()
+    }
+
+    fn defines_component(&self, component: &str) -> bool {
+        self.components().any(|n| self.node_text(&n) == component)
+    }
+}
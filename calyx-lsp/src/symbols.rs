@@ -0,0 +1,316 @@
+use tower_lsp::lsp_types as lspt;
+use tree_sitter as ts;
+
+use crate::{
+    convert::Range,
+    document::Document,
+    ts_utils::{children_of_kind, first_ident},
+};
+
+pub trait SymbolProvider {
+    /// The outline of the document: its components and primitives, with
+    /// their cells, groups and control program nested inside them.
+    fn document_symbols(&self) -> Vec<lspt::DocumentSymbol>;
+
+    /// The components, primitives, cells and groups of the document, for
+    /// searching across files.
+    fn symbol_information(&self) -> Vec<lspt::SymbolInformation>;
+
+    /// The regions of the document that can be folded: `cells`, `wires`,
+    /// `control` and group bodies.
+    fn folding_ranges(&self) -> Vec<lspt::FoldingRange>;
+}
+
+/// Build a symbol for `node`, selecting `name_node` when the symbol is picked.
+#[allow(deprecated)]
+fn symbol(
+    name: String,
+    detail: Option<String>,
+    kind: lspt::SymbolKind,
+    node: ts::Node,
+    name_node: ts::Node,
+    children: Vec<lspt::DocumentSymbol>,
+) -> lspt::DocumentSymbol {
+    lspt::DocumentSymbol {
+        name,
+        detail,
+        kind,
+        tags: None,
+        deprecated: None,
+        range: Range::from(node).into(),
+        selection_range: Range::from(name_node).into(),
+        children: Some(children),
+    }
+}
+
+impl Document {
+    fn component_symbol(&self, node: ts::Node) -> Option<lspt::DocumentSymbol> {
+        let name = first_ident(node)?;
+        let mut children = vec![];
+        for cells in children_of_kind(node, "cells") {
+            children.extend(
+                self.captures(cells, "(cell_assignment) @cell")["cell"]
+                    .iter()
+                    .filter_map(|cell| self.cell_symbol(*cell)),
+            );
+        }
+        for wires in children_of_kind(node, "wires") {
+            children.extend(
+                self.captures(wires, "(group) @group")["group"]
+                    .iter()
+                    .filter_map(|group| self.group_symbol(*group)),
+            );
+        }
+        for control in children_of_kind(node, "control") {
+            // `control` is a keyword, so select the whole section
+            children.push(symbol(
+                "control".to_string(),
+                None,
+                lspt::SymbolKind::NAMESPACE,
+                control,
+                control,
+                self.control_symbols(control),
+            ));
+        }
+
+        let (kind, keyword) = if node.kind() == "primitive" {
+            (lspt::SymbolKind::INTERFACE, "primitive")
+        } else {
+            (lspt::SymbolKind::CLASS, "component")
+        };
+        let detail = match children_of_kind(node, "comb_or_static").next() {
+            Some(modifier) => {
+                format!("{} {keyword}", self.node_text(&modifier))
+            }
+            None => keyword.to_string(),
+        };
+        Some(symbol(
+            self.node_text(&name).to_string(),
+            Some(detail),
+            kind,
+            node,
+            name,
+            children,
+        ))
+    }
+
+    fn cell_symbol(&self, node: ts::Node) -> Option<lspt::DocumentSymbol> {
+        let name = first_ident(node)?;
+        let detail = children_of_kind(node, "instantiation")
+            .next()
+            .map(|inst| self.node_text(&inst).to_string());
+        Some(symbol(
+            self.node_text(&name).to_string(),
+            detail,
+            lspt::SymbolKind::FIELD,
+            node,
+            name,
+            vec![],
+        ))
+    }
+
+    fn group_symbol(&self, node: ts::Node) -> Option<lspt::DocumentSymbol> {
+        let name = first_ident(node)?;
+        let detail = if node.child(0).is_some_and(|c| c.kind() == "comb") {
+            "comb group".to_string()
+        } else {
+            match children_of_kind(node, "static_annotation").next() {
+                Some(prefix) => format!("{} group", self.node_text(&prefix)),
+                None => "group".to_string(),
+            }
+        };
+        Some(symbol(
+            self.node_text(&name).to_string(),
+            Some(detail),
+            lspt::SymbolKind::METHOD,
+            node,
+            name,
+            vec![],
+        ))
+    }
+
+    /// Symbols for the control statements directly under `node`.
+    fn control_symbols(&self, node: ts::Node) -> Vec<lspt::DocumentSymbol> {
+        let mut cursor = node.walk();
+        let children: Vec<_> = node.named_children(&mut cursor).collect();
+        children
+            .into_iter()
+            .flat_map(|child| match child.kind() {
+                "control_inner" | "stmt" | "block" => {
+                    self.control_symbols(child)
+                }
+                _ => self.control_symbol(child).into_iter().collect(),
+            })
+            .collect()
+    }
+
+    fn control_symbol(&self, node: ts::Node) -> Option<lspt::DocumentSymbol> {
+        let is_static = children_of_kind(node, "static_annotation")
+            .next()
+            .map(|s| format!("{} ", self.node_text(&s)))
+            .unwrap_or_default();
+        let port_with = || {
+            children_of_kind(node, "port_with")
+                .next()
+                .map(|p| self.node_text(&p).to_string())
+                .unwrap_or_default()
+        };
+        let (name, kind, name_node) = match node.kind() {
+            "enable" => {
+                let group = first_ident(node)?;
+                (
+                    self.node_text(&group).to_string(),
+                    lspt::SymbolKind::EVENT,
+                    group,
+                )
+            }
+            "invoke" => {
+                let cell = first_ident(node)?;
+                (
+                    format!("{is_static}invoke {}", self.node_text(&cell)),
+                    lspt::SymbolKind::FUNCTION,
+                    cell,
+                )
+            }
+            "seq" | "par" => (
+                format!("{is_static}{}", node.kind()),
+                lspt::SymbolKind::OPERATOR,
+                node,
+            ),
+            "if_stmt" => (
+                format!("{is_static}if {}", port_with()),
+                lspt::SymbolKind::OPERATOR,
+                node,
+            ),
+            "while_stmt" => (
+                format!("while {}", port_with()),
+                lspt::SymbolKind::OPERATOR,
+                node,
+            ),
+            "repeat_stmt" => {
+                let count = children_of_kind(node, "number")
+                    .next()
+                    .map(|n| self.node_text(&n).to_string())
+                    .unwrap_or_default();
+                (format!("repeat {count}"), lspt::SymbolKind::OPERATOR, node)
+            }
+            "for_stmt" => {
+                let range = children_of_kind(node, "for_range")
+                    .next()
+                    .map(|r| self.node_text(&r).to_string())
+                    .unwrap_or_default();
+                (format!("for {range}"), lspt::SymbolKind::OPERATOR, node)
+            }
+            _ => return None,
+        };
+        Some(symbol(
+            name,
+            None,
+            kind,
+            node,
+            name_node,
+            self.control_symbols(node),
+        ))
+    }
+}
+
+impl SymbolProvider for Document {
+    fn document_symbols(&self) -> Vec<lspt::DocumentSymbol> {
+        let Some(root) = self.root_node() else {
+            return vec![];
+        };
+        let mut cursor = root.walk();
+        let children: Vec<_> = root.named_children(&mut cursor).collect();
+        children
+            .into_iter()
+            .filter_map(|node| match node.kind() {
+                "component" | "primitive" => self.component_symbol(node),
+                "extern" => {
+                    let file = children_of_kind(node, "string").next()?;
+                    Some(symbol(
+                        self.node_text(&file).replace('"', ""),
+                        Some("extern".to_string()),
+                        lspt::SymbolKind::MODULE,
+                        node,
+                        file,
+                        children_of_kind(node, "primitive")
+                            .filter_map(|prim| self.component_symbol(prim))
+                            .collect(),
+                    ))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[allow(deprecated)]
+    fn symbol_information(&self) -> Vec<lspt::SymbolInformation> {
+        fn flatten(
+            url: &lspt::Url,
+            symbols: Vec<lspt::DocumentSymbol>,
+            container: Option<&str>,
+            out: &mut Vec<lspt::SymbolInformation>,
+        ) {
+            for sym in symbols {
+                let keep = matches!(
+                    sym.kind,
+                    lspt::SymbolKind::CLASS
+                        | lspt::SymbolKind::INTERFACE
+                        | lspt::SymbolKind::FIELD
+                        | lspt::SymbolKind::METHOD
+                );
+                let children = sym.children.unwrap_or_default();
+                if keep {
+                    flatten(url, children, Some(&sym.name), out);
+                    out.push(lspt::SymbolInformation {
+                        name: sym.name,
+                        kind: sym.kind,
+                        tags: None,
+                        deprecated: None,
+                        location: lspt::Location::new(
+                            url.clone(),
+                            sym.selection_range,
+                        ),
+                        container_name: container.map(str::to_string),
+                    });
+                } else if sym.kind == lspt::SymbolKind::MODULE {
+                    // primitives in an `extern` block
+                    flatten(url, children, container, out);
+                }
+            }
+        }
+        let mut out = vec![];
+        flatten(&self.url, self.document_symbols(), None, &mut out);
+        out
+    }
+
+    fn folding_ranges(&self) -> Vec<lspt::FoldingRange> {
+        let Some(root) = self.root_node() else {
+            return vec![];
+        };
+        self.captures(root, "(cells) @r (wires) @r (control) @r (group) @r")
+            ["r"]
+            .iter()
+            .map(|node| (node.start_position().row, node.end_position().row))
+            .filter(|(start, end)| start < end)
+            .map(|(start, end)| lspt::FoldingRange {
+                start_line: start as u32,
+                start_character: None,
+                end_line: end as u32,
+                end_character: None,
+                kind: Some(lspt::FoldingRangeKind::Region),
+                collapsed_text: None,
+            })
+            .collect()
+    }
+}
+
+/// Check whether `name` matches a workspace symbol `query`: the characters of
+/// the query have to appear in the name in order, ignoring case.
+pub fn matches_query(name: &str, query: &str) -> bool {
+    let mut name = name.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|q| name.any(|c| c == q))
+}
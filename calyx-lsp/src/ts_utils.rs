@@ -33,3 +33,18 @@ impl ParentUntil for Node<'_> {
         self.parent_until(|p| names.iter().any(|n| p.kind() == n.as_ref()))
     }
 }
+
+/// The named children of `node` with kind `kind`
+pub fn children_of_kind<'a>(
+    node: Node<'a>,
+    kind: &'a str,
+) -> impl Iterator<Item = Node<'a>> {
+    let mut cursor = node.walk();
+    let children: Vec<_> = node.named_children(&mut cursor).collect();
+    children.into_iter().filter(move |n| n.kind() == kind)
+}
+
+/// The first identifier directly under `node`
+pub fn first_ident(node: Node<'_>) -> Option<Node<'_>> {
+    children_of_kind(node, "ident").next()
+}
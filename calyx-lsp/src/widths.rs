@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use tower_lsp::lsp_types as lspt;
+use tree_sitter as ts;
+
+use crate::{
+    convert::{Point, Range},
+    document::Document,
+    ts_utils::{children_of_kind, ParentUntil},
+};
+
+/// The width of a port in a signature
+#[derive(Clone, Debug)]
+pub enum Width {
+    Known(u64),
+    /// The width is a parameter of the primitive
+    Param(String),
+}
+
+/// The widths of the ports of a component or primitive
+#[derive(Clone, Debug, Default)]
+pub struct PortWidths {
+    /// The parameters of a primitive, in order
+    pub params: Vec<String>,
+    /// The widths of the inputs and outputs
+    pub ports: HashMap<String, Width>,
+}
+
+impl PortWidths {
+    /// The width of `port` on an instance created with `args`.
+    pub fn width(&self, port: &str, args: &[u64]) -> Option<u64> {
+        match self.ports.get(port)? {
+            Width::Known(w) => Some(*w),
+            Width::Param(p) => {
+                let idx = self.params.iter().position(|x| x == p)?;
+                args.get(idx).copied()
+            }
+        }
+    }
+}
+
+/// Port widths of every component and primitive that a document can see
+pub type Signatures = HashMap<String, PortWidths>;
+
+pub trait WidthProvider {
+    /// The port widths of the components and primitives defined in this
+    /// document.
+    fn port_widths(&self) -> Signatures;
+
+    /// The width of the port, hole, or literal `node`.
+    fn width_of(&self, sigs: &Signatures, node: ts::Node) -> Option<u64>;
+
+    /// Assignments whose two sides have different widths.
+    fn width_mismatches(&self, sigs: &Signatures) -> Vec<(Range, String)>;
+
+    /// Hints with the width of every `cell.port` in an assignment.
+    fn width_hints(&self, sigs: &Signatures) -> Vec<lspt::InlayHint>;
+
+    /// Describe the cell or port at `point`.
+    fn describe_at_point(
+        &self,
+        sigs: &Signatures,
+        point: &Point,
+    ) -> Option<String>;
+}
+
+impl Document {
+    /// Find the instantiation of `cell` in the component containing `node`:
+    /// the name of its component and its arguments.
+    fn instance(
+        &self,
+        node: ts::Node,
+        cell: &str,
+    ) -> Option<(String, Vec<u64>)> {
+        let comp = node.parent_until_names(&["component"])?;
+        let map = self.captures(
+            comp,
+            "(cell_assignment (ident) @name (instantiation) @inst)",
+        );
+        let (_, inst) = map["name"]
+            .iter()
+            .zip(&map["inst"])
+            .find(|(name, _)| self.node_text(name) == cell)?;
+        let comp_name =
+            self.node_text(&children_of_kind(*inst, "ident").next()?);
+        let args = children_of_kind(*inst, "arg_list")
+            .flat_map(|args| children_of_kind(args, "number"))
+            .filter_map(|n| self.node_text(&n).parse().ok())
+            .collect();
+        Some((comp_name.to_string(), args))
+    }
+
+    /// The name of the component containing `node`
+    fn component_of(&self, node: ts::Node) -> Option<String> {
+        let comp = node.parent_until_names(&["component"])?;
+        let name = children_of_kind(comp, "ident").next()?;
+        Some(self.node_text(&name).to_string())
+    }
+
+    /// The value assigned by `assign`, if it is a single port, hole, or
+    /// literal.
+    fn assigned_value<'a>(&self, assign: ts::Node<'a>) -> Option<ts::Node<'a>> {
+        let value = children_of_kind(assign, "switch")
+            .flat_map(|s| children_of_kind(s, "base_expr"))
+            .chain(
+                children_of_kind(assign, "expr")
+                    .flat_map(|e| children_of_kind(e, "base_expr")),
+            )
+            .next()?;
+        value.named_child(0)
+    }
+}
+
+impl WidthProvider for Document {
+    fn port_widths(&self) -> Signatures {
+        let Some(root) = self.root_node() else {
+            return Signatures::new();
+        };
+        self.captures(root, "(component) @c (primitive) @c")["c"]
+            .iter()
+            .filter_map(|def| {
+                let name = children_of_kind(*def, "ident").next()?;
+                let params = children_of_kind(*def, "params")
+                    .flat_map(|p| children_of_kind(p, "ident"))
+                    .map(|p| self.node_text(&p).to_string())
+                    .collect();
+                let mut ports: HashMap<_, _> = self
+                    .captures(*def, "(io_port) @port")["port"]
+                    .iter()
+                    .filter_map(|port| {
+                        let name = children_of_kind(*port, "ident").next()?;
+                        let width =
+                            port.named_child(port.named_child_count() - 1)?;
+                        let width = match width.kind() {
+                            "number" => Width::Known(
+                                self.node_text(&width).parse().ok()?,
+                            ),
+                            _ => {
+                                Width::Param(self.node_text(&width).to_string())
+                            }
+                        };
+                        Some((self.node_text(&name).to_string(), width))
+                    })
+                    .collect();
+                // components have implicit interface ports
+                if def.kind() == "component" {
+                    for port in ["go", "done", "clk", "reset"] {
+                        ports
+                            .entry(port.to_string())
+                            .or_insert(Width::Known(1));
+                    }
+                }
+                Some((
+                    self.node_text(&name).to_string(),
+                    PortWidths { params, ports },
+                ))
+            })
+            .collect()
+    }
+
+    fn width_of(&self, sigs: &Signatures, node: ts::Node) -> Option<u64> {
+        match node.kind() {
+            "hole" => Some(1),
+            "literal" => self.node_text(&node.named_child(0)?).parse().ok(),
+            "port" if node.named_child_count() == 2 => {
+                let cell = self.node_text(&node.named_child(0)?);
+                let port = self.node_text(&node.named_child(1)?);
+                let (comp, args) = self.instance(node, cell)?;
+                sigs.get(&comp)?.width(port, &args)
+            }
+            "port" => {
+                let port = self.node_text(&node.named_child(0)?);
+                sigs.get(&self.component_of(node)?)?.width(port, &[])
+            }
+            "lhs" => self.width_of(sigs, node.named_child(0)?),
+            _ => None,
+        }
+    }
+
+    fn width_mismatches(&self, sigs: &Signatures) -> Vec<(Range, String)> {
+        let Some(root) = self.root_node() else {
+            return vec![];
+        };
+        self.captures(root, "(wire_assignment (lhs) @lhs) @assign")["assign"]
+            .iter()
+            .filter_map(|assign| {
+                let lhs = children_of_kind(*assign, "lhs").next()?;
+                let rhs = self.assigned_value(*assign)?;
+                let dst = self.width_of(sigs, lhs)?;
+                let src = self.width_of(sigs, rhs)?;
+                (dst != src).then(|| {
+                    (
+                        Range::from(*assign),
+                        format!(
+                            "`{}` is {dst} bits wide but `{}` is {src} bits wide",
+                            self.node_text(&lhs),
+                            self.node_text(&rhs),
+                        ),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn width_hints(&self, sigs: &Signatures) -> Vec<lspt::InlayHint> {
+        let Some(root) = self.root_node() else {
+            return vec![];
+        };
+        self.captures(root, "(wire_assignment) @assign")["assign"]
+            .iter()
+            .flat_map(|assign| {
+                self.captures(*assign, "(port) @port")["port"].clone()
+            })
+            .filter(|port| port.named_child_count() == 2)
+            .filter_map(|port| {
+                let width = self.width_of(sigs, port)?;
+                Some(lspt::InlayHint {
+                    position: Point::from(port.end_position()).into(),
+                    label: lspt::InlayHintLabel::String(format!(": {width}")),
+                    kind: Some(lspt::InlayHintKind::TYPE),
+                    text_edits: None,
+                    tooltip: None,
+                    padding_left: None,
+                    padding_right: None,
+                    data: None,
+                })
+            })
+            .collect()
+    }
+
+    fn describe_at_point(
+        &self,
+        sigs: &Signatures,
+        point: &Point,
+    ) -> Option<String> {
+        let node = self.node_at_point(point)?;
+        let parent = node.parent()?;
+        match parent.kind() {
+            "port" => {
+                let width = self.width_of(sigs, parent)?;
+                Some(format!("`{}`: {width} bits", self.node_text(&parent)))
+            }
+            "cell_assignment" => {
+                let cell = self.node_text(&node);
+                let (comp, args) = self.instance(node, cell)?;
+                let sig = sigs.get(&comp)?;
+                let mut ports: Vec<_> = sig
+                    .ports
+                    .keys()
+                    .filter_map(|p| Some((p, sig.width(p, &args)?)))
+                    .collect();
+                ports.sort();
+                let ports = ports
+                    .into_iter()
+                    .map(|(p, w)| format!("{p}: {w}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let inst = children_of_kind(parent, "instantiation").next()?;
+                Some(format!(
+                    "`{cell} = {}`\n\nports: {ports}",
+                    self.node_text(&inst)
+                ))
+            }
+            _ => None,
+        }
+    }
+}
@@ -9,18 +9,18 @@ module.exports = grammar({
   rules: {
     source_file: $ => seq(
       repeat($.import),
-      repeat(choice($.component, $.extern, $.primitive)),
+      repeat(choice($.component, $.extern, $.primitive, $.bundle)),
       optional($.metadata)
     ),
-    comment: $ => choice(
-      seq('//', /.*/, '\n'),
-      seq(/\/\*(.|\n)*\*\//)
-    ),
+    comment: $ => token(choice(
+      seq('//', /.*/),
+      seq('/*', /[^*]*\*+([^/*][^*]*\*+)*/, '/')
+    )),
 
     import: $ => seq('import', $.string, ';'),
 
     metadata_ci: $ => seq(/[mM][eE][tT][aA][dD][aA][tT][aA]/),
-    metadata: $ => seq($.metadata_ci, /#\{(.|\n)*\}#/),
+    metadata: $ => seq($.metadata_ci, /#\{([^}]|\}[^#])*\}#/),
 
     // components
     signature: $ => seq($.io_port_list, '->', $.io_port_list),
@@ -28,6 +28,7 @@ module.exports = grammar({
       optional($.comb_or_static),
       'component',
       $.ident, optional($.attributes),
+      optional($.params),
       $.signature, '{',
       optional($.cells),
       optional($.wires),
@@ -40,40 +41,80 @@ module.exports = grammar({
       seq('comb', $.static_annotation),
       seq($.static_annotation, 'comb')
     ),
-    io_port: $ => seq(repeat($.at_attribute), $.ident, ':', choice($.number, $.ident)),
+    io_port: $ => seq(
+      repeat($.at_attribute), $.ident, ':', choice($._width, $.bundle_type)
+    ),
     io_port_list: $ => seq('(', repeat(seq($.io_port, ',')), optional($.io_port), ')'),
 
+    // widths of generic components are arithmetic over their parameters
+    _width: $ => choice($.number, $.ident, $.width_expr),
+    width_expr: $ => choice(
+      prec.left(1, seq($._width, choice('+', '-'), $._width)),
+      prec.left(2, seq($._width, choice('*', '/'), $._width)),
+      seq('(', $._width, ')')
+    ),
+    bundle_type: $ => seq($.ident, $.arg_list),
+
+    // bundles
+    bundle_field: $ => seq(
+      repeat($.at_attribute), optional('flip'), $.ident, ':', $._width, ';'
+    ),
+    bundle: $ => seq(
+      'bundle', $.ident, optional($.attributes), optional($.params),
+      '{',
+      repeat($.bundle_field),
+      '}'
+    ),
+
     // primitives
-    params: $ => seq('[', seq(repeat(seq($.ident, ',')), $.ident), ']'),
-    any_line: $ => /([^\}\n])*\n/,
+    params: $ => seq('[', optional(seq(repeat(seq($.ident, ',')), $.ident)), ']'),
+    // the body can contain balanced braces, like the Verilog `{a, b}`
+    _blob_text: $ => /[^{}]+/,
     primitive_blob: $ => seq(
       '{',
-      repeat($.any_line),
+      repeat(choice($._blob_text, $.primitive_blob)),
       '}'
     ),
     primitive: $ => seq(
       optional($.comb_or_static), 'primitive', $.ident, optional($.attributes),
       optional($.params), $.signature,
-      optional($.primitive_blob),
-      ';'
+      choice(';', seq($.primitive_blob, optional(';')))
     ),
     extern: $ => seq('extern', $.string, '{', repeat($.primitive), '}'),
 
+    // for loops
+    for_range: $ => seq($.ident, 'in', $._width, '..', $._width),
+
     // cells
     cells: $ => seq('cells', '{', optional($.cells_inner), '}'),
-    cells_inner: $ => repeat1($.cell_assignment),
+    cells_inner: $ => repeat1(choice($.cell_assignment, $.cell_for)),
+    cell_for: $ => seq(
+      'for', $.for_range, '{',
+      repeat(choice($.cell_assignment, $.cell_for)),
+      '}'
+    ),
     cell_assignment: $ => seq(
-      optional($.at_attribute), optional('ref'),
+      repeat($.at_attribute), optional('ref'),
       $.ident, '=', $.instantiation, optional(';')
     ),
     instantiation: $ => seq($.ident, $.arg_list),
-    arg_list: $ => seq('(', repeat(seq($.number, optional(','))), ')'),
+    arg_list: $ => seq(
+      '(',
+      optional(seq(repeat(seq($._width, ',')), $._width, optional(','))),
+      ')'
+    ),
 
     // wires
     wires: $ => seq('wires', '{', optional($.wires_inner), '}'),
-    wires_inner: $ => repeat1(choice($.group, $.wire_assignment)),
+    wires_inner: $ => repeat1(choice($.group, $.wire_assignment, $.wires_for)),
+    wires_for: $ => seq(
+      'for', $.for_range, '{',
+      repeat(choice($.group, $.wire_assignment, $.wires_for)),
+      '}'
+    ),
     group: $ => seq(
-      optional('comb'), 'group', $.ident, optional($.attributes),
+      optional(choice('comb', $.static_annotation)),
+      'group', $.ident, optional($.attributes),
       '{',
       repeat($.wire_assignment),
       '}'
@@ -82,31 +123,31 @@ module.exports = grammar({
     port: $ => choice(seq($.ident, '.', $.ident), $.ident),
     lhs: $ => choice($.hole, $.port),
     base_expr: $ => choice($.hole, $.port, $.literal),
+    static_timing: $ => choice(
+      seq('%', $.number),
+      seq('%', '[', $.number, ':', $.number, ']')
+    ),
     expr: $ => choice(
       $.base_expr,
+      $.static_timing,
       $.cmp_expr,
+      $.not_expr,
+      $.logic_expr,
       seq('(', $.expr, ')'),
     ),
-    cmp_expr: $ => choice(
-      prec.left(6, seq($.expr, "==", $.expr)),
-      prec.left(5, seq($.expr, "!=", $.expr)),
-      prec.left(4, seq($.expr, "<=", $.expr)),
-      prec.left(3, seq($.expr, ">=", $.expr)),
-      prec.left(2, seq($.expr, ">", $.expr)),
-      prec.left(1, seq($.expr, "<", $.expr)),
-    ),
-    term: $ => choice(
-      $.expr,
-      seq('!', $.expr),
-      prec.left(2, seq($.expr, '|', $.expr)),
-      prec.left(1, seq($.expr, '&', $.expr))
-    ),
-    switch: $ => seq($.term, '?', $.base_expr),
-    wire_assignment: $ => seq(optional($.at_attribute), $.lhs, '=', choice($.switch, $.expr), ';'),
+    cmp_expr: $ => prec.left(3, seq(
+      $.expr, choice('==', '!=', '<=', '>=', '<', '>'), $.expr
+    )),
+    not_expr: $ => prec(4, seq('!', $.expr)),
+    logic_expr: $ => prec.left(1, seq($.expr, choice('&', '|'), $.expr)),
+    switch: $ => seq($.expr, '?', $.base_expr),
+    wire_assignment: $ => seq(
+      repeat($.at_attribute), $.lhs, '=', choice($.switch, $.expr), ';'
+    ),
 
     // control
-    control: $ => seq('control', '{', $.control_inner, '}'),
-    control_inner: $ => $.stmt,
+    control: $ => seq('control', '{', optional($.control_inner), '}'),
+    control_inner: $ => repeat1($.stmt),
     enable: $ => seq(repeat($.at_attribute), optional($.ident), ';'),
     invoke_ref_arg: $ => seq($.ident, '=', $.ident),
     invoke_ref_args: $ => seq(
@@ -142,14 +183,18 @@ module.exports = grammar({
       '}'
     ),
     port_with: $ => seq($.port, optional(seq('with', $.ident))),
-    block: $ => seq('{', $.stmt, '}'),
+    block: $ => seq('{', repeat($.stmt), '}'),
     if_stmt: $ => seq(
       repeat($.at_attribute), optional($.static_annotation),
       'if', $.port_with, $.block,
       optional(seq('else', choice($.block, $.if_stmt)))
     ),
     while_stmt: $ => seq(repeat($.at_attribute), 'while', $.port_with, $.block),
-    repeat_stmt: $ => seq(repeat($.at_attribute), optional('static'), 'repeat', $.number, $.block),
+    repeat_stmt: $ => seq(
+      repeat($.at_attribute), optional($.static_annotation),
+      'repeat', $.number, $.block
+    ),
+    for_stmt: $ => seq('for', $.for_range, '{', repeat($.stmt), '}'),
     stmt: $ => choice(
       $.enable,
       $.invoke,
@@ -157,7 +202,8 @@ module.exports = grammar({
       $.par,
       $.if_stmt,
       $.while_stmt,
-      $.repeat_stmt
+      $.repeat_stmt,
+      $.for_stmt
     ),
 
     attribute: $ => seq($.string, "=", $.number),
@@ -166,9 +212,10 @@ module.exports = grammar({
     at_attribute: $ => seq('@', $.ident, optional(seq('(', $.number, ')'))),
     static_annotation: $ => seq('static', optional($.latency_annotation)),
 
-    string: $ => /".*"/,
-    ident: $ => /[a-zA-Z_]+[a-zA-Z0-9_\-]*/,
+    string: $ => /"[^"]*"/,
+    // `{expr}` in an identifier is replaced when `for` loops are elaborated
+    ident: $ => /[a-zA-Z_]+([a-zA-Z0-9_'\-]|\{ *[a-zA-Z0-9_(][a-zA-Z0-9_ +\-*\/()]*\})*/,
     number: $ => /[0-9]+/,
-    literal: $ => seq($.number, "'", choice('d', 'b', 'x', 'o'), $.number)
+    literal: $ => seq($.number, token.immediate(/'[dbxo][0-9a-fA-F]+/))
   }
 });
@@ -6,150 +6,161 @@
 #endif
 
 #define LANGUAGE_VERSION 14
-#define STATE_COUNT 462
+#define STATE_COUNT 499
 #define LARGE_STATE_COUNT 2
-#define SYMBOL_COUNT 126
+#define SYMBOL_COUNT 140
 #define ALIAS_COUNT 0
-#define TOKEN_COUNT 58
+#define TOKEN_COUNT 61
 #define EXTERNAL_TOKEN_COUNT 0
 #define FIELD_COUNT 0
-#define MAX_ALIAS_SEQUENCE_LENGTH 10
+#define MAX_ALIAS_SEQUENCE_LENGTH 11
 #define PRODUCTION_ID_COUNT 1
 
 enum {
-  anon_sym_SLASH_SLASH = 1,
-  aux_sym_comment_token1 = 2,
-  anon_sym_LF = 3,
-  aux_sym_comment_token2 = 4,
-  anon_sym_import = 5,
-  anon_sym_SEMI = 6,
-  aux_sym_metadata_ci_token1 = 7,
-  aux_sym_metadata_token1 = 8,
-  anon_sym_DASH_GT = 9,
-  anon_sym_component = 10,
-  anon_sym_LBRACE = 11,
-  anon_sym_RBRACE = 12,
-  anon_sym_comb = 13,
-  anon_sym_COLON = 14,
-  anon_sym_LPAREN = 15,
-  anon_sym_COMMA = 16,
-  anon_sym_RPAREN = 17,
-  anon_sym_LBRACK = 18,
-  anon_sym_RBRACK = 19,
-  sym_any_line = 20,
-  anon_sym_primitive = 21,
-  anon_sym_extern = 22,
-  anon_sym_cells = 23,
-  anon_sym_ref = 24,
-  anon_sym_EQ = 25,
-  anon_sym_wires = 26,
-  anon_sym_group = 27,
-  anon_sym_DOT = 28,
-  anon_sym_EQ_EQ = 29,
-  anon_sym_BANG_EQ = 30,
-  anon_sym_LT_EQ = 31,
-  anon_sym_GT_EQ = 32,
-  anon_sym_GT = 33,
-  anon_sym_LT = 34,
-  anon_sym_BANG = 35,
-  anon_sym_PIPE = 36,
-  anon_sym_AMP = 37,
-  anon_sym_QMARK = 38,
-  anon_sym_control = 39,
-  anon_sym_invoke = 40,
-  anon_sym_with = 41,
-  anon_sym_seq = 42,
-  anon_sym_par = 43,
-  anon_sym_if = 44,
-  anon_sym_else = 45,
-  anon_sym_while = 46,
-  anon_sym_static = 47,
-  anon_sym_repeat = 48,
-  anon_sym_AT = 49,
-  sym_string = 50,
-  sym_ident = 51,
-  sym_number = 52,
-  anon_sym_SQUOTE = 53,
-  anon_sym_d = 54,
-  anon_sym_b = 55,
-  anon_sym_x = 56,
-  anon_sym_o = 57,
-  sym_source_file = 58,
-  sym_comment = 59,
-  sym_import = 60,
-  sym_metadata_ci = 61,
-  sym_metadata = 62,
-  sym_signature = 63,
-  sym_component = 64,
-  sym_comb_or_static = 65,
-  sym_io_port = 66,
-  sym_io_port_list = 67,
-  sym_params = 68,
-  sym_primitive_blob = 69,
-  sym_primitive = 70,
-  sym_extern = 71,
-  sym_cells = 72,
-  sym_cells_inner = 73,
-  sym_cell_assignment = 74,
-  sym_instantiation = 75,
-  sym_arg_list = 76,
-  sym_wires = 77,
-  sym_wires_inner = 78,
-  sym_group = 79,
-  sym_hole = 80,
-  sym_port = 81,
-  sym_lhs = 82,
-  sym_base_expr = 83,
-  sym_expr = 84,
-  sym_cmp_expr = 85,
-  sym_term = 86,
-  sym_switch = 87,
-  sym_wire_assignment = 88,
-  sym_control = 89,
-  sym_control_inner = 90,
-  sym_enable = 91,
-  sym_invoke_ref_arg = 92,
-  sym_invoke_ref_args = 93,
-  sym_invoke_arg = 94,
-  sym_invoke_args = 95,
-  sym_invoke = 96,
-  sym_seq = 97,
-  sym_par = 98,
-  sym_port_with = 99,
-  sym_block = 100,
-  sym_if_stmt = 101,
-  sym_while_stmt = 102,
-  sym_repeat_stmt = 103,
-  sym_stmt = 104,
-  sym_attribute = 105,
-  sym_attributes = 106,
-  sym_latency_annotation = 107,
-  sym_at_attribute = 108,
-  sym_static_annotation = 109,
-  sym_literal = 110,
-  aux_sym_source_file_repeat1 = 111,
-  aux_sym_source_file_repeat2 = 112,
-  aux_sym_io_port_repeat1 = 113,
-  aux_sym_io_port_list_repeat1 = 114,
-  aux_sym_params_repeat1 = 115,
-  aux_sym_primitive_blob_repeat1 = 116,
-  aux_sym_extern_repeat1 = 117,
-  aux_sym_cells_inner_repeat1 = 118,
-  aux_sym_arg_list_repeat1 = 119,
-  aux_sym_wires_inner_repeat1 = 120,
-  aux_sym_group_repeat1 = 121,
-  aux_sym_invoke_ref_args_repeat1 = 122,
-  aux_sym_invoke_args_repeat1 = 123,
-  aux_sym_seq_repeat1 = 124,
-  aux_sym_attributes_repeat1 = 125,
+  sym_comment = 1,
+  anon_sym_import = 2,
+  anon_sym_SEMI = 3,
+  aux_sym_metadata_ci_token1 = 4,
+  aux_sym_metadata_token1 = 5,
+  anon_sym_DASH_GT = 6,
+  anon_sym_component = 7,
+  anon_sym_LBRACE = 8,
+  anon_sym_RBRACE = 9,
+  anon_sym_comb = 10,
+  anon_sym_COLON = 11,
+  anon_sym_LPAREN = 12,
+  anon_sym_COMMA = 13,
+  anon_sym_RPAREN = 14,
+  anon_sym_PLUS = 15,
+  anon_sym_DASH = 16,
+  anon_sym_STAR = 17,
+  anon_sym_SLASH = 18,
+  anon_sym_flip = 19,
+  anon_sym_bundle = 20,
+  anon_sym_LBRACK = 21,
+  anon_sym_RBRACK = 22,
+  sym__blob_text = 23,
+  anon_sym_primitive = 24,
+  anon_sym_extern = 25,
+  anon_sym_in = 26,
+  anon_sym_DOT_DOT = 27,
+  anon_sym_cells = 28,
+  anon_sym_for = 29,
+  anon_sym_ref = 30,
+  anon_sym_EQ = 31,
+  anon_sym_wires = 32,
+  anon_sym_group = 33,
+  anon_sym_DOT = 34,
+  anon_sym_PERCENT = 35,
+  anon_sym_EQ_EQ = 36,
+  anon_sym_BANG_EQ = 37,
+  anon_sym_LT_EQ = 38,
+  anon_sym_GT_EQ = 39,
+  anon_sym_LT = 40,
+  anon_sym_GT = 41,
+  anon_sym_BANG = 42,
+  anon_sym_AMP = 43,
+  anon_sym_PIPE = 44,
+  anon_sym_QMARK = 45,
+  anon_sym_control = 46,
+  anon_sym_invoke = 47,
+  anon_sym_with = 48,
+  anon_sym_seq = 49,
+  anon_sym_par = 50,
+  anon_sym_if = 51,
+  anon_sym_else = 52,
+  anon_sym_while = 53,
+  anon_sym_repeat = 54,
+  anon_sym_AT = 55,
+  anon_sym_static = 56,
+  sym_string = 57,
+  sym_ident = 58,
+  sym_number = 59,
+  aux_sym_literal_token1 = 60,
+  sym_source_file = 61,
+  sym_import = 62,
+  sym_metadata_ci = 63,
+  sym_metadata = 64,
+  sym_signature = 65,
+  sym_component = 66,
+  sym_comb_or_static = 67,
+  sym_io_port = 68,
+  sym_io_port_list = 69,
+  sym__width = 70,
+  sym_width_expr = 71,
+  sym_bundle_type = 72,
+  sym_bundle_field = 73,
+  sym_bundle = 74,
+  sym_params = 75,
+  sym_primitive_blob = 76,
+  sym_primitive = 77,
+  sym_extern = 78,
+  sym_for_range = 79,
+  sym_cells = 80,
+  sym_cells_inner = 81,
+  sym_cell_for = 82,
+  sym_cell_assignment = 83,
+  sym_instantiation = 84,
+  sym_arg_list = 85,
+  sym_wires = 86,
+  sym_wires_inner = 87,
+  sym_wires_for = 88,
+  sym_group = 89,
+  sym_hole = 90,
+  sym_port = 91,
+  sym_lhs = 92,
+  sym_base_expr = 93,
+  sym_static_timing = 94,
+  sym_expr = 95,
+  sym_cmp_expr = 96,
+  sym_not_expr = 97,
+  sym_logic_expr = 98,
+  sym_switch = 99,
+  sym_wire_assignment = 100,
+  sym_control = 101,
+  sym_control_inner = 102,
+  sym_enable = 103,
+  sym_invoke_ref_arg = 104,
+  sym_invoke_ref_args = 105,
+  sym_invoke_arg = 106,
+  sym_invoke_args = 107,
+  sym_invoke = 108,
+  sym_seq = 109,
+  sym_par = 110,
+  sym_port_with = 111,
+  sym_block = 112,
+  sym_if_stmt = 113,
+  sym_while_stmt = 114,
+  sym_repeat_stmt = 115,
+  sym_for_stmt = 116,
+  sym_stmt = 117,
+  sym_attribute = 118,
+  sym_attributes = 119,
+  sym_latency_annotation = 120,
+  sym_at_attribute = 121,
+  sym_static_annotation = 122,
+  sym_literal = 123,
+  aux_sym_source_file_repeat1 = 124,
+  aux_sym_source_file_repeat2 = 125,
+  aux_sym_io_port_repeat1 = 126,
+  aux_sym_io_port_list_repeat1 = 127,
+  aux_sym_bundle_repeat1 = 128,
+  aux_sym_params_repeat1 = 129,
+  aux_sym_primitive_blob_repeat1 = 130,
+  aux_sym_extern_repeat1 = 131,
+  aux_sym_cells_inner_repeat1 = 132,
+  aux_sym_arg_list_repeat1 = 133,
+  aux_sym_wires_inner_repeat1 = 134,
+  aux_sym_group_repeat1 = 135,
+  aux_sym_control_inner_repeat1 = 136,
+  aux_sym_invoke_ref_args_repeat1 = 137,
+  aux_sym_invoke_args_repeat1 = 138,
+  aux_sym_attributes_repeat1 = 139,
 };
 
 static const char * const ts_symbol_names[] = {
   [ts_builtin_sym_end] = "end",
-  [anon_sym_SLASH_SLASH] = "//",
-  [aux_sym_comment_token1] = "comment_token1",
-  [anon_sym_LF] = "\n",
-  [aux_sym_comment_token2] = "comment_token2",
+  [sym_comment] = "comment",
   [anon_sym_import] = "import",
   [anon_sym_SEMI] = ";",
   [aux_sym_metadata_ci_token1] = "metadata_ci_token1",
@@ -163,26 +174,36 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_LPAREN] = "(",
   [anon_sym_COMMA] = ",",
   [anon_sym_RPAREN] = ")",
+  [anon_sym_PLUS] = "+",
+  [anon_sym_DASH] = "-",
+  [anon_sym_STAR] = "*",
+  [anon_sym_SLASH] = "/",
+  [anon_sym_flip] = "flip",
+  [anon_sym_bundle] = "bundle",
   [anon_sym_LBRACK] = "[",
   [anon_sym_RBRACK] = "]",
-  [sym_any_line] = "any_line",
+  [sym__blob_text] = "_blob_text",
   [anon_sym_primitive] = "primitive",
   [anon_sym_extern] = "extern",
+  [anon_sym_in] = "in",
+  [anon_sym_DOT_DOT] = "..",
   [anon_sym_cells] = "cells",
+  [anon_sym_for] = "for",
   [anon_sym_ref] = "ref",
   [anon_sym_EQ] = "=",
   [anon_sym_wires] = "wires",
   [anon_sym_group] = "group",
   [anon_sym_DOT] = ".",
+  [anon_sym_PERCENT] = "%",
   [anon_sym_EQ_EQ] = "==",
   [anon_sym_BANG_EQ] = "!=",
   [anon_sym_LT_EQ] = "<=",
   [anon_sym_GT_EQ] = ">=",
-  [anon_sym_GT] = ">",
   [anon_sym_LT] = "<",
+  [anon_sym_GT] = ">",
   [anon_sym_BANG] = "!",
-  [anon_sym_PIPE] = "|",
   [anon_sym_AMP] = "&",
+  [anon_sym_PIPE] = "|",
   [anon_sym_QMARK] = "\?",
   [anon_sym_control] = "control",
   [anon_sym_invoke] = "invoke",
@@ -192,19 +213,14 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_if] = "if",
   [anon_sym_else] = "else",
   [anon_sym_while] = "while",
-  [anon_sym_static] = "static",
   [anon_sym_repeat] = "repeat",
   [anon_sym_AT] = "@",
+  [anon_sym_static] = "static",
   [sym_string] = "string",
   [sym_ident] = "ident",
   [sym_number] = "number",
-  [anon_sym_SQUOTE] = "'",
-  [anon_sym_d] = "d",
-  [anon_sym_b] = "b",
-  [anon_sym_x] = "x",
-  [anon_sym_o] = "o",
+  [aux_sym_literal_token1] = "literal_token1",
   [sym_source_file] = "source_file",
-  [sym_comment] = "comment",
   [sym_import] = "import",
   [sym_metadata_ci] = "metadata_ci",
   [sym_metadata] = "metadata",
@@ -213,25 +229,35 @@ static const char * const ts_symbol_names[] = {
   [sym_comb_or_static] = "comb_or_static",
   [sym_io_port] = "io_port",
   [sym_io_port_list] = "io_port_list",
+  [sym__width] = "_width",
+  [sym_width_expr] = "width_expr",
+  [sym_bundle_type] = "bundle_type",
+  [sym_bundle_field] = "bundle_field",
+  [sym_bundle] = "bundle",
   [sym_params] = "params",
   [sym_primitive_blob] = "primitive_blob",
   [sym_primitive] = "primitive",
   [sym_extern] = "extern",
+  [sym_for_range] = "for_range",
   [sym_cells] = "cells",
   [sym_cells_inner] = "cells_inner",
+  [sym_cell_for] = "cell_for",
   [sym_cell_assignment] = "cell_assignment",
   [sym_instantiation] = "instantiation",
   [sym_arg_list] = "arg_list",
   [sym_wires] = "wires",
   [sym_wires_inner] = "wires_inner",
+  [sym_wires_for] = "wires_for",
   [sym_group] = "group",
   [sym_hole] = "hole",
   [sym_port] = "port",
   [sym_lhs] = "lhs",
   [sym_base_expr] = "base_expr",
+  [sym_static_timing] = "static_timing",
   [sym_expr] = "expr",
   [sym_cmp_expr] = "cmp_expr",
-  [sym_term] = "term",
+  [sym_not_expr] = "not_expr",
+  [sym_logic_expr] = "logic_expr",
   [sym_switch] = "switch",
   [sym_wire_assignment] = "wire_assignment",
   [sym_control] = "control",
@@ -249,6 +275,7 @@ static const char * const ts_symbol_names[] = {
   [sym_if_stmt] = "if_stmt",
   [sym_while_stmt] = "while_stmt",
   [sym_repeat_stmt] = "repeat_stmt",
+  [sym_for_stmt] = "for_stmt",
   [sym_stmt] = "stmt",
   [sym_attribute] = "attribute",
   [sym_attributes] = "attributes",
@@ -260,6 +287,7 @@ static const char * const ts_symbol_names[] = {
   [aux_sym_source_file_repeat2] = "source_file_repeat2",
   [aux_sym_io_port_repeat1] = "io_port_repeat1",
   [aux_sym_io_port_list_repeat1] = "io_port_list_repeat1",
+  [aux_sym_bundle_repeat1] = "bundle_repeat1",
   [aux_sym_params_repeat1] = "params_repeat1",
   [aux_sym_primitive_blob_repeat1] = "primitive_blob_repeat1",
   [aux_sym_extern_repeat1] = "extern_repeat1",
@@ -267,18 +295,15 @@ static const char * const ts_symbol_names[] = {
   [aux_sym_arg_list_repeat1] = "arg_list_repeat1",
   [aux_sym_wires_inner_repeat1] = "wires_inner_repeat1",
   [aux_sym_group_repeat1] = "group_repeat1",
+  [aux_sym_control_inner_repeat1] = "control_inner_repeat1",
   [aux_sym_invoke_ref_args_repeat1] = "invoke_ref_args_repeat1",
   [aux_sym_invoke_args_repeat1] = "invoke_args_repeat1",
-  [aux_sym_seq_repeat1] = "seq_repeat1",
   [aux_sym_attributes_repeat1] = "attributes_repeat1",
 };
 
 static const TSSymbol ts_symbol_map[] = {
   [ts_builtin_sym_end] = ts_builtin_sym_end,
-  [anon_sym_SLASH_SLASH] = anon_sym_SLASH_SLASH,
-  [aux_sym_comment_token1] = aux_sym_comment_token1,
-  [anon_sym_LF] = anon_sym_LF,
-  [aux_sym_comment_token2] = aux_sym_comment_token2,
+  [sym_comment] = sym_comment,
   [anon_sym_import] = anon_sym_import,
   [anon_sym_SEMI] = anon_sym_SEMI,
   [aux_sym_metadata_ci_token1] = aux_sym_metadata_ci_token1,
@@ -292,26 +317,36 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_LPAREN] = anon_sym_LPAREN,
   [anon_sym_COMMA] = anon_sym_COMMA,
   [anon_sym_RPAREN] = anon_sym_RPAREN,
+  [anon_sym_PLUS] = anon_sym_PLUS,
+  [anon_sym_DASH] = anon_sym_DASH,
+  [anon_sym_STAR] = anon_sym_STAR,
+  [anon_sym_SLASH] = anon_sym_SLASH,
+  [anon_sym_flip] = anon_sym_flip,
+  [anon_sym_bundle] = anon_sym_bundle,
   [anon_sym_LBRACK] = anon_sym_LBRACK,
   [anon_sym_RBRACK] = anon_sym_RBRACK,
-  [sym_any_line] = sym_any_line,
+  [sym__blob_text] = sym__blob_text,
   [anon_sym_primitive] = anon_sym_primitive,
   [anon_sym_extern] = anon_sym_extern,
+  [anon_sym_in] = anon_sym_in,
+  [anon_sym_DOT_DOT] = anon_sym_DOT_DOT,
   [anon_sym_cells] = anon_sym_cells,
+  [anon_sym_for] = anon_sym_for,
   [anon_sym_ref] = anon_sym_ref,
   [anon_sym_EQ] = anon_sym_EQ,
   [anon_sym_wires] = anon_sym_wires,
   [anon_sym_group] = anon_sym_group,
   [anon_sym_DOT] = anon_sym_DOT,
+  [anon_sym_PERCENT] = anon_sym_PERCENT,
   [anon_sym_EQ_EQ] = anon_sym_EQ_EQ,
   [anon_sym_BANG_EQ] = anon_sym_BANG_EQ,
   [anon_sym_LT_EQ] = anon_sym_LT_EQ,
   [anon_sym_GT_EQ] = anon_sym_GT_EQ,
-  [anon_sym_GT] = anon_sym_GT,
   [anon_sym_LT] = anon_sym_LT,
+  [anon_sym_GT] = anon_sym_GT,
   [anon_sym_BANG] = anon_sym_BANG,
-  [anon_sym_PIPE] = anon_sym_PIPE,
   [anon_sym_AMP] = anon_sym_AMP,
+  [anon_sym_PIPE] = anon_sym_PIPE,
   [anon_sym_QMARK] = anon_sym_QMARK,
   [anon_sym_control] = anon_sym_control,
   [anon_sym_invoke] = anon_sym_invoke,
@@ -321,19 +356,14 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_if] = anon_sym_if,
   [anon_sym_else] = anon_sym_else,
   [anon_sym_while] = anon_sym_while,
-  [anon_sym_static] = anon_sym_static,
   [anon_sym_repeat] = anon_sym_repeat,
   [anon_sym_AT] = anon_sym_AT,
+  [anon_sym_static] = anon_sym_static,
   [sym_string] = sym_string,
   [sym_ident] = sym_ident,
   [sym_number] = sym_number,
-  [anon_sym_SQUOTE] = anon_sym_SQUOTE,
-  [anon_sym_d] = anon_sym_d,
-  [anon_sym_b] = anon_sym_b,
-  [anon_sym_x] = anon_sym_x,
-  [anon_sym_o] = anon_sym_o,
+  [aux_sym_literal_token1] = aux_sym_literal_token1,
   [sym_source_file] = sym_source_file,
-  [sym_comment] = sym_comment,
   [sym_import] = sym_import,
   [sym_metadata_ci] = sym_metadata_ci,
   [sym_metadata] = sym_metadata,
@@ -342,25 +372,35 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_comb_or_static] = sym_comb_or_static,
   [sym_io_port] = sym_io_port,
   [sym_io_port_list] = sym_io_port_list,
+  [sym__width] = sym__width,
+  [sym_width_expr] = sym_width_expr,
+  [sym_bundle_type] = sym_bundle_type,
+  [sym_bundle_field] = sym_bundle_field,
+  [sym_bundle] = sym_bundle,
   [sym_params] = sym_params,
   [sym_primitive_blob] = sym_primitive_blob,
   [sym_primitive] = sym_primitive,
   [sym_extern] = sym_extern,
+  [sym_for_range] = sym_for_range,
   [sym_cells] = sym_cells,
   [sym_cells_inner] = sym_cells_inner,
+  [sym_cell_for] = sym_cell_for,
   [sym_cell_assignment] = sym_cell_assignment,
   [sym_instantiation] = sym_instantiation,
   [sym_arg_list] = sym_arg_list,
   [sym_wires] = sym_wires,
   [sym_wires_inner] = sym_wires_inner,
+  [sym_wires_for] = sym_wires_for,
   [sym_group] = sym_group,
   [sym_hole] = sym_hole,
   [sym_port] = sym_port,
   [sym_lhs] = sym_lhs,
   [sym_base_expr] = sym_base_expr,
+  [sym_static_timing] = sym_static_timing,
   [sym_expr] = sym_expr,
   [sym_cmp_expr] = sym_cmp_expr,
-  [sym_term] = sym_term,
+  [sym_not_expr] = sym_not_expr,
+  [sym_logic_expr] = sym_logic_expr,
   [sym_switch] = sym_switch,
   [sym_wire_assignment] = sym_wire_assignment,
   [sym_control] = sym_control,
@@ -378,6 +418,7 @@ static const TSSymbol ts_symbol_map[] = {
   [sym_if_stmt] = sym_if_stmt,
   [sym_while_stmt] = sym_while_stmt,
   [sym_repeat_stmt] = sym_repeat_stmt,
+  [sym_for_stmt] = sym_for_stmt,
   [sym_stmt] = sym_stmt,
   [sym_attribute] = sym_attribute,
   [sym_attributes] = sym_attributes,
@@ -389,6 +430,7 @@ static const TSSymbol ts_symbol_map[] = {
   [aux_sym_source_file_repeat2] = aux_sym_source_file_repeat2,
   [aux_sym_io_port_repeat1] = aux_sym_io_port_repeat1,
   [aux_sym_io_port_list_repeat1] = aux_sym_io_port_list_repeat1,
+  [aux_sym_bundle_repeat1] = aux_sym_bundle_repeat1,
   [aux_sym_params_repeat1] = aux_sym_params_repeat1,
   [aux_sym_primitive_blob_repeat1] = aux_sym_primitive_blob_repeat1,
   [aux_sym_extern_repeat1] = aux_sym_extern_repeat1,
@@ -396,9 +438,9 @@ static const TSSymbol ts_symbol_map[] = {
   [aux_sym_arg_list_repeat1] = aux_sym_arg_list_repeat1,
   [aux_sym_wires_inner_repeat1] = aux_sym_wires_inner_repeat1,
   [aux_sym_group_repeat1] = aux_sym_group_repeat1,
+  [aux_sym_control_inner_repeat1] = aux_sym_control_inner_repeat1,
   [aux_sym_invoke_ref_args_repeat1] = aux_sym_invoke_ref_args_repeat1,
   [aux_sym_invoke_args_repeat1] = aux_sym_invoke_args_repeat1,
-  [aux_sym_seq_repeat1] = aux_sym_seq_repeat1,
   [aux_sym_attributes_repeat1] = aux_sym_attributes_repeat1,
 };
 
@@ -407,21 +449,9 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = true,
   },
-  [anon_sym_SLASH_SLASH] = {
-    .visible = true,
-    .named = false,
-  },
-  [aux_sym_comment_token1] = {
-    .visible = false,
-    .named = false,
-  },
-  [anon_sym_LF] = {
+  [sym_comment] = {
     .visible = true,
-    .named = false,
-  },
-  [aux_sym_comment_token2] = {
-    .visible = false,
-    .named = false,
+    .named = true,
   },
   [anon_sym_import] = {
     .visible = true,
@@ -475,6 +505,30 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_PLUS] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_STAR] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_SLASH] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_flip] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_bundle] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_LBRACK] = {
     .visible = true,
     .named = false,
@@ -483,8 +537,8 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [sym_any_line] = {
-    .visible = true,
+  [sym__blob_text] = {
+    .visible = false,
     .named = true,
   },
   [anon_sym_primitive] = {
@@ -495,10 +549,22 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_in] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_DOT_DOT] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_cells] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_for] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_ref] = {
     .visible = true,
     .named = false,
@@ -519,6 +585,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_PERCENT] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_EQ_EQ] = {
     .visible = true,
     .named = false,
@@ -535,11 +605,11 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_GT] = {
+  [anon_sym_LT] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_LT] = {
+  [anon_sym_GT] = {
     .visible = true,
     .named = false,
   },
@@ -547,11 +617,11 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_PIPE] = {
+  [anon_sym_AMP] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_AMP] = {
+  [anon_sym_PIPE] = {
     .visible = true,
     .named = false,
   },
@@ -591,15 +661,15 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_static] = {
+  [anon_sym_repeat] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_repeat] = {
+  [anon_sym_AT] = {
     .visible = true,
     .named = false,
   },
-  [anon_sym_AT] = {
+  [anon_sym_static] = {
     .visible = true,
     .named = false,
   },
@@ -615,63 +685,63 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
-  [anon_sym_SQUOTE] = {
-    .visible = true,
+  [aux_sym_literal_token1] = {
+    .visible = false,
     .named = false,
   },
-  [anon_sym_d] = {
+  [sym_source_file] = {
     .visible = true,
-    .named = false,
+    .named = true,
   },
-  [anon_sym_b] = {
+  [sym_import] = {
     .visible = true,
-    .named = false,
+    .named = true,
   },
-  [anon_sym_x] = {
+  [sym_metadata_ci] = {
     .visible = true,
-    .named = false,
+    .named = true,
   },
-  [anon_sym_o] = {
+  [sym_metadata] = {
     .visible = true,
-    .named = false,
+    .named = true,
   },
-  [sym_source_file] = {
+  [sym_signature] = {
     .visible = true,
     .named = true,
   },
-  [sym_comment] = {
+  [sym_component] = {
     .visible = true,
     .named = true,
   },
-  [sym_import] = {
+  [sym_comb_or_static] = {
     .visible = true,
     .named = true,
   },
-  [sym_metadata_ci] = {
+  [sym_io_port] = {
     .visible = true,
     .named = true,
   },
-  [sym_metadata] = {
+  [sym_io_port_list] = {
     .visible = true,
     .named = true,
   },
-  [sym_signature] = {
-    .visible = true,
+  [sym__width] = {
+    .visible = false,
     .named = true,
   },
-  [sym_component] = {
+  [sym_width_expr] = {
     .visible = true,
     .named = true,
   },
-  [sym_comb_or_static] = {
+  [sym_bundle_type] = {
     .visible = true,
     .named = true,
   },
-  [sym_io_port] = {
+  [sym_bundle_field] = {
     .visible = true,
     .named = true,
   },
-  [sym_io_port_list] = {
+  [sym_bundle] = {
     .visible = true,
     .named = true,
   },
@@ -691,6 +761,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_for_range] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_cells] = {
     .visible = true,
     .named = true,
@@ -699,6 +773,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_cell_for] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_cell_assignment] = {
     .visible = true,
     .named = true,
@@ -719,6 +797,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_wires_for] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_group] = {
     .visible = true,
     .named = true,
@@ -739,6 +821,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_static_timing] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_expr] = {
     .visible = true,
     .named = true,
@@ -747,7 +833,11 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
-  [sym_term] = {
+  [sym_not_expr] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_logic_expr] = {
     .visible = true,
     .named = true,
   },
@@ -819,6 +909,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_for_stmt] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_stmt] = {
     .visible = true,
     .named = true,
@@ -863,6 +957,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
+  [aux_sym_bundle_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
   [aux_sym_params_repeat1] = {
     .visible = false,
     .named = false,
@@ -891,15 +989,15 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
-  [aux_sym_invoke_ref_args_repeat1] = {
+  [aux_sym_control_inner_repeat1] = {
     .visible = false,
     .named = false,
   },
-  [aux_sym_invoke_args_repeat1] = {
+  [aux_sym_invoke_ref_args_repeat1] = {
     .visible = false,
     .named = false,
   },
-  [aux_sym_seq_repeat1] = {
+  [aux_sym_invoke_args_repeat1] = {
     .visible = false,
     .named = false,
   },
@@ -933,28 +1031,28 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [12] = 12,
   [13] = 13,
   [14] = 14,
-  [15] = 15,
+  [15] = 4,
   [16] = 16,
-  [17] = 16,
-  [18] = 16,
+  [17] = 17,
+  [18] = 18,
   [19] = 19,
   [20] = 20,
   [21] = 21,
-  [22] = 22,
+  [22] = 11,
   [23] = 23,
   [24] = 24,
   [25] = 25,
-  [26] = 25,
+  [26] = 26,
   [27] = 27,
   [28] = 28,
   [29] = 29,
   [30] = 30,
   [31] = 31,
   [32] = 32,
-  [33] = 31,
+  [33] = 33,
   [34] = 34,
-  [35] = 35,
-  [36] = 29,
+  [35] = 33,
+  [36] = 31,
   [37] = 37,
   [38] = 38,
   [39] = 39,
@@ -965,7 +1063,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [44] = 44,
   [45] = 45,
   [46] = 46,
-  [47] = 37,
+  [47] = 47,
   [48] = 48,
   [49] = 49,
   [50] = 50,
@@ -996,13 +1094,13 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [75] = 75,
   [76] = 76,
   [77] = 77,
-  [78] = 78,
-  [79] = 79,
+  [78] = 43,
+  [79] = 45,
   [80] = 80,
-  [81] = 80,
+  [81] = 81,
   [82] = 82,
   [83] = 83,
-  [84] = 82,
+  [84] = 84,
   [85] = 85,
   [86] = 86,
   [87] = 87,
@@ -1013,13 +1111,13 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [92] = 92,
   [93] = 93,
   [94] = 94,
-  [95] = 95,
+  [95] = 94,
   [96] = 96,
-  [97] = 95,
+  [97] = 97,
   [98] = 98,
-  [99] = 88,
+  [99] = 99,
   [100] = 100,
-  [101] = 98,
+  [101] = 101,
   [102] = 102,
   [103] = 103,
   [104] = 104,
@@ -1032,7 +1130,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [111] = 111,
   [112] = 112,
   [113] = 113,
-  [114] = 114,
+  [114] = 103,
   [115] = 115,
   [116] = 116,
   [117] = 117,
@@ -1054,7 +1152,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [133] = 133,
   [134] = 134,
   [135] = 135,
-  [136] = 126,
+  [136] = 136,
   [137] = 137,
   [138] = 138,
   [139] = 139,
@@ -1066,10 +1164,10 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [145] = 145,
   [146] = 146,
   [147] = 147,
-  [148] = 128,
+  [148] = 148,
   [149] = 149,
   [150] = 150,
-  [151] = 149,
+  [151] = 151,
   [152] = 152,
   [153] = 153,
   [154] = 154,
@@ -1085,7 +1183,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [164] = 164,
   [165] = 165,
   [166] = 166,
-  [167] = 28,
+  [167] = 167,
   [168] = 168,
   [169] = 169,
   [170] = 170,
@@ -1097,9 +1195,9 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [176] = 176,
   [177] = 177,
   [178] = 178,
-  [179] = 179,
+  [179] = 161,
   [180] = 180,
-  [181] = 28,
+  [181] = 181,
   [182] = 182,
   [183] = 183,
   [184] = 184,
@@ -1107,18 +1205,18 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [186] = 186,
   [187] = 187,
   [188] = 188,
-  [189] = 185,
+  [189] = 189,
   [190] = 190,
   [191] = 191,
   [192] = 192,
-  [193] = 41,
+  [193] = 193,
   [194] = 194,
   [195] = 195,
-  [196] = 194,
-  [197] = 192,
+  [196] = 196,
+  [197] = 197,
   [198] = 198,
   [199] = 199,
-  [200] = 41,
+  [200] = 200,
   [201] = 201,
   [202] = 202,
   [203] = 203,
@@ -1134,23 +1232,23 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [213] = 213,
   [214] = 214,
   [215] = 215,
-  [216] = 85,
+  [216] = 216,
   [217] = 217,
   [218] = 218,
   [219] = 219,
   [220] = 220,
   [221] = 221,
-  [222] = 206,
+  [222] = 222,
   [223] = 223,
-  [224] = 224,
+  [224] = 80,
   [225] = 225,
   [226] = 226,
-  [227] = 227,
+  [227] = 80,
   [228] = 228,
   [229] = 229,
-  [230] = 230,
-  [231] = 231,
-  [232] = 232,
+  [230] = 80,
+  [231] = 219,
+  [232] = 217,
   [233] = 233,
   [234] = 234,
   [235] = 235,
@@ -1158,22 +1256,22 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [237] = 237,
   [238] = 238,
   [239] = 239,
-  [240] = 41,
+  [240] = 240,
   [241] = 241,
-  [242] = 215,
-  [243] = 171,
-  [244] = 154,
+  [242] = 92,
+  [243] = 243,
+  [244] = 244,
   [245] = 245,
-  [246] = 79,
+  [246] = 92,
   [247] = 247,
-  [248] = 207,
+  [248] = 80,
   [249] = 249,
-  [250] = 250,
+  [250] = 92,
   [251] = 251,
   [252] = 252,
   [253] = 253,
-  [254] = 202,
-  [255] = 205,
+  [254] = 254,
+  [255] = 255,
   [256] = 256,
   [257] = 257,
   [258] = 258,
@@ -1184,55 +1282,55 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [263] = 263,
   [264] = 264,
   [265] = 265,
-  [266] = 263,
+  [266] = 266,
   [267] = 267,
-  [268] = 32,
-  [269] = 267,
+  [268] = 92,
+  [269] = 269,
   [270] = 270,
   [271] = 271,
   [272] = 272,
-  [273] = 265,
+  [273] = 273,
   [274] = 274,
   [275] = 275,
   [276] = 276,
-  [277] = 79,
+  [277] = 277,
   [278] = 278,
   [279] = 279,
   [280] = 280,
   [281] = 281,
   [282] = 282,
-  [283] = 85,
+  [283] = 283,
   [284] = 284,
   [285] = 285,
   [286] = 286,
   [287] = 287,
   [288] = 288,
-  [289] = 258,
+  [289] = 289,
   [290] = 290,
   [291] = 291,
   [292] = 292,
   [293] = 293,
   [294] = 294,
-  [295] = 295,
-  [296] = 259,
-  [297] = 274,
-  [298] = 298,
-  [299] = 299,
-  [300] = 300,
+  [295] = 104,
+  [296] = 93,
+  [297] = 113,
+  [298] = 170,
+  [299] = 175,
+  [300] = 113,
   [301] = 301,
   [302] = 302,
-  [303] = 303,
+  [303] = 113,
   [304] = 304,
   [305] = 305,
-  [306] = 35,
+  [306] = 306,
   [307] = 307,
-  [308] = 261,
+  [308] = 308,
   [309] = 309,
   [310] = 310,
-  [311] = 311,
-  [312] = 272,
-  [313] = 85,
-  [314] = 38,
+  [311] = 113,
+  [312] = 312,
+  [313] = 313,
+  [314] = 314,
   [315] = 315,
   [316] = 316,
   [317] = 317,
@@ -1241,7 +1339,7 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [320] = 320,
   [321] = 321,
   [322] = 322,
-  [323] = 37,
+  [323] = 323,
   [324] = 324,
   [325] = 325,
   [326] = 326,
@@ -1331,55 +1429,92 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [410] = 410,
   [411] = 411,
   [412] = 412,
-  [413] = 408,
-  [414] = 343,
+  [413] = 413,
+  [414] = 414,
   [415] = 415,
   [416] = 416,
   [417] = 417,
   [418] = 418,
-  [419] = 328,
-  [420] = 398,
-  [421] = 378,
+  [419] = 419,
+  [420] = 420,
+  [421] = 421,
   [422] = 422,
-  [423] = 408,
-  [424] = 343,
-  [425] = 378,
-  [426] = 408,
-  [427] = 343,
-  [428] = 325,
+  [423] = 423,
+  [424] = 424,
+  [425] = 425,
+  [426] = 426,
+  [427] = 427,
+  [428] = 428,
   [429] = 429,
   [430] = 430,
   [431] = 431,
   [432] = 432,
   [433] = 433,
-  [434] = 417,
+  [434] = 434,
   [435] = 435,
   [436] = 436,
   [437] = 437,
   [438] = 438,
-  [439] = 352,
+  [439] = 439,
   [440] = 440,
   [441] = 441,
-  [442] = 377,
+  [442] = 442,
   [443] = 443,
-  [444] = 325,
+  [444] = 444,
   [445] = 445,
-  [446] = 325,
-  [447] = 332,
+  [446] = 446,
+  [447] = 447,
   [448] = 448,
   [449] = 449,
-  [450] = 374,
+  [450] = 450,
   [451] = 451,
-  [452] = 384,
+  [452] = 452,
   [453] = 453,
   [454] = 454,
   [455] = 455,
-  [456] = 438,
+  [456] = 456,
   [457] = 457,
   [458] = 458,
   [459] = 459,
   [460] = 460,
   [461] = 461,
+  [462] = 462,
+  [463] = 463,
+  [464] = 464,
+  [465] = 465,
+  [466] = 466,
+  [467] = 467,
+  [468] = 468,
+  [469] = 469,
+  [470] = 470,
+  [471] = 471,
+  [472] = 472,
+  [473] = 371,
+  [474] = 474,
+  [475] = 475,
+  [476] = 423,
+  [477] = 477,
+  [478] = 395,
+  [479] = 479,
+  [480] = 480,
+  [481] = 420,
+  [482] = 482,
+  [483] = 371,
+  [484] = 423,
+  [485] = 371,
+  [486] = 423,
+  [487] = 371,
+  [488] = 423,
+  [489] = 412,
+  [490] = 490,
+  [491] = 491,
+  [492] = 412,
+  [493] = 412,
+  [494] = 412,
+  [495] = 482,
+  [496] = 409,
+  [497] = 497,
+  [498] = 498,
 };
 
 static bool ts_lex(TSLexer *lexer, TSStateId state) {
@@ -1387,1248 +1522,1602 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
   eof = lexer->eof(lexer);
   switch (state) {
     case 0:
-      if (eof) ADVANCE(113);
-      if (lookahead == '!') ADVANCE(163);
-      if (lookahead == '"') ADVANCE(27);
-      if (lookahead == '#') ADVANCE(99);
-      if (lookahead == '&') ADVANCE(165);
-      if (lookahead == '\'') ADVANCE(224);
-      if (lookahead == '(') ADVANCE(137);
-      if (lookahead == ')') ADVANCE(139);
-      if (lookahead == ',') ADVANCE(138);
-      if (lookahead == '-') ADVANCE(33);
-      if (lookahead == '.') ADVANCE(155);
-      if (lookahead == '/') ADVANCE(29);
-      if (lookahead == ':') ADVANCE(136);
-      if (lookahead == ';') ADVANCE(127);
-      if (lookahead == '<') ADVANCE(161);
-      if (lookahead == '=') ADVANCE(151);
-      if (lookahead == '>') ADVANCE(160);
-      if (lookahead == '?') ADVANCE(166);
-      if (lookahead == '@') ADVANCE(185);
-      if (lookahead == '[') ADVANCE(140);
-      if (lookahead == '\\') SKIP(112)
-      if (lookahead == ']') ADVANCE(141);
-      if (lookahead == 'b') ADVANCE(226);
-      if (lookahead == 'c') ADVANCE(39);
-      if (lookahead == 'd') ADVANCE(225);
-      if (lookahead == 'e') ADVANCE(61);
-      if (lookahead == 'g') ADVANCE(79);
-      if (lookahead == 'i') ADVANCE(50);
-      if (lookahead == 'o') ADVANCE(228);
-      if (lookahead == 'p') ADVANCE(34);
-      if (lookahead == 'r') ADVANCE(40);
-      if (lookahead == 's') ADVANCE(41);
-      if (lookahead == 'w') ADVANCE(53);
-      if (lookahead == 'x') ADVANCE(227);
-      if (lookahead == '{') ADVANCE(132);
-      if (lookahead == '|') ADVANCE(164);
-      if (lookahead == '}') ADVANCE(133);
+      if (eof) ADVANCE(134);
+      if (lookahead == '!') ADVANCE(194);
+      if (lookahead == '"') ADVANCE(24);
+      if (lookahead == '#') ADVANCE(119);
+      if (lookahead == '%') ADVANCE(186);
+      if (lookahead == '&') ADVANCE(195);
+      if (lookahead == '\'') ADVANCE(129);
+      if (lookahead == '(') ADVANCE(150);
+      if (lookahead == ')') ADVANCE(152);
+      if (lookahead == '*') ADVANCE(156);
+      if (lookahead == '+') ADVANCE(153);
+      if (lookahead == ',') ADVANCE(151);
+      if (lookahead == '-') ADVANCE(155);
+      if (lookahead == '.') ADVANCE(185);
+      if (lookahead == '/') ADVANCE(157);
+      if (lookahead == ':') ADVANCE(149);
+      if (lookahead == ';') ADVANCE(140);
+      if (lookahead == '<') ADVANCE(191);
+      if (lookahead == '=') ADVANCE(180);
+      if (lookahead == '>') ADVANCE(192);
+      if (lookahead == '?') ADVANCE(197);
+      if (lookahead == '@') ADVANCE(214);
+      if (lookahead == '[') ADVANCE(161);
+      if (lookahead == '\\') SKIP(132)
+      if (lookahead == ']') ADVANCE(162);
+      if (lookahead == 'b') ADVANCE(115);
+      if (lookahead == 'c') ADVANCE(45);
+      if (lookahead == 'e') ADVANCE(72);
+      if (lookahead == 'f') ADVANCE(73);
+      if (lookahead == 'g') ADVANCE(97);
+      if (lookahead == 'i') ADVANCE(59);
+      if (lookahead == 'p') ADVANCE(39);
+      if (lookahead == 'r') ADVANCE(46);
+      if (lookahead == 's') ADVANCE(48);
+      if (lookahead == 'w') ADVANCE(63);
+      if (lookahead == '{') ADVANCE(145);
+      if (lookahead == '|') ADVANCE(196);
+      if (lookahead == '}') ADVANCE(146);
       if (lookahead == 'M' ||
-          lookahead == 'm') ADVANCE(104);
+          lookahead == 'm') ADVANCE(126);
       if (lookahead == '\t' ||
           lookahead == '\n' ||
           lookahead == '\r' ||
-          lookahead == ' ') SKIP(0)
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(223);
+          lookahead == ' ') SKIP(133)
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(260);
       END_STATE();
     case 1:
-      if (lookahead == '\n') SKIP(23)
+      if (lookahead == '\n') SKIP(20)
       END_STATE();
     case 2:
-      if (lookahead == '\n') SKIP(23)
+      if (lookahead == '\n') SKIP(20)
       if (lookahead == '\r') SKIP(1)
       END_STATE();
     case 3:
-      if (lookahead == '\n') SKIP(24)
+      if (lookahead == '\n') SKIP(21)
       END_STATE();
     case 4:
-      if (lookahead == '\n') SKIP(24)
+      if (lookahead == '\n') SKIP(21)
       if (lookahead == '\r') SKIP(3)
       END_STATE();
     case 5:
-      if (lookahead == '\n') SKIP(25)
+      if (lookahead == '\n') SKIP(23)
       END_STATE();
     case 6:
-      if (lookahead == '\n') SKIP(25)
+      if (lookahead == '\n') SKIP(23)
       if (lookahead == '\r') SKIP(5)
       END_STATE();
     case 7:
-      if (lookahead == '\n') SKIP(26)
+      if (lookahead == '\n') SKIP(22)
       END_STATE();
     case 8:
-      if (lookahead == '\n') SKIP(26)
+      if (lookahead == '\n') SKIP(22)
       if (lookahead == '\r') SKIP(7)
       END_STATE();
     case 9:
-      if (lookahead == '\n') SKIP(30)
+      if (lookahead == '\n') SKIP(34)
       END_STATE();
     case 10:
-      if (lookahead == '\n') SKIP(30)
+      if (lookahead == '\n') SKIP(34)
       if (lookahead == '\r') SKIP(9)
       END_STATE();
     case 11:
-      if (lookahead == '\n') SKIP(28)
+      if (lookahead == '\n') SKIP(26)
       END_STATE();
     case 12:
-      if (lookahead == '\n') SKIP(28)
+      if (lookahead == '\n') SKIP(26)
       if (lookahead == '\r') SKIP(11)
       END_STATE();
     case 13:
-      if (lookahead == '\n') ADVANCE(143);
-      if (lookahead == '\r') ADVANCE(15);
-      if (lookahead != 0 &&
-          lookahead != '}') ADVANCE(17);
+      if (lookahead == '\n') SKIP(29)
       END_STATE();
     case 14:
-      if (lookahead == '\n') ADVANCE(143);
-      if (lookahead == '/') ADVANCE(16);
-      if (lookahead == '\\') ADVANCE(13);
-      if (lookahead == '}') ADVANCE(133);
-      if (lookahead == '\t' ||
-          lookahead == '\r' ||
-          lookahead == ' ') ADVANCE(14);
-      if (lookahead != 0) ADVANCE(17);
+      if (lookahead == '\n') SKIP(29)
+      if (lookahead == '\r') SKIP(13)
       END_STATE();
     case 15:
-      if (lookahead == '\n') ADVANCE(143);
-      if (lookahead != 0 &&
-          lookahead != '}') ADVANCE(17);
+      if (lookahead == '\n') SKIP(27)
       END_STATE();
     case 16:
-      if (lookahead == '\n') ADVANCE(142);
-      if (lookahead == '*') ADVANCE(19);
-      if (lookahead == '/') ADVANCE(115);
-      if (lookahead != 0 &&
-          lookahead != '}') ADVANCE(17);
+      if (lookahead == '\n') SKIP(27)
+      if (lookahead == '\r') SKIP(15)
       END_STATE();
     case 17:
-      if (lookahead == '\n') ADVANCE(142);
-      if (lookahead != 0 &&
-          lookahead != '}') ADVANCE(17);
+      if (lookahead == '\n') SKIP(28)
       END_STATE();
     case 18:
-      if (lookahead == '\n') ADVANCE(144);
-      if (lookahead == '*') ADVANCE(18);
-      if (lookahead == '/') ADVANCE(124);
-      if (lookahead == '}') ADVANCE(109);
-      if (lookahead != 0) ADVANCE(19);
+      if (lookahead == '\n') SKIP(28)
+      if (lookahead == '\r') SKIP(17)
       END_STATE();
     case 19:
-      if (lookahead == '\n') ADVANCE(144);
-      if (lookahead == '*') ADVANCE(18);
-      if (lookahead == '}') ADVANCE(109);
-      if (lookahead != 0) ADVANCE(19);
+      if (lookahead == ' ') ADVANCE(19);
+      if (lookahead == '(' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(121);
       END_STATE();
     case 20:
-      if (lookahead == '\n') SKIP(22)
+      if (lookahead == '!') ADVANCE(36);
+      if (lookahead == '&') ADVANCE(195);
+      if (lookahead == '(') ADVANCE(150);
+      if (lookahead == ')') ADVANCE(152);
+      if (lookahead == '*') ADVANCE(156);
+      if (lookahead == '+') ADVANCE(153);
+      if (lookahead == ',') ADVANCE(151);
+      if (lookahead == '-') ADVANCE(154);
+      if (lookahead == '.') ADVANCE(184);
+      if (lookahead == '/') ADVANCE(157);
+      if (lookahead == ';') ADVANCE(140);
+      if (lookahead == '<') ADVANCE(191);
+      if (lookahead == '=') ADVANCE(180);
+      if (lookahead == '>') ADVANCE(192);
+      if (lookahead == '?') ADVANCE(197);
+      if (lookahead == '@') ADVANCE(214);
+      if (lookahead == '[') ADVANCE(161);
+      if (lookahead == '\\') SKIP(2)
+      if (lookahead == 'f') ADVANCE(241);
+      if (lookahead == 'i') ADVANCE(230);
+      if (lookahead == 'p') ADVANCE(219);
+      if (lookahead == 'r') ADVANCE(223);
+      if (lookahead == 's') ADVANCE(224);
+      if (lookahead == 'w') ADVANCE(232);
+      if (lookahead == '{') ADVANCE(145);
+      if (lookahead == '|') ADVANCE(196);
+      if (lookahead == '}') ADVANCE(146);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(20)
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
     case 21:
-      if (lookahead == '\n') SKIP(22)
-      if (lookahead == '\r') SKIP(20)
+      if (lookahead == '!') ADVANCE(36);
+      if (lookahead == '&') ADVANCE(195);
+      if (lookahead == ')') ADVANCE(152);
+      if (lookahead == '*') ADVANCE(156);
+      if (lookahead == '+') ADVANCE(153);
+      if (lookahead == ',') ADVANCE(151);
+      if (lookahead == '-') ADVANCE(154);
+      if (lookahead == '.') ADVANCE(33);
+      if (lookahead == '/') ADVANCE(157);
+      if (lookahead == ';') ADVANCE(140);
+      if (lookahead == '<') ADVANCE(191);
+      if (lookahead == '=') ADVANCE(37);
+      if (lookahead == '>') ADVANCE(192);
+      if (lookahead == '?') ADVANCE(197);
+      if (lookahead == '@') ADVANCE(214);
+      if (lookahead == '\\') SKIP(4)
+      if (lookahead == 'c') ADVANCE(243);
+      if (lookahead == 'f') ADVANCE(241);
+      if (lookahead == 'g') ADVANCE(251);
+      if (lookahead == 's') ADVANCE(254);
+      if (lookahead == '{') ADVANCE(145);
+      if (lookahead == '|') ADVANCE(196);
+      if (lookahead == '}') ADVANCE(146);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(21)
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
     case 22:
-      if (lookahead == '\n') ADVANCE(123);
-      if (lookahead == '/') ADVANCE(29);
-      if (lookahead == '\\') SKIP(21)
+      if (lookahead == '!') ADVANCE(36);
+      if (lookahead == '&') ADVANCE(195);
+      if (lookahead == ')') ADVANCE(152);
+      if (lookahead == ',') ADVANCE(151);
+      if (lookahead == '.') ADVANCE(184);
+      if (lookahead == '/') ADVANCE(30);
+      if (lookahead == ';') ADVANCE(140);
+      if (lookahead == '<') ADVANCE(191);
+      if (lookahead == '=') ADVANCE(180);
+      if (lookahead == '>') ADVANCE(192);
+      if (lookahead == '?') ADVANCE(197);
+      if (lookahead == '\\') SKIP(8)
+      if (lookahead == 'c') ADVANCE(89);
+      if (lookahead == 'g') ADVANCE(97);
+      if (lookahead == 'i') ADVANCE(60);
+      if (lookahead == 'p') ADVANCE(39);
+      if (lookahead == 'r') ADVANCE(57);
+      if (lookahead == 's') ADVANCE(47);
+      if (lookahead == 'w') ADVANCE(69);
+      if (lookahead == '{') ADVANCE(145);
+      if (lookahead == '|') ADVANCE(196);
       if (lookahead == '\t' ||
+          lookahead == '\n' ||
           lookahead == '\r' ||
           lookahead == ' ') SKIP(22)
       END_STATE();
     case 23:
-      if (lookahead == '!') ADVANCE(31);
-      if (lookahead == '&') ADVANCE(165);
-      if (lookahead == '(') ADVANCE(137);
-      if (lookahead == ')') ADVANCE(139);
-      if (lookahead == '.') ADVANCE(155);
-      if (lookahead == '/') ADVANCE(29);
-      if (lookahead == ';') ADVANCE(127);
-      if (lookahead == '<') ADVANCE(161);
-      if (lookahead == '=') ADVANCE(151);
-      if (lookahead == '>') ADVANCE(160);
-      if (lookahead == '?') ADVANCE(166);
-      if (lookahead == '@') ADVANCE(185);
-      if (lookahead == '[') ADVANCE(140);
-      if (lookahead == '\\') SKIP(2)
-      if (lookahead == 'i') ADVANCE(199);
-      if (lookahead == 'p') ADVANCE(187);
-      if (lookahead == 'r') ADVANCE(192);
-      if (lookahead == 's') ADVANCE(193);
-      if (lookahead == 'w') ADVANCE(201);
-      if (lookahead == '|') ADVANCE(164);
-      if (lookahead == '}') ADVANCE(133);
+      if (lookahead == '!') ADVANCE(193);
+      if (lookahead == '%') ADVANCE(186);
+      if (lookahead == '(') ADVANCE(150);
+      if (lookahead == ')') ADVANCE(152);
+      if (lookahead == '-') ADVANCE(38);
+      if (lookahead == '/') ADVANCE(30);
+      if (lookahead == ';') ADVANCE(140);
+      if (lookahead == '=') ADVANCE(179);
+      if (lookahead == '@') ADVANCE(214);
+      if (lookahead == '\\') SKIP(6)
+      if (lookahead == ']') ADVANCE(162);
+      if (lookahead == '{') ADVANCE(145);
+      if (lookahead == '}') ADVANCE(146);
       if (lookahead == '\t' ||
           lookahead == '\n' ||
           lookahead == '\r' ||
           lookahead == ' ') SKIP(23)
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(260);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
     case 24:
-      if (lookahead == '!') ADVANCE(31);
-      if (lookahead == '&') ADVANCE(165);
-      if (lookahead == ')') ADVANCE(139);
-      if (lookahead == ',') ADVANCE(138);
-      if (lookahead == '/') ADVANCE(29);
-      if (lookahead == ';') ADVANCE(127);
-      if (lookahead == '<') ADVANCE(161);
-      if (lookahead == '=') ADVANCE(151);
-      if (lookahead == '>') ADVANCE(160);
-      if (lookahead == '?') ADVANCE(166);
-      if (lookahead == '\\') SKIP(4)
-      if (lookahead == 'w') ADVANCE(58);
-      if (lookahead == '{') ADVANCE(132);
-      if (lookahead == '|') ADVANCE(164);
-      if (lookahead == '\t' ||
-          lookahead == '\n' ||
-          lookahead == '\r' ||
-          lookahead == ' ') SKIP(24)
+      if (lookahead == '"') ADVANCE(217);
+      if (lookahead != 0) ADVANCE(24);
       END_STATE();
     case 25:
-      if (lookahead == '!') ADVANCE(31);
-      if (lookahead == '&') ADVANCE(165);
-      if (lookahead == ')') ADVANCE(139);
-      if (lookahead == ',') ADVANCE(138);
-      if (lookahead == '/') ADVANCE(29);
-      if (lookahead == ';') ADVANCE(127);
-      if (lookahead == '<') ADVANCE(161);
-      if (lookahead == '=') ADVANCE(32);
-      if (lookahead == '>') ADVANCE(160);
-      if (lookahead == '?') ADVANCE(166);
-      if (lookahead == '@') ADVANCE(185);
-      if (lookahead == '\\') SKIP(6)
-      if (lookahead == 'c') ADVANCE(209);
-      if (lookahead == 'g') ADVANCE(215);
-      if (lookahead == '|') ADVANCE(164);
-      if (lookahead == '}') ADVANCE(133);
+      if (lookahead == '#') ADVANCE(142);
+      if (lookahead != 0) ADVANCE(120);
+      END_STATE();
+    case 26:
+      if (lookahead == '(') ADVANCE(150);
+      if (lookahead == '/') ADVANCE(30);
+      if (lookahead == ';') ADVANCE(140);
+      if (lookahead == '@') ADVANCE(214);
+      if (lookahead == '\\') SKIP(12)
+      if (lookahead == 'i') ADVANCE(230);
+      if (lookahead == 'p') ADVANCE(219);
+      if (lookahead == 'r') ADVANCE(223);
+      if (lookahead == 's') ADVANCE(224);
+      if (lookahead == 'w') ADVANCE(232);
       if (lookahead == '\t' ||
           lookahead == '\n' ||
           lookahead == '\r' ||
-          lookahead == ' ') SKIP(25)
+          lookahead == ' ') SKIP(26)
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 26:
-      if (lookahead == '!') ADVANCE(162);
-      if (lookahead == '(') ADVANCE(137);
-      if (lookahead == ')') ADVANCE(139);
-      if (lookahead == '/') ADVANCE(29);
-      if (lookahead == '=') ADVANCE(150);
-      if (lookahead == '@') ADVANCE(185);
-      if (lookahead == '\\') SKIP(8)
-      if (lookahead == ']') ADVANCE(141);
-      if (lookahead == '}') ADVANCE(133);
+    case 27:
+      if (lookahead == '(') ADVANCE(150);
+      if (lookahead == '/') ADVANCE(30);
+      if (lookahead == '@') ADVANCE(214);
+      if (lookahead == '\\') SKIP(16)
+      if (lookahead == 'f') ADVANCE(239);
+      if (lookahead == '}') ADVANCE(146);
       if (lookahead == '\t' ||
           lookahead == '\n' ||
           lookahead == '\r' ||
-          lookahead == ' ') SKIP(26)
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(223);
+          lookahead == ' ') SKIP(27)
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
-      END_STATE();
-    case 27:
-      if (lookahead == '"') ADVANCE(186);
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(27);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
     case 28:
-      if (lookahead == '(') ADVANCE(137);
-      if (lookahead == '/') ADVANCE(29);
-      if (lookahead == ';') ADVANCE(127);
-      if (lookahead == '@') ADVANCE(185);
-      if (lookahead == '\\') SKIP(12)
-      if (lookahead == 'r') ADVANCE(197);
-      if (lookahead == '}') ADVANCE(133);
+      if (lookahead == '(') ADVANCE(150);
+      if (lookahead == '/') ADVANCE(30);
+      if (lookahead == '@') ADVANCE(214);
+      if (lookahead == '\\') SKIP(18)
+      if (lookahead == 'r') ADVANCE(228);
       if (lookahead == '\t' ||
           lookahead == '\n' ||
           lookahead == '\r' ||
           lookahead == ' ') SKIP(28)
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
     case 29:
-      if (lookahead == '*') ADVANCE(109);
-      if (lookahead == '/') ADVANCE(114);
-      END_STATE();
-    case 30:
-      if (lookahead == '/') ADVANCE(29);
-      if (lookahead == ';') ADVANCE(127);
-      if (lookahead == '@') ADVANCE(185);
-      if (lookahead == '\\') SKIP(10)
-      if (lookahead == 'e') ADVANCE(205);
-      if (lookahead == 'i') ADVANCE(199);
-      if (lookahead == 'p') ADVANCE(187);
-      if (lookahead == 'r') ADVANCE(192);
-      if (lookahead == 's') ADVANCE(193);
-      if (lookahead == 'w') ADVANCE(201);
-      if (lookahead == '}') ADVANCE(133);
+      if (lookahead == ')') ADVANCE(152);
+      if (lookahead == ',') ADVANCE(151);
+      if (lookahead == '/') ADVANCE(30);
+      if (lookahead == ';') ADVANCE(140);
+      if (lookahead == '@') ADVANCE(214);
+      if (lookahead == '\\') SKIP(14)
+      if (lookahead == 'f') ADVANCE(241);
+      if (lookahead == 'r') ADVANCE(228);
+      if (lookahead == '}') ADVANCE(146);
       if (lookahead == '\t' ||
           lookahead == '\n' ||
           lookahead == '\r' ||
-          lookahead == ' ') SKIP(30)
+          lookahead == ' ') SKIP(29)
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
+      END_STATE();
+    case 30:
+      if (lookahead == '*') ADVANCE(32);
+      if (lookahead == '/') ADVANCE(138);
       END_STATE();
     case 31:
-      if (lookahead == '=') ADVANCE(157);
+      if (lookahead == '*') ADVANCE(31);
+      if (lookahead == '/') ADVANCE(135);
+      if (lookahead != 0) ADVANCE(32);
       END_STATE();
     case 32:
-      if (lookahead == '=') ADVANCE(156);
+      if (lookahead == '*') ADVANCE(31);
+      if (lookahead != 0) ADVANCE(32);
       END_STATE();
     case 33:
-      if (lookahead == '>') ADVANCE(130);
+      if (lookahead == '.') ADVANCE(173);
       END_STATE();
     case 34:
-      if (lookahead == 'a') ADVANCE(80);
-      if (lookahead == 'r') ADVANCE(54);
+      if (lookahead == '/') ADVANCE(30);
+      if (lookahead == ';') ADVANCE(140);
+      if (lookahead == '@') ADVANCE(214);
+      if (lookahead == '\\') SKIP(10)
+      if (lookahead == 'e') ADVANCE(237);
+      if (lookahead == 'f') ADVANCE(241);
+      if (lookahead == 'i') ADVANCE(230);
+      if (lookahead == 'p') ADVANCE(219);
+      if (lookahead == 'r') ADVANCE(223);
+      if (lookahead == 's') ADVANCE(224);
+      if (lookahead == 'w') ADVANCE(232);
+      if (lookahead == '}') ADVANCE(146);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(34)
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
     case 35:
-      if (lookahead == 'a') ADVANCE(93);
+      if (lookahead == '/') ADVANCE(165);
+      if (lookahead == '\\') ADVANCE(163);
+      if (lookahead == '{') ADVANCE(145);
+      if (lookahead == '}') ADVANCE(146);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\r' ||
+          lookahead == ' ') ADVANCE(168);
+      if (lookahead != 0) ADVANCE(169);
       END_STATE();
     case 36:
-      if (lookahead == 'a') ADVANCE(90);
+      if (lookahead == '=') ADVANCE(188);
       END_STATE();
     case 37:
-      if (lookahead == 'b') ADVANCE(134);
-      if (lookahead == 'p') ADVANCE(73);
+      if (lookahead == '=') ADVANCE(187);
       END_STATE();
     case 38:
-      if (lookahead == 'c') ADVANCE(181);
+      if (lookahead == '>') ADVANCE(143);
       END_STATE();
     case 39:
-      if (lookahead == 'e') ADVANCE(63);
-      if (lookahead == 'o') ADVANCE(66);
+      if (lookahead == 'a') ADVANCE(99);
+      if (lookahead == 'r') ADVANCE(64);
       END_STATE();
     case 40:
-      if (lookahead == 'e') ADVANCE(51);
+      if (lookahead == 'a') ADVANCE(112);
       END_STATE();
     case 41:
-      if (lookahead == 'e') ADVANCE(78);
-      if (lookahead == 't') ADVANCE(35);
+      if (lookahead == 'a') ADVANCE(109);
       END_STATE();
     case 42:
-      if (lookahead == 'e') ADVANCE(177);
+      if (lookahead == 'b') ADVANCE(147);
+      if (lookahead == 'p') ADVANCE(88);
       END_STATE();
     case 43:
-      if (lookahead == 'e') ADVANCE(179);
+      if (lookahead == 'c') ADVANCE(215);
       END_STATE();
     case 44:
-      if (lookahead == 'e') ADVANCE(168);
+      if (lookahead == 'd') ADVANCE(78);
       END_STATE();
     case 45:
-      if (lookahead == 'e') ADVANCE(145);
+      if (lookahead == 'e') ADVANCE(75);
+      if (lookahead == 'o') ADVANCE(80);
       END_STATE();
     case 46:
-      if (lookahead == 'e') ADVANCE(81);
+      if (lookahead == 'e') ADVANCE(61);
       END_STATE();
     case 47:
-      if (lookahead == 'e') ADVANCE(86);
+      if (lookahead == 'e') ADVANCE(96);
       END_STATE();
     case 48:
-      if (lookahead == 'e') ADVANCE(69);
+      if (lookahead == 'e') ADVANCE(96);
+      if (lookahead == 't') ADVANCE(40);
       END_STATE();
     case 49:
-      if (lookahead == 'e') ADVANCE(36);
+      if (lookahead == 'e') ADVANCE(208);
       END_STATE();
     case 50:
-      if (lookahead == 'f') ADVANCE(175);
-      if (lookahead == 'm') ADVANCE(77);
-      if (lookahead == 'n') ADVANCE(97);
+      if (lookahead == 'e') ADVANCE(210);
       END_STATE();
     case 51:
-      if (lookahead == 'f') ADVANCE(148);
-      if (lookahead == 'p') ADVANCE(49);
+      if (lookahead == 'e') ADVANCE(160);
       END_STATE();
     case 52:
-      if (lookahead == 'h') ADVANCE(170);
+      if (lookahead == 'e') ADVANCE(170);
       END_STATE();
     case 53:
-      if (lookahead == 'h') ADVANCE(57);
-      if (lookahead == 'i') ADVANCE(83);
+      if (lookahead == 'e') ADVANCE(199);
       END_STATE();
     case 54:
-      if (lookahead == 'i') ADVANCE(67);
+      if (lookahead == 'e') ADVANCE(105);
       END_STATE();
     case 55:
-      if (lookahead == 'i') ADVANCE(38);
+      if (lookahead == 'e') ADVANCE(100);
       END_STATE();
     case 56:
-      if (lookahead == 'i') ADVANCE(98);
+      if (lookahead == 'e') ADVANCE(84);
       END_STATE();
     case 57:
-      if (lookahead == 'i') ADVANCE(65);
+      if (lookahead == 'e') ADVANCE(95);
       END_STATE();
     case 58:
-      if (lookahead == 'i') ADVANCE(88);
+      if (lookahead == 'e') ADVANCE(41);
       END_STATE();
     case 59:
-      if (lookahead == 'i') ADVANCE(94);
+      if (lookahead == 'f') ADVANCE(206);
+      if (lookahead == 'm') ADVANCE(94);
+      if (lookahead == 'n') ADVANCE(172);
       END_STATE();
     case 60:
-      if (lookahead == 'k') ADVANCE(44);
+      if (lookahead == 'f') ADVANCE(206);
+      if (lookahead == 'n') ADVANCE(117);
       END_STATE();
     case 61:
-      if (lookahead == 'l') ADVANCE(87);
-      if (lookahead == 'x') ADVANCE(92);
+      if (lookahead == 'f') ADVANCE(177);
+      if (lookahead == 'p') ADVANCE(58);
       END_STATE();
     case 62:
-      if (lookahead == 'l') ADVANCE(167);
+      if (lookahead == 'h') ADVANCE(201);
       END_STATE();
     case 63:
-      if (lookahead == 'l') ADVANCE(64);
+      if (lookahead == 'h') ADVANCE(68);
+      if (lookahead == 'i') ADVANCE(103);
       END_STATE();
     case 64:
-      if (lookahead == 'l') ADVANCE(85);
+      if (lookahead == 'i') ADVANCE(81);
       END_STATE();
     case 65:
-      if (lookahead == 'l') ADVANCE(43);
+      if (lookahead == 'i') ADVANCE(43);
       END_STATE();
     case 66:
-      if (lookahead == 'm') ADVANCE(37);
-      if (lookahead == 'n') ADVANCE(95);
+      if (lookahead == 'i') ADVANCE(118);
       END_STATE();
     case 67:
-      if (lookahead == 'm') ADVANCE(59);
+      if (lookahead == 'i') ADVANCE(92);
       END_STATE();
     case 68:
-      if (lookahead == 'n') ADVANCE(146);
+      if (lookahead == 'i') ADVANCE(77);
       END_STATE();
     case 69:
-      if (lookahead == 'n') ADVANCE(91);
+      if (lookahead == 'i') ADVANCE(107);
       END_STATE();
     case 70:
-      if (lookahead == 'n') ADVANCE(48);
+      if (lookahead == 'i') ADVANCE(113);
       END_STATE();
     case 71:
-      if (lookahead == 'o') ADVANCE(96);
+      if (lookahead == 'k') ADVANCE(53);
       END_STATE();
     case 72:
-      if (lookahead == 'o') ADVANCE(60);
+      if (lookahead == 'l') ADVANCE(106);
+      if (lookahead == 'x') ADVANCE(111);
       END_STATE();
     case 73:
-      if (lookahead == 'o') ADVANCE(70);
+      if (lookahead == 'l') ADVANCE(67);
+      if (lookahead == 'o') ADVANCE(98);
       END_STATE();
     case 74:
-      if (lookahead == 'o') ADVANCE(82);
+      if (lookahead == 'l') ADVANCE(198);
       END_STATE();
     case 75:
-      if (lookahead == 'o') ADVANCE(62);
+      if (lookahead == 'l') ADVANCE(76);
       END_STATE();
     case 76:
-      if (lookahead == 'p') ADVANCE(153);
+      if (lookahead == 'l') ADVANCE(104);
       END_STATE();
     case 77:
-      if (lookahead == 'p') ADVANCE(74);
+      if (lookahead == 'l') ADVANCE(50);
       END_STATE();
     case 78:
-      if (lookahead == 'q') ADVANCE(171);
+      if (lookahead == 'l') ADVANCE(51);
       END_STATE();
     case 79:
-      if (lookahead == 'r') ADVANCE(71);
+      if (lookahead == 'm') ADVANCE(42);
       END_STATE();
     case 80:
-      if (lookahead == 'r') ADVANCE(173);
+      if (lookahead == 'm') ADVANCE(42);
+      if (lookahead == 'n') ADVANCE(114);
       END_STATE();
     case 81:
-      if (lookahead == 'r') ADVANCE(68);
+      if (lookahead == 'm') ADVANCE(70);
       END_STATE();
     case 82:
-      if (lookahead == 'r') ADVANCE(89);
+      if (lookahead == 'n') ADVANCE(44);
       END_STATE();
     case 83:
-      if (lookahead == 'r') ADVANCE(47);
-      if (lookahead == 't') ADVANCE(52);
+      if (lookahead == 'n') ADVANCE(171);
       END_STATE();
     case 84:
-      if (lookahead == 'r') ADVANCE(75);
+      if (lookahead == 'n') ADVANCE(110);
       END_STATE();
     case 85:
-      if (lookahead == 's') ADVANCE(147);
+      if (lookahead == 'n') ADVANCE(56);
       END_STATE();
     case 86:
-      if (lookahead == 's') ADVANCE(152);
+      if (lookahead == 'o') ADVANCE(71);
       END_STATE();
     case 87:
-      if (lookahead == 's') ADVANCE(42);
+      if (lookahead == 'o') ADVANCE(116);
       END_STATE();
     case 88:
-      if (lookahead == 't') ADVANCE(52);
+      if (lookahead == 'o') ADVANCE(85);
       END_STATE();
     case 89:
-      if (lookahead == 't') ADVANCE(126);
+      if (lookahead == 'o') ADVANCE(79);
       END_STATE();
     case 90:
-      if (lookahead == 't') ADVANCE(183);
+      if (lookahead == 'o') ADVANCE(74);
       END_STATE();
     case 91:
-      if (lookahead == 't') ADVANCE(131);
+      if (lookahead == 'o') ADVANCE(101);
       END_STATE();
     case 92:
-      if (lookahead == 't') ADVANCE(46);
+      if (lookahead == 'p') ADVANCE(158);
       END_STATE();
     case 93:
-      if (lookahead == 't') ADVANCE(55);
+      if (lookahead == 'p') ADVANCE(182);
       END_STATE();
     case 94:
-      if (lookahead == 't') ADVANCE(56);
+      if (lookahead == 'p') ADVANCE(91);
       END_STATE();
     case 95:
-      if (lookahead == 't') ADVANCE(84);
+      if (lookahead == 'p') ADVANCE(58);
       END_STATE();
     case 96:
-      if (lookahead == 'u') ADVANCE(76);
+      if (lookahead == 'q') ADVANCE(202);
       END_STATE();
     case 97:
-      if (lookahead == 'v') ADVANCE(72);
+      if (lookahead == 'r') ADVANCE(87);
       END_STATE();
     case 98:
-      if (lookahead == 'v') ADVANCE(45);
+      if (lookahead == 'r') ADVANCE(175);
       END_STATE();
     case 99:
-      if (lookahead == '{') ADVANCE(110);
+      if (lookahead == 'r') ADVANCE(204);
       END_STATE();
     case 100:
-      if (lookahead == 'A' ||
-          lookahead == 'a') ADVANCE(103);
+      if (lookahead == 'r') ADVANCE(83);
       END_STATE();
     case 101:
-      if (lookahead == 'A' ||
-          lookahead == 'a') ADVANCE(128);
+      if (lookahead == 'r') ADVANCE(108);
       END_STATE();
     case 102:
-      if (lookahead == 'A' ||
-          lookahead == 'a') ADVANCE(106);
+      if (lookahead == 'r') ADVANCE(90);
       END_STATE();
     case 103:
-      if (lookahead == 'D' ||
-          lookahead == 'd') ADVANCE(102);
+      if (lookahead == 'r') ADVANCE(54);
+      if (lookahead == 't') ADVANCE(62);
       END_STATE();
     case 104:
-      if (lookahead == 'E' ||
-          lookahead == 'e') ADVANCE(105);
+      if (lookahead == 's') ADVANCE(174);
       END_STATE();
     case 105:
-      if (lookahead == 'T' ||
-          lookahead == 't') ADVANCE(100);
+      if (lookahead == 's') ADVANCE(181);
       END_STATE();
     case 106:
-      if (lookahead == 'T' ||
-          lookahead == 't') ADVANCE(101);
+      if (lookahead == 's') ADVANCE(49);
       END_STATE();
     case 107:
-      if (lookahead != 0 &&
-          lookahead != '#' &&
-          lookahead != '}') ADVANCE(110);
-      if (lookahead == '#') ADVANCE(129);
-      if (lookahead == '}') ADVANCE(107);
+      if (lookahead == 't') ADVANCE(62);
       END_STATE();
     case 108:
-      if (lookahead != 0 &&
-          lookahead != '*' &&
-          lookahead != '/') ADVANCE(109);
-      if (lookahead == '*') ADVANCE(108);
-      if (lookahead == '/') ADVANCE(125);
+      if (lookahead == 't') ADVANCE(139);
       END_STATE();
     case 109:
-      if (lookahead != 0 &&
-          lookahead != '*') ADVANCE(109);
-      if (lookahead == '*') ADVANCE(108);
+      if (lookahead == 't') ADVANCE(212);
       END_STATE();
     case 110:
-      if (lookahead != 0 &&
-          lookahead != '}') ADVANCE(110);
-      if (lookahead == '}') ADVANCE(107);
+      if (lookahead == 't') ADVANCE(144);
       END_STATE();
     case 111:
-      if (eof) ADVANCE(113);
-      if (lookahead == '\n') SKIP(0)
+      if (lookahead == 't') ADVANCE(55);
       END_STATE();
     case 112:
-      if (eof) ADVANCE(113);
-      if (lookahead == '\n') SKIP(0)
-      if (lookahead == '\r') SKIP(111)
+      if (lookahead == 't') ADVANCE(65);
       END_STATE();
     case 113:
-      ACCEPT_TOKEN(ts_builtin_sym_end);
+      if (lookahead == 't') ADVANCE(66);
       END_STATE();
     case 114:
-      ACCEPT_TOKEN(anon_sym_SLASH_SLASH);
+      if (lookahead == 't') ADVANCE(102);
       END_STATE();
     case 115:
-      ACCEPT_TOKEN(anon_sym_SLASH_SLASH);
-      if (lookahead == '\n') ADVANCE(142);
-      if (lookahead != 0 &&
-          lookahead != '}') ADVANCE(17);
+      if (lookahead == 'u') ADVANCE(82);
       END_STATE();
     case 116:
-      ACCEPT_TOKEN(anon_sym_SLASH_SLASH);
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(122);
+      if (lookahead == 'u') ADVANCE(93);
       END_STATE();
     case 117:
-      ACCEPT_TOKEN(aux_sym_comment_token1);
-      if (lookahead == '\n') ADVANCE(109);
-      if (lookahead == '*') ADVANCE(117);
-      if (lookahead == '/') ADVANCE(118);
-      if (lookahead != 0) ADVANCE(118);
+      if (lookahead == 'v') ADVANCE(86);
       END_STATE();
     case 118:
-      ACCEPT_TOKEN(aux_sym_comment_token1);
-      if (lookahead == '\n') ADVANCE(109);
-      if (lookahead == '*') ADVANCE(117);
-      if (lookahead != 0) ADVANCE(118);
+      if (lookahead == 'v') ADVANCE(52);
       END_STATE();
     case 119:
-      ACCEPT_TOKEN(aux_sym_comment_token1);
-      if (lookahead == '\r') ADVANCE(122);
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(122);
+      if (lookahead == '{') ADVANCE(120);
       END_STATE();
     case 120:
-      ACCEPT_TOKEN(aux_sym_comment_token1);
-      if (lookahead == '*') ADVANCE(118);
-      if (lookahead == '/') ADVANCE(116);
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(122);
+      if (lookahead == '}') ADVANCE(25);
+      if (lookahead != 0) ADVANCE(120);
       END_STATE();
     case 121:
-      ACCEPT_TOKEN(aux_sym_comment_token1);
-      if (lookahead == '/') ADVANCE(120);
-      if (lookahead == '\\') ADVANCE(119);
-      if (lookahead == '\t' ||
-          lookahead == '\r' ||
-          lookahead == ' ') ADVANCE(121);
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(122);
+      if (lookahead == '}') ADVANCE(259);
+      if (lookahead == ' ' ||
+          ('(' <= lookahead && lookahead <= '+') ||
+          lookahead == '-' ||
+          ('/' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(121);
       END_STATE();
     case 122:
-      ACCEPT_TOKEN(aux_sym_comment_token1);
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(122);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(125);
       END_STATE();
     case 123:
-      ACCEPT_TOKEN(anon_sym_LF);
-      if (lookahead == '\n') ADVANCE(123);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(141);
       END_STATE();
     case 124:
-      ACCEPT_TOKEN(aux_sym_comment_token2);
-      if (lookahead == '\n') ADVANCE(144);
-      if (lookahead == '*') ADVANCE(18);
-      if (lookahead == '}') ADVANCE(109);
-      if (lookahead != 0) ADVANCE(19);
+      if (lookahead == 'A' ||
+          lookahead == 'a') ADVANCE(128);
       END_STATE();
     case 125:
-      ACCEPT_TOKEN(aux_sym_comment_token2);
-      if (lookahead != 0 &&
-          lookahead != '*') ADVANCE(109);
-      if (lookahead == '*') ADVANCE(108);
+      if (lookahead == 'D' ||
+          lookahead == 'd') ADVANCE(124);
       END_STATE();
     case 126:
-      ACCEPT_TOKEN(anon_sym_import);
+      if (lookahead == 'E' ||
+          lookahead == 'e') ADVANCE(127);
       END_STATE();
     case 127:
-      ACCEPT_TOKEN(anon_sym_SEMI);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(122);
       END_STATE();
     case 128:
-      ACCEPT_TOKEN(aux_sym_metadata_ci_token1);
+      if (lookahead == 'T' ||
+          lookahead == 't') ADVANCE(123);
       END_STATE();
     case 129:
-      ACCEPT_TOKEN(aux_sym_metadata_token1);
-      if (lookahead != 0 &&
-          lookahead != '}') ADVANCE(110);
-      if (lookahead == '}') ADVANCE(107);
+      if (lookahead == 'b' ||
+          lookahead == 'd' ||
+          lookahead == 'o' ||
+          lookahead == 'x') ADVANCE(130);
       END_STATE();
     case 130:
-      ACCEPT_TOKEN(anon_sym_DASH_GT);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(261);
       END_STATE();
     case 131:
-      ACCEPT_TOKEN(anon_sym_component);
+      if (eof) ADVANCE(134);
+      if (lookahead == '\n') SKIP(133)
       END_STATE();
     case 132:
-      ACCEPT_TOKEN(anon_sym_LBRACE);
+      if (eof) ADVANCE(134);
+      if (lookahead == '\n') SKIP(133)
+      if (lookahead == '\r') SKIP(131)
       END_STATE();
     case 133:
-      ACCEPT_TOKEN(anon_sym_RBRACE);
+      if (eof) ADVANCE(134);
+      if (lookahead == '!') ADVANCE(194);
+      if (lookahead == '"') ADVANCE(24);
+      if (lookahead == '#') ADVANCE(119);
+      if (lookahead == '%') ADVANCE(186);
+      if (lookahead == '&') ADVANCE(195);
+      if (lookahead == '(') ADVANCE(150);
+      if (lookahead == ')') ADVANCE(152);
+      if (lookahead == '*') ADVANCE(156);
+      if (lookahead == '+') ADVANCE(153);
+      if (lookahead == ',') ADVANCE(151);
+      if (lookahead == '-') ADVANCE(155);
+      if (lookahead == '.') ADVANCE(185);
+      if (lookahead == '/') ADVANCE(157);
+      if (lookahead == ':') ADVANCE(149);
+      if (lookahead == ';') ADVANCE(140);
+      if (lookahead == '<') ADVANCE(191);
+      if (lookahead == '=') ADVANCE(180);
+      if (lookahead == '>') ADVANCE(192);
+      if (lookahead == '?') ADVANCE(197);
+      if (lookahead == '@') ADVANCE(214);
+      if (lookahead == '[') ADVANCE(161);
+      if (lookahead == '\\') SKIP(132)
+      if (lookahead == ']') ADVANCE(162);
+      if (lookahead == 'b') ADVANCE(115);
+      if (lookahead == 'c') ADVANCE(45);
+      if (lookahead == 'e') ADVANCE(72);
+      if (lookahead == 'f') ADVANCE(73);
+      if (lookahead == 'g') ADVANCE(97);
+      if (lookahead == 'i') ADVANCE(59);
+      if (lookahead == 'p') ADVANCE(39);
+      if (lookahead == 'r') ADVANCE(46);
+      if (lookahead == 's') ADVANCE(48);
+      if (lookahead == 'w') ADVANCE(63);
+      if (lookahead == '{') ADVANCE(145);
+      if (lookahead == '|') ADVANCE(196);
+      if (lookahead == '}') ADVANCE(146);
+      if (lookahead == 'M' ||
+          lookahead == 'm') ADVANCE(126);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(133)
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(260);
       END_STATE();
     case 134:
-      ACCEPT_TOKEN(anon_sym_comb);
+      ACCEPT_TOKEN(ts_builtin_sym_end);
       END_STATE();
     case 135:
-      ACCEPT_TOKEN(anon_sym_comb);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+      ACCEPT_TOKEN(sym_comment);
       END_STATE();
     case 136:
-      ACCEPT_TOKEN(anon_sym_COLON);
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead == '\n') ADVANCE(169);
+      if (lookahead == '{' ||
+          lookahead == '}') ADVANCE(138);
+      if (lookahead != 0) ADVANCE(136);
       END_STATE();
     case 137:
-      ACCEPT_TOKEN(anon_sym_LPAREN);
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead != 0 &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(169);
       END_STATE();
     case 138:
-      ACCEPT_TOKEN(anon_sym_COMMA);
+      ACCEPT_TOKEN(sym_comment);
+      if (lookahead != 0 &&
+          lookahead != '\n') ADVANCE(138);
       END_STATE();
     case 139:
-      ACCEPT_TOKEN(anon_sym_RPAREN);
+      ACCEPT_TOKEN(anon_sym_import);
       END_STATE();
     case 140:
-      ACCEPT_TOKEN(anon_sym_LBRACK);
+      ACCEPT_TOKEN(anon_sym_SEMI);
       END_STATE();
     case 141:
-      ACCEPT_TOKEN(anon_sym_RBRACK);
+      ACCEPT_TOKEN(aux_sym_metadata_ci_token1);
       END_STATE();
     case 142:
-      ACCEPT_TOKEN(sym_any_line);
+      ACCEPT_TOKEN(aux_sym_metadata_token1);
       END_STATE();
     case 143:
-      ACCEPT_TOKEN(sym_any_line);
-      if (lookahead == '\n') ADVANCE(143);
-      if (lookahead == '/') ADVANCE(16);
-      if (lookahead == '\\') ADVANCE(13);
+      ACCEPT_TOKEN(anon_sym_DASH_GT);
+      END_STATE();
+    case 144:
+      ACCEPT_TOKEN(anon_sym_component);
+      END_STATE();
+    case 145:
+      ACCEPT_TOKEN(anon_sym_LBRACE);
+      END_STATE();
+    case 146:
+      ACCEPT_TOKEN(anon_sym_RBRACE);
+      END_STATE();
+    case 147:
+      ACCEPT_TOKEN(anon_sym_comb);
+      END_STATE();
+    case 148:
+      ACCEPT_TOKEN(anon_sym_comb);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
+      END_STATE();
+    case 149:
+      ACCEPT_TOKEN(anon_sym_COLON);
+      END_STATE();
+    case 150:
+      ACCEPT_TOKEN(anon_sym_LPAREN);
+      END_STATE();
+    case 151:
+      ACCEPT_TOKEN(anon_sym_COMMA);
+      END_STATE();
+    case 152:
+      ACCEPT_TOKEN(anon_sym_RPAREN);
+      END_STATE();
+    case 153:
+      ACCEPT_TOKEN(anon_sym_PLUS);
+      END_STATE();
+    case 154:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      END_STATE();
+    case 155:
+      ACCEPT_TOKEN(anon_sym_DASH);
+      if (lookahead == '>') ADVANCE(143);
+      END_STATE();
+    case 156:
+      ACCEPT_TOKEN(anon_sym_STAR);
+      END_STATE();
+    case 157:
+      ACCEPT_TOKEN(anon_sym_SLASH);
+      if (lookahead == '*') ADVANCE(32);
+      if (lookahead == '/') ADVANCE(138);
+      END_STATE();
+    case 158:
+      ACCEPT_TOKEN(anon_sym_flip);
+      END_STATE();
+    case 159:
+      ACCEPT_TOKEN(anon_sym_flip);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
+      END_STATE();
+    case 160:
+      ACCEPT_TOKEN(anon_sym_bundle);
+      END_STATE();
+    case 161:
+      ACCEPT_TOKEN(anon_sym_LBRACK);
+      END_STATE();
+    case 162:
+      ACCEPT_TOKEN(anon_sym_RBRACK);
+      END_STATE();
+    case 163:
+      ACCEPT_TOKEN(sym__blob_text);
+      if (lookahead == '\n') ADVANCE(168);
+      if (lookahead == '\r') ADVANCE(164);
+      if (lookahead != 0 &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(169);
+      END_STATE();
+    case 164:
+      ACCEPT_TOKEN(sym__blob_text);
+      if (lookahead == '\n') ADVANCE(168);
+      if (lookahead != 0 &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(169);
+      END_STATE();
+    case 165:
+      ACCEPT_TOKEN(sym__blob_text);
+      if (lookahead == '*') ADVANCE(167);
+      if (lookahead == '/') ADVANCE(136);
+      if (lookahead != 0 &&
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(169);
+      END_STATE();
+    case 166:
+      ACCEPT_TOKEN(sym__blob_text);
+      if (lookahead == '*') ADVANCE(166);
+      if (lookahead == '/') ADVANCE(137);
+      if (lookahead == '{' ||
+          lookahead == '}') ADVANCE(32);
+      if (lookahead != 0) ADVANCE(167);
+      END_STATE();
+    case 167:
+      ACCEPT_TOKEN(sym__blob_text);
+      if (lookahead == '*') ADVANCE(166);
+      if (lookahead == '{' ||
+          lookahead == '}') ADVANCE(32);
+      if (lookahead != 0) ADVANCE(167);
+      END_STATE();
+    case 168:
+      ACCEPT_TOKEN(sym__blob_text);
+      if (lookahead == '/') ADVANCE(165);
+      if (lookahead == '\\') ADVANCE(163);
       if (lookahead == '\t' ||
+          lookahead == '\n' ||
           lookahead == '\r' ||
-          lookahead == ' ') ADVANCE(14);
+          lookahead == ' ') ADVANCE(168);
       if (lookahead != 0 &&
-          lookahead != '}') ADVANCE(17);
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(169);
       END_STATE();
-    case 144:
-      ACCEPT_TOKEN(sym_any_line);
+    case 169:
+      ACCEPT_TOKEN(sym__blob_text);
       if (lookahead != 0 &&
-          lookahead != '*') ADVANCE(109);
-      if (lookahead == '*') ADVANCE(108);
+          lookahead != '{' &&
+          lookahead != '}') ADVANCE(169);
       END_STATE();
-    case 145:
+    case 170:
       ACCEPT_TOKEN(anon_sym_primitive);
       END_STATE();
-    case 146:
+    case 171:
       ACCEPT_TOKEN(anon_sym_extern);
       END_STATE();
-    case 147:
+    case 172:
+      ACCEPT_TOKEN(anon_sym_in);
+      END_STATE();
+    case 173:
+      ACCEPT_TOKEN(anon_sym_DOT_DOT);
+      END_STATE();
+    case 174:
       ACCEPT_TOKEN(anon_sym_cells);
       END_STATE();
-    case 148:
+    case 175:
+      ACCEPT_TOKEN(anon_sym_for);
+      END_STATE();
+    case 176:
+      ACCEPT_TOKEN(anon_sym_for);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
+      END_STATE();
+    case 177:
       ACCEPT_TOKEN(anon_sym_ref);
       END_STATE();
-    case 149:
+    case 178:
       ACCEPT_TOKEN(anon_sym_ref);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 150:
+    case 179:
       ACCEPT_TOKEN(anon_sym_EQ);
       END_STATE();
-    case 151:
+    case 180:
       ACCEPT_TOKEN(anon_sym_EQ);
-      if (lookahead == '=') ADVANCE(156);
+      if (lookahead == '=') ADVANCE(187);
       END_STATE();
-    case 152:
+    case 181:
       ACCEPT_TOKEN(anon_sym_wires);
       END_STATE();
-    case 153:
+    case 182:
       ACCEPT_TOKEN(anon_sym_group);
       END_STATE();
-    case 154:
+    case 183:
       ACCEPT_TOKEN(anon_sym_group);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 155:
+    case 184:
       ACCEPT_TOKEN(anon_sym_DOT);
       END_STATE();
-    case 156:
+    case 185:
+      ACCEPT_TOKEN(anon_sym_DOT);
+      if (lookahead == '.') ADVANCE(173);
+      END_STATE();
+    case 186:
+      ACCEPT_TOKEN(anon_sym_PERCENT);
+      END_STATE();
+    case 187:
       ACCEPT_TOKEN(anon_sym_EQ_EQ);
       END_STATE();
-    case 157:
+    case 188:
       ACCEPT_TOKEN(anon_sym_BANG_EQ);
       END_STATE();
-    case 158:
+    case 189:
       ACCEPT_TOKEN(anon_sym_LT_EQ);
       END_STATE();
-    case 159:
+    case 190:
       ACCEPT_TOKEN(anon_sym_GT_EQ);
       END_STATE();
-    case 160:
-      ACCEPT_TOKEN(anon_sym_GT);
-      if (lookahead == '=') ADVANCE(159);
-      END_STATE();
-    case 161:
+    case 191:
       ACCEPT_TOKEN(anon_sym_LT);
-      if (lookahead == '=') ADVANCE(158);
+      if (lookahead == '=') ADVANCE(189);
       END_STATE();
-    case 162:
-      ACCEPT_TOKEN(anon_sym_BANG);
+    case 192:
+      ACCEPT_TOKEN(anon_sym_GT);
+      if (lookahead == '=') ADVANCE(190);
       END_STATE();
-    case 163:
+    case 193:
       ACCEPT_TOKEN(anon_sym_BANG);
-      if (lookahead == '=') ADVANCE(157);
       END_STATE();
-    case 164:
-      ACCEPT_TOKEN(anon_sym_PIPE);
+    case 194:
+      ACCEPT_TOKEN(anon_sym_BANG);
+      if (lookahead == '=') ADVANCE(188);
       END_STATE();
-    case 165:
+    case 195:
       ACCEPT_TOKEN(anon_sym_AMP);
       END_STATE();
-    case 166:
+    case 196:
+      ACCEPT_TOKEN(anon_sym_PIPE);
+      END_STATE();
+    case 197:
       ACCEPT_TOKEN(anon_sym_QMARK);
       END_STATE();
-    case 167:
+    case 198:
       ACCEPT_TOKEN(anon_sym_control);
       END_STATE();
-    case 168:
+    case 199:
       ACCEPT_TOKEN(anon_sym_invoke);
       END_STATE();
-    case 169:
+    case 200:
       ACCEPT_TOKEN(anon_sym_invoke);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 170:
+    case 201:
       ACCEPT_TOKEN(anon_sym_with);
       END_STATE();
-    case 171:
+    case 202:
       ACCEPT_TOKEN(anon_sym_seq);
       END_STATE();
-    case 172:
+    case 203:
       ACCEPT_TOKEN(anon_sym_seq);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 173:
+    case 204:
       ACCEPT_TOKEN(anon_sym_par);
       END_STATE();
-    case 174:
+    case 205:
       ACCEPT_TOKEN(anon_sym_par);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 175:
+    case 206:
       ACCEPT_TOKEN(anon_sym_if);
       END_STATE();
-    case 176:
+    case 207:
       ACCEPT_TOKEN(anon_sym_if);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 177:
+    case 208:
       ACCEPT_TOKEN(anon_sym_else);
       END_STATE();
-    case 178:
+    case 209:
       ACCEPT_TOKEN(anon_sym_else);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 179:
+    case 210:
       ACCEPT_TOKEN(anon_sym_while);
       END_STATE();
-    case 180:
+    case 211:
       ACCEPT_TOKEN(anon_sym_while);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
-      if (('A' <= lookahead && lookahead <= 'Z') ||
-          lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
-      END_STATE();
-    case 181:
-      ACCEPT_TOKEN(anon_sym_static);
-      END_STATE();
-    case 182:
-      ACCEPT_TOKEN(anon_sym_static);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 183:
+    case 212:
       ACCEPT_TOKEN(anon_sym_repeat);
       END_STATE();
-    case 184:
+    case 213:
       ACCEPT_TOKEN(anon_sym_repeat);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 185:
+    case 214:
       ACCEPT_TOKEN(anon_sym_AT);
       END_STATE();
-    case 186:
+    case 215:
+      ACCEPT_TOKEN(anon_sym_static);
+      END_STATE();
+    case 216:
+      ACCEPT_TOKEN(anon_sym_static);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
+      END_STATE();
+    case 217:
       ACCEPT_TOKEN(sym_string);
-      if (lookahead == '"') ADVANCE(186);
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(27);
       END_STATE();
-    case 187:
+    case 218:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'a') ADVANCE(214);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'a') ADVANCE(255);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 188:
+    case 219:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'a') ADVANCE(218);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'a') ADVANCE(250);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 189:
+    case 220:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'a') ADVANCE(217);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'a') ADVANCE(253);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('b' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 190:
+    case 221:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'b') ADVANCE(135);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'b') ADVANCE(148);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 191:
+    case 222:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'c') ADVANCE(182);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'c') ADVANCE(216);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 192:
+    case 223:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'e') ADVANCE(212);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'e') ADVANCE(247);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 193:
+    case 224:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'e') ADVANCE(213);
-      if (lookahead == 't') ADVANCE(188);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'e') ADVANCE(248);
+      if (lookahead == 't') ADVANCE(218);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 194:
+    case 225:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'e') ADVANCE(180);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'e') ADVANCE(211);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 195:
+    case 226:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'e') ADVANCE(169);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'e') ADVANCE(200);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 196:
+    case 227:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'e') ADVANCE(178);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'e') ADVANCE(209);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 197:
+    case 228:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'e') ADVANCE(200);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'e') ADVANCE(231);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 198:
+    case 229:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'e') ADVANCE(189);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'e') ADVANCE(220);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 199:
+    case 230:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'f') ADVANCE(176);
-      if (lookahead == 'n') ADVANCE(220);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'f') ADVANCE(207);
+      if (lookahead == 'n') ADVANCE(257);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 200:
+    case 231:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'f') ADVANCE(149);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'f') ADVANCE(178);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 201:
+    case 232:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'h') ADVANCE(202);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'h') ADVANCE(233);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 202:
+    case 233:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'i') ADVANCE(206);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'i') ADVANCE(238);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 203:
+    case 234:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'i') ADVANCE(191);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'i') ADVANCE(222);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 204:
+    case 235:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'k') ADVANCE(195);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'i') ADVANCE(246);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 205:
+    case 236:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'l') ADVANCE(216);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'k') ADVANCE(226);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 206:
+    case 237:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'l') ADVANCE(194);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'l') ADVANCE(252);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 207:
+    case 238:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'm') ADVANCE(190);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'l') ADVANCE(225);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 208:
+    case 239:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'o') ADVANCE(204);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'l') ADVANCE(235);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 209:
+    case 240:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'o') ADVANCE(207);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'm') ADVANCE(221);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 210:
+    case 241:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'o') ADVANCE(219);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'o') ADVANCE(249);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 211:
+    case 242:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'p') ADVANCE(154);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'o') ADVANCE(236);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 212:
+    case 243:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'p') ADVANCE(198);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'o') ADVANCE(240);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 213:
+    case 244:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'q') ADVANCE(172);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'o') ADVANCE(256);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 214:
+    case 245:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'r') ADVANCE(174);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'p') ADVANCE(183);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 215:
+    case 246:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'r') ADVANCE(210);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'p') ADVANCE(159);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 216:
+    case 247:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 's') ADVANCE(196);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'p') ADVANCE(229);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 217:
+    case 248:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 't') ADVANCE(184);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'q') ADVANCE(203);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 218:
+    case 249:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 't') ADVANCE(203);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'r') ADVANCE(176);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 219:
+    case 250:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'u') ADVANCE(211);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'r') ADVANCE(205);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 220:
+    case 251:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == 'v') ADVANCE(208);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 'r') ADVANCE(244);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 221:
+    case 252:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9')) ADVANCE(222);
+      if (lookahead == 's') ADVANCE(227);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
       if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(221);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 222:
+    case 253:
       ACCEPT_TOKEN(sym_ident);
-      if (lookahead == '-' ||
-          ('0' <= lookahead && lookahead <= '9') ||
-          ('A' <= lookahead && lookahead <= 'Z') ||
+      if (lookahead == 't') ADVANCE(213);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
           lookahead == '_' ||
-          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(222);
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 223:
-      ACCEPT_TOKEN(sym_number);
-      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(223);
+    case 254:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == 't') ADVANCE(218);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 224:
-      ACCEPT_TOKEN(anon_sym_SQUOTE);
+    case 255:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == 't') ADVANCE(234);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 225:
-      ACCEPT_TOKEN(anon_sym_d);
+    case 256:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == 'u') ADVANCE(245);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 226:
-      ACCEPT_TOKEN(anon_sym_b);
+    case 257:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == 'v') ADVANCE(242);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 227:
-      ACCEPT_TOKEN(anon_sym_x);
+    case 258:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9')) ADVANCE(259);
+      if (('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(258);
       END_STATE();
-    case 228:
-      ACCEPT_TOKEN(anon_sym_o);
+    case 259:
+      ACCEPT_TOKEN(sym_ident);
+      if (lookahead == '{') ADVANCE(19);
+      if (lookahead == '\'' ||
+          lookahead == '-' ||
+          ('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'Z') ||
+          lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(259);
+      END_STATE();
+    case 260:
+      ACCEPT_TOKEN(sym_number);
+      if (('0' <= lookahead && lookahead <= '9')) ADVANCE(260);
+      END_STATE();
+    case 261:
+      ACCEPT_TOKEN(aux_sym_literal_token1);
+      if (('0' <= lookahead && lookahead <= '9') ||
+          ('A' <= lookahead && lookahead <= 'F') ||
+          ('a' <= lookahead && lookahead <= 'f')) ADVANCE(261);
       END_STATE();
     default:
       return false;
@@ -2638,474 +3127,509 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
 static const TSLexMode ts_lex_modes[STATE_COUNT] = {
   [0] = {.lex_state = 0},
   [1] = {.lex_state = 0},
-  [2] = {.lex_state = 23},
-  [3] = {.lex_state = 23},
-  [4] = {.lex_state = 23},
-  [5] = {.lex_state = 23},
-  [6] = {.lex_state = 23},
-  [7] = {.lex_state = 23},
-  [8] = {.lex_state = 23},
-  [9] = {.lex_state = 23},
-  [10] = {.lex_state = 23},
-  [11] = {.lex_state = 23},
-  [12] = {.lex_state = 23},
-  [13] = {.lex_state = 23},
-  [14] = {.lex_state = 23},
-  [15] = {.lex_state = 23},
-  [16] = {.lex_state = 23},
-  [17] = {.lex_state = 23},
-  [18] = {.lex_state = 23},
-  [19] = {.lex_state = 0},
-  [20] = {.lex_state = 24},
-  [21] = {.lex_state = 0},
-  [22] = {.lex_state = 0},
-  [23] = {.lex_state = 23},
-  [24] = {.lex_state = 25},
-  [25] = {.lex_state = 23},
-  [26] = {.lex_state = 23},
-  [27] = {.lex_state = 0},
-  [28] = {.lex_state = 23},
-  [29] = {.lex_state = 26},
-  [30] = {.lex_state = 25},
-  [31] = {.lex_state = 26},
-  [32] = {.lex_state = 30},
-  [33] = {.lex_state = 26},
-  [34] = {.lex_state = 25},
-  [35] = {.lex_state = 30},
-  [36] = {.lex_state = 26},
-  [37] = {.lex_state = 30},
-  [38] = {.lex_state = 30},
-  [39] = {.lex_state = 23},
-  [40] = {.lex_state = 25},
+  [2] = {.lex_state = 20},
+  [3] = {.lex_state = 20},
+  [4] = {.lex_state = 20},
+  [5] = {.lex_state = 20},
+  [6] = {.lex_state = 20},
+  [7] = {.lex_state = 20},
+  [8] = {.lex_state = 20},
+  [9] = {.lex_state = 20},
+  [10] = {.lex_state = 20},
+  [11] = {.lex_state = 20},
+  [12] = {.lex_state = 20},
+  [13] = {.lex_state = 20},
+  [14] = {.lex_state = 20},
+  [15] = {.lex_state = 20},
+  [16] = {.lex_state = 20},
+  [17] = {.lex_state = 20},
+  [18] = {.lex_state = 20},
+  [19] = {.lex_state = 20},
+  [20] = {.lex_state = 20},
+  [21] = {.lex_state = 20},
+  [22] = {.lex_state = 20},
+  [23] = {.lex_state = 0},
+  [24] = {.lex_state = 21},
+  [25] = {.lex_state = 0},
+  [26] = {.lex_state = 0},
+  [27] = {.lex_state = 21},
+  [28] = {.lex_state = 21},
+  [29] = {.lex_state = 21},
+  [30] = {.lex_state = 21},
+  [31] = {.lex_state = 23},
+  [32] = {.lex_state = 22},
+  [33] = {.lex_state = 23},
+  [34] = {.lex_state = 0},
+  [35] = {.lex_state = 23},
+  [36] = {.lex_state = 23},
+  [37] = {.lex_state = 23},
+  [38] = {.lex_state = 23},
+  [39] = {.lex_state = 20},
+  [40] = {.lex_state = 23},
   [41] = {.lex_state = 23},
-  [42] = {.lex_state = 23},
-  [43] = {.lex_state = 23},
-  [44] = {.lex_state = 23},
-  [45] = {.lex_state = 23},
-  [46] = {.lex_state = 23},
-  [47] = {.lex_state = 23},
-  [48] = {.lex_state = 25},
-  [49] = {.lex_state = 25},
-  [50] = {.lex_state = 25},
-  [51] = {.lex_state = 25},
-  [52] = {.lex_state = 23},
-  [53] = {.lex_state = 25},
-  [54] = {.lex_state = 25},
-  [55] = {.lex_state = 25},
-  [56] = {.lex_state = 23},
-  [57] = {.lex_state = 23},
-  [58] = {.lex_state = 23},
-  [59] = {.lex_state = 23},
-  [60] = {.lex_state = 23},
-  [61] = {.lex_state = 23},
-  [62] = {.lex_state = 23},
-  [63] = {.lex_state = 23},
-  [64] = {.lex_state = 23},
-  [65] = {.lex_state = 23},
-  [66] = {.lex_state = 23},
-  [67] = {.lex_state = 25},
-  [68] = {.lex_state = 25},
-  [69] = {.lex_state = 23},
-  [70] = {.lex_state = 23},
-  [71] = {.lex_state = 23},
-  [72] = {.lex_state = 23},
-  [73] = {.lex_state = 23},
-  [74] = {.lex_state = 23},
-  [75] = {.lex_state = 23},
-  [76] = {.lex_state = 23},
-  [77] = {.lex_state = 23},
-  [78] = {.lex_state = 23},
-  [79] = {.lex_state = 23},
-  [80] = {.lex_state = 25},
-  [81] = {.lex_state = 25},
-  [82] = {.lex_state = 25},
-  [83] = {.lex_state = 0},
-  [84] = {.lex_state = 25},
-  [85] = {.lex_state = 23},
-  [86] = {.lex_state = 26},
-  [87] = {.lex_state = 26},
-  [88] = {.lex_state = 0},
-  [89] = {.lex_state = 26},
-  [90] = {.lex_state = 26},
-  [91] = {.lex_state = 26},
+  [42] = {.lex_state = 34},
+  [43] = {.lex_state = 34},
+  [44] = {.lex_state = 34},
+  [45] = {.lex_state = 34},
+  [46] = {.lex_state = 26},
+  [47] = {.lex_state = 34},
+  [48] = {.lex_state = 20},
+  [49] = {.lex_state = 20},
+  [50] = {.lex_state = 20},
+  [51] = {.lex_state = 20},
+  [52] = {.lex_state = 20},
+  [53] = {.lex_state = 20},
+  [54] = {.lex_state = 20},
+  [55] = {.lex_state = 21},
+  [56] = {.lex_state = 20},
+  [57] = {.lex_state = 20},
+  [58] = {.lex_state = 20},
+  [59] = {.lex_state = 20},
+  [60] = {.lex_state = 20},
+  [61] = {.lex_state = 20},
+  [62] = {.lex_state = 20},
+  [63] = {.lex_state = 20},
+  [64] = {.lex_state = 20},
+  [65] = {.lex_state = 20},
+  [66] = {.lex_state = 20},
+  [67] = {.lex_state = 20},
+  [68] = {.lex_state = 20},
+  [69] = {.lex_state = 20},
+  [70] = {.lex_state = 20},
+  [71] = {.lex_state = 20},
+  [72] = {.lex_state = 20},
+  [73] = {.lex_state = 20},
+  [74] = {.lex_state = 20},
+  [75] = {.lex_state = 20},
+  [76] = {.lex_state = 20},
+  [77] = {.lex_state = 20},
+  [78] = {.lex_state = 20},
+  [79] = {.lex_state = 20},
+  [80] = {.lex_state = 26},
+  [81] = {.lex_state = 21},
+  [82] = {.lex_state = 0},
+  [83] = {.lex_state = 29},
+  [84] = {.lex_state = 21},
+  [85] = {.lex_state = 21},
+  [86] = {.lex_state = 21},
+  [87] = {.lex_state = 22},
+  [88] = {.lex_state = 21},
+  [89] = {.lex_state = 21},
+  [90] = {.lex_state = 21},
+  [91] = {.lex_state = 21},
   [92] = {.lex_state = 26},
-  [93] = {.lex_state = 26},
-  [94] = {.lex_state = 26},
-  [95] = {.lex_state = 0},
-  [96] = {.lex_state = 26},
-  [97] = {.lex_state = 0},
+  [93] = {.lex_state = 0},
+  [94] = {.lex_state = 21},
+  [95] = {.lex_state = 21},
+  [96] = {.lex_state = 29},
+  [97] = {.lex_state = 23},
   [98] = {.lex_state = 0},
-  [99] = {.lex_state = 0},
-  [100] = {.lex_state = 26},
-  [101] = {.lex_state = 0},
-  [102] = {.lex_state = 26},
-  [103] = {.lex_state = 26},
-  [104] = {.lex_state = 26},
-  [105] = {.lex_state = 26},
-  [106] = {.lex_state = 26},
-  [107] = {.lex_state = 26},
-  [108] = {.lex_state = 26},
-  [109] = {.lex_state = 0},
-  [110] = {.lex_state = 0},
-  [111] = {.lex_state = 28},
+  [99] = {.lex_state = 29},
+  [100] = {.lex_state = 23},
+  [101] = {.lex_state = 23},
+  [102] = {.lex_state = 23},
+  [103] = {.lex_state = 21},
+  [104] = {.lex_state = 0},
+  [105] = {.lex_state = 0},
+  [106] = {.lex_state = 0},
+  [107] = {.lex_state = 23},
+  [108] = {.lex_state = 29},
+  [109] = {.lex_state = 29},
+  [110] = {.lex_state = 23},
+  [111] = {.lex_state = 23},
   [112] = {.lex_state = 0},
-  [113] = {.lex_state = 0},
-  [114] = {.lex_state = 0},
-  [115] = {.lex_state = 0},
+  [113] = {.lex_state = 26},
+  [114] = {.lex_state = 21},
+  [115] = {.lex_state = 21},
   [116] = {.lex_state = 0},
-  [117] = {.lex_state = 0},
+  [117] = {.lex_state = 21},
   [118] = {.lex_state = 0},
   [119] = {.lex_state = 0},
-  [120] = {.lex_state = 0},
-  [121] = {.lex_state = 26},
+  [120] = {.lex_state = 22},
+  [121] = {.lex_state = 0},
   [122] = {.lex_state = 0},
   [123] = {.lex_state = 0},
-  [124] = {.lex_state = 25},
+  [124] = {.lex_state = 0},
   [125] = {.lex_state = 0},
-  [126] = {.lex_state = 0},
-  [127] = {.lex_state = 25},
-  [128] = {.lex_state = 0},
-  [129] = {.lex_state = 0},
-  [130] = {.lex_state = 0},
-  [131] = {.lex_state = 0},
-  [132] = {.lex_state = 0},
+  [126] = {.lex_state = 22},
+  [127] = {.lex_state = 0},
+  [128] = {.lex_state = 21},
+  [129] = {.lex_state = 21},
+  [130] = {.lex_state = 27},
+  [131] = {.lex_state = 29},
+  [132] = {.lex_state = 29},
   [133] = {.lex_state = 0},
   [134] = {.lex_state = 0},
-  [135] = {.lex_state = 26},
+  [135] = {.lex_state = 0},
   [136] = {.lex_state = 0},
-  [137] = {.lex_state = 0},
-  [138] = {.lex_state = 26},
+  [137] = {.lex_state = 27},
+  [138] = {.lex_state = 27},
   [139] = {.lex_state = 0},
   [140] = {.lex_state = 0},
-  [141] = {.lex_state = 28},
-  [142] = {.lex_state = 0},
-  [143] = {.lex_state = 25},
-  [144] = {.lex_state = 0},
-  [145] = {.lex_state = 25},
-  [146] = {.lex_state = 28},
+  [141] = {.lex_state = 20},
+  [142] = {.lex_state = 27},
+  [143] = {.lex_state = 0},
+  [144] = {.lex_state = 29},
+  [145] = {.lex_state = 27},
+  [146] = {.lex_state = 27},
   [147] = {.lex_state = 0},
   [148] = {.lex_state = 0},
   [149] = {.lex_state = 0},
   [150] = {.lex_state = 0},
-  [151] = {.lex_state = 0},
-  [152] = {.lex_state = 26},
+  [151] = {.lex_state = 27},
+  [152] = {.lex_state = 0},
   [153] = {.lex_state = 0},
-  [154] = {.lex_state = 25},
+  [154] = {.lex_state = 29},
   [155] = {.lex_state = 0},
-  [156] = {.lex_state = 25},
-  [157] = {.lex_state = 28},
+  [156] = {.lex_state = 0},
+  [157] = {.lex_state = 0},
   [158] = {.lex_state = 0},
   [159] = {.lex_state = 0},
-  [160] = {.lex_state = 25},
-  [161] = {.lex_state = 25},
-  [162] = {.lex_state = 28},
-  [163] = {.lex_state = 25},
-  [164] = {.lex_state = 28},
+  [160] = {.lex_state = 23},
+  [161] = {.lex_state = 23},
+  [162] = {.lex_state = 0},
+  [163] = {.lex_state = 0},
+  [164] = {.lex_state = 23},
   [165] = {.lex_state = 0},
   [166] = {.lex_state = 0},
-  [167] = {.lex_state = 0},
-  [168] = {.lex_state = 0},
-  [169] = {.lex_state = 28},
-  [170] = {.lex_state = 0},
-  [171] = {.lex_state = 25},
-  [172] = {.lex_state = 28},
-  [173] = {.lex_state = 25},
-  [174] = {.lex_state = 28},
-  [175] = {.lex_state = 26},
-  [176] = {.lex_state = 28},
-  [177] = {.lex_state = 0},
-  [178] = {.lex_state = 0},
-  [179] = {.lex_state = 0},
-  [180] = {.lex_state = 26},
-  [181] = {.lex_state = 26},
-  [182] = {.lex_state = 28},
-  [183] = {.lex_state = 0},
-  [184] = {.lex_state = 26},
-  [185] = {.lex_state = 0},
-  [186] = {.lex_state = 0},
-  [187] = {.lex_state = 26},
-  [188] = {.lex_state = 0},
-  [189] = {.lex_state = 0},
-  [190] = {.lex_state = 0},
-  [191] = {.lex_state = 0},
-  [192] = {.lex_state = 26},
-  [193] = {.lex_state = 0},
-  [194] = {.lex_state = 0},
-  [195] = {.lex_state = 28},
+  [167] = {.lex_state = 21},
+  [168] = {.lex_state = 21},
+  [169] = {.lex_state = 23},
+  [170] = {.lex_state = 21},
+  [171] = {.lex_state = 0},
+  [172] = {.lex_state = 0},
+  [173] = {.lex_state = 21},
+  [174] = {.lex_state = 21},
+  [175] = {.lex_state = 21},
+  [176] = {.lex_state = 21},
+  [177] = {.lex_state = 21},
+  [178] = {.lex_state = 23},
+  [179] = {.lex_state = 23},
+  [180] = {.lex_state = 23},
+  [181] = {.lex_state = 20},
+  [182] = {.lex_state = 23},
+  [183] = {.lex_state = 29},
+  [184] = {.lex_state = 23},
+  [185] = {.lex_state = 29},
+  [186] = {.lex_state = 29},
+  [187] = {.lex_state = 23},
+  [188] = {.lex_state = 29},
+  [189] = {.lex_state = 20},
+  [190] = {.lex_state = 20},
+  [191] = {.lex_state = 23},
+  [192] = {.lex_state = 0},
+  [193] = {.lex_state = 20},
+  [194] = {.lex_state = 20},
+  [195] = {.lex_state = 0},
   [196] = {.lex_state = 0},
-  [197] = {.lex_state = 26},
+  [197] = {.lex_state = 0},
   [198] = {.lex_state = 0},
-  [199] = {.lex_state = 28},
-  [200] = {.lex_state = 26},
-  [201] = {.lex_state = 0},
-  [202] = {.lex_state = 26},
-  [203] = {.lex_state = 0},
-  [204] = {.lex_state = 0},
-  [205] = {.lex_state = 26},
-  [206] = {.lex_state = 26},
-  [207] = {.lex_state = 26},
-  [208] = {.lex_state = 0},
+  [199] = {.lex_state = 0},
+  [200] = {.lex_state = 20},
+  [201] = {.lex_state = 21},
+  [202] = {.lex_state = 29},
+  [203] = {.lex_state = 23},
+  [204] = {.lex_state = 29},
+  [205] = {.lex_state = 22},
+  [206] = {.lex_state = 23},
+  [207] = {.lex_state = 0},
+  [208] = {.lex_state = 20},
   [209] = {.lex_state = 0},
-  [210] = {.lex_state = 26},
-  [211] = {.lex_state = 26},
-  [212] = {.lex_state = 0},
-  [213] = {.lex_state = 0},
-  [214] = {.lex_state = 0},
-  [215] = {.lex_state = 26},
-  [216] = {.lex_state = 0},
-  [217] = {.lex_state = 0},
-  [218] = {.lex_state = 0},
-  [219] = {.lex_state = 0},
-  [220] = {.lex_state = 14},
-  [221] = {.lex_state = 26},
-  [222] = {.lex_state = 26},
-  [223] = {.lex_state = 26},
-  [224] = {.lex_state = 0},
+  [210] = {.lex_state = 23},
+  [211] = {.lex_state = 23},
+  [212] = {.lex_state = 23},
+  [213] = {.lex_state = 22},
+  [214] = {.lex_state = 29},
+  [215] = {.lex_state = 23},
+  [216] = {.lex_state = 20},
+  [217] = {.lex_state = 35},
+  [218] = {.lex_state = 27},
+  [219] = {.lex_state = 35},
+  [220] = {.lex_state = 23},
+  [221] = {.lex_state = 20},
+  [222] = {.lex_state = 29},
+  [223] = {.lex_state = 28},
+  [224] = {.lex_state = 27},
   [225] = {.lex_state = 0},
-  [226] = {.lex_state = 0},
-  [227] = {.lex_state = 0},
-  [228] = {.lex_state = 0},
-  [229] = {.lex_state = 14},
+  [226] = {.lex_state = 22},
+  [227] = {.lex_state = 28},
+  [228] = {.lex_state = 23},
+  [229] = {.lex_state = 20},
   [230] = {.lex_state = 0},
-  [231] = {.lex_state = 0},
-  [232] = {.lex_state = 0},
-  [233] = {.lex_state = 0},
+  [231] = {.lex_state = 35},
+  [232] = {.lex_state = 35},
+  [233] = {.lex_state = 35},
   [234] = {.lex_state = 0},
-  [235] = {.lex_state = 26},
-  [236] = {.lex_state = 0},
-  [237] = {.lex_state = 0},
-  [238] = {.lex_state = 0},
-  [239] = {.lex_state = 14},
-  [240] = {.lex_state = 28},
-  [241] = {.lex_state = 26},
-  [242] = {.lex_state = 26},
-  [243] = {.lex_state = 26},
-  [244] = {.lex_state = 26},
+  [235] = {.lex_state = 29},
+  [236] = {.lex_state = 23},
+  [237] = {.lex_state = 27},
+  [238] = {.lex_state = 23},
+  [239] = {.lex_state = 0},
+  [240] = {.lex_state = 0},
+  [241] = {.lex_state = 0},
+  [242] = {.lex_state = 27},
+  [243] = {.lex_state = 27},
+  [244] = {.lex_state = 0},
   [245] = {.lex_state = 0},
-  [246] = {.lex_state = 0},
-  [247] = {.lex_state = 0},
-  [248] = {.lex_state = 26},
-  [249] = {.lex_state = 0},
+  [246] = {.lex_state = 28},
+  [247] = {.lex_state = 27},
+  [248] = {.lex_state = 23},
+  [249] = {.lex_state = 23},
   [250] = {.lex_state = 0},
-  [251] = {.lex_state = 0},
+  [251] = {.lex_state = 23},
   [252] = {.lex_state = 0},
   [253] = {.lex_state = 0},
-  [254] = {.lex_state = 26},
-  [255] = {.lex_state = 26},
+  [254] = {.lex_state = 23},
+  [255] = {.lex_state = 23},
   [256] = {.lex_state = 0},
   [257] = {.lex_state = 0},
   [258] = {.lex_state = 0},
   [259] = {.lex_state = 0},
-  [260] = {.lex_state = 0},
+  [260] = {.lex_state = 23},
   [261] = {.lex_state = 0},
   [262] = {.lex_state = 0},
-  [263] = {.lex_state = 0},
-  [264] = {.lex_state = 26},
+  [263] = {.lex_state = 23},
+  [264] = {.lex_state = 0},
   [265] = {.lex_state = 0},
   [266] = {.lex_state = 0},
   [267] = {.lex_state = 0},
-  [268] = {.lex_state = 0},
-  [269] = {.lex_state = 0},
-  [270] = {.lex_state = 0},
+  [268] = {.lex_state = 23},
+  [269] = {.lex_state = 23},
+  [270] = {.lex_state = 23},
   [271] = {.lex_state = 0},
   [272] = {.lex_state = 0},
-  [273] = {.lex_state = 0},
-  [274] = {.lex_state = 0},
-  [275] = {.lex_state = 26},
+  [273] = {.lex_state = 23},
+  [274] = {.lex_state = 23},
+  [275] = {.lex_state = 0},
   [276] = {.lex_state = 0},
-  [277] = {.lex_state = 26},
-  [278] = {.lex_state = 0},
-  [279] = {.lex_state = 26},
+  [277] = {.lex_state = 23},
+  [278] = {.lex_state = 23},
+  [279] = {.lex_state = 23},
   [280] = {.lex_state = 0},
   [281] = {.lex_state = 0},
   [282] = {.lex_state = 0},
-  [283] = {.lex_state = 28},
-  [284] = {.lex_state = 26},
-  [285] = {.lex_state = 26},
+  [283] = {.lex_state = 0},
+  [284] = {.lex_state = 23},
+  [285] = {.lex_state = 23},
   [286] = {.lex_state = 0},
   [287] = {.lex_state = 0},
   [288] = {.lex_state = 0},
-  [289] = {.lex_state = 0},
+  [289] = {.lex_state = 23},
   [290] = {.lex_state = 0},
   [291] = {.lex_state = 0},
   [292] = {.lex_state = 0},
-  [293] = {.lex_state = 0},
+  [293] = {.lex_state = 23},
   [294] = {.lex_state = 0},
-  [295] = {.lex_state = 0},
-  [296] = {.lex_state = 0},
-  [297] = {.lex_state = 0},
-  [298] = {.lex_state = 0},
-  [299] = {.lex_state = 0},
-  [300] = {.lex_state = 0},
-  [301] = {.lex_state = 0},
-  [302] = {.lex_state = 0},
+  [295] = {.lex_state = 35},
+  [296] = {.lex_state = 35},
+  [297] = {.lex_state = 27},
+  [298] = {.lex_state = 23},
+  [299] = {.lex_state = 23},
+  [300] = {.lex_state = 28},
+  [301] = {.lex_state = 23},
+  [302] = {.lex_state = 23},
   [303] = {.lex_state = 0},
   [304] = {.lex_state = 0},
   [305] = {.lex_state = 0},
   [306] = {.lex_state = 0},
-  [307] = {.lex_state = 26},
+  [307] = {.lex_state = 0},
   [308] = {.lex_state = 0},
-  [309] = {.lex_state = 14},
-  [310] = {.lex_state = 26},
-  [311] = {.lex_state = 0},
+  [309] = {.lex_state = 0},
+  [310] = {.lex_state = 23},
+  [311] = {.lex_state = 23},
   [312] = {.lex_state = 0},
-  [313] = {.lex_state = 26},
+  [313] = {.lex_state = 0},
   [314] = {.lex_state = 0},
-  [315] = {.lex_state = 0},
-  [316] = {.lex_state = 26},
+  [315] = {.lex_state = 23},
+  [316] = {.lex_state = 0},
   [317] = {.lex_state = 0},
   [318] = {.lex_state = 0},
   [319] = {.lex_state = 0},
-  [320] = {.lex_state = 0},
-  [321] = {.lex_state = 28},
+  [320] = {.lex_state = 23},
+  [321] = {.lex_state = 0},
   [322] = {.lex_state = 0},
   [323] = {.lex_state = 0},
   [324] = {.lex_state = 0},
   [325] = {.lex_state = 0},
   [326] = {.lex_state = 0},
   [327] = {.lex_state = 0},
-  [328] = {.lex_state = 0},
+  [328] = {.lex_state = 23},
   [329] = {.lex_state = 0},
   [330] = {.lex_state = 0},
-  [331] = {.lex_state = 26},
-  [332] = {.lex_state = 26},
+  [331] = {.lex_state = 0},
+  [332] = {.lex_state = 0},
   [333] = {.lex_state = 0},
-  [334] = {.lex_state = 26},
+  [334] = {.lex_state = 0},
   [335] = {.lex_state = 0},
-  [336] = {.lex_state = 26},
+  [336] = {.lex_state = 0},
   [337] = {.lex_state = 0},
   [338] = {.lex_state = 0},
-  [339] = {.lex_state = 26},
+  [339] = {.lex_state = 0},
   [340] = {.lex_state = 0},
   [341] = {.lex_state = 0},
-  [342] = {.lex_state = 26},
+  [342] = {.lex_state = 23},
   [343] = {.lex_state = 0},
   [344] = {.lex_state = 0},
-  [345] = {.lex_state = 26},
-  [346] = {.lex_state = 26},
+  [345] = {.lex_state = 0},
+  [346] = {.lex_state = 0},
   [347] = {.lex_state = 0},
   [348] = {.lex_state = 0},
-  [349] = {.lex_state = 0},
-  [350] = {.lex_state = 26},
-  [351] = {.lex_state = 121},
+  [349] = {.lex_state = 23},
+  [350] = {.lex_state = 0},
+  [351] = {.lex_state = 0},
   [352] = {.lex_state = 0},
   [353] = {.lex_state = 0},
   [354] = {.lex_state = 0},
   [355] = {.lex_state = 0},
-  [356] = {.lex_state = 26},
-  [357] = {.lex_state = 0},
-  [358] = {.lex_state = 0},
+  [356] = {.lex_state = 0},
+  [357] = {.lex_state = 23},
+  [358] = {.lex_state = 23},
   [359] = {.lex_state = 0},
   [360] = {.lex_state = 0},
   [361] = {.lex_state = 0},
   [362] = {.lex_state = 0},
-  [363] = {.lex_state = 26},
+  [363] = {.lex_state = 0},
   [364] = {.lex_state = 0},
-  [365] = {.lex_state = 26},
-  [366] = {.lex_state = 26},
+  [365] = {.lex_state = 0},
+  [366] = {.lex_state = 0},
   [367] = {.lex_state = 0},
-  [368] = {.lex_state = 26},
-  [369] = {.lex_state = 26},
-  [370] = {.lex_state = 0},
-  [371] = {.lex_state = 26},
+  [368] = {.lex_state = 23},
+  [369] = {.lex_state = 0},
+  [370] = {.lex_state = 23},
+  [371] = {.lex_state = 23},
   [372] = {.lex_state = 0},
   [373] = {.lex_state = 0},
-  [374] = {.lex_state = 26},
-  [375] = {.lex_state = 26},
+  [374] = {.lex_state = 0},
+  [375] = {.lex_state = 0},
   [376] = {.lex_state = 0},
   [377] = {.lex_state = 0},
-  [378] = {.lex_state = 0},
-  [379] = {.lex_state = 0},
-  [380] = {.lex_state = 0},
+  [378] = {.lex_state = 23},
+  [379] = {.lex_state = 23},
+  [380] = {.lex_state = 23},
   [381] = {.lex_state = 0},
   [382] = {.lex_state = 0},
   [383] = {.lex_state = 0},
-  [384] = {.lex_state = 0},
-  [385] = {.lex_state = 0},
-  [386] = {.lex_state = 26},
-  [387] = {.lex_state = 26},
-  [388] = {.lex_state = 0},
+  [384] = {.lex_state = 23},
+  [385] = {.lex_state = 23},
+  [386] = {.lex_state = 23},
+  [387] = {.lex_state = 0},
+  [388] = {.lex_state = 23},
   [389] = {.lex_state = 0},
-  [390] = {.lex_state = 26},
-  [391] = {.lex_state = 0},
-  [392] = {.lex_state = 26},
-  [393] = {.lex_state = 0},
-  [394] = {.lex_state = 0},
+  [390] = {.lex_state = 0},
+  [391] = {.lex_state = 23},
+  [392] = {.lex_state = 0},
+  [393] = {.lex_state = 23},
+  [394] = {.lex_state = 23},
   [395] = {.lex_state = 0},
   [396] = {.lex_state = 0},
-  [397] = {.lex_state = 0},
-  [398] = {.lex_state = 0},
-  [399] = {.lex_state = 26},
+  [397] = {.lex_state = 23},
+  [398] = {.lex_state = 23},
+  [399] = {.lex_state = 23},
   [400] = {.lex_state = 0},
   [401] = {.lex_state = 0},
   [402] = {.lex_state = 0},
   [403] = {.lex_state = 0},
-  [404] = {.lex_state = 26},
-  [405] = {.lex_state = 0},
-  [406] = {.lex_state = 26},
+  [404] = {.lex_state = 23},
+  [405] = {.lex_state = 23},
+  [406] = {.lex_state = 23},
   [407] = {.lex_state = 0},
-  [408] = {.lex_state = 26},
-  [409] = {.lex_state = 0},
+  [408] = {.lex_state = 0},
+  [409] = {.lex_state = 23},
   [410] = {.lex_state = 0},
   [411] = {.lex_state = 0},
   [412] = {.lex_state = 0},
-  [413] = {.lex_state = 26},
-  [414] = {.lex_state = 0},
+  [413] = {.lex_state = 23},
+  [414] = {.lex_state = 23},
   [415] = {.lex_state = 0},
   [416] = {.lex_state = 0},
-  [417] = {.lex_state = 0},
+  [417] = {.lex_state = 23},
   [418] = {.lex_state = 0},
   [419] = {.lex_state = 0},
   [420] = {.lex_state = 0},
   [421] = {.lex_state = 0},
   [422] = {.lex_state = 0},
-  [423] = {.lex_state = 26},
+  [423] = {.lex_state = 0},
   [424] = {.lex_state = 0},
-  [425] = {.lex_state = 0},
-  [426] = {.lex_state = 26},
+  [425] = {.lex_state = 23},
+  [426] = {.lex_state = 0},
   [427] = {.lex_state = 0},
   [428] = {.lex_state = 0},
   [429] = {.lex_state = 0},
-  [430] = {.lex_state = 26},
-  [431] = {.lex_state = 26},
-  [432] = {.lex_state = 0},
+  [430] = {.lex_state = 0},
+  [431] = {.lex_state = 0},
+  [432] = {.lex_state = 23},
   [433] = {.lex_state = 0},
   [434] = {.lex_state = 0},
-  [435] = {.lex_state = 26},
+  [435] = {.lex_state = 23},
   [436] = {.lex_state = 0},
   [437] = {.lex_state = 0},
   [438] = {.lex_state = 0},
   [439] = {.lex_state = 0},
   [440] = {.lex_state = 0},
-  [441] = {.lex_state = 22},
+  [441] = {.lex_state = 23},
   [442] = {.lex_state = 0},
-  [443] = {.lex_state = 26},
+  [443] = {.lex_state = 0},
   [444] = {.lex_state = 0},
-  [445] = {.lex_state = 0},
+  [445] = {.lex_state = 23},
   [446] = {.lex_state = 0},
-  [447] = {.lex_state = 26},
+  [447] = {.lex_state = 0},
   [448] = {.lex_state = 0},
   [449] = {.lex_state = 0},
-  [450] = {.lex_state = 26},
-  [451] = {.lex_state = 0},
-  [452] = {.lex_state = 0},
+  [450] = {.lex_state = 0},
+  [451] = {.lex_state = 23},
+  [452] = {.lex_state = 23},
   [453] = {.lex_state = 0},
   [454] = {.lex_state = 0},
-  [455] = {.lex_state = 26},
-  [456] = {.lex_state = 0},
-  [457] = {.lex_state = 26},
+  [455] = {.lex_state = 0},
+  [456] = {.lex_state = 23},
+  [457] = {.lex_state = 0},
   [458] = {.lex_state = 0},
   [459] = {.lex_state = 0},
-  [460] = {(TSStateId)(-1)},
-  [461] = {(TSStateId)(-1)},
+  [460] = {.lex_state = 0},
+  [461] = {.lex_state = 0},
+  [462] = {.lex_state = 0},
+  [463] = {.lex_state = 23},
+  [464] = {.lex_state = 0},
+  [465] = {.lex_state = 0},
+  [466] = {.lex_state = 0},
+  [467] = {.lex_state = 0},
+  [468] = {.lex_state = 0},
+  [469] = {.lex_state = 0},
+  [470] = {.lex_state = 0},
+  [471] = {.lex_state = 0},
+  [472] = {.lex_state = 0},
+  [473] = {.lex_state = 23},
+  [474] = {.lex_state = 23},
+  [475] = {.lex_state = 0},
+  [476] = {.lex_state = 0},
+  [477] = {.lex_state = 0},
+  [478] = {.lex_state = 0},
+  [479] = {.lex_state = 23},
+  [480] = {.lex_state = 0},
+  [481] = {.lex_state = 0},
+  [482] = {.lex_state = 23},
+  [483] = {.lex_state = 23},
+  [484] = {.lex_state = 0},
+  [485] = {.lex_state = 23},
+  [486] = {.lex_state = 0},
+  [487] = {.lex_state = 23},
+  [488] = {.lex_state = 0},
+  [489] = {.lex_state = 0},
+  [490] = {.lex_state = 0},
+  [491] = {.lex_state = 0},
+  [492] = {.lex_state = 0},
+  [493] = {.lex_state = 0},
+  [494] = {.lex_state = 0},
+  [495] = {.lex_state = 23},
+  [496] = {.lex_state = 23},
+  [497] = {.lex_state = 0},
+  [498] = {.lex_state = 23},
 };
 
 static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
   [0] = {
-    [sym_comment] = STATE(0),
     [ts_builtin_sym_end] = ACTIONS(1),
-    [anon_sym_SLASH_SLASH] = ACTIONS(3),
-    [aux_sym_comment_token2] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
     [anon_sym_import] = ACTIONS(1),
     [anon_sym_SEMI] = ACTIONS(1),
     [aux_sym_metadata_ci_token1] = ACTIONS(1),
@@ -3119,65 +3643,70 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_LPAREN] = ACTIONS(1),
     [anon_sym_COMMA] = ACTIONS(1),
     [anon_sym_RPAREN] = ACTIONS(1),
+    [anon_sym_PLUS] = ACTIONS(1),
+    [anon_sym_DASH] = ACTIONS(1),
+    [anon_sym_STAR] = ACTIONS(1),
+    [anon_sym_SLASH] = ACTIONS(1),
+    [anon_sym_flip] = ACTIONS(1),
+    [anon_sym_bundle] = ACTIONS(1),
     [anon_sym_LBRACK] = ACTIONS(1),
     [anon_sym_RBRACK] = ACTIONS(1),
     [anon_sym_primitive] = ACTIONS(1),
     [anon_sym_extern] = ACTIONS(1),
+    [anon_sym_in] = ACTIONS(1),
+    [anon_sym_DOT_DOT] = ACTIONS(1),
     [anon_sym_cells] = ACTIONS(1),
+    [anon_sym_for] = ACTIONS(1),
     [anon_sym_ref] = ACTIONS(1),
     [anon_sym_EQ] = ACTIONS(1),
     [anon_sym_wires] = ACTIONS(1),
     [anon_sym_group] = ACTIONS(1),
     [anon_sym_DOT] = ACTIONS(1),
+    [anon_sym_PERCENT] = ACTIONS(1),
     [anon_sym_EQ_EQ] = ACTIONS(1),
     [anon_sym_BANG_EQ] = ACTIONS(1),
     [anon_sym_LT_EQ] = ACTIONS(1),
     [anon_sym_GT_EQ] = ACTIONS(1),
-    [anon_sym_GT] = ACTIONS(1),
     [anon_sym_LT] = ACTIONS(1),
+    [anon_sym_GT] = ACTIONS(1),
     [anon_sym_BANG] = ACTIONS(1),
-    [anon_sym_PIPE] = ACTIONS(1),
     [anon_sym_AMP] = ACTIONS(1),
+    [anon_sym_PIPE] = ACTIONS(1),
     [anon_sym_QMARK] = ACTIONS(1),
     [anon_sym_control] = ACTIONS(1),
-    [anon_sym_invoke] = ACTIONS(1),
     [anon_sym_with] = ACTIONS(1),
     [anon_sym_seq] = ACTIONS(1),
     [anon_sym_par] = ACTIONS(1),
     [anon_sym_if] = ACTIONS(1),
     [anon_sym_else] = ACTIONS(1),
     [anon_sym_while] = ACTIONS(1),
-    [anon_sym_static] = ACTIONS(1),
     [anon_sym_repeat] = ACTIONS(1),
     [anon_sym_AT] = ACTIONS(1),
+    [anon_sym_static] = ACTIONS(1),
     [sym_string] = ACTIONS(1),
     [sym_number] = ACTIONS(1),
-    [anon_sym_SQUOTE] = ACTIONS(1),
-    [anon_sym_d] = ACTIONS(1),
-    [anon_sym_b] = ACTIONS(1),
-    [anon_sym_x] = ACTIONS(1),
-    [anon_sym_o] = ACTIONS(1),
+    [aux_sym_literal_token1] = ACTIONS(1),
   },
   [1] = {
-    [sym_source_file] = STATE(449),
-    [sym_comment] = STATE(1),
-    [sym_import] = STATE(117),
-    [sym_metadata_ci] = STATE(448),
-    [sym_metadata] = STATE(445),
-    [sym_component] = STATE(140),
-    [sym_comb_or_static] = STATE(260),
-    [sym_primitive] = STATE(140),
-    [sym_extern] = STATE(140),
-    [sym_static_annotation] = STATE(204),
-    [aux_sym_source_file_repeat1] = STATE(19),
-    [aux_sym_source_file_repeat2] = STATE(21),
-    [ts_builtin_sym_end] = ACTIONS(7),
-    [anon_sym_SLASH_SLASH] = ACTIONS(3),
-    [aux_sym_comment_token2] = ACTIONS(5),
-    [anon_sym_import] = ACTIONS(9),
-    [aux_sym_metadata_ci_token1] = ACTIONS(11),
-    [anon_sym_component] = ACTIONS(13),
-    [anon_sym_comb] = ACTIONS(15),
+    [sym_source_file] = STATE(491),
+    [sym_import] = STATE(23),
+    [sym_metadata_ci] = STATE(460),
+    [sym_metadata] = STATE(437),
+    [sym_component] = STATE(25),
+    [sym_comb_or_static] = STATE(308),
+    [sym_bundle] = STATE(25),
+    [sym_primitive] = STATE(25),
+    [sym_extern] = STATE(25),
+    [sym_static_annotation] = STATE(264),
+    [aux_sym_source_file_repeat1] = STATE(23),
+    [aux_sym_source_file_repeat2] = STATE(25),
+    [ts_builtin_sym_end] = ACTIONS(5),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_import] = ACTIONS(7),
+    [aux_sym_metadata_ci_token1] = ACTIONS(9),
+    [anon_sym_component] = ACTIONS(11),
+    [anon_sym_comb] = ACTIONS(13),
+    [anon_sym_bundle] = ACTIONS(15),
     [anon_sym_primitive] = ACTIONS(17),
     [anon_sym_extern] = ACTIONS(19),
     [anon_sym_static] = ACTIONS(21),
@@ -3185,46 +3714,44 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
 };
 
 static const uint16_t ts_small_parse_table[] = {
-  [0] = 20,
+  [0] = 18,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(23), 1,
       anon_sym_SEMI,
     ACTIONS(25), 1,
       anon_sym_RBRACE,
     ACTIONS(27), 1,
-      anon_sym_invoke,
+      anon_sym_for,
     ACTIONS(29), 1,
-      anon_sym_seq,
+      anon_sym_invoke,
     ACTIONS(31), 1,
-      anon_sym_par,
+      anon_sym_seq,
     ACTIONS(33), 1,
-      anon_sym_if,
+      anon_sym_par,
     ACTIONS(35), 1,
-      anon_sym_while,
+      anon_sym_if,
     ACTIONS(37), 1,
-      anon_sym_static,
+      anon_sym_while,
     ACTIONS(39), 1,
       anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
       sym_ident,
-    STATE(2), 1,
-      sym_comment,
-    STATE(7), 1,
-      aux_sym_seq_repeat1,
-    STATE(26), 1,
-      aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(411), 1,
+      sym_control_inner,
+    STATE(5), 2,
       sym_stmt,
-    STATE(79), 1,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
       sym_at_attribute,
-    STATE(185), 1,
-      sym_static_annotation,
-    STATE(45), 7,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3232,46 +3759,43 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [67] = 20,
+      sym_for_stmt,
+  [64] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(23), 1,
       anon_sym_SEMI,
     ACTIONS(27), 1,
-      anon_sym_invoke,
+      anon_sym_for,
     ACTIONS(29), 1,
-      anon_sym_seq,
+      anon_sym_invoke,
     ACTIONS(31), 1,
-      anon_sym_par,
+      anon_sym_seq,
     ACTIONS(33), 1,
-      anon_sym_if,
+      anon_sym_par,
     ACTIONS(35), 1,
-      anon_sym_while,
+      anon_sym_if,
     ACTIONS(37), 1,
-      anon_sym_static,
+      anon_sym_while,
     ACTIONS(39), 1,
       anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
-      sym_ident,
+      anon_sym_static,
     ACTIONS(45), 1,
+      sym_ident,
+    ACTIONS(47), 1,
       anon_sym_RBRACE,
-    STATE(3), 1,
-      sym_comment,
-    STATE(11), 1,
-      aux_sym_seq_repeat1,
-    STATE(26), 1,
-      aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(8), 2,
       sym_stmt,
-    STATE(79), 1,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
       sym_at_attribute,
-    STATE(185), 1,
-      sym_static_annotation,
-    STATE(45), 7,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3279,46 +3803,43 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [134] = 20,
+      sym_for_stmt,
+  [125] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(23), 1,
       anon_sym_SEMI,
     ACTIONS(27), 1,
-      anon_sym_invoke,
+      anon_sym_for,
     ACTIONS(29), 1,
-      anon_sym_seq,
+      anon_sym_invoke,
     ACTIONS(31), 1,
-      anon_sym_par,
+      anon_sym_seq,
     ACTIONS(33), 1,
-      anon_sym_if,
+      anon_sym_par,
     ACTIONS(35), 1,
-      anon_sym_while,
+      anon_sym_if,
     ACTIONS(37), 1,
-      anon_sym_static,
+      anon_sym_while,
     ACTIONS(39), 1,
       anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
       sym_ident,
-    ACTIONS(47), 1,
+    ACTIONS(49), 1,
       anon_sym_RBRACE,
-    STATE(4), 1,
-      sym_comment,
-    STATE(10), 1,
-      aux_sym_seq_repeat1,
-    STATE(26), 1,
-      aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(8), 2,
       sym_stmt,
-    STATE(79), 1,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
       sym_at_attribute,
-    STATE(185), 1,
-      sym_static_annotation,
-    STATE(45), 7,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3326,46 +3847,43 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [201] = 20,
+      sym_for_stmt,
+  [186] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(23), 1,
       anon_sym_SEMI,
     ACTIONS(27), 1,
-      anon_sym_invoke,
+      anon_sym_for,
     ACTIONS(29), 1,
-      anon_sym_seq,
+      anon_sym_invoke,
     ACTIONS(31), 1,
-      anon_sym_par,
+      anon_sym_seq,
     ACTIONS(33), 1,
-      anon_sym_if,
+      anon_sym_par,
     ACTIONS(35), 1,
-      anon_sym_while,
+      anon_sym_if,
     ACTIONS(37), 1,
-      anon_sym_static,
+      anon_sym_while,
     ACTIONS(39), 1,
       anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
-      sym_ident,
+      anon_sym_static,
     ACTIONS(45), 1,
+      sym_ident,
+    ACTIONS(51), 1,
       anon_sym_RBRACE,
-    STATE(5), 1,
-      sym_comment,
-    STATE(7), 1,
-      aux_sym_seq_repeat1,
-    STATE(26), 1,
-      aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(8), 2,
       sym_stmt,
-    STATE(79), 1,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
       sym_at_attribute,
-    STATE(185), 1,
-      sym_static_annotation,
-    STATE(45), 7,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3373,46 +3891,43 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [268] = 20,
+      sym_for_stmt,
+  [247] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(23), 1,
       anon_sym_SEMI,
     ACTIONS(27), 1,
-      anon_sym_invoke,
+      anon_sym_for,
     ACTIONS(29), 1,
-      anon_sym_seq,
+      anon_sym_invoke,
     ACTIONS(31), 1,
-      anon_sym_par,
+      anon_sym_seq,
     ACTIONS(33), 1,
-      anon_sym_if,
+      anon_sym_par,
     ACTIONS(35), 1,
-      anon_sym_while,
+      anon_sym_if,
     ACTIONS(37), 1,
-      anon_sym_static,
+      anon_sym_while,
     ACTIONS(39), 1,
       anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
       sym_ident,
-    ACTIONS(47), 1,
+    ACTIONS(53), 1,
       anon_sym_RBRACE,
-    STATE(6), 1,
-      sym_comment,
-    STATE(7), 1,
-      aux_sym_seq_repeat1,
-    STATE(26), 1,
-      aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(3), 2,
       sym_stmt,
-    STATE(79), 1,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
       sym_at_attribute,
-    STATE(185), 1,
-      sym_static_annotation,
-    STATE(45), 7,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3420,45 +3935,43 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [335] = 19,
+      sym_for_stmt,
+  [308] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(49), 1,
+      sym_comment,
+    ACTIONS(23), 1,
       anon_sym_SEMI,
-    ACTIONS(52), 1,
-      anon_sym_RBRACE,
-    ACTIONS(54), 1,
+    ACTIONS(27), 1,
+      anon_sym_for,
+    ACTIONS(29), 1,
       anon_sym_invoke,
-    ACTIONS(57), 1,
+    ACTIONS(31), 1,
       anon_sym_seq,
-    ACTIONS(60), 1,
+    ACTIONS(33), 1,
       anon_sym_par,
-    ACTIONS(63), 1,
+    ACTIONS(35), 1,
       anon_sym_if,
-    ACTIONS(66), 1,
+    ACTIONS(37), 1,
       anon_sym_while,
-    ACTIONS(69), 1,
-      anon_sym_static,
-    ACTIONS(72), 1,
+    ACTIONS(39), 1,
       anon_sym_repeat,
-    ACTIONS(75), 1,
+    ACTIONS(41), 1,
       anon_sym_AT,
-    ACTIONS(78), 1,
+    ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
       sym_ident,
-    STATE(26), 1,
-      aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    ACTIONS(55), 1,
+      anon_sym_RBRACE,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(10), 2,
       sym_stmt,
-    STATE(79), 1,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
       sym_at_attribute,
-    STATE(185), 1,
-      sym_static_annotation,
-    STATE(7), 2,
-      sym_comment,
-      aux_sym_seq_repeat1,
-    STATE(45), 7,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3466,46 +3979,43 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [400] = 20,
+      sym_for_stmt,
+  [369] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(23), 1,
+      sym_comment,
+    ACTIONS(57), 1,
       anon_sym_SEMI,
-    ACTIONS(27), 1,
+    ACTIONS(60), 1,
+      anon_sym_RBRACE,
+    ACTIONS(62), 1,
+      anon_sym_for,
+    ACTIONS(65), 1,
       anon_sym_invoke,
-    ACTIONS(29), 1,
+    ACTIONS(68), 1,
       anon_sym_seq,
-    ACTIONS(31), 1,
+    ACTIONS(71), 1,
       anon_sym_par,
-    ACTIONS(33), 1,
+    ACTIONS(74), 1,
       anon_sym_if,
-    ACTIONS(35), 1,
+    ACTIONS(77), 1,
       anon_sym_while,
-    ACTIONS(37), 1,
-      anon_sym_static,
-    ACTIONS(39), 1,
+    ACTIONS(80), 1,
       anon_sym_repeat,
-    ACTIONS(41), 1,
+    ACTIONS(83), 1,
       anon_sym_AT,
-    ACTIONS(43), 1,
+    ACTIONS(86), 1,
+      anon_sym_static,
+    ACTIONS(89), 1,
       sym_ident,
-    ACTIONS(81), 1,
-      anon_sym_RBRACE,
-    STATE(5), 1,
-      aux_sym_seq_repeat1,
-    STATE(8), 1,
-      sym_comment,
-    STATE(26), 1,
-      aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(8), 2,
       sym_stmt,
-    STATE(79), 1,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
       sym_at_attribute,
-    STATE(185), 1,
-      sym_static_annotation,
-    STATE(45), 7,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3513,46 +4023,43 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [467] = 20,
+      sym_for_stmt,
+  [430] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(23), 1,
       anon_sym_SEMI,
     ACTIONS(27), 1,
-      anon_sym_invoke,
+      anon_sym_for,
     ACTIONS(29), 1,
-      anon_sym_seq,
+      anon_sym_invoke,
     ACTIONS(31), 1,
-      anon_sym_par,
+      anon_sym_seq,
     ACTIONS(33), 1,
-      anon_sym_if,
+      anon_sym_par,
     ACTIONS(35), 1,
-      anon_sym_while,
+      anon_sym_if,
     ACTIONS(37), 1,
-      anon_sym_static,
+      anon_sym_while,
     ACTIONS(39), 1,
       anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
       sym_ident,
-    ACTIONS(83), 1,
+    ACTIONS(92), 1,
       anon_sym_RBRACE,
-    STATE(6), 1,
-      aux_sym_seq_repeat1,
-    STATE(9), 1,
-      sym_comment,
-    STATE(26), 1,
-      aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(14), 2,
       sym_stmt,
-    STATE(79), 1,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
       sym_at_attribute,
-    STATE(185), 1,
-      sym_static_annotation,
-    STATE(45), 7,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3560,46 +4067,43 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [534] = 20,
+      sym_for_stmt,
+  [491] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(23), 1,
       anon_sym_SEMI,
     ACTIONS(27), 1,
-      anon_sym_invoke,
+      anon_sym_for,
     ACTIONS(29), 1,
-      anon_sym_seq,
+      anon_sym_invoke,
     ACTIONS(31), 1,
-      anon_sym_par,
+      anon_sym_seq,
     ACTIONS(33), 1,
-      anon_sym_if,
+      anon_sym_par,
     ACTIONS(35), 1,
-      anon_sym_while,
+      anon_sym_if,
     ACTIONS(37), 1,
-      anon_sym_static,
+      anon_sym_while,
     ACTIONS(39), 1,
       anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
       sym_ident,
-    ACTIONS(85), 1,
+    ACTIONS(94), 1,
       anon_sym_RBRACE,
-    STATE(7), 1,
-      aux_sym_seq_repeat1,
-    STATE(10), 1,
-      sym_comment,
-    STATE(26), 1,
-      aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(8), 2,
       sym_stmt,
-    STATE(79), 1,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
       sym_at_attribute,
-    STATE(185), 1,
-      sym_static_annotation,
-    STATE(45), 7,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3607,46 +4111,43 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [601] = 20,
+      sym_for_stmt,
+  [552] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(23), 1,
       anon_sym_SEMI,
     ACTIONS(27), 1,
-      anon_sym_invoke,
+      anon_sym_for,
     ACTIONS(29), 1,
-      anon_sym_seq,
+      anon_sym_invoke,
     ACTIONS(31), 1,
-      anon_sym_par,
+      anon_sym_seq,
     ACTIONS(33), 1,
-      anon_sym_if,
+      anon_sym_par,
     ACTIONS(35), 1,
-      anon_sym_while,
+      anon_sym_if,
     ACTIONS(37), 1,
-      anon_sym_static,
+      anon_sym_while,
     ACTIONS(39), 1,
       anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
       sym_ident,
-    ACTIONS(87), 1,
+    ACTIONS(96), 1,
       anon_sym_RBRACE,
-    STATE(7), 1,
-      aux_sym_seq_repeat1,
-    STATE(11), 1,
-      sym_comment,
-    STATE(26), 1,
-      aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(15), 2,
       sym_stmt,
-    STATE(79), 1,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
       sym_at_attribute,
-    STATE(185), 1,
-      sym_static_annotation,
-    STATE(45), 7,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3654,46 +4155,43 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [668] = 20,
+      sym_for_stmt,
+  [613] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(23), 1,
       anon_sym_SEMI,
     ACTIONS(27), 1,
-      anon_sym_invoke,
+      anon_sym_for,
     ACTIONS(29), 1,
-      anon_sym_seq,
+      anon_sym_invoke,
     ACTIONS(31), 1,
-      anon_sym_par,
+      anon_sym_seq,
     ACTIONS(33), 1,
-      anon_sym_if,
+      anon_sym_par,
     ACTIONS(35), 1,
-      anon_sym_while,
+      anon_sym_if,
     ACTIONS(37), 1,
-      anon_sym_static,
+      anon_sym_while,
     ACTIONS(39), 1,
       anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
       sym_ident,
-    ACTIONS(85), 1,
+    ACTIONS(47), 1,
       anon_sym_RBRACE,
-    STATE(12), 1,
-      sym_comment,
-    STATE(14), 1,
-      aux_sym_seq_repeat1,
-    STATE(26), 1,
-      aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(16), 2,
       sym_stmt,
-    STATE(79), 1,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
       sym_at_attribute,
-    STATE(185), 1,
-      sym_static_annotation,
-    STATE(45), 7,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3701,46 +4199,43 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [735] = 20,
+      sym_for_stmt,
+  [674] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(23), 1,
       anon_sym_SEMI,
     ACTIONS(27), 1,
-      anon_sym_invoke,
+      anon_sym_for,
     ACTIONS(29), 1,
-      anon_sym_seq,
+      anon_sym_invoke,
     ACTIONS(31), 1,
-      anon_sym_par,
+      anon_sym_seq,
     ACTIONS(33), 1,
-      anon_sym_if,
+      anon_sym_par,
     ACTIONS(35), 1,
-      anon_sym_while,
+      anon_sym_if,
     ACTIONS(37), 1,
-      anon_sym_static,
+      anon_sym_while,
     ACTIONS(39), 1,
       anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
       sym_ident,
-    ACTIONS(87), 1,
+    ACTIONS(94), 1,
       anon_sym_RBRACE,
-    STATE(2), 1,
-      aux_sym_seq_repeat1,
-    STATE(13), 1,
-      sym_comment,
-    STATE(26), 1,
-      aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(17), 2,
       sym_stmt,
-    STATE(79), 1,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
       sym_at_attribute,
-    STATE(185), 1,
-      sym_static_annotation,
-    STATE(45), 7,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3748,46 +4243,43 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [802] = 20,
+      sym_for_stmt,
+  [735] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(23), 1,
       anon_sym_SEMI,
     ACTIONS(27), 1,
-      anon_sym_invoke,
+      anon_sym_for,
     ACTIONS(29), 1,
-      anon_sym_seq,
+      anon_sym_invoke,
     ACTIONS(31), 1,
-      anon_sym_par,
+      anon_sym_seq,
     ACTIONS(33), 1,
-      anon_sym_if,
+      anon_sym_par,
     ACTIONS(35), 1,
-      anon_sym_while,
+      anon_sym_if,
     ACTIONS(37), 1,
-      anon_sym_static,
+      anon_sym_while,
     ACTIONS(39), 1,
       anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
       sym_ident,
-    ACTIONS(89), 1,
+    ACTIONS(98), 1,
       anon_sym_RBRACE,
-    STATE(7), 1,
-      aux_sym_seq_repeat1,
-    STATE(14), 1,
-      sym_comment,
-    STATE(26), 1,
-      aux_sym_io_port_repeat1,
-    STATE(75), 1,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(8), 2,
       sym_stmt,
-    STATE(79), 1,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
       sym_at_attribute,
-    STATE(185), 1,
-      sym_static_annotation,
-    STATE(45), 7,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3795,44 +4287,43 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [869] = 19,
+      sym_for_stmt,
+  [796] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(23), 1,
       anon_sym_SEMI,
     ACTIONS(27), 1,
-      anon_sym_invoke,
+      anon_sym_for,
     ACTIONS(29), 1,
-      anon_sym_seq,
+      anon_sym_invoke,
     ACTIONS(31), 1,
+      anon_sym_seq,
+    ACTIONS(33), 1,
       anon_sym_par,
+    ACTIONS(35), 1,
+      anon_sym_if,
+    ACTIONS(37), 1,
+      anon_sym_while,
+    ACTIONS(39), 1,
+      anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
-      sym_ident,
-    ACTIONS(91), 1,
-      anon_sym_if,
-    ACTIONS(93), 1,
-      anon_sym_while,
-    ACTIONS(95), 1,
       anon_sym_static,
-    ACTIONS(97), 1,
-      anon_sym_repeat,
-    STATE(15), 1,
-      sym_comment,
-    STATE(25), 1,
-      aux_sym_io_port_repeat1,
-    STATE(79), 1,
-      sym_at_attribute,
-    STATE(189), 1,
+    ACTIONS(45), 1,
+      sym_ident,
+    ACTIONS(100), 1,
+      anon_sym_RBRACE,
+    STATE(205), 1,
       sym_static_annotation,
-    STATE(357), 1,
+    STATE(8), 2,
       sym_stmt,
-    STATE(394), 1,
-      sym_control_inner,
-    STATE(45), 7,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3840,42 +4331,43 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [933] = 18,
+      sym_for_stmt,
+  [857] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(23), 1,
       anon_sym_SEMI,
     ACTIONS(27), 1,
-      anon_sym_invoke,
+      anon_sym_for,
     ACTIONS(29), 1,
-      anon_sym_seq,
+      anon_sym_invoke,
     ACTIONS(31), 1,
+      anon_sym_seq,
+    ACTIONS(33), 1,
       anon_sym_par,
+    ACTIONS(35), 1,
+      anon_sym_if,
+    ACTIONS(37), 1,
+      anon_sym_while,
+    ACTIONS(39), 1,
+      anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
-      sym_ident,
-    ACTIONS(91), 1,
-      anon_sym_if,
-    ACTIONS(93), 1,
-      anon_sym_while,
-    ACTIONS(95), 1,
       anon_sym_static,
-    ACTIONS(97), 1,
-      anon_sym_repeat,
-    STATE(16), 1,
-      sym_comment,
-    STATE(25), 1,
-      aux_sym_io_port_repeat1,
-    STATE(79), 1,
-      sym_at_attribute,
-    STATE(189), 1,
+    ACTIONS(45), 1,
+      sym_ident,
+    ACTIONS(102), 1,
+      anon_sym_RBRACE,
+    STATE(205), 1,
       sym_static_annotation,
-    STATE(378), 1,
+    STATE(8), 2,
       sym_stmt,
-    STATE(45), 7,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3883,42 +4375,87 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [994] = 18,
+      sym_for_stmt,
+  [918] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(23), 1,
       anon_sym_SEMI,
     ACTIONS(27), 1,
-      anon_sym_invoke,
+      anon_sym_for,
     ACTIONS(29), 1,
-      anon_sym_seq,
+      anon_sym_invoke,
     ACTIONS(31), 1,
+      anon_sym_seq,
+    ACTIONS(33), 1,
       anon_sym_par,
+    ACTIONS(35), 1,
+      anon_sym_if,
+    ACTIONS(37), 1,
+      anon_sym_while,
+    ACTIONS(39), 1,
+      anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
       sym_ident,
-    ACTIONS(91), 1,
+    ACTIONS(104), 1,
+      anon_sym_RBRACE,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(8), 2,
+      sym_stmt,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
+      sym_enable,
+      sym_invoke,
+      sym_seq,
+      sym_par,
+      sym_if_stmt,
+      sym_while_stmt,
+      sym_repeat_stmt,
+      sym_for_stmt,
+  [979] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(23), 1,
+      anon_sym_SEMI,
+    ACTIONS(27), 1,
+      anon_sym_for,
+    ACTIONS(29), 1,
+      anon_sym_invoke,
+    ACTIONS(31), 1,
+      anon_sym_seq,
+    ACTIONS(33), 1,
+      anon_sym_par,
+    ACTIONS(35), 1,
       anon_sym_if,
-    ACTIONS(93), 1,
+    ACTIONS(37), 1,
       anon_sym_while,
-    ACTIONS(95), 1,
-      anon_sym_static,
-    ACTIONS(97), 1,
+    ACTIONS(39), 1,
       anon_sym_repeat,
-    STATE(17), 1,
-      sym_comment,
-    STATE(25), 1,
-      aux_sym_io_port_repeat1,
-    STATE(79), 1,
-      sym_at_attribute,
-    STATE(189), 1,
+    ACTIONS(41), 1,
+      anon_sym_AT,
+    ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
+      sym_ident,
+    ACTIONS(102), 1,
+      anon_sym_RBRACE,
+    STATE(205), 1,
       sym_static_annotation,
-    STATE(421), 1,
+    STATE(20), 2,
       sym_stmt,
-    STATE(45), 7,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3926,42 +4463,87 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [1055] = 18,
+      sym_for_stmt,
+  [1040] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(23), 1,
       anon_sym_SEMI,
     ACTIONS(27), 1,
-      anon_sym_invoke,
+      anon_sym_for,
     ACTIONS(29), 1,
-      anon_sym_seq,
+      anon_sym_invoke,
     ACTIONS(31), 1,
+      anon_sym_seq,
+    ACTIONS(33), 1,
       anon_sym_par,
+    ACTIONS(35), 1,
+      anon_sym_if,
+    ACTIONS(37), 1,
+      anon_sym_while,
+    ACTIONS(39), 1,
+      anon_sym_repeat,
     ACTIONS(41), 1,
       anon_sym_AT,
     ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
       sym_ident,
-    ACTIONS(91), 1,
+    ACTIONS(104), 1,
+      anon_sym_RBRACE,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(21), 2,
+      sym_stmt,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
+      sym_enable,
+      sym_invoke,
+      sym_seq,
+      sym_par,
+      sym_if_stmt,
+      sym_while_stmt,
+      sym_repeat_stmt,
+      sym_for_stmt,
+  [1101] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(23), 1,
+      anon_sym_SEMI,
+    ACTIONS(27), 1,
+      anon_sym_for,
+    ACTIONS(29), 1,
+      anon_sym_invoke,
+    ACTIONS(31), 1,
+      anon_sym_seq,
+    ACTIONS(33), 1,
+      anon_sym_par,
+    ACTIONS(35), 1,
       anon_sym_if,
-    ACTIONS(93), 1,
+    ACTIONS(37), 1,
       anon_sym_while,
-    ACTIONS(95), 1,
-      anon_sym_static,
-    ACTIONS(97), 1,
+    ACTIONS(39), 1,
       anon_sym_repeat,
-    STATE(18), 1,
-      sym_comment,
-    STATE(25), 1,
-      aux_sym_io_port_repeat1,
-    STATE(79), 1,
-      sym_at_attribute,
-    STATE(189), 1,
+    ACTIONS(41), 1,
+      anon_sym_AT,
+    ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
+      sym_ident,
+    ACTIONS(106), 1,
+      anon_sym_RBRACE,
+    STATE(205), 1,
       sym_static_annotation,
-    STATE(425), 1,
+    STATE(8), 2,
       sym_stmt,
-    STATE(45), 7,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
       sym_enable,
       sym_invoke,
       sym_seq,
@@ -3969,7204 +4551,6314 @@ static const uint16_t ts_small_parse_table[] = {
       sym_if_stmt,
       sym_while_stmt,
       sym_repeat_stmt,
-  [1116] = 19,
+      sym_for_stmt,
+  [1162] = 17,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(9), 1,
-      anon_sym_import,
-    ACTIONS(11), 1,
-      aux_sym_metadata_ci_token1,
-    ACTIONS(13), 1,
-      anon_sym_component,
-    ACTIONS(15), 1,
-      anon_sym_comb,
-    ACTIONS(17), 1,
-      anon_sym_primitive,
-    ACTIONS(19), 1,
-      anon_sym_extern,
-    ACTIONS(21), 1,
-      anon_sym_static,
-    ACTIONS(99), 1,
-      ts_builtin_sym_end,
-    STATE(19), 1,
       sym_comment,
-    STATE(22), 1,
-      aux_sym_source_file_repeat2,
-    STATE(83), 1,
-      aux_sym_source_file_repeat1,
-    STATE(117), 1,
-      sym_import,
-    STATE(204), 1,
-      sym_static_annotation,
-    STATE(260), 1,
-      sym_comb_or_static,
-    STATE(429), 1,
+    ACTIONS(23), 1,
+      anon_sym_SEMI,
+    ACTIONS(27), 1,
+      anon_sym_for,
+    ACTIONS(29), 1,
+      anon_sym_invoke,
+    ACTIONS(31), 1,
+      anon_sym_seq,
+    ACTIONS(33), 1,
+      anon_sym_par,
+    ACTIONS(35), 1,
+      anon_sym_if,
+    ACTIONS(37), 1,
+      anon_sym_while,
+    ACTIONS(39), 1,
+      anon_sym_repeat,
+    ACTIONS(41), 1,
+      anon_sym_AT,
+    ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
+      sym_ident,
+    ACTIONS(108), 1,
+      anon_sym_RBRACE,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(8), 2,
+      sym_stmt,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
+      sym_enable,
+      sym_invoke,
+      sym_seq,
+      sym_par,
+      sym_if_stmt,
+      sym_while_stmt,
+      sym_repeat_stmt,
+      sym_for_stmt,
+  [1223] = 17,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(23), 1,
+      anon_sym_SEMI,
+    ACTIONS(27), 1,
+      anon_sym_for,
+    ACTIONS(29), 1,
+      anon_sym_invoke,
+    ACTIONS(31), 1,
+      anon_sym_seq,
+    ACTIONS(33), 1,
+      anon_sym_par,
+    ACTIONS(35), 1,
+      anon_sym_if,
+    ACTIONS(37), 1,
+      anon_sym_while,
+    ACTIONS(39), 1,
+      anon_sym_repeat,
+    ACTIONS(41), 1,
+      anon_sym_AT,
+    ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(45), 1,
+      sym_ident,
+    ACTIONS(110), 1,
+      anon_sym_RBRACE,
+    STATE(205), 1,
+      sym_static_annotation,
+    STATE(4), 2,
+      sym_stmt,
+      aux_sym_control_inner_repeat1,
+    STATE(46), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(67), 8,
+      sym_enable,
+      sym_invoke,
+      sym_seq,
+      sym_par,
+      sym_if_stmt,
+      sym_while_stmt,
+      sym_repeat_stmt,
+      sym_for_stmt,
+  [1284] = 16,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(7), 1,
+      anon_sym_import,
+    ACTIONS(9), 1,
+      aux_sym_metadata_ci_token1,
+    ACTIONS(11), 1,
+      anon_sym_component,
+    ACTIONS(13), 1,
+      anon_sym_comb,
+    ACTIONS(15), 1,
+      anon_sym_bundle,
+    ACTIONS(17), 1,
+      anon_sym_primitive,
+    ACTIONS(19), 1,
+      anon_sym_extern,
+    ACTIONS(21), 1,
+      anon_sym_static,
+    ACTIONS(112), 1,
+      ts_builtin_sym_end,
+    STATE(264), 1,
+      sym_static_annotation,
+    STATE(308), 1,
+      sym_comb_or_static,
+    STATE(389), 1,
       sym_metadata,
-    STATE(448), 1,
+    STATE(460), 1,
       sym_metadata_ci,
-    STATE(140), 3,
+    STATE(82), 2,
+      sym_import,
+      aux_sym_source_file_repeat1,
+    STATE(26), 5,
       sym_component,
+      sym_bundle,
       sym_primitive,
       sym_extern,
-  [1176] = 5,
+      aux_sym_source_file_repeat2,
+  [1338] = 14,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(20), 1,
       sym_comment,
-    ACTIONS(103), 3,
-      anon_sym_EQ,
-      anon_sym_GT,
-      anon_sym_LT,
-    ACTIONS(101), 12,
-      anon_sym_SEMI,
-      anon_sym_LBRACE,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_QMARK,
-      anon_sym_with,
-  [1205] = 16,
+    ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(114), 1,
+      anon_sym_RBRACE,
+    ACTIONS(116), 1,
+      anon_sym_comb,
+    ACTIONS(118), 1,
+      anon_sym_for,
+    ACTIONS(120), 1,
+      anon_sym_group,
+    ACTIONS(122), 1,
+      anon_sym_AT,
+    ACTIONS(124), 1,
+      sym_ident,
+    STATE(418), 1,
+      sym_static_annotation,
+    STATE(422), 1,
+      sym_wires_inner,
+    STATE(482), 1,
+      sym_lhs,
+    STATE(161), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(474), 2,
+      sym_hole,
+      sym_port,
+    STATE(29), 4,
+      sym_wires_for,
+      sym_group,
+      sym_wire_assignment,
+      aux_sym_wires_inner_repeat1,
+  [1386] = 14,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(9), 1,
       aux_sym_metadata_ci_token1,
-    ACTIONS(13), 1,
+    ACTIONS(11), 1,
       anon_sym_component,
-    ACTIONS(15), 1,
+    ACTIONS(13), 1,
       anon_sym_comb,
+    ACTIONS(15), 1,
+      anon_sym_bundle,
     ACTIONS(17), 1,
       anon_sym_primitive,
     ACTIONS(19), 1,
       anon_sym_extern,
     ACTIONS(21), 1,
       anon_sym_static,
-    ACTIONS(99), 1,
+    ACTIONS(112), 1,
       ts_builtin_sym_end,
-    STATE(21), 1,
-      sym_comment,
-    STATE(27), 1,
-      aux_sym_source_file_repeat2,
-    STATE(204), 1,
+    STATE(264), 1,
       sym_static_annotation,
-    STATE(260), 1,
+    STATE(308), 1,
       sym_comb_or_static,
-    STATE(429), 1,
+    STATE(389), 1,
       sym_metadata,
-    STATE(448), 1,
+    STATE(460), 1,
       sym_metadata_ci,
-    STATE(140), 3,
+    STATE(34), 5,
       sym_component,
+      sym_bundle,
       sym_primitive,
       sym_extern,
-  [1256] = 16,
+      aux_sym_source_file_repeat2,
+  [1433] = 14,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(11), 1,
+      sym_comment,
+    ACTIONS(9), 1,
       aux_sym_metadata_ci_token1,
-    ACTIONS(13), 1,
+    ACTIONS(11), 1,
       anon_sym_component,
-    ACTIONS(15), 1,
+    ACTIONS(13), 1,
       anon_sym_comb,
+    ACTIONS(15), 1,
+      anon_sym_bundle,
     ACTIONS(17), 1,
       anon_sym_primitive,
     ACTIONS(19), 1,
       anon_sym_extern,
     ACTIONS(21), 1,
       anon_sym_static,
-    ACTIONS(105), 1,
+    ACTIONS(126), 1,
       ts_builtin_sym_end,
-    STATE(22), 1,
-      sym_comment,
-    STATE(27), 1,
-      aux_sym_source_file_repeat2,
-    STATE(204), 1,
+    STATE(264), 1,
       sym_static_annotation,
-    STATE(260), 1,
+    STATE(308), 1,
       sym_comb_or_static,
-    STATE(409), 1,
+    STATE(438), 1,
       sym_metadata,
-    STATE(448), 1,
+    STATE(460), 1,
       sym_metadata_ci,
-    STATE(140), 3,
+    STATE(34), 5,
       sym_component,
+      sym_bundle,
       sym_primitive,
       sym_extern,
-  [1307] = 7,
+      aux_sym_source_file_repeat2,
+  [1480] = 13,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(109), 1,
-      anon_sym_LBRACK,
-    ACTIONS(113), 1,
-      anon_sym_DOT,
-    STATE(23), 1,
       sym_comment,
-    ACTIONS(111), 3,
-      anon_sym_EQ,
-      anon_sym_GT,
-      anon_sym_LT,
-    ACTIONS(107), 9,
-      anon_sym_SEMI,
-      anon_sym_RPAREN,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_QMARK,
-  [1339] = 14,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(115), 1,
-      anon_sym_RBRACE,
-    ACTIONS(117), 1,
+    ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(116), 1,
       anon_sym_comb,
-    ACTIONS(119), 1,
+    ACTIONS(118), 1,
+      anon_sym_for,
+    ACTIONS(120), 1,
       anon_sym_group,
-    ACTIONS(121), 1,
+    ACTIONS(122), 1,
       anon_sym_AT,
-    ACTIONS(123), 1,
+    ACTIONS(124), 1,
       sym_ident,
-    STATE(24), 1,
-      sym_comment,
-    STATE(40), 1,
-      aux_sym_wires_inner_repeat1,
-    STATE(197), 1,
-      sym_at_attribute,
-    STATE(332), 1,
+    ACTIONS(128), 1,
+      anon_sym_RBRACE,
+    STATE(418), 1,
+      sym_static_annotation,
+    STATE(482), 1,
       sym_lhs,
-    STATE(335), 1,
-      sym_wires_inner,
-    STATE(156), 2,
-      sym_group,
-      sym_wire_assignment,
-    STATE(334), 2,
+    STATE(161), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(474), 2,
       sym_hole,
       sym_port,
-  [1384] = 16,
+    STATE(28), 4,
+      sym_wires_for,
+      sym_group,
+      sym_wire_assignment,
+      aux_sym_wires_inner_repeat1,
+  [1525] = 13,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(41), 1,
-      anon_sym_AT,
-    ACTIONS(125), 1,
-      anon_sym_SEMI,
-    ACTIONS(127), 1,
-      anon_sym_invoke,
-    ACTIONS(129), 1,
-      anon_sym_seq,
-    ACTIONS(131), 1,
-      anon_sym_par,
-    ACTIONS(133), 1,
-      anon_sym_if,
+      sym_comment,
+    ACTIONS(130), 1,
+      anon_sym_RBRACE,
+    ACTIONS(132), 1,
+      anon_sym_comb,
     ACTIONS(135), 1,
-      anon_sym_while,
-    ACTIONS(137), 1,
-      anon_sym_static,
-    ACTIONS(139), 1,
-      anon_sym_repeat,
+      anon_sym_for,
+    ACTIONS(138), 1,
+      anon_sym_group,
     ACTIONS(141), 1,
+      anon_sym_AT,
+    ACTIONS(144), 1,
+      anon_sym_static,
+    ACTIONS(147), 1,
       sym_ident,
-    STATE(25), 1,
-      sym_comment,
-    STATE(28), 1,
-      aux_sym_io_port_repeat1,
-    STATE(79), 1,
-      sym_at_attribute,
-    STATE(196), 1,
+    STATE(418), 1,
       sym_static_annotation,
-  [1433] = 16,
+    STATE(482), 1,
+      sym_lhs,
+    STATE(161), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(474), 2,
+      sym_hole,
+      sym_port,
+    STATE(28), 4,
+      sym_wires_for,
+      sym_group,
+      sym_wire_assignment,
+      aux_sym_wires_inner_repeat1,
+  [1570] = 13,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(41), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(116), 1,
+      anon_sym_comb,
+    ACTIONS(118), 1,
+      anon_sym_for,
+    ACTIONS(120), 1,
+      anon_sym_group,
+    ACTIONS(122), 1,
       anon_sym_AT,
-    ACTIONS(125), 1,
-      anon_sym_SEMI,
-    ACTIONS(127), 1,
-      anon_sym_invoke,
-    ACTIONS(129), 1,
-      anon_sym_seq,
-    ACTIONS(131), 1,
-      anon_sym_par,
-    ACTIONS(141), 1,
+    ACTIONS(124), 1,
       sym_ident,
-    ACTIONS(143), 1,
-      anon_sym_if,
-    ACTIONS(145), 1,
-      anon_sym_while,
-    ACTIONS(147), 1,
-      anon_sym_static,
-    ACTIONS(149), 1,
-      anon_sym_repeat,
-    STATE(26), 1,
-      sym_comment,
-    STATE(28), 1,
-      aux_sym_io_port_repeat1,
-    STATE(79), 1,
-      sym_at_attribute,
-    STATE(194), 1,
+    ACTIONS(150), 1,
+      anon_sym_RBRACE,
+    STATE(418), 1,
       sym_static_annotation,
-  [1482] = 12,
+    STATE(482), 1,
+      sym_lhs,
+    STATE(161), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(474), 2,
+      sym_hole,
+      sym_port,
+    STATE(28), 4,
+      sym_wires_for,
+      sym_group,
+      sym_wire_assignment,
+      aux_sym_wires_inner_repeat1,
+  [1615] = 13,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(153), 1,
-      anon_sym_component,
-    ACTIONS(156), 1,
+      sym_comment,
+    ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(116), 1,
       anon_sym_comb,
-    ACTIONS(159), 1,
-      anon_sym_primitive,
-    ACTIONS(162), 1,
-      anon_sym_extern,
-    ACTIONS(165), 1,
-      anon_sym_static,
-    STATE(204), 1,
-      sym_static_annotation,
-    STATE(260), 1,
-      sym_comb_or_static,
-    ACTIONS(151), 2,
-      ts_builtin_sym_end,
-      aux_sym_metadata_ci_token1,
-    STATE(27), 2,
-      sym_comment,
-      aux_sym_source_file_repeat2,
-    STATE(140), 3,
-      sym_component,
-      sym_primitive,
-      sym_extern,
-  [1523] = 7,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(168), 1,
-      anon_sym_SEMI,
-    ACTIONS(172), 1,
+    ACTIONS(118), 1,
+      anon_sym_for,
+    ACTIONS(120), 1,
+      anon_sym_group,
+    ACTIONS(122), 1,
       anon_sym_AT,
-    STATE(79), 1,
+    ACTIONS(124), 1,
+      sym_ident,
+    ACTIONS(152), 1,
+      anon_sym_RBRACE,
+    STATE(418), 1,
+      sym_static_annotation,
+    STATE(482), 1,
+      sym_lhs,
+    STATE(161), 2,
       sym_at_attribute,
-    STATE(28), 2,
-      sym_comment,
       aux_sym_io_port_repeat1,
-    ACTIONS(170), 8,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-      anon_sym_while,
-      anon_sym_static,
-      anon_sym_repeat,
-      sym_ident,
-  [1553] = 12,
+    STATE(474), 2,
+      sym_hole,
+      sym_port,
+    STATE(27), 4,
+      sym_wires_for,
+      sym_group,
+      sym_wire_assignment,
+      aux_sym_wires_inner_repeat1,
+  [1660] = 10,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
+      sym_comment,
+    ACTIONS(154), 1,
       anon_sym_LPAREN,
-    ACTIONS(177), 1,
+    ACTIONS(156), 1,
+      anon_sym_PERCENT,
+    ACTIONS(158), 1,
       anon_sym_BANG,
-    ACTIONS(179), 1,
+    ACTIONS(160), 1,
       sym_ident,
-    ACTIONS(181), 1,
+    ACTIONS(162), 1,
       sym_number,
-    STATE(29), 1,
-      sym_comment,
-    STATE(80), 1,
+    STATE(103), 1,
       sym_expr,
-    STATE(422), 1,
-      sym_term,
-    STATE(434), 1,
+    STATE(395), 1,
       sym_switch,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
+    STATE(84), 3,
       sym_hole,
       sym_port,
       sym_literal,
-  [1593] = 5,
+    STATE(89), 5,
+      sym_base_expr,
+      sym_static_timing,
+      sym_cmp_expr,
+      sym_not_expr,
+      sym_logic_expr,
+  [1697] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(30), 1,
       sym_comment,
-    ACTIONS(185), 2,
-      anon_sym_GT,
+    ACTIONS(166), 3,
+      anon_sym_EQ,
       anon_sym_LT,
-    ACTIONS(183), 10,
+      anon_sym_GT,
+    ACTIONS(164), 12,
       anon_sym_SEMI,
+      anon_sym_LBRACE,
       anon_sym_COMMA,
       anon_sym_RPAREN,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-      anon_sym_PIPE,
       anon_sym_AMP,
+      anon_sym_PIPE,
       anon_sym_QMARK,
-  [1619] = 12,
+      anon_sym_with,
+  [1720] = 10,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
+      sym_comment,
+    ACTIONS(154), 1,
       anon_sym_LPAREN,
-    ACTIONS(177), 1,
+    ACTIONS(156), 1,
+      anon_sym_PERCENT,
+    ACTIONS(158), 1,
       anon_sym_BANG,
-    ACTIONS(179), 1,
+    ACTIONS(160), 1,
       sym_ident,
-    ACTIONS(181), 1,
+    ACTIONS(162), 1,
       sym_number,
-    STATE(31), 1,
-      sym_comment,
-    STATE(84), 1,
+    STATE(95), 1,
       sym_expr,
-    STATE(398), 1,
+    STATE(420), 1,
       sym_switch,
-    STATE(422), 1,
-      sym_term,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
+    STATE(84), 3,
       sym_hole,
       sym_port,
       sym_literal,
-  [1659] = 6,
+    STATE(89), 5,
+      sym_base_expr,
+      sym_static_timing,
+      sym_cmp_expr,
+      sym_not_expr,
+      sym_logic_expr,
+  [1757] = 11,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(191), 1,
-      anon_sym_else,
-    STATE(32), 1,
       sym_comment,
-    ACTIONS(187), 3,
-      anon_sym_SEMI,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(189), 8,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-      anon_sym_while,
+    ACTIONS(170), 1,
+      anon_sym_component,
+    ACTIONS(173), 1,
+      anon_sym_comb,
+    ACTIONS(176), 1,
+      anon_sym_bundle,
+    ACTIONS(179), 1,
+      anon_sym_primitive,
+    ACTIONS(182), 1,
+      anon_sym_extern,
+    ACTIONS(185), 1,
       anon_sym_static,
-      anon_sym_repeat,
-      sym_ident,
-  [1687] = 12,
+    STATE(264), 1,
+      sym_static_annotation,
+    STATE(308), 1,
+      sym_comb_or_static,
+    ACTIONS(168), 2,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+    STATE(34), 5,
+      sym_component,
+      sym_bundle,
+      sym_primitive,
+      sym_extern,
+      aux_sym_source_file_repeat2,
+  [1796] = 10,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
+      sym_comment,
+    ACTIONS(154), 1,
       anon_sym_LPAREN,
-    ACTIONS(177), 1,
+    ACTIONS(156), 1,
+      anon_sym_PERCENT,
+    ACTIONS(158), 1,
       anon_sym_BANG,
-    ACTIONS(179), 1,
+    ACTIONS(160), 1,
       sym_ident,
-    ACTIONS(181), 1,
+    ACTIONS(162), 1,
       sym_number,
-    STATE(33), 1,
-      sym_comment,
-    STATE(82), 1,
+    STATE(94), 1,
       sym_expr,
-    STATE(420), 1,
+    STATE(481), 1,
       sym_switch,
-    STATE(422), 1,
-      sym_term,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
+    STATE(84), 3,
       sym_hole,
       sym_port,
       sym_literal,
-  [1727] = 12,
+    STATE(89), 5,
+      sym_base_expr,
+      sym_static_timing,
+      sym_cmp_expr,
+      sym_not_expr,
+      sym_logic_expr,
+  [1833] = 10,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(193), 1,
-      anon_sym_RBRACE,
-    ACTIONS(195), 1,
-      anon_sym_comb,
-    ACTIONS(198), 1,
-      anon_sym_group,
-    ACTIONS(201), 1,
-      anon_sym_AT,
-    ACTIONS(204), 1,
-      sym_ident,
-    STATE(197), 1,
-      sym_at_attribute,
-    STATE(332), 1,
-      sym_lhs,
-    STATE(34), 2,
       sym_comment,
-      aux_sym_wires_inner_repeat1,
-    STATE(156), 2,
-      sym_group,
-      sym_wire_assignment,
-    STATE(334), 2,
+    ACTIONS(154), 1,
+      anon_sym_LPAREN,
+    ACTIONS(156), 1,
+      anon_sym_PERCENT,
+    ACTIONS(158), 1,
+      anon_sym_BANG,
+    ACTIONS(160), 1,
+      sym_ident,
+    ACTIONS(162), 1,
+      sym_number,
+    STATE(114), 1,
+      sym_expr,
+    STATE(478), 1,
+      sym_switch,
+    STATE(84), 3,
       sym_hole,
       sym_port,
-  [1767] = 6,
+      sym_literal,
+    STATE(89), 5,
+      sym_base_expr,
+      sym_static_timing,
+      sym_cmp_expr,
+      sym_not_expr,
+      sym_logic_expr,
+  [1870] = 9,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(211), 1,
-      anon_sym_else,
-    STATE(35), 1,
       sym_comment,
-    ACTIONS(207), 3,
-      anon_sym_SEMI,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(209), 8,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-      anon_sym_while,
-      anon_sym_static,
-      anon_sym_repeat,
-      sym_ident,
-  [1795] = 12,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
+    ACTIONS(154), 1,
       anon_sym_LPAREN,
-    ACTIONS(177), 1,
+    ACTIONS(156), 1,
+      anon_sym_PERCENT,
+    ACTIONS(158), 1,
       anon_sym_BANG,
-    ACTIONS(179), 1,
+    ACTIONS(160), 1,
       sym_ident,
-    ACTIONS(181), 1,
+    ACTIONS(162), 1,
       sym_number,
-    STATE(36), 1,
-      sym_comment,
     STATE(81), 1,
       sym_expr,
-    STATE(417), 1,
-      sym_switch,
-    STATE(422), 1,
-      sym_term,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
+    STATE(84), 3,
       sym_hole,
       sym_port,
       sym_literal,
-  [1835] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(37), 1,
-      sym_comment,
-    ACTIONS(213), 3,
-      anon_sym_SEMI,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(215), 9,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-      anon_sym_else,
-      anon_sym_while,
-      anon_sym_static,
-      anon_sym_repeat,
-      sym_ident,
-  [1861] = 6,
+    STATE(89), 5,
+      sym_base_expr,
+      sym_static_timing,
+      sym_cmp_expr,
+      sym_not_expr,
+      sym_logic_expr,
+  [1904] = 9,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(221), 1,
-      anon_sym_else,
-    STATE(38), 1,
       sym_comment,
-    ACTIONS(217), 3,
-      anon_sym_SEMI,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(219), 8,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-      anon_sym_while,
-      anon_sym_static,
-      anon_sym_repeat,
+    ACTIONS(154), 1,
+      anon_sym_LPAREN,
+    ACTIONS(156), 1,
+      anon_sym_PERCENT,
+    ACTIONS(158), 1,
+      anon_sym_BANG,
+    ACTIONS(160), 1,
       sym_ident,
-  [1889] = 5,
+    ACTIONS(162), 1,
+      sym_number,
+    STATE(91), 1,
+      sym_expr,
+    STATE(84), 3,
+      sym_hole,
+      sym_port,
+      sym_literal,
+    STATE(89), 5,
+      sym_base_expr,
+      sym_static_timing,
+      sym_cmp_expr,
+      sym_not_expr,
+      sym_logic_expr,
+  [1938] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(39), 1,
       sym_comment,
-    ACTIONS(225), 3,
+    ACTIONS(190), 1,
+      anon_sym_LBRACK,
+    ACTIONS(194), 1,
+      anon_sym_DOT,
+    ACTIONS(192), 3,
       anon_sym_EQ,
-      anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(223), 9,
+      anon_sym_GT,
+    ACTIONS(188), 9,
       anon_sym_SEMI,
       anon_sym_RPAREN,
       anon_sym_EQ_EQ,
       anon_sym_BANG_EQ,
       anon_sym_LT_EQ,
       anon_sym_GT_EQ,
-      anon_sym_PIPE,
       anon_sym_AMP,
+      anon_sym_PIPE,
       anon_sym_QMARK,
-  [1915] = 13,
+  [1964] = 9,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(117), 1,
-      anon_sym_comb,
-    ACTIONS(119), 1,
-      anon_sym_group,
-    ACTIONS(121), 1,
-      anon_sym_AT,
-    ACTIONS(123), 1,
-      sym_ident,
-    ACTIONS(227), 1,
-      anon_sym_RBRACE,
-    STATE(34), 1,
-      aux_sym_wires_inner_repeat1,
-    STATE(40), 1,
       sym_comment,
-    STATE(197), 1,
-      sym_at_attribute,
-    STATE(332), 1,
-      sym_lhs,
-    STATE(156), 2,
-      sym_group,
-      sym_wire_assignment,
-    STATE(334), 2,
+    ACTIONS(154), 1,
+      anon_sym_LPAREN,
+    ACTIONS(156), 1,
+      anon_sym_PERCENT,
+    ACTIONS(158), 1,
+      anon_sym_BANG,
+    ACTIONS(160), 1,
+      sym_ident,
+    ACTIONS(162), 1,
+      sym_number,
+    STATE(128), 1,
+      sym_expr,
+    STATE(84), 3,
       sym_hole,
       sym_port,
-  [1957] = 6,
+      sym_literal,
+    STATE(89), 5,
+      sym_base_expr,
+      sym_static_timing,
+      sym_cmp_expr,
+      sym_not_expr,
+      sym_logic_expr,
+  [1998] = 9,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(231), 1,
+      sym_comment,
+    ACTIONS(154), 1,
       anon_sym_LPAREN,
-    STATE(41), 1,
+    ACTIONS(156), 1,
+      anon_sym_PERCENT,
+    ACTIONS(158), 1,
+      anon_sym_BANG,
+    ACTIONS(160), 1,
+      sym_ident,
+    ACTIONS(162), 1,
+      sym_number,
+    STATE(88), 1,
+      sym_expr,
+    STATE(84), 3,
+      sym_hole,
+      sym_port,
+      sym_literal,
+    STATE(89), 5,
+      sym_base_expr,
+      sym_static_timing,
+      sym_cmp_expr,
+      sym_not_expr,
+      sym_logic_expr,
+  [2032] = 4,
+    ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(229), 2,
+    ACTIONS(200), 1,
+      anon_sym_else,
+    ACTIONS(196), 3,
       anon_sym_SEMI,
+      anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(233), 8,
+    ACTIONS(198), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [1984] = 5,
+  [2055] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(42), 1,
       sym_comment,
-    ACTIONS(235), 3,
+    ACTIONS(202), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(237), 8,
+    ACTIONS(204), 10,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
+      anon_sym_else,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2009] = 5,
+  [2076] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(43), 1,
       sym_comment,
-    ACTIONS(239), 3,
+    ACTIONS(210), 1,
+      anon_sym_else,
+    ACTIONS(206), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(241), 8,
+    ACTIONS(208), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2034] = 5,
+  [2099] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(44), 1,
       sym_comment,
-    ACTIONS(243), 3,
+    ACTIONS(212), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(245), 8,
+    ACTIONS(214), 10,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
+      anon_sym_else,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2059] = 5,
+  [2120] = 13,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(45), 1,
       sym_comment,
-    ACTIONS(247), 3,
-      anon_sym_SEMI,
-      anon_sym_RBRACE,
+    ACTIONS(41), 1,
       anon_sym_AT,
-    ACTIONS(249), 8,
+    ACTIONS(43), 1,
+      anon_sym_static,
+    ACTIONS(216), 1,
+      anon_sym_SEMI,
+    ACTIONS(218), 1,
       anon_sym_invoke,
+    ACTIONS(220), 1,
       anon_sym_seq,
+    ACTIONS(222), 1,
       anon_sym_par,
+    ACTIONS(224), 1,
       anon_sym_if,
+    ACTIONS(226), 1,
       anon_sym_while,
-      anon_sym_static,
+    ACTIONS(228), 1,
       anon_sym_repeat,
+    ACTIONS(230), 1,
       sym_ident,
-  [2084] = 5,
+    STATE(213), 1,
+      sym_static_annotation,
+    STATE(80), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [2161] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(46), 1,
       sym_comment,
-    ACTIONS(251), 3,
+    ACTIONS(236), 1,
+      anon_sym_else,
+    ACTIONS(232), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(253), 8,
+    ACTIONS(234), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2109] = 5,
+  [2184] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(47), 1,
       sym_comment,
-    ACTIONS(213), 3,
+    ACTIONS(238), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(215), 8,
+    ACTIONS(240), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2134] = 5,
+  [2204] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(48), 1,
       sym_comment,
-    ACTIONS(257), 2,
-      anon_sym_GT,
-      anon_sym_LT,
-    ACTIONS(255), 9,
+    ACTIONS(242), 3,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_QMARK,
-  [2159] = 5,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(244), 9,
+      anon_sym_for,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_static,
+      sym_ident,
+  [2224] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(49), 1,
       sym_comment,
-    ACTIONS(261), 2,
-      anon_sym_GT,
-      anon_sym_LT,
-    ACTIONS(259), 9,
+    ACTIONS(246), 3,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_QMARK,
-  [2184] = 6,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(248), 9,
+      anon_sym_for,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_static,
+      sym_ident,
+  [2244] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(263), 1,
-      anon_sym_EQ_EQ,
-    STATE(50), 1,
       sym_comment,
-    ACTIONS(261), 2,
-      anon_sym_GT,
-      anon_sym_LT,
-    ACTIONS(259), 8,
+    ACTIONS(250), 3,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_QMARK,
-  [2211] = 7,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(252), 9,
+      anon_sym_for,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_static,
+      sym_ident,
+  [2264] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(263), 1,
-      anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
-      anon_sym_BANG_EQ,
-    STATE(51), 1,
       sym_comment,
-    ACTIONS(261), 2,
-      anon_sym_GT,
-      anon_sym_LT,
-    ACTIONS(259), 7,
+    ACTIONS(254), 3,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_QMARK,
-  [2240] = 5,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(256), 9,
+      anon_sym_for,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_static,
+      sym_ident,
+  [2284] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(52), 1,
       sym_comment,
-    ACTIONS(267), 3,
+    ACTIONS(258), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(269), 8,
+    ACTIONS(260), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2265] = 8,
+  [2304] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(263), 1,
-      anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
-      anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
-      anon_sym_LT_EQ,
-    STATE(53), 1,
       sym_comment,
-    ACTIONS(261), 2,
-      anon_sym_GT,
+    ACTIONS(264), 3,
+      anon_sym_EQ,
       anon_sym_LT,
-    ACTIONS(259), 6,
+      anon_sym_GT,
+    ACTIONS(262), 9,
       anon_sym_SEMI,
       anon_sym_RPAREN,
-      anon_sym_GT_EQ,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_QMARK,
-  [2296] = 9,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(263), 1,
       anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
       anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
       anon_sym_LT_EQ,
-    ACTIONS(273), 1,
       anon_sym_GT_EQ,
-    STATE(54), 1,
-      sym_comment,
-    ACTIONS(261), 2,
-      anon_sym_GT,
-      anon_sym_LT,
-    ACTIONS(259), 5,
-      anon_sym_SEMI,
-      anon_sym_RPAREN,
-      anon_sym_PIPE,
       anon_sym_AMP,
+      anon_sym_PIPE,
       anon_sym_QMARK,
-  [2329] = 10,
+  [2324] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(261), 1,
+      sym_comment,
+    ACTIONS(268), 2,
       anon_sym_LT,
-    ACTIONS(263), 1,
+      anon_sym_GT,
+    ACTIONS(266), 10,
+      anon_sym_SEMI,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
       anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
       anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
       anon_sym_LT_EQ,
-    ACTIONS(273), 1,
       anon_sym_GT_EQ,
-    ACTIONS(275), 1,
-      anon_sym_GT,
-    STATE(55), 1,
-      sym_comment,
-    ACTIONS(259), 5,
-      anon_sym_SEMI,
-      anon_sym_RPAREN,
-      anon_sym_PIPE,
       anon_sym_AMP,
+      anon_sym_PIPE,
       anon_sym_QMARK,
-  [2364] = 5,
+  [2344] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(56), 1,
       sym_comment,
-    ACTIONS(277), 3,
+    ACTIONS(270), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(279), 8,
+    ACTIONS(272), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2389] = 5,
+  [2364] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(57), 1,
       sym_comment,
-    ACTIONS(281), 3,
+    ACTIONS(274), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(283), 8,
+    ACTIONS(276), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2414] = 5,
+  [2384] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(58), 1,
       sym_comment,
-    ACTIONS(285), 3,
+    ACTIONS(278), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(287), 8,
+    ACTIONS(280), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2439] = 5,
+  [2404] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(59), 1,
       sym_comment,
-    ACTIONS(289), 3,
+    ACTIONS(282), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(291), 8,
+    ACTIONS(284), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2464] = 5,
+  [2424] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(60), 1,
       sym_comment,
-    ACTIONS(293), 3,
+    ACTIONS(286), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(295), 8,
+    ACTIONS(288), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2489] = 5,
+  [2444] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(61), 1,
       sym_comment,
-    ACTIONS(217), 3,
+    ACTIONS(290), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(219), 8,
+    ACTIONS(292), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2514] = 5,
+  [2464] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(62), 1,
       sym_comment,
-    ACTIONS(297), 3,
+    ACTIONS(294), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(299), 8,
+    ACTIONS(296), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2539] = 5,
+  [2484] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(63), 1,
       sym_comment,
-    ACTIONS(301), 3,
+    ACTIONS(232), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(303), 8,
+    ACTIONS(234), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2564] = 5,
+  [2504] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(64), 1,
       sym_comment,
-    ACTIONS(305), 3,
+    ACTIONS(298), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(307), 8,
+    ACTIONS(300), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2589] = 5,
+  [2524] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(65), 1,
       sym_comment,
-    ACTIONS(309), 3,
+    ACTIONS(302), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(311), 8,
+    ACTIONS(304), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2614] = 5,
+  [2544] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(66), 1,
       sym_comment,
-    ACTIONS(313), 3,
+    ACTIONS(306), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(315), 8,
+    ACTIONS(308), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2639] = 5,
+  [2564] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(67), 1,
       sym_comment,
-    ACTIONS(319), 2,
-      anon_sym_GT,
-      anon_sym_LT,
-    ACTIONS(317), 9,
+    ACTIONS(310), 3,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_QMARK,
-  [2664] = 5,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(312), 9,
+      anon_sym_for,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_static,
+      sym_ident,
+  [2584] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(68), 1,
       sym_comment,
-    ACTIONS(323), 2,
-      anon_sym_GT,
-      anon_sym_LT,
-    ACTIONS(321), 9,
+    ACTIONS(314), 3,
       anon_sym_SEMI,
-      anon_sym_RPAREN,
-      anon_sym_EQ_EQ,
-      anon_sym_BANG_EQ,
-      anon_sym_LT_EQ,
-      anon_sym_GT_EQ,
-      anon_sym_PIPE,
-      anon_sym_AMP,
-      anon_sym_QMARK,
-  [2689] = 5,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(316), 9,
+      anon_sym_for,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_static,
+      sym_ident,
+  [2604] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(69), 1,
       sym_comment,
-    ACTIONS(325), 3,
+    ACTIONS(318), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(327), 8,
+    ACTIONS(320), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2714] = 5,
+  [2624] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(70), 1,
       sym_comment,
-    ACTIONS(329), 3,
+    ACTIONS(322), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(331), 8,
+    ACTIONS(324), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2739] = 5,
+  [2644] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(71), 1,
       sym_comment,
-    ACTIONS(333), 3,
+    ACTIONS(326), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(335), 8,
+    ACTIONS(328), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2764] = 5,
+  [2664] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(72), 1,
       sym_comment,
-    ACTIONS(337), 3,
+    ACTIONS(330), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(339), 8,
+    ACTIONS(332), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2789] = 5,
+  [2684] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(73), 1,
       sym_comment,
-    ACTIONS(341), 3,
+    ACTIONS(334), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(343), 8,
+    ACTIONS(336), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2814] = 5,
+  [2704] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(74), 1,
       sym_comment,
-    ACTIONS(345), 3,
+    ACTIONS(338), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(347), 8,
+    ACTIONS(340), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2839] = 5,
+  [2724] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(75), 1,
       sym_comment,
-    ACTIONS(349), 3,
+    ACTIONS(342), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(351), 8,
+    ACTIONS(344), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2864] = 5,
+  [2744] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(76), 1,
       sym_comment,
-    ACTIONS(353), 3,
+    ACTIONS(346), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(355), 8,
+    ACTIONS(348), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2889] = 5,
+  [2764] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(77), 1,
       sym_comment,
-    ACTIONS(357), 3,
+    ACTIONS(350), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(359), 8,
+    ACTIONS(352), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2914] = 5,
+  [2784] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(78), 1,
       sym_comment,
-    ACTIONS(361), 3,
+    ACTIONS(202), 3,
       anon_sym_SEMI,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(363), 8,
+    ACTIONS(204), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2939] = 5,
+  [2804] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(79), 1,
       sym_comment,
-    ACTIONS(365), 2,
+    ACTIONS(212), 3,
       anon_sym_SEMI,
+      anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(367), 8,
+    ACTIONS(214), 9,
+      anon_sym_for,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
+      anon_sym_repeat,
       anon_sym_static,
+      sym_ident,
+  [2824] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(354), 1,
+      anon_sym_SEMI,
+    ACTIONS(358), 1,
+      anon_sym_AT,
+    STATE(80), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    ACTIONS(356), 8,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_while,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [2963] = 13,
+  [2848] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(263), 1,
+      sym_comment,
+    ACTIONS(365), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(363), 4,
       anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
       anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
       anon_sym_LT_EQ,
-    ACTIONS(273), 1,
       anon_sym_GT_EQ,
-    ACTIONS(275), 1,
-      anon_sym_GT,
-    ACTIONS(369), 1,
+    ACTIONS(361), 5,
       anon_sym_SEMI,
-    ACTIONS(371), 1,
-      anon_sym_LT,
-    ACTIONS(373), 1,
-      anon_sym_PIPE,
-    ACTIONS(375), 1,
+      anon_sym_RPAREN,
       anon_sym_AMP,
-    ACTIONS(377), 1,
-      anon_sym_QMARK,
-    STATE(80), 1,
-      sym_comment,
-  [3003] = 13,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(263), 1,
-      anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
-      anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
-      anon_sym_LT_EQ,
-    ACTIONS(273), 1,
-      anon_sym_GT_EQ,
-    ACTIONS(275), 1,
-      anon_sym_GT,
-    ACTIONS(371), 1,
-      anon_sym_LT,
-    ACTIONS(373), 1,
       anon_sym_PIPE,
-    ACTIONS(375), 1,
-      anon_sym_AMP,
-    ACTIONS(377), 1,
       anon_sym_QMARK,
-    ACTIONS(379), 1,
-      anon_sym_SEMI,
-    STATE(81), 1,
-      sym_comment,
-  [3043] = 13,
+  [2869] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(263), 1,
-      anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
-      anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
-      anon_sym_LT_EQ,
-    ACTIONS(273), 1,
-      anon_sym_GT_EQ,
-    ACTIONS(275), 1,
-      anon_sym_GT,
-    ACTIONS(371), 1,
-      anon_sym_LT,
-    ACTIONS(373), 1,
-      anon_sym_PIPE,
-    ACTIONS(375), 1,
-      anon_sym_AMP,
-    ACTIONS(377), 1,
-      anon_sym_QMARK,
-    ACTIONS(381), 1,
-      anon_sym_SEMI,
-    STATE(82), 1,
       sym_comment,
-  [3083] = 6,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(385), 1,
+    ACTIONS(369), 1,
       anon_sym_import,
-    STATE(117), 1,
+    STATE(82), 2,
       sym_import,
-    STATE(83), 2,
-      sym_comment,
       aux_sym_source_file_repeat1,
-    ACTIONS(383), 7,
+    ACTIONS(367), 8,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
       anon_sym_comb,
+      anon_sym_bundle,
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [3109] = 13,
+  [2890] = 9,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(372), 1,
+      anon_sym_RBRACE,
+    ACTIONS(374), 1,
+      anon_sym_for,
+    ACTIONS(376), 1,
+      anon_sym_ref,
+    ACTIONS(378), 1,
+      anon_sym_AT,
+    ACTIONS(380), 1,
+      sym_ident,
+    STATE(419), 1,
+      sym_cells_inner,
+    STATE(223), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(99), 3,
+      sym_cell_for,
+      sym_cell_assignment,
+      aux_sym_cells_inner_repeat1,
+  [2921] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(384), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(382), 9,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+      anon_sym_QMARK,
+  [2940] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(263), 1,
+      sym_comment,
+    ACTIONS(388), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(386), 9,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
       anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
       anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
       anon_sym_LT_EQ,
-    ACTIONS(273), 1,
       anon_sym_GT_EQ,
-    ACTIONS(275), 1,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+      anon_sym_QMARK,
+  [2959] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(392), 2,
+      anon_sym_LT,
       anon_sym_GT,
-    ACTIONS(371), 1,
+    ACTIONS(390), 9,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+      anon_sym_QMARK,
+  [2978] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(396), 1,
+      anon_sym_LT,
+    STATE(120), 1,
+      sym_latency_annotation,
+    ACTIONS(394), 9,
+      anon_sym_component,
+      anon_sym_comb,
+      anon_sym_primitive,
+      anon_sym_group,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_repeat,
+  [2999] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(400), 2,
       anon_sym_LT,
-    ACTIONS(373), 1,
+      anon_sym_GT,
+    ACTIONS(398), 9,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP,
       anon_sym_PIPE,
-    ACTIONS(375), 1,
+      anon_sym_QMARK,
+  [3018] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(404), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(402), 9,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
       anon_sym_AMP,
-    ACTIONS(377), 1,
+      anon_sym_PIPE,
       anon_sym_QMARK,
-    ACTIONS(388), 1,
+  [3037] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(408), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(406), 9,
       anon_sym_SEMI,
-    STATE(84), 1,
+      anon_sym_RPAREN,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+      anon_sym_QMARK,
+  [3056] = 3,
+    ACTIONS(3), 1,
       sym_comment,
-  [3149] = 5,
+    ACTIONS(412), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(410), 9,
+      anon_sym_SEMI,
+      anon_sym_RPAREN,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+      anon_sym_QMARK,
+  [3075] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(85), 1,
       sym_comment,
-    ACTIONS(390), 2,
+    ACTIONS(416), 1,
+      anon_sym_LPAREN,
+    ACTIONS(414), 2,
       anon_sym_SEMI,
       anon_sym_AT,
-    ACTIONS(392), 8,
+    ACTIONS(418), 8,
       anon_sym_invoke,
       anon_sym_seq,
       anon_sym_par,
       anon_sym_if,
       anon_sym_while,
-      anon_sym_static,
       anon_sym_repeat,
+      anon_sym_static,
       sym_ident,
-  [3173] = 10,
+  [3096] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(394), 1,
+      sym_comment,
+    ACTIONS(420), 10,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
       anon_sym_RBRACE,
-    ACTIONS(396), 1,
+      anon_sym_comb,
+      anon_sym_bundle,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [3112] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(422), 1,
+      anon_sym_SEMI,
+    ACTIONS(426), 1,
+      anon_sym_QMARK,
+    ACTIONS(365), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(424), 2,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+    ACTIONS(363), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [3136] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(426), 1,
+      anon_sym_QMARK,
+    ACTIONS(428), 1,
+      anon_sym_SEMI,
+    ACTIONS(365), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(424), 2,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+    ACTIONS(363), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [3160] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(374), 1,
+      anon_sym_for,
+    ACTIONS(376), 1,
+      anon_sym_ref,
+    ACTIONS(378), 1,
       anon_sym_AT,
-    ACTIONS(399), 1,
+    ACTIONS(380), 1,
       sym_ident,
-    STATE(192), 1,
+    ACTIONS(430), 1,
+      anon_sym_RBRACE,
+    STATE(223), 2,
       sym_at_attribute,
-    STATE(241), 1,
-      sym_wire_assignment,
-    STATE(447), 1,
-      sym_lhs,
-    STATE(86), 2,
-      sym_comment,
-      aux_sym_group_repeat1,
-    STATE(334), 2,
-      sym_hole,
-      sym_port,
-  [3206] = 11,
+      aux_sym_io_port_repeat1,
+    STATE(109), 3,
+      sym_cell_for,
+      sym_cell_assignment,
+      aux_sym_cells_inner_repeat1,
+  [3188] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(121), 1,
+      sym_comment,
+    ACTIONS(122), 1,
       anon_sym_AT,
-    ACTIONS(179), 1,
+    ACTIONS(160), 1,
       sym_ident,
-    ACTIONS(402), 1,
+    ACTIONS(432), 1,
       anon_sym_RBRACE,
-    STATE(87), 1,
-      sym_comment,
-    STATE(107), 1,
+    STATE(495), 1,
+      sym_lhs,
+    STATE(111), 2,
+      sym_wire_assignment,
       aux_sym_group_repeat1,
-    STATE(192), 1,
+    STATE(179), 2,
       sym_at_attribute,
-    STATE(241), 1,
-      sym_wire_assignment,
-    STATE(447), 1,
-      sym_lhs,
-    STATE(334), 2,
+      aux_sym_io_port_repeat1,
+    STATE(474), 2,
       sym_hole,
       sym_port,
-  [3241] = 11,
+  [3216] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(21), 1,
+      sym_comment,
+    ACTIONS(436), 1,
+      anon_sym_SEMI,
+    ACTIONS(434), 9,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_RBRACE,
+      anon_sym_comb,
+      anon_sym_bundle,
+      anon_sym_primitive,
+      anon_sym_extern,
       anon_sym_static,
-    ACTIONS(404), 1,
-      anon_sym_LBRACE,
-    ACTIONS(406), 1,
-      anon_sym_if,
-    ACTIONS(408), 1,
-      anon_sym_AT,
-    STATE(88), 1,
+  [3234] = 8,
+    ACTIONS(3), 1,
       sym_comment,
-    STATE(151), 1,
-      aux_sym_io_port_repeat1,
-    STATE(246), 1,
+    ACTIONS(374), 1,
+      anon_sym_for,
+    ACTIONS(376), 1,
+      anon_sym_ref,
+    ACTIONS(378), 1,
+      anon_sym_AT,
+    ACTIONS(380), 1,
+      sym_ident,
+    ACTIONS(438), 1,
+      anon_sym_RBRACE,
+    STATE(223), 2,
       sym_at_attribute,
-    STATE(352), 1,
-      sym_static_annotation,
-    STATE(59), 2,
-      sym_block,
-      sym_if_stmt,
-  [3276] = 9,
+      aux_sym_io_port_repeat1,
+    STATE(108), 3,
+      sym_cell_for,
+      sym_cell_assignment,
+      aux_sym_cells_inner_repeat1,
+  [3262] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
-      anon_sym_LPAREN,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
-    STATE(54), 1,
-      sym_expr,
-    STATE(89), 1,
       sym_comment,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
+    ACTIONS(122), 1,
+      anon_sym_AT,
+    ACTIONS(160), 1,
+      sym_ident,
+    ACTIONS(440), 1,
+      anon_sym_RBRACE,
+    STATE(495), 1,
+      sym_lhs,
+    STATE(102), 2,
+      sym_wire_assignment,
+      aux_sym_group_repeat1,
+    STATE(179), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(474), 2,
       sym_hole,
       sym_port,
-      sym_literal,
-  [3307] = 9,
+  [3290] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
-      anon_sym_LPAREN,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
-    STATE(55), 1,
-      sym_expr,
-    STATE(90), 1,
       sym_comment,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
+    ACTIONS(122), 1,
+      anon_sym_AT,
+    ACTIONS(160), 1,
+      sym_ident,
+    ACTIONS(440), 1,
+      anon_sym_RBRACE,
+    STATE(495), 1,
+      sym_lhs,
+    STATE(107), 2,
+      sym_wire_assignment,
+      aux_sym_group_repeat1,
+    STATE(179), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(474), 2,
       sym_hole,
       sym_port,
-      sym_literal,
-  [3338] = 9,
+  [3318] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
-      anon_sym_LPAREN,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
-    STATE(51), 1,
-      sym_expr,
-    STATE(91), 1,
       sym_comment,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
+    ACTIONS(442), 1,
+      anon_sym_RBRACE,
+    ACTIONS(444), 1,
+      anon_sym_AT,
+    ACTIONS(447), 1,
+      sym_ident,
+    STATE(495), 1,
+      sym_lhs,
+    STATE(102), 2,
+      sym_wire_assignment,
+      aux_sym_group_repeat1,
+    STATE(179), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(474), 2,
       sym_hole,
       sym_port,
-      sym_literal,
-  [3369] = 9,
+  [3346] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
-      anon_sym_LPAREN,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
-    STATE(92), 1,
       sym_comment,
-    STATE(143), 1,
-      sym_expr,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
-      sym_hole,
-      sym_port,
-      sym_literal,
-  [3400] = 11,
+    ACTIONS(426), 1,
+      anon_sym_QMARK,
+    ACTIONS(450), 1,
+      anon_sym_SEMI,
+    ACTIONS(365), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(424), 2,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+    ACTIONS(363), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [3370] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(452), 10,
+      ts_builtin_sym_end,
+      anon_sym_SEMI,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_RBRACE,
+      anon_sym_comb,
+      anon_sym_bundle,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [3386] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(456), 1,
+      anon_sym_SEMI,
+    ACTIONS(454), 9,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_RBRACE,
+      anon_sym_comb,
+      anon_sym_bundle,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [3404] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(460), 1,
+      anon_sym_SEMI,
+    ACTIONS(458), 9,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_RBRACE,
+      anon_sym_comb,
+      anon_sym_bundle,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [3422] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(121), 1,
+      sym_comment,
+    ACTIONS(122), 1,
       anon_sym_AT,
-    ACTIONS(179), 1,
+    ACTIONS(160), 1,
       sym_ident,
-    ACTIONS(410), 1,
+    ACTIONS(462), 1,
       anon_sym_RBRACE,
-    STATE(86), 1,
+    STATE(495), 1,
+      sym_lhs,
+    STATE(102), 2,
+      sym_wire_assignment,
       aux_sym_group_repeat1,
-    STATE(93), 1,
+    STATE(179), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(474), 2,
+      sym_hole,
+      sym_port,
+  [3450] = 8,
+    ACTIONS(3), 1,
       sym_comment,
-    STATE(192), 1,
+    ACTIONS(464), 1,
+      anon_sym_RBRACE,
+    ACTIONS(466), 1,
+      anon_sym_for,
+    ACTIONS(469), 1,
+      anon_sym_ref,
+    ACTIONS(472), 1,
+      anon_sym_AT,
+    ACTIONS(475), 1,
+      sym_ident,
+    STATE(223), 2,
       sym_at_attribute,
-    STATE(241), 1,
-      sym_wire_assignment,
-    STATE(447), 1,
+      aux_sym_io_port_repeat1,
+    STATE(108), 3,
+      sym_cell_for,
+      sym_cell_assignment,
+      aux_sym_cells_inner_repeat1,
+  [3478] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(374), 1,
+      anon_sym_for,
+    ACTIONS(376), 1,
+      anon_sym_ref,
+    ACTIONS(378), 1,
+      anon_sym_AT,
+    ACTIONS(380), 1,
+      sym_ident,
+    ACTIONS(478), 1,
+      anon_sym_RBRACE,
+    STATE(223), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(108), 3,
+      sym_cell_for,
+      sym_cell_assignment,
+      aux_sym_cells_inner_repeat1,
+  [3506] = 8,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(122), 1,
+      anon_sym_AT,
+    ACTIONS(160), 1,
+      sym_ident,
+    ACTIONS(480), 1,
+      anon_sym_RBRACE,
+    STATE(495), 1,
       sym_lhs,
-    STATE(334), 2,
+    STATE(100), 2,
+      sym_wire_assignment,
+      aux_sym_group_repeat1,
+    STATE(179), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(474), 2,
       sym_hole,
       sym_port,
-  [3435] = 9,
+  [3534] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
-      anon_sym_LPAREN,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
-    STATE(50), 1,
-      sym_expr,
-    STATE(94), 1,
       sym_comment,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
+    ACTIONS(122), 1,
+      anon_sym_AT,
+    ACTIONS(160), 1,
+      sym_ident,
+    ACTIONS(480), 1,
+      anon_sym_RBRACE,
+    STATE(495), 1,
+      sym_lhs,
+    STATE(102), 2,
+      sym_wire_assignment,
+      aux_sym_group_repeat1,
+    STATE(179), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(474), 2,
       sym_hole,
       sym_port,
-      sym_literal,
-  [3466] = 11,
+  [3562] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(21), 1,
+      sym_comment,
+    ACTIONS(484), 1,
+      anon_sym_SEMI,
+    ACTIONS(482), 9,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_RBRACE,
+      anon_sym_comb,
+      anon_sym_bundle,
+      anon_sym_primitive,
+      anon_sym_extern,
       anon_sym_static,
-    ACTIONS(408), 1,
+  [3580] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(486), 2,
+      anon_sym_SEMI,
       anon_sym_AT,
-    ACTIONS(412), 1,
-      anon_sym_LBRACE,
-    ACTIONS(414), 1,
+    ACTIONS(488), 8,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
       anon_sym_if,
-    STATE(95), 1,
+      anon_sym_while,
+      anon_sym_repeat,
+      anon_sym_static,
+      sym_ident,
+  [3598] = 6,
+    ACTIONS(3), 1,
       sym_comment,
-    STATE(149), 1,
-      aux_sym_io_port_repeat1,
-    STATE(246), 1,
-      sym_at_attribute,
-    STATE(439), 1,
-      sym_static_annotation,
-    STATE(61), 2,
-      sym_block,
-      sym_if_stmt,
-  [3501] = 9,
+    ACTIONS(426), 1,
+      anon_sym_QMARK,
+    ACTIONS(490), 1,
+      anon_sym_SEMI,
+    ACTIONS(365), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(424), 2,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+    ACTIONS(363), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [3622] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
-      anon_sym_LPAREN,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
-    STATE(96), 1,
       sym_comment,
-    STATE(145), 1,
-      sym_expr,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
-      sym_hole,
-      sym_port,
-      sym_literal,
-  [3532] = 11,
+    ACTIONS(494), 1,
+      anon_sym_SLASH,
+    ACTIONS(492), 8,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_DOT_DOT,
+  [3639] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(454), 9,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_RBRACE,
+      anon_sym_comb,
+      anon_sym_bundle,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [3654] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(496), 1,
+      anon_sym_STAR,
+    ACTIONS(498), 1,
+      anon_sym_SLASH,
+    ACTIONS(492), 7,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_DOT_DOT,
+  [3673] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(21), 1,
       anon_sym_static,
-    ACTIONS(404), 1,
+    ACTIONS(500), 1,
       anon_sym_LBRACE,
-    ACTIONS(406), 1,
+    ACTIONS(502), 1,
       anon_sym_if,
-    ACTIONS(408), 1,
+    ACTIONS(504), 1,
       anon_sym_AT,
-    STATE(97), 1,
-      sym_comment,
-    STATE(151), 1,
-      aux_sym_io_port_repeat1,
-    STATE(246), 1,
-      sym_at_attribute,
-    STATE(352), 1,
+    STATE(408), 1,
       sym_static_annotation,
-    STATE(61), 2,
+    STATE(70), 2,
       sym_block,
       sym_if_stmt,
-  [3567] = 11,
+    STATE(192), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [3700] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(21), 1,
+      sym_comment,
+    ACTIONS(458), 9,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_RBRACE,
+      anon_sym_comb,
+      anon_sym_bundle,
+      anon_sym_primitive,
+      anon_sym_extern,
       anon_sym_static,
-    ACTIONS(408), 1,
-      anon_sym_AT,
-    ACTIONS(412), 1,
-      anon_sym_LBRACE,
-    ACTIONS(414), 1,
-      anon_sym_if,
-    STATE(98), 1,
+  [3715] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-    STATE(149), 1,
-      aux_sym_io_port_repeat1,
-    STATE(246), 1,
-      sym_at_attribute,
-    STATE(439), 1,
-      sym_static_annotation,
-    STATE(74), 2,
-      sym_block,
-      sym_if_stmt,
-  [3602] = 11,
+    ACTIONS(506), 9,
+      anon_sym_component,
+      anon_sym_comb,
+      anon_sym_primitive,
+      anon_sym_group,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_repeat,
+  [3730] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(21), 1,
       anon_sym_static,
-    ACTIONS(408), 1,
-      anon_sym_AT,
-    ACTIONS(412), 1,
+    ACTIONS(500), 1,
       anon_sym_LBRACE,
-    ACTIONS(414), 1,
+    ACTIONS(502), 1,
       anon_sym_if,
-    STATE(99), 1,
-      sym_comment,
-    STATE(149), 1,
-      aux_sym_io_port_repeat1,
-    STATE(246), 1,
-      sym_at_attribute,
-    STATE(439), 1,
+    ACTIONS(504), 1,
+      anon_sym_AT,
+    STATE(408), 1,
       sym_static_annotation,
-    STATE(59), 2,
+    STATE(63), 2,
       sym_block,
       sym_if_stmt,
-  [3637] = 11,
+    STATE(192), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [3757] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(121), 1,
-      anon_sym_AT,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(416), 1,
+      sym_comment,
+    ACTIONS(508), 9,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
       anon_sym_RBRACE,
-    STATE(93), 1,
-      aux_sym_group_repeat1,
-    STATE(100), 1,
+      anon_sym_comb,
+      anon_sym_bundle,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [3772] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-    STATE(192), 1,
-      sym_at_attribute,
-    STATE(241), 1,
-      sym_wire_assignment,
-    STATE(447), 1,
-      sym_lhs,
-    STATE(334), 2,
-      sym_hole,
-      sym_port,
-  [3672] = 11,
+    ACTIONS(434), 9,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_RBRACE,
+      anon_sym_comb,
+      anon_sym_bundle,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [3787] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(21), 1,
       anon_sym_static,
-    ACTIONS(404), 1,
+    ACTIONS(500), 1,
       anon_sym_LBRACE,
-    ACTIONS(406), 1,
+    ACTIONS(502), 1,
       anon_sym_if,
-    ACTIONS(408), 1,
+    ACTIONS(504), 1,
       anon_sym_AT,
-    STATE(101), 1,
-      sym_comment,
-    STATE(151), 1,
-      aux_sym_io_port_repeat1,
-    STATE(246), 1,
-      sym_at_attribute,
-    STATE(352), 1,
+    STATE(408), 1,
       sym_static_annotation,
     STATE(74), 2,
       sym_block,
       sym_if_stmt,
-  [3707] = 11,
+    STATE(192), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [3814] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(121), 1,
-      anon_sym_AT,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(402), 1,
+      sym_comment,
+    ACTIONS(510), 9,
+      ts_builtin_sym_end,
+      anon_sym_import,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_comb,
+      anon_sym_bundle,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [3829] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(512), 9,
+      anon_sym_component,
+      anon_sym_comb,
+      anon_sym_primitive,
+      anon_sym_group,
+      anon_sym_invoke,
+      anon_sym_seq,
+      anon_sym_par,
+      anon_sym_if,
+      anon_sym_repeat,
+  [3844] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(482), 9,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
       anon_sym_RBRACE,
-    STATE(86), 1,
-      aux_sym_group_repeat1,
-    STATE(102), 1,
+      anon_sym_comb,
+      anon_sym_bundle,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [3859] = 5,
+    ACTIONS(3), 1,
       sym_comment,
-    STATE(192), 1,
+    ACTIONS(514), 1,
+      anon_sym_RPAREN,
+    ACTIONS(365), 2,
+      anon_sym_LT,
+      anon_sym_GT,
+    ACTIONS(424), 2,
+      anon_sym_AMP,
+      anon_sym_PIPE,
+    ACTIONS(363), 4,
+      anon_sym_EQ_EQ,
+      anon_sym_BANG_EQ,
+      anon_sym_LT_EQ,
+      anon_sym_GT_EQ,
+  [3880] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(494), 1,
+      anon_sym_SLASH,
+    ACTIONS(492), 8,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+      anon_sym_DOT_DOT,
+  [3897] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(516), 1,
+      anon_sym_RBRACE,
+    ACTIONS(518), 1,
+      anon_sym_flip,
+    ACTIONS(520), 1,
+      anon_sym_AT,
+    ACTIONS(522), 1,
+      sym_ident,
+    STATE(138), 2,
+      sym_bundle_field,
+      aux_sym_bundle_repeat1,
+    STATE(218), 2,
       sym_at_attribute,
-    STATE(241), 1,
-      sym_wire_assignment,
-    STATE(447), 1,
-      sym_lhs,
-    STATE(334), 2,
-      sym_hole,
-      sym_port,
-  [3742] = 11,
+      aux_sym_io_port_repeat1,
+  [3921] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(121), 1,
+      sym_comment,
+    ACTIONS(526), 3,
+      anon_sym_for,
+      anon_sym_ref,
+      sym_ident,
+    ACTIONS(524), 5,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
       anon_sym_AT,
-    ACTIONS(179), 1,
+  [3937] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(530), 3,
+      anon_sym_for,
+      anon_sym_ref,
       sym_ident,
-    ACTIONS(410), 1,
+    ACTIONS(528), 5,
+      anon_sym_SEMI,
       anon_sym_RBRACE,
-    STATE(102), 1,
-      aux_sym_group_repeat1,
-    STATE(103), 1,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_AT,
+  [3953] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-    STATE(192), 1,
-      sym_at_attribute,
-    STATE(241), 1,
-      sym_wire_assignment,
-    STATE(447), 1,
-      sym_lhs,
-    STATE(334), 2,
-      sym_hole,
-      sym_port,
-  [3777] = 9,
+    ACTIONS(532), 8,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_comb,
+      anon_sym_bundle,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [3967] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
-      anon_sym_LPAREN,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
-    STATE(53), 1,
-      sym_expr,
-    STATE(104), 1,
       sym_comment,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
-      sym_hole,
-      sym_port,
-      sym_literal,
-  [3808] = 9,
+    ACTIONS(534), 8,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_comb,
+      anon_sym_bundle,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [3981] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
-      anon_sym_LPAREN,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
-    STATE(105), 1,
       sym_comment,
-    STATE(124), 1,
-      sym_expr,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
-      sym_hole,
-      sym_port,
-      sym_literal,
-  [3839] = 9,
+    ACTIONS(13), 1,
+      anon_sym_comb,
+    ACTIONS(17), 1,
+      anon_sym_primitive,
+    ACTIONS(21), 1,
+      anon_sym_static,
+    ACTIONS(536), 1,
+      anon_sym_RBRACE,
+    STATE(264), 1,
+      sym_static_annotation,
+    STATE(457), 1,
+      sym_comb_or_static,
+    STATE(139), 2,
+      sym_primitive,
+      aux_sym_extern_repeat1,
+  [4007] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(538), 8,
+      ts_builtin_sym_end,
+      aux_sym_metadata_ci_token1,
+      anon_sym_component,
+      anon_sym_comb,
+      anon_sym_bundle,
+      anon_sym_primitive,
+      anon_sym_extern,
+      anon_sym_static,
+  [4021] = 7,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
-      anon_sym_LPAREN,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
-    STATE(106), 1,
       sym_comment,
-    STATE(127), 1,
-      sym_expr,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
-      sym_hole,
-      sym_port,
-      sym_literal,
-  [3870] = 11,
+    ACTIONS(540), 1,
+      anon_sym_RBRACE,
+    ACTIONS(542), 1,
+      anon_sym_flip,
+    ACTIONS(545), 1,
+      anon_sym_AT,
+    ACTIONS(548), 1,
+      sym_ident,
+    STATE(137), 2,
+      sym_bundle_field,
+      aux_sym_bundle_repeat1,
+    STATE(218), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [4045] = 7,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(121), 1,
+      sym_comment,
+    ACTIONS(518), 1,
+      anon_sym_flip,
+    ACTIONS(520), 1,
       anon_sym_AT,
-    ACTIONS(179), 1,
+    ACTIONS(522), 1,
       sym_ident,
-    ACTIONS(418), 1,
+    ACTIONS(551), 1,
       anon_sym_RBRACE,
-    STATE(86), 1,
-      aux_sym_group_repeat1,
-    STATE(107), 1,
-      sym_comment,
-    STATE(192), 1,
+    STATE(137), 2,
+      sym_bundle_field,
+      aux_sym_bundle_repeat1,
+    STATE(218), 2,
       sym_at_attribute,
-    STATE(241), 1,
-      sym_wire_assignment,
-    STATE(447), 1,
-      sym_lhs,
-    STATE(334), 2,
-      sym_hole,
-      sym_port,
-  [3905] = 9,
+      aux_sym_io_port_repeat1,
+  [4069] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(175), 1,
-      anon_sym_LPAREN,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
-    STATE(49), 1,
-      sym_expr,
-    STATE(108), 1,
       sym_comment,
-    STATE(68), 2,
-      sym_base_expr,
-      sym_cmp_expr,
-    STATE(67), 3,
-      sym_hole,
-      sym_port,
-      sym_literal,
-  [3936] = 11,
+    ACTIONS(553), 1,
+      anon_sym_RBRACE,
+    ACTIONS(555), 1,
+      anon_sym_comb,
+    ACTIONS(558), 1,
+      anon_sym_primitive,
+    ACTIONS(561), 1,
+      anon_sym_static,
+    STATE(264), 1,
+      sym_static_annotation,
+    STATE(457), 1,
+      sym_comb_or_static,
+    STATE(139), 2,
+      sym_primitive,
+      aux_sym_extern_repeat1,
+  [4095] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(15), 1,
+      sym_comment,
+    ACTIONS(13), 1,
       anon_sym_comb,
     ACTIONS(17), 1,
       anon_sym_primitive,
     ACTIONS(21), 1,
       anon_sym_static,
-    ACTIONS(420), 1,
+    ACTIONS(564), 1,
       anon_sym_RBRACE,
-    STATE(109), 1,
-      sym_comment,
-    STATE(118), 1,
-      aux_sym_extern_repeat1,
-    STATE(198), 1,
-      sym_primitive,
-    STATE(204), 1,
+    STATE(264), 1,
       sym_static_annotation,
-    STATE(396), 1,
+    STATE(457), 1,
       sym_comb_or_static,
-  [3970] = 4,
+    STATE(135), 2,
+      sym_primitive,
+      aux_sym_extern_repeat1,
+  [4121] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(566), 1,
+      anon_sym_LPAREN,
+    ACTIONS(570), 1,
+      anon_sym_SLASH,
+    STATE(309), 1,
+      sym_arg_list,
+    ACTIONS(568), 5,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+      anon_sym_STAR,
+  [4141] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(518), 1,
+      anon_sym_flip,
+    ACTIONS(520), 1,
+      anon_sym_AT,
+    ACTIONS(522), 1,
+      sym_ident,
+    ACTIONS(551), 1,
+      anon_sym_RBRACE,
+    STATE(145), 2,
+      sym_bundle_field,
+      aux_sym_bundle_repeat1,
+    STATE(218), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [4165] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(110), 1,
       sym_comment,
-    ACTIONS(422), 8,
+    ACTIONS(572), 8,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
-      anon_sym_RBRACE,
       anon_sym_comb,
+      anon_sym_bundle,
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [3990] = 11,
+  [4179] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(424), 1,
-      anon_sym_RBRACE,
-    ACTIONS(426), 1,
+      sym_comment,
+    ACTIONS(576), 3,
+      anon_sym_for,
       anon_sym_ref,
-    ACTIONS(428), 1,
+      sym_ident,
+    ACTIONS(574), 5,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
       anon_sym_AT,
-    ACTIONS(430), 1,
+  [4195] = 7,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(518), 1,
+      anon_sym_flip,
+    ACTIONS(520), 1,
+      anon_sym_AT,
+    ACTIONS(522), 1,
       sym_ident,
-    STATE(111), 1,
+    ACTIONS(578), 1,
+      anon_sym_RBRACE,
+    STATE(137), 2,
+      sym_bundle_field,
+      aux_sym_bundle_repeat1,
+    STATE(218), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [4219] = 7,
+    ACTIONS(3), 1,
       sym_comment,
-    STATE(146), 1,
-      aux_sym_cells_inner_repeat1,
-    STATE(182), 1,
-      sym_cell_assignment,
-    STATE(321), 1,
+    ACTIONS(518), 1,
+      anon_sym_flip,
+    ACTIONS(520), 1,
+      anon_sym_AT,
+    ACTIONS(522), 1,
+      sym_ident,
+    ACTIONS(578), 1,
+      anon_sym_RBRACE,
+    STATE(151), 2,
+      sym_bundle_field,
+      aux_sym_bundle_repeat1,
+    STATE(218), 2,
       sym_at_attribute,
-    STATE(338), 1,
-      sym_cells_inner,
-  [4024] = 4,
+      aux_sym_io_port_repeat1,
+  [4243] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(112), 1,
       sym_comment,
-    ACTIONS(432), 8,
+    ACTIONS(580), 8,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
-      anon_sym_RBRACE,
       anon_sym_comb,
+      anon_sym_bundle,
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4044] = 4,
+  [4257] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(113), 1,
       sym_comment,
-    ACTIONS(434), 8,
+    ACTIONS(582), 8,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
-      anon_sym_RBRACE,
       anon_sym_comb,
+      anon_sym_bundle,
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4064] = 4,
+  [4271] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(114), 1,
       sym_comment,
-    ACTIONS(436), 8,
+    ACTIONS(584), 8,
       ts_builtin_sym_end,
-      anon_sym_import,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
       anon_sym_comb,
+      anon_sym_bundle,
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4084] = 4,
+  [4285] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(115), 1,
       sym_comment,
-    ACTIONS(438), 8,
+    ACTIONS(586), 8,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
-      anon_sym_RBRACE,
       anon_sym_comb,
+      anon_sym_bundle,
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4104] = 10,
+  [4299] = 7,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(440), 1,
-      anon_sym_RBRACE,
-    ACTIONS(442), 1,
-      anon_sym_comb,
-    ACTIONS(445), 1,
-      anon_sym_primitive,
-    ACTIONS(448), 1,
-      anon_sym_static,
-    STATE(198), 1,
-      sym_primitive,
-    STATE(204), 1,
-      sym_static_annotation,
-    STATE(396), 1,
-      sym_comb_or_static,
-    STATE(116), 2,
       sym_comment,
-      aux_sym_extern_repeat1,
-  [4136] = 4,
+    ACTIONS(518), 1,
+      anon_sym_flip,
+    ACTIONS(520), 1,
+      anon_sym_AT,
+    ACTIONS(522), 1,
+      sym_ident,
+    ACTIONS(588), 1,
+      anon_sym_RBRACE,
+    STATE(137), 2,
+      sym_bundle_field,
+      aux_sym_bundle_repeat1,
+    STATE(218), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [4323] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(117), 1,
       sym_comment,
-    ACTIONS(451), 8,
+    ACTIONS(590), 8,
       ts_builtin_sym_end,
-      anon_sym_import,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
       anon_sym_comb,
+      anon_sym_bundle,
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4156] = 11,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(15), 1,
-      anon_sym_comb,
-    ACTIONS(17), 1,
-      anon_sym_primitive,
-    ACTIONS(21), 1,
-      anon_sym_static,
-    ACTIONS(453), 1,
-      anon_sym_RBRACE,
-    STATE(116), 1,
-      aux_sym_extern_repeat1,
-    STATE(118), 1,
-      sym_comment,
-    STATE(198), 1,
-      sym_primitive,
-    STATE(204), 1,
-      sym_static_annotation,
-    STATE(396), 1,
-      sym_comb_or_static,
-  [4190] = 4,
+  [4337] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(119), 1,
       sym_comment,
-    ACTIONS(455), 8,
+    ACTIONS(592), 8,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
-      anon_sym_RBRACE,
       anon_sym_comb,
+      anon_sym_bundle,
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4210] = 4,
+  [4351] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(596), 3,
+      anon_sym_for,
+      anon_sym_ref,
+      sym_ident,
+    ACTIONS(594), 5,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_AT,
+  [4367] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(120), 1,
       sym_comment,
-    ACTIONS(457), 7,
+    ACTIONS(598), 8,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
       anon_sym_comb,
+      anon_sym_bundle,
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4229] = 10,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(121), 1,
-      anon_sym_AT,
-    ACTIONS(459), 1,
-      anon_sym_RPAREN,
-    ACTIONS(461), 1,
-      sym_ident,
-    STATE(121), 1,
-      sym_comment,
-    STATE(135), 1,
-      aux_sym_io_port_list_repeat1,
-    STATE(187), 1,
-      aux_sym_io_port_repeat1,
-    STATE(276), 1,
-      sym_io_port,
-    STATE(277), 1,
-      sym_at_attribute,
-  [4260] = 4,
+  [4381] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(122), 1,
       sym_comment,
-    ACTIONS(463), 7,
+    ACTIONS(600), 8,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
       anon_sym_comb,
+      anon_sym_bundle,
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4279] = 4,
+  [4395] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(123), 1,
       sym_comment,
-    ACTIONS(465), 7,
+    ACTIONS(602), 8,
       ts_builtin_sym_end,
       aux_sym_metadata_ci_token1,
       anon_sym_component,
       anon_sym_comb,
+      anon_sym_bundle,
       anon_sym_primitive,
       anon_sym_extern,
       anon_sym_static,
-  [4298] = 10,
+  [4409] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(263), 1,
-      anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
-      anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
-      anon_sym_LT_EQ,
-    ACTIONS(273), 1,
-      anon_sym_GT_EQ,
-    ACTIONS(275), 1,
-      anon_sym_GT,
-    ACTIONS(371), 1,
+      sym_comment,
+    ACTIONS(604), 1,
+      anon_sym_LPAREN,
+    ACTIONS(606), 1,
+      anon_sym_LBRACK,
+    ACTIONS(608), 1,
       anon_sym_LT,
-    ACTIONS(467), 1,
-      anon_sym_QMARK,
-    STATE(124), 1,
+    STATE(198), 1,
+      sym_attributes,
+    STATE(265), 1,
+      sym_signature,
+    STATE(266), 1,
+      sym_params,
+    STATE(479), 1,
+      sym_io_port_list,
+  [4434] = 8,
+    ACTIONS(3), 1,
       sym_comment,
-  [4329] = 4,
+    ACTIONS(610), 1,
+      anon_sym_RBRACE,
+    ACTIONS(612), 1,
+      anon_sym_cells,
+    ACTIONS(614), 1,
+      anon_sym_wires,
+    ACTIONS(616), 1,
+      anon_sym_control,
+    STATE(207), 1,
+      sym_cells,
+    STATE(271), 1,
+      sym_wires,
+    STATE(497), 1,
+      sym_control,
+  [4459] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(125), 1,
       sym_comment,
-    ACTIONS(469), 7,
-      anon_sym_component,
-      anon_sym_comb,
-      anon_sym_primitive,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-  [4348] = 7,
+    ACTIONS(618), 1,
+      anon_sym_LPAREN,
+    ACTIONS(620), 1,
+      anon_sym_RPAREN,
+    STATE(191), 1,
+      aux_sym_arg_list_repeat1,
+    ACTIONS(622), 2,
+      sym_ident,
+      sym_number,
+    STATE(190), 2,
+      sym__width,
+      sym_width_expr,
+  [4480] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(471), 1,
-      anon_sym_LT,
-    ACTIONS(475), 1,
-      anon_sym_repeat,
-    STATE(126), 1,
       sym_comment,
-    STATE(132), 1,
-      sym_latency_annotation,
-    ACTIONS(473), 4,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-  [4373] = 10,
+    ACTIONS(122), 1,
+      anon_sym_AT,
+    ACTIONS(160), 1,
+      sym_ident,
+    STATE(409), 1,
+      sym_lhs,
+    STATE(248), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(474), 2,
+      sym_hole,
+      sym_port,
+  [4501] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(263), 1,
-      anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
-      anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
-      anon_sym_LT_EQ,
-    ACTIONS(273), 1,
-      anon_sym_GT_EQ,
-    ACTIONS(275), 1,
-      anon_sym_GT,
-    ACTIONS(371), 1,
+      sym_comment,
+    ACTIONS(604), 1,
+      anon_sym_LPAREN,
+    ACTIONS(606), 1,
+      anon_sym_LBRACK,
+    ACTIONS(608), 1,
       anon_sym_LT,
-    ACTIONS(477), 1,
-      anon_sym_QMARK,
-    STATE(127), 1,
+    STATE(197), 1,
+      sym_attributes,
+    STATE(262), 1,
+      sym_params,
+    STATE(426), 1,
+      sym_signature,
+    STATE(479), 1,
+      sym_io_port_list,
+  [4526] = 8,
+    ACTIONS(3), 1,
       sym_comment,
-  [4404] = 7,
+    ACTIONS(612), 1,
+      anon_sym_cells,
+    ACTIONS(614), 1,
+      anon_sym_wires,
+    ACTIONS(616), 1,
+      anon_sym_control,
+    ACTIONS(624), 1,
+      anon_sym_RBRACE,
+    STATE(199), 1,
+      sym_cells,
+    STATE(280), 1,
+      sym_wires,
+    STATE(490), 1,
+      sym_control,
+  [4551] = 7,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(471), 1,
-      anon_sym_LT,
-    ACTIONS(479), 1,
-      anon_sym_repeat,
-    STATE(128), 1,
       sym_comment,
-    STATE(132), 1,
-      sym_latency_annotation,
-    ACTIONS(473), 4,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-  [4429] = 10,
+    ACTIONS(122), 1,
+      anon_sym_AT,
+    ACTIONS(626), 1,
+      anon_sym_RPAREN,
+    ACTIONS(628), 1,
+      sym_ident,
+    STATE(169), 1,
+      aux_sym_io_port_list_repeat1,
+    STATE(312), 1,
+      sym_io_port,
+    STATE(238), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [4574] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(481), 1,
-      anon_sym_RBRACE,
-    ACTIONS(483), 1,
+      sym_comment,
+    ACTIONS(612), 1,
       anon_sym_cells,
-    ACTIONS(485), 1,
+    ACTIONS(614), 1,
       anon_sym_wires,
-    ACTIONS(487), 1,
+    ACTIONS(616), 1,
       anon_sym_control,
-    STATE(129), 1,
-      sym_comment,
-    STATE(153), 1,
+    ACTIONS(630), 1,
+      anon_sym_RBRACE,
+    STATE(209), 1,
       sym_cells,
-    STATE(232), 1,
+    STATE(256), 1,
       sym_wires,
-    STATE(379), 1,
+    STATE(480), 1,
       sym_control,
-  [4460] = 4,
+  [4599] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(130), 1,
       sym_comment,
-    ACTIONS(489), 7,
-      ts_builtin_sym_end,
-      aux_sym_metadata_ci_token1,
-      anon_sym_component,
-      anon_sym_comb,
-      anon_sym_primitive,
-      anon_sym_extern,
-      anon_sym_static,
-  [4479] = 10,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(491), 1,
+    ACTIONS(604), 1,
       anon_sym_LPAREN,
-    ACTIONS(493), 1,
+    ACTIONS(606), 1,
       anon_sym_LBRACK,
-    ACTIONS(495), 1,
+    ACTIONS(608), 1,
       anon_sym_LT,
-    STATE(131), 1,
-      sym_comment,
-    STATE(155), 1,
+    STATE(234), 1,
       sym_attributes,
-    STATE(249), 1,
+    STATE(304), 1,
       sym_params,
-    STATE(250), 1,
+    STATE(477), 1,
       sym_signature,
-    STATE(412), 1,
+    STATE(479), 1,
       sym_io_port_list,
-  [4510] = 4,
+  [4624] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(132), 1,
       sym_comment,
-    ACTIONS(497), 7,
-      anon_sym_component,
-      anon_sym_comb,
-      anon_sym_primitive,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-  [4529] = 10,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(483), 1,
-      anon_sym_cells,
-    ACTIONS(485), 1,
-      anon_sym_wires,
-    ACTIONS(487), 1,
-      anon_sym_control,
-    ACTIONS(499), 1,
+    ACTIONS(632), 2,
       anon_sym_RBRACE,
-    STATE(133), 1,
-      sym_comment,
-    STATE(165), 1,
-      sym_cells,
-    STATE(224), 1,
-      sym_wires,
-    STATE(353), 1,
-      sym_control,
-  [4560] = 4,
+      anon_sym_AT,
+    ACTIONS(634), 5,
+      anon_sym_comb,
+      anon_sym_for,
+      anon_sym_group,
+      anon_sym_static,
+      sym_ident,
+  [4639] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(134), 1,
       sym_comment,
-    ACTIONS(501), 7,
-      ts_builtin_sym_end,
-      aux_sym_metadata_ci_token1,
-      anon_sym_component,
+    ACTIONS(636), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(638), 5,
       anon_sym_comb,
-      anon_sym_primitive,
-      anon_sym_extern,
+      anon_sym_for,
+      anon_sym_group,
       anon_sym_static,
-  [4579] = 10,
+      sym_ident,
+  [4654] = 7,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(121), 1,
+      sym_comment,
+    ACTIONS(640), 1,
+      anon_sym_RPAREN,
+    ACTIONS(642), 1,
       anon_sym_AT,
-    ACTIONS(461), 1,
+    ACTIONS(645), 1,
       sym_ident,
-    ACTIONS(503), 1,
-      anon_sym_RPAREN,
-    STATE(135), 1,
-      sym_comment,
-    STATE(138), 1,
+    STATE(169), 1,
       aux_sym_io_port_list_repeat1,
-    STATE(187), 1,
-      aux_sym_io_port_repeat1,
-    STATE(277), 1,
-      sym_at_attribute,
-    STATE(286), 1,
+    STATE(363), 1,
       sym_io_port,
-  [4610] = 7,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(471), 1,
-      anon_sym_LT,
-    ACTIONS(505), 1,
-      anon_sym_repeat,
-    STATE(132), 1,
-      sym_latency_annotation,
-    STATE(136), 1,
-      sym_comment,
-    ACTIONS(473), 4,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-  [4635] = 4,
+    STATE(238), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [4677] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(137), 1,
       sym_comment,
-    ACTIONS(507), 7,
-      ts_builtin_sym_end,
-      aux_sym_metadata_ci_token1,
-      anon_sym_component,
+    ACTIONS(648), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(650), 5,
       anon_sym_comb,
-      anon_sym_primitive,
-      anon_sym_extern,
+      anon_sym_for,
+      anon_sym_group,
       anon_sym_static,
-  [4654] = 9,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(509), 1,
-      anon_sym_RPAREN,
-    ACTIONS(511), 1,
-      anon_sym_AT,
-    ACTIONS(514), 1,
       sym_ident,
-    STATE(187), 1,
-      aux_sym_io_port_repeat1,
-    STATE(277), 1,
-      sym_at_attribute,
-    STATE(355), 1,
-      sym_io_port,
-    STATE(138), 2,
+  [4692] = 8,
+    ACTIONS(3), 1,
       sym_comment,
-      aux_sym_io_port_list_repeat1,
-  [4683] = 10,
+    ACTIONS(604), 1,
+      anon_sym_LPAREN,
+    ACTIONS(606), 1,
+      anon_sym_LBRACK,
+    ACTIONS(608), 1,
+      anon_sym_LT,
+    STATE(195), 1,
+      sym_attributes,
+    STATE(257), 1,
+      sym_signature,
+    STATE(258), 1,
+      sym_params,
+    STATE(479), 1,
+      sym_io_port_list,
+  [4717] = 8,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(483), 1,
+      sym_comment,
+    ACTIONS(612), 1,
       anon_sym_cells,
-    ACTIONS(485), 1,
+    ACTIONS(614), 1,
       anon_sym_wires,
-    ACTIONS(487), 1,
+    ACTIONS(616), 1,
       anon_sym_control,
-    ACTIONS(517), 1,
+    ACTIONS(652), 1,
       anon_sym_RBRACE,
-    STATE(139), 1,
-      sym_comment,
-    STATE(170), 1,
+    STATE(225), 1,
       sym_cells,
-    STATE(208), 1,
+    STATE(294), 1,
       sym_wires,
-    STATE(360), 1,
+    STATE(454), 1,
       sym_control,
-  [4714] = 4,
+  [4742] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(140), 1,
       sym_comment,
-    ACTIONS(519), 7,
-      ts_builtin_sym_end,
-      aux_sym_metadata_ci_token1,
-      anon_sym_component,
+    ACTIONS(654), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(656), 5,
       anon_sym_comb,
-      anon_sym_primitive,
-      anon_sym_extern,
+      anon_sym_for,
+      anon_sym_group,
       anon_sym_static,
-  [4733] = 9,
+      sym_ident,
+  [4757] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(521), 1,
+      sym_comment,
+    ACTIONS(658), 2,
       anon_sym_RBRACE,
-    ACTIONS(523), 1,
-      anon_sym_ref,
-    ACTIONS(526), 1,
       anon_sym_AT,
-    ACTIONS(529), 1,
+    ACTIONS(660), 5,
+      anon_sym_comb,
+      anon_sym_for,
+      anon_sym_group,
+      anon_sym_static,
       sym_ident,
-    STATE(182), 1,
-      sym_cell_assignment,
-    STATE(321), 1,
-      sym_at_attribute,
-    STATE(141), 2,
-      sym_comment,
-      aux_sym_cells_inner_repeat1,
-  [4762] = 4,
+  [4772] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(142), 1,
       sym_comment,
-    ACTIONS(532), 7,
-      ts_builtin_sym_end,
-      aux_sym_metadata_ci_token1,
-      anon_sym_component,
+    ACTIONS(662), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(664), 5,
       anon_sym_comb,
-      anon_sym_primitive,
-      anon_sym_extern,
+      anon_sym_for,
+      anon_sym_group,
       anon_sym_static,
-  [4781] = 10,
+      sym_ident,
+  [4787] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(263), 1,
-      anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
-      anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
-      anon_sym_LT_EQ,
-    ACTIONS(273), 1,
-      anon_sym_GT_EQ,
-    ACTIONS(275), 1,
-      anon_sym_GT,
-    ACTIONS(371), 1,
-      anon_sym_LT,
-    ACTIONS(477), 1,
-      anon_sym_QMARK,
-    STATE(143), 1,
       sym_comment,
-  [4812] = 4,
+    ACTIONS(666), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(668), 5,
+      anon_sym_comb,
+      anon_sym_for,
+      anon_sym_group,
+      anon_sym_static,
+      sym_ident,
+  [4802] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(144), 1,
       sym_comment,
-    ACTIONS(534), 7,
-      ts_builtin_sym_end,
-      aux_sym_metadata_ci_token1,
-      anon_sym_component,
+    ACTIONS(670), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(672), 5,
       anon_sym_comb,
-      anon_sym_primitive,
-      anon_sym_extern,
+      anon_sym_for,
+      anon_sym_group,
       anon_sym_static,
-  [4831] = 10,
+      sym_ident,
+  [4817] = 7,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(263), 1,
-      anon_sym_EQ_EQ,
-    ACTIONS(265), 1,
-      anon_sym_BANG_EQ,
-    ACTIONS(271), 1,
-      anon_sym_LT_EQ,
-    ACTIONS(273), 1,
-      anon_sym_GT_EQ,
-    ACTIONS(275), 1,
-      anon_sym_GT,
-    ACTIONS(371), 1,
-      anon_sym_LT,
-    ACTIONS(536), 1,
-      anon_sym_RPAREN,
-    STATE(145), 1,
       sym_comment,
-  [4862] = 10,
+    ACTIONS(122), 1,
+      anon_sym_AT,
+    ACTIONS(628), 1,
+      sym_ident,
+    ACTIONS(674), 1,
+      anon_sym_RPAREN,
+    STATE(164), 1,
+      aux_sym_io_port_list_repeat1,
+    STATE(319), 1,
+      sym_io_port,
+    STATE(238), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [4840] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(426), 1,
-      anon_sym_ref,
-    ACTIONS(428), 1,
+      sym_comment,
+    ACTIONS(122), 1,
       anon_sym_AT,
-    ACTIONS(430), 1,
+    ACTIONS(160), 1,
       sym_ident,
-    ACTIONS(538), 1,
-      anon_sym_RBRACE,
-    STATE(141), 1,
-      aux_sym_cells_inner_repeat1,
-    STATE(146), 1,
+    STATE(496), 1,
+      sym_lhs,
+    STATE(248), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+    STATE(474), 2,
+      sym_hole,
+      sym_port,
+  [4861] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(618), 1,
+      anon_sym_LPAREN,
+    ACTIONS(676), 1,
+      sym_ident,
+    ACTIONS(678), 1,
+      sym_number,
+    STATE(353), 1,
+      sym_bundle_type,
+    STATE(189), 2,
+      sym__width,
+      sym_width_expr,
+  [4881] = 6,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(496), 1,
+      anon_sym_STAR,
+    ACTIONS(498), 1,
+      anon_sym_SLASH,
+    ACTIONS(680), 1,
+      anon_sym_COMMA,
+    ACTIONS(682), 1,
+      anon_sym_RPAREN,
+    ACTIONS(684), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [4901] = 5,
+    ACTIONS(3), 1,
       sym_comment,
+    ACTIONS(686), 1,
+      anon_sym_LPAREN,
     STATE(182), 1,
-      sym_cell_assignment,
-    STATE(321), 1,
-      sym_at_attribute,
-  [4893] = 10,
+      aux_sym_arg_list_repeat1,
+    ACTIONS(689), 2,
+      sym_ident,
+      sym_number,
+    STATE(200), 2,
+      sym__width,
+      sym_width_expr,
+  [4919] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(692), 1,
+      anon_sym_SEMI,
+    ACTIONS(694), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(696), 3,
+      anon_sym_for,
+      anon_sym_ref,
+      sym_ident,
+  [4935] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(491), 1,
+      sym_comment,
+    ACTIONS(618), 1,
       anon_sym_LPAREN,
-    ACTIONS(493), 1,
-      anon_sym_LBRACK,
-    ACTIONS(495), 1,
-      anon_sym_LT,
-    STATE(147), 1,
+    ACTIONS(676), 1,
+      sym_ident,
+    ACTIONS(698), 1,
+      sym_number,
+    STATE(339), 1,
+      sym_bundle_type,
+    STATE(193), 2,
+      sym__width,
+      sym_width_expr,
+  [4955] = 4,
+    ACTIONS(3), 1,
       sym_comment,
-    STATE(159), 1,
-      sym_attributes,
-    STATE(237), 1,
-      sym_params,
-    STATE(238), 1,
-      sym_signature,
-    STATE(412), 1,
-      sym_io_port_list,
-  [4924] = 7,
+    ACTIONS(700), 1,
+      anon_sym_SEMI,
+    ACTIONS(702), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(704), 3,
+      anon_sym_for,
+      anon_sym_ref,
+      sym_ident,
+  [4971] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(706), 3,
+      anon_sym_SEMI,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(708), 3,
+      anon_sym_for,
+      anon_sym_ref,
+      sym_ident,
+  [4985] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(160), 1,
+      sym_ident,
+    ACTIONS(162), 1,
+      sym_number,
+    STATE(390), 1,
+      sym_base_expr,
+    STATE(84), 3,
+      sym_hole,
+      sym_port,
+      sym_literal,
+  [5003] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(710), 1,
+      anon_sym_SEMI,
+    ACTIONS(712), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(714), 3,
+      anon_sym_for,
+      anon_sym_ref,
+      sym_ident,
+  [5019] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(471), 1,
-      anon_sym_LT,
-    ACTIONS(540), 1,
-      anon_sym_repeat,
-    STATE(132), 1,
-      sym_latency_annotation,
-    STATE(148), 1,
       sym_comment,
-    ACTIONS(473), 4,
-      anon_sym_invoke,
-      anon_sym_seq,
-      anon_sym_par,
-      anon_sym_if,
-  [4949] = 9,
+    ACTIONS(496), 1,
+      anon_sym_STAR,
+    ACTIONS(498), 1,
+      anon_sym_SLASH,
+    ACTIONS(684), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(716), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [5037] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(21), 1,
-      anon_sym_static,
-    ACTIONS(408), 1,
-      anon_sym_AT,
-    ACTIONS(542), 1,
-      anon_sym_if,
-    STATE(149), 1,
       sym_comment,
-    STATE(167), 1,
-      aux_sym_io_port_repeat1,
-    STATE(246), 1,
-      sym_at_attribute,
-    STATE(442), 1,
-      sym_static_annotation,
-  [4977] = 6,
+    ACTIONS(496), 1,
+      anon_sym_STAR,
+    ACTIONS(498), 1,
+      anon_sym_SLASH,
+    ACTIONS(718), 1,
+      anon_sym_COMMA,
+    ACTIONS(720), 1,
+      anon_sym_RPAREN,
+    ACTIONS(684), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [5057] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(471), 1,
-      anon_sym_LT,
-    STATE(132), 1,
-      sym_latency_annotation,
-    STATE(150), 1,
       sym_comment,
-    ACTIONS(473), 4,
-      anon_sym_component,
-      anon_sym_comb,
-      anon_sym_primitive,
-      anon_sym_if,
-  [4999] = 9,
+    ACTIONS(618), 1,
+      anon_sym_LPAREN,
+    STATE(182), 1,
+      aux_sym_arg_list_repeat1,
+    ACTIONS(722), 2,
+      sym_ident,
+      sym_number,
+    STATE(181), 2,
+      sym__width,
+      sym_width_expr,
+  [5075] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
+      sym_comment,
     ACTIONS(21), 1,
       anon_sym_static,
-    ACTIONS(408), 1,
+    ACTIONS(504), 1,
       anon_sym_AT,
-    ACTIONS(544), 1,
+    ACTIONS(724), 1,
       anon_sym_if,
-    STATE(151), 1,
-      sym_comment,
-    STATE(167), 1,
-      aux_sym_io_port_repeat1,
-    STATE(246), 1,
-      sym_at_attribute,
-    STATE(377), 1,
+    STATE(434), 1,
       sym_static_annotation,
-  [5027] = 7,
+    STATE(230), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [5095] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(179), 1,
-      sym_ident,
-    ACTIONS(181), 1,
-      sym_number,
-    STATE(152), 1,
       sym_comment,
-    STATE(340), 1,
-      sym_base_expr,
-    STATE(67), 3,
-      sym_hole,
-      sym_port,
-      sym_literal,
-  [5051] = 8,
+    ACTIONS(496), 1,
+      anon_sym_STAR,
+    ACTIONS(498), 1,
+      anon_sym_SLASH,
+    ACTIONS(684), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+    ACTIONS(726), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [5113] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(485), 1,
-      anon_sym_wires,
-    ACTIONS(487), 1,
-      anon_sym_control,
-    ACTIONS(499), 1,
-      anon_sym_RBRACE,
-    STATE(153), 1,
       sym_comment,
-    STATE(224), 1,
-      sym_wires,
-    STATE(353), 1,
-      sym_control,
-  [5076] = 5,
+    ACTIONS(496), 1,
+      anon_sym_STAR,
+    ACTIONS(498), 1,
+      anon_sym_SLASH,
+    ACTIONS(728), 1,
+      anon_sym_RPAREN,
+    ACTIONS(684), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [5130] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(154), 1,
       sym_comment,
-    ACTIONS(546), 2,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(548), 3,
-      anon_sym_comb,
-      anon_sym_group,
-      sym_ident,
-  [5095] = 8,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(491), 1,
+    ACTIONS(604), 1,
       anon_sym_LPAREN,
-    ACTIONS(493), 1,
+    ACTIONS(606), 1,
       anon_sym_LBRACK,
-    STATE(155), 1,
-      sym_comment,
-    STATE(237), 1,
-      sym_params,
-    STATE(238), 1,
+    STATE(265), 1,
       sym_signature,
-    STATE(412), 1,
+    STATE(266), 1,
+      sym_params,
+    STATE(479), 1,
       sym_io_port_list,
-  [5120] = 5,
+  [5149] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(156), 1,
       sym_comment,
-    ACTIONS(550), 2,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(552), 3,
-      anon_sym_comb,
-      anon_sym_group,
-      sym_ident,
-  [5139] = 5,
+    ACTIONS(606), 1,
+      anon_sym_LBRACK,
+    ACTIONS(608), 1,
+      anon_sym_LT,
+    ACTIONS(730), 1,
+      anon_sym_LBRACE,
+    STATE(288), 1,
+      sym_attributes,
+    STATE(446), 1,
+      sym_params,
+  [5168] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(157), 1,
       sym_comment,
-    ACTIONS(556), 2,
-      anon_sym_ref,
-      sym_ident,
-    ACTIONS(554), 3,
-      anon_sym_SEMI,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-  [5158] = 8,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(491), 1,
+    ACTIONS(604), 1,
       anon_sym_LPAREN,
-    ACTIONS(495), 1,
-      anon_sym_LT,
-    STATE(158), 1,
-      sym_comment,
-    STATE(251), 1,
-      sym_attributes,
-    STATE(412), 1,
-      sym_io_port_list,
-    STATE(416), 1,
+    ACTIONS(606), 1,
+      anon_sym_LBRACK,
+    STATE(286), 1,
+      sym_params,
+    STATE(366), 1,
       sym_signature,
-  [5183] = 8,
+    STATE(479), 1,
+      sym_io_port_list,
+  [5187] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(491), 1,
+      sym_comment,
+    ACTIONS(604), 1,
       anon_sym_LPAREN,
-    ACTIONS(493), 1,
+    ACTIONS(606), 1,
       anon_sym_LBRACK,
-    STATE(159), 1,
-      sym_comment,
-    STATE(227), 1,
-      sym_params,
-    STATE(228), 1,
+    STATE(282), 1,
       sym_signature,
-    STATE(412), 1,
+    STATE(287), 1,
+      sym_params,
+    STATE(479), 1,
       sym_io_port_list,
-  [5208] = 5,
+  [5206] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(160), 1,
       sym_comment,
-    ACTIONS(558), 2,
+    ACTIONS(614), 1,
+      anon_sym_wires,
+    ACTIONS(616), 1,
+      anon_sym_control,
+    ACTIONS(732), 1,
       anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(560), 3,
-      anon_sym_comb,
-      anon_sym_group,
-      sym_ident,
-  [5227] = 5,
+    STATE(272), 1,
+      sym_wires,
+    STATE(369), 1,
+      sym_control,
+  [5225] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(161), 1,
       sym_comment,
-    ACTIONS(562), 2,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(564), 3,
-      anon_sym_comb,
-      anon_sym_group,
-      sym_ident,
-  [5246] = 6,
+    ACTIONS(496), 1,
+      anon_sym_STAR,
+    ACTIONS(498), 1,
+      anon_sym_SLASH,
+    ACTIONS(734), 1,
+      anon_sym_COMMA,
+    ACTIONS(684), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [5242] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(496), 1,
+      anon_sym_STAR,
+    ACTIONS(498), 1,
+      anon_sym_SLASH,
+    ACTIONS(736), 1,
+      anon_sym_DOT_DOT,
+    ACTIONS(684), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [5259] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(566), 1,
-      anon_sym_SEMI,
-    STATE(162), 1,
       sym_comment,
-    ACTIONS(568), 2,
+    ACTIONS(738), 2,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(570), 2,
+    ACTIONS(740), 3,
+      anon_sym_for,
       anon_sym_ref,
       sym_ident,
-  [5267] = 5,
+  [5272] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(618), 1,
+      anon_sym_LPAREN,
+    ACTIONS(742), 2,
+      sym_ident,
+      sym_number,
+    STATE(117), 2,
+      sym__width,
+      sym_width_expr,
+  [5287] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(163), 1,
       sym_comment,
-    ACTIONS(572), 2,
+    ACTIONS(702), 2,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(574), 3,
-      anon_sym_comb,
-      anon_sym_group,
+    ACTIONS(704), 3,
+      anon_sym_for,
+      anon_sym_ref,
       sym_ident,
-  [5286] = 5,
+  [5300] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(164), 1,
       sym_comment,
-    ACTIONS(578), 2,
-      anon_sym_ref,
+    ACTIONS(724), 1,
+      anon_sym_if,
+    ACTIONS(744), 1,
+      anon_sym_invoke,
+    ACTIONS(746), 1,
+      anon_sym_seq,
+    ACTIONS(748), 1,
+      anon_sym_par,
+    ACTIONS(750), 1,
+      anon_sym_repeat,
+  [5319] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(618), 1,
+      anon_sym_LPAREN,
+    ACTIONS(752), 2,
       sym_ident,
-    ACTIONS(576), 3,
-      anon_sym_SEMI,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-  [5305] = 8,
+      sym_number,
+    STATE(115), 2,
+      sym__width,
+      sym_width_expr,
+  [5334] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(485), 1,
+      sym_comment,
+    ACTIONS(614), 1,
       anon_sym_wires,
-    ACTIONS(487), 1,
+    ACTIONS(616), 1,
       anon_sym_control,
-    ACTIONS(517), 1,
+    ACTIONS(624), 1,
       anon_sym_RBRACE,
-    STATE(165), 1,
-      sym_comment,
-    STATE(208), 1,
+    STATE(280), 1,
       sym_wires,
-    STATE(360), 1,
+    STATE(490), 1,
       sym_control,
-  [5330] = 8,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(491), 1,
-      anon_sym_LPAREN,
-    ACTIONS(495), 1,
-      anon_sym_LT,
-    STATE(166), 1,
-      sym_comment,
-    STATE(236), 1,
-      sym_attributes,
-    STATE(401), 1,
-      sym_signature,
-    STATE(412), 1,
-      sym_io_port_list,
-  [5355] = 6,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(580), 1,
-      anon_sym_AT,
-    STATE(246), 1,
-      sym_at_attribute,
-    ACTIONS(168), 2,
-      anon_sym_if,
-      anon_sym_static,
-    STATE(167), 2,
-      sym_comment,
-      aux_sym_io_port_repeat1,
-  [5376] = 5,
+  [5353] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(113), 1,
-      anon_sym_DOT,
-    STATE(168), 1,
       sym_comment,
-    ACTIONS(107), 4,
-      anon_sym_LBRACE,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
-      anon_sym_with,
-  [5395] = 6,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(583), 1,
+    ACTIONS(496), 1,
+      anon_sym_STAR,
+    ACTIONS(498), 1,
+      anon_sym_SLASH,
+    ACTIONS(754), 1,
       anon_sym_SEMI,
-    STATE(169), 1,
-      sym_comment,
-    ACTIONS(585), 2,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(587), 2,
-      anon_sym_ref,
-      sym_ident,
-  [5416] = 8,
+    ACTIONS(684), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [5370] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(485), 1,
+      sym_comment,
+    ACTIONS(614), 1,
       anon_sym_wires,
-    ACTIONS(487), 1,
+    ACTIONS(616), 1,
       anon_sym_control,
-    ACTIONS(589), 1,
+    ACTIONS(652), 1,
       anon_sym_RBRACE,
-    STATE(170), 1,
-      sym_comment,
-    STATE(212), 1,
+    STATE(294), 1,
       sym_wires,
-    STATE(405), 1,
+    STATE(454), 1,
       sym_control,
-  [5441] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(171), 1,
-      sym_comment,
-    ACTIONS(591), 2,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(593), 3,
-      anon_sym_comb,
-      anon_sym_group,
-      sym_ident,
-  [5460] = 6,
+  [5389] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(595), 1,
-      anon_sym_SEMI,
-    STATE(172), 1,
       sym_comment,
-    ACTIONS(597), 2,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(599), 2,
-      anon_sym_ref,
+    ACTIONS(618), 1,
+      anon_sym_LPAREN,
+    ACTIONS(756), 2,
       sym_ident,
-  [5481] = 5,
+      sym_number,
+    STATE(216), 2,
+      sym__width,
+      sym_width_expr,
+  [5404] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(173), 1,
       sym_comment,
-    ACTIONS(601), 2,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-    ACTIONS(603), 3,
-      anon_sym_comb,
-      anon_sym_group,
+    ACTIONS(618), 1,
+      anon_sym_LPAREN,
+    ACTIONS(758), 2,
       sym_ident,
-  [5500] = 5,
+      sym_number,
+    STATE(229), 2,
+      sym__width,
+      sym_width_expr,
+  [5419] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(174), 1,
       sym_comment,
-    ACTIONS(607), 2,
-      anon_sym_ref,
+    ACTIONS(618), 1,
+      anon_sym_LPAREN,
+    ACTIONS(760), 2,
       sym_ident,
-    ACTIONS(605), 3,
-      anon_sym_SEMI,
-      anon_sym_RBRACE,
-      anon_sym_AT,
-  [5519] = 6,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(181), 1,
       sym_number,
-    ACTIONS(609), 1,
-      sym_ident,
-    STATE(175), 1,
+    STATE(221), 2,
+      sym__width,
+      sym_width_expr,
+  [5434] = 6,
+    ACTIONS(3), 1,
       sym_comment,
-    STATE(304), 2,
-      sym_port,
-      sym_literal,
-  [5539] = 5,
+    ACTIONS(762), 1,
+      anon_sym_invoke,
+    ACTIONS(764), 1,
+      anon_sym_seq,
+    ACTIONS(766), 1,
+      anon_sym_par,
+    ACTIONS(768), 1,
+      anon_sym_if,
+    ACTIONS(770), 1,
+      anon_sym_repeat,
+  [5453] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(176), 1,
       sym_comment,
-    ACTIONS(568), 2,
+    ACTIONS(712), 2,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(570), 2,
+    ACTIONS(714), 3,
+      anon_sym_for,
       anon_sym_ref,
       sym_ident,
-  [5557] = 7,
+  [5466] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
-    ACTIONS(613), 1,
-      anon_sym_LBRACK,
-    STATE(177), 1,
       sym_comment,
-    STATE(294), 1,
-      sym_invoke_args,
-    STATE(303), 1,
-      sym_invoke_ref_args,
-  [5579] = 7,
+    ACTIONS(618), 1,
+      anon_sym_LPAREN,
+    ACTIONS(772), 2,
+      sym_ident,
+      sym_number,
+    STATE(201), 2,
+      sym__width,
+      sym_width_expr,
+  [5481] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(615), 1,
-      anon_sym_GT,
-    ACTIONS(617), 1,
-      sym_string,
-    STATE(178), 1,
       sym_comment,
-    STATE(190), 1,
-      aux_sym_attributes_repeat1,
-    STATE(280), 1,
-      sym_attribute,
-  [5601] = 7,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
-    ACTIONS(613), 1,
-      anon_sym_LBRACK,
-    STATE(179), 1,
+    ACTIONS(496), 1,
+      anon_sym_STAR,
+    ACTIONS(498), 1,
+      anon_sym_SLASH,
+    ACTIONS(774), 1,
+      anon_sym_SEMI,
+    ACTIONS(684), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [5498] = 5,
+    ACTIONS(776), 1,
       sym_comment,
-    STATE(315), 1,
-      sym_invoke_ref_args,
-    STATE(318), 1,
-      sym_invoke_args,
-  [5623] = 7,
+    ACTIONS(778), 1,
+      anon_sym_LBRACE,
+    ACTIONS(780), 1,
+      anon_sym_RBRACE,
+    ACTIONS(782), 1,
+      sym__blob_text,
+    STATE(233), 2,
+      sym_primitive_blob,
+      aux_sym_primitive_blob_repeat1,
+  [5515] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(619), 1,
-      anon_sym_RPAREN,
-    ACTIONS(621), 1,
+      sym_comment,
+    ACTIONS(520), 1,
+      anon_sym_AT,
+    ACTIONS(784), 1,
+      anon_sym_flip,
+    ACTIONS(786), 1,
       sym_ident,
-    STATE(180), 1,
+    STATE(224), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [5532] = 5,
+    ACTIONS(776), 1,
       sym_comment,
-    STATE(211), 1,
-      aux_sym_invoke_args_repeat1,
-    STATE(288), 1,
-      sym_invoke_arg,
-  [5645] = 6,
+    ACTIONS(778), 1,
+      anon_sym_LBRACE,
+    ACTIONS(788), 1,
+      anon_sym_RBRACE,
+    ACTIONS(790), 1,
+      sym__blob_text,
+    STATE(217), 2,
+      sym_primitive_blob,
+      aux_sym_primitive_blob_repeat1,
+  [5549] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(168), 1,
+      sym_comment,
+    ACTIONS(618), 1,
+      anon_sym_LPAREN,
+    ACTIONS(792), 2,
       sym_ident,
-    ACTIONS(623), 1,
-      anon_sym_AT,
-    STATE(277), 1,
-      sym_at_attribute,
-    STATE(181), 2,
+      sym_number,
+    STATE(194), 2,
+      sym__width,
+      sym_width_expr,
+  [5564] = 5,
+    ACTIONS(3), 1,
       sym_comment,
-      aux_sym_io_port_repeat1,
-  [5665] = 5,
+    ACTIONS(496), 1,
+      anon_sym_STAR,
+    ACTIONS(498), 1,
+      anon_sym_SLASH,
+    ACTIONS(794), 1,
+      anon_sym_LBRACE,
+    ACTIONS(684), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [5581] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(182), 1,
       sym_comment,
-    ACTIONS(626), 2,
+    ACTIONS(796), 2,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(628), 2,
+    ACTIONS(798), 3,
+      anon_sym_for,
       anon_sym_ref,
       sym_ident,
-  [5683] = 7,
+  [5594] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
-    ACTIONS(613), 1,
-      anon_sym_LBRACK,
-    STATE(183), 1,
       sym_comment,
-    STATE(270), 1,
-      sym_invoke_args,
-    STATE(271), 1,
-      sym_invoke_ref_args,
-  [5705] = 7,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(630), 1,
-      anon_sym_RBRACK,
-    ACTIONS(632), 1,
+    ACTIONS(378), 1,
+      anon_sym_AT,
+    ACTIONS(800), 1,
+      anon_sym_ref,
+    ACTIONS(802), 1,
       sym_ident,
-    STATE(184), 1,
-      sym_comment,
-    STATE(210), 1,
-      aux_sym_invoke_ref_args_repeat1,
-    STATE(292), 1,
-      sym_invoke_ref_arg,
-  [5727] = 7,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(542), 1,
-      anon_sym_if,
-    ACTIONS(634), 1,
-      anon_sym_invoke,
-    ACTIONS(636), 1,
-      anon_sym_seq,
-    ACTIONS(638), 1,
-      anon_sym_par,
-    STATE(185), 1,
-      sym_comment,
-  [5749] = 6,
+    STATE(227), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [5611] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(640), 1,
-      anon_sym_GT,
-    ACTIONS(642), 1,
-      sym_string,
-    STATE(354), 1,
-      sym_attribute,
-    STATE(186), 2,
       sym_comment,
-      aux_sym_attributes_repeat1,
-  [5769] = 7,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(121), 1,
+    ACTIONS(804), 1,
       anon_sym_AT,
-    ACTIONS(645), 1,
+    ACTIONS(356), 2,
+      anon_sym_flip,
       sym_ident,
-    STATE(181), 1,
-      aux_sym_io_port_repeat1,
-    STATE(187), 1,
-      sym_comment,
-    STATE(277), 1,
+    STATE(224), 2,
       sym_at_attribute,
-  [5791] = 6,
+      aux_sym_io_port_repeat1,
+  [5626] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(21), 1,
-      anon_sym_static,
-    STATE(188), 1,
       sym_comment,
-    STATE(262), 1,
-      sym_static_annotation,
-    ACTIONS(647), 2,
-      anon_sym_component,
-      anon_sym_primitive,
-  [5811] = 7,
+    ACTIONS(610), 1,
+      anon_sym_RBRACE,
+    ACTIONS(614), 1,
+      anon_sym_wires,
+    ACTIONS(616), 1,
+      anon_sym_control,
+    STATE(271), 1,
+      sym_wires,
+    STATE(497), 1,
+      sym_control,
+  [5645] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(544), 1,
-      anon_sym_if,
-    ACTIONS(634), 1,
-      anon_sym_invoke,
-    ACTIONS(636), 1,
-      anon_sym_seq,
-    ACTIONS(638), 1,
-      anon_sym_par,
-    STATE(189), 1,
       sym_comment,
-  [5833] = 7,
+    ACTIONS(194), 1,
+      anon_sym_DOT,
+    ACTIONS(188), 4,
+      anon_sym_LBRACE,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+      anon_sym_with,
+  [5658] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(617), 1,
-      sym_string,
-    ACTIONS(649), 1,
-      anon_sym_GT,
-    STATE(186), 1,
-      aux_sym_attributes_repeat1,
-    STATE(190), 1,
       sym_comment,
-    STATE(291), 1,
-      sym_attribute,
-  [5855] = 4,
+    ACTIONS(807), 1,
+      anon_sym_AT,
+    ACTIONS(356), 2,
+      anon_sym_ref,
+      sym_ident,
+    STATE(227), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [5673] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(191), 1,
       sym_comment,
-    ACTIONS(651), 4,
-      anon_sym_d,
-      anon_sym_b,
-      anon_sym_x,
-      anon_sym_o,
-  [5871] = 6,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(179), 1,
+    ACTIONS(618), 1,
+      anon_sym_LPAREN,
+    ACTIONS(810), 2,
       sym_ident,
-    STATE(192), 1,
+      sym_number,
+    STATE(208), 2,
+      sym__width,
+      sym_width_expr,
+  [5688] = 5,
+    ACTIONS(3), 1,
       sym_comment,
-    STATE(450), 1,
-      sym_lhs,
-    STATE(334), 2,
-      sym_hole,
-      sym_port,
-  [5891] = 5,
+    ACTIONS(496), 1,
+      anon_sym_STAR,
+    ACTIONS(498), 1,
+      anon_sym_SLASH,
+    ACTIONS(812), 1,
+      anon_sym_SEMI,
+    ACTIONS(684), 2,
+      anon_sym_PLUS,
+      anon_sym_DASH,
+  [5705] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(653), 1,
-      anon_sym_LPAREN,
-    STATE(193), 1,
       sym_comment,
-    ACTIONS(229), 3,
+    ACTIONS(814), 1,
+      anon_sym_AT,
+    ACTIONS(354), 2,
       anon_sym_if,
       anon_sym_static,
-      anon_sym_AT,
-  [5909] = 7,
+    STATE(230), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [5720] = 5,
+    ACTIONS(776), 1,
+      sym_comment,
+    ACTIONS(778), 1,
+      anon_sym_LBRACE,
+    ACTIONS(817), 1,
+      anon_sym_RBRACE,
+    ACTIONS(819), 1,
+      sym__blob_text,
+    STATE(232), 2,
+      sym_primitive_blob,
+      aux_sym_primitive_blob_repeat1,
+  [5737] = 5,
+    ACTIONS(776), 1,
+      sym_comment,
+    ACTIONS(778), 1,
+      anon_sym_LBRACE,
+    ACTIONS(782), 1,
+      sym__blob_text,
+    ACTIONS(821), 1,
+      anon_sym_RBRACE,
+    STATE(233), 2,
+      sym_primitive_blob,
+      aux_sym_primitive_blob_repeat1,
+  [5754] = 5,
+    ACTIONS(776), 1,
+      sym_comment,
+    ACTIONS(823), 1,
+      anon_sym_LBRACE,
+    ACTIONS(826), 1,
+      anon_sym_RBRACE,
+    ACTIONS(828), 1,
+      sym__blob_text,
+    STATE(233), 2,
+      sym_primitive_blob,
+      aux_sym_primitive_blob_repeat1,
+  [5771] = 6,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(655), 1,
-      anon_sym_invoke,
-    ACTIONS(657), 1,
-      anon_sym_seq,
-    ACTIONS(659), 1,
-      anon_sym_par,
-    ACTIONS(661), 1,
-      anon_sym_if,
-    STATE(194), 1,
       sym_comment,
-  [5931] = 5,
+    ACTIONS(604), 1,
+      anon_sym_LPAREN,
+    ACTIONS(606), 1,
+      anon_sym_LBRACK,
+    STATE(262), 1,
+      sym_params,
+    STATE(426), 1,
+      sym_signature,
+    STATE(479), 1,
+      sym_io_port_list,
+  [5790] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(195), 1,
       sym_comment,
-    ACTIONS(585), 2,
+    ACTIONS(831), 2,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(587), 2,
+    ACTIONS(833), 3,
+      anon_sym_for,
       anon_sym_ref,
       sym_ident,
-  [5949] = 7,
+  [5803] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(655), 1,
-      anon_sym_invoke,
-    ACTIONS(657), 1,
-      anon_sym_seq,
-    ACTIONS(659), 1,
-      anon_sym_par,
-    ACTIONS(663), 1,
-      anon_sym_if,
-    STATE(196), 1,
       sym_comment,
-  [5971] = 6,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(179), 1,
+    ACTIONS(682), 1,
+      anon_sym_RPAREN,
+    ACTIONS(835), 3,
+      anon_sym_LPAREN,
       sym_ident,
-    STATE(197), 1,
-      sym_comment,
-    STATE(374), 1,
-      sym_lhs,
-    STATE(334), 2,
-      sym_hole,
-      sym_port,
-  [5991] = 4,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(198), 1,
-      sym_comment,
-    ACTIONS(665), 4,
-      anon_sym_RBRACE,
-      anon_sym_comb,
-      anon_sym_primitive,
-      anon_sym_static,
-  [6007] = 5,
+      sym_number,
+  [5815] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(199), 1,
       sym_comment,
-    ACTIONS(667), 2,
+    ACTIONS(837), 2,
       anon_sym_RBRACE,
       anon_sym_AT,
-    ACTIONS(669), 2,
-      anon_sym_ref,
+    ACTIONS(839), 2,
+      anon_sym_flip,
       sym_ident,
-  [6025] = 5,
+  [5827] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(671), 1,
-      anon_sym_LPAREN,
-    STATE(200), 1,
       sym_comment,
-    ACTIONS(229), 2,
+    ACTIONS(122), 1,
       anon_sym_AT,
+    ACTIONS(841), 1,
       sym_ident,
-  [6042] = 5,
+    STATE(248), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [5841] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(673), 1,
-      anon_sym_COMMA,
-    STATE(201), 1,
       sym_comment,
-    ACTIONS(675), 2,
-      anon_sym_RPAREN,
-      sym_number,
-  [6059] = 6,
+    ACTIONS(843), 1,
+      anon_sym_GT,
+    ACTIONS(845), 1,
+      sym_string,
+    STATE(244), 1,
+      aux_sym_attributes_repeat1,
+    STATE(318), 1,
+      sym_attribute,
+  [5857] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(609), 1,
-      sym_ident,
-    STATE(202), 1,
       sym_comment,
-    STATE(297), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6078] = 6,
+    ACTIONS(847), 1,
+      anon_sym_LPAREN,
+    ACTIONS(849), 1,
+      anon_sym_LBRACK,
+    STATE(340), 1,
+      sym_invoke_ref_args,
+    STATE(341), 1,
+      sym_invoke_args,
+  [5873] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(495), 1,
-      anon_sym_LT,
-    ACTIONS(677), 1,
-      anon_sym_LBRACE,
-    STATE(203), 1,
       sym_comment,
-    STATE(411), 1,
-      sym_attributes,
-  [6097] = 5,
+    ACTIONS(845), 1,
+      sym_string,
+    ACTIONS(851), 1,
+      anon_sym_GT,
+    STATE(239), 1,
+      aux_sym_attributes_repeat1,
+    STATE(361), 1,
+      sym_attribute,
+  [5889] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(679), 1,
-      anon_sym_comb,
-    STATE(204), 1,
       sym_comment,
-    ACTIONS(647), 2,
-      anon_sym_component,
-      anon_sym_primitive,
-  [6114] = 6,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(609), 1,
+    ACTIONS(414), 1,
+      anon_sym_AT,
+    ACTIONS(853), 1,
+      anon_sym_LPAREN,
+    ACTIONS(418), 2,
+      anon_sym_flip,
       sym_ident,
-    STATE(205), 1,
-      sym_comment,
-    STATE(265), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6133] = 6,
+  [5903] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(609), 1,
-      sym_ident,
-    STATE(206), 1,
       sym_comment,
-    STATE(263), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6152] = 6,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(609), 1,
+    ACTIONS(855), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(857), 2,
+      anon_sym_flip,
       sym_ident,
-    STATE(207), 1,
-      sym_comment,
-    STATE(296), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6171] = 6,
+  [5915] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(487), 1,
-      anon_sym_control,
-    ACTIONS(589), 1,
-      anon_sym_RBRACE,
-    STATE(208), 1,
       sym_comment,
-    STATE(405), 1,
-      sym_control,
-  [6190] = 4,
+    ACTIONS(859), 1,
+      anon_sym_GT,
+    ACTIONS(861), 1,
+      sym_string,
+    STATE(244), 1,
+      aux_sym_attributes_repeat1,
+    STATE(440), 1,
+      sym_attribute,
+  [5931] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(209), 1,
       sym_comment,
-    ACTIONS(681), 3,
-      anon_sym_RBRACE,
-      anon_sym_wires,
-      anon_sym_control,
-  [6205] = 6,
+    ACTIONS(21), 1,
+      anon_sym_static,
+    STATE(356), 1,
+      sym_static_annotation,
+    ACTIONS(864), 2,
+      anon_sym_component,
+      anon_sym_primitive,
+  [5945] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(632), 1,
-      sym_ident,
-    STATE(210), 1,
       sym_comment,
-    STATE(223), 1,
-      aux_sym_invoke_ref_args_repeat1,
-    STATE(319), 1,
-      sym_invoke_ref_arg,
-  [6224] = 6,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(621), 1,
+    ACTIONS(414), 1,
+      anon_sym_AT,
+    ACTIONS(866), 1,
+      anon_sym_LPAREN,
+    ACTIONS(418), 2,
+      anon_sym_ref,
       sym_ident,
-    STATE(211), 1,
-      sym_comment,
-    STATE(221), 1,
-      aux_sym_invoke_args_repeat1,
-    STATE(322), 1,
-      sym_invoke_arg,
-  [6243] = 6,
+  [5959] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(487), 1,
-      anon_sym_control,
-    ACTIONS(683), 1,
-      anon_sym_RBRACE,
-    STATE(212), 1,
       sym_comment,
-    STATE(453), 1,
-      sym_control,
-  [6262] = 4,
+    ACTIONS(868), 2,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+    ACTIONS(870), 2,
+      anon_sym_flip,
+      sym_ident,
+  [5971] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(213), 1,
       sym_comment,
-    ACTIONS(685), 3,
-      anon_sym_SEMI,
-      anon_sym_LPAREN,
-      anon_sym_with,
-  [6277] = 6,
+    ACTIONS(354), 1,
+      sym_ident,
+    ACTIONS(872), 1,
+      anon_sym_AT,
+    STATE(248), 2,
+      sym_at_attribute,
+      aux_sym_io_port_repeat1,
+  [5985] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(687), 1,
-      anon_sym_RPAREN,
-    ACTIONS(689), 1,
-      sym_number,
-    STATE(214), 1,
       sym_comment,
-    STATE(230), 1,
-      aux_sym_arg_list_repeat1,
-  [6296] = 6,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(609), 1,
+    ACTIONS(875), 1,
+      anon_sym_RPAREN,
+    ACTIONS(835), 3,
+      anon_sym_LPAREN,
       sym_ident,
-    STATE(215), 1,
-      sym_comment,
-    STATE(272), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6315] = 4,
+      sym_number,
+  [5997] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(216), 1,
       sym_comment,
-    ACTIONS(390), 3,
+    ACTIONS(877), 1,
+      anon_sym_LPAREN,
+    ACTIONS(414), 3,
       anon_sym_if,
-      anon_sym_static,
       anon_sym_AT,
-  [6330] = 6,
+      anon_sym_static,
+  [6009] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(691), 1,
-      anon_sym_SEMI,
-    ACTIONS(693), 1,
-      anon_sym_LBRACE,
-    STATE(217), 1,
       sym_comment,
-    STATE(361), 1,
-      sym_primitive_blob,
-  [6349] = 6,
+    ACTIONS(162), 1,
+      sym_number,
+    ACTIONS(879), 1,
+      sym_ident,
+    STATE(343), 2,
+      sym_port,
+      sym_literal,
+  [6023] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(495), 1,
-      anon_sym_LT,
-    ACTIONS(695), 1,
-      anon_sym_LBRACE,
-    STATE(218), 1,
       sym_comment,
-    STATE(437), 1,
-      sym_attributes,
-  [6368] = 4,
+    ACTIONS(847), 1,
+      anon_sym_LPAREN,
+    ACTIONS(849), 1,
+      anon_sym_LBRACK,
+    STATE(336), 1,
+      sym_invoke_args,
+    STATE(338), 1,
+      sym_invoke_ref_args,
+  [6039] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(219), 1,
       sym_comment,
-    ACTIONS(697), 3,
-      anon_sym_SEMI,
+    ACTIONS(847), 1,
       anon_sym_LPAREN,
-      anon_sym_with,
-  [6383] = 5,
-    ACTIONS(699), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(701), 1,
-      aux_sym_comment_token2,
-    ACTIONS(703), 1,
-      anon_sym_RBRACE,
-    ACTIONS(705), 1,
-      sym_any_line,
-    STATE(220), 2,
-      sym_comment,
-      aux_sym_primitive_blob_repeat1,
-  [6400] = 5,
+    ACTIONS(849), 1,
+      anon_sym_LBRACK,
+    STATE(324), 1,
+      sym_invoke_args,
+    STATE(326), 1,
+      sym_invoke_ref_args,
+  [6055] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(708), 1,
-      sym_ident,
-    STATE(370), 1,
-      sym_invoke_arg,
-    STATE(221), 2,
       sym_comment,
+    ACTIONS(881), 1,
+      anon_sym_RPAREN,
+    ACTIONS(883), 1,
+      sym_ident,
+    STATE(277), 1,
       aux_sym_invoke_args_repeat1,
-  [6417] = 6,
+    STATE(321), 1,
+      sym_invoke_arg,
+  [6071] = 5,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(609), 1,
-      sym_ident,
-    STATE(222), 1,
       sym_comment,
-    STATE(266), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6436] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(711), 1,
+    ACTIONS(885), 1,
+      anon_sym_RBRACK,
+    ACTIONS(887), 1,
       sym_ident,
-    STATE(373), 1,
-      sym_invoke_ref_arg,
-    STATE(223), 2,
-      sym_comment,
+    STATE(278), 1,
       aux_sym_invoke_ref_args_repeat1,
-  [6453] = 6,
+    STATE(323), 1,
+      sym_invoke_ref_arg,
+  [6087] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(487), 1,
+      sym_comment,
+    ACTIONS(616), 1,
       anon_sym_control,
-    ACTIONS(517), 1,
+    ACTIONS(652), 1,
       anon_sym_RBRACE,
-    STATE(224), 1,
-      sym_comment,
-    STATE(360), 1,
+    STATE(454), 1,
       sym_control,
-  [6472] = 4,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(225), 1,
-      sym_comment,
-    ACTIONS(714), 3,
-      anon_sym_LBRACE,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-  [6487] = 4,
+  [6100] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(226), 1,
       sym_comment,
-    ACTIONS(716), 3,
+    ACTIONS(889), 1,
       anon_sym_SEMI,
-      anon_sym_DASH_GT,
+    ACTIONS(891), 1,
       anon_sym_LBRACE,
-  [6502] = 6,
+    STATE(112), 1,
+      sym_primitive_blob,
+  [6113] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(491), 1,
+      sym_comment,
+    ACTIONS(604), 1,
       anon_sym_LPAREN,
-    STATE(217), 1,
+    STATE(265), 1,
       sym_signature,
-    STATE(227), 1,
-      sym_comment,
-    STATE(412), 1,
+    STATE(479), 1,
       sym_io_port_list,
-  [6521] = 6,
+  [6126] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(693), 1,
-      anon_sym_LBRACE,
-    ACTIONS(718), 1,
-      anon_sym_SEMI,
-    STATE(228), 1,
       sym_comment,
-    STATE(344), 1,
+    ACTIONS(436), 1,
+      anon_sym_SEMI,
+    ACTIONS(891), 1,
+      anon_sym_LBRACE,
+    STATE(105), 1,
       sym_primitive_blob,
-  [6540] = 6,
-    ACTIONS(699), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(701), 1,
-      aux_sym_comment_token2,
-    ACTIONS(720), 1,
-      anon_sym_RBRACE,
-    ACTIONS(722), 1,
-      sym_any_line,
-    STATE(220), 1,
-      aux_sym_primitive_blob_repeat1,
-    STATE(229), 1,
+  [6139] = 4,
+    ACTIONS(3), 1,
       sym_comment,
-  [6559] = 5,
+    ACTIONS(879), 1,
+      sym_ident,
+    STATE(350), 1,
+      sym_port,
+    STATE(352), 1,
+      sym_port_with,
+  [6152] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(724), 1,
-      anon_sym_RPAREN,
-    ACTIONS(726), 1,
-      sym_number,
-    STATE(230), 2,
       sym_comment,
-      aux_sym_arg_list_repeat1,
-  [6576] = 4,
+    ACTIONS(608), 1,
+      anon_sym_LT,
+    ACTIONS(893), 1,
+      anon_sym_LBRACE,
+    STATE(475), 1,
+      sym_attributes,
+  [6165] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(231), 1,
       sym_comment,
-    ACTIONS(729), 3,
-      anon_sym_SEMI,
+    ACTIONS(604), 1,
       anon_sym_LPAREN,
-      anon_sym_with,
-  [6591] = 6,
+    STATE(366), 1,
+      sym_signature,
+    STATE(479), 1,
+      sym_io_port_list,
+  [6178] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(487), 1,
-      anon_sym_control,
-    ACTIONS(499), 1,
-      anon_sym_RBRACE,
-    STATE(232), 1,
       sym_comment,
-    STATE(353), 1,
-      sym_control,
-  [6610] = 4,
+    ACTIONS(879), 1,
+      sym_ident,
+    STATE(350), 1,
+      sym_port,
+    STATE(354), 1,
+      sym_port_with,
+  [6191] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(233), 1,
       sym_comment,
-    ACTIONS(731), 3,
-      anon_sym_LBRACE,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-  [6625] = 4,
+    ACTIONS(895), 1,
+      anon_sym_comb,
+    ACTIONS(864), 2,
+      anon_sym_component,
+      anon_sym_primitive,
+  [6202] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(234), 1,
       sym_comment,
-    ACTIONS(733), 3,
+    ACTIONS(484), 1,
       anon_sym_SEMI,
-      anon_sym_DASH_GT,
+    ACTIONS(891), 1,
       anon_sym_LBRACE,
-  [6640] = 4,
+    STATE(106), 1,
+      sym_primitive_blob,
+  [6215] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(235), 1,
       sym_comment,
-    ACTIONS(509), 3,
-      anon_sym_RPAREN,
-      anon_sym_AT,
-      sym_ident,
-  [6655] = 6,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(491), 1,
+    ACTIONS(604), 1,
       anon_sym_LPAREN,
-    STATE(236), 1,
-      sym_comment,
-    STATE(359), 1,
+    STATE(282), 1,
       sym_signature,
-    STATE(412), 1,
+    STATE(479), 1,
       sym_io_port_list,
-  [6674] = 6,
+  [6228] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(491), 1,
-      anon_sym_LPAREN,
-    STATE(228), 1,
-      sym_signature,
-    STATE(237), 1,
       sym_comment,
-    STATE(412), 1,
-      sym_io_port_list,
-  [6693] = 6,
+    ACTIONS(897), 3,
+      anon_sym_RBRACE,
+      anon_sym_wires,
+      anon_sym_control,
+  [6237] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(693), 1,
-      anon_sym_LBRACE,
-    ACTIONS(735), 1,
-      anon_sym_SEMI,
-    STATE(238), 1,
-      sym_comment,
-    STATE(362), 1,
-      sym_primitive_blob,
-  [6712] = 6,
-    ACTIONS(699), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(701), 1,
-      aux_sym_comment_token2,
-    ACTIONS(722), 1,
-      sym_any_line,
-    ACTIONS(737), 1,
-      anon_sym_RBRACE,
-    STATE(229), 1,
-      aux_sym_primitive_blob_repeat1,
-    STATE(239), 1,
       sym_comment,
-  [6731] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(739), 1,
+    ACTIONS(899), 1,
       anon_sym_LPAREN,
-    STATE(240), 1,
-      sym_comment,
-    ACTIONS(233), 2,
-      anon_sym_ref,
-      sym_ident,
-  [6748] = 4,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(241), 1,
-      sym_comment,
-    ACTIONS(741), 3,
-      anon_sym_RBRACE,
+    ACTIONS(414), 2,
       anon_sym_AT,
       sym_ident,
-  [6763] = 6,
+  [6248] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(609), 1,
-      sym_ident,
-    STATE(242), 1,
       sym_comment,
-    STATE(298), 1,
-      sym_port,
-    STATE(312), 1,
+    ACTIONS(879), 1,
+      sym_ident,
+    STATE(329), 1,
       sym_port_with,
-  [6782] = 4,
+    STATE(350), 1,
+      sym_port,
+  [6261] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(243), 1,
       sym_comment,
-    ACTIONS(591), 3,
-      anon_sym_RBRACE,
+    ACTIONS(640), 3,
+      anon_sym_RPAREN,
       anon_sym_AT,
       sym_ident,
-  [6797] = 4,
+  [6270] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(244), 1,
       sym_comment,
-    ACTIONS(546), 3,
+    ACTIONS(616), 1,
+      anon_sym_control,
+    ACTIONS(624), 1,
       anon_sym_RBRACE,
-      anon_sym_AT,
-      sym_ident,
-  [6812] = 4,
+    STATE(490), 1,
+      sym_control,
+  [6283] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(245), 1,
       sym_comment,
-    ACTIONS(743), 3,
-      anon_sym_LBRACE,
-      anon_sym_LPAREN,
-      anon_sym_LBRACK,
-  [6827] = 4,
+    ACTIONS(616), 1,
+      anon_sym_control,
+    ACTIONS(901), 1,
+      anon_sym_RBRACE,
+    STATE(374), 1,
+      sym_control,
+  [6296] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(246), 1,
       sym_comment,
-    ACTIONS(365), 3,
-      anon_sym_if,
-      anon_sym_static,
-      anon_sym_AT,
-  [6842] = 4,
+    ACTIONS(903), 3,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
+      anon_sym_LBRACE,
+  [6305] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(247), 1,
       sym_comment,
-    ACTIONS(745), 3,
+    ACTIONS(905), 3,
       anon_sym_SEMI,
       anon_sym_DASH_GT,
       anon_sym_LBRACE,
-  [6857] = 6,
+  [6314] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(609), 1,
-      sym_ident,
-    STATE(248), 1,
       sym_comment,
-    STATE(259), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6876] = 6,
+    ACTIONS(907), 3,
+      anon_sym_LBRACE,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+  [6323] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(491), 1,
+      sym_comment,
+    ACTIONS(909), 3,
+      anon_sym_SEMI,
       anon_sym_LPAREN,
-    STATE(238), 1,
-      sym_signature,
-    STATE(249), 1,
+      anon_sym_with,
+  [6332] = 4,
+    ACTIONS(3), 1,
       sym_comment,
-    STATE(412), 1,
-      sym_io_port_list,
-  [6895] = 6,
+    ACTIONS(883), 1,
+      sym_ident,
+    STATE(284), 1,
+      aux_sym_invoke_args_repeat1,
+    STATE(333), 1,
+      sym_invoke_arg,
+  [6345] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(693), 1,
-      anon_sym_LBRACE,
-    ACTIONS(747), 1,
-      anon_sym_SEMI,
-    STATE(250), 1,
       sym_comment,
-    STATE(397), 1,
-      sym_primitive_blob,
-  [6914] = 6,
+    ACTIONS(887), 1,
+      sym_ident,
+    STATE(285), 1,
+      aux_sym_invoke_ref_args_repeat1,
+    STATE(362), 1,
+      sym_invoke_ref_arg,
+  [6358] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(491), 1,
+      sym_comment,
+    ACTIONS(835), 3,
       anon_sym_LPAREN,
-    STATE(251), 1,
+      sym_ident,
+      sym_number,
+  [6367] = 4,
+    ACTIONS(3), 1,
       sym_comment,
-    STATE(401), 1,
-      sym_signature,
-    STATE(412), 1,
-      sym_io_port_list,
-  [6933] = 6,
+    ACTIONS(616), 1,
+      anon_sym_control,
+    ACTIONS(732), 1,
+      anon_sym_RBRACE,
+    STATE(369), 1,
+      sym_control,
+  [6380] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(689), 1,
-      sym_number,
-    ACTIONS(749), 1,
-      anon_sym_RPAREN,
-    STATE(214), 1,
-      aux_sym_arg_list_repeat1,
-    STATE(252), 1,
       sym_comment,
-  [6952] = 4,
+    ACTIONS(608), 1,
+      anon_sym_LT,
+    ACTIONS(911), 1,
+      anon_sym_LBRACE,
+    STATE(403), 1,
+      sym_attributes,
+  [6393] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(253), 1,
       sym_comment,
-    ACTIONS(751), 3,
-      anon_sym_RBRACE,
-      anon_sym_wires,
-      anon_sym_control,
-  [6967] = 6,
+    ACTIONS(460), 1,
+      anon_sym_SEMI,
+    ACTIONS(891), 1,
+      anon_sym_LBRACE,
+    STATE(98), 1,
+      sym_primitive_blob,
+  [6406] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(609), 1,
-      sym_ident,
-    STATE(254), 1,
       sym_comment,
-    STATE(274), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [6986] = 6,
+    ACTIONS(913), 3,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_with,
+  [6415] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(609), 1,
+      sym_comment,
+    ACTIONS(915), 1,
       sym_ident,
-    STATE(255), 1,
+    STATE(284), 1,
+      aux_sym_invoke_args_repeat1,
+    STATE(427), 1,
+      sym_invoke_arg,
+  [6428] = 4,
+    ACTIONS(3), 1,
       sym_comment,
-    STATE(273), 1,
-      sym_port_with,
-    STATE(298), 1,
-      sym_port,
-  [7005] = 4,
+    ACTIONS(918), 1,
+      sym_ident,
+    STATE(285), 1,
+      aux_sym_invoke_ref_args_repeat1,
+    STATE(430), 1,
+      sym_invoke_ref_arg,
+  [6441] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(256), 1,
       sym_comment,
-    ACTIONS(753), 2,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
-  [7019] = 4,
+    ACTIONS(604), 1,
+      anon_sym_LPAREN,
+    STATE(472), 1,
+      sym_signature,
+    STATE(479), 1,
+      sym_io_port_list,
+  [6454] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(257), 1,
       sym_comment,
-    ACTIONS(755), 2,
-      anon_sym_COMMA,
-      anon_sym_GT,
-  [7033] = 5,
+    ACTIONS(604), 1,
+      anon_sym_LPAREN,
+    STATE(259), 1,
+      sym_signature,
+    STATE(479), 1,
+      sym_io_port_list,
+  [6467] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(412), 1,
-      anon_sym_LBRACE,
-    STATE(56), 1,
-      sym_block,
-    STATE(258), 1,
       sym_comment,
-  [7049] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(412), 1,
+    ACTIONS(606), 1,
+      anon_sym_LBRACK,
+    ACTIONS(921), 1,
       anon_sym_LBRACE,
-    STATE(63), 1,
-      sym_block,
-    STATE(259), 1,
-      sym_comment,
-  [7065] = 5,
+    STATE(392), 1,
+      sym_params,
+  [6480] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(757), 1,
-      anon_sym_component,
-    ACTIONS(759), 1,
-      anon_sym_primitive,
-    STATE(260), 1,
       sym_comment,
-  [7081] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(412), 1,
+    ACTIONS(923), 3,
+      anon_sym_SEMI,
+      anon_sym_DASH_GT,
       anon_sym_LBRACE,
-    STATE(66), 1,
-      sym_block,
-    STATE(261), 1,
-      sym_comment,
-  [7097] = 4,
+  [6489] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(262), 1,
       sym_comment,
-    ACTIONS(761), 2,
-      anon_sym_component,
-      anon_sym_primitive,
-  [7111] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(404), 1,
+    ACTIONS(925), 3,
       anon_sym_LBRACE,
-    STATE(44), 1,
-      sym_block,
-    STATE(263), 1,
-      sym_comment,
-  [7127] = 5,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+  [6498] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(763), 1,
-      sym_ident,
-    STATE(162), 1,
-      sym_instantiation,
-    STATE(264), 1,
       sym_comment,
-  [7143] = 5,
+    ACTIONS(927), 3,
+      anon_sym_SEMI,
+      anon_sym_LPAREN,
+      anon_sym_with,
+  [6507] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(404), 1,
-      anon_sym_LBRACE,
-    STATE(265), 1,
       sym_comment,
-    STATE(306), 1,
-      sym_block,
-  [7159] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(412), 1,
+    ACTIONS(929), 3,
       anon_sym_LBRACE,
-    STATE(44), 1,
-      sym_block,
-    STATE(266), 1,
-      sym_comment,
-  [7175] = 5,
+      anon_sym_LPAREN,
+      anon_sym_LBRACK,
+  [6516] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(404), 1,
-      anon_sym_LBRACE,
-    STATE(43), 1,
-      sym_block,
-    STATE(267), 1,
       sym_comment,
-  [7191] = 5,
+    ACTIONS(931), 1,
+      anon_sym_RBRACK,
+    ACTIONS(933), 1,
+      sym_ident,
+    STATE(342), 1,
+      aux_sym_params_repeat1,
+  [6529] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(187), 1,
+      sym_comment,
+    ACTIONS(610), 1,
       anon_sym_RBRACE,
-    ACTIONS(765), 1,
-      anon_sym_else,
-    STATE(268), 1,
+    ACTIONS(616), 1,
+      anon_sym_control,
+    STATE(497), 1,
+      sym_control,
+  [6542] = 2,
+    ACTIONS(776), 1,
       sym_comment,
-  [7207] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(412), 1,
+    ACTIONS(935), 3,
       anon_sym_LBRACE,
-    STATE(43), 1,
-      sym_block,
-    STATE(269), 1,
+      anon_sym_RBRACE,
+      sym__blob_text,
+  [6551] = 2,
+    ACTIONS(776), 1,
       sym_comment,
-  [7223] = 5,
+    ACTIONS(937), 3,
+      anon_sym_LBRACE,
+      anon_sym_RBRACE,
+      sym__blob_text,
+  [6560] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
-    STATE(270), 1,
       sym_comment,
-    STATE(295), 1,
-      sym_invoke_args,
-  [7239] = 5,
+    ACTIONS(486), 1,
+      anon_sym_AT,
+    ACTIONS(488), 2,
+      anon_sym_flip,
+      sym_ident,
+  [6571] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
-    STATE(271), 1,
       sym_comment,
-    STATE(294), 1,
-      sym_invoke_args,
-  [7255] = 5,
+    ACTIONS(648), 3,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+      sym_ident,
+  [6580] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(767), 1,
-      anon_sym_LBRACE,
-    STATE(38), 1,
-      sym_block,
-    STATE(272), 1,
       sym_comment,
-  [7271] = 5,
+    ACTIONS(662), 3,
+      anon_sym_RBRACE,
+      anon_sym_AT,
+      sym_ident,
+  [6589] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(767), 1,
-      anon_sym_LBRACE,
-    STATE(35), 1,
-      sym_block,
-    STATE(273), 1,
       sym_comment,
-  [7287] = 5,
+    ACTIONS(486), 1,
+      anon_sym_AT,
+    ACTIONS(488), 2,
+      anon_sym_ref,
+      sym_ident,
+  [6600] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(767), 1,
-      anon_sym_LBRACE,
-    STATE(32), 1,
-      sym_block,
-    STATE(274), 1,
       sym_comment,
-  [7303] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(769), 1,
+    ACTIONS(879), 1,
       sym_ident,
-    STATE(275), 1,
-      sym_comment,
-    STATE(284), 1,
-      aux_sym_params_repeat1,
-  [7319] = 5,
+    STATE(350), 1,
+      sym_port,
+    STATE(359), 1,
+      sym_port_with,
+  [6613] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(503), 1,
-      anon_sym_RPAREN,
-    ACTIONS(771), 1,
-      anon_sym_COMMA,
-    STATE(276), 1,
       sym_comment,
-  [7335] = 4,
+    ACTIONS(879), 1,
+      sym_ident,
+    STATE(334), 1,
+      sym_port_with,
+    STATE(350), 1,
+      sym_port,
+  [6626] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(277), 1,
       sym_comment,
-    ACTIONS(365), 2,
+    ACTIONS(486), 3,
+      anon_sym_if,
       anon_sym_AT,
-      sym_ident,
-  [7349] = 5,
+      anon_sym_static,
+  [6635] = 4,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(773), 1,
-      anon_sym_LPAREN,
-    STATE(174), 1,
-      sym_arg_list,
-    STATE(278), 1,
       sym_comment,
-  [7365] = 5,
+    ACTIONS(604), 1,
+      anon_sym_LPAREN,
+    STATE(426), 1,
+      sym_signature,
+    STATE(479), 1,
+      sym_io_port_list,
+  [6648] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(763), 1,
-      sym_ident,
-    STATE(169), 1,
-      sym_instantiation,
-    STATE(279), 1,
       sym_comment,
-  [7381] = 5,
+    ACTIONS(939), 3,
+      anon_sym_RBRACE,
+      anon_sym_wires,
+      anon_sym_control,
+  [6657] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(649), 1,
-      anon_sym_GT,
-    ACTIONS(775), 1,
-      anon_sym_COMMA,
-    STATE(280), 1,
       sym_comment,
-  [7397] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(491), 1,
+    ACTIONS(566), 1,
       anon_sym_LPAREN,
-    STATE(281), 1,
+    STATE(186), 1,
+      sym_arg_list,
+  [6667] = 3,
+    ACTIONS(3), 1,
       sym_comment,
-    STATE(300), 1,
+    ACTIONS(604), 1,
+      anon_sym_LPAREN,
+    STATE(327), 1,
       sym_io_port_list,
-  [7413] = 5,
+  [6677] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(777), 1,
-      anon_sym_COMMA,
-    ACTIONS(779), 1,
-      anon_sym_RBRACK,
-    STATE(282), 1,
       sym_comment,
-  [7429] = 4,
+    ACTIONS(941), 1,
+      anon_sym_component,
+    ACTIONS(943), 1,
+      anon_sym_primitive,
+  [6687] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(283), 1,
       sym_comment,
-    ACTIONS(392), 2,
-      anon_sym_ref,
-      sym_ident,
-  [7443] = 5,
+    ACTIONS(945), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [6695] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(781), 1,
-      sym_ident,
-    STATE(284), 1,
       sym_comment,
-    STATE(307), 1,
-      aux_sym_params_repeat1,
-  [7459] = 4,
+    ACTIONS(947), 1,
+      sym_ident,
+    STATE(188), 1,
+      sym_instantiation,
+  [6705] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(285), 1,
       sym_comment,
-    ACTIONS(783), 2,
+    ACTIONS(486), 2,
+      anon_sym_AT,
       sym_ident,
-      sym_number,
-  [7473] = 5,
+  [6713] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(771), 1,
+      sym_comment,
+    ACTIONS(949), 1,
       anon_sym_COMMA,
-    ACTIONS(785), 1,
+    ACTIONS(951), 1,
       anon_sym_RPAREN,
-    STATE(286), 1,
-      sym_comment,
-  [7489] = 5,
+  [6723] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(787), 1,
-      anon_sym_SEMI,
-    ACTIONS(789), 1,
-      anon_sym_with,
-    STATE(287), 1,
       sym_comment,
-  [7505] = 5,
+    ACTIONS(953), 2,
+      anon_sym_LBRACE,
+      anon_sym_LPAREN,
+  [6731] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(791), 1,
+      sym_comment,
+    ACTIONS(955), 1,
       anon_sym_COMMA,
-    ACTIONS(793), 1,
-      anon_sym_RPAREN,
-    STATE(288), 1,
+    ACTIONS(957), 1,
+      anon_sym_RBRACK,
+  [6741] = 3,
+    ACTIONS(3), 1,
       sym_comment,
-  [7521] = 5,
+    ACTIONS(959), 1,
+      sym_ident,
+    STATE(315), 1,
+      aux_sym_params_repeat1,
+  [6751] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(404), 1,
-      anon_sym_LBRACE,
-    STATE(56), 1,
-      sym_block,
-    STATE(289), 1,
       sym_comment,
-  [7537] = 4,
+    ACTIONS(962), 2,
+      anon_sym_RBRACE,
+      anon_sym_control,
+  [6759] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(290), 1,
       sym_comment,
-    ACTIONS(640), 2,
+    ACTIONS(859), 2,
       anon_sym_GT,
       sym_string,
-  [7551] = 5,
+  [6767] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(775), 1,
+      sym_comment,
+    ACTIONS(964), 1,
       anon_sym_COMMA,
-    ACTIONS(795), 1,
+    ACTIONS(966), 1,
       anon_sym_GT,
-    STATE(291), 1,
-      sym_comment,
-  [7567] = 5,
+  [6777] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(797), 1,
-      anon_sym_COMMA,
-    ACTIONS(799), 1,
-      anon_sym_RBRACK,
-    STATE(292), 1,
       sym_comment,
-  [7583] = 5,
+    ACTIONS(626), 1,
+      anon_sym_RPAREN,
+    ACTIONS(949), 1,
+      anon_sym_COMMA,
+  [6787] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
-    STATE(287), 1,
-      sym_invoke_args,
-    STATE(293), 1,
       sym_comment,
-  [7599] = 5,
+    ACTIONS(968), 1,
+      sym_ident,
+    STATE(381), 1,
+      sym_for_range,
+  [6797] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(611), 1,
-      anon_sym_LPAREN,
-    STATE(294), 1,
       sym_comment,
-    STATE(324), 1,
-      sym_invoke_args,
-  [7615] = 5,
+    ACTIONS(970), 1,
+      anon_sym_COMMA,
+    ACTIONS(972), 1,
+      anon_sym_RPAREN,
+  [6807] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(801), 1,
-      anon_sym_SEMI,
-    ACTIONS(803), 1,
-      anon_sym_with,
-    STATE(295), 1,
       sym_comment,
-  [7631] = 5,
+    ACTIONS(974), 1,
+      anon_sym_LBRACK,
+    ACTIONS(976), 1,
+      sym_number,
+  [6817] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(404), 1,
-      anon_sym_LBRACE,
-    STATE(63), 1,
-      sym_block,
-    STATE(296), 1,
       sym_comment,
-  [7647] = 5,
+    ACTIONS(978), 1,
+      anon_sym_COMMA,
+    ACTIONS(980), 1,
+      anon_sym_RBRACK,
+  [6827] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(404), 1,
-      anon_sym_LBRACE,
-    STATE(268), 1,
-      sym_block,
-    STATE(297), 1,
       sym_comment,
-  [7663] = 5,
+    ACTIONS(847), 1,
+      anon_sym_LPAREN,
+    STATE(335), 1,
+      sym_invoke_args,
+  [6837] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(805), 1,
-      anon_sym_LBRACE,
-    ACTIONS(807), 1,
-      anon_sym_with,
-    STATE(298), 1,
       sym_comment,
-  [7679] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(809), 1,
+    ACTIONS(982), 1,
       anon_sym_SEMI,
-    ACTIONS(811), 1,
+    ACTIONS(984), 1,
       anon_sym_with,
-    STATE(299), 1,
-      sym_comment,
-  [7695] = 4,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(300), 1,
-      sym_comment,
-    ACTIONS(813), 2,
-      anon_sym_SEMI,
-      anon_sym_LBRACE,
-  [7709] = 4,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(301), 1,
-      sym_comment,
-    ACTIONS(815), 2,
-      anon_sym_COMMA,
-      anon_sym_RBRACK,
-  [7723] = 4,
+  [6847] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(302), 1,
       sym_comment,
-    ACTIONS(817), 2,
-      anon_sym_RBRACE,
-      anon_sym_control,
-  [7737] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(611), 1,
+    ACTIONS(847), 1,
       anon_sym_LPAREN,
-    STATE(303), 1,
-      sym_comment,
-    STATE(318), 1,
+    STATE(336), 1,
       sym_invoke_args,
-  [7753] = 4,
+  [6857] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(304), 1,
       sym_comment,
-    ACTIONS(819), 2,
-      anon_sym_COMMA,
-      anon_sym_RPAREN,
-  [7767] = 5,
+    ACTIONS(986), 2,
+      anon_sym_SEMI,
+      anon_sym_LBRACE,
+  [6865] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(777), 1,
-      anon_sym_COMMA,
-    ACTIONS(821), 1,
-      anon_sym_RBRACK,
-    STATE(305), 1,
       sym_comment,
-  [7783] = 5,
+    ACTIONS(947), 1,
+      sym_ident,
+    STATE(183), 1,
+      sym_instantiation,
+  [6875] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(207), 1,
-      anon_sym_RBRACE,
-    ACTIONS(823), 1,
-      anon_sym_else,
-    STATE(306), 1,
       sym_comment,
-  [7799] = 4,
+    ACTIONS(988), 1,
+      anon_sym_LBRACE,
+    STATE(47), 1,
+      sym_block,
+  [6885] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(825), 1,
-      sym_ident,
-    STATE(307), 2,
       sym_comment,
-      aux_sym_params_repeat1,
-  [7813] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(404), 1,
+    ACTIONS(500), 1,
       anon_sym_LBRACE,
-    STATE(66), 1,
+    STATE(68), 1,
       sym_block,
-    STATE(308), 1,
-      sym_comment,
-  [7829] = 4,
-    ACTIONS(699), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(701), 1,
-      aux_sym_comment_token2,
-    STATE(309), 1,
+  [6895] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(828), 2,
+    ACTIONS(990), 2,
       anon_sym_RBRACE,
-      sym_any_line,
-  [7843] = 5,
+      anon_sym_control,
+  [6903] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(763), 1,
-      sym_ident,
-    STATE(172), 1,
-      sym_instantiation,
-    STATE(310), 1,
       sym_comment,
-  [7859] = 4,
+    ACTIONS(992), 2,
+      anon_sym_LBRACE,
+      anon_sym_LPAREN,
+  [6911] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(311), 1,
       sym_comment,
-    ACTIONS(724), 2,
+    ACTIONS(970), 1,
+      anon_sym_COMMA,
+    ACTIONS(994), 1,
       anon_sym_RPAREN,
-      sym_number,
-  [7873] = 5,
+  [6921] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(404), 1,
-      anon_sym_LBRACE,
-    STATE(312), 1,
       sym_comment,
-    STATE(314), 1,
+    ACTIONS(500), 1,
+      anon_sym_LBRACE,
+    STATE(60), 1,
       sym_block,
-  [7889] = 4,
+  [6931] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(313), 1,
       sym_comment,
-    ACTIONS(390), 2,
-      anon_sym_AT,
-      sym_ident,
-  [7903] = 5,
+    ACTIONS(996), 1,
+      anon_sym_SEMI,
+    ACTIONS(998), 1,
+      anon_sym_with,
+  [6941] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(217), 1,
-      anon_sym_RBRACE,
-    ACTIONS(830), 1,
-      anon_sym_else,
-    STATE(314), 1,
       sym_comment,
-  [7919] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(611), 1,
+    ACTIONS(847), 1,
       anon_sym_LPAREN,
-    STATE(293), 1,
+    STATE(346), 1,
       sym_invoke_args,
-    STATE(315), 1,
+  [6951] = 3,
+    ACTIONS(3), 1,
       sym_comment,
-  [7935] = 4,
+    ACTIONS(955), 1,
+      anon_sym_COMMA,
+    ACTIONS(1000), 1,
+      anon_sym_RBRACK,
+  [6961] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(316), 1,
       sym_comment,
-    ACTIONS(832), 2,
-      sym_ident,
-      sym_number,
-  [7949] = 4,
+    ACTIONS(847), 1,
+      anon_sym_LPAREN,
+    STATE(347), 1,
+      sym_invoke_args,
+  [6971] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(317), 1,
       sym_comment,
-    ACTIONS(834), 2,
+    ACTIONS(726), 2,
       anon_sym_COMMA,
       anon_sym_RPAREN,
-  [7963] = 5,
+  [6979] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(611), 1,
+      sym_comment,
+    ACTIONS(847), 1,
       anon_sym_LPAREN,
-    STATE(299), 1,
+    STATE(324), 1,
       sym_invoke_args,
-    STATE(318), 1,
-      sym_comment,
-  [7979] = 5,
+  [6989] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(797), 1,
-      anon_sym_COMMA,
-    ACTIONS(836), 1,
-      anon_sym_RBRACK,
-    STATE(319), 1,
       sym_comment,
-  [7995] = 4,
+    ACTIONS(847), 1,
+      anon_sym_LPAREN,
+    STATE(325), 1,
+      sym_invoke_args,
+  [6999] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(320), 1,
       sym_comment,
-    ACTIONS(838), 2,
-      anon_sym_RBRACE,
-      anon_sym_control,
-  [8009] = 5,
-    ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(840), 1,
-      anon_sym_ref,
-    ACTIONS(842), 1,
+    ACTIONS(1002), 1,
       sym_ident,
-    STATE(321), 1,
-      sym_comment,
-  [8025] = 5,
+    STATE(315), 1,
+      aux_sym_params_repeat1,
+  [7009] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(791), 1,
+      sym_comment,
+    ACTIONS(1004), 2,
       anon_sym_COMMA,
-    ACTIONS(844), 1,
       anon_sym_RPAREN,
-    STATE(322), 1,
+  [7017] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [8041] = 4,
+    ACTIONS(1006), 2,
+      anon_sym_COMMA,
+      anon_sym_RBRACK,
+  [7025] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    STATE(323), 1,
       sym_comment,
-    ACTIONS(213), 2,
-      anon_sym_RBRACE,
-      anon_sym_else,
-  [8055] = 5,
+    ACTIONS(1008), 2,
+      anon_sym_LBRACE,
+      anon_sym_LPAREN,
+  [7033] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(846), 1,
+      sym_comment,
+    ACTIONS(1010), 1,
       anon_sym_SEMI,
-    ACTIONS(848), 1,
+    ACTIONS(1012), 1,
       anon_sym_with,
-    STATE(324), 1,
+  [7043] = 3,
+    ACTIONS(3), 1,
       sym_comment,
-  [8071] = 4,
+    ACTIONS(847), 1,
+      anon_sym_LPAREN,
+    STATE(351), 1,
+      sym_invoke_args,
+  [7053] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(850), 1,
-      sym_number,
-    STATE(325), 1,
       sym_comment,
-  [8084] = 4,
+    ACTIONS(1014), 2,
+      anon_sym_COMMA,
+      anon_sym_GT,
+  [7061] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(852), 1,
-      anon_sym_SEMI,
-    STATE(326), 1,
       sym_comment,
-  [8097] = 4,
+    ACTIONS(947), 1,
+      sym_ident,
+    STATE(185), 1,
+      sym_instantiation,
+  [7071] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(125), 1,
-      anon_sym_SEMI,
-    STATE(327), 1,
       sym_comment,
-  [8110] = 4,
+    ACTIONS(1016), 1,
+      anon_sym_LBRACE,
+    ACTIONS(1018), 1,
+      anon_sym_with,
+  [7081] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(854), 1,
-      sym_number,
-    STATE(328), 1,
       sym_comment,
-  [8123] = 4,
+    ACTIONS(1020), 1,
+      anon_sym_SEMI,
+    ACTIONS(1022), 1,
+      anon_sym_with,
+  [7091] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(856), 1,
+      sym_comment,
+    ACTIONS(988), 1,
       anon_sym_LBRACE,
-    STATE(329), 1,
+    STATE(42), 1,
+      sym_block,
+  [7101] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [8136] = 4,
+    ACTIONS(716), 2,
+      anon_sym_COMMA,
+      anon_sym_RPAREN,
+  [7109] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(858), 1,
-      anon_sym_LBRACE,
-    STATE(330), 1,
       sym_comment,
-  [8149] = 4,
+    ACTIONS(500), 1,
+      anon_sym_LBRACE,
+    STATE(51), 1,
+      sym_block,
+  [7119] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(860), 1,
-      sym_ident,
-    STATE(331), 1,
       sym_comment,
-  [8162] = 4,
+    ACTIONS(500), 1,
+      anon_sym_LBRACE,
+    STATE(52), 1,
+      sym_block,
+  [7129] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(862), 1,
-      anon_sym_EQ,
-    STATE(332), 1,
       sym_comment,
-  [8175] = 4,
+    ACTIONS(1024), 2,
+      anon_sym_component,
+      anon_sym_primitive,
+  [7137] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(864), 1,
-      sym_number,
-    STATE(333), 1,
       sym_comment,
-  [8188] = 4,
+    ACTIONS(968), 1,
+      sym_ident,
+    STATE(365), 1,
+      sym_for_range,
+  [7147] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(866), 1,
-      anon_sym_EQ,
-    STATE(334), 1,
       sym_comment,
-  [8201] = 4,
+    ACTIONS(968), 1,
+      sym_ident,
+    STATE(416), 1,
+      sym_for_range,
+  [7157] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(868), 1,
-      anon_sym_RBRACE,
-    STATE(335), 1,
       sym_comment,
-  [8214] = 4,
+    ACTIONS(988), 1,
+      anon_sym_LBRACE,
+    STATE(44), 1,
+      sym_block,
+  [7167] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(870), 1,
-      sym_ident,
-    STATE(336), 1,
       sym_comment,
-  [8227] = 4,
+    ACTIONS(500), 1,
+      anon_sym_LBRACE,
+    STATE(59), 1,
+      sym_block,
+  [7177] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(872), 1,
-      anon_sym_group,
-    STATE(337), 1,
       sym_comment,
-  [8240] = 4,
+    ACTIONS(843), 1,
+      anon_sym_GT,
+    ACTIONS(964), 1,
+      anon_sym_COMMA,
+  [7187] = 3,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(874), 1,
-      anon_sym_RBRACE,
-    STATE(338), 1,
       sym_comment,
-  [8253] = 4,
+    ACTIONS(978), 1,
+      anon_sym_COMMA,
+    ACTIONS(1026), 1,
+      anon_sym_RBRACK,
+  [7197] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(876), 1,
-      anon_sym_EQ,
-    STATE(339), 1,
       sym_comment,
-  [8266] = 4,
+    ACTIONS(949), 1,
+      anon_sym_COMMA,
+  [7204] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(878), 1,
-      anon_sym_SEMI,
-    STATE(340), 1,
       sym_comment,
-  [8279] = 4,
+    ACTIONS(1028), 1,
+      anon_sym_LBRACE,
+  [7211] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(880), 1,
-      anon_sym_LPAREN,
-    STATE(341), 1,
       sym_comment,
-  [8292] = 4,
+    ACTIONS(1030), 1,
+      anon_sym_LBRACE,
+  [7218] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(882), 1,
-      sym_ident,
-    STATE(342), 1,
       sym_comment,
-  [8305] = 4,
+    ACTIONS(1032), 1,
+      anon_sym_LBRACE,
+  [7225] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(884), 1,
-      anon_sym_RPAREN,
-    STATE(343), 1,
       sym_comment,
-  [8318] = 4,
+    ACTIONS(1034), 1,
+      anon_sym_COLON,
+  [7232] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(691), 1,
-      anon_sym_SEMI,
-    STATE(344), 1,
       sym_comment,
-  [8331] = 4,
+    ACTIONS(1036), 1,
+      anon_sym_EQ,
+  [7239] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(886), 1,
-      sym_ident,
-    STATE(345), 1,
       sym_comment,
-  [8344] = 4,
+    ACTIONS(901), 1,
+      anon_sym_RBRACE,
+  [7246] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(888), 1,
-      sym_ident,
-    STATE(346), 1,
       sym_comment,
-  [8357] = 4,
+    ACTIONS(1038), 1,
+      sym_ident,
+  [7253] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(890), 1,
-      anon_sym_LPAREN,
-    STATE(347), 1,
       sym_comment,
-  [8370] = 4,
+    ACTIONS(1040), 1,
+      sym_ident,
+  [7260] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(892), 1,
-      anon_sym_SEMI,
-    STATE(348), 1,
       sym_comment,
-  [8383] = 4,
+    ACTIONS(1042), 1,
+      anon_sym_COLON,
+  [7267] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(777), 1,
-      anon_sym_COMMA,
-    STATE(349), 1,
       sym_comment,
-  [8396] = 4,
+    ACTIONS(1044), 1,
+      anon_sym_LBRACE,
+  [7274] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(894), 1,
-      sym_ident,
-    STATE(350), 1,
       sym_comment,
-  [8409] = 4,
-    ACTIONS(699), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(701), 1,
-      aux_sym_comment_token2,
-    ACTIONS(896), 1,
-      aux_sym_comment_token1,
-    STATE(351), 1,
+    ACTIONS(1046), 1,
+      anon_sym_RBRACE,
+  [7281] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [8422] = 4,
+    ACTIONS(1048), 1,
+      sym_number,
+  [7288] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(544), 1,
-      anon_sym_if,
-    STATE(352), 1,
       sym_comment,
-  [8435] = 4,
+    ACTIONS(1050), 1,
+      aux_sym_literal_token1,
+  [7295] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(517), 1,
-      anon_sym_RBRACE,
-    STATE(353), 1,
       sym_comment,
-  [8448] = 4,
+    ACTIONS(1052), 1,
+      anon_sym_LBRACE,
+  [7302] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(775), 1,
-      anon_sym_COMMA,
-    STATE(354), 1,
       sym_comment,
-  [8461] = 4,
+    ACTIONS(1054), 1,
+      sym_ident,
+  [7309] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(771), 1,
-      anon_sym_COMMA,
-    STATE(355), 1,
       sym_comment,
-  [8474] = 4,
+    ACTIONS(1056), 1,
+      sym_ident,
+  [7316] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(898), 1,
+      sym_comment,
+    ACTIONS(1058), 1,
       sym_ident,
-    STATE(356), 1,
+  [7323] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [8487] = 4,
+    ACTIONS(1060), 1,
+      anon_sym_LBRACE,
+  [7330] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(900), 1,
-      anon_sym_RBRACE,
-    STATE(357), 1,
       sym_comment,
-  [8500] = 4,
+    ACTIONS(1062), 1,
+      aux_sym_metadata_token1,
+  [7337] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(902), 1,
-      anon_sym_LBRACE,
-    STATE(358), 1,
       sym_comment,
-  [8513] = 4,
+    ACTIONS(1064), 1,
+      ts_builtin_sym_end,
+  [7344] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(904), 1,
-      anon_sym_LBRACE,
-    STATE(359), 1,
       sym_comment,
-  [8526] = 4,
+    ACTIONS(1066), 1,
+      sym_ident,
+  [7351] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(589), 1,
-      anon_sym_RBRACE,
-    STATE(360), 1,
       sym_comment,
-  [8539] = 4,
+    ACTIONS(1068), 1,
+      sym_ident,
+  [7358] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(906), 1,
-      anon_sym_SEMI,
-    STATE(361), 1,
       sym_comment,
-  [8552] = 4,
+    ACTIONS(1070), 1,
+      sym_ident,
+  [7365] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(718), 1,
-      anon_sym_SEMI,
-    STATE(362), 1,
       sym_comment,
-  [8565] = 4,
+    ACTIONS(1072), 1,
+      anon_sym_COLON,
+  [7372] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(908), 1,
-      anon_sym_EQ,
-    STATE(363), 1,
       sym_comment,
-  [8578] = 4,
+    ACTIONS(1074), 1,
+      sym_ident,
+  [7379] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(910), 1,
-      anon_sym_SEMI,
-    STATE(364), 1,
       sym_comment,
-  [8591] = 4,
+    ACTIONS(126), 1,
+      ts_builtin_sym_end,
+  [7386] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(912), 1,
-      sym_ident,
-    STATE(365), 1,
       sym_comment,
-  [8604] = 4,
+    ACTIONS(1076), 1,
+      anon_sym_SEMI,
+  [7393] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(914), 1,
+      sym_comment,
+    ACTIONS(1078), 1,
       sym_ident,
-    STATE(366), 1,
+  [7400] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [8617] = 4,
+    ACTIONS(1080), 1,
+      anon_sym_LBRACE,
+  [7407] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(916), 1,
-      anon_sym_LPAREN,
-    STATE(367), 1,
       sym_comment,
-  [8630] = 4,
+    ACTIONS(1082), 1,
+      sym_ident,
+  [7414] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(918), 1,
+      sym_comment,
+    ACTIONS(1084), 1,
       sym_ident,
-    STATE(368), 1,
+  [7421] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [8643] = 4,
+    ACTIONS(450), 1,
+      anon_sym_SEMI,
+  [7428] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(920), 1,
-      sym_ident,
-    STATE(369), 1,
       sym_comment,
-  [8656] = 4,
+    ACTIONS(1086), 1,
+      sym_number,
+  [7435] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(791), 1,
-      anon_sym_COMMA,
-    STATE(370), 1,
       sym_comment,
-  [8669] = 4,
+    ACTIONS(1088), 1,
+      sym_ident,
+  [7442] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(922), 1,
+      sym_comment,
+    ACTIONS(1090), 1,
       sym_ident,
-    STATE(371), 1,
+  [7449] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [8682] = 4,
+    ACTIONS(1092), 1,
+      sym_ident,
+  [7456] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(924), 1,
+      sym_comment,
+    ACTIONS(1094), 1,
       anon_sym_LPAREN,
-    STATE(372), 1,
+  [7463] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(1096), 1,
+      anon_sym_LBRACE,
+  [7470] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [8695] = 4,
+    ACTIONS(1098), 1,
+      sym_number,
+  [7477] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(797), 1,
-      anon_sym_COMMA,
-    STATE(373), 1,
       sym_comment,
-  [8708] = 4,
+    ACTIONS(893), 1,
+      anon_sym_LBRACE,
+  [7484] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(926), 1,
-      anon_sym_EQ,
-    STATE(374), 1,
       sym_comment,
-  [8721] = 4,
+    ACTIONS(1100), 1,
+      sym_ident,
+  [7491] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(928), 1,
+      sym_comment,
+    ACTIONS(1102), 1,
       sym_ident,
-    STATE(375), 1,
+  [7498] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [8734] = 4,
+    ACTIONS(1104), 1,
+      sym_ident,
+  [7505] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(809), 1,
-      anon_sym_SEMI,
-    STATE(376), 1,
       sym_comment,
-  [8747] = 4,
+    ACTIONS(216), 1,
+      anon_sym_SEMI,
+  [7512] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(663), 1,
+      sym_comment,
+    ACTIONS(724), 1,
       anon_sym_if,
-    STATE(377), 1,
+  [7519] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [8760] = 4,
+    ACTIONS(1106), 1,
+      anon_sym_EQ,
+  [7526] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(930), 1,
-      anon_sym_RBRACE,
-    STATE(378), 1,
       sym_comment,
-  [8773] = 4,
+    ACTIONS(1108), 1,
+      sym_number,
+  [7533] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(499), 1,
-      anon_sym_RBRACE,
-    STATE(379), 1,
       sym_comment,
-  [8786] = 4,
+    ACTIONS(1110), 1,
+      anon_sym_RBRACE,
+  [7540] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(932), 1,
-      anon_sym_LBRACE,
-    STATE(380), 1,
       sym_comment,
-  [8799] = 4,
+    ACTIONS(1112), 1,
+      sym_number,
+  [7547] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(934), 1,
-      anon_sym_LBRACE,
-    STATE(381), 1,
       sym_comment,
-  [8812] = 4,
+    ACTIONS(1114), 1,
+      sym_ident,
+  [7554] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(936), 1,
-      anon_sym_LBRACE,
-    STATE(382), 1,
       sym_comment,
-  [8825] = 4,
+    ACTIONS(1116), 1,
+      anon_sym_EQ,
+  [7561] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(938), 1,
-      anon_sym_LBRACE,
-    STATE(383), 1,
       sym_comment,
-  [8838] = 4,
+    ACTIONS(1118), 1,
+      sym_string,
+  [7568] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(940), 1,
-      sym_number,
-    STATE(384), 1,
       sym_comment,
-  [8851] = 4,
+    ACTIONS(1120), 1,
+      anon_sym_LBRACE,
+  [7575] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(787), 1,
-      anon_sym_SEMI,
-    STATE(385), 1,
       sym_comment,
-  [8864] = 4,
+    ACTIONS(1122), 1,
+      sym_ident,
+  [7582] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(942), 1,
-      anon_sym_EQ,
-    STATE(386), 1,
       sym_comment,
-  [8877] = 4,
+    ACTIONS(1124), 1,
+      anon_sym_group,
+  [7589] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(944), 1,
-      sym_ident,
-    STATE(387), 1,
       sym_comment,
-  [8890] = 4,
+    ACTIONS(1126), 1,
+      anon_sym_RBRACE,
+  [7596] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(946), 1,
-      anon_sym_LPAREN,
-    STATE(388), 1,
       sym_comment,
-  [8903] = 4,
+    ACTIONS(428), 1,
+      anon_sym_SEMI,
+  [7603] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(948), 1,
-      sym_number,
-    STATE(389), 1,
       sym_comment,
-  [8916] = 4,
+    ACTIONS(1128), 1,
+      anon_sym_SEMI,
+  [7610] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(950), 1,
-      anon_sym_EQ,
-    STATE(390), 1,
       sym_comment,
-  [8929] = 4,
+    ACTIONS(1130), 1,
+      anon_sym_RBRACE,
+  [7617] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(952), 1,
-      anon_sym_SEMI,
-    STATE(391), 1,
       sym_comment,
-  [8942] = 4,
+    ACTIONS(1132), 1,
+      anon_sym_RPAREN,
+  [7624] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(954), 1,
-      sym_ident,
-    STATE(392), 1,
       sym_comment,
-  [8955] = 4,
+    ACTIONS(1134), 1,
+      sym_number,
+  [7631] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(956), 1,
-      anon_sym_COLON,
-    STATE(393), 1,
       sym_comment,
-  [8968] = 4,
+    ACTIONS(1136), 1,
+      anon_sym_EQ,
+  [7638] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(958), 1,
-      anon_sym_RBRACE,
-    STATE(394), 1,
       sym_comment,
-  [8981] = 4,
+    ACTIONS(1138), 1,
+      anon_sym_LBRACE,
+  [7645] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(960), 1,
-      anon_sym_RBRACE,
-    STATE(395), 1,
       sym_comment,
-  [8994] = 4,
+    ACTIONS(970), 1,
+      anon_sym_COMMA,
+  [7652] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(759), 1,
-      anon_sym_primitive,
-    STATE(396), 1,
       sym_comment,
-  [9007] = 4,
+    ACTIONS(1140), 1,
+      anon_sym_COLON,
+  [7659] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(735), 1,
-      anon_sym_SEMI,
-    STATE(397), 1,
       sym_comment,
-  [9020] = 4,
+    ACTIONS(1142), 1,
+      anon_sym_LPAREN,
+  [7666] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(388), 1,
-      anon_sym_SEMI,
-    STATE(398), 1,
       sym_comment,
-  [9033] = 4,
+    ACTIONS(978), 1,
+      anon_sym_COMMA,
+  [7673] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(962), 1,
-      sym_ident,
-    STATE(399), 1,
       sym_comment,
-  [9046] = 4,
+    ACTIONS(1144), 1,
+      anon_sym_COLON,
+  [7680] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(964), 1,
-      anon_sym_LBRACE,
-    STATE(400), 1,
       sym_comment,
-  [9059] = 4,
+    ACTIONS(1146), 1,
+      sym_ident,
+  [7687] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(966), 1,
-      anon_sym_LBRACE,
-    STATE(401), 1,
       sym_comment,
-  [9072] = 4,
+    ACTIONS(1010), 1,
+      anon_sym_SEMI,
+  [7694] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(968), 1,
-      anon_sym_LBRACE,
-    STATE(402), 1,
       sym_comment,
-  [9085] = 4,
+    ACTIONS(768), 1,
+      anon_sym_if,
+  [7701] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(970), 1,
-      anon_sym_SEMI,
-    STATE(403), 1,
       sym_comment,
-  [9098] = 4,
+    ACTIONS(1148), 1,
+      sym_ident,
+  [7708] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(972), 1,
-      anon_sym_EQ,
-    STATE(404), 1,
       sym_comment,
-  [9111] = 4,
+    ACTIONS(955), 1,
+      anon_sym_COMMA,
+  [7715] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(683), 1,
-      anon_sym_RBRACE,
-    STATE(405), 1,
       sym_comment,
-  [9124] = 4,
+    ACTIONS(112), 1,
+      ts_builtin_sym_end,
+  [7722] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(974), 1,
-      anon_sym_EQ,
-    STATE(406), 1,
       sym_comment,
-  [9137] = 4,
+    ACTIONS(1150), 1,
+      ts_builtin_sym_end,
+  [7729] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(976), 1,
-      anon_sym_COLON,
-    STATE(407), 1,
       sym_comment,
-  [9150] = 4,
+    ACTIONS(1152), 1,
+      anon_sym_LPAREN,
+  [7736] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(978), 1,
-      sym_ident,
-    STATE(408), 1,
       sym_comment,
-  [9163] = 4,
+    ACTIONS(964), 1,
+      anon_sym_COMMA,
+  [7743] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(980), 1,
-      ts_builtin_sym_end,
-    STATE(409), 1,
       sym_comment,
-  [9176] = 4,
+    ACTIONS(1154), 1,
+      anon_sym_EQ,
+  [7750] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(982), 1,
-      anon_sym_GT,
-    STATE(410), 1,
       sym_comment,
-  [9189] = 4,
+    ACTIONS(1156), 1,
+      anon_sym_RBRACK,
+  [7757] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(695), 1,
-      anon_sym_LBRACE,
-    STATE(411), 1,
       sym_comment,
-  [9202] = 4,
+    ACTIONS(1020), 1,
+      anon_sym_SEMI,
+  [7764] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(984), 1,
-      anon_sym_DASH_GT,
-    STATE(412), 1,
       sym_comment,
-  [9215] = 4,
+    ACTIONS(1158), 1,
+      sym_string,
+  [7771] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(986), 1,
+      sym_comment,
+    ACTIONS(1160), 1,
       sym_ident,
-    STATE(413), 1,
+  [7778] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [9228] = 4,
+    ACTIONS(921), 1,
+      anon_sym_LBRACE,
+  [7785] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(988), 1,
-      anon_sym_RPAREN,
-    STATE(414), 1,
       sym_comment,
-  [9241] = 4,
+    ACTIONS(1162), 1,
+      anon_sym_LBRACE,
+  [7792] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(990), 1,
-      anon_sym_RBRACK,
-    STATE(415), 1,
       sym_comment,
-  [9254] = 4,
+    ACTIONS(1164), 1,
+      sym_number,
+  [7799] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(992), 1,
-      anon_sym_LBRACE,
-    STATE(416), 1,
       sym_comment,
-  [9267] = 4,
+    ACTIONS(1166), 1,
+      anon_sym_RBRACK,
+  [7806] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(379), 1,
-      anon_sym_SEMI,
-    STATE(417), 1,
       sym_comment,
-  [9280] = 4,
+    ACTIONS(1168), 1,
+      anon_sym_SEMI,
+  [7813] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(994), 1,
-      anon_sym_SQUOTE,
-    STATE(418), 1,
       sym_comment,
-  [9293] = 4,
+    ACTIONS(1170), 1,
+      sym_ident,
+  [7820] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(996), 1,
-      sym_number,
-    STATE(419), 1,
       sym_comment,
-  [9306] = 4,
+    ACTIONS(1172), 1,
+      sym_ident,
+  [7827] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(381), 1,
-      anon_sym_SEMI,
-    STATE(420), 1,
       sym_comment,
-  [9319] = 4,
+    ACTIONS(1174), 1,
+      anon_sym_SEMI,
+  [7834] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(998), 1,
+      sym_comment,
+    ACTIONS(610), 1,
       anon_sym_RBRACE,
-    STATE(421), 1,
+  [7841] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [9332] = 4,
+    ACTIONS(1176), 1,
+      anon_sym_LBRACE,
+  [7848] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1000), 1,
-      anon_sym_QMARK,
-    STATE(422), 1,
       sym_comment,
-  [9345] = 4,
+    ACTIONS(1178), 1,
+      anon_sym_EQ,
+  [7855] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1002), 1,
-      sym_ident,
-    STATE(423), 1,
       sym_comment,
-  [9358] = 4,
+    ACTIONS(943), 1,
+      anon_sym_primitive,
+  [7862] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1004), 1,
-      anon_sym_RPAREN,
-    STATE(424), 1,
       sym_comment,
-  [9371] = 4,
+    ACTIONS(1180), 1,
+      anon_sym_LBRACE,
+  [7869] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1006), 1,
+      sym_comment,
+    ACTIONS(1182), 1,
       anon_sym_RBRACE,
-    STATE(425), 1,
+  [7876] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [9384] = 4,
+    ACTIONS(1184), 1,
+      aux_sym_metadata_token1,
+  [7883] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1008), 1,
-      sym_ident,
-    STATE(426), 1,
       sym_comment,
-  [9397] = 4,
+    ACTIONS(1186), 1,
+      anon_sym_RBRACE,
+  [7890] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1010), 1,
-      anon_sym_RPAREN,
-    STATE(427), 1,
       sym_comment,
-  [9410] = 4,
+    ACTIONS(1188), 1,
+      anon_sym_GT,
+  [7897] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1012), 1,
-      sym_number,
-    STATE(428), 1,
       sym_comment,
-  [9423] = 4,
+    ACTIONS(1190), 1,
+      sym_ident,
+  [7904] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(105), 1,
-      ts_builtin_sym_end,
-    STATE(429), 1,
       sym_comment,
-  [9436] = 4,
+    ACTIONS(1192), 1,
+      anon_sym_LBRACE,
+  [7911] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1014), 1,
-      sym_ident,
-    STATE(430), 1,
       sym_comment,
-  [9449] = 4,
+    ACTIONS(1194), 1,
+      anon_sym_LBRACE,
+  [7918] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1016), 1,
-      sym_ident,
-    STATE(431), 1,
       sym_comment,
-  [9462] = 4,
+    ACTIONS(1196), 1,
+      anon_sym_LBRACE,
+  [7925] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1018), 1,
-      ts_builtin_sym_end,
-    STATE(432), 1,
       sym_comment,
-  [9475] = 4,
+    ACTIONS(1198), 1,
+      anon_sym_LBRACE,
+  [7932] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1020), 1,
+      sym_comment,
+    ACTIONS(1200), 1,
       sym_number,
-    STATE(433), 1,
+  [7939] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [9488] = 4,
+    ACTIONS(1202), 1,
+      anon_sym_COLON,
+  [7946] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(369), 1,
-      anon_sym_SEMI,
-    STATE(434), 1,
       sym_comment,
-  [9501] = 4,
+    ACTIONS(1204), 1,
+      anon_sym_in,
+  [7953] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1022), 1,
-      sym_ident,
-    STATE(435), 1,
       sym_comment,
-  [9514] = 4,
+    ACTIONS(1206), 1,
+      anon_sym_SEMI,
+  [7960] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1024), 1,
+      sym_comment,
+    ACTIONS(1208), 1,
       anon_sym_LBRACE,
-    STATE(436), 1,
+  [7967] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [9527] = 4,
+    ACTIONS(1210), 1,
+      sym_ident,
+  [7974] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1026), 1,
-      anon_sym_LBRACE,
-    STATE(437), 1,
       sym_comment,
-  [9540] = 4,
+    ACTIONS(1212), 1,
+      anon_sym_EQ,
+  [7981] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1028), 1,
-      sym_number,
-    STATE(438), 1,
       sym_comment,
-  [9553] = 4,
+    ACTIONS(1214), 1,
+      anon_sym_LBRACE,
+  [7988] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(542), 1,
-      anon_sym_if,
-    STATE(439), 1,
       sym_comment,
-  [9566] = 4,
+    ACTIONS(1216), 1,
+      anon_sym_RPAREN,
+  [7995] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1030), 1,
-      anon_sym_SEMI,
-    STATE(440), 1,
       sym_comment,
-  [9579] = 4,
-    ACTIONS(699), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(701), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1032), 1,
-      anon_sym_LF,
-    STATE(441), 1,
+    ACTIONS(1218), 1,
+      anon_sym_LBRACE,
+  [8002] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [9592] = 4,
+    ACTIONS(490), 1,
+      anon_sym_SEMI,
+  [8009] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(661), 1,
-      anon_sym_if,
-    STATE(442), 1,
       sym_comment,
-  [9605] = 4,
+    ACTIONS(1220), 1,
+      anon_sym_DASH_GT,
+  [8016] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1034), 1,
-      sym_ident,
-    STATE(443), 1,
       sym_comment,
-  [9618] = 4,
+    ACTIONS(652), 1,
+      anon_sym_RBRACE,
+  [8023] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1036), 1,
-      sym_number,
-    STATE(444), 1,
       sym_comment,
-  [9631] = 4,
+    ACTIONS(422), 1,
+      anon_sym_SEMI,
+  [8030] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(99), 1,
-      ts_builtin_sym_end,
-    STATE(445), 1,
       sym_comment,
-  [9644] = 4,
+    ACTIONS(1222), 1,
+      anon_sym_EQ,
+  [8037] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1038), 1,
-      sym_number,
-    STATE(446), 1,
       sym_comment,
-  [9657] = 4,
+    ACTIONS(1224), 1,
+      sym_ident,
+  [8044] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1040), 1,
-      anon_sym_EQ,
-    STATE(447), 1,
       sym_comment,
-  [9670] = 4,
+    ACTIONS(1226), 1,
+      anon_sym_RPAREN,
+  [8051] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1042), 1,
-      aux_sym_metadata_token1,
-    STATE(448), 1,
       sym_comment,
-  [9683] = 4,
+    ACTIONS(1228), 1,
+      sym_ident,
+  [8058] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1044), 1,
-      ts_builtin_sym_end,
-    STATE(449), 1,
       sym_comment,
-  [9696] = 4,
+    ACTIONS(1230), 1,
+      anon_sym_RPAREN,
+  [8065] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1046), 1,
-      anon_sym_EQ,
-    STATE(450), 1,
       sym_comment,
-  [9709] = 4,
+    ACTIONS(1232), 1,
+      sym_ident,
+  [8072] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1048), 1,
-      anon_sym_LBRACE,
-    STATE(451), 1,
       sym_comment,
-  [9722] = 4,
+    ACTIONS(1234), 1,
+      anon_sym_RPAREN,
+  [8079] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1050), 1,
-      sym_number,
-    STATE(452), 1,
       sym_comment,
-  [9735] = 4,
+    ACTIONS(1236), 1,
+      sym_number,
+  [8086] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1052), 1,
+      sym_comment,
+    ACTIONS(732), 1,
       anon_sym_RBRACE,
-    STATE(453), 1,
+  [8093] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [9748] = 4,
+    ACTIONS(1238), 1,
+      ts_builtin_sym_end,
+  [8100] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1054), 1,
-      sym_string,
-    STATE(454), 1,
       sym_comment,
-  [9761] = 4,
+    ACTIONS(1240), 1,
+      sym_number,
+  [8107] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1056), 1,
-      sym_ident,
-    STATE(455), 1,
       sym_comment,
-  [9774] = 4,
+    ACTIONS(1242), 1,
+      sym_number,
+  [8114] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1058), 1,
+      sym_comment,
+    ACTIONS(1244), 1,
       sym_number,
-    STATE(456), 1,
+  [8121] = 2,
+    ACTIONS(3), 1,
       sym_comment,
-  [9787] = 4,
+    ACTIONS(1246), 1,
+      anon_sym_EQ,
+  [8128] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1060), 1,
-      sym_ident,
-    STATE(457), 1,
       sym_comment,
-  [9800] = 4,
+    ACTIONS(1248), 1,
+      anon_sym_EQ,
+  [8135] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1062), 1,
-      aux_sym_metadata_token1,
-    STATE(458), 1,
       sym_comment,
-  [9813] = 4,
+    ACTIONS(624), 1,
+      anon_sym_RBRACE,
+  [8142] = 2,
     ACTIONS(3), 1,
-      anon_sym_SLASH_SLASH,
-    ACTIONS(5), 1,
-      aux_sym_comment_token2,
-    ACTIONS(1064), 1,
-      sym_string,
-    STATE(459), 1,
       sym_comment,
-  [9826] = 1,
-    ACTIONS(1066), 1,
-      ts_builtin_sym_end,
-  [9830] = 1,
-    ACTIONS(1068), 1,
-      ts_builtin_sym_end,
+    ACTIONS(1250), 1,
+      anon_sym_EQ,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(2)] = 0,
-  [SMALL_STATE(3)] = 67,
-  [SMALL_STATE(4)] = 134,
-  [SMALL_STATE(5)] = 201,
-  [SMALL_STATE(6)] = 268,
-  [SMALL_STATE(7)] = 335,
-  [SMALL_STATE(8)] = 400,
-  [SMALL_STATE(9)] = 467,
-  [SMALL_STATE(10)] = 534,
-  [SMALL_STATE(11)] = 601,
-  [SMALL_STATE(12)] = 668,
-  [SMALL_STATE(13)] = 735,
-  [SMALL_STATE(14)] = 802,
-  [SMALL_STATE(15)] = 869,
-  [SMALL_STATE(16)] = 933,
-  [SMALL_STATE(17)] = 994,
-  [SMALL_STATE(18)] = 1055,
-  [SMALL_STATE(19)] = 1116,
-  [SMALL_STATE(20)] = 1176,
-  [SMALL_STATE(21)] = 1205,
-  [SMALL_STATE(22)] = 1256,
-  [SMALL_STATE(23)] = 1307,
-  [SMALL_STATE(24)] = 1339,
-  [SMALL_STATE(25)] = 1384,
+  [SMALL_STATE(3)] = 64,
+  [SMALL_STATE(4)] = 125,
+  [SMALL_STATE(5)] = 186,
+  [SMALL_STATE(6)] = 247,
+  [SMALL_STATE(7)] = 308,
+  [SMALL_STATE(8)] = 369,
+  [SMALL_STATE(9)] = 430,
+  [SMALL_STATE(10)] = 491,
+  [SMALL_STATE(11)] = 552,
+  [SMALL_STATE(12)] = 613,
+  [SMALL_STATE(13)] = 674,
+  [SMALL_STATE(14)] = 735,
+  [SMALL_STATE(15)] = 796,
+  [SMALL_STATE(16)] = 857,
+  [SMALL_STATE(17)] = 918,
+  [SMALL_STATE(18)] = 979,
+  [SMALL_STATE(19)] = 1040,
+  [SMALL_STATE(20)] = 1101,
+  [SMALL_STATE(21)] = 1162,
+  [SMALL_STATE(22)] = 1223,
+  [SMALL_STATE(23)] = 1284,
+  [SMALL_STATE(24)] = 1338,
+  [SMALL_STATE(25)] = 1386,
   [SMALL_STATE(26)] = 1433,
-  [SMALL_STATE(27)] = 1482,
-  [SMALL_STATE(28)] = 1523,
-  [SMALL_STATE(29)] = 1553,
-  [SMALL_STATE(30)] = 1593,
-  [SMALL_STATE(31)] = 1619,
-  [SMALL_STATE(32)] = 1659,
-  [SMALL_STATE(33)] = 1687,
-  [SMALL_STATE(34)] = 1727,
-  [SMALL_STATE(35)] = 1767,
-  [SMALL_STATE(36)] = 1795,
-  [SMALL_STATE(37)] = 1835,
-  [SMALL_STATE(38)] = 1861,
-  [SMALL_STATE(39)] = 1889,
-  [SMALL_STATE(40)] = 1915,
-  [SMALL_STATE(41)] = 1957,
-  [SMALL_STATE(42)] = 1984,
-  [SMALL_STATE(43)] = 2009,
-  [SMALL_STATE(44)] = 2034,
-  [SMALL_STATE(45)] = 2059,
-  [SMALL_STATE(46)] = 2084,
-  [SMALL_STATE(47)] = 2109,
-  [SMALL_STATE(48)] = 2134,
-  [SMALL_STATE(49)] = 2159,
-  [SMALL_STATE(50)] = 2184,
-  [SMALL_STATE(51)] = 2211,
-  [SMALL_STATE(52)] = 2240,
-  [SMALL_STATE(53)] = 2265,
-  [SMALL_STATE(54)] = 2296,
-  [SMALL_STATE(55)] = 2329,
-  [SMALL_STATE(56)] = 2364,
-  [SMALL_STATE(57)] = 2389,
-  [SMALL_STATE(58)] = 2414,
-  [SMALL_STATE(59)] = 2439,
-  [SMALL_STATE(60)] = 2464,
-  [SMALL_STATE(61)] = 2489,
-  [SMALL_STATE(62)] = 2514,
-  [SMALL_STATE(63)] = 2539,
-  [SMALL_STATE(64)] = 2564,
-  [SMALL_STATE(65)] = 2589,
-  [SMALL_STATE(66)] = 2614,
-  [SMALL_STATE(67)] = 2639,
-  [SMALL_STATE(68)] = 2664,
-  [SMALL_STATE(69)] = 2689,
-  [SMALL_STATE(70)] = 2714,
-  [SMALL_STATE(71)] = 2739,
-  [SMALL_STATE(72)] = 2764,
-  [SMALL_STATE(73)] = 2789,
-  [SMALL_STATE(74)] = 2814,
-  [SMALL_STATE(75)] = 2839,
-  [SMALL_STATE(76)] = 2864,
-  [SMALL_STATE(77)] = 2889,
-  [SMALL_STATE(78)] = 2914,
-  [SMALL_STATE(79)] = 2939,
-  [SMALL_STATE(80)] = 2963,
-  [SMALL_STATE(81)] = 3003,
-  [SMALL_STATE(82)] = 3043,
-  [SMALL_STATE(83)] = 3083,
-  [SMALL_STATE(84)] = 3109,
-  [SMALL_STATE(85)] = 3149,
-  [SMALL_STATE(86)] = 3173,
-  [SMALL_STATE(87)] = 3206,
-  [SMALL_STATE(88)] = 3241,
-  [SMALL_STATE(89)] = 3276,
-  [SMALL_STATE(90)] = 3307,
-  [SMALL_STATE(91)] = 3338,
-  [SMALL_STATE(92)] = 3369,
-  [SMALL_STATE(93)] = 3400,
-  [SMALL_STATE(94)] = 3435,
-  [SMALL_STATE(95)] = 3466,
-  [SMALL_STATE(96)] = 3501,
-  [SMALL_STATE(97)] = 3532,
-  [SMALL_STATE(98)] = 3567,
-  [SMALL_STATE(99)] = 3602,
-  [SMALL_STATE(100)] = 3637,
-  [SMALL_STATE(101)] = 3672,
-  [SMALL_STATE(102)] = 3707,
-  [SMALL_STATE(103)] = 3742,
-  [SMALL_STATE(104)] = 3777,
-  [SMALL_STATE(105)] = 3808,
-  [SMALL_STATE(106)] = 3839,
-  [SMALL_STATE(107)] = 3870,
-  [SMALL_STATE(108)] = 3905,
-  [SMALL_STATE(109)] = 3936,
-  [SMALL_STATE(110)] = 3970,
-  [SMALL_STATE(111)] = 3990,
-  [SMALL_STATE(112)] = 4024,
-  [SMALL_STATE(113)] = 4044,
-  [SMALL_STATE(114)] = 4064,
-  [SMALL_STATE(115)] = 4084,
-  [SMALL_STATE(116)] = 4104,
-  [SMALL_STATE(117)] = 4136,
-  [SMALL_STATE(118)] = 4156,
-  [SMALL_STATE(119)] = 4190,
-  [SMALL_STATE(120)] = 4210,
-  [SMALL_STATE(121)] = 4229,
-  [SMALL_STATE(122)] = 4260,
-  [SMALL_STATE(123)] = 4279,
-  [SMALL_STATE(124)] = 4298,
-  [SMALL_STATE(125)] = 4329,
-  [SMALL_STATE(126)] = 4348,
-  [SMALL_STATE(127)] = 4373,
-  [SMALL_STATE(128)] = 4404,
-  [SMALL_STATE(129)] = 4429,
-  [SMALL_STATE(130)] = 4460,
-  [SMALL_STATE(131)] = 4479,
-  [SMALL_STATE(132)] = 4510,
-  [SMALL_STATE(133)] = 4529,
-  [SMALL_STATE(134)] = 4560,
-  [SMALL_STATE(135)] = 4579,
-  [SMALL_STATE(136)] = 4610,
-  [SMALL_STATE(137)] = 4635,
-  [SMALL_STATE(138)] = 4654,
-  [SMALL_STATE(139)] = 4683,
-  [SMALL_STATE(140)] = 4714,
-  [SMALL_STATE(141)] = 4733,
-  [SMALL_STATE(142)] = 4762,
-  [SMALL_STATE(143)] = 4781,
-  [SMALL_STATE(144)] = 4812,
-  [SMALL_STATE(145)] = 4831,
-  [SMALL_STATE(146)] = 4862,
-  [SMALL_STATE(147)] = 4893,
-  [SMALL_STATE(148)] = 4924,
-  [SMALL_STATE(149)] = 4949,
-  [SMALL_STATE(150)] = 4977,
-  [SMALL_STATE(151)] = 4999,
-  [SMALL_STATE(152)] = 5027,
-  [SMALL_STATE(153)] = 5051,
-  [SMALL_STATE(154)] = 5076,
-  [SMALL_STATE(155)] = 5095,
-  [SMALL_STATE(156)] = 5120,
-  [SMALL_STATE(157)] = 5139,
-  [SMALL_STATE(158)] = 5158,
-  [SMALL_STATE(159)] = 5183,
-  [SMALL_STATE(160)] = 5208,
-  [SMALL_STATE(161)] = 5227,
-  [SMALL_STATE(162)] = 5246,
-  [SMALL_STATE(163)] = 5267,
-  [SMALL_STATE(164)] = 5286,
-  [SMALL_STATE(165)] = 5305,
-  [SMALL_STATE(166)] = 5330,
-  [SMALL_STATE(167)] = 5355,
-  [SMALL_STATE(168)] = 5376,
-  [SMALL_STATE(169)] = 5395,
-  [SMALL_STATE(170)] = 5416,
-  [SMALL_STATE(171)] = 5441,
-  [SMALL_STATE(172)] = 5460,
-  [SMALL_STATE(173)] = 5481,
-  [SMALL_STATE(174)] = 5500,
-  [SMALL_STATE(175)] = 5519,
-  [SMALL_STATE(176)] = 5539,
-  [SMALL_STATE(177)] = 5557,
-  [SMALL_STATE(178)] = 5579,
-  [SMALL_STATE(179)] = 5601,
-  [SMALL_STATE(180)] = 5623,
-  [SMALL_STATE(181)] = 5645,
-  [SMALL_STATE(182)] = 5665,
-  [SMALL_STATE(183)] = 5683,
-  [SMALL_STATE(184)] = 5705,
-  [SMALL_STATE(185)] = 5727,
-  [SMALL_STATE(186)] = 5749,
-  [SMALL_STATE(187)] = 5769,
-  [SMALL_STATE(188)] = 5791,
-  [SMALL_STATE(189)] = 5811,
-  [SMALL_STATE(190)] = 5833,
-  [SMALL_STATE(191)] = 5855,
-  [SMALL_STATE(192)] = 5871,
-  [SMALL_STATE(193)] = 5891,
-  [SMALL_STATE(194)] = 5909,
-  [SMALL_STATE(195)] = 5931,
-  [SMALL_STATE(196)] = 5949,
-  [SMALL_STATE(197)] = 5971,
-  [SMALL_STATE(198)] = 5991,
-  [SMALL_STATE(199)] = 6007,
-  [SMALL_STATE(200)] = 6025,
-  [SMALL_STATE(201)] = 6042,
-  [SMALL_STATE(202)] = 6059,
-  [SMALL_STATE(203)] = 6078,
-  [SMALL_STATE(204)] = 6097,
-  [SMALL_STATE(205)] = 6114,
-  [SMALL_STATE(206)] = 6133,
-  [SMALL_STATE(207)] = 6152,
-  [SMALL_STATE(208)] = 6171,
-  [SMALL_STATE(209)] = 6190,
-  [SMALL_STATE(210)] = 6205,
-  [SMALL_STATE(211)] = 6224,
-  [SMALL_STATE(212)] = 6243,
-  [SMALL_STATE(213)] = 6262,
-  [SMALL_STATE(214)] = 6277,
-  [SMALL_STATE(215)] = 6296,
-  [SMALL_STATE(216)] = 6315,
-  [SMALL_STATE(217)] = 6330,
-  [SMALL_STATE(218)] = 6349,
-  [SMALL_STATE(219)] = 6368,
-  [SMALL_STATE(220)] = 6383,
-  [SMALL_STATE(221)] = 6400,
-  [SMALL_STATE(222)] = 6417,
-  [SMALL_STATE(223)] = 6436,
-  [SMALL_STATE(224)] = 6453,
-  [SMALL_STATE(225)] = 6472,
-  [SMALL_STATE(226)] = 6487,
-  [SMALL_STATE(227)] = 6502,
-  [SMALL_STATE(228)] = 6521,
-  [SMALL_STATE(229)] = 6540,
-  [SMALL_STATE(230)] = 6559,
-  [SMALL_STATE(231)] = 6576,
-  [SMALL_STATE(232)] = 6591,
-  [SMALL_STATE(233)] = 6610,
-  [SMALL_STATE(234)] = 6625,
-  [SMALL_STATE(235)] = 6640,
-  [SMALL_STATE(236)] = 6655,
-  [SMALL_STATE(237)] = 6674,
-  [SMALL_STATE(238)] = 6693,
-  [SMALL_STATE(239)] = 6712,
-  [SMALL_STATE(240)] = 6731,
-  [SMALL_STATE(241)] = 6748,
-  [SMALL_STATE(242)] = 6763,
-  [SMALL_STATE(243)] = 6782,
-  [SMALL_STATE(244)] = 6797,
-  [SMALL_STATE(245)] = 6812,
-  [SMALL_STATE(246)] = 6827,
-  [SMALL_STATE(247)] = 6842,
-  [SMALL_STATE(248)] = 6857,
-  [SMALL_STATE(249)] = 6876,
-  [SMALL_STATE(250)] = 6895,
-  [SMALL_STATE(251)] = 6914,
-  [SMALL_STATE(252)] = 6933,
-  [SMALL_STATE(253)] = 6952,
-  [SMALL_STATE(254)] = 6967,
-  [SMALL_STATE(255)] = 6986,
-  [SMALL_STATE(256)] = 7005,
-  [SMALL_STATE(257)] = 7019,
-  [SMALL_STATE(258)] = 7033,
-  [SMALL_STATE(259)] = 7049,
-  [SMALL_STATE(260)] = 7065,
-  [SMALL_STATE(261)] = 7081,
-  [SMALL_STATE(262)] = 7097,
-  [SMALL_STATE(263)] = 7111,
-  [SMALL_STATE(264)] = 7127,
-  [SMALL_STATE(265)] = 7143,
-  [SMALL_STATE(266)] = 7159,
-  [SMALL_STATE(267)] = 7175,
-  [SMALL_STATE(268)] = 7191,
-  [SMALL_STATE(269)] = 7207,
-  [SMALL_STATE(270)] = 7223,
-  [SMALL_STATE(271)] = 7239,
-  [SMALL_STATE(272)] = 7255,
-  [SMALL_STATE(273)] = 7271,
-  [SMALL_STATE(274)] = 7287,
-  [SMALL_STATE(275)] = 7303,
-  [SMALL_STATE(276)] = 7319,
-  [SMALL_STATE(277)] = 7335,
-  [SMALL_STATE(278)] = 7349,
-  [SMALL_STATE(279)] = 7365,
-  [SMALL_STATE(280)] = 7381,
-  [SMALL_STATE(281)] = 7397,
-  [SMALL_STATE(282)] = 7413,
-  [SMALL_STATE(283)] = 7429,
-  [SMALL_STATE(284)] = 7443,
-  [SMALL_STATE(285)] = 7459,
-  [SMALL_STATE(286)] = 7473,
-  [SMALL_STATE(287)] = 7489,
-  [SMALL_STATE(288)] = 7505,
-  [SMALL_STATE(289)] = 7521,
-  [SMALL_STATE(290)] = 7537,
-  [SMALL_STATE(291)] = 7551,
-  [SMALL_STATE(292)] = 7567,
-  [SMALL_STATE(293)] = 7583,
-  [SMALL_STATE(294)] = 7599,
-  [SMALL_STATE(295)] = 7615,
-  [SMALL_STATE(296)] = 7631,
-  [SMALL_STATE(297)] = 7647,
-  [SMALL_STATE(298)] = 7663,
-  [SMALL_STATE(299)] = 7679,
-  [SMALL_STATE(300)] = 7695,
-  [SMALL_STATE(301)] = 7709,
-  [SMALL_STATE(302)] = 7723,
-  [SMALL_STATE(303)] = 7737,
-  [SMALL_STATE(304)] = 7753,
-  [SMALL_STATE(305)] = 7767,
-  [SMALL_STATE(306)] = 7783,
-  [SMALL_STATE(307)] = 7799,
-  [SMALL_STATE(308)] = 7813,
-  [SMALL_STATE(309)] = 7829,
-  [SMALL_STATE(310)] = 7843,
-  [SMALL_STATE(311)] = 7859,
-  [SMALL_STATE(312)] = 7873,
-  [SMALL_STATE(313)] = 7889,
-  [SMALL_STATE(314)] = 7903,
-  [SMALL_STATE(315)] = 7919,
-  [SMALL_STATE(316)] = 7935,
-  [SMALL_STATE(317)] = 7949,
-  [SMALL_STATE(318)] = 7963,
-  [SMALL_STATE(319)] = 7979,
-  [SMALL_STATE(320)] = 7995,
-  [SMALL_STATE(321)] = 8009,
-  [SMALL_STATE(322)] = 8025,
-  [SMALL_STATE(323)] = 8041,
-  [SMALL_STATE(324)] = 8055,
-  [SMALL_STATE(325)] = 8071,
-  [SMALL_STATE(326)] = 8084,
-  [SMALL_STATE(327)] = 8097,
-  [SMALL_STATE(328)] = 8110,
-  [SMALL_STATE(329)] = 8123,
-  [SMALL_STATE(330)] = 8136,
-  [SMALL_STATE(331)] = 8149,
-  [SMALL_STATE(332)] = 8162,
-  [SMALL_STATE(333)] = 8175,
-  [SMALL_STATE(334)] = 8188,
-  [SMALL_STATE(335)] = 8201,
-  [SMALL_STATE(336)] = 8214,
-  [SMALL_STATE(337)] = 8227,
-  [SMALL_STATE(338)] = 8240,
-  [SMALL_STATE(339)] = 8253,
-  [SMALL_STATE(340)] = 8266,
-  [SMALL_STATE(341)] = 8279,
-  [SMALL_STATE(342)] = 8292,
-  [SMALL_STATE(343)] = 8305,
-  [SMALL_STATE(344)] = 8318,
-  [SMALL_STATE(345)] = 8331,
-  [SMALL_STATE(346)] = 8344,
-  [SMALL_STATE(347)] = 8357,
-  [SMALL_STATE(348)] = 8370,
-  [SMALL_STATE(349)] = 8383,
-  [SMALL_STATE(350)] = 8396,
-  [SMALL_STATE(351)] = 8409,
-  [SMALL_STATE(352)] = 8422,
-  [SMALL_STATE(353)] = 8435,
-  [SMALL_STATE(354)] = 8448,
-  [SMALL_STATE(355)] = 8461,
-  [SMALL_STATE(356)] = 8474,
-  [SMALL_STATE(357)] = 8487,
-  [SMALL_STATE(358)] = 8500,
-  [SMALL_STATE(359)] = 8513,
-  [SMALL_STATE(360)] = 8526,
-  [SMALL_STATE(361)] = 8539,
-  [SMALL_STATE(362)] = 8552,
-  [SMALL_STATE(363)] = 8565,
-  [SMALL_STATE(364)] = 8578,
-  [SMALL_STATE(365)] = 8591,
-  [SMALL_STATE(366)] = 8604,
-  [SMALL_STATE(367)] = 8617,
-  [SMALL_STATE(368)] = 8630,
-  [SMALL_STATE(369)] = 8643,
-  [SMALL_STATE(370)] = 8656,
-  [SMALL_STATE(371)] = 8669,
-  [SMALL_STATE(372)] = 8682,
-  [SMALL_STATE(373)] = 8695,
-  [SMALL_STATE(374)] = 8708,
-  [SMALL_STATE(375)] = 8721,
-  [SMALL_STATE(376)] = 8734,
-  [SMALL_STATE(377)] = 8747,
-  [SMALL_STATE(378)] = 8760,
-  [SMALL_STATE(379)] = 8773,
-  [SMALL_STATE(380)] = 8786,
-  [SMALL_STATE(381)] = 8799,
-  [SMALL_STATE(382)] = 8812,
-  [SMALL_STATE(383)] = 8825,
-  [SMALL_STATE(384)] = 8838,
-  [SMALL_STATE(385)] = 8851,
-  [SMALL_STATE(386)] = 8864,
-  [SMALL_STATE(387)] = 8877,
-  [SMALL_STATE(388)] = 8890,
-  [SMALL_STATE(389)] = 8903,
-  [SMALL_STATE(390)] = 8916,
-  [SMALL_STATE(391)] = 8929,
-  [SMALL_STATE(392)] = 8942,
-  [SMALL_STATE(393)] = 8955,
-  [SMALL_STATE(394)] = 8968,
-  [SMALL_STATE(395)] = 8981,
-  [SMALL_STATE(396)] = 8994,
-  [SMALL_STATE(397)] = 9007,
-  [SMALL_STATE(398)] = 9020,
-  [SMALL_STATE(399)] = 9033,
-  [SMALL_STATE(400)] = 9046,
-  [SMALL_STATE(401)] = 9059,
-  [SMALL_STATE(402)] = 9072,
-  [SMALL_STATE(403)] = 9085,
-  [SMALL_STATE(404)] = 9098,
-  [SMALL_STATE(405)] = 9111,
-  [SMALL_STATE(406)] = 9124,
-  [SMALL_STATE(407)] = 9137,
-  [SMALL_STATE(408)] = 9150,
-  [SMALL_STATE(409)] = 9163,
-  [SMALL_STATE(410)] = 9176,
-  [SMALL_STATE(411)] = 9189,
-  [SMALL_STATE(412)] = 9202,
-  [SMALL_STATE(413)] = 9215,
-  [SMALL_STATE(414)] = 9228,
-  [SMALL_STATE(415)] = 9241,
-  [SMALL_STATE(416)] = 9254,
-  [SMALL_STATE(417)] = 9267,
-  [SMALL_STATE(418)] = 9280,
-  [SMALL_STATE(419)] = 9293,
-  [SMALL_STATE(420)] = 9306,
-  [SMALL_STATE(421)] = 9319,
-  [SMALL_STATE(422)] = 9332,
-  [SMALL_STATE(423)] = 9345,
-  [SMALL_STATE(424)] = 9358,
-  [SMALL_STATE(425)] = 9371,
-  [SMALL_STATE(426)] = 9384,
-  [SMALL_STATE(427)] = 9397,
-  [SMALL_STATE(428)] = 9410,
-  [SMALL_STATE(429)] = 9423,
-  [SMALL_STATE(430)] = 9436,
-  [SMALL_STATE(431)] = 9449,
-  [SMALL_STATE(432)] = 9462,
-  [SMALL_STATE(433)] = 9475,
-  [SMALL_STATE(434)] = 9488,
-  [SMALL_STATE(435)] = 9501,
-  [SMALL_STATE(436)] = 9514,
-  [SMALL_STATE(437)] = 9527,
-  [SMALL_STATE(438)] = 9540,
-  [SMALL_STATE(439)] = 9553,
-  [SMALL_STATE(440)] = 9566,
-  [SMALL_STATE(441)] = 9579,
-  [SMALL_STATE(442)] = 9592,
-  [SMALL_STATE(443)] = 9605,
-  [SMALL_STATE(444)] = 9618,
-  [SMALL_STATE(445)] = 9631,
-  [SMALL_STATE(446)] = 9644,
-  [SMALL_STATE(447)] = 9657,
-  [SMALL_STATE(448)] = 9670,
-  [SMALL_STATE(449)] = 9683,
-  [SMALL_STATE(450)] = 9696,
-  [SMALL_STATE(451)] = 9709,
-  [SMALL_STATE(452)] = 9722,
-  [SMALL_STATE(453)] = 9735,
-  [SMALL_STATE(454)] = 9748,
-  [SMALL_STATE(455)] = 9761,
-  [SMALL_STATE(456)] = 9774,
-  [SMALL_STATE(457)] = 9787,
-  [SMALL_STATE(458)] = 9800,
-  [SMALL_STATE(459)] = 9813,
-  [SMALL_STATE(460)] = 9826,
-  [SMALL_STATE(461)] = 9830,
+  [SMALL_STATE(27)] = 1480,
+  [SMALL_STATE(28)] = 1525,
+  [SMALL_STATE(29)] = 1570,
+  [SMALL_STATE(30)] = 1615,
+  [SMALL_STATE(31)] = 1660,
+  [SMALL_STATE(32)] = 1697,
+  [SMALL_STATE(33)] = 1720,
+  [SMALL_STATE(34)] = 1757,
+  [SMALL_STATE(35)] = 1796,
+  [SMALL_STATE(36)] = 1833,
+  [SMALL_STATE(37)] = 1870,
+  [SMALL_STATE(38)] = 1904,
+  [SMALL_STATE(39)] = 1938,
+  [SMALL_STATE(40)] = 1964,
+  [SMALL_STATE(41)] = 1998,
+  [SMALL_STATE(42)] = 2032,
+  [SMALL_STATE(43)] = 2055,
+  [SMALL_STATE(44)] = 2076,
+  [SMALL_STATE(45)] = 2099,
+  [SMALL_STATE(46)] = 2120,
+  [SMALL_STATE(47)] = 2161,
+  [SMALL_STATE(48)] = 2184,
+  [SMALL_STATE(49)] = 2204,
+  [SMALL_STATE(50)] = 2224,
+  [SMALL_STATE(51)] = 2244,
+  [SMALL_STATE(52)] = 2264,
+  [SMALL_STATE(53)] = 2284,
+  [SMALL_STATE(54)] = 2304,
+  [SMALL_STATE(55)] = 2324,
+  [SMALL_STATE(56)] = 2344,
+  [SMALL_STATE(57)] = 2364,
+  [SMALL_STATE(58)] = 2384,
+  [SMALL_STATE(59)] = 2404,
+  [SMALL_STATE(60)] = 2424,
+  [SMALL_STATE(61)] = 2444,
+  [SMALL_STATE(62)] = 2464,
+  [SMALL_STATE(63)] = 2484,
+  [SMALL_STATE(64)] = 2504,
+  [SMALL_STATE(65)] = 2524,
+  [SMALL_STATE(66)] = 2544,
+  [SMALL_STATE(67)] = 2564,
+  [SMALL_STATE(68)] = 2584,
+  [SMALL_STATE(69)] = 2604,
+  [SMALL_STATE(70)] = 2624,
+  [SMALL_STATE(71)] = 2644,
+  [SMALL_STATE(72)] = 2664,
+  [SMALL_STATE(73)] = 2684,
+  [SMALL_STATE(74)] = 2704,
+  [SMALL_STATE(75)] = 2724,
+  [SMALL_STATE(76)] = 2744,
+  [SMALL_STATE(77)] = 2764,
+  [SMALL_STATE(78)] = 2784,
+  [SMALL_STATE(79)] = 2804,
+  [SMALL_STATE(80)] = 2824,
+  [SMALL_STATE(81)] = 2848,
+  [SMALL_STATE(82)] = 2869,
+  [SMALL_STATE(83)] = 2890,
+  [SMALL_STATE(84)] = 2921,
+  [SMALL_STATE(85)] = 2940,
+  [SMALL_STATE(86)] = 2959,
+  [SMALL_STATE(87)] = 2978,
+  [SMALL_STATE(88)] = 2999,
+  [SMALL_STATE(89)] = 3018,
+  [SMALL_STATE(90)] = 3037,
+  [SMALL_STATE(91)] = 3056,
+  [SMALL_STATE(92)] = 3075,
+  [SMALL_STATE(93)] = 3096,
+  [SMALL_STATE(94)] = 3112,
+  [SMALL_STATE(95)] = 3136,
+  [SMALL_STATE(96)] = 3160,
+  [SMALL_STATE(97)] = 3188,
+  [SMALL_STATE(98)] = 3216,
+  [SMALL_STATE(99)] = 3234,
+  [SMALL_STATE(100)] = 3262,
+  [SMALL_STATE(101)] = 3290,
+  [SMALL_STATE(102)] = 3318,
+  [SMALL_STATE(103)] = 3346,
+  [SMALL_STATE(104)] = 3370,
+  [SMALL_STATE(105)] = 3386,
+  [SMALL_STATE(106)] = 3404,
+  [SMALL_STATE(107)] = 3422,
+  [SMALL_STATE(108)] = 3450,
+  [SMALL_STATE(109)] = 3478,
+  [SMALL_STATE(110)] = 3506,
+  [SMALL_STATE(111)] = 3534,
+  [SMALL_STATE(112)] = 3562,
+  [SMALL_STATE(113)] = 3580,
+  [SMALL_STATE(114)] = 3598,
+  [SMALL_STATE(115)] = 3622,
+  [SMALL_STATE(116)] = 3639,
+  [SMALL_STATE(117)] = 3654,
+  [SMALL_STATE(118)] = 3673,
+  [SMALL_STATE(119)] = 3700,
+  [SMALL_STATE(120)] = 3715,
+  [SMALL_STATE(121)] = 3730,
+  [SMALL_STATE(122)] = 3757,
+  [SMALL_STATE(123)] = 3772,
+  [SMALL_STATE(124)] = 3787,
+  [SMALL_STATE(125)] = 3814,
+  [SMALL_STATE(126)] = 3829,
+  [SMALL_STATE(127)] = 3844,
+  [SMALL_STATE(128)] = 3859,
+  [SMALL_STATE(129)] = 3880,
+  [SMALL_STATE(130)] = 3897,
+  [SMALL_STATE(131)] = 3921,
+  [SMALL_STATE(132)] = 3937,
+  [SMALL_STATE(133)] = 3953,
+  [SMALL_STATE(134)] = 3967,
+  [SMALL_STATE(135)] = 3981,
+  [SMALL_STATE(136)] = 4007,
+  [SMALL_STATE(137)] = 4021,
+  [SMALL_STATE(138)] = 4045,
+  [SMALL_STATE(139)] = 4069,
+  [SMALL_STATE(140)] = 4095,
+  [SMALL_STATE(141)] = 4121,
+  [SMALL_STATE(142)] = 4141,
+  [SMALL_STATE(143)] = 4165,
+  [SMALL_STATE(144)] = 4179,
+  [SMALL_STATE(145)] = 4195,
+  [SMALL_STATE(146)] = 4219,
+  [SMALL_STATE(147)] = 4243,
+  [SMALL_STATE(148)] = 4257,
+  [SMALL_STATE(149)] = 4271,
+  [SMALL_STATE(150)] = 4285,
+  [SMALL_STATE(151)] = 4299,
+  [SMALL_STATE(152)] = 4323,
+  [SMALL_STATE(153)] = 4337,
+  [SMALL_STATE(154)] = 4351,
+  [SMALL_STATE(155)] = 4367,
+  [SMALL_STATE(156)] = 4381,
+  [SMALL_STATE(157)] = 4395,
+  [SMALL_STATE(158)] = 4409,
+  [SMALL_STATE(159)] = 4434,
+  [SMALL_STATE(160)] = 4459,
+  [SMALL_STATE(161)] = 4480,
+  [SMALL_STATE(162)] = 4501,
+  [SMALL_STATE(163)] = 4526,
+  [SMALL_STATE(164)] = 4551,
+  [SMALL_STATE(165)] = 4574,
+  [SMALL_STATE(166)] = 4599,
+  [SMALL_STATE(167)] = 4624,
+  [SMALL_STATE(168)] = 4639,
+  [SMALL_STATE(169)] = 4654,
+  [SMALL_STATE(170)] = 4677,
+  [SMALL_STATE(171)] = 4692,
+  [SMALL_STATE(172)] = 4717,
+  [SMALL_STATE(173)] = 4742,
+  [SMALL_STATE(174)] = 4757,
+  [SMALL_STATE(175)] = 4772,
+  [SMALL_STATE(176)] = 4787,
+  [SMALL_STATE(177)] = 4802,
+  [SMALL_STATE(178)] = 4817,
+  [SMALL_STATE(179)] = 4840,
+  [SMALL_STATE(180)] = 4861,
+  [SMALL_STATE(181)] = 4881,
+  [SMALL_STATE(182)] = 4901,
+  [SMALL_STATE(183)] = 4919,
+  [SMALL_STATE(184)] = 4935,
+  [SMALL_STATE(185)] = 4955,
+  [SMALL_STATE(186)] = 4971,
+  [SMALL_STATE(187)] = 4985,
+  [SMALL_STATE(188)] = 5003,
+  [SMALL_STATE(189)] = 5019,
+  [SMALL_STATE(190)] = 5037,
+  [SMALL_STATE(191)] = 5057,
+  [SMALL_STATE(192)] = 5075,
+  [SMALL_STATE(193)] = 5095,
+  [SMALL_STATE(194)] = 5113,
+  [SMALL_STATE(195)] = 5130,
+  [SMALL_STATE(196)] = 5149,
+  [SMALL_STATE(197)] = 5168,
+  [SMALL_STATE(198)] = 5187,
+  [SMALL_STATE(199)] = 5206,
+  [SMALL_STATE(200)] = 5225,
+  [SMALL_STATE(201)] = 5242,
+  [SMALL_STATE(202)] = 5259,
+  [SMALL_STATE(203)] = 5272,
+  [SMALL_STATE(204)] = 5287,
+  [SMALL_STATE(205)] = 5300,
+  [SMALL_STATE(206)] = 5319,
+  [SMALL_STATE(207)] = 5334,
+  [SMALL_STATE(208)] = 5353,
+  [SMALL_STATE(209)] = 5370,
+  [SMALL_STATE(210)] = 5389,
+  [SMALL_STATE(211)] = 5404,
+  [SMALL_STATE(212)] = 5419,
+  [SMALL_STATE(213)] = 5434,
+  [SMALL_STATE(214)] = 5453,
+  [SMALL_STATE(215)] = 5466,
+  [SMALL_STATE(216)] = 5481,
+  [SMALL_STATE(217)] = 5498,
+  [SMALL_STATE(218)] = 5515,
+  [SMALL_STATE(219)] = 5532,
+  [SMALL_STATE(220)] = 5549,
+  [SMALL_STATE(221)] = 5564,
+  [SMALL_STATE(222)] = 5581,
+  [SMALL_STATE(223)] = 5594,
+  [SMALL_STATE(224)] = 5611,
+  [SMALL_STATE(225)] = 5626,
+  [SMALL_STATE(226)] = 5645,
+  [SMALL_STATE(227)] = 5658,
+  [SMALL_STATE(228)] = 5673,
+  [SMALL_STATE(229)] = 5688,
+  [SMALL_STATE(230)] = 5705,
+  [SMALL_STATE(231)] = 5720,
+  [SMALL_STATE(232)] = 5737,
+  [SMALL_STATE(233)] = 5754,
+  [SMALL_STATE(234)] = 5771,
+  [SMALL_STATE(235)] = 5790,
+  [SMALL_STATE(236)] = 5803,
+  [SMALL_STATE(237)] = 5815,
+  [SMALL_STATE(238)] = 5827,
+  [SMALL_STATE(239)] = 5841,
+  [SMALL_STATE(240)] = 5857,
+  [SMALL_STATE(241)] = 5873,
+  [SMALL_STATE(242)] = 5889,
+  [SMALL_STATE(243)] = 5903,
+  [SMALL_STATE(244)] = 5915,
+  [SMALL_STATE(245)] = 5931,
+  [SMALL_STATE(246)] = 5945,
+  [SMALL_STATE(247)] = 5959,
+  [SMALL_STATE(248)] = 5971,
+  [SMALL_STATE(249)] = 5985,
+  [SMALL_STATE(250)] = 5997,
+  [SMALL_STATE(251)] = 6009,
+  [SMALL_STATE(252)] = 6023,
+  [SMALL_STATE(253)] = 6039,
+  [SMALL_STATE(254)] = 6055,
+  [SMALL_STATE(255)] = 6071,
+  [SMALL_STATE(256)] = 6087,
+  [SMALL_STATE(257)] = 6100,
+  [SMALL_STATE(258)] = 6113,
+  [SMALL_STATE(259)] = 6126,
+  [SMALL_STATE(260)] = 6139,
+  [SMALL_STATE(261)] = 6152,
+  [SMALL_STATE(262)] = 6165,
+  [SMALL_STATE(263)] = 6178,
+  [SMALL_STATE(264)] = 6191,
+  [SMALL_STATE(265)] = 6202,
+  [SMALL_STATE(266)] = 6215,
+  [SMALL_STATE(267)] = 6228,
+  [SMALL_STATE(268)] = 6237,
+  [SMALL_STATE(269)] = 6248,
+  [SMALL_STATE(270)] = 6261,
+  [SMALL_STATE(271)] = 6270,
+  [SMALL_STATE(272)] = 6283,
+  [SMALL_STATE(273)] = 6296,
+  [SMALL_STATE(274)] = 6305,
+  [SMALL_STATE(275)] = 6314,
+  [SMALL_STATE(276)] = 6323,
+  [SMALL_STATE(277)] = 6332,
+  [SMALL_STATE(278)] = 6345,
+  [SMALL_STATE(279)] = 6358,
+  [SMALL_STATE(280)] = 6367,
+  [SMALL_STATE(281)] = 6380,
+  [SMALL_STATE(282)] = 6393,
+  [SMALL_STATE(283)] = 6406,
+  [SMALL_STATE(284)] = 6415,
+  [SMALL_STATE(285)] = 6428,
+  [SMALL_STATE(286)] = 6441,
+  [SMALL_STATE(287)] = 6454,
+  [SMALL_STATE(288)] = 6467,
+  [SMALL_STATE(289)] = 6480,
+  [SMALL_STATE(290)] = 6489,
+  [SMALL_STATE(291)] = 6498,
+  [SMALL_STATE(292)] = 6507,
+  [SMALL_STATE(293)] = 6516,
+  [SMALL_STATE(294)] = 6529,
+  [SMALL_STATE(295)] = 6542,
+  [SMALL_STATE(296)] = 6551,
+  [SMALL_STATE(297)] = 6560,
+  [SMALL_STATE(298)] = 6571,
+  [SMALL_STATE(299)] = 6580,
+  [SMALL_STATE(300)] = 6589,
+  [SMALL_STATE(301)] = 6600,
+  [SMALL_STATE(302)] = 6613,
+  [SMALL_STATE(303)] = 6626,
+  [SMALL_STATE(304)] = 6635,
+  [SMALL_STATE(305)] = 6648,
+  [SMALL_STATE(306)] = 6657,
+  [SMALL_STATE(307)] = 6667,
+  [SMALL_STATE(308)] = 6677,
+  [SMALL_STATE(309)] = 6687,
+  [SMALL_STATE(310)] = 6695,
+  [SMALL_STATE(311)] = 6705,
+  [SMALL_STATE(312)] = 6713,
+  [SMALL_STATE(313)] = 6723,
+  [SMALL_STATE(314)] = 6731,
+  [SMALL_STATE(315)] = 6741,
+  [SMALL_STATE(316)] = 6751,
+  [SMALL_STATE(317)] = 6759,
+  [SMALL_STATE(318)] = 6767,
+  [SMALL_STATE(319)] = 6777,
+  [SMALL_STATE(320)] = 6787,
+  [SMALL_STATE(321)] = 6797,
+  [SMALL_STATE(322)] = 6807,
+  [SMALL_STATE(323)] = 6817,
+  [SMALL_STATE(324)] = 6827,
+  [SMALL_STATE(325)] = 6837,
+  [SMALL_STATE(326)] = 6847,
+  [SMALL_STATE(327)] = 6857,
+  [SMALL_STATE(328)] = 6865,
+  [SMALL_STATE(329)] = 6875,
+  [SMALL_STATE(330)] = 6885,
+  [SMALL_STATE(331)] = 6895,
+  [SMALL_STATE(332)] = 6903,
+  [SMALL_STATE(333)] = 6911,
+  [SMALL_STATE(334)] = 6921,
+  [SMALL_STATE(335)] = 6931,
+  [SMALL_STATE(336)] = 6941,
+  [SMALL_STATE(337)] = 6951,
+  [SMALL_STATE(338)] = 6961,
+  [SMALL_STATE(339)] = 6971,
+  [SMALL_STATE(340)] = 6979,
+  [SMALL_STATE(341)] = 6989,
+  [SMALL_STATE(342)] = 6999,
+  [SMALL_STATE(343)] = 7009,
+  [SMALL_STATE(344)] = 7017,
+  [SMALL_STATE(345)] = 7025,
+  [SMALL_STATE(346)] = 7033,
+  [SMALL_STATE(347)] = 7043,
+  [SMALL_STATE(348)] = 7053,
+  [SMALL_STATE(349)] = 7061,
+  [SMALL_STATE(350)] = 7071,
+  [SMALL_STATE(351)] = 7081,
+  [SMALL_STATE(352)] = 7091,
+  [SMALL_STATE(353)] = 7101,
+  [SMALL_STATE(354)] = 7109,
+  [SMALL_STATE(355)] = 7119,
+  [SMALL_STATE(356)] = 7129,
+  [SMALL_STATE(357)] = 7137,
+  [SMALL_STATE(358)] = 7147,
+  [SMALL_STATE(359)] = 7157,
+  [SMALL_STATE(360)] = 7167,
+  [SMALL_STATE(361)] = 7177,
+  [SMALL_STATE(362)] = 7187,
+  [SMALL_STATE(363)] = 7197,
+  [SMALL_STATE(364)] = 7204,
+  [SMALL_STATE(365)] = 7211,
+  [SMALL_STATE(366)] = 7218,
+  [SMALL_STATE(367)] = 7225,
+  [SMALL_STATE(368)] = 7232,
+  [SMALL_STATE(369)] = 7239,
+  [SMALL_STATE(370)] = 7246,
+  [SMALL_STATE(371)] = 7253,
+  [SMALL_STATE(372)] = 7260,
+  [SMALL_STATE(373)] = 7267,
+  [SMALL_STATE(374)] = 7274,
+  [SMALL_STATE(375)] = 7281,
+  [SMALL_STATE(376)] = 7288,
+  [SMALL_STATE(377)] = 7295,
+  [SMALL_STATE(378)] = 7302,
+  [SMALL_STATE(379)] = 7309,
+  [SMALL_STATE(380)] = 7316,
+  [SMALL_STATE(381)] = 7323,
+  [SMALL_STATE(382)] = 7330,
+  [SMALL_STATE(383)] = 7337,
+  [SMALL_STATE(384)] = 7344,
+  [SMALL_STATE(385)] = 7351,
+  [SMALL_STATE(386)] = 7358,
+  [SMALL_STATE(387)] = 7365,
+  [SMALL_STATE(388)] = 7372,
+  [SMALL_STATE(389)] = 7379,
+  [SMALL_STATE(390)] = 7386,
+  [SMALL_STATE(391)] = 7393,
+  [SMALL_STATE(392)] = 7400,
+  [SMALL_STATE(393)] = 7407,
+  [SMALL_STATE(394)] = 7414,
+  [SMALL_STATE(395)] = 7421,
+  [SMALL_STATE(396)] = 7428,
+  [SMALL_STATE(397)] = 7435,
+  [SMALL_STATE(398)] = 7442,
+  [SMALL_STATE(399)] = 7449,
+  [SMALL_STATE(400)] = 7456,
+  [SMALL_STATE(401)] = 7463,
+  [SMALL_STATE(402)] = 7470,
+  [SMALL_STATE(403)] = 7477,
+  [SMALL_STATE(404)] = 7484,
+  [SMALL_STATE(405)] = 7491,
+  [SMALL_STATE(406)] = 7498,
+  [SMALL_STATE(407)] = 7505,
+  [SMALL_STATE(408)] = 7512,
+  [SMALL_STATE(409)] = 7519,
+  [SMALL_STATE(410)] = 7526,
+  [SMALL_STATE(411)] = 7533,
+  [SMALL_STATE(412)] = 7540,
+  [SMALL_STATE(413)] = 7547,
+  [SMALL_STATE(414)] = 7554,
+  [SMALL_STATE(415)] = 7561,
+  [SMALL_STATE(416)] = 7568,
+  [SMALL_STATE(417)] = 7575,
+  [SMALL_STATE(418)] = 7582,
+  [SMALL_STATE(419)] = 7589,
+  [SMALL_STATE(420)] = 7596,
+  [SMALL_STATE(421)] = 7603,
+  [SMALL_STATE(422)] = 7610,
+  [SMALL_STATE(423)] = 7617,
+  [SMALL_STATE(424)] = 7624,
+  [SMALL_STATE(425)] = 7631,
+  [SMALL_STATE(426)] = 7638,
+  [SMALL_STATE(427)] = 7645,
+  [SMALL_STATE(428)] = 7652,
+  [SMALL_STATE(429)] = 7659,
+  [SMALL_STATE(430)] = 7666,
+  [SMALL_STATE(431)] = 7673,
+  [SMALL_STATE(432)] = 7680,
+  [SMALL_STATE(433)] = 7687,
+  [SMALL_STATE(434)] = 7694,
+  [SMALL_STATE(435)] = 7701,
+  [SMALL_STATE(436)] = 7708,
+  [SMALL_STATE(437)] = 7715,
+  [SMALL_STATE(438)] = 7722,
+  [SMALL_STATE(439)] = 7729,
+  [SMALL_STATE(440)] = 7736,
+  [SMALL_STATE(441)] = 7743,
+  [SMALL_STATE(442)] = 7750,
+  [SMALL_STATE(443)] = 7757,
+  [SMALL_STATE(444)] = 7764,
+  [SMALL_STATE(445)] = 7771,
+  [SMALL_STATE(446)] = 7778,
+  [SMALL_STATE(447)] = 7785,
+  [SMALL_STATE(448)] = 7792,
+  [SMALL_STATE(449)] = 7799,
+  [SMALL_STATE(450)] = 7806,
+  [SMALL_STATE(451)] = 7813,
+  [SMALL_STATE(452)] = 7820,
+  [SMALL_STATE(453)] = 7827,
+  [SMALL_STATE(454)] = 7834,
+  [SMALL_STATE(455)] = 7841,
+  [SMALL_STATE(456)] = 7848,
+  [SMALL_STATE(457)] = 7855,
+  [SMALL_STATE(458)] = 7862,
+  [SMALL_STATE(459)] = 7869,
+  [SMALL_STATE(460)] = 7876,
+  [SMALL_STATE(461)] = 7883,
+  [SMALL_STATE(462)] = 7890,
+  [SMALL_STATE(463)] = 7897,
+  [SMALL_STATE(464)] = 7904,
+  [SMALL_STATE(465)] = 7911,
+  [SMALL_STATE(466)] = 7918,
+  [SMALL_STATE(467)] = 7925,
+  [SMALL_STATE(468)] = 7932,
+  [SMALL_STATE(469)] = 7939,
+  [SMALL_STATE(470)] = 7946,
+  [SMALL_STATE(471)] = 7953,
+  [SMALL_STATE(472)] = 7960,
+  [SMALL_STATE(473)] = 7967,
+  [SMALL_STATE(474)] = 7974,
+  [SMALL_STATE(475)] = 7981,
+  [SMALL_STATE(476)] = 7988,
+  [SMALL_STATE(477)] = 7995,
+  [SMALL_STATE(478)] = 8002,
+  [SMALL_STATE(479)] = 8009,
+  [SMALL_STATE(480)] = 8016,
+  [SMALL_STATE(481)] = 8023,
+  [SMALL_STATE(482)] = 8030,
+  [SMALL_STATE(483)] = 8037,
+  [SMALL_STATE(484)] = 8044,
+  [SMALL_STATE(485)] = 8051,
+  [SMALL_STATE(486)] = 8058,
+  [SMALL_STATE(487)] = 8065,
+  [SMALL_STATE(488)] = 8072,
+  [SMALL_STATE(489)] = 8079,
+  [SMALL_STATE(490)] = 8086,
+  [SMALL_STATE(491)] = 8093,
+  [SMALL_STATE(492)] = 8100,
+  [SMALL_STATE(493)] = 8107,
+  [SMALL_STATE(494)] = 8114,
+  [SMALL_STATE(495)] = 8121,
+  [SMALL_STATE(496)] = 8128,
+  [SMALL_STATE(497)] = 8135,
+  [SMALL_STATE(498)] = 8142,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
   [0] = {.entry = {.count = 0, .reusable = false}},
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
-  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT(351),
-  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(461),
-  [7] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 0),
-  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(459),
-  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(458),
-  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(457),
-  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
-  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(455),
-  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(454),
-  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
-  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(46),
-  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(70),
-  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(331),
-  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(330),
-  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(329),
-  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(254),
-  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(248),
-  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(128),
-  [39] = {.entry = {.count = 1, .reusable = false}}, SHIFT(419),
-  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(423),
-  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(327),
-  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
-  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
-  [49] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(46),
-  [52] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_seq_repeat1, 2),
-  [54] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(331),
-  [57] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(330),
-  [60] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(329),
-  [63] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(254),
-  [66] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(248),
-  [69] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(128),
-  [72] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(419),
-  [75] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(423),
-  [78] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 2), SHIFT_REPEAT(327),
-  [81] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
-  [83] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
-  [85] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
-  [87] = {.entry = {.count = 1, .reusable = true}}, SHIFT(42),
-  [89] = {.entry = {.count = 1, .reusable = true}}, SHIFT(60),
-  [91] = {.entry = {.count = 1, .reusable = false}}, SHIFT(202),
-  [93] = {.entry = {.count = 1, .reusable = false}}, SHIFT(207),
-  [95] = {.entry = {.count = 1, .reusable = false}}, SHIFT(148),
-  [97] = {.entry = {.count = 1, .reusable = false}}, SHIFT(328),
-  [99] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1),
-  [101] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port, 3),
-  [103] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_port, 3),
-  [105] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2),
-  [107] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port, 1),
-  [109] = {.entry = {.count = 1, .reusable = true}}, SHIFT(368),
-  [111] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_port, 1),
-  [113] = {.entry = {.count = 1, .reusable = true}}, SHIFT(371),
-  [115] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
-  [117] = {.entry = {.count = 1, .reusable = false}}, SHIFT(337),
-  [119] = {.entry = {.count = 1, .reusable = false}}, SHIFT(336),
-  [121] = {.entry = {.count = 1, .reusable = true}}, SHIFT(408),
-  [123] = {.entry = {.count = 1, .reusable = false}}, SHIFT(23),
-  [125] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
-  [127] = {.entry = {.count = 1, .reusable = false}}, SHIFT(399),
-  [129] = {.entry = {.count = 1, .reusable = false}}, SHIFT(400),
-  [131] = {.entry = {.count = 1, .reusable = false}}, SHIFT(402),
-  [133] = {.entry = {.count = 1, .reusable = false}}, SHIFT(205),
-  [135] = {.entry = {.count = 1, .reusable = false}}, SHIFT(206),
-  [137] = {.entry = {.count = 1, .reusable = false}}, SHIFT(126),
-  [139] = {.entry = {.count = 1, .reusable = false}}, SHIFT(384),
-  [141] = {.entry = {.count = 1, .reusable = false}}, SHIFT(403),
-  [143] = {.entry = {.count = 1, .reusable = false}}, SHIFT(255),
-  [145] = {.entry = {.count = 1, .reusable = false}}, SHIFT(222),
-  [147] = {.entry = {.count = 1, .reusable = false}}, SHIFT(136),
-  [149] = {.entry = {.count = 1, .reusable = false}}, SHIFT(452),
-  [151] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2),
-  [153] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(457),
-  [156] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(188),
-  [159] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(455),
-  [162] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(454),
-  [165] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(150),
-  [168] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2),
-  [170] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_io_port_repeat1, 2),
-  [172] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2), SHIFT_REPEAT(423),
-  [175] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
-  [177] = {.entry = {.count = 1, .reusable = true}}, SHIFT(105),
-  [179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(23),
-  [181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(418),
-  [183] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 4),
-  [185] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal, 4),
-  [187] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 3),
-  [189] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 3),
-  [191] = {.entry = {.count = 1, .reusable = false}}, SHIFT(95),
-  [193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_wires_inner_repeat1, 2),
-  [195] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(337),
-  [198] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(336),
-  [201] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(408),
-  [204] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(23),
-  [207] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 4),
-  [209] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 4),
-  [211] = {.entry = {.count = 1, .reusable = false}}, SHIFT(99),
-  [213] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3),
-  [215] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 3),
-  [217] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 5),
-  [219] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 5),
-  [221] = {.entry = {.count = 1, .reusable = false}}, SHIFT(98),
-  [223] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_hole, 4),
-  [225] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_hole, 4),
-  [227] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wires_inner, 1),
-  [229] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_attribute, 2),
-  [231] = {.entry = {.count = 1, .reusable = true}}, SHIFT(444),
-  [233] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_attribute, 2),
-  [235] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_par, 5),
-  [237] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_par, 5),
-  [239] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_repeat_stmt, 4),
-  [241] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_repeat_stmt, 4),
-  [243] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_stmt, 4),
-  [245] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_while_stmt, 4),
-  [247] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_stmt, 1),
-  [249] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_stmt, 1),
-  [251] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enable, 1),
-  [253] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enable, 1),
-  [255] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expr, 3),
-  [257] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expr, 3),
-  [259] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cmp_expr, 3),
-  [261] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cmp_expr, 3),
-  [263] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
-  [265] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
-  [267] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 9),
-  [269] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 9),
-  [271] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
-  [273] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
-  [275] = {.entry = {.count = 1, .reusable = false}}, SHIFT(89),
-  [277] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_repeat_stmt, 3),
-  [279] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_repeat_stmt, 3),
-  [281] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enable, 2),
-  [283] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enable, 2),
-  [285] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 5),
-  [287] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 5),
-  [289] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 6),
-  [291] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 6),
-  [293] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_seq, 6),
-  [295] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_seq, 6),
-  [297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_seq, 5),
-  [299] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_seq, 5),
-  [301] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_stmt, 3),
-  [303] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_while_stmt, 3),
-  [305] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 7),
-  [307] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 7),
-  [309] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enable, 3),
-  [311] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enable, 3),
-  [313] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_repeat_stmt, 5),
-  [315] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_repeat_stmt, 5),
-  [317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_base_expr, 1),
-  [319] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_base_expr, 1),
-  [321] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expr, 1),
-  [323] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expr, 1),
-  [325] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_par, 4),
-  [327] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_par, 4),
-  [329] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_par, 6),
-  [331] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_par, 6),
-  [333] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_par, 3),
-  [335] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_par, 3),
-  [337] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_seq, 4),
-  [339] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_seq, 4),
-  [341] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 8),
-  [343] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 8),
-  [345] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 7),
-  [347] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 7),
-  [349] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_seq_repeat1, 1),
-  [351] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_seq_repeat1, 1),
-  [353] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_seq, 3),
-  [355] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_seq, 3),
-  [357] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 6),
-  [359] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 6),
-  [361] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 10),
-  [363] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 10),
-  [365] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 1),
-  [367] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_io_port_repeat1, 1),
-  [369] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
-  [371] = {.entry = {.count = 1, .reusable = false}}, SHIFT(90),
-  [373] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
-  [375] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
-  [377] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_term, 1),
-  [379] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
-  [381] = {.entry = {.count = 1, .reusable = true}}, SHIFT(244),
-  [383] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2),
-  [385] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2), SHIFT_REPEAT(459),
-  [388] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
-  [390] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_attribute, 5),
-  [392] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_attribute, 5),
-  [394] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_group_repeat1, 2),
-  [396] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_group_repeat1, 2), SHIFT_REPEAT(408),
-  [399] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_group_repeat1, 2), SHIFT_REPEAT(23),
-  [402] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
-  [404] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
-  [406] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
-  [408] = {.entry = {.count = 1, .reusable = true}}, SHIFT(426),
-  [410] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
-  [412] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
-  [414] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
-  [416] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
-  [418] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
-  [420] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
-  [422] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 7),
-  [424] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
-  [426] = {.entry = {.count = 1, .reusable = false}}, SHIFT(356),
-  [428] = {.entry = {.count = 1, .reusable = true}}, SHIFT(413),
-  [430] = {.entry = {.count = 1, .reusable = false}}, SHIFT(339),
-  [432] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 6),
-  [434] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 5),
-  [436] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import, 3),
-  [438] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 4),
-  [440] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2),
-  [442] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2), SHIFT_REPEAT(188),
-  [445] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2), SHIFT_REPEAT(455),
-  [448] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2), SHIFT_REPEAT(150),
-  [451] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 1),
-  [453] = {.entry = {.count = 1, .reusable = true}}, SHIFT(120),
-  [455] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 8),
-  [457] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern, 5),
-  [459] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
-  [461] = {.entry = {.count = 1, .reusable = true}}, SHIFT(407),
-  [463] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern, 4),
-  [465] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 8),
-  [467] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_term, 2),
-  [469] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_latency_annotation, 3),
-  [471] = {.entry = {.count = 1, .reusable = true}}, SHIFT(433),
-  [473] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_static_annotation, 1),
-  [475] = {.entry = {.count = 1, .reusable = true}}, SHIFT(438),
-  [477] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_term, 3),
-  [479] = {.entry = {.count = 1, .reusable = true}}, SHIFT(452),
-  [481] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
-  [483] = {.entry = {.count = 1, .reusable = true}}, SHIFT(383),
-  [485] = {.entry = {.count = 1, .reusable = true}}, SHIFT(382),
-  [487] = {.entry = {.count = 1, .reusable = true}}, SHIFT(381),
-  [489] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 9),
-  [491] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
-  [493] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
-  [495] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
-  [497] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_static_annotation, 2),
-  [499] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
-  [501] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 6),
-  [503] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
-  [505] = {.entry = {.count = 1, .reusable = true}}, SHIFT(456),
-  [507] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 5),
-  [509] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_io_port_list_repeat1, 2),
-  [511] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_list_repeat1, 2), SHIFT_REPEAT(408),
-  [514] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_list_repeat1, 2), SHIFT_REPEAT(407),
-  [517] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
-  [519] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 1),
-  [521] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_cells_inner_repeat1, 2),
-  [523] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_cells_inner_repeat1, 2), SHIFT_REPEAT(356),
-  [526] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_cells_inner_repeat1, 2), SHIFT_REPEAT(413),
-  [529] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_cells_inner_repeat1, 2), SHIFT_REPEAT(339),
-  [532] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 10),
-  [534] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 7),
-  [536] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
-  [538] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cells_inner, 1),
-  [540] = {.entry = {.count = 1, .reusable = true}}, SHIFT(384),
-  [542] = {.entry = {.count = 1, .reusable = true}}, SHIFT(255),
-  [544] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
-  [546] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wire_assignment, 5),
-  [548] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_wire_assignment, 5),
-  [550] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_wires_inner_repeat1, 1),
-  [552] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 1),
-  [554] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arg_list, 3),
-  [556] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arg_list, 3),
-  [558] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group, 5),
-  [560] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group, 5),
-  [562] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group, 6),
-  [564] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group, 6),
-  [566] = {.entry = {.count = 1, .reusable = true}}, SHIFT(199),
-  [568] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 5),
-  [570] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 5),
-  [572] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group, 4),
-  [574] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group, 4),
-  [576] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arg_list, 2),
-  [578] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arg_list, 2),
-  [580] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2), SHIFT_REPEAT(426),
-  [583] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
-  [585] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 4),
-  [587] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 4),
-  [589] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
-  [591] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wire_assignment, 4),
-  [593] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_wire_assignment, 4),
-  [595] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
-  [597] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 3),
-  [599] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 3),
-  [601] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group, 7),
-  [603] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group, 7),
-  [605] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_instantiation, 2),
-  [607] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_instantiation, 2),
-  [609] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
-  [611] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
-  [613] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
-  [615] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
-  [617] = {.entry = {.count = 1, .reusable = true}}, SHIFT(404),
-  [619] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
-  [621] = {.entry = {.count = 1, .reusable = true}}, SHIFT(390),
-  [623] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2), SHIFT_REPEAT(408),
-  [626] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_cells_inner_repeat1, 1),
-  [628] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_cells_inner_repeat1, 1),
-  [630] = {.entry = {.count = 1, .reusable = true}}, SHIFT(388),
-  [632] = {.entry = {.count = 1, .reusable = true}}, SHIFT(386),
-  [634] = {.entry = {.count = 1, .reusable = true}}, SHIFT(399),
-  [636] = {.entry = {.count = 1, .reusable = true}}, SHIFT(400),
-  [638] = {.entry = {.count = 1, .reusable = true}}, SHIFT(402),
-  [640] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_attributes_repeat1, 2),
-  [642] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_attributes_repeat1, 2), SHIFT_REPEAT(404),
-  [645] = {.entry = {.count = 1, .reusable = true}}, SHIFT(393),
-  [647] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comb_or_static, 1),
-  [649] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
-  [651] = {.entry = {.count = 1, .reusable = true}}, SHIFT(333),
-  [653] = {.entry = {.count = 1, .reusable = true}}, SHIFT(446),
-  [655] = {.entry = {.count = 1, .reusable = true}}, SHIFT(443),
-  [657] = {.entry = {.count = 1, .reusable = true}}, SHIFT(451),
-  [659] = {.entry = {.count = 1, .reusable = true}}, SHIFT(358),
-  [661] = {.entry = {.count = 1, .reusable = true}}, SHIFT(215),
-  [663] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
-  [665] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 1),
-  [667] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 6),
-  [669] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 6),
-  [671] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
-  [673] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
-  [675] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arg_list_repeat1, 1),
-  [677] = {.entry = {.count = 1, .reusable = true}}, SHIFT(100),
-  [679] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
-  [681] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cells, 4),
-  [683] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
-  [685] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_args, 2),
-  [687] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
-  [689] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
-  [691] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
-  [693] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
-  [695] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
-  [697] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_args, 3),
-  [699] = {.entry = {.count = 1, .reusable = false}}, SHIFT(351),
-  [701] = {.entry = {.count = 1, .reusable = false}}, SHIFT(461),
-  [703] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_primitive_blob_repeat1, 2),
-  [705] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_primitive_blob_repeat1, 2), SHIFT_REPEAT(309),
-  [708] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_invoke_args_repeat1, 2), SHIFT_REPEAT(390),
-  [711] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_invoke_ref_args_repeat1, 2), SHIFT_REPEAT(386),
-  [714] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attributes, 4),
-  [716] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port_list, 4),
-  [718] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
-  [720] = {.entry = {.count = 1, .reusable = false}}, SHIFT(348),
-  [722] = {.entry = {.count = 1, .reusable = false}}, SHIFT(309),
-  [724] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arg_list_repeat1, 2),
-  [726] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_arg_list_repeat1, 2), SHIFT_REPEAT(201),
-  [729] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_args, 4),
-  [731] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attributes, 3),
-  [733] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port_list, 3),
-  [735] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
-  [737] = {.entry = {.count = 1, .reusable = false}}, SHIFT(364),
-  [739] = {.entry = {.count = 1, .reusable = true}}, SHIFT(428),
-  [741] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_group_repeat1, 1),
-  [743] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attributes, 2),
-  [745] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port_list, 2),
-  [747] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
-  [749] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
-  [751] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cells, 3),
-  [753] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port, 3),
-  [755] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 3),
-  [757] = {.entry = {.count = 1, .reusable = true}}, SHIFT(431),
-  [759] = {.entry = {.count = 1, .reusable = true}}, SHIFT(430),
-  [761] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comb_or_static, 2),
-  [763] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
-  [765] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
-  [767] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
-  [769] = {.entry = {.count = 1, .reusable = true}}, SHIFT(282),
-  [771] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
-  [773] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
-  [775] = {.entry = {.count = 1, .reusable = true}}, SHIFT(290),
-  [777] = {.entry = {.count = 1, .reusable = true}}, SHIFT(369),
-  [779] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
-  [781] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
-  [783] = {.entry = {.count = 1, .reusable = true}}, SHIFT(256),
-  [785] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
-  [787] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
-  [789] = {.entry = {.count = 1, .reusable = true}}, SHIFT(392),
-  [791] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
-  [793] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
-  [795] = {.entry = {.count = 1, .reusable = true}}, SHIFT(225),
-  [797] = {.entry = {.count = 1, .reusable = true}}, SHIFT(346),
-  [799] = {.entry = {.count = 1, .reusable = true}}, SHIFT(347),
-  [801] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
-  [803] = {.entry = {.count = 1, .reusable = true}}, SHIFT(350),
-  [805] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port_with, 1),
-  [807] = {.entry = {.count = 1, .reusable = true}}, SHIFT(435),
-  [809] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
-  [811] = {.entry = {.count = 1, .reusable = true}}, SHIFT(387),
-  [813] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_signature, 3),
-  [815] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_ref_arg, 3),
-  [817] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wires, 4),
-  [819] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_arg, 3),
-  [821] = {.entry = {.count = 1, .reusable = true}}, SHIFT(341),
-  [823] = {.entry = {.count = 1, .reusable = true}}, SHIFT(88),
-  [825] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_params_repeat1, 2), SHIFT_REPEAT(349),
-  [828] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_primitive_blob_repeat1, 1),
-  [830] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
-  [832] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
-  [834] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port, 4),
-  [836] = {.entry = {.count = 1, .reusable = true}}, SHIFT(372),
-  [838] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wires, 3),
-  [840] = {.entry = {.count = 1, .reusable = false}}, SHIFT(365),
-  [842] = {.entry = {.count = 1, .reusable = false}}, SHIFT(363),
-  [844] = {.entry = {.count = 1, .reusable = true}}, SHIFT(231),
-  [846] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
-  [848] = {.entry = {.count = 1, .reusable = true}}, SHIFT(375),
-  [850] = {.entry = {.count = 1, .reusable = true}}, SHIFT(343),
-  [852] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
-  [854] = {.entry = {.count = 1, .reusable = true}}, SHIFT(289),
-  [856] = {.entry = {.count = 1, .reusable = true}}, SHIFT(8),
-  [858] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
-  [860] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
-  [862] = {.entry = {.count = 1, .reusable = true}}, SHIFT(29),
-  [864] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
-  [866] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lhs, 1),
-  [868] = {.entry = {.count = 1, .reusable = true}}, SHIFT(302),
-  [870] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
-  [872] = {.entry = {.count = 1, .reusable = true}}, SHIFT(366),
-  [874] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
-  [876] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
-  [878] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_switch, 3),
-  [880] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_params, 4),
-  [882] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_invoke_args_repeat1, 2),
-  [884] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
-  [886] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
-  [888] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_invoke_ref_args_repeat1, 2),
-  [890] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_ref_args, 3),
-  [892] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive_blob, 3),
-  [894] = {.entry = {.count = 1, .reusable = true}}, SHIFT(376),
-  [896] = {.entry = {.count = 1, .reusable = false}}, SHIFT(441),
-  [898] = {.entry = {.count = 1, .reusable = true}}, SHIFT(363),
-  [900] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_control_inner, 1),
-  [902] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
-  [904] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
-  [906] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
-  [908] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
-  [910] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive_blob, 2),
-  [912] = {.entry = {.count = 1, .reusable = true}}, SHIFT(406),
-  [914] = {.entry = {.count = 1, .reusable = true}}, SHIFT(218),
-  [916] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_params, 3),
-  [918] = {.entry = {.count = 1, .reusable = true}}, SHIFT(415),
-  [920] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_params_repeat1, 2),
-  [922] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
-  [924] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_ref_args, 4),
-  [926] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
-  [928] = {.entry = {.count = 1, .reusable = true}}, SHIFT(385),
-  [930] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
-  [932] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port_with, 3),
-  [934] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
-  [936] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
-  [938] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
-  [940] = {.entry = {.count = 1, .reusable = true}}, SHIFT(267),
-  [942] = {.entry = {.count = 1, .reusable = true}}, SHIFT(345),
-  [944] = {.entry = {.count = 1, .reusable = true}}, SHIFT(391),
-  [946] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_ref_args, 2),
-  [948] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
-  [950] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
-  [952] = {.entry = {.count = 1, .reusable = true}}, SHIFT(52),
-  [954] = {.entry = {.count = 1, .reusable = true}}, SHIFT(326),
-  [956] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
-  [958] = {.entry = {.count = 1, .reusable = true}}, SHIFT(395),
-  [960] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_control, 4),
-  [962] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
-  [964] = {.entry = {.count = 1, .reusable = true}}, SHIFT(4),
-  [966] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
-  [968] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
-  [970] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
-  [972] = {.entry = {.count = 1, .reusable = true}}, SHIFT(389),
-  [974] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
-  [976] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
-  [978] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
-  [980] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3),
-  [982] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
-  [984] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
-  [986] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
-  [988] = {.entry = {.count = 1, .reusable = true}}, SHIFT(283),
-  [990] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
-  [992] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
-  [994] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
-  [996] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
-  [998] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
-  [1000] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
-  [1002] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
-  [1004] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
-  [1006] = {.entry = {.count = 1, .reusable = true}}, SHIFT(47),
-  [1008] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
-  [1010] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
-  [1012] = {.entry = {.count = 1, .reusable = true}}, SHIFT(414),
-  [1014] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
-  [1016] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
-  [1018] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_metadata, 2),
-  [1020] = {.entry = {.count = 1, .reusable = true}}, SHIFT(410),
-  [1022] = {.entry = {.count = 1, .reusable = true}}, SHIFT(380),
-  [1024] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
-  [1026] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
-  [1028] = {.entry = {.count = 1, .reusable = true}}, SHIFT(308),
-  [1030] = {.entry = {.count = 1, .reusable = true}}, SHIFT(114),
-  [1032] = {.entry = {.count = 1, .reusable = true}}, SHIFT(460),
-  [1034] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
-  [1036] = {.entry = {.count = 1, .reusable = true}}, SHIFT(424),
-  [1038] = {.entry = {.count = 1, .reusable = true}}, SHIFT(427),
-  [1040] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
-  [1042] = {.entry = {.count = 1, .reusable = true}}, SHIFT(432),
-  [1044] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [1046] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
-  [1048] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
-  [1050] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
-  [1052] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
-  [1054] = {.entry = {.count = 1, .reusable = true}}, SHIFT(436),
-  [1056] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
+  [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 0),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(444),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(382),
+  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(397),
+  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(245),
+  [15] = {.entry = {.count = 1, .reusable = true}}, SHIFT(404),
+  [17] = {.entry = {.count = 1, .reusable = true}}, SHIFT(406),
+  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(415),
+  [21] = {.entry = {.count = 1, .reusable = true}}, SHIFT(87),
+  [23] = {.entry = {.count = 1, .reusable = true}}, SHIFT(66),
+  [25] = {.entry = {.count = 1, .reusable = true}}, SHIFT(459),
+  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(358),
+  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(393),
+  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(447),
+  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(377),
+  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(260),
+  [37] = {.entry = {.count = 1, .reusable = false}}, SHIFT(263),
+  [39] = {.entry = {.count = 1, .reusable = false}}, SHIFT(402),
+  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(485),
+  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(87),
+  [45] = {.entry = {.count = 1, .reusable = false}}, SHIFT(407),
+  [47] = {.entry = {.count = 1, .reusable = true}}, SHIFT(57),
+  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [51] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_control_inner, 1),
+  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(48),
+  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(50),
+  [57] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_control_inner_repeat1, 2), SHIFT_REPEAT(66),
+  [60] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_control_inner_repeat1, 2),
+  [62] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_control_inner_repeat1, 2), SHIFT_REPEAT(358),
+  [65] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_control_inner_repeat1, 2), SHIFT_REPEAT(393),
+  [68] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_control_inner_repeat1, 2), SHIFT_REPEAT(447),
+  [71] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_control_inner_repeat1, 2), SHIFT_REPEAT(377),
+  [74] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_control_inner_repeat1, 2), SHIFT_REPEAT(260),
+  [77] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_control_inner_repeat1, 2), SHIFT_REPEAT(263),
+  [80] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_control_inner_repeat1, 2), SHIFT_REPEAT(402),
+  [83] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_control_inner_repeat1, 2), SHIFT_REPEAT(485),
+  [86] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_control_inner_repeat1, 2), SHIFT_REPEAT(87),
+  [89] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_control_inner_repeat1, 2), SHIFT_REPEAT(407),
+  [92] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [94] = {.entry = {.count = 1, .reusable = true}}, SHIFT(58),
+  [96] = {.entry = {.count = 1, .reusable = true}}, SHIFT(43),
+  [98] = {.entry = {.count = 1, .reusable = true}}, SHIFT(61),
+  [100] = {.entry = {.count = 1, .reusable = true}}, SHIFT(45),
+  [102] = {.entry = {.count = 1, .reusable = true}}, SHIFT(64),
+  [104] = {.entry = {.count = 1, .reusable = true}}, SHIFT(65),
+  [106] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [108] = {.entry = {.count = 1, .reusable = true}}, SHIFT(72),
+  [110] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [112] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1),
+  [114] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
+  [116] = {.entry = {.count = 1, .reusable = false}}, SHIFT(418),
+  [118] = {.entry = {.count = 1, .reusable = false}}, SHIFT(320),
+  [120] = {.entry = {.count = 1, .reusable = false}}, SHIFT(379),
+  [122] = {.entry = {.count = 1, .reusable = true}}, SHIFT(371),
+  [124] = {.entry = {.count = 1, .reusable = false}}, SHIFT(39),
+  [126] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 2),
+  [128] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
+  [130] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_wires_inner_repeat1, 2),
+  [132] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(418),
+  [135] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(320),
+  [138] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(379),
+  [141] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(371),
+  [144] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(87),
+  [147] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_wires_inner_repeat1, 2), SHIFT_REPEAT(39),
+  [150] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wires_inner, 1),
+  [152] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
+  [154] = {.entry = {.count = 1, .reusable = true}}, SHIFT(40),
+  [156] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
+  [158] = {.entry = {.count = 1, .reusable = true}}, SHIFT(41),
+  [160] = {.entry = {.count = 1, .reusable = true}}, SHIFT(39),
+  [162] = {.entry = {.count = 1, .reusable = true}}, SHIFT(376),
+  [164] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port, 3),
+  [166] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_port, 3),
+  [168] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2),
+  [170] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(397),
+  [173] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(245),
+  [176] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(404),
+  [179] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(406),
+  [182] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(415),
+  [185] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat2, 2), SHIFT_REPEAT(87),
+  [188] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port, 1),
+  [190] = {.entry = {.count = 1, .reusable = true}}, SHIFT(384),
+  [192] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_port, 1),
+  [194] = {.entry = {.count = 1, .reusable = true}}, SHIFT(386),
+  [196] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 3),
+  [198] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 3),
+  [200] = {.entry = {.count = 1, .reusable = false}}, SHIFT(121),
+  [202] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 2),
+  [204] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 2),
+  [206] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 4),
+  [208] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 4),
+  [210] = {.entry = {.count = 1, .reusable = false}}, SHIFT(118),
+  [212] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_block, 3),
+  [214] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_block, 3),
+  [216] = {.entry = {.count = 1, .reusable = true}}, SHIFT(49),
+  [218] = {.entry = {.count = 1, .reusable = false}}, SHIFT(463),
+  [220] = {.entry = {.count = 1, .reusable = false}}, SHIFT(464),
+  [222] = {.entry = {.count = 1, .reusable = false}}, SHIFT(465),
+  [224] = {.entry = {.count = 1, .reusable = false}}, SHIFT(301),
+  [226] = {.entry = {.count = 1, .reusable = false}}, SHIFT(302),
+  [228] = {.entry = {.count = 1, .reusable = false}}, SHIFT(468),
+  [230] = {.entry = {.count = 1, .reusable = false}}, SHIFT(471),
+  [232] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 5),
+  [234] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 5),
+  [236] = {.entry = {.count = 1, .reusable = false}}, SHIFT(124),
+  [238] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_seq, 3),
+  [240] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_seq, 3),
+  [242] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enable, 2),
+  [244] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enable, 2),
+  [246] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_par, 3),
+  [248] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_par, 3),
+  [250] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_stmt, 3),
+  [252] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_while_stmt, 3),
+  [254] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_repeat_stmt, 3),
+  [256] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_repeat_stmt, 3),
+  [258] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enable, 3),
+  [260] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enable, 3),
+  [262] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_hole, 4),
+  [264] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_hole, 4),
+  [266] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 2),
+  [268] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal, 2),
+  [270] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_stmt, 4),
+  [272] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_for_stmt, 4),
+  [274] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_seq, 4),
+  [276] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_seq, 4),
+  [278] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_par, 4),
+  [280] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_par, 4),
+  [282] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_repeat_stmt, 4),
+  [284] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_repeat_stmt, 4),
+  [286] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_while_stmt, 4),
+  [288] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_while_stmt, 4),
+  [290] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_stmt, 5),
+  [292] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_for_stmt, 5),
+  [294] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 5),
+  [296] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 5),
+  [298] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_seq, 5),
+  [300] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_seq, 5),
+  [302] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_par, 5),
+  [304] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_par, 5),
+  [306] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_enable, 1),
+  [308] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_enable, 1),
+  [310] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_stmt, 1),
+  [312] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_stmt, 1),
+  [314] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_repeat_stmt, 5),
+  [316] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_repeat_stmt, 5),
+  [318] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 6),
+  [320] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 6),
+  [322] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 6),
+  [324] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 6),
+  [326] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_seq, 6),
+  [328] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_seq, 6),
+  [330] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_par, 6),
+  [332] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_par, 6),
+  [334] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 7),
+  [336] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 7),
+  [338] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_if_stmt, 7),
+  [340] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_if_stmt, 7),
+  [342] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 8),
+  [344] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 8),
+  [346] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 9),
+  [348] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 9),
+  [350] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke, 10),
+  [352] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_invoke, 10),
+  [354] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2),
+  [356] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_io_port_repeat1, 2),
+  [358] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2), SHIFT_REPEAT(485),
+  [361] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_logic_expr, 3),
+  [363] = {.entry = {.count = 1, .reusable = true}}, SHIFT(38),
+  [365] = {.entry = {.count = 1, .reusable = false}}, SHIFT(38),
+  [367] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2),
+  [369] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2), SHIFT_REPEAT(444),
+  [372] = {.entry = {.count = 1, .reusable = true}}, SHIFT(267),
+  [374] = {.entry = {.count = 1, .reusable = false}}, SHIFT(357),
+  [376] = {.entry = {.count = 1, .reusable = false}}, SHIFT(413),
+  [378] = {.entry = {.count = 1, .reusable = true}}, SHIFT(483),
+  [380] = {.entry = {.count = 1, .reusable = false}}, SHIFT(414),
+  [382] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_base_expr, 1),
+  [384] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_base_expr, 1),
+  [386] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_static_timing, 2),
+  [388] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_static_timing, 2),
+  [390] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_static_timing, 6),
+  [392] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_static_timing, 6),
+  [394] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_static_annotation, 1),
+  [396] = {.entry = {.count = 1, .reusable = true}}, SHIFT(375),
+  [398] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_not_expr, 2),
+  [400] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_not_expr, 2),
+  [402] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expr, 1),
+  [404] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expr, 1),
+  [406] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expr, 3),
+  [408] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expr, 3),
+  [410] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cmp_expr, 3),
+  [412] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cmp_expr, 3),
+  [414] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_attribute, 2),
+  [416] = {.entry = {.count = 1, .reusable = true}}, SHIFT(493),
+  [418] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_attribute, 2),
+  [420] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive_blob, 3),
+  [422] = {.entry = {.count = 1, .reusable = true}}, SHIFT(299),
+  [424] = {.entry = {.count = 1, .reusable = true}}, SHIFT(37),
+  [426] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
+  [428] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [430] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [432] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
+  [434] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 6),
+  [436] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [438] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cells_inner, 1),
+  [440] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [442] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_group_repeat1, 2),
+  [444] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_group_repeat1, 2), SHIFT_REPEAT(371),
+  [447] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_group_repeat1, 2), SHIFT_REPEAT(39),
+  [450] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
+  [452] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive_blob, 2),
+  [454] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 7),
+  [456] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [458] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 5),
+  [460] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [462] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [464] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_cells_inner_repeat1, 2),
+  [466] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_cells_inner_repeat1, 2), SHIFT_REPEAT(357),
+  [469] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_cells_inner_repeat1, 2), SHIFT_REPEAT(413),
+  [472] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_cells_inner_repeat1, 2), SHIFT_REPEAT(483),
+  [475] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_cells_inner_repeat1, 2), SHIFT_REPEAT(414),
+  [478] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
+  [480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
+  [482] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 4),
+  [484] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [486] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_at_attribute, 5),
+  [488] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_at_attribute, 5),
+  [490] = {.entry = {.count = 1, .reusable = true}}, SHIFT(298),
+  [492] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_width_expr, 3),
+  [494] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_width_expr, 3),
+  [496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
+  [498] = {.entry = {.count = 1, .reusable = false}}, SHIFT(206),
+  [500] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
+  [502] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
+  [504] = {.entry = {.count = 1, .reusable = true}}, SHIFT(487),
+  [506] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_static_annotation, 2),
+  [508] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_primitive, 8),
+  [510] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_import, 3),
+  [512] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_latency_annotation, 3),
+  [514] = {.entry = {.count = 1, .reusable = true}}, SHIFT(90),
+  [516] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [518] = {.entry = {.count = 1, .reusable = false}}, SHIFT(417),
+  [520] = {.entry = {.count = 1, .reusable = true}}, SHIFT(473),
+  [522] = {.entry = {.count = 1, .reusable = false}}, SHIFT(431),
+  [524] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arg_list, 3),
+  [526] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arg_list, 3),
+  [528] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arg_list, 2),
+  [530] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arg_list, 2),
+  [532] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 6),
+  [534] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 11),
+  [536] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
+  [538] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern, 5),
+  [540] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_bundle_repeat1, 2),
+  [542] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_bundle_repeat1, 2), SHIFT_REPEAT(417),
+  [545] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_bundle_repeat1, 2), SHIFT_REPEAT(473),
+  [548] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_bundle_repeat1, 2), SHIFT_REPEAT(431),
+  [551] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [553] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2),
+  [555] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2), SHIFT_REPEAT(245),
+  [558] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2), SHIFT_REPEAT(406),
+  [561] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_extern_repeat1, 2), SHIFT_REPEAT(87),
+  [564] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [566] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [568] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym__width, 1),
+  [570] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym__width, 1),
+  [572] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 9),
+  [574] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arg_list, 4),
+  [576] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arg_list, 4),
+  [578] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [580] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bundle, 6),
+  [582] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 5),
+  [584] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_extern, 4),
+  [586] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 8),
+  [588] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [590] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 10),
+  [592] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bundle, 4),
+  [594] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_arg_list, 5),
+  [596] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_arg_list, 5),
+  [598] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_component, 7),
+  [600] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bundle, 7),
+  [602] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bundle, 5),
+  [604] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
+  [606] = {.entry = {.count = 1, .reusable = true}}, SHIFT(293),
+  [608] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
+  [610] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [612] = {.entry = {.count = 1, .reusable = true}}, SHIFT(455),
+  [614] = {.entry = {.count = 1, .reusable = true}}, SHIFT(458),
+  [616] = {.entry = {.count = 1, .reusable = true}}, SHIFT(467),
+  [618] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
+  [620] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [622] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
+  [624] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [626] = {.entry = {.count = 1, .reusable = true}}, SHIFT(273),
+  [628] = {.entry = {.count = 1, .reusable = true}}, SHIFT(372),
+  [630] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [632] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wires_for, 4),
+  [634] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_wires_for, 4),
+  [636] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group, 4),
+  [638] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group, 4),
+  [640] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_io_port_list_repeat1, 2),
+  [642] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_list_repeat1, 2), SHIFT_REPEAT(371),
+  [645] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_list_repeat1, 2), SHIFT_REPEAT(372),
+  [648] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wire_assignment, 4),
+  [650] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_wire_assignment, 4),
+  [652] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
+  [654] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group, 5),
+  [656] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group, 5),
+  [658] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wires_for, 5),
+  [660] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_wires_for, 5),
+  [662] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wire_assignment, 5),
+  [664] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_wire_assignment, 5),
+  [666] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group, 6),
+  [668] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group, 6),
+  [670] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_group, 7),
+  [672] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_group, 7),
+  [674] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
+  [676] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [678] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
+  [680] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
+  [682] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [684] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [686] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_arg_list_repeat1, 2), SHIFT_REPEAT(220),
+  [689] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_arg_list_repeat1, 2), SHIFT_REPEAT(200),
+  [692] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
+  [694] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 3),
+  [696] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 3),
+  [698] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
+  [700] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
+  [702] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 4),
+  [704] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 4),
+  [706] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_instantiation, 2),
+  [708] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_instantiation, 2),
+  [710] = {.entry = {.count = 1, .reusable = true}}, SHIFT(222),
+  [712] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 5),
+  [714] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 5),
+  [716] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port, 4),
+  [718] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
+  [720] = {.entry = {.count = 1, .reusable = true}}, SHIFT(131),
+  [722] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
+  [724] = {.entry = {.count = 1, .reusable = true}}, SHIFT(301),
+  [726] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port, 3),
+  [728] = {.entry = {.count = 1, .reusable = true}}, SHIFT(129),
+  [730] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [732] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [734] = {.entry = {.count = 1, .reusable = true}}, SHIFT(279),
+  [736] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
+  [738] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_for, 4),
+  [740] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_for, 4),
+  [742] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [744] = {.entry = {.count = 1, .reusable = true}}, SHIFT(463),
+  [746] = {.entry = {.count = 1, .reusable = true}}, SHIFT(464),
+  [748] = {.entry = {.count = 1, .reusable = true}}, SHIFT(465),
+  [750] = {.entry = {.count = 1, .reusable = true}}, SHIFT(468),
+  [752] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
+  [754] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
+  [756] = {.entry = {.count = 1, .reusable = true}}, SHIFT(216),
+  [758] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
+  [760] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
+  [762] = {.entry = {.count = 1, .reusable = true}}, SHIFT(435),
+  [764] = {.entry = {.count = 1, .reusable = true}}, SHIFT(364),
+  [766] = {.entry = {.count = 1, .reusable = true}}, SHIFT(401),
+  [768] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
+  [770] = {.entry = {.count = 1, .reusable = true}}, SHIFT(448),
+  [772] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
+  [774] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
+  [776] = {.entry = {.count = 1, .reusable = false}}, SHIFT_EXTRA(),
+  [778] = {.entry = {.count = 1, .reusable = false}}, SHIFT(231),
+  [780] = {.entry = {.count = 1, .reusable = false}}, SHIFT(93),
+  [782] = {.entry = {.count = 1, .reusable = false}}, SHIFT(233),
+  [784] = {.entry = {.count = 1, .reusable = false}}, SHIFT(370),
+  [786] = {.entry = {.count = 1, .reusable = false}}, SHIFT(367),
+  [788] = {.entry = {.count = 1, .reusable = false}}, SHIFT(104),
+  [790] = {.entry = {.count = 1, .reusable = false}}, SHIFT(217),
+  [792] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [794] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_for_range, 5),
+  [796] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_assignment, 6),
+  [798] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_assignment, 6),
+  [800] = {.entry = {.count = 1, .reusable = false}}, SHIFT(378),
+  [802] = {.entry = {.count = 1, .reusable = false}}, SHIFT(368),
+  [804] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2), SHIFT_REPEAT(473),
+  [807] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2), SHIFT_REPEAT(483),
+  [810] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
+  [812] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
+  [814] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2), SHIFT_REPEAT(487),
+  [817] = {.entry = {.count = 1, .reusable = false}}, SHIFT(295),
+  [819] = {.entry = {.count = 1, .reusable = false}}, SHIFT(232),
+  [821] = {.entry = {.count = 1, .reusable = false}}, SHIFT(296),
+  [823] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_primitive_blob_repeat1, 2), SHIFT_REPEAT(231),
+  [826] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_primitive_blob_repeat1, 2),
+  [828] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_primitive_blob_repeat1, 2), SHIFT_REPEAT(233),
+  [831] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cell_for, 5),
+  [833] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cell_for, 5),
+  [835] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_arg_list_repeat1, 2),
+  [837] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bundle_field, 6),
+  [839] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_bundle_field, 6),
+  [841] = {.entry = {.count = 1, .reusable = true}}, SHIFT(428),
+  [843] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
+  [845] = {.entry = {.count = 1, .reusable = true}}, SHIFT(456),
+  [847] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
+  [849] = {.entry = {.count = 1, .reusable = true}}, SHIFT(255),
+  [851] = {.entry = {.count = 1, .reusable = true}}, SHIFT(290),
+  [853] = {.entry = {.count = 1, .reusable = true}}, SHIFT(489),
+  [855] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bundle_field, 5),
+  [857] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_bundle_field, 5),
+  [859] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_attributes_repeat1, 2),
+  [861] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_attributes_repeat1, 2), SHIFT_REPEAT(456),
+  [864] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comb_or_static, 1),
+  [866] = {.entry = {.count = 1, .reusable = true}}, SHIFT(492),
+  [868] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bundle_field, 4),
+  [870] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_bundle_field, 4),
+  [872] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_io_port_repeat1, 2), SHIFT_REPEAT(371),
+  [875] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
+  [877] = {.entry = {.count = 1, .reusable = true}}, SHIFT(494),
+  [879] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
+  [881] = {.entry = {.count = 1, .reusable = true}}, SHIFT(276),
+  [883] = {.entry = {.count = 1, .reusable = true}}, SHIFT(498),
+  [885] = {.entry = {.count = 1, .reusable = true}}, SHIFT(439),
+  [887] = {.entry = {.count = 1, .reusable = true}}, SHIFT(441),
+  [889] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [891] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
+  [893] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
+  [895] = {.entry = {.count = 1, .reusable = true}}, SHIFT(356),
+  [897] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cells, 3),
+  [899] = {.entry = {.count = 1, .reusable = true}}, SHIFT(412),
+  [901] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [903] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port_list, 3),
+  [905] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port_list, 2),
+  [907] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attributes, 3),
+  [909] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_args, 2),
+  [911] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [913] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_args, 3),
+  [915] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_invoke_args_repeat1, 2), SHIFT_REPEAT(498),
+  [918] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_invoke_ref_args_repeat1, 2), SHIFT_REPEAT(441),
+  [921] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
+  [923] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_io_port_list, 4),
+  [925] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attributes, 2),
+  [927] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_args, 4),
+  [929] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attributes, 4),
+  [931] = {.entry = {.count = 1, .reusable = true}}, SHIFT(332),
+  [933] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
+  [935] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_primitive_blob, 2),
+  [937] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_primitive_blob, 3),
+  [939] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cells, 4),
+  [941] = {.entry = {.count = 1, .reusable = true}}, SHIFT(385),
+  [943] = {.entry = {.count = 1, .reusable = true}}, SHIFT(388),
+  [945] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_bundle_type, 2),
+  [947] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
+  [949] = {.entry = {.count = 1, .reusable = true}}, SHIFT(270),
+  [951] = {.entry = {.count = 1, .reusable = true}}, SHIFT(289),
+  [953] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_params, 3),
+  [955] = {.entry = {.count = 1, .reusable = true}}, SHIFT(391),
+  [957] = {.entry = {.count = 1, .reusable = true}}, SHIFT(345),
+  [959] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_params_repeat1, 2), SHIFT_REPEAT(436),
+  [962] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wires, 3),
+  [964] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
+  [966] = {.entry = {.count = 1, .reusable = true}}, SHIFT(292),
+  [968] = {.entry = {.count = 1, .reusable = true}}, SHIFT(470),
+  [970] = {.entry = {.count = 1, .reusable = true}}, SHIFT(394),
+  [972] = {.entry = {.count = 1, .reusable = true}}, SHIFT(283),
+  [974] = {.entry = {.count = 1, .reusable = true}}, SHIFT(396),
+  [976] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [978] = {.entry = {.count = 1, .reusable = true}}, SHIFT(399),
+  [980] = {.entry = {.count = 1, .reusable = true}}, SHIFT(400),
+  [982] = {.entry = {.count = 1, .reusable = true}}, SHIFT(62),
+  [984] = {.entry = {.count = 1, .reusable = true}}, SHIFT(405),
+  [986] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_signature, 3),
+  [988] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
+  [990] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wires, 4),
+  [992] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_params, 2),
+  [994] = {.entry = {.count = 1, .reusable = true}}, SHIFT(291),
+  [996] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [998] = {.entry = {.count = 1, .reusable = true}}, SHIFT(432),
+  [1000] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
+  [1002] = {.entry = {.count = 1, .reusable = true}}, SHIFT(314),
+  [1004] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_arg, 3),
+  [1006] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_ref_arg, 3),
+  [1008] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_params, 4),
+  [1010] = {.entry = {.count = 1, .reusable = true}}, SHIFT(73),
+  [1012] = {.entry = {.count = 1, .reusable = true}}, SHIFT(445),
+  [1014] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_attribute, 3),
+  [1016] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port_with, 1),
+  [1018] = {.entry = {.count = 1, .reusable = true}}, SHIFT(452),
+  [1020] = {.entry = {.count = 1, .reusable = true}}, SHIFT(75),
+  [1022] = {.entry = {.count = 1, .reusable = true}}, SHIFT(451),
+  [1024] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comb_or_static, 2),
+  [1026] = {.entry = {.count = 1, .reusable = true}}, SHIFT(429),
+  [1028] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
+  [1030] = {.entry = {.count = 1, .reusable = true}}, SHIFT(96),
+  [1032] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
+  [1034] = {.entry = {.count = 1, .reusable = true}}, SHIFT(228),
+  [1036] = {.entry = {.count = 1, .reusable = true}}, SHIFT(349),
+  [1038] = {.entry = {.count = 1, .reusable = true}}, SHIFT(469),
+  [1040] = {.entry = {.count = 1, .reusable = true}}, SHIFT(268),
+  [1042] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [1044] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
+  [1046] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
+  [1048] = {.entry = {.count = 1, .reusable = true}}, SHIFT(462),
+  [1050] = {.entry = {.count = 1, .reusable = true}}, SHIFT(55),
+  [1052] = {.entry = {.count = 1, .reusable = true}}, SHIFT(7),
+  [1054] = {.entry = {.count = 1, .reusable = true}}, SHIFT(425),
+  [1056] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
   [1058] = {.entry = {.count = 1, .reusable = true}}, SHIFT(261),
-  [1060] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
+  [1060] = {.entry = {.count = 1, .reusable = true}}, SHIFT(30),
   [1062] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_metadata_ci, 1),
-  [1064] = {.entry = {.count = 1, .reusable = true}}, SHIFT(440),
-  [1066] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comment, 3),
-  [1068] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_comment, 1),
+  [1064] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_metadata, 2),
+  [1066] = {.entry = {.count = 1, .reusable = true}}, SHIFT(449),
+  [1068] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
+  [1070] = {.entry = {.count = 1, .reusable = true}}, SHIFT(32),
+  [1072] = {.entry = {.count = 1, .reusable = true}}, SHIFT(424),
+  [1074] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
+  [1076] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_switch, 3),
+  [1078] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_params_repeat1, 2),
+  [1080] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
+  [1082] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
+  [1084] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_invoke_args_repeat1, 2),
+  [1086] = {.entry = {.count = 1, .reusable = true}}, SHIFT(387),
+  [1088] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [1090] = {.entry = {.count = 1, .reusable = true}}, SHIFT(344),
+  [1092] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_invoke_ref_args_repeat1, 2),
+  [1094] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_ref_args, 3),
+  [1096] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
+  [1098] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
+  [1100] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [1102] = {.entry = {.count = 1, .reusable = true}}, SHIFT(433),
+  [1104] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
+  [1106] = {.entry = {.count = 1, .reusable = true}}, SHIFT(33),
+  [1108] = {.entry = {.count = 1, .reusable = true}}, SHIFT(348),
+  [1110] = {.entry = {.count = 1, .reusable = true}}, SHIFT(461),
+  [1112] = {.entry = {.count = 1, .reusable = true}}, SHIFT(423),
+  [1114] = {.entry = {.count = 1, .reusable = true}}, SHIFT(368),
+  [1116] = {.entry = {.count = 1, .reusable = true}}, SHIFT(328),
+  [1118] = {.entry = {.count = 1, .reusable = true}}, SHIFT(373),
+  [1120] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
+  [1122] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
+  [1124] = {.entry = {.count = 1, .reusable = true}}, SHIFT(380),
+  [1126] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
+  [1128] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [1130] = {.entry = {.count = 1, .reusable = true}}, SHIFT(331),
+  [1132] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
+  [1134] = {.entry = {.count = 1, .reusable = true}}, SHIFT(442),
+  [1136] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
+  [1138] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
+  [1140] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [1142] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_ref_args, 4),
+  [1144] = {.entry = {.count = 1, .reusable = true}}, SHIFT(211),
+  [1146] = {.entry = {.count = 1, .reusable = true}}, SHIFT(443),
+  [1148] = {.entry = {.count = 1, .reusable = true}}, SHIFT(252),
+  [1150] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 3),
+  [1152] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_invoke_ref_args, 2),
+  [1154] = {.entry = {.count = 1, .reusable = true}}, SHIFT(398),
+  [1156] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [1158] = {.entry = {.count = 1, .reusable = true}}, SHIFT(421),
+  [1160] = {.entry = {.count = 1, .reusable = true}}, SHIFT(450),
+  [1162] = {.entry = {.count = 1, .reusable = true}}, SHIFT(6),
+  [1164] = {.entry = {.count = 1, .reusable = true}}, SHIFT(330),
+  [1166] = {.entry = {.count = 1, .reusable = true}}, SHIFT(54),
+  [1168] = {.entry = {.count = 1, .reusable = true}}, SHIFT(76),
+  [1170] = {.entry = {.count = 1, .reusable = true}}, SHIFT(453),
+  [1172] = {.entry = {.count = 1, .reusable = true}}, SHIFT(466),
+  [1174] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [1176] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [1178] = {.entry = {.count = 1, .reusable = true}}, SHIFT(410),
+  [1180] = {.entry = {.count = 1, .reusable = true}}, SHIFT(24),
+  [1182] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_control, 3),
+  [1184] = {.entry = {.count = 1, .reusable = true}}, SHIFT(383),
+  [1186] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_control, 4),
+  [1188] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
+  [1190] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
+  [1192] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [1194] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
+  [1196] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_port_with, 3),
+  [1198] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
+  [1200] = {.entry = {.count = 1, .reusable = true}}, SHIFT(360),
+  [1202] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [1204] = {.entry = {.count = 1, .reusable = true}}, SHIFT(215),
+  [1206] = {.entry = {.count = 1, .reusable = true}}, SHIFT(53),
+  [1208] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [1210] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
+  [1212] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lhs, 1),
+  [1214] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [1216] = {.entry = {.count = 1, .reusable = true}}, SHIFT(297),
+  [1218] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
+  [1220] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
+  [1222] = {.entry = {.count = 1, .reusable = true}}, SHIFT(31),
+  [1224] = {.entry = {.count = 1, .reusable = true}}, SHIFT(246),
+  [1226] = {.entry = {.count = 1, .reusable = true}}, SHIFT(300),
+  [1228] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [1230] = {.entry = {.count = 1, .reusable = true}}, SHIFT(113),
+  [1232] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
+  [1234] = {.entry = {.count = 1, .reusable = true}}, SHIFT(303),
+  [1236] = {.entry = {.count = 1, .reusable = true}}, SHIFT(476),
+  [1238] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [1240] = {.entry = {.count = 1, .reusable = true}}, SHIFT(484),
+  [1242] = {.entry = {.count = 1, .reusable = true}}, SHIFT(486),
+  [1244] = {.entry = {.count = 1, .reusable = true}}, SHIFT(488),
+  [1246] = {.entry = {.count = 1, .reusable = true}}, SHIFT(36),
+  [1248] = {.entry = {.count = 1, .reusable = true}}, SHIFT(35),
+  [1250] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
 };
 
 #ifdef __cplusplus
@@ -1,15 +1,16 @@
 //! Defines the default passes available to [PassManager].
 use crate::passes::{
-    AddGuard, Canonicalize, CellShare, ClkInsertion, CollapseControl, CombProp,
-    CompileInvoke, CompileRepeat, CompileStatic, CompileStaticInterface,
-    CompileSync, CompileSyncWithoutSyncReg, ComponentInliner, DataPathInfer,
-    DeadAssignmentRemoval, DeadCellRemoval, DeadGroupRemoval, DefaultAssigns,
-    DiscoverExternal, ExternalToRef, Externalize, GoInsertion, GroupToInvoke,
-    GroupToSeq, HoleInliner, InferShare, LowerGuards, MergeAssign, Papercut,
-    ParToSeq, RegisterUnsharing, RemoveIds, ResetInsertion,
-    SimplifyStaticGuards, SimplifyWithControl, StaticInference, StaticInliner,
-    StaticPromotion, SynthesisPapercut, TopDownCompileControl, UnrollBounded,
-    WellFormed, WireInliner, WrapMain,
+    AddGuard, AxiWrapper, Canonicalize, CellShare, ClkInsertion,
+    CollapseControl, CombProp, CompileInvoke, CompileRepeat, CompileStatic,
+    CompileStaticInterface, CompileSync, CompileSyncWithoutSyncReg,
+    ComponentInliner, DataPathInfer, DeadAssignmentRemoval, DeadCellRemoval,
+    DeadGroupRemoval, DefaultAssigns, DiscoverExternal, ExternalToRef,
+    Externalize, GoInsertion, GroupToInvoke, GroupToSeq, HoleInliner,
+    InferShare, LowerGuards, MergeAssign, Papercut, ParToSeq,
+    RegisterUnsharing, RemoveIds, ResetInsertion, SimplifyStaticGuards,
+    SimplifyWithControl, StaticInference, StaticInliner, StaticPromotion,
+    SynthesisPapercut, TopDownCompileControl, UnrollBounded, WellFormed,
+    WireInliner, WrapMain,
 };
 use crate::traversal::Named;
 use crate::{pass_manager::PassManager, register_alias};
@@ -21,8 +22,8 @@ impl PassManager {
         let mut pm = PassManager::default();
 
         // Validation passes
-        pm.register_pass::<WellFormed>()?;
-        pm.register_pass::<Papercut>()?;
+        pm.register_check::<WellFormed>()?;
+        pm.register_check::<Papercut>()?;
         pm.register_pass::<Canonicalize>()?;
 
         // Optimization passes
@@ -62,7 +63,7 @@ impl PassManager {
         pm.register_pass::<DefaultAssigns>()?;
 
         // Enabled in the synthesis compilation flow
-        pm.register_pass::<SynthesisPapercut>()?;
+        pm.register_check::<SynthesisPapercut>()?;
         pm.register_pass::<Externalize>()?;
 
         // Disabled by default
@@ -73,6 +74,7 @@ impl PassManager {
         pm.register_pass::<ParToSeq>()?;
         pm.register_pass::<LowerGuards>()?;
         pm.register_pass::<HoleInliner>()?;
+        pm.register_pass::<AxiWrapper>()?;
         pm.register_pass::<RemoveIds>()?;
         pm.register_pass::<ExternalToRef>()?;
 
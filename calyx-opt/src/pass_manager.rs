@@ -19,6 +19,9 @@ pub struct PassManager {
     aliases: HashMap<String, Vec<String>>,
     // Track the help information for passes
     help: HashMap<String, String>,
+    /// Passes that check the program and record the problems they find in
+    /// [ir::Context::diagnostics] instead of failing.
+    checks: HashSet<String>,
 }
 
 impl PassManager {
@@ -62,6 +65,20 @@ impl PassManager {
         Ok(())
     }
 
+    /// Register a pass that checks the program. Instead of failing at the
+    /// first problem, these passes record every problem they find in
+    /// [ir::Context::diagnostics], and the errors of consecutive checks are
+    /// reported together.
+    pub fn register_check<Pass>(&mut self) -> CalyxResult<()>
+    where
+        Pass:
+            traversal::Visitor + traversal::ConstructVisitor + traversal::Named,
+    {
+        self.register_pass::<Pass>()?;
+        self.checks.insert(Pass::name().to_string());
+        Ok(())
+    }
+
     /// Adds a new alias for groups of passes. An alias is a list of strings
     /// that represent valid pass names OR an alias.
     /// The passes and aliases are executed in the order of specification.
@@ -187,6 +204,11 @@ impl PassManager {
         let (passes, excl_set) = self.create_plan(incl, excl)?;
 
         for name in passes {
+            // Other passes assume that the checks succeeded.
+            if !self.checks.contains(&name) {
+                ctx.diagnostics.take_errors()?;
+            }
+
             // Pass is known to exist because create_plan validates the
             // names of passes.
             let pass = &self.passes[&name];
@@ -219,7 +241,7 @@ impl PassManager {
             }
         }
 
-        Ok(())
+        ctx.diagnostics.take_errors()
     }
 }
 
@@ -0,0 +1,1289 @@
+use crate::passes::math_utilities::get_bit_width_from;
+use crate::traversal::{
+    Action, ConstructVisitor, Named, ParseVal, PassOpt, VisResult, Visitor,
+};
+use calyx_ir::{self as ir, build_assignments, guard, structure, RRC};
+use calyx_utils::{CalyxResult, Error};
+use ir::utils::GetMemInfo;
+use ir::GetAttributes;
+use std::rc::Rc;
+
+/// Maximum number of beats in an AXI4 INCR burst.
+const MAX_BURST_BEATS: u64 = 256;
+/// AXI4 bursts are not allowed to cross a 4KB address boundary.
+const BURST_BOUNDARY: u64 = 4096;
+/// Width of the AXI address channels.
+const ADDR_WIDTH: u64 = 64;
+
+type CellMap = Vec<(ir::Id, RRC<ir::Cell>)>;
+
+/// Wraps the entrypoint component in a new top-level `wrapper` component
+/// which acts as an AXI4 manager for each of its `@external` memories.
+///
+/// The memories are turned into `ref` cells of the entrypoint and the
+/// wrapper instantiates them as `internal_mem_<name>`. Before the
+/// entrypoint is invoked, the contents of each memory are copied from host
+/// memory using burst reads, and after it finishes, they are copied back
+/// using burst writes. Every memory gets its own AXI interface whose signals
+/// are prefixed with the memory's name (e.g. `A0_ARVALID`).
+///
+/// Multi-dimensional memories are transferred in row-major order. When the
+/// data bus is wider than a memory element, several elements are packed into
+/// each beat with the lowest index in the least significant bits. Elements
+/// have to be at least a byte wide since AXI addresses and write strobes work
+/// on bytes.
+///
+/// The memories are read from the program instead of its YXI description,
+/// which doesn't record the primitive, dimensions or index widths of a memory.
+pub struct AxiWrapper {
+    /// Width of the AXI data bus. If `None`, each memory uses its own width.
+    data_width: Option<u64>,
+    /// Host address at which the transfers for every memory start.
+    base_address: u64,
+}
+
+impl Named for AxiWrapper {
+    fn name() -> &'static str {
+        "axi-wrapper"
+    }
+
+    fn description() -> &'static str {
+        "Wrap the entrypoint in a component that transfers its `@external` memories over AXI4 manager interfaces"
+    }
+
+    fn opts() -> Vec<PassOpt> {
+        vec![
+            PassOpt::new(
+                "data-width",
+                "width of the AXI data bus in bits. 0 uses the width of each memory",
+                ParseVal::Num(0),
+                PassOpt::parse_num,
+            ),
+            PassOpt::new(
+                "base-address",
+                "host address at which the transfers for each memory start",
+                ParseVal::Num(BURST_BOUNDARY as i64),
+                PassOpt::parse_num,
+            ),
+        ]
+    }
+}
+
+impl ConstructVisitor for AxiWrapper {
+    fn from(ctx: &ir::Context) -> CalyxResult<Self>
+    where
+        Self: Sized,
+    {
+        let opts = Self::get_opts(ctx);
+        let data_width = opts[&"data-width"].pos_num().ok_or_else(|| {
+            Error::pass_assumption(
+                Self::name(),
+                "`data-width' must be non-negative",
+            )
+        })?;
+        let base_address =
+            opts[&"base-address"].pos_num().ok_or_else(|| {
+                Error::pass_assumption(
+                    Self::name(),
+                    "`base-address' must be non-negative",
+                )
+            })?;
+        if base_address % BURST_BOUNDARY != 0 {
+            return Err(Error::pass_assumption(
+                Self::name(),
+                format!(
+                    "`base-address' must be aligned to {BURST_BOUNDARY} bytes"
+                ),
+            ));
+        }
+        Ok(AxiWrapper {
+            data_width: (data_width != 0).then_some(data_width),
+            base_address,
+        })
+    }
+
+    fn clear_data(&mut self) {}
+}
+
+/// Layout of an `@external` memory and how it is transferred over AXI.
+struct AxiMemory {
+    /// Name of the memory in the entrypoint component.
+    name: ir::Id,
+    /// Name of the memory primitive.
+    prim: ir::Id,
+    /// Parameters of the memory primitive.
+    params: Vec<u64>,
+    /// True if the memory is a `seq_mem_*` primitive.
+    is_seq: bool,
+    /// Width of a single element.
+    width: u64,
+    /// Total number of elements.
+    size: u64,
+    /// Size of each dimension, outermost first.
+    dimension_sizes: Vec<u64>,
+    /// Width of the address port of each dimension, outermost first.
+    idx_sizes: Vec<u64>,
+    /// Width of the AXI data bus.
+    bus_width: u64,
+}
+
+impl AxiMemory {
+    fn new(
+        cell: &ir::Cell,
+        info: ir::utils::MemInfo,
+        data_width: Option<u64>,
+    ) -> CalyxResult<Self> {
+        let name = cell.name();
+        let ir::CellType::Primitive {
+            name: prim,
+            param_binding,
+            ..
+        } = &cell.prototype
+        else {
+            unreachable!("external memories are primitives")
+        };
+        let bus_width = data_width.unwrap_or(info.width);
+        if !(8..=512).contains(&bus_width) || !bus_width.is_power_of_two() {
+            return Err(Error::pass_assumption(
+                AxiWrapper::name(),
+                format!("AXI data width for `{name}' must be a power of two between 8 and 512, got {bus_width}"),
+            ));
+        }
+        // The bus width is a power of two, so the width of each element has
+        // to be one as well.
+        if info.width < 8
+            || !info.width.is_power_of_two()
+            || info.width > bus_width
+        {
+            return Err(Error::pass_assumption(
+                AxiWrapper::name(),
+                format!("width of memory `{name}' ({}) must be a power of two between 8 and the AXI data width ({bus_width})", info.width),
+            ));
+        }
+        Ok(AxiMemory {
+            name,
+            prim: *prim,
+            params: param_binding.iter().map(|(_, v)| *v).collect(),
+            is_seq: prim.as_ref().starts_with("seq_mem"),
+            width: info.width,
+            size: info.size,
+            dimension_sizes: info.dimension_sizes,
+            idx_sizes: info.idx_sizes,
+            bus_width,
+        })
+    }
+
+    /// Number of bytes in each beat.
+    fn bytes_per_beat(&self) -> u64 {
+        self.bus_width / 8
+    }
+
+    /// Number of memory elements packed into each beat.
+    fn elems_per_beat(&self) -> u64 {
+        self.bus_width / self.width
+    }
+
+    /// Number of beats needed to transfer the memory.
+    fn beats(&self) -> u64 {
+        self.size.div_ceil(self.elems_per_beat())
+    }
+
+    /// True if the last beat has slots past the end of the memory.
+    fn has_partial_beat(&self) -> bool {
+        self.beats() * self.elems_per_beat() != self.size
+    }
+
+    /// Width of the register counting the transferred elements.
+    fn count_width(&self) -> u64 {
+        get_bit_width_from(self.size + 1)
+    }
+
+    /// Returns the number of beats in a full-length burst, the number of
+    /// full-length bursts, and the length of the final shorter burst (which
+    /// may be zero).
+    fn bursts(&self) -> (u64, u64, u64) {
+        let beats = self.beats();
+        let max_len =
+            MAX_BURST_BEATS.min(BURST_BOUNDARY / self.bytes_per_beat());
+        (max_len, beats / max_len, beats % max_len)
+    }
+}
+
+/// Cells that track the progress of transferring a memory. The read and
+/// write channels take them as `ref` cells and the wrapper instantiates them.
+struct TransferState {
+    mem: RRC<ir::Cell>,
+    curr_addr_axi: RRC<ir::Cell>,
+    elem_count: RRC<ir::Cell>,
+    curr_idx: Vec<RRC<ir::Cell>>,
+}
+
+impl TransferState {
+    /// Add the cells to the component. Each cell name is suffixed with
+    /// `suffix`.
+    fn add(
+        builder: &mut ir::Builder,
+        mem: &AxiMemory,
+        mem_name: &str,
+        suffix: &str,
+        is_ref: bool,
+    ) -> Self {
+        let mem_cell = builder.add_primitive(mem_name, mem.prim, &mem.params);
+        let curr_addr_axi = builder.add_primitive(
+            format!("curr_addr_axi{suffix}"),
+            "std_reg",
+            &[ADDR_WIDTH],
+        );
+        let elem_count = builder.add_primitive(
+            format!("elem_count{suffix}"),
+            "std_reg",
+            &[mem.count_width()],
+        );
+        let curr_idx = mem
+            .idx_sizes
+            .iter()
+            .enumerate()
+            .map(|(d, size)| {
+                builder.add_primitive(
+                    format!("curr_idx{d}{suffix}"),
+                    "std_reg",
+                    &[*size],
+                )
+            })
+            .collect::<Vec<_>>();
+        let state = TransferState {
+            mem: mem_cell,
+            curr_addr_axi,
+            elem_count,
+            curr_idx,
+        };
+        if is_ref {
+            for cell in state.cells() {
+                cell.borrow_mut().set_reference(true);
+            }
+        }
+        state
+    }
+
+    /// All the cells in the state.
+    fn cells(&self) -> impl Iterator<Item = &RRC<ir::Cell>> {
+        [&self.mem, &self.curr_addr_axi, &self.elem_count]
+            .into_iter()
+            .chain(self.curr_idx.iter())
+    }
+
+    /// Pair the `ref` cells of a channel with the cells in this state.
+    fn bind(&self, refs: &TransferState) -> CellMap {
+        refs.cells()
+            .zip(self.cells())
+            .map(|(r, c)| (r.borrow().name(), Rc::clone(c)))
+            .collect()
+    }
+
+    /// Invoke each register to reset it before a transfer.
+    fn reset(
+        &self,
+        builder: &mut ir::Builder,
+        base_address: u64,
+    ) -> ir::Control {
+        let addr = builder.add_constant(base_address, ADDR_WIDTH);
+        let mut inits = vec![invoke_reg(&self.curr_addr_axi, &addr)];
+        let zero = builder
+            .add_constant(0, self.elem_count.borrow().get("in").borrow().width);
+        inits.push(invoke_reg(&self.elem_count, &zero));
+        for idx in &self.curr_idx {
+            let width = idx.borrow().get("in").borrow().width;
+            let zero = builder.add_constant(0, width);
+            inits.push(invoke_reg(idx, &zero));
+        }
+        ir::Control::par(inits)
+    }
+
+    /// Assign the current index to the address ports of the memory.
+    fn address_memory(
+        &self,
+        builder: &ir::Builder,
+    ) -> Vec<ir::Assignment<ir::Nothing>> {
+        self.curr_idx
+            .iter()
+            .enumerate()
+            .map(|(d, idx)| {
+                builder.build_assignment(
+                    self.mem.borrow().get(format!("addr{d}")),
+                    idx.borrow().get("out"),
+                    ir::Guard::True,
+                )
+            })
+            .collect()
+    }
+
+    /// Group that moves the index to the next element in row-major order
+    /// and increments the element count.
+    fn incr_idx(
+        &self,
+        builder: &mut ir::Builder,
+        mem: &AxiMemory,
+    ) -> RRC<ir::Group> {
+        let count_width = mem.count_width();
+        structure!(builder;
+            let signal_on = constant(1, 1);
+            let elem_count_adder = prim std_add(count_width);
+            let count_one = constant(1, count_width);
+        );
+        let elem_count = &self.elem_count;
+        let incr_idx = builder.add_group("incr_idx");
+        let mut assigns = build_assignments!(builder;
+            elem_count_adder["left"] = ? elem_count["out"];
+            elem_count_adder["right"] = ? count_one["out"];
+            elem_count["in"] = ? elem_count_adder["out"];
+            elem_count["write_en"] = ? signal_on["out"];
+            incr_idx["done"] = ? elem_count["done"];
+        )
+        .to_vec();
+
+        // An index wraps around when every inner index is at its last
+        // element, and is otherwise incremented.
+        let mut carry: ir::Guard<ir::Nothing> = ir::Guard::True;
+        for (idx, (&size, &width)) in self
+            .curr_idx
+            .iter()
+            .zip(mem.dimension_sizes.iter().zip(&mem.idx_sizes))
+            .rev()
+        {
+            structure!(builder;
+                let curr_idx_adder = prim std_add(width);
+                let one = constant(1, width);
+                let zero = constant(0, width);
+                let last = constant(size - 1, width);
+            );
+            let at_last = guard!(idx["out"] == last["out"]);
+            let wrap = carry.clone() & at_last.clone();
+            let incr = carry.clone() & !at_last.clone();
+            assigns.extend(build_assignments!(builder;
+                curr_idx_adder["left"] = ? idx["out"];
+                curr_idx_adder["right"] = ? one["out"];
+                idx["in"] = wrap ? zero["out"];
+                idx["in"] = incr ? curr_idx_adder["out"];
+                idx["write_en"] = carry ? signal_on["out"];
+            ));
+            carry &= at_last;
+        }
+        incr_idx.borrow_mut().assignments = assigns;
+        incr_idx
+    }
+
+    /// Group that advances the AXI address by one beat.
+    fn incr_curr_addr_axi(
+        &self,
+        builder: &mut ir::Builder,
+        mem: &AxiMemory,
+    ) -> RRC<ir::Group> {
+        structure!(builder;
+            let signal_on = constant(1, 1);
+            let curr_addr_axi_adder = prim std_add(ADDR_WIDTH);
+            let beat_bytes = constant(mem.bytes_per_beat(), ADDR_WIDTH);
+        );
+        let curr_addr_axi = &self.curr_addr_axi;
+        let group = builder.add_group("incr_curr_addr_axi");
+        let assigns = build_assignments!(builder;
+            curr_addr_axi_adder["left"] = ? curr_addr_axi["out"];
+            curr_addr_axi_adder["right"] = ? beat_bytes["out"];
+            curr_addr_axi["in"] = ? curr_addr_axi_adder["out"];
+            curr_addr_axi["write_en"] = ? signal_on["out"];
+            group["done"] = ? curr_addr_axi["done"];
+        );
+        group.borrow_mut().assignments.extend(assigns);
+        group
+    }
+
+    /// Combinational group that checks if there are elements left to
+    /// transfer. The result is available on the returned port.
+    fn check_in_bounds(
+        &self,
+        builder: &mut ir::Builder,
+        mem: &AxiMemory,
+    ) -> (RRC<ir::Port>, RRC<ir::CombGroup>) {
+        let count_width = mem.count_width();
+        structure!(builder;
+            let elem_count_lt = prim std_lt(count_width);
+            let mem_size = constant(mem.size, count_width);
+        );
+        let elem_count = &self.elem_count;
+        let group = builder.add_comb_group("check_in_bounds");
+        let assigns = build_assignments!(builder;
+            elem_count_lt["left"] = ? elem_count["out"];
+            elem_count_lt["right"] = ? mem_size["out"];
+        );
+        group.borrow_mut().assignments.extend(assigns);
+        let port = elem_count_lt.borrow().get("out");
+        (port, group)
+    }
+}
+
+/// Construct an input port definition.
+fn input(name: &str, width: u64) -> ir::PortDef<u64> {
+    ir::PortDef::new(
+        name,
+        width,
+        ir::Direction::Input,
+        ir::Attributes::default(),
+    )
+}
+
+/// Construct an output port definition.
+fn output(name: &str, width: u64) -> ir::PortDef<u64> {
+    ir::PortDef::new(
+        name,
+        width,
+        ir::Direction::Output,
+        ir::Attributes::default(),
+    )
+}
+
+/// The signature of a component as seen by the cells that instantiate it.
+fn signature(comp: &ir::Component) -> Vec<ir::PortDef<u64>> {
+    comp.signature
+        .borrow()
+        .get_signature()
+        .into_iter()
+        .map(|mut pd| {
+            pd.direction = pd.direction.reverse();
+            pd
+        })
+        .collect()
+}
+
+/// Invoke a register to write `val` into it.
+fn invoke_reg(reg: &RRC<ir::Cell>, val: &RRC<ir::Cell>) -> ir::Control {
+    ir::Control::invoke(
+        Rc::clone(reg),
+        vec![("in".into(), val.borrow().get("out"))],
+        vec![],
+    )
+}
+
+/// Invoke a channel component, passing `ref` cells and connecting its ports
+/// to the AXI ports of the wrapper for memory `mem`.
+fn invoke_channel(
+    channel: &RRC<ir::Cell>,
+    ref_cells: CellMap,
+    this: &RRC<ir::Cell>,
+    mem: ir::Id,
+    burst_len: Option<&RRC<ir::Cell>>,
+) -> ir::Control {
+    let mut inputs = vec![];
+    let mut outputs = vec![];
+    for port in channel.borrow().ports() {
+        let port = port.borrow();
+        if port.attributes.has(ir::NumAttr::Go)
+            || port.attributes.has(ir::NumAttr::Done)
+            || port.attributes.has(ir::BoolAttr::Clk)
+            || port.attributes.has(ir::BoolAttr::Reset)
+        {
+            continue;
+        }
+        if port.name == "burst_len" {
+            let len = burst_len.expect("channel requires a burst length");
+            inputs.push((port.name, len.borrow().get("out")));
+            continue;
+        }
+        let wrapper_port = this.borrow().get(format!("{mem}_{}", port.name));
+        match port.direction {
+            ir::Direction::Input => inputs.push((port.name, wrapper_port)),
+            ir::Direction::Output => outputs.push((port.name, wrapper_port)),
+            ir::Direction::Inout => unreachable!(),
+        }
+    }
+    ir::Control::Invoke(ir::Invoke {
+        comp: Rc::clone(channel),
+        inputs,
+        outputs,
+        attributes: ir::Attributes::default(),
+        comb_group: None,
+        ref_cells,
+    })
+}
+
+/// Repeat a transfer for each burst of a memory. `burst` constructs a single
+/// burst given the `ARLEN`/`AWLEN` value for it.
+fn for_each_burst(
+    builder: &mut ir::Builder,
+    mem: &AxiMemory,
+    mut burst: impl FnMut(&RRC<ir::Cell>) -> ir::Control,
+) -> Vec<ir::Control> {
+    let (max_len, full, rem) = mem.bursts();
+    let mut stmts = vec![];
+    if full > 0 {
+        let len = builder.add_constant(max_len - 1, 8);
+        let body = burst(&len);
+        stmts.push(if full == 1 {
+            body
+        } else {
+            ir::Control::repeat(full, Box::new(body))
+        });
+    }
+    if rem > 0 {
+        let len = builder.add_constant(rem - 1, 8);
+        stmts.push(burst(&len));
+    }
+    stmts
+}
+
+/// The AXI signals of a single memory, as seen from the manager.
+fn axi_ports(mem: &AxiMemory) -> Vec<ir::PortDef<u64>> {
+    let bus = mem.bus_width;
+    let ports = [
+        input("ARESETn", 1),
+        input("ARREADY", 1),
+        input("RVALID", 1),
+        input("RLAST", 1),
+        input("RDATA", bus),
+        input("RRESP", 2),
+        input("RID", 1),
+        input("AWREADY", 1),
+        input("WREADY", 1),
+        input("BVALID", 1),
+        input("BRESP", 2),
+        input("BID", 1),
+        output("ARVALID", 1),
+        output("ARADDR", ADDR_WIDTH),
+        output("ARSIZE", 3),
+        output("ARLEN", 8),
+        output("ARBURST", 2),
+        output("ARPROT", 3),
+        output("ARID", 1),
+        output("RREADY", 1),
+        output("AWVALID", 1),
+        output("AWADDR", ADDR_WIDTH),
+        output("AWSIZE", 3),
+        output("AWLEN", 8),
+        output("AWBURST", 2),
+        output("AWPROT", 3),
+        output("AWID", 1),
+        output("WVALID", 1),
+        output("WLAST", 1),
+        output("WDATA", bus),
+        output("WSTRB", bus / 8),
+        output("WID", 1),
+        output("BREADY", 1),
+    ];
+    ports
+        .into_iter()
+        .map(|pd| {
+            let name = format!("{}_{}", mem.name, pd.name());
+            ir::PortDef::new(name, pd.width, pd.direction, pd.attributes)
+        })
+        .collect()
+}
+
+impl AxiWrapper {
+    /// Component that performs a single handshake on the read (`AR`) or
+    /// write (`AW`) address channel for a burst starting at `curr_addr_axi`.
+    fn address_channel(
+        mem: &AxiMemory,
+        lib: &ir::LibrarySignatures,
+        channel: &str,
+    ) -> ir::Component {
+        let ports = vec![
+            input(&format!("{channel}READY"), 1),
+            input("burst_len", 8),
+            output(&format!("{channel}VALID"), 1),
+            output(&format!("{channel}ADDR"), ADDR_WIDTH),
+            output(&format!("{channel}SIZE"), 3),
+            output(&format!("{channel}LEN"), 8),
+            output(&format!("{channel}BURST"), 2),
+            output(&format!("{channel}PROT"), 3),
+        ];
+        let prefix = channel.to_lowercase();
+        let name = format!("m_{prefix}_channel_{}", mem.name);
+        let mut comp = ir::Component::new(name, ports, true, false, None);
+        let mut builder = ir::Builder::new(&mut comp, lib);
+        let this = builder.component.signature.clone();
+        let xvalid =
+            builder.add_primitive(format!("{prefix}valid"), "std_reg", &[1]);
+        let handshake_occurred = builder.add_primitive(
+            format!("{prefix}_handshake_occurred"),
+            "std_reg",
+            &[1],
+        );
+        structure!(builder;
+            let bt_reg = prim std_reg(1);
+            let curr_addr_axi = prim std_reg(ADDR_WIDTH);
+            let signal_on = constant(1, 1);
+            let signal_off = constant(0, 1);
+            // Number of bytes in each beat, as log2.
+            let size = constant(mem.bytes_per_beat().trailing_zeros() as u64, 3);
+            // INCR burst type.
+            let burst = constant(1, 2);
+            // Privileged, non-secure, instruction access.
+            let prot = constant(0b110, 3);
+        );
+        curr_addr_axi.borrow_mut().set_reference(true);
+
+        let ready = format!("{channel}READY");
+        let valid = format!("{channel}VALID");
+        let handshake = guard!(xvalid["out"] & this[ready.as_str()]);
+        let occurred = guard!(handshake_occurred["out"]);
+        let raise = !handshake.clone() & !occurred.clone();
+        let lower = handshake.clone() | occurred.clone();
+        let not_handshake = !handshake.clone();
+        let not_occurred = !occurred;
+
+        let group = builder.add_group(format!("do_{prefix}_transfer"));
+        let assigns = build_assignments!(builder;
+            xvalid["in"] = raise ? signal_on["out"];
+            xvalid["in"] = lower ? signal_off["out"];
+            xvalid["write_en"] = ? signal_on["out"];
+            handshake_occurred["in"] = handshake ? signal_on["out"];
+            handshake_occurred["write_en"] = not_occurred ? signal_on["out"];
+            this[format!("{channel}ADDR")] = ? curr_addr_axi["out"];
+            this[format!("{channel}SIZE")] = ? size["out"];
+            this[format!("{channel}LEN")] = ? this["burst_len"];
+            this[format!("{channel}BURST")] = ? burst["out"];
+            this[format!("{channel}PROT")] = ? prot["out"];
+            bt_reg["in"] = handshake ? signal_on["out"];
+            bt_reg["in"] = not_handshake ? signal_off["out"];
+            bt_reg["write_en"] = ? signal_on["out"];
+            group["done"] = ? bt_reg["out"];
+        );
+        group.borrow_mut().assignments.extend(assigns);
+        let cont = build_assignments!(builder;
+            this[valid.as_str()] = ? xvalid["out"];
+        );
+        builder.add_continuous_assignments(cont.to_vec());
+
+        *comp.control.borrow_mut() = ir::Control::seq(vec![
+            ir::Control::par(vec![
+                invoke_reg(&bt_reg, &signal_off),
+                invoke_reg(&handshake_occurred, &signal_off),
+            ]),
+            ir::Control::enable(group),
+            invoke_reg(&xvalid, &signal_off),
+        ]);
+        comp
+    }
+
+    /// Component that receives a burst on the read data channel and stores
+    /// the elements of each beat into the memory.
+    fn read_channel(
+        mem: &AxiMemory,
+        lib: &ir::LibrarySignatures,
+    ) -> (ir::Component, TransferState) {
+        let bus = mem.bus_width;
+        let ports = vec![
+            input("RVALID", 1),
+            input("RLAST", 1),
+            input("RDATA", bus),
+            input("RRESP", 2),
+            output("RREADY", 1),
+        ];
+        let name = format!("m_read_channel_{}", mem.name);
+        let mut comp = ir::Component::new(name, ports, true, false, None);
+        let mut builder = ir::Builder::new(&mut comp, lib);
+        let this = builder.component.signature.clone();
+        let state = TransferState::add(&mut builder, mem, "mem_ref", "", true);
+        structure!(builder;
+            let rready = prim std_reg(1);
+            let n_rlast = prim std_reg(1);
+            let read_data_reg = prim std_reg(bus);
+            let bt_reg = prim std_reg(1);
+            let signal_on = constant(1, 1);
+            let signal_off = constant(0, 1);
+        );
+
+        // Wait for a single beat. `rready` is kept low once the beat has
+        // been received so that the next beat is not accepted while this
+        // one is being stored.
+        let handshake = guard!(rready["out"] & this["RVALID"]);
+        let finished = guard!(bt_reg["out"]);
+        let raise = !handshake.clone() & !finished.clone();
+        let lower = handshake.clone() | finished;
+        let not_handshake = !handshake.clone();
+        let is_last = guard!(this["RLAST"]);
+        let not_last = !is_last.clone();
+        let block_transfer = builder.add_group("block_transfer");
+        let assigns = build_assignments!(builder;
+            rready["in"] = raise ? signal_on["out"];
+            rready["in"] = lower ? signal_off["out"];
+            rready["write_en"] = ? signal_on["out"];
+            read_data_reg["in"] = handshake ? this["RDATA"];
+            read_data_reg["write_en"] = handshake ? signal_on["out"];
+            n_rlast["in"] = is_last ? signal_off["out"];
+            n_rlast["in"] = not_last ? signal_on["out"];
+            n_rlast["write_en"] = handshake ? signal_on["out"];
+            bt_reg["in"] = handshake ? signal_on["out"];
+            bt_reg["in"] = not_handshake ? signal_off["out"];
+            bt_reg["write_en"] = ? signal_on["out"];
+            block_transfer["done"] = ? bt_reg["out"];
+        );
+        block_transfer.borrow_mut().assignments.extend(assigns);
+        let cont = build_assignments!(builder;
+            this["RREADY"] = ? rready["out"];
+        );
+        builder.add_continuous_assignments(cont.to_vec());
+
+        // Store the lowest element of the beat into the memory.
+        let k = mem.elems_per_beat();
+        let service = builder.add_group("service_read_transfer");
+        let mut assigns = state.address_memory(&builder);
+        let mem_ref = &state.mem;
+        if k == 1 {
+            assigns.extend(build_assignments!(builder;
+                mem_ref["write_data"] = ? read_data_reg["out"];
+            ));
+        } else {
+            structure!(builder;
+                let elem_slice = prim std_slice(bus, mem.width);
+            );
+            assigns.extend(build_assignments!(builder;
+                elem_slice["in"] = ? read_data_reg["out"];
+                mem_ref["write_data"] = ? elem_slice["out"];
+            ));
+        }
+        assigns.extend(build_assignments!(builder;
+            mem_ref["write_en"] = ? signal_on["out"];
+            service["done"] = ? mem_ref["done"];
+        ));
+        if mem.is_seq {
+            assigns.extend(build_assignments!(builder;
+                mem_ref["content_en"] = ? signal_on["out"];
+            ));
+        }
+        service.borrow_mut().assignments = assigns;
+
+        let incr_idx = state.incr_idx(&mut builder, mem);
+        let incr_addr = state.incr_curr_addr_axi(&mut builder, mem);
+
+        let store = if k == 1 {
+            ir::Control::seq(vec![
+                ir::Control::enable(service),
+                ir::Control::enable(incr_idx),
+            ])
+        } else {
+            structure!(builder;
+                let data_rsh = prim std_rsh(bus);
+                let elem_width = constant(mem.width, bus);
+            );
+            let shift = builder.add_group("shift_data");
+            let assigns = build_assignments!(builder;
+                data_rsh["left"] = ? read_data_reg["out"];
+                data_rsh["right"] = ? elem_width["out"];
+                read_data_reg["in"] = ? data_rsh["out"];
+                read_data_reg["write_en"] = ? signal_on["out"];
+                shift["done"] = ? read_data_reg["done"];
+            );
+            shift.borrow_mut().assignments.extend(assigns);
+            let mut store = ir::Control::seq(vec![
+                ir::Control::enable(service),
+                ir::Control::par(vec![
+                    ir::Control::enable(incr_idx),
+                    ir::Control::enable(shift),
+                ]),
+            ]);
+            if mem.has_partial_beat() {
+                let (port, cond) = state.check_in_bounds(&mut builder, mem);
+                store = ir::Control::if_(
+                    port,
+                    Some(cond),
+                    Box::new(store),
+                    Box::new(ir::Control::empty()),
+                );
+            }
+            ir::Control::repeat(k, Box::new(store))
+        };
+
+        *comp.control.borrow_mut() = ir::Control::seq(vec![
+            invoke_reg(&n_rlast, &signal_on),
+            ir::Control::while_(
+                n_rlast.borrow().get("out"),
+                None,
+                Box::new(ir::Control::seq(vec![
+                    invoke_reg(&bt_reg, &signal_off),
+                    ir::Control::enable(block_transfer),
+                    store,
+                    ir::Control::enable(incr_addr),
+                ])),
+            ),
+        ]);
+        (comp, state)
+    }
+
+    /// Component that sends a burst on the write data channel, packing the
+    /// elements of the memory into each beat.
+    fn write_channel(
+        mem: &AxiMemory,
+        lib: &ir::LibrarySignatures,
+    ) -> (ir::Component, TransferState) {
+        let bus = mem.bus_width;
+        let strb_width = bus / 8;
+        let ports = vec![
+            input("WREADY", 1),
+            input("burst_len", 8),
+            output("WVALID", 1),
+            output("WLAST", 1),
+            output("WDATA", bus),
+            output("WSTRB", strb_width),
+        ];
+        let name = format!("m_write_channel_{}", mem.name);
+        let mut comp = ir::Component::new(name, ports, true, false, None);
+        let mut builder = ir::Builder::new(&mut comp, lib);
+        let this = builder.component.signature.clone();
+        let state = TransferState::add(&mut builder, mem, "mem_ref", "", true);
+        structure!(builder;
+            let wvalid = prim std_reg(1);
+            let w_handshake_occurred = prim std_reg(1);
+            let curr_transfer_count = prim std_reg(8);
+            let n_finished_last_transfer = prim std_reg(1);
+            let bt_reg = prim std_reg(1);
+            let write_data_reg = prim std_reg(bus);
+            let signal_on = constant(1, 1);
+            let signal_off = constant(0, 1);
+        );
+        let mem_ref = &state.mem;
+        let k = mem.elems_per_beat();
+
+        // Only the last beat can have slots past the end of the memory, so
+        // the strobe is tracked in a register only when such a beat exists.
+        let write_strb_reg = if mem.has_partial_beat() {
+            structure!(builder;
+                let write_strb_reg = prim std_reg(strb_width);
+            );
+            Some(write_strb_reg)
+        } else {
+            let all_bytes =
+                builder.add_constant(u64::MAX >> (64 - strb_width), strb_width);
+            let cont = build_assignments!(builder;
+                this["WSTRB"] = ? all_bytes["out"];
+            );
+            builder.add_continuous_assignments(cont.to_vec());
+            None
+        };
+
+        // Sequential memories need a separate cycle to read an element.
+        let read_mem = mem.is_seq.then(|| {
+            let group = builder.add_group("read_mem");
+            let mut assigns = state.address_memory(&builder);
+            assigns.extend(build_assignments!(builder;
+                mem_ref["content_en"] = ? signal_on["out"];
+                group["done"] = ? mem_ref["done"];
+            ));
+            group.borrow_mut().assignments = assigns;
+            group
+        });
+
+        // Shift the next element into the top of the beat.
+        let pack = builder.add_group("pack_data");
+        let mut assigns = if mem.is_seq {
+            vec![]
+        } else {
+            state.address_memory(&builder)
+        };
+        if k == 1 {
+            assigns.extend(build_assignments!(builder;
+                write_data_reg["in"] = ? mem_ref["read_data"];
+            ));
+        } else {
+            structure!(builder;
+                let data_pad = prim std_pad(mem.width, bus);
+                let data_lsh = prim std_lsh(bus);
+                let data_rsh = prim std_rsh(bus);
+                let data_or = prim std_or(bus);
+                let top_offset = constant(bus - mem.width, bus);
+                let elem_width = constant(mem.width, bus);
+            );
+            assigns.extend(build_assignments!(builder;
+                data_pad["in"] = ? mem_ref["read_data"];
+                data_lsh["left"] = ? data_pad["out"];
+                data_lsh["right"] = ? top_offset["out"];
+                data_rsh["left"] = ? write_data_reg["out"];
+                data_rsh["right"] = ? elem_width["out"];
+                data_or["left"] = ? data_lsh["out"];
+                data_or["right"] = ? data_rsh["out"];
+                write_data_reg["in"] = ? data_or["out"];
+            ));
+        }
+        assigns.extend(build_assignments!(builder;
+            write_data_reg["write_en"] = ? signal_on["out"];
+            pack["done"] = ? write_data_reg["done"];
+        ));
+
+        let pack_elem = || {
+            let mut stmts = vec![];
+            if let Some(read) = &read_mem {
+                stmts.push(ir::Control::enable(Rc::clone(read)));
+            }
+            stmts.push(ir::Control::enable(Rc::clone(&pack)));
+            stmts
+        };
+
+        let incr_idx = state.incr_idx(&mut builder, mem);
+        let fill_beat = if k == 1 {
+            let mut stmts = pack_elem();
+            stmts.push(ir::Control::enable(incr_idx));
+            ir::Control::seq(stmts)
+        } else if let Some(strb) = &write_strb_reg {
+            // Slots past the end of the memory are filled with zeros and
+            // their bytes are masked out by the strobe.
+            let elem_bytes = mem.width / 8;
+            structure!(builder;
+                let strb_rsh = prim std_rsh(strb_width);
+                let strb_or = prim std_or(strb_width);
+                let elem_strb = constant(elem_bytes, strb_width);
+                let top_strb = constant(
+                    ((1 << elem_bytes) - 1) << (strb_width - elem_bytes),
+                    strb_width
+                );
+                let data_rsh = prim std_rsh(bus);
+                let elem_width = constant(mem.width, bus);
+            );
+            pack.borrow_mut()
+                .assignments
+                .extend(build_assignments!(builder;
+                    strb_rsh["left"] = ? strb["out"];
+                    strb_rsh["right"] = ? elem_strb["out"];
+                    strb_or["left"] = ? strb_rsh["out"];
+                    strb_or["right"] = ? top_strb["out"];
+                    strb["in"] = ? strb_or["out"];
+                    strb["write_en"] = ? signal_on["out"];
+                ));
+            let pad_data = builder.add_group("pad_data");
+            let pad_assigns = build_assignments!(builder;
+                data_rsh["left"] = ? write_data_reg["out"];
+                data_rsh["right"] = ? elem_width["out"];
+                write_data_reg["in"] = ? data_rsh["out"];
+                write_data_reg["write_en"] = ? signal_on["out"];
+                strb_rsh["left"] = ? strb["out"];
+                strb_rsh["right"] = ? elem_strb["out"];
+                strb["in"] = ? strb_rsh["out"];
+                strb["write_en"] = ? signal_on["out"];
+                pad_data["done"] = ? write_data_reg["done"];
+            );
+            pad_data.borrow_mut().assignments.extend(pad_assigns);
+            let (port, cond) = state.check_in_bounds(&mut builder, mem);
+            let mut stmts = pack_elem();
+            stmts.push(ir::Control::enable(incr_idx));
+            ir::Control::repeat(
+                k,
+                Box::new(ir::Control::if_(
+                    port,
+                    Some(cond),
+                    Box::new(ir::Control::seq(stmts)),
+                    Box::new(ir::Control::enable(pad_data)),
+                )),
+            )
+        } else {
+            let mut stmts = pack_elem();
+            stmts.push(ir::Control::enable(incr_idx));
+            ir::Control::repeat(k, Box::new(ir::Control::seq(stmts)))
+        };
+        pack.borrow_mut().assignments.extend(assigns);
+
+        // Send a single beat, raising `WLAST` on the final one.
+        structure!(builder;
+            let transfer_count_adder = prim std_add(8);
+            let transfer_count_one = constant(1, 8);
+        );
+        let handshake = guard!(wvalid["out"] & this["WREADY"]);
+        let occurred = guard!(w_handshake_occurred["out"]);
+        let raise = !handshake.clone() & !occurred.clone();
+        let lower = handshake.clone() | occurred.clone();
+        let not_handshake = !handshake.clone();
+        let not_occurred = !occurred;
+        let is_last = guard!(curr_transfer_count["out"] == this["burst_len"]);
+        let last_handshake = is_last.clone() & handshake.clone();
+        let service = builder.add_group("service_write_transfer");
+        let mut assigns = build_assignments!(builder;
+            wvalid["in"] = raise ? signal_on["out"];
+            wvalid["in"] = lower ? signal_off["out"];
+            wvalid["write_en"] = ? signal_on["out"];
+            w_handshake_occurred["in"] = handshake ? signal_on["out"];
+            w_handshake_occurred["write_en"] = not_occurred ? signal_on["out"];
+            this["WDATA"] = ? write_data_reg["out"];
+            this["WLAST"] = is_last ? signal_on["out"];
+            n_finished_last_transfer["in"] = last_handshake ? signal_off["out"];
+            n_finished_last_transfer["write_en"] = last_handshake ? signal_on["out"];
+            bt_reg["in"] = handshake ? signal_on["out"];
+            bt_reg["in"] = not_handshake ? signal_off["out"];
+            bt_reg["write_en"] = ? signal_on["out"];
+            service["done"] = ? bt_reg["out"];
+        )
+        .to_vec();
+        if let Some(strb) = &write_strb_reg {
+            assigns.extend(build_assignments!(builder;
+                this["WSTRB"] = ? strb["out"];
+            ));
+        }
+        service.borrow_mut().assignments = assigns;
+        let cont = build_assignments!(builder;
+            this["WVALID"] = ? wvalid["out"];
+        );
+        builder.add_continuous_assignments(cont.to_vec());
+
+        let incr_transfer_count = builder.add_group("incr_transfer_count");
+        let assigns = build_assignments!(builder;
+            transfer_count_adder["left"] = ? curr_transfer_count["out"];
+            transfer_count_adder["right"] = ? transfer_count_one["out"];
+            curr_transfer_count["in"] = ? transfer_count_adder["out"];
+            curr_transfer_count["write_en"] = ? signal_on["out"];
+            incr_transfer_count["done"] = ? curr_transfer_count["done"];
+        );
+        incr_transfer_count.borrow_mut().assignments.extend(assigns);
+        let incr_addr = state.incr_curr_addr_axi(&mut builder, mem);
+
+        let zero_count = builder.add_constant(0, 8);
+        *comp.control.borrow_mut() = ir::Control::seq(vec![
+            ir::Control::par(vec![
+                invoke_reg(&curr_transfer_count, &zero_count),
+                invoke_reg(&n_finished_last_transfer, &signal_on),
+            ]),
+            ir::Control::while_(
+                n_finished_last_transfer.borrow().get("out"),
+                None,
+                Box::new(ir::Control::seq(vec![
+                    fill_beat,
+                    ir::Control::par(vec![
+                        invoke_reg(&bt_reg, &signal_off),
+                        invoke_reg(&w_handshake_occurred, &signal_off),
+                    ]),
+                    ir::Control::enable(service),
+                    ir::Control::par(vec![
+                        ir::Control::enable(incr_addr),
+                        ir::Control::enable(incr_transfer_count),
+                    ]),
+                ])),
+            ),
+        ]);
+        (comp, state)
+    }
+
+    /// Component that waits for the write response of a burst.
+    fn bresp_channel(
+        mem: &AxiMemory,
+        lib: &ir::LibrarySignatures,
+    ) -> ir::Component {
+        let ports = vec![input("BVALID", 1), output("BREADY", 1)];
+        let name = format!("m_bresp_channel_{}", mem.name);
+        let mut comp = ir::Component::new(name, ports, true, false, None);
+        let mut builder = ir::Builder::new(&mut comp, lib);
+        let this = builder.component.signature.clone();
+        structure!(builder;
+            let bready = prim std_reg(1);
+            let bt_reg = prim std_reg(1);
+            let signal_on = constant(1, 1);
+            let signal_off = constant(0, 1);
+        );
+        let handshake = guard!(bready["out"] & this["BVALID"]);
+        let finished = guard!(bt_reg["out"]);
+        let raise = !handshake.clone() & !finished.clone();
+        let lower = handshake.clone() | finished;
+        let not_handshake = !handshake.clone();
+        let group = builder.add_group("block_transfer");
+        let assigns = build_assignments!(builder;
+            bready["in"] = raise ? signal_on["out"];
+            bready["in"] = lower ? signal_off["out"];
+            bready["write_en"] = ? signal_on["out"];
+            bt_reg["in"] = handshake ? signal_on["out"];
+            bt_reg["in"] = not_handshake ? signal_off["out"];
+            bt_reg["write_en"] = ? signal_on["out"];
+            group["done"] = ? bt_reg["out"];
+        );
+        group.borrow_mut().assignments.extend(assigns);
+        let cont = build_assignments!(builder;
+            this["BREADY"] = ? bready["out"];
+        );
+        builder.add_continuous_assignments(cont.to_vec());
+
+        *comp.control.borrow_mut() = ir::Control::seq(vec![
+            invoke_reg(&bt_reg, &signal_off),
+            ir::Control::enable(group),
+        ]);
+        comp
+    }
+}
+
+impl Visitor for AxiWrapper {
+    fn start_context(&mut self, ctx: &mut ir::Context) -> VisResult {
+        let entry = ctx.entrypoint();
+        let entry_name = entry.name;
+        if ctx.components.iter().any(|c| c.name == "wrapper") {
+            return Err(Error::pass_assumption(
+                Self::name(),
+                "a component named `wrapper' already exists",
+            ));
+        }
+        if let Some(p) = entry.signature.borrow().ports.iter().find(|p| {
+            let attr = &p.borrow().attributes;
+            !(attr.has(ir::BoolAttr::Clk)
+                || attr.has(ir::BoolAttr::Reset)
+                || attr.has(ir::NumAttr::Go)
+                || attr.has(ir::NumAttr::Done))
+        }) {
+            return Err(Error::pass_assumption(
+                Self::name(),
+                format!(
+                    "entrypoint `{entry_name}' has non-interface port `{}'",
+                    p.borrow().name
+                ),
+            ));
+        }
+        let cells = ir::utils::external_memories_cells(entry);
+        if cells.is_empty() {
+            return Err(Error::pass_assumption(
+                Self::name(),
+                format!(
+                    "entrypoint `{entry_name}' has no `@external' memories"
+                ),
+            ));
+        }
+        let memories = cells
+            .get_mem_info()
+            .into_iter()
+            .zip(&cells)
+            .map(|(info, cell)| {
+                AxiMemory::new(&cell.borrow(), info, self.data_width)
+            })
+            .collect::<CalyxResult<Vec<_>>>()?;
+
+        // The memories are now owned by the wrapper.
+        for cell in &cells {
+            let mut cell = cell.borrow_mut();
+            cell.get_mut_attributes().remove(ir::BoolAttr::External);
+            cell.set_reference(true);
+        }
+        let entry = ctx.entrypoint_mut();
+        entry.attributes.remove(ir::BoolAttr::TopLevel);
+        let entry_sig = signature(entry);
+
+        let mut wrapper = ir::Component::new(
+            "wrapper",
+            memories.iter().flat_map(axi_ports).collect(),
+            true,
+            false,
+            None,
+        );
+        wrapper.attributes.insert(ir::BoolAttr::TopLevel, 1);
+        let mut builder = ir::Builder::new(&mut wrapper, &ctx.lib);
+        let this = builder.component.signature.clone();
+        let main_compute = builder.add_component(
+            "main_compute",
+            entry_name.as_ref(),
+            entry_sig,
+        );
+
+        let mut channels = vec![];
+        let mut reads = vec![];
+        let mut writes = vec![];
+        let mut main_refs = vec![];
+        for mem in &memories {
+            let name = mem.name;
+            let state = TransferState::add(
+                &mut builder,
+                mem,
+                &format!("internal_mem_{name}"),
+                &format!("_{name}"),
+                false,
+            );
+            main_refs.push((name, Rc::clone(&state.mem)));
+
+            // IDs are unused because every transfer is in order.
+            let zero = builder.add_constant(0, 1);
+            for id in ["ARID", "AWID", "WID"] {
+                let port = this.borrow().get(format!("{name}_{id}"));
+                let assign = builder.build_assignment(
+                    port,
+                    zero.borrow().get("out"),
+                    ir::Guard::True,
+                );
+                builder.add_continuous_assignments(vec![assign]);
+            }
+
+            let ar = Self::address_channel(mem, &ctx.lib, "AR");
+            let (read, read_refs) = Self::read_channel(mem, &ctx.lib);
+            let aw = Self::address_channel(mem, &ctx.lib, "AW");
+            let (write, write_refs) = Self::write_channel(mem, &ctx.lib);
+            let bresp = Self::bresp_channel(mem, &ctx.lib);
+            let [ar_cell, read_cell, aw_cell, write_cell, bresp_cell] = [
+                ("ar", &ar),
+                ("read", &read),
+                ("aw", &aw),
+                ("write", &write),
+                ("bresp", &bresp),
+            ]
+            .map(|(prefix, comp)| {
+                builder.add_component(
+                    ir::Id::from(format!("{prefix}_channel_{name}")),
+                    comp.name,
+                    signature(comp),
+                )
+            });
+            let ar_refs = vec![(
+                ir::Id::from("curr_addr_axi"),
+                Rc::clone(&state.curr_addr_axi),
+            )];
+
+            let mut read_stmts =
+                vec![state.reset(&mut builder, self.base_address)];
+            read_stmts.extend(for_each_burst(&mut builder, mem, |len| {
+                ir::Control::seq(vec![
+                    invoke_channel(
+                        &ar_cell,
+                        ar_refs.clone(),
+                        &this,
+                        name,
+                        Some(len),
+                    ),
+                    invoke_channel(
+                        &read_cell,
+                        state.bind(&read_refs),
+                        &this,
+                        name,
+                        None,
+                    ),
+                ])
+            }));
+            reads.push(ir::Control::seq(read_stmts));
+
+            let mut write_stmts =
+                vec![state.reset(&mut builder, self.base_address)];
+            write_stmts.extend(for_each_burst(&mut builder, mem, |len| {
+                ir::Control::seq(vec![
+                    invoke_channel(
+                        &aw_cell,
+                        ar_refs.clone(),
+                        &this,
+                        name,
+                        Some(len),
+                    ),
+                    invoke_channel(
+                        &write_cell,
+                        state.bind(&write_refs),
+                        &this,
+                        name,
+                        Some(len),
+                    ),
+                    invoke_channel(&bresp_cell, vec![], &this, name, None),
+                ])
+            }));
+            writes.push(ir::Control::seq(write_stmts));
+
+            channels.extend([ar, read, aw, write, bresp]);
+        }
+
+        let mut invoke_main = ir::Control::invoke(main_compute, vec![], vec![]);
+        if let ir::Control::Invoke(invoke) = &mut invoke_main {
+            invoke.ref_cells = main_refs;
+        }
+        *wrapper.control.borrow_mut() = ir::Control::seq(vec![
+            ir::Control::par(reads),
+            invoke_main,
+            ir::Control::par(writes),
+        ]);
+
+        ctx.components.extend(channels);
+        ctx.entrypoint = wrapper.name;
+        ctx.components.push(wrapper);
+
+        // Purely context directed pass
+        Ok(Action::Stop)
+    }
+}
@@ -1,7 +1,7 @@
 use crate::analysis::{self, AssignmentAnalysis};
 use crate::traversal::{Action, ConstructVisitor, Named, VisResult, Visitor};
 use calyx_ir::{self as ir, LibrarySignatures};
-use calyx_utils::{CalyxResult, Error};
+use calyx_utils::{CalyxResult, Diagnostics, Error};
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
@@ -11,6 +11,8 @@ type ReadTogether = (ir::Id, HashSet<ir::Id>);
 
 /// Pass to check for common errors such as missing assignments to `done` holes
 /// of groups.
+///
+/// The pass records every error it finds in [ir::Context::diagnostics].
 pub struct Papercut {
     /// Map from (primitive name) -> Vec<(set of ports)>
     /// When any of the ports in a set is driven, all ports in that set must
@@ -26,6 +28,9 @@ pub struct Papercut {
 
     /// The cells that are driven through continuous assignments
     cont_cells: HashSet<ir::Id>,
+
+    /// Errors found so far
+    diag: Diagnostics,
 }
 
 impl Papercut {
@@ -62,6 +67,7 @@ impl ConstructVisitor for Papercut {
             write_together,
             read_together,
             cont_cells: HashSet::new(),
+            diag: Diagnostics::default(),
         })
     }
 
@@ -121,7 +127,7 @@ impl Visitor for Papercut {
                             assign.name == p.borrow().name && !assign.is_hole()
                         });
                     if done_use.is_none() {
-                        return Err(Error::papercut(format!("Component `{}` has an empty control program and does not assign to the done port `{}`. Without an assignment to the done port, the component cannot return control flow.", comp.name, p.borrow().name)));
+                        self.diag.err(Error::papercut(format!("Component `{}` has an empty control program and does not assign to the done port `{}`. Without an assignment to the done port, the component cannot return control flow.", comp.name, p.borrow().name)));
                     }
                 }
             }
@@ -133,18 +139,24 @@ impl Visitor for Papercut {
         // driven.
         for group_ref in comp.get_groups().iter() {
             let group = group_ref.borrow();
-            self.check_specs(&group.assignments)
-                .map_err(|err| err.with_pos(&group.attributes))?;
+            let res = self
+                .check_specs(&group.assignments)
+                .map_err(|err| err.with_pos(&group.attributes));
+            self.diag.accumulate(res);
         }
         for group_ref in comp.get_static_groups().iter() {
             let group = group_ref.borrow();
-            self.check_specs(&group.assignments)
-                .map_err(|err| err.with_pos(&group.attributes))?;
+            let res = self
+                .check_specs(&group.assignments)
+                .map_err(|err| err.with_pos(&group.attributes));
+            self.diag.accumulate(res);
         }
         for cgr in comp.comb_groups.iter() {
             let cg = cgr.borrow();
-            self.check_specs(&cg.assignments)
-                .map_err(|err| err.with_pos(&cg.attributes))?;
+            let res = self
+                .check_specs(&cg.assignments)
+                .map_err(|err| err.with_pos(&cg.attributes));
+            self.diag.accumulate(res);
         }
 
         // Compute all cells that are driven in by the continuous assignments0
@@ -180,10 +192,8 @@ impl Visitor for Papercut {
                     // If the cell is combinational and not driven by continuous assignments
                     if *is_comb && !self.cont_cells.contains(&cell.name()) {
                         let msg = format!("Port `{}.{}` is an output port on combinational primitive `{}` and will always output 0. Add a `with` statement to the `while` statement to ensure it has a valid value during execution.", cell.name(), port.name, prim_name);
-                        // Use dummy Id to get correct source location for error
-                        return Err(
-                            Error::papercut(msg).with_pos(&s.attributes)
-                        );
+                        self.diag
+                            .err(Error::papercut(msg).with_pos(&s.attributes));
                     }
                 }
             }
@@ -212,16 +222,19 @@ impl Visitor for Papercut {
                     // If the cell is combinational and not driven by continuous assignments
                     if *is_comb && !self.cont_cells.contains(&cell.name()) {
                         let msg = format!("Port `{}.{}` is an output port on combinational primitive `{}` and will always output 0. Add a `with` statement to the `if` statement to ensure it has a valid value during execution.", cell.name(), port.name, prim_name);
-                        // Use dummy Id to get correct source location for error
-                        return Err(
-                            Error::papercut(msg).with_pos(&s.attributes)
-                        );
+                        self.diag
+                            .err(Error::papercut(msg).with_pos(&s.attributes));
                     }
                 }
             }
         }
         Ok(Action::Continue)
     }
+
+    fn finish_context(&mut self, ctx: &mut ir::Context) -> VisResult {
+        ctx.diagnostics.append(std::mem::take(&mut self.diag));
+        Ok(Action::Continue)
+    }
 }
 
 impl Papercut {
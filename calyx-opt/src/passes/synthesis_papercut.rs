@@ -1,7 +1,7 @@
 use crate::analysis::GraphAnalysis;
-use crate::traversal::{Action, Named, VisResult, Visitor};
+use crate::traversal::{Action, ConstructVisitor, Named, VisResult, Visitor};
 use calyx_ir::{self as ir, LibrarySignatures};
-use calyx_utils::Error;
+use calyx_utils::{CalyxResult, Diagnostics, Error};
 use std::collections::HashSet;
 
 const READ_PORT: &str = "read_data";
@@ -10,19 +10,30 @@ const WRITE_PORT: &str = "write_data";
 /// Pass to check common synthesis issues.
 /// 1. If a memory is only read-from or written-to, synthesis tools will optimize it away. Add
 ///    @external attribute to the cell definition to make it an interface memory.
+///
+/// The pass records every error it finds in [ir::Context::diagnostics].
 pub struct SynthesisPapercut {
     /// Names of memory primitives
     memories: HashSet<ir::Id>,
+    /// Errors found so far
+    diag: Diagnostics,
 }
 
-impl Default for SynthesisPapercut {
-    fn default() -> Self {
+impl ConstructVisitor for SynthesisPapercut {
+    fn from(_ctx: &ir::Context) -> CalyxResult<Self> {
         let memories =
             ["comb_mem_d1", "comb_mem_d2", "comb_mem_d3", "comb_mem_d4"]
                 .iter()
                 .map(|&mem| mem.into())
                 .collect();
-        SynthesisPapercut { memories }
+        Ok(SynthesisPapercut {
+            memories,
+            diag: Diagnostics::default(),
+        })
+    }
+
+    fn clear_data(&mut self) {
+        // The errors are reported once every component has been checked
     }
 }
 
@@ -74,11 +85,18 @@ impl Visitor for SynthesisPapercut {
                 has_mem_parent(p1) || has_mem_parent(p2)
             });
 
-        for mem in memory_cells {
+        // report the memories in the order they are defined
+        let memories = comp
+            .cells
+            .iter()
+            .map(|cell| cell.borrow().name())
+            .filter(|name| memory_cells.contains(name))
+            .collect::<Vec<_>>();
+        for mem in memories {
             let cell = comp.find_cell(mem).unwrap();
             let read_port = cell.borrow().get(READ_PORT);
             if analysis.reads_from(&read_port.borrow()).next().is_none() {
-                return Err(Error::papercut(
+                self.diag.err(Error::papercut(
                     format!(
                         "Only writes performed on memory `{mem}'. Synthesis tools will remove this memory. Add @external to cell to turn this into an interface memory.",
                     ),
@@ -86,7 +104,7 @@ impl Visitor for SynthesisPapercut {
             }
             let write_port = cell.borrow().get(WRITE_PORT);
             if analysis.writes_to(&write_port.borrow()).next().is_none() {
-                return Err(Error::papercut(
+                self.diag.err(Error::papercut(
                     format!(
                         "Only reads performed on memory `{mem}'. Synthesis tools will remove this memory. Add @external to cell to turn this into an interface memory.",
                     ),
@@ -95,4 +113,9 @@ impl Visitor for SynthesisPapercut {
         }
         Ok(Action::Stop)
     }
+
+    fn finish_context(&mut self, ctx: &mut ir::Context) -> VisResult {
+        ctx.diagnostics.append(std::mem::take(&mut self.diag));
+        Ok(Action::Continue)
+    }
 }
@@ -3,7 +3,7 @@ use calyx_ir::{
     self as ir, CellType, Component, GetAttributes, LibrarySignatures,
     RESERVED_NAMES,
 };
-use calyx_utils::{CalyxResult, Error, WithPos};
+use calyx_utils::{CalyxResult, Diagnostics, Error, Warning, WithPos};
 use ir::Nothing;
 use ir::StaticTiming;
 use itertools::Itertools;
@@ -73,6 +73,9 @@ impl ActiveAssignments {
 /// 5. Invoking components with unmentioned ref cells.
 /// 6. Invoking components with wrong ref cell name.
 /// 7. Invoking components with impatible fed-in cell type for ref cells.
+///
+/// The pass records every independent error and warning it finds in
+/// [ir::Context::diagnostics].
 pub struct WellFormed {
     /// Reserved names
     reserved_names: HashSet<ir::Id>,
@@ -84,6 +87,50 @@ pub struct WellFormed {
     ref_cell_types: HashMap<ir::Id, LinkedHashMap<ir::Id, CellType>>,
     /// Stack of currently active combinational groups
     active_comb: ActiveAssignments,
+    /// Errors found so far, and warnings about the program that don't make
+    /// it malformed.
+    diag: Diagnostics,
+}
+
+impl WellFormed {
+    /// Check the ref cells passed to an invocation of `cell`.
+    fn check_ref_cells(
+        &mut self,
+        cell: &ir::Cell,
+        ref_cells: &[(ir::Id, ir::RRC<ir::Cell>)],
+        attrs: &ir::Attributes,
+    ) {
+        let CellType::Component { name: id } = &cell.prototype else {
+            return;
+        };
+        let cellmap = &self.ref_cell_types[id];
+        let mut mentioned_cells = HashSet::new();
+        for (outcell, incell) in ref_cells.iter() {
+            if let Some(t) = cellmap.get(outcell) {
+                let proto = incell.borrow().prototype.clone();
+                let res =
+                    same_type(t, &proto).map_err(|err| err.with_pos(attrs));
+                self.diag.accumulate(res);
+                mentioned_cells.insert(outcell);
+            } else {
+                self.diag.err(Error::malformed_control(format!(
+                    "{} does not have ref cell named {}",
+                    id, outcell
+                )));
+            }
+        }
+        for id in cellmap.keys() {
+            if mentioned_cells.get(id).is_none() {
+                self.diag.err(
+                    Error::malformed_control(format!(
+                        "unmentioned ref cell: {}",
+                        id
+                    ))
+                    .with_pos(attrs),
+                );
+            }
+        }
+    }
 }
 
 impl ConstructVisitor for WellFormed {
@@ -94,6 +141,7 @@ impl ConstructVisitor for WellFormed {
         let reserved_names =
             RESERVED_NAMES.iter().map(|s| ir::Id::from(*s)).collect();
 
+        let mut diag = Diagnostics::default();
         let mut ref_cell_types = HashMap::new();
         for comp in ctx.components.iter() {
             // Non-main components cannot use @external attribute
@@ -106,14 +154,15 @@ impl ConstructVisitor for WellFormed {
                     if cell.attributes.has(ir::BoolAttr::External)
                         && comp.name != ctx.entrypoint
                     {
-                        Some(Err(Error::malformed_structure("Cell cannot be marked `@external` in non-entrypoint component").with_pos(&cell.attributes)))
+                        diag.err(Error::malformed_structure("Cell cannot be marked `@external` in non-entrypoint component").with_pos(&cell.attributes));
+                        None
                     } else if cell.is_reference() {
-                        Some(Ok((cell.name(), cell.prototype.clone())))
+                        Some((cell.name(), cell.prototype.clone()))
                     } else {
                         None
                     }
                 })
-                .collect::<CalyxResult<_>>()?;
+                .collect();
             ref_cell_types.insert(comp.name, cellmap);
         }
 
@@ -123,6 +172,7 @@ impl ConstructVisitor for WellFormed {
             used_comb_groups: HashSet::new(),
             ref_cell_types,
             active_comb: ActiveAssignments::default(),
+            diag,
         };
 
         Ok(w_f)
@@ -178,17 +228,31 @@ where
         .sorted_by(|(dst1, _), (dst2, _)| ir::Canonical::cmp(dst1, dst2))
         .group_by(|(dst, _)| dst.clone());
 
+    let mut diag = Diagnostics::default();
     for (_, group) in &dst_grps {
         let assigns = group.map(|(_, a)| a).collect_vec();
         if assigns.len() > 1 {
             let msg = assigns.into_iter().join("");
-            return Err(Error::malformed_structure(format!(
+            diag.err(Error::malformed_structure(format!(
                 "Obviously conflicting assignments found:\n{}",
                 msg
             )));
         }
     }
-    Ok(())
+    diag.into_result()
+}
+
+/// The ports that `assigns` write to unconditionally. An enable only checks the
+/// active assignments to these ports, since conflicts between the other active
+/// assignments are reported where they become active.
+fn unconditional_dsts<T>(
+    assigns: &[ir::Assignment<T>],
+) -> HashSet<ir::Canonical> {
+    assigns
+        .iter()
+        .filter(|a| a.guard.is_true())
+        .map(|a| a.dst.borrow().canonical())
+        .collect()
 }
 
 fn same_type(proto_out: &CellType, proto_in: &CellType) -> CalyxResult<()> {
@@ -203,6 +267,223 @@ fn same_type(proto_out: &CellType, proto_in: &CellType) -> CalyxResult<()> {
     }
 }
 
+/// Check that a cell doesn't use a reserved name and is a valid `ref` cell.
+fn check_cell(cell: &ir::Cell, reserved: &HashSet<ir::Id>) -> CalyxResult<()> {
+    // Check if any of the cells use a reserved name.
+    if reserved.contains(&cell.name()) {
+        return Err(
+            Error::reserved_name(cell.name()).with_pos(cell.get_attributes())
+        );
+    }
+    // Check if a `ref` cell is invalid
+    if cell.is_reference() {
+        if cell.is_primitive(Some("std_const")) {
+            return Err(Error::malformed_structure(
+                "constant not allowed for ref cells".to_string(),
+            )
+            .with_pos(cell.get_attributes()));
+        }
+        if matches!(cell.prototype, CellType::ThisComponent) {
+            unreachable!("the current component not allowed for ref cells");
+        }
+    }
+    Ok(())
+}
+
+/// Check that a combinational component only has combinational cells, no
+/// groups, and no control program.
+fn check_comb_component(
+    comp: &Component,
+    comps: &[ir::Component],
+) -> CalyxResult<()> {
+    if !matches!(&*comp.control.borrow(), ir::Control::Empty(..)) {
+        return Err(Error::malformed_structure(format!("Component `{}` is marked combinational but has a non-empty control program", comp.name)));
+    }
+
+    if !comp.get_groups().is_empty() {
+        let group = comp.get_groups().iter().next().unwrap().borrow();
+        return Err(Error::malformed_structure(format!(
+            "Component `{}` is marked combinational but contains a group `{}`",
+            comp.name,
+            group.name()
+        ))
+        .with_pos(&group.attributes));
+    }
+
+    if !comp.get_static_groups().is_empty() {
+        let group = comp.get_static_groups().iter().next().unwrap().borrow();
+        return Err(Error::malformed_structure(format!(
+            "Component `{}` is marked combinational but contains a group `{}`",
+            comp.name,
+            group.name()
+        ))
+        .with_pos(&group.attributes));
+    }
+
+    if !comp.comb_groups.is_empty() {
+        let group = comp.comb_groups.iter().next().unwrap().borrow();
+        return Err(Error::malformed_structure(format!(
+            "Component `{}` is marked combinational but contains a group `{}`",
+            comp.name,
+            group.name()
+        ))
+        .with_pos(&group.attributes));
+    }
+
+    for cell_ref in comp.cells.iter() {
+        let cell = cell_ref.borrow();
+        let is_comb = match &cell.prototype {
+            CellType::Primitive { is_comb, .. } => is_comb.to_owned(),
+            CellType::Constant { .. } => true,
+            CellType::Component { name } => {
+                let comp_idx =
+                    comps.iter().position(|x| x.name == name).unwrap();
+                let comp = comps
+                    .get(comp_idx)
+                    .expect("Found cell that does not exist");
+                comp.is_comb
+            }
+            _ => false,
+        };
+        if !is_comb {
+            return Err(Error::malformed_structure(format!("Component `{}` is marked combinational but contains non-combinational cell `{}`", comp.name, cell.name())).with_pos(&cell.attributes));
+        }
+    }
+    Ok(())
+}
+
+/// Checking that @interval annotations are placed correctly.
+/// There are two options for @interval annotations:
+/// 1. You have written only continuous assignments (this is similar
+///    to primitives written in Verilog).
+/// 2. You are using static<n> control.
+fn check_intervals(comp: &Component) -> CalyxResult<()> {
+    let comp_sig = &comp.signature.borrow();
+    let go_ports = comp_sig.find_all_with_attr(ir::NumAttr::Go).collect_vec();
+    if !go_ports
+        .iter()
+        .any(|go_port| go_port.borrow().attributes.has(ir::NumAttr::Interval))
+    {
+        return Ok(());
+    }
+    match &*comp.control.borrow() {
+        ir::Control::Static(_) | ir::Control::Empty(_) => (),
+        _ => {
+            return Err(Error::malformed_structure(format!(
+            "component {} has dynamic control but has @interval annotations",
+            comp.name
+        ))
+            .with_pos(&comp.attributes))
+        }
+    };
+    if comp.control.borrow().is_empty() {
+        return Ok(());
+    }
+    // Getting "reference value" should be the same for all go ports and
+    // the control.
+    let reference_val =
+        match go_ports[0].borrow().attributes.get(ir::NumAttr::Interval) {
+            Some(val) => val,
+            None => {
+                return Err(Error::malformed_structure(
+                "@interval(n) attribute on all @go ports since there is static<n> control",
+                )
+                .with_pos(&comp.attributes))
+            }
+        };
+    // Checking go ports.
+    for go_port in &go_ports {
+        let go_port_val =
+            match go_port.borrow().attributes.get(ir::NumAttr::Interval) {
+                Some(val) => val,
+                None => {
+                    return Err(Error::malformed_structure(format!(
+                        "@go port expected @interval({reference_val}) attribute on all ports \
+                        since the component has static<n> control",
+                    ))
+                    .with_pos(&comp.attributes))
+                }
+            };
+        if go_port_val != reference_val {
+            return Err(Error::malformed_structure(format!(
+                "@go port expected @interval {reference_val}, got @interval {go_port_val}",
+            ))
+            .with_pos(&go_port.borrow().attributes));
+        }
+        // Checking control latency
+        match comp.control.borrow().get_latency() {
+            None => {
+                unreachable!("already checked control is static")
+            }
+            Some(control_latency) => {
+                if control_latency != reference_val {
+                    return Err(Error::malformed_structure(format!(
+                        "component {} expected @interval {reference_val}, got @interval {control_latency}", comp.name,
+                    ))
+                    .with_pos(&comp.attributes));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Check that a group writes to its own done signal exactly once, and
+/// doesn't write to static cells.
+fn check_done(group: &ir::Group) -> CalyxResult<()> {
+    let gname = group.name();
+    let mut has_done = false;
+    // Find an assignment writing to this group's done condition.
+    for assign in &group.assignments {
+        let dst = assign.dst.borrow();
+        if port_is_static_prim(&dst) {
+            return Err(Error::malformed_structure(format!(
+                "Static cell `{}` written to in non-static group",
+                dst.get_parent_name()
+            ))
+            .with_pos(&assign.attributes));
+        }
+        if dst.is_hole() && dst.name == "done" {
+            // Group has multiple done conditions
+            if has_done {
+                return Err(Error::malformed_structure(format!(
+                    "Group `{}` has multiple done conditions",
+                    gname
+                ))
+                .with_pos(&assign.attributes));
+            } else {
+                has_done = true;
+            }
+            // Group uses another group's done condition
+            if gname != dst.get_parent_name() {
+                return Err(Error::malformed_structure(
+                    format!("Group `{}` refers to the done condition of another group (`{}`).",
+                    gname,
+                    dst.get_parent_name())).with_pos(&dst.attributes));
+            }
+        }
+    }
+
+    // Group does not have a done condition
+    if !has_done {
+        return Err(Error::malformed_structure(format!(
+            "No writes to the `done' hole for group `{gname}'",
+        ))
+        .with_pos(&group.attributes));
+    }
+    Ok(())
+}
+
+/// Attach `msg` to each of the errors in `err`.
+fn with_post_msg(err: Error, msg: Option<String>) -> Error {
+    Error::multiple(
+        err.into_errors()
+            .into_iter()
+            .map(|e| e.with_post_msg(msg.clone()))
+            .collect(),
+    )
+}
+
 impl Visitor for WellFormed {
     fn start(
         &mut self,
@@ -210,71 +491,16 @@ impl Visitor for WellFormed {
         _ctx: &LibrarySignatures,
         comps: &[ir::Component],
     ) -> VisResult {
+        let errors = self.diag.num_errors();
         for cell_ref in comp.cells.iter() {
-            let cell = cell_ref.borrow();
-            // Check if any of the cells use a reserved name.
-            if self.reserved_names.contains(&cell.name()) {
-                return Err(Error::reserved_name(cell.name())
-                    .with_pos(cell.get_attributes()));
-            }
-            // Check if a `ref` cell is invalid
-            if cell.is_reference() {
-                if cell.is_primitive(Some("std_const")) {
-                    return Err(Error::malformed_structure(
-                        "constant not allowed for ref cells".to_string(),
-                    )
-                    .with_pos(cell.get_attributes()));
-                }
-                if matches!(cell.prototype, CellType::ThisComponent) {
-                    unreachable!(
-                        "the current component not allowed for ref cells"
-                    );
-                }
-            }
+            let res = check_cell(&cell_ref.borrow(), &self.reserved_names);
+            self.diag.accumulate(res);
         }
 
         // If the component is combinational, make sure all cells are also combinational,
         // there are no group or comb group definitions, and the control program is empty
         if comp.is_comb {
-            if !matches!(&*comp.control.borrow(), ir::Control::Empty(..)) {
-                return Err(Error::malformed_structure(format!("Component `{}` is marked combinational but has a non-empty control program", comp.name)));
-            }
-
-            if !comp.get_groups().is_empty() {
-                let group = comp.get_groups().iter().next().unwrap().borrow();
-                return Err(Error::malformed_structure(format!("Component `{}` is marked combinational but contains a group `{}`", comp.name, group.name())).with_pos(&group.attributes));
-            }
-
-            if !comp.get_static_groups().is_empty() {
-                let group =
-                    comp.get_static_groups().iter().next().unwrap().borrow();
-                return Err(Error::malformed_structure(format!("Component `{}` is marked combinational but contains a group `{}`", comp.name, group.name())).with_pos(&group.attributes));
-            }
-
-            if !comp.comb_groups.is_empty() {
-                let group = comp.comb_groups.iter().next().unwrap().borrow();
-                return Err(Error::malformed_structure(format!("Component `{}` is marked combinational but contains a group `{}`", comp.name, group.name())).with_pos(&group.attributes));
-            }
-
-            for cell_ref in comp.cells.iter() {
-                let cell = cell_ref.borrow();
-                let is_comb = match &cell.prototype {
-                    CellType::Primitive { is_comb, .. } => is_comb.to_owned(),
-                    CellType::Constant { .. } => true,
-                    CellType::Component { name } => {
-                        let comp_idx =
-                            comps.iter().position(|x| x.name == name).unwrap();
-                        let comp = comps
-                            .get(comp_idx)
-                            .expect("Found cell that does not exist");
-                        comp.is_comb
-                    }
-                    _ => false,
-                };
-                if !is_comb {
-                    return Err(Error::malformed_structure(format!("Component `{}` is marked combinational but contains non-combinational cell `{}`", comp.name, cell.name())).with_pos(&cell.attributes));
-                }
-            }
+            self.diag.accumulate(check_comb_component(comp, comps));
         }
         // in ast_to_ir, we should have already checked that static components have static_control_body
         if comp.latency.is_some() {
@@ -285,124 +511,12 @@ impl Visitor for WellFormed {
             );
         }
 
-        // Checking that @interval annotations are placed correctly.
-        // There are two options for @interval annotations:
-        // 1. You have written only continuous assignments (this is similar
-        // to primitives written in Verilog).
-        // 2. You are using static<n> control.
-        let comp_sig = &comp.signature.borrow();
-        let go_ports =
-            comp_sig.find_all_with_attr(ir::NumAttr::Go).collect_vec();
-        if go_ports.iter().any(|go_port| {
-            go_port.borrow().attributes.has(ir::NumAttr::Interval)
-        }) {
-            match &*comp.control.borrow() {
-                ir::Control::Static(_) | ir::Control::Empty(_) => (),
-                _ => return Err(Error::malformed_structure(
-                    format!("component {} has dynamic control but has @interval annotations", comp.name),
-                    )
-                    .with_pos(&comp.attributes)),
-            };
-            if !comp.control.borrow().is_empty() {
-                // Getting "reference value" should be the same for all go ports and
-                // the control.
-                let reference_val = match go_ports[0]
-                    .borrow()
-                    .attributes
-                    .get(ir::NumAttr::Interval)
-                {
-                    Some(val) => val,
-                    None => {
-                        return Err(Error::malformed_structure(
-                        "@interval(n) attribute on all @go ports since there is static<n> control",
-                        )
-                        .with_pos(&comp.attributes))
-                    }
-                };
-                // Checking go ports.
-                for go_port in &go_ports {
-                    let go_port_val = match go_port
-                        .borrow()
-                        .attributes
-                        .get(ir::NumAttr::Interval)
-                    {
-                        Some(val) => val,
-                        None => {
-                            return Err(Error::malformed_structure(format!(
-                                "@go port expected @interval({reference_val}) attribute on all ports \
-                                since the component has static<n> control",
-                            ))
-                            .with_pos(&comp.attributes))
-                        }
-                    };
-                    if go_port_val != reference_val {
-                        return Err(Error::malformed_structure(format!(
-                            "@go port expected @interval {reference_val}, got @interval {go_port_val}",
-                        ))
-                        .with_pos(&go_port.borrow().attributes));
-                    }
-                    // Checking control latency
-                    match comp.control.borrow().get_latency() {
-                        None => {
-                            unreachable!("already checked control is static")
-                        }
-                        Some(control_latency) => {
-                            if control_latency != reference_val {
-                                return Err(Error::malformed_structure(format!(
-                                    "component {} expected @interval {reference_val}, got @interval {control_latency}", comp.name,
-                                ))
-                                .with_pos(&comp.attributes));
-                            }
-                        }
-                    }
-                }
-            }
-        }
+        self.diag.accumulate(check_intervals(comp));
 
         // For each non-combinational group, check if there is at least one write to the done
         // signal of that group and that the write is to the group's done signal.
         for gr in comp.get_groups().iter() {
-            let group = gr.borrow();
-            let gname = group.name();
-            let mut has_done = false;
-            // Find an assignment writing to this group's done condition.
-            for assign in &group.assignments {
-                let dst = assign.dst.borrow();
-                if port_is_static_prim(&dst) {
-                    return Err(Error::malformed_structure(format!(
-                        "Static cell `{}` written to in non-static group",
-                        dst.get_parent_name()
-                    ))
-                    .with_pos(&assign.attributes));
-                }
-                if dst.is_hole() && dst.name == "done" {
-                    // Group has multiple done conditions
-                    if has_done {
-                        return Err(Error::malformed_structure(format!(
-                            "Group `{}` has multiple done conditions",
-                            gname
-                        ))
-                        .with_pos(&assign.attributes));
-                    } else {
-                        has_done = true;
-                    }
-                    // Group uses another group's done condition
-                    if gname != dst.get_parent_name() {
-                        return Err(Error::malformed_structure(
-                            format!("Group `{}` refers to the done condition of another group (`{}`).",
-                            gname,
-                            dst.get_parent_name())).with_pos(&dst.attributes));
-                    }
-                }
-            }
-
-            // Group does not have a done condition
-            if !has_done {
-                return Err(Error::malformed_structure(format!(
-                    "No writes to the `done' hole for group `{gname}'",
-                ))
-                .with_pos(&group.attributes));
-            }
+            self.diag.accumulate(check_done(&gr.borrow()));
         }
 
         // Don't need to check done condition for static groups. Instead, just
@@ -413,7 +527,7 @@ impl Visitor for WellFormed {
             let group_latency = group.get_latency();
             // Check that for each interval %[beg, end], end > beg.
             for assign in &group.assignments {
-                assign.guard.check_for_each_info(
+                let res = assign.guard.check_for_each_info(
                     &mut |static_timing: &StaticTiming| {
                         if static_timing.get_interval().0
                             >= static_timing.get_interval().1
@@ -434,36 +548,44 @@ impl Visitor for WellFormed {
                             Ok(())
                         }
                     },
-                )?;
+                );
+                self.diag.accumulate(res);
             }
         }
 
         // Check for obvious conflicting assignments in the continuous assignments
-        obvious_conflicts(
+        self.diag.accumulate(obvious_conflicts(
             comp.continuous_assignments.iter(),
             std::iter::empty::<&ir::Assignment<StaticTiming>>(),
-        )?;
+        ));
         // Check for obvious conflicting assignments between the continuous assignments and the groups
         for cgr in comp.comb_groups.iter() {
             for assign in &cgr.borrow().assignments {
                 let dst = assign.dst.borrow();
                 if port_is_static_prim(&dst) {
-                    return Err(Error::malformed_structure(format!(
-                        "Static cell `{}` written to in non-static group",
-                        dst.get_parent_name()
-                    ))
-                    .with_pos(&assign.attributes));
+                    self.diag.err(
+                        Error::malformed_structure(format!(
+                            "Static cell `{}` written to in non-static group",
+                            dst.get_parent_name()
+                        ))
+                        .with_pos(&assign.attributes),
+                    );
                 }
             }
-            obvious_conflicts(
+            self.diag.accumulate(obvious_conflicts(
                 cgr.borrow()
                     .assignments
                     .iter()
                     .chain(comp.continuous_assignments.iter()),
                 std::iter::empty::<&ir::Assignment<StaticTiming>>(),
-            )?;
+            ));
         }
 
+        // The checks on the control program assume that the groups are well
+        // formed, so skip them if the component isn't.
+        if self.diag.num_errors() > errors {
+            return Ok(Action::SkipChildren);
+        }
         Ok(Action::Continue)
     }
 
@@ -479,10 +601,12 @@ impl Visitor for WellFormed {
         let group = s.group.borrow();
 
         // check for obvious conflicts within static groups and continuous/comb group assigns
-        obvious_conflicts(
+        let dsts = unconditional_dsts(&group.assignments);
+        let res = obvious_conflicts(
             comp.continuous_assignments
                 .iter()
-                .chain(self.active_comb.iter()),
+                .chain(self.active_comb.iter())
+                .filter(|a| dsts.contains(&a.dst.borrow().canonical())),
             group.assignments.iter(),
         )
         .map_err(|err| {
@@ -491,8 +615,9 @@ impl Visitor for WellFormed {
                 .copy_span()
                 .into_option()
                 .map(|s| s.format("Assigments activated by group enable"));
-            err.with_post_msg(msg)
-        })?;
+            with_post_msg(err, msg)
+        });
+        self.diag.accumulate(res);
 
         Ok(Action::Continue)
     }
@@ -512,7 +637,7 @@ impl Visitor for WellFormed {
             asgn.guard.is_true() && asgn.src.borrow().is_constant(1, 1);
 
         if const_done_assign {
-            return Err(Error::malformed_structure("Group with constant done condition is invalid. Use `comb group` instead to define a combinational group.").with_pos(&group.attributes));
+            self.diag.err(Error::malformed_structure("Group with constant done condition is invalid. Use `comb group` instead to define a combinational group.").with_pos(&group.attributes));
         }
 
         // A group with "static"=0 annotation
@@ -522,16 +647,18 @@ impl Visitor for WellFormed {
             .map(|v| v == 0)
             .unwrap_or(false)
         {
-            return Err(Error::malformed_structure("Group with annotation \"promotable\"=0 is invalid. Use `comb group` instead to define a combinational group or if the group's done condition is not constant, provide the correct \"static\" annotation.").with_pos(&group.attributes));
+            self.diag.err(Error::malformed_structure("Group with annotation \"promotable\"=0 is invalid. Use `comb group` instead to define a combinational group or if the group's done condition is not constant, provide the correct \"static\" annotation.").with_pos(&group.attributes));
         }
 
         // Check if the group has obviously conflicting assignments with the continuous assignments and the active combinational groups
-        obvious_conflicts(
-            group
-                .assignments
-                .iter()
-                .chain(comp.continuous_assignments.iter())
-                .chain(self.active_comb.iter()),
+        let dsts = unconditional_dsts(&group.assignments);
+        let res = obvious_conflicts(
+            group.assignments.iter().chain(
+                comp.continuous_assignments
+                    .iter()
+                    .chain(self.active_comb.iter())
+                    .filter(|a| dsts.contains(&a.dst.borrow().canonical())),
+            ),
             std::iter::empty::<&ir::Assignment<StaticTiming>>(),
         )
         .map_err(|err| {
@@ -540,8 +667,9 @@ impl Visitor for WellFormed {
                 .copy_span()
                 .into_option()
                 .map(|s| s.format("Assigments activated by group enable"));
-            err.with_post_msg(msg)
-        })?;
+            with_post_msg(err, msg)
+        });
+        self.diag.accumulate(res);
 
         Ok(Action::Continue)
     }
@@ -557,34 +685,7 @@ impl Visitor for WellFormed {
             self.used_comb_groups.insert(c.borrow().name());
         }
         // Only refers to ports defined in the invoked instance.
-        let cell = s.comp.borrow();
-
-        if let CellType::Component { name: id } = &cell.prototype {
-            let cellmap = &self.ref_cell_types[id];
-            let mut mentioned_cells = HashSet::new();
-            for (outcell, incell) in s.ref_cells.iter() {
-                if let Some(t) = cellmap.get(outcell) {
-                    let proto = incell.borrow().prototype.clone();
-                    same_type(t, &proto)
-                        .map_err(|err| err.with_pos(&s.attributes))?;
-                    mentioned_cells.insert(outcell);
-                } else {
-                    return Err(Error::malformed_control(format!(
-                        "{} does not have ref cell named {}",
-                        id, outcell
-                    )));
-                }
-            }
-            for id in cellmap.keys() {
-                if mentioned_cells.get(id).is_none() {
-                    return Err(Error::malformed_control(format!(
-                        "unmentioned ref cell: {}",
-                        id
-                    ))
-                    .with_pos(&s.attributes));
-                }
-            }
-        }
+        self.check_ref_cells(&s.comp.borrow(), &s.ref_cells, &s.attributes);
 
         Ok(Action::Continue)
     }
@@ -597,34 +698,7 @@ impl Visitor for WellFormed {
         _comps: &[ir::Component],
     ) -> VisResult {
         // Only refers to ports defined in the invoked instance.
-        let cell = s.comp.borrow();
-
-        if let CellType::Component { name: id } = &cell.prototype {
-            let cellmap = &self.ref_cell_types[id];
-            let mut mentioned_cells = HashSet::new();
-            for (outcell, incell) in s.ref_cells.iter() {
-                if let Some(t) = cellmap.get(outcell) {
-                    let proto = incell.borrow().prototype.clone();
-                    same_type(t, &proto)
-                        .map_err(|err| err.with_pos(&s.attributes))?;
-                    mentioned_cells.insert(outcell);
-                } else {
-                    return Err(Error::malformed_control(format!(
-                        "{} does not have ref cell named {}",
-                        id, outcell
-                    )));
-                }
-            }
-            for id in cellmap.keys() {
-                if mentioned_cells.get(id).is_none() {
-                    return Err(Error::malformed_control(format!(
-                        "unmentioned ref cell: {}",
-                        id
-                    ))
-                    .with_pos(&s.attributes));
-                }
-            }
-        }
+        self.check_ref_cells(&s.comp.borrow(), &s.ref_cells, &s.attributes);
 
         Ok(Action::Continue)
     }
@@ -640,7 +714,7 @@ impl Visitor for WellFormed {
             let cg = cgr.borrow();
             let assigns = &cg.assignments;
             // Check if the combinational group conflicts with the active combinational groups
-            obvious_conflicts(
+            let res = obvious_conflicts(
                 assigns.iter().chain(self.active_comb.iter()),
                 std::iter::empty::<&ir::Assignment<StaticTiming>>(),
             )
@@ -649,16 +723,17 @@ impl Visitor for WellFormed {
                     "Assignments from `{}' are actived here",
                     cg.name()
                 ));
-                err.with_post_msg(Some(msg))
-            })?;
+                with_post_msg(err, Some(msg))
+            });
+            self.diag.accumulate(res);
             // Push the combinational group to the stack of active groups
             self.active_comb.push(assigns);
         } else if !s.port.borrow().has_attribute(ir::BoolAttr::Stable) {
-            let msg = s.attributes.copy_span().format(format!(
-                    "If statement has no comb group and its condition port {} is unstable",
-                    s.port.borrow().canonical()
-                ));
-            log::warn!("{msg}");
+            let msg = format!(
+                "If statement has no comb group and its condition port {} is unstable",
+                s.port.borrow().canonical()
+            );
+            self.diag.warning(Warning::new(msg).with_pos(&s.attributes));
         }
         Ok(Action::Continue)
     }
@@ -671,11 +746,11 @@ impl Visitor for WellFormed {
         _comps: &[ir::Component],
     ) -> VisResult {
         if !s.port.borrow().has_attribute(ir::BoolAttr::Stable) {
-            let msg = s.attributes.copy_span().format(format!(
+            let msg = format!(
                 "static if statement's condition port {} is unstable",
                 s.port.borrow().canonical()
-            ));
-            log::warn!("{msg}");
+            );
+            self.diag.warning(Warning::new(msg).with_pos(&s.attributes));
         }
         Ok(Action::Continue)
     }
@@ -707,7 +782,7 @@ impl Visitor for WellFormed {
             let cg = cgr.borrow();
             let assigns = &cg.assignments;
             // Check if the combinational group conflicts with the active combinational groups
-            obvious_conflicts(
+            let res = obvious_conflicts(
                 assigns.iter().chain(self.active_comb.iter()),
                 std::iter::empty::<&ir::Assignment<StaticTiming>>(),
             )
@@ -716,16 +791,17 @@ impl Visitor for WellFormed {
                     "Assignments from `{}' are actived here",
                     cg.name()
                 ));
-                err.with_post_msg(Some(msg))
-            })?;
+                with_post_msg(err, Some(msg))
+            });
+            self.diag.accumulate(res);
             // Push the combinational group to the stack of active groups
             self.active_comb.push(assigns);
         } else if !s.port.borrow().has_attribute(ir::BoolAttr::Stable) {
-            let msg = s.attributes.copy_span().format(format!(
-                    "While loop has no comb group and its condition port {} is unstable",
-                    s.port.borrow().canonical()
-                ));
-            log::warn!("{msg}");
+            let msg = format!(
+                "While loop has no comb group and its condition port {} is unstable",
+                s.port.borrow().canonical()
+            );
+            self.diag.warning(Warning::new(msg).with_pos(&s.attributes));
         }
         Ok(Action::Continue)
     }
@@ -773,46 +849,37 @@ impl Visitor for WellFormed {
         });
 
         // Find unused groups
-        let mut all_groups: HashSet<ir::Id> = comp
-            .get_groups()
-            .iter()
-            .map(|g| g.borrow().name())
-            .collect();
-        let static_groups: HashSet<ir::Id> = comp
-            .get_static_groups()
-            .iter()
-            .map(|g| g.borrow().name())
-            .collect();
-        all_groups.extend(static_groups);
-
-        if let Some(group) = all_groups.difference(&self.used_groups).next() {
-            match comp.find_group(*group) {
-                Some(gr) => {
-                    let gr = gr.borrow();
-                    return Err(
-                        Error::unused(*group, "group").with_pos(&gr.attributes)
-                    );
-                }
-                None => {
-                    let gr = comp.find_static_group(*group).unwrap();
-                    let gr = gr.borrow();
-                    return Err(
-                        Error::unused(*group, "group").with_pos(&gr.attributes)
-                    );
-                }
+        for gr in comp.get_groups().iter() {
+            let gr = gr.borrow();
+            if !self.used_groups.contains(&gr.name()) {
+                self.diag.err(
+                    Error::unused(gr.name(), "group").with_pos(&gr.attributes),
+                );
             }
-        };
+        }
+        for gr in comp.get_static_groups().iter() {
+            let gr = gr.borrow();
+            if !self.used_groups.contains(&gr.name()) {
+                self.diag.err(
+                    Error::unused(gr.name(), "group").with_pos(&gr.attributes),
+                );
+            }
+        }
 
-        let all_comb_groups: HashSet<ir::Id> =
-            comp.comb_groups.iter().map(|g| g.borrow().name()).collect();
-        if let Some(comb_group) =
-            all_comb_groups.difference(&self.used_comb_groups).next()
-        {
-            let cgr = comp.find_comb_group(*comb_group).unwrap();
+        for cgr in comp.comb_groups.iter() {
             let cgr = cgr.borrow();
-            return Err(Error::unused(*comb_group, "combinational group")
-                .with_pos(&cgr.attributes));
+            if !self.used_comb_groups.contains(&cgr.name()) {
+                self.diag.err(
+                    Error::unused(cgr.name(), "combinational group")
+                        .with_pos(&cgr.attributes),
+                );
+            }
         }
         Ok(Action::Continue)
     }
+
+    fn finish_context(&mut self, ctx: &mut ir::Context) -> VisResult {
+        ctx.diagnostics.append(std::mem::take(&mut self.diag));
+        Ok(Action::Continue)
+    }
 }
@@ -1,5 +1,6 @@
 //! Errors generated by the compiler.
 use crate::{GPosIdx, Id, WithPos};
+use serde_json::json;
 
 /// Convience wrapper to represent success or meaningul compiler error.
 pub type CalyxResult<T> = std::result::Result<T, Error>;
@@ -116,12 +117,188 @@ impl Error {
             post_msg: None,
         }
     }
+    /// Combine independent errors into one error that reports all of them.
+    pub fn multiple(mut errors: Vec<Error>) -> Self {
+        if errors.len() == 1 {
+            return errors.pop().unwrap();
+        }
+        Self {
+            kind: Box::new(ErrorKind::Multiple(errors)),
+            pos: GPosIdx::UNKNOWN,
+            post_msg: None,
+        }
+    }
+    /// The independent errors that make up this error.
+    pub fn into_errors(self) -> Vec<Error> {
+        match *self.kind {
+            ErrorKind::Multiple(errors) => errors,
+            kind => vec![Self {
+                kind: Box::new(kind),
+                pos: self.pos,
+                post_msg: self.post_msg,
+            }],
+        }
+    }
     pub fn location(&self) -> (&str, usize, usize) {
         self.pos.get_location()
     }
     pub fn message(&self) -> String {
         self.kind.to_string()
     }
+    /// A JSON object describing this error. Use [Error::into_errors] first to
+    /// get one object for each error.
+    pub fn to_json(&self) -> serde_json::Value {
+        diagnostic_json(
+            "error",
+            self.kind.name(),
+            &self.message(),
+            self.pos,
+            self.post_msg.as_deref(),
+        )
+    }
+}
+
+/// A diagnostic as a JSON object. Errors, warnings and log messages are all
+/// reported with this schema.
+pub fn diagnostic_json(
+    severity: &str,
+    kind: &str,
+    message: &str,
+    pos: GPosIdx,
+    post_msg: Option<&str>,
+) -> serde_json::Value {
+    let mut obj = position_json(pos);
+    obj["severity"] = json!(severity);
+    obj["kind"] = json!(kind);
+    obj["message"] = json!(message);
+    obj["post_msg"] = json!(post_msg);
+    obj
+}
+
+/// The location fields of a JSON diagnostic. They are all `null` when the
+/// position is unknown.
+fn position_json(pos: GPosIdx) -> serde_json::Value {
+    let Some(pos) = pos.into_option() else {
+        return json!({
+            "file": null,
+            "start": null,
+            "end": null,
+            "line": null,
+            "column": null,
+            "end_line": null,
+            "end_column": null,
+        });
+    };
+    let (file, start, end) = pos.get_location();
+    let ((line, column), (end_line, end_column)) = pos.get_line_col();
+    json!({
+        "file": file,
+        "start": start,
+        "end": end,
+        "line": line,
+        "column": column,
+        "end_line": end_line,
+        "end_column": end_column,
+    })
+}
+
+/// Collects the errors and warnings found while checking a program so that
+/// all of them are reported at once instead of stopping at the first error.
+#[derive(Default)]
+pub struct Diagnostics {
+    errors: Vec<Error>,
+    warnings: Vec<Warning>,
+}
+
+impl Diagnostics {
+    /// Record an error and keep going.
+    pub fn err(&mut self, err: Error) {
+        self.errors.extend(err.into_errors());
+    }
+    /// Record a warning.
+    pub fn warning(&mut self, warning: Warning) {
+        self.warnings.push(warning);
+    }
+    /// Record the error in `res` if there is one, and return its value
+    /// otherwise.
+    pub fn accumulate<T>(&mut self, res: CalyxResult<T>) -> Option<T> {
+        match res {
+            Ok(v) => Some(v),
+            Err(err) => {
+                self.err(err);
+                None
+            }
+        }
+    }
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+    pub fn num_errors(&self) -> usize {
+        self.errors.len()
+    }
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+    /// Record the errors and warnings collected by `other`.
+    pub fn append(&mut self, mut other: Diagnostics) {
+        self.errors.append(&mut other.errors);
+        self.warnings.append(&mut other.warnings);
+    }
+    /// Remove and return the warnings recorded so far.
+    pub fn take_warnings(&mut self) -> Vec<Warning> {
+        std::mem::take(&mut self.warnings)
+    }
+    /// Fail with all the errors recorded so far, and forget them.
+    pub fn take_errors(&mut self) -> CalyxResult<()> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::multiple(std::mem::take(&mut self.errors)))
+        }
+    }
+    /// Fail with all the recorded errors.
+    pub fn into_result(mut self) -> CalyxResult<()> {
+        self.take_errors()
+    }
+}
+
+/// A warning generated by the compiler. Unlike an [Error], a warning does not stop compilation.
+pub struct Warning {
+    msg: String,
+    pos: GPosIdx,
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.pos == GPosIdx::UNKNOWN {
+            write!(f, "{}", self.msg)
+        } else {
+            write!(f, "{}", self.pos.format(&self.msg))
+        }
+    }
+}
+
+impl Warning {
+    pub fn new<S: ToString>(msg: S) -> Self {
+        Self {
+            msg: msg.to_string(),
+            pos: GPosIdx::UNKNOWN,
+        }
+    }
+    pub fn with_pos<T: WithPos>(mut self, pos: &T) -> Self {
+        self.pos = pos.copy_span();
+        self
+    }
+    pub fn location(&self) -> (&str, usize, usize) {
+        self.pos.get_location()
+    }
+    pub fn message(&self) -> String {
+        self.msg.clone()
+    }
+    /// A JSON object describing this warning.
+    pub fn to_json(&self) -> serde_json::Value {
+        diagnostic_json("warning", "Warning", &self.msg, self.pos, None)
+    }
 }
 
 /// Standard error type for Calyx errors.
@@ -155,6 +332,30 @@ enum ErrorKind {
     InvalidFile(String),
     /// Failed to write the output
     WriteError(String),
+
+    /// Several independent errors
+    Multiple(Vec<Error>),
+}
+
+impl ErrorKind {
+    /// Name of the kind of error
+    fn name(&self) -> &'static str {
+        use ErrorKind::*;
+        match self {
+            ReservedName(..) => "ReservedName",
+            MalformedControl(..) => "MalformedControl",
+            MalformedStructure(..) => "MalformedStructure",
+            PassAssumption(..) => "PassAssumption",
+            Undefined(..) => "Undefined",
+            AlreadyBound(..) => "AlreadyBound",
+            Unused(..) => "Unused",
+            Papercut(..) => "Papercut",
+            Misc(..) => "Misc",
+            InvalidFile(..) => "InvalidFile",
+            WriteError(..) => "WriteError",
+            Multiple(..) => "Multiple",
+        }
+    }
 }
 
 impl std::fmt::Display for ErrorKind {
@@ -186,6 +387,16 @@ impl std::fmt::Display for ErrorKind {
             InvalidFile(msg) | WriteError(msg) | Misc(msg) => {
                 write!(f, "{msg}")
             }
+            Multiple(errors) => {
+                // the first error is prefixed by whoever prints this one
+                for (idx, err) in errors.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, "\nError: ")?;
+                    }
+                    write!(f, "{err:?}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -9,7 +9,7 @@ mod weight_graph;
 mod math;
 pub(crate) mod measure_time;
 
-pub use errors::{CalyxResult, Error};
+pub use errors::{diagnostic_json, CalyxResult, Diagnostics, Error, Warning};
 pub use id::{GSym, GetName, Id};
 pub use math::bits_needed_for;
 pub use namegenerator::NameGenerator;
@@ -48,6 +48,8 @@ impl Default for PositionTable {
 impl PositionTable {
     /// The unknown position
     pub const UNKNOWN: PosIdx = PosIdx(0);
+    /// The file of the unknown position
+    pub const UNKNOWN_FILE: FileIdx = FileIdx(0);
 
     /// Create a new position table where the first file and first position are unknown
     pub fn new() -> Self {
@@ -222,6 +224,29 @@ impl GPosIdx {
         (name, pos_d.start, pos_d.end)
     }
 
+    /// Returns the 1-indexed line and column of the start and the end of this
+    /// position.
+    pub fn get_line_col(&self) -> ((usize, usize), (usize, usize)) {
+        let table = GlobalPositionTable::as_ref();
+        let pos_d = table.get_pos(self.0);
+        let source = &table.get_file_data(pos_d.file).source;
+        let line_col = |offset: usize| {
+            let before = &source[..offset.min(source.len())];
+            let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+            (
+                before.matches('\n').count() + 1,
+                before[line_start..].chars().count() + 1,
+            )
+        };
+        (line_col(pos_d.start), line_col(pos_d.end))
+    }
+
+    /// Returns the source code of the file that contains this position
+    pub fn get_source(&self) -> &str {
+        let table = GlobalPositionTable::as_ref();
+        table.get_source(table.get_pos(self.0).file)
+    }
+
     /// Visualizes the span without any message or mkaring
     pub fn show(&self) -> String {
         let (lines, _, linum) = self.get_lines();
@@ -112,7 +112,7 @@ def register_stages(registry):
         futil.CalyxStage(
             "resources",
             "-b resources",
-            "Generate a CSV that estimates a Calyx program's resource usage",
+            "Generate a JSON report that estimates a Calyx program's resource usage",
         )
     )
 
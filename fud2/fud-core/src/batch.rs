@@ -0,0 +1,325 @@
+//! Run many builds at once in a single Ninja build graph.
+//!
+//! Each entry in a batch is an ordinary plan whose generated files live in a subdirectory of the
+//! shared working directory. The setups that the plans need are emitted only once, and Ninja is
+//! free to run the steps of different entries in parallel.
+
+use crate::cache::{self, Cache, Hits};
+use crate::config;
+use crate::exec::{Driver, Plan};
+use crate::run::{
+    describe_plan, emit_cache_rule, emit_setups, emit_steps, join_paths,
+    EmitResult, Emitter,
+};
+use camino::{Utf8Path, Utf8PathBuf};
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::process::Command;
+use std::time::Duration;
+
+/// One build in a batch.
+pub struct Entry {
+    /// A unique name for the entry, which is also the subdirectory that holds its files.
+    pub name: String,
+    pub plan: Plan,
+}
+
+/// The outcome of building one entry of a batch.
+pub struct Outcome {
+    pub name: String,
+
+    /// Whether all of the entry's results were produced.
+    pub ok: bool,
+
+    /// The total time spent running the entry's build commands, when Ninja recorded it.
+    pub elapsed: Option<Duration>,
+
+    /// The number of cycles reported by a simulation, if the entry ran one.
+    pub cycles: Option<u64>,
+
+    /// The copies of the entry's results in the output directory.
+    pub results: Vec<Utf8PathBuf>,
+}
+
+pub struct Batch<'a> {
+    pub driver: &'a Driver,
+    pub entries: Vec<Entry>,
+    pub workdir: Utf8PathBuf,
+    pub config_data: figment::Figment,
+    pub global_config: config::GlobalConfig,
+}
+
+impl<'a> Batch<'a> {
+    /// Create a batch from plans that share a working directory. Each plan is moved into its own
+    /// subdirectory, named after its first input file.
+    pub fn new(
+        driver: &'a Driver,
+        plans: Vec<Plan>,
+        workdir: Utf8PathBuf,
+        config_data: figment::Figment,
+    ) -> Self {
+        let global_config: config::GlobalConfig =
+            config_data.extract().expect("failed to load config");
+
+        let mut names = HashSet::new();
+        let entries = plans
+            .into_iter()
+            .map(|mut plan| {
+                let stem = plan
+                    .inputs
+                    .first()
+                    .and_then(|f| f.file_stem())
+                    .unwrap_or("entry")
+                    .to_string();
+                let mut name = stem.clone();
+                let mut suffix = 1;
+                while !names.insert(name.clone()) {
+                    suffix += 1;
+                    name = format!("{}-{}", stem, suffix);
+                }
+                plan.relocate(Utf8Path::new(&name));
+                plan.stdout = false;
+                Entry { name, plan }
+            })
+            .collect();
+
+        Self {
+            driver,
+            entries,
+            workdir,
+            config_data,
+            global_config,
+        }
+    }
+
+    /// Print the plan for each entry.
+    pub fn show(&self) {
+        for entry in &self.entries {
+            println!("{}: {}", entry.name, join_paths(&entry.plan.inputs));
+            for (op, _, outputs) in &entry.plan.steps {
+                println!(
+                    "  {}: {} -> {}",
+                    op,
+                    self.driver.ops[*op].name,
+                    join_paths(outputs)
+                );
+            }
+        }
+    }
+
+    /// Emit a single `build.ninja` file for every entry in the batch.
+    pub fn emit<T: Write + 'a>(&self, out: T) -> EmitResult {
+        let hits: Vec<_> = self.entries.iter().map(|_| Hits::new()).collect();
+        self.emit_with_hits(out, &hits)
+    }
+
+    /// Emit `build.ninja`, copying the outputs of the steps in `hits`, which has an element for
+    /// each entry, from the cache.
+    fn emit_with_hits<T: Write + 'a>(
+        &self,
+        out: T,
+        hits: &[Hits],
+    ) -> EmitResult {
+        let mut emitter =
+            Emitter::new(out, self.config_data.clone(), self.workdir.clone());
+        let plans: Vec<_> = self.entries.iter().map(|e| &e.plan).collect();
+        emit_setups(&mut emitter, self.driver, &self.global_config, &plans)?;
+        if hits.iter().any(|h| !h.is_empty()) {
+            emit_cache_rule(&mut emitter)?;
+        }
+
+        for (entry, hits) in self.entries.iter().zip(hits) {
+            emitter.comment(&format!("build targets for {}", entry.name))?;
+            emitter.scratch_dir = entry.name.clone().into();
+            emit_steps(&mut emitter, self.driver, &entry.plan, hits)?;
+            writeln!(emitter.out)?;
+        }
+
+        let results: Vec<_> = self
+            .entries
+            .iter()
+            .flat_map(|e| e.plan.results.iter().cloned())
+            .collect();
+        writeln!(emitter.out, "default {}", join_paths(&results))?;
+        Ok(())
+    }
+
+    /// Print the `build.ninja` file to stdout.
+    pub fn emit_to_stdout(&self) -> EmitResult {
+        self.emit(std::io::stdout())
+    }
+
+    /// Ensure that the working directory exists and write `build.ninja` inside it.
+    pub fn emit_to_dir(&self) -> EmitResult {
+        let hits: Vec<_> = self.entries.iter().map(|_| Hits::new()).collect();
+        self.write_ninja(&hits)
+    }
+
+    fn write_ninja(&self, hits: &[Hits]) -> EmitResult {
+        std::fs::create_dir_all(&self.workdir)?;
+        let ninja_file =
+            std::fs::File::create(self.workdir.join("build.ninja"))?;
+        self.emit_with_hits(ninja_file, hits)
+    }
+
+    /// Emit `build.ninja`, run every entry's build, and copy the results to `out_dir`.
+    ///
+    /// Ninja keeps going when an entry fails so that the other entries still finish.
+    pub fn emit_and_run(
+        &self,
+        out_dir: &Utf8Path,
+    ) -> anyhow::Result<Vec<Outcome>> {
+        let stale_dir = self.workdir.exists();
+        std::fs::create_dir_all(&self.workdir)?;
+
+        // Look for steps whose outputs are already cached.
+        let cache = Cache::from_config(&self.driver.name, &self.global_config);
+        let mut keys = vec![];
+        let mut hits = vec![];
+        for entry in &self.entries {
+            match &cache {
+                Some(cache) => {
+                    let scratch_dir = Utf8Path::new(&entry.name);
+                    let desc = describe_plan(
+                        self.driver,
+                        &entry.plan,
+                        &self.config_data,
+                        &self.global_config,
+                        scratch_dir,
+                    )?;
+                    let entry_keys = cache::step_keys(
+                        self.driver,
+                        &entry.plan,
+                        &desc,
+                        scratch_dir,
+                    )?;
+                    hits.push(cache.lookup(&entry.plan, &entry_keys));
+                    keys.push(entry_keys);
+                }
+                None => hits.push(Hits::new()),
+            }
+        }
+        self.write_ninja(&hits)?;
+
+        let mut cmd = Command::new(&self.global_config.ninja);
+        cmd.current_dir(&self.workdir).args(["-k", "0"]);
+        cmd.status()?;
+
+        let times = read_ninja_log(&self.workdir.join(".ninja_log"));
+        std::fs::create_dir_all(out_dir)?;
+        let mut outcomes = vec![];
+        for (idx, entry) in self.entries.iter().enumerate() {
+            let mut outcome = Outcome {
+                name: entry.name.clone(),
+                ok: true,
+                elapsed: times.get(entry.name.as_str()).copied(),
+                cycles: None,
+                results: vec![],
+            };
+            for result in &entry.plan.results {
+                let built = self.workdir.join(result);
+                if !built.exists() {
+                    outcome.ok = false;
+                    continue;
+                }
+                if outcome.cycles.is_none() {
+                    outcome.cycles = read_cycles(&built);
+                }
+                // Results are already in a subdirectory named after the entry.
+                let dest = out_dir.join(result);
+                std::fs::create_dir_all(dest.parent().unwrap())?;
+                std::fs::copy(&built, &dest)?;
+                outcome.results.push(dest);
+            }
+            // Ninja keeps going after failures, so only cache the outputs of entries that worked.
+            if let Some(cache) = &cache {
+                if outcome.ok {
+                    cache.store(&entry.plan, &keys[idx], &hits[idx])?;
+                }
+            }
+            outcomes.push(outcome);
+        }
+
+        if !self.global_config.keep_build_dir && !stale_dir {
+            std::fs::remove_dir_all(&self.workdir)?;
+        }
+        Ok(outcomes)
+    }
+}
+
+/// Total the time that Ninja spent running build commands in each top-level subdirectory of the
+/// build directory, using the timings Ninja records in `.ninja_log`.
+fn read_ninja_log(path: &Utf8Path) -> HashMap<String, Duration> {
+    let mut times = HashMap::new();
+    let Ok(log) = std::fs::read_to_string(path) else {
+        return times;
+    };
+    // Later runs append to the log, so only the last line for each output counts.
+    let mut last = HashMap::new();
+    for line in log.lines().filter(|l| !l.starts_with('#')) {
+        let fields: Vec<_> = line.split('\t').collect();
+        let [start, end, _, output, ..] = fields[..] else {
+            continue;
+        };
+        if let (Ok(start), Ok(end)) = (start.parse::<u64>(), end.parse::<u64>())
+        {
+            last.insert(output, (start, end));
+        }
+    }
+    // Commands with several outputs appear once per output.
+    let mut seen = HashSet::new();
+    for (output, (start, end)) in last {
+        let Some((dir, _)) = output.split_once('/') else {
+            continue;
+        };
+        if seen.insert((dir, start, end)) {
+            *times.entry(dir.to_string()).or_default() +=
+                Duration::from_millis(end.saturating_sub(start));
+        }
+    }
+    times
+}
+
+/// Read the cycle count from a simulation's JSON results, if there is one.
+fn read_cycles(path: &Utf8Path) -> Option<u64> {
+    if path.extension() != Some("json") {
+        return None;
+    }
+    let data = std::fs::read_to_string(path).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&data).ok()?;
+    json.get("cycles")?.as_u64()
+}
+
+/// Print a table summarizing the outcome of each entry in a batch.
+pub fn print_summary(outcomes: &[Outcome]) {
+    let width = outcomes
+        .iter()
+        .map(|o| o.name.len())
+        .chain([4])
+        .max()
+        .unwrap_or_default();
+    println!(
+        "{:width$}  {:6}  {:>9}  {:>10}  results",
+        "file", "status", "time", "cycles"
+    );
+    for outcome in outcomes {
+        let time = outcome
+            .elapsed
+            .map(|t| format!("{:.2}s", t.as_secs_f64()))
+            .unwrap_or_else(|| "-".into());
+        let cycles = outcome
+            .cycles
+            .map(|c| c.to_string())
+            .unwrap_or_else(|| "-".into());
+        println!(
+            "{:width$}  {:6}  {:>9}  {:>10}  {}",
+            outcome.name,
+            if outcome.ok { "ok" } else { "failed" },
+            time,
+            cycles,
+            join_paths(&outcome.results)
+        );
+    }
+    let failed = outcomes.iter().filter(|o| !o.ok).count();
+    println!("{} of {} passed", outcomes.len() - failed, outcomes.len());
+}
@@ -0,0 +1,274 @@
+//! A cache of operation outputs that builds can share.
+//!
+//! Each step of a plan gets a key that hashes everything that determines its outputs: the
+//! operation's name, the commands it runs with all of their configuration filled in, the contents
+//! of its input files, of the other files it declares that it reads (like the files a program
+//! imports), and of the resource files it uses, and the size and modification time of the
+//! executables it runs. A step's outputs are stored in the cache directory under its key. When
+//! a later build has a step with the same key, it copies the stored outputs instead of running the
+//! step again, even if the build uses a different working directory.
+//!
+//! Keys are computed before running anything, so the key for a file that an earlier step produces
+//! is derived from that step's key rather than from the file's contents. For the same reason, a
+//! step that declares extra dependencies can't be cached when one of its inputs comes from an
+//! earlier step, because its dependencies can't be found until that file exists.
+
+use crate::config;
+use crate::exec::{Driver, Plan};
+use crate::run::PlanDesc;
+use camino::{Utf8Path, Utf8PathBuf};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+
+/// Cached outputs to use for some steps of a plan, indexed by step.
+pub type Hits = HashMap<usize, Vec<Utf8PathBuf>>;
+
+pub struct Cache {
+    pub dir: Utf8PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: Utf8PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Open the cache if the configuration enables it.
+    pub fn from_config(
+        name: &str,
+        global_config: &config::GlobalConfig,
+    ) -> Option<Self> {
+        if !global_config.cache {
+            return None;
+        }
+        let dir = match &global_config.cache_dir {
+            Some(dir) => dir.into(),
+            None => Utf8PathBuf::from_path_buf(config::cache_dir(name))
+                .expect("non-UTF-8 cache directory"),
+        };
+        Some(Self::new(dir))
+    }
+
+    /// Find the cached outputs for each step whose key is in the cache.
+    pub fn lookup(&self, plan: &Plan, keys: &[Option<String>]) -> Hits {
+        let mut hits = Hits::new();
+        for (idx, ((_, _, outputs), key)) in
+            plan.steps.iter().zip(keys).enumerate()
+        {
+            let Some(key) = key else {
+                continue;
+            };
+            let files: Vec<_> = (0..outputs.len())
+                .map(|i| self.dir.join(key).join(i.to_string()))
+                .collect();
+            if files.iter().all(|f| f.exists()) {
+                log::info!("using cached outputs for step {}", idx);
+                hits.insert(idx, files);
+            }
+        }
+        hits
+    }
+
+    /// Store the outputs of the steps that ran to produce the plan's results.
+    pub fn store(
+        &self,
+        plan: &Plan,
+        keys: &[Option<String>],
+        hits: &Hits,
+    ) -> std::io::Result<()> {
+        for (idx, live) in live_steps(plan, hits).into_iter().enumerate() {
+            let Some(key) = &keys[idx] else {
+                continue;
+            };
+            let (_, _, outputs) = &plan.steps[idx];
+            let built: Vec<_> =
+                outputs.iter().map(|f| plan.workdir.join(f)).collect();
+            if !live
+                || hits.contains_key(&idx)
+                || !built.iter().all(|f| f.is_file())
+            {
+                continue;
+            }
+
+            // Copy the outputs into a temporary directory and then rename it into place, so that
+            // concurrent builds never see a partial entry.
+            let tmp_dir =
+                self.dir.join(format!("{}.tmp{}", key, std::process::id()));
+            std::fs::create_dir_all(&tmp_dir)?;
+            for (i, file) in built.iter().enumerate() {
+                std::fs::copy(file, tmp_dir.join(i.to_string()))?;
+            }
+            if std::fs::rename(&tmp_dir, self.dir.join(key)).is_err() {
+                // Another build stored the same outputs first.
+                std::fs::remove_dir_all(&tmp_dir)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Which steps of a plan need to run to produce its results when the steps in `hits` come from
+/// the cache.
+fn live_steps(plan: &Plan, hits: &Hits) -> Vec<bool> {
+    let mut needed: HashSet<&Utf8PathBuf> = plan.results.iter().collect();
+    let mut live = vec![false; plan.steps.len()];
+    for (idx, (_, inputs, outputs)) in plan.steps.iter().enumerate().rev() {
+        if outputs.iter().any(|f| needed.contains(f)) {
+            live[idx] = true;
+            if !hits.contains_key(&idx) {
+                needed.extend(inputs);
+            }
+        }
+    }
+    live
+}
+
+/// Compute the cache key for each step of a plan, or `None` for steps that cannot be cached.
+/// The plan's input files must already exist.
+pub fn step_keys(
+    driver: &Driver,
+    plan: &Plan,
+    desc: &PlanDesc,
+    scratch_dir: &Utf8Path,
+) -> std::io::Result<Vec<Option<String>>> {
+    // Resource files are extracted by `get-rsrc` builds.
+    let rsrcs: HashSet<&str> = std::iter::once(&desc.preamble)
+        .chain(desc.setups.iter().map(|s| &s.record))
+        .chain(desc.steps.iter().map(|s| &s.record))
+        .flat_map(|r| &r.builds)
+        .filter(|b| b.rule == "get-rsrc")
+        .flat_map(|b| b.targets.iter().map(|t| t.as_str()))
+        .collect();
+
+    // The configuration values that the plan uses, which operations need to find their
+    // dependencies.
+    let config: HashMap<&str, &str> = std::iter::once(&desc.preamble)
+        .chain(desc.setups.iter().map(|s| &s.record))
+        .flat_map(|r| &r.vars)
+        .filter_map(|v| Some((v.key.as_deref()?, v.value.as_str())))
+        .collect();
+
+    // The keys of files that earlier steps produce.
+    let mut file_keys: HashMap<&Utf8PathBuf, Option<String>> = HashMap::new();
+    let mut keys = vec![];
+    for ((op, inputs, outputs), step) in plan.steps.iter().zip(&desc.steps) {
+        let mut hasher = Sha256::new();
+        hasher.update(driver.ops[*op].name.as_bytes());
+        let mut cacheable = driver.ops[*op].cacheable;
+
+        for input in inputs {
+            let input_key = match file_keys.get(input) {
+                Some(key) => key.clone(),
+                None => Some(hash_file(&plan.workdir.join(input))?),
+            };
+            match input_key {
+                Some(key) => hasher.update(key.as_bytes()),
+                None => cacheable = false,
+            }
+        }
+
+        if let Some(deps) = driver.ops[*op].deps {
+            if inputs.iter().any(|f| file_keys.contains_key(f)) {
+                cacheable = false;
+            } else {
+                let files: Vec<_> =
+                    inputs.iter().map(|f| plan.workdir.join(f)).collect();
+                for dep in deps(&files, &config)? {
+                    hasher.update(hash_file(&dep)?.as_bytes());
+                }
+            }
+        }
+
+        // Hash the commands with the step's own files replaced by placeholders, so the key does
+        // not depend on where those files are.
+        let scratch_prefix = format!("{}/", scratch_dir);
+        let placeholder = |token: &str| {
+            if let Some(i) = inputs.iter().position(|f| f == token) {
+                format!("{{input{}}}", i)
+            } else if let Some(i) = outputs.iter().position(|f| f == token) {
+                format!("{{output{}}}", i)
+            } else {
+                token
+                    .strip_prefix(scratch_prefix.as_str())
+                    .filter(|_| !scratch_dir.as_str().is_empty())
+                    .unwrap_or(token)
+                    .to_string()
+            }
+        };
+        let mut tools = HashSet::new();
+        let mut used_rsrcs = HashSet::new();
+        for build in &step.record.builds {
+            let Some(command) = &build.command else {
+                continue;
+            };
+            let tokens: Vec<_> = command.split_whitespace().collect();
+            for token in &tokens {
+                hasher.update(placeholder(token).as_bytes());
+                hasher.update(b" ");
+                if rsrcs.contains(token) {
+                    used_rsrcs.insert(*token);
+                }
+            }
+            hasher.update(b"\n");
+            for dep in build.deps.iter().chain(&build.implicit) {
+                if rsrcs.contains(dep.as_str()) {
+                    used_rsrcs.insert(dep.as_str());
+                }
+            }
+            // The executable is the first word that isn't an environment variable assignment.
+            if let Some(tool) = tokens.iter().find(|t| !t.contains('=')) {
+                tools.insert(tool.trim_start_matches('('));
+            }
+        }
+
+        let mut used_rsrcs: Vec<_> = used_rsrcs.into_iter().collect();
+        used_rsrcs.sort();
+        for name in used_rsrcs {
+            if let Some(data) = driver.rsrc_data(name) {
+                hasher.update(Sha256::digest(data?));
+            }
+        }
+        let mut tools: Vec<_> = tools.into_iter().collect();
+        tools.sort();
+        for tool in tools {
+            if let Some(stamp) = tool_stamp(tool, &plan.workdir) {
+                hasher.update(stamp.as_bytes());
+            }
+        }
+
+        let key = cacheable.then(|| hex(&hasher.finalize()));
+        for (i, output) in outputs.iter().enumerate() {
+            file_keys
+                .insert(output, key.as_ref().map(|k| format!("{}:{}", k, i)));
+        }
+        keys.push(key);
+    }
+    Ok(keys)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn hash_file(path: &Utf8Path) -> std::io::Result<String> {
+    Ok(hex(&Sha256::digest(std::fs::read(path)?)))
+}
+
+/// Identify the version of an executable by its size and modification time.
+fn tool_stamp(tool: &str, workdir: &Utf8Path) -> Option<String> {
+    let path = if tool.contains('/') {
+        workdir.join(tool)
+    } else {
+        let path_var = std::env::var("PATH").ok()?;
+        path_var
+            .split(':')
+            .map(|dir| Utf8Path::new(dir).join(tool))
+            .find(|p| p.is_file())?
+    };
+    let meta = std::fs::metadata(path).ok()?;
+    let mtime = meta
+        .modified()
+        .ok()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?;
+    Some(format!("{}:{}:{}", tool, meta.len(), mtime.as_secs()))
+}
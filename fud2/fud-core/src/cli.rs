@@ -1,12 +1,16 @@
+use crate::batch::{self, Batch};
 use crate::config;
-use crate::exec::{Driver, Request, StateRef};
-use crate::run::Run;
+use crate::exec::{Driver, OpRef, Request, StateRef};
+use crate::inspect;
+use crate::run::{join_paths, Run};
+use crate::utils;
 use anyhow::{anyhow, bail};
 use argh::FromArgs;
 use camino::{Utf8Path, Utf8PathBuf};
 use std::fmt::Display;
 use std::str::FromStr;
 
+#[derive(PartialEq, Debug)]
 enum Mode {
     EmitNinja,
     ShowPlan,
@@ -42,6 +46,25 @@ impl Display for Mode {
     }
 }
 
+/// How to print plans.
+#[derive(PartialEq, Debug)]
+enum PlanFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for PlanFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(PlanFormat::Text),
+            "json" => Ok(PlanFormat::Json),
+            _ => Err("unknown format".to_string()),
+        }
+    }
+}
+
 /// edit the configuration file
 #[derive(FromArgs, PartialEq, Debug)]
 #[argh(subcommand, name = "edit-config")]
@@ -69,6 +92,85 @@ pub struct GetResource {
 #[argh(subcommand, name = "list")]
 pub struct ListCommand {}
 
+/// check the configuration file for unknown or mistyped options
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "check-config")]
+pub struct CheckConfig {}
+
+/// list the files of a kept build, or rerun it from one of them
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "inspect")]
+pub struct InspectCommand {
+    /// the build directory to inspect
+    #[argh(option)]
+    dir: Option<Utf8PathBuf>,
+
+    /// rerun the steps that depend on this file, after changing it
+    #[argh(option)]
+    from: Option<Utf8PathBuf>,
+
+    /// set a configuration variable (key=value)
+    #[argh(option, short = 's')]
+    set: Vec<String>,
+}
+
+/// build many files at once
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "batch")]
+pub struct BatchCommand {
+    /// input files or glob patterns; each file is a separate build
+    #[argh(positional)]
+    inputs: Vec<String>,
+
+    /// a file listing one build per line, as the input files for that build
+    #[argh(option)]
+    manifest: Option<Utf8PathBuf>,
+
+    /// the states to start from, for each build
+    #[argh(option)]
+    from: Vec<String>,
+
+    /// the states to produce, for each build
+    #[argh(option)]
+    to: Vec<String>,
+
+    /// execution mode (run, plan, emit, gen)
+    #[argh(option, short = 'm', default = "Mode::Run")]
+    mode: Mode,
+
+    /// working directory for the build
+    #[argh(option)]
+    dir: Option<Utf8PathBuf>,
+
+    /// directory to collect the results in
+    #[argh(option, short = 'o', default = "\"fud2-results\".into()")]
+    out_dir: Utf8PathBuf,
+
+    /// in run mode, keep the temporary directory
+    #[argh(switch)]
+    keep: Option<bool>,
+
+    /// in run mode, reuse outputs from earlier builds
+    #[argh(switch)]
+    cache: Option<bool>,
+
+    /// set a configuration variable (key=value)
+    #[argh(option, short = 's')]
+    set: Vec<String>,
+
+    /// route the conversion through a specific operation
+    #[argh(option)]
+    through: Vec<String>,
+
+    /// prefer operations with this name or tag when planning
+    #[argh(option)]
+    prefer: Vec<String>,
+
+    /// never use operations with this name or tag when planning
+    #[argh(option)]
+    avoid: Vec<String>,
+}
+
 /// supported subcommands
 #[derive(FromArgs, PartialEq, Debug)]
 #[argh(subcommand)]
@@ -81,6 +183,15 @@ pub enum Subcommand {
 
     /// list the available states and ops
     List(ListCommand),
+
+    /// check the configuration file for unknown or mistyped options
+    CheckConfig(CheckConfig),
+
+    /// list the files of a kept build, or rerun it from one of them
+    Inspect(InspectCommand),
+
+    /// build many files at once
+    Batch(BatchCommand),
 }
 
 #[derive(FromArgs)]
@@ -89,26 +200,30 @@ struct FakeArgs {
     #[argh(subcommand)]
     pub sub: Option<Subcommand>,
 
-    /// the input file
+    /// the input files
     #[argh(positional)]
-    input: Option<Utf8PathBuf>,
+    input: Vec<Utf8PathBuf>,
 
-    /// the output file
+    /// the output files
     #[argh(option, short = 'o')]
-    output: Option<Utf8PathBuf>,
+    output: Vec<Utf8PathBuf>,
 
-    /// the state to start from
+    /// the states to start from
     #[argh(option)]
-    from: Option<String>,
+    from: Vec<String>,
 
-    /// the state to produce
+    /// the states to produce
     #[argh(option)]
-    to: Option<String>,
+    to: Vec<String>,
 
     /// execution mode (run, plan, emit, gen, dot)
     #[argh(option, short = 'm', default = "Mode::Run")]
     mode: Mode,
 
+    /// output format for plan mode (text, json)
+    #[argh(option, default = "PlanFormat::Text")]
+    format: PlanFormat,
+
     /// working directory for the build
     #[argh(option)]
     dir: Option<Utf8PathBuf>,
@@ -117,6 +232,10 @@ struct FakeArgs {
     #[argh(switch)]
     keep: Option<bool>,
 
+    /// in run mode, reuse outputs from earlier builds
+    #[argh(switch)]
+    cache: Option<bool>,
+
     /// set a configuration variable (key=value)
     #[argh(option, short = 's')]
     set: Vec<String>,
@@ -125,6 +244,14 @@ struct FakeArgs {
     #[argh(option)]
     through: Vec<String>,
 
+    /// prefer operations with this name or tag when planning
+    #[argh(option)]
+    prefer: Vec<String>,
+
+    /// never use operations with this name or tag when planning
+    #[argh(option)]
+    avoid: Vec<String>,
+
     /// verbose ouput
     #[argh(switch, short = 'v')]
     verbose: Option<bool>,
@@ -134,34 +261,116 @@ struct FakeArgs {
     pub log_level: log::LevelFilter,
 }
 
-fn from_state(driver: &Driver, args: &FakeArgs) -> anyhow::Result<StateRef> {
-    match &args.from {
-        Some(name) => driver
-            .get_state(name)
-            .ok_or(anyhow!("unknown --from state")),
-        None => match args.input {
-            Some(ref input) => driver
-                .guess_state(input)
-                .ok_or(anyhow!("could not infer input state")),
-            None => bail!("specify an input file or use --from"),
-        },
+fn from_states(
+    driver: &Driver,
+    from: &[String],
+    input: &[Utf8PathBuf],
+) -> anyhow::Result<Vec<StateRef>> {
+    if !from.is_empty() {
+        if input.is_empty() && from.len() > 1 {
+            bail!("only one input can be read from stdin");
+        }
+        if !input.is_empty() && from.len() != input.len() {
+            bail!("specify a --from state for each input file");
+        }
+        from.iter()
+            .map(|name| {
+                driver
+                    .get_state(name)
+                    .ok_or(anyhow!("unknown --from state {}", name))
+            })
+            .collect()
+    } else if input.is_empty() {
+        bail!("specify an input file or use --from")
+    } else {
+        input
+            .iter()
+            .map(|input| {
+                driver
+                    .guess_state(input)
+                    .ok_or(anyhow!("could not infer input state for {}", input))
+            })
+            .collect()
     }
 }
 
-fn to_state(driver: &Driver, args: &FakeArgs) -> anyhow::Result<StateRef> {
-    match &args.to {
-        Some(name) => {
-            driver.get_state(name).ok_or(anyhow!("unknown --to state"))
+fn to_states(
+    driver: &Driver,
+    to: &[String],
+    output: &[Utf8PathBuf],
+) -> anyhow::Result<Vec<StateRef>> {
+    if !to.is_empty() {
+        if !output.is_empty() && to.len() != output.len() {
+            bail!("specify a --to state for each output file");
         }
-        None => match &args.output {
-            Some(out) => driver
-                .guess_state(out)
-                .ok_or(anyhow!("could not infer output state")),
-            None => Err(anyhow!("specify an output file or use --to")),
-        },
+        to.iter()
+            .map(|name| {
+                driver
+                    .get_state(name)
+                    .ok_or(anyhow!("unknown --to state {}", name))
+            })
+            .collect()
+    } else if output.is_empty() {
+        bail!("specify an output file or use --to")
+    } else {
+        output
+            .iter()
+            .map(|out| {
+                driver
+                    .guess_state(out)
+                    .ok_or(anyhow!("could not infer output state for {}", out))
+            })
+            .collect()
     }
 }
 
+/// Find all the operations to route through.
+fn through_ops(
+    driver: &Driver,
+    through: &[String],
+) -> anyhow::Result<Vec<OpRef>> {
+    through
+        .iter()
+        .map(|s| {
+            driver
+                .get_op(s)
+                .ok_or(anyhow!("unknown --through op {}", s))
+        })
+        .collect()
+}
+
+/// Check that preferences refer to something that exists.
+fn check_prefs(
+    driver: &Driver,
+    prefer: &[String],
+    avoid: &[String],
+) -> anyhow::Result<()> {
+    for name in prefer.iter().chain(avoid.iter()) {
+        if !driver.is_known_name(name) {
+            bail!("unknown op, state, or tag {}", name);
+        }
+    }
+    Ok(())
+}
+
+/// Use `--set` arguments to override configuration values.
+fn apply_sets(
+    mut config_data: figment::Figment,
+    sets: &[String],
+) -> anyhow::Result<figment::Figment> {
+    for set in sets {
+        let mut parts = set.splitn(2, '=');
+        let key = parts.next().unwrap();
+        let value = parts
+            .next()
+            .ok_or(anyhow!("--set arguments must be in key=value form"))?;
+        let dict = figment::util::nest(key, value.into());
+        config_data =
+            config_data.merge(figment::providers::Serialized::defaults(dict));
+    }
+    Ok(config_data)
+}
+
 fn get_request(driver: &Driver, args: &FakeArgs) -> anyhow::Result<Request> {
     // The default working directory (if not specified) depends on the mode.
     let default_workdir = driver.default_workdir();
@@ -170,23 +379,18 @@ fn get_request(driver: &Driver, args: &FakeArgs) -> anyhow::Result<Request> {
         _ => Utf8Path::new("."),
     });
 
-    // Find all the operations to route through.
-    let through: Result<Vec<_>, _> = args
-        .through
-        .iter()
-        .map(|s| {
-            driver
-                .get_op(s)
-                .ok_or(anyhow!("unknown --through op {}", s))
-        })
-        .collect();
-
+    check_prefs(driver, &args.prefer, &args.avoid)?;
+    if args.output.is_empty() && args.to.len() > 1 {
+        bail!("only one output can be written to stdout");
+    }
     Ok(Request {
-        start_file: args.input.clone(),
-        start_state: from_state(driver, args)?,
-        end_file: args.output.clone(),
-        end_state: to_state(driver, args)?,
-        through: through?,
+        start_files: args.input.clone(),
+        start_states: from_states(driver, &args.from, &args.input)?,
+        end_files: args.output.clone(),
+        end_states: to_states(driver, &args.to, &args.output)?,
+        through: through_ops(driver, &args.through)?,
+        prefer: args.prefer.clone(),
+        avoid: args.avoid.clone(),
         workdir: workdir.into(),
     })
 }
@@ -212,28 +416,132 @@ fn edit_config(driver: &Driver, cmd: EditConfig) -> anyhow::Result<()> {
 
 fn get_resource(driver: &Driver, cmd: GetResource) -> anyhow::Result<()> {
     let to_path = cmd.output.as_deref().unwrap_or(&cmd.filename);
+    let data = driver
+        .rsrc_data(cmd.filename.as_str())
+        .ok_or_else(|| anyhow!("unknown resource file {}", cmd.filename))?;
+    log::info!("extracting {} to {}", cmd.filename, to_path);
+    std::fs::write(to_path, data?)?;
+    Ok(())
+}
 
-    // Try extracting embedded resource data.
-    if let Some(rsrc_files) = &driver.rsrc_files {
-        if let Some(data) = rsrc_files.get(cmd.filename.as_str()) {
-            log::info!("extracting {} to {}", cmd.filename, to_path);
-            std::fs::write(to_path, data)?;
-            return Ok(());
+fn check_config(driver: &Driver) -> anyhow::Result<()> {
+    let config_path = config::config_path(&driver.name);
+    let config_data = config::load_config(&driver.name);
+    let problems = config::check(&config_data, &driver.config_keys())?;
+    for problem in &problems {
+        let level = if problem.is_error() {
+            "error"
+        } else {
+            "warning"
+        };
+        eprintln!("{}: {}", level, problem);
+    }
+    if problems.iter().any(|p| p.is_error()) {
+        bail!("invalid configuration in {}", config_path.display());
+    }
+    Ok(())
+}
+
+fn inspect_build(driver: &Driver, cmd: InspectCommand) -> anyhow::Result<()> {
+    let dir = cmd.dir.unwrap_or_else(|| driver.default_workdir());
+    let plan = inspect::read_plan(driver, &dir)?;
+    match cmd.from {
+        None => inspect::print_files(driver, &plan),
+        Some(file) => {
+            let plan = inspect::resume_from(plan, &file)?;
+            let mut run = Run::new(driver, plan);
+            run.config_data = apply_sets(run.config_data, &cmd.set)?;
+            run.emit_and_run(&dir)?;
         }
     }
+    Ok(())
+}
 
-    // Try copying a resource file from the resource directory.
-    if let Some(rsrc_dir) = &driver.rsrc_dir {
-        let from_path = rsrc_dir.join(&cmd.filename);
-        if !from_path.exists() {
-            bail!("resource file not found: {}", cmd.filename);
+/// Collect the input files for each build in a batch.
+fn batch_inputs(cmd: &BatchCommand) -> anyhow::Result<Vec<Vec<Utf8PathBuf>>> {
+    let mut entries = vec![];
+    for pattern in &cmd.inputs {
+        let files = utils::glob(pattern)?;
+        if files.is_empty() {
+            bail!("no files match {}", pattern);
         }
-        log::info!("copying {} to {}", cmd.filename, to_path);
-        std::fs::copy(from_path, to_path)?;
-        return Ok(());
+        entries.extend(files.into_iter().map(|f| vec![f]));
+    }
+    if let Some(manifest) = &cmd.manifest {
+        // Paths in the manifest are relative to the manifest itself.
+        let base = manifest.parent().unwrap_or(Utf8Path::new(""));
+        for line in std::fs::read_to_string(manifest)?.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            entries
+                .push(line.split_whitespace().map(|f| base.join(f)).collect());
+        }
+    }
+    if entries.is_empty() {
+        bail!("specify input files or a --manifest");
+    }
+    Ok(entries)
+}
+
+fn run_batch(driver: &Driver, cmd: BatchCommand) -> anyhow::Result<()> {
+    if cmd.mode == Mode::ShowDot {
+        bail!("dot mode is not supported for batches");
+    }
+    let default_workdir = driver.default_workdir();
+    let workdir = cmd.dir.as_deref().unwrap_or_else(|| match cmd.mode {
+        Mode::Generate | Mode::Run => default_workdir.as_ref(),
+        _ => Utf8Path::new("."),
+    });
+
+    // Plan a build for each entry.
+    check_prefs(driver, &cmd.prefer, &cmd.avoid)?;
+    let end_states = to_states(driver, &cmd.to, &[])?;
+    let through = through_ops(driver, &cmd.through)?;
+    let mut plans = vec![];
+    for inputs in batch_inputs(&cmd)? {
+        let req = Request {
+            start_states: from_states(driver, &cmd.from, &inputs)?,
+            start_files: inputs,
+            end_files: vec![],
+            end_states: end_states.clone(),
+            through: through.clone(),
+            prefer: cmd.prefer.clone(),
+            avoid: cmd.avoid.clone(),
+            workdir: workdir.into(),
+        };
+        let files = join_paths(&req.start_files);
+        plans.push(
+            driver
+                .plan(req)
+                .ok_or(anyhow!("could not find path for {}", files))?,
+        );
+    }
+
+    let config_data = apply_sets(config::load_config(&driver.name), &cmd.set)?;
+    let mut batch = Batch::new(driver, plans, workdir.into(), config_data);
+    if let Some(keep) = cmd.keep {
+        batch.global_config.keep_build_dir = keep;
+    }
+    if let Some(cache) = cmd.cache {
+        batch.global_config.cache = cache;
     }
 
-    bail!("unknown resource file {}", cmd.filename);
+    match cmd.mode {
+        Mode::ShowPlan => batch.show(),
+        Mode::EmitNinja => batch.emit_to_stdout()?,
+        Mode::Generate => batch.emit_to_dir()?,
+        Mode::Run => {
+            let outcomes = batch.emit_and_run(&cmd.out_dir)?;
+            batch::print_summary(&outcomes);
+            if outcomes.iter().any(|o| !o.ok) {
+                bail!("some builds failed");
+            }
+        }
+        Mode::ShowDot => unreachable!(),
+    }
+    Ok(())
 }
 
 pub fn cli(driver: &Driver) -> anyhow::Result<()> {
@@ -258,9 +566,22 @@ pub fn cli(driver: &Driver) -> anyhow::Result<()> {
             driver.print_info();
             return Ok(());
         }
+        Some(Subcommand::CheckConfig(_)) => {
+            return check_config(driver);
+        }
+        Some(Subcommand::Inspect(cmd)) => {
+            return inspect_build(driver, cmd);
+        }
+        Some(Subcommand::Batch(cmd)) => {
+            return run_batch(driver, cmd);
+        }
         None => {}
     }
 
+    if args.format != PlanFormat::Text && args.mode != Mode::ShowPlan {
+        bail!("--format only applies to plan mode");
+    }
+
     // Make a plan.
     let req = get_request(driver, &args)?;
     let workdir = req.workdir.clone();
@@ -273,30 +594,34 @@ pub fn cli(driver: &Driver) -> anyhow::Result<()> {
     if let Some(keep) = args.keep {
         run.global_config.keep_build_dir = keep;
     }
+    if let Some(cache) = args.cache {
+        run.global_config.cache = cache;
+    }
     if let Some(verbose) = args.verbose {
         run.global_config.verbose = verbose;
     }
 
-    // Use `--set` arguments to override configuration values.
-    for set in args.set {
-        let mut parts = set.splitn(2, '=');
-        let key = parts.next().unwrap();
-        let value = parts
-            .next()
-            .ok_or(anyhow!("--set arguments must be in key=value form"))?;
-        let dict = figment::util::nest(key, value.into());
-        run.config_data = run
-            .config_data
-            .merge(figment::providers::Serialized::defaults(dict));
-    }
+    run.config_data = apply_sets(run.config_data, &args.set)?;
 
     // Execute.
     match args.mode {
-        Mode::ShowPlan => run.show(),
+        Mode::ShowPlan => match args.format {
+            PlanFormat::Text => run.show(),
+            PlanFormat::Json => run.show_json()?,
+        },
         Mode::ShowDot => run.show_dot(),
         Mode::EmitNinja => run.emit_to_stdout()?,
-        Mode::Generate => run.emit_to_dir(&workdir)?,
-        Mode::Run => run.emit_and_run(&workdir)?,
+        Mode::Generate => {
+            run.emit_to_dir(&workdir)?;
+            inspect::write_manifest(driver, &run.plan)?;
+        }
+        Mode::Run => {
+            run.emit_and_run(&workdir)?;
+            // Record the plan if the build directory sticks around, so `inspect` can find it.
+            if workdir.exists() {
+                inspect::write_manifest(driver, &run.plan)?;
+            }
+        }
     }
 
     Ok(())
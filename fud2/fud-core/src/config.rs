@@ -1,8 +1,10 @@
 use figment::{
     providers::{Format, Serialized, Toml},
+    value::{Dict, Value},
     Figment,
 };
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
 use std::{env, path::Path};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -18,6 +20,12 @@ pub struct GlobalConfig {
 
     /// The path to the build tool executable.
     pub exe: String,
+
+    /// Reuse the outputs of operations from earlier builds in `run` mode.
+    pub cache: bool,
+
+    /// Where to keep cached outputs. Defaults to `~/.cache/driver_name`.
+    pub cache_dir: Option<String>,
 }
 
 impl Default for GlobalConfig {
@@ -31,22 +39,43 @@ impl Default for GlobalConfig {
                 .to_str()
                 .expect("invalid executable name")
                 .into(),
+            cache: false,
+            cache_dir: None,
         }
     }
 }
 
+/// The base directory for configuration files, usually `~/.config`.
+fn config_base() -> String {
+    env::var("XDG_CONFIG_HOME").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("$HOME not set");
+        home + "/.config"
+    })
+}
+
 /// Location of the configuration file
 pub fn config_path(name: &str) -> std::path::PathBuf {
     // The configuration is usually at `~/.config/driver_name.toml`.
-    let config_base = env::var("XDG_CONFIG_HOME").unwrap_or_else(|_| {
-        let home = env::var("HOME").expect("$HOME not set");
-        home + "/.config"
-    });
-    let config_path = Path::new(&config_base).join(name).with_extension("toml");
+    let config_path =
+        Path::new(&config_base()).join(name).with_extension("toml");
     log::info!("Loading config from {}", config_path.display());
     config_path
 }
 
+/// Location of the directory containing plugin files, usually `~/.config/driver_name/plugins`.
+pub fn plugin_dir(name: &str) -> std::path::PathBuf {
+    Path::new(&config_base()).join(name).join("plugins")
+}
+
+/// The default location of the output cache, usually `~/.cache/driver_name`.
+pub fn cache_dir(name: &str) -> std::path::PathBuf {
+    let base = env::var("XDG_CACHE_HOME").unwrap_or_else(|_| {
+        let home = env::var("HOME").expect("$HOME not set");
+        home + "/.cache"
+    });
+    Path::new(&base).join(name)
+}
+
 /// Get raw configuration data with some default options.
 pub fn default_config() -> Figment {
     Figment::from(Serialized::defaults(GlobalConfig::default()))
@@ -59,3 +88,205 @@ pub fn load_config(name: &str) -> Figment {
     // Use our defaults, overridden by the TOML config file.
     default_config().merge(Toml::file(config_path))
 }
+
+/// The kind of value a configuration option holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigType {
+    String,
+    Path,
+    Integer,
+    Boolean,
+}
+
+impl ConfigType {
+    /// Check whether a configured value has this type. Numbers and booleans may also be written
+    /// as strings, like they are with `--set`.
+    fn accepts(&self, value: &Value) -> bool {
+        match (self, value) {
+            (ConfigType::String | ConfigType::Path, Value::String(..)) => true,
+            (ConfigType::Integer, Value::Num(_, n)) => n.to_i128().is_some(),
+            (ConfigType::Integer, Value::String(_, s)) => {
+                s.parse::<i128>().is_ok()
+            }
+            (ConfigType::Boolean, Value::Bool(..)) => true,
+            (ConfigType::Boolean, Value::String(_, s)) => {
+                s == "true" || s == "false"
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Display for ConfigType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigType::String => write!(f, "string"),
+            ConfigType::Path => write!(f, "path"),
+            ConfigType::Integer => write!(f, "integer"),
+            ConfigType::Boolean => write!(f, "boolean"),
+        }
+    }
+}
+
+/// A configuration option that a setup or operation reads.
+#[derive(Debug, Clone)]
+pub struct ConfigKey {
+    /// The dotted name of the option, like `calyx.base`.
+    pub key: String,
+    pub ty: ConfigType,
+    /// The value to use when the option isn't set, or `None` if it is required.
+    pub default: Option<String>,
+    /// A short description of the option.
+    pub doc: String,
+}
+
+impl ConfigKey {
+    /// Declare a required option.
+    pub fn new(key: &str, ty: ConfigType, doc: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            ty,
+            default: None,
+            doc: doc.to_string(),
+        }
+    }
+
+    /// Give the option a default value, making it optional.
+    pub fn with_default(mut self, default: &str) -> Self {
+        self.default = Some(default.to_string());
+        self
+    }
+}
+
+impl Display for ConfigKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}", self.key, self.ty)?;
+        if let Some(default) = &self.default {
+            write!(f, ", default `{}`", default)?;
+        }
+        write!(f, ")")?;
+        if !self.doc.is_empty() {
+            write!(f, ": {}", self.doc)?;
+        }
+        Ok(())
+    }
+}
+
+/// Something wrong with a configuration option.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ConfigProblem {
+    /// Nothing reads the option. `suggestion` is a known option with a similar name.
+    Unknown {
+        key: String,
+        suggestion: Option<String>,
+    },
+    /// The option's value is not of the declared type.
+    WrongType { key: String, expected: ConfigType },
+}
+
+impl ConfigProblem {
+    /// Whether the problem will break builds, rather than just being suspicious.
+    pub fn is_error(&self) -> bool {
+        matches!(self, ConfigProblem::WrongType { .. })
+    }
+}
+
+impl Display for ConfigProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigProblem::Unknown { key, suggestion } => {
+                write!(f, "unknown option {}", key)?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, " (did you mean {}?)", suggestion)?;
+                }
+                Ok(())
+            }
+            ConfigProblem::WrongType { key, expected } => {
+                write!(
+                    f,
+                    "option {} has the wrong type: expected {}",
+                    key, expected
+                )
+            }
+        }
+    }
+}
+
+/// Collect the dotted names of all the non-table values in a configuration dictionary.
+fn flatten<'a>(
+    prefix: &str,
+    dict: &'a Dict,
+    out: &mut Vec<(String, &'a Value)>,
+) {
+    for (name, value) in dict {
+        let key = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{}.{}", prefix, name)
+        };
+        match value {
+            Value::Dict(_, inner) => flatten(&key, inner, out),
+            _ => out.push((key, value)),
+        }
+    }
+}
+
+/// The number of single-character edits that turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let subst = prev[j] + usize::from(ca != *cb);
+            cur.push(subst.min(prev[j + 1] + 1).min(cur[j] + 1));
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+/// Check configuration data against the options that a driver declares.
+///
+/// Options that configure the driver itself, like `ninja` and `plugins`, are always known.
+pub fn check(
+    config_data: &Figment,
+    keys: &[&ConfigKey],
+) -> anyhow::Result<Vec<ConfigProblem>> {
+    let data = config_data.extract::<Dict>()?;
+    let mut values = vec![];
+    flatten("", &data, &mut values);
+
+    let global_keys: Vec<String> = match default_config().extract::<Dict>() {
+        Ok(dict) => dict.into_keys().chain(["plugins".to_string()]).collect(),
+        Err(_) => vec![],
+    };
+
+    let mut problems = vec![];
+    for (key, value) in values {
+        if global_keys.contains(&key) {
+            continue;
+        }
+        match keys.iter().find(|k| k.key == key) {
+            Some(decl) if !decl.ty.accepts(value) => {
+                problems.push(ConfigProblem::WrongType {
+                    key,
+                    expected: decl.ty,
+                })
+            }
+            Some(_) => {}
+            None => {
+                // Suggest the closest known option, if any is close enough to be a typo.
+                let suggestion = keys
+                    .iter()
+                    .map(|k| (edit_distance(&key, &k.key), &k.key))
+                    .filter(|(dist, _)| *dist <= 2)
+                    .min()
+                    .map(|(_, k)| k.clone());
+                problems.push(ConfigProblem::Unknown { key, suggestion })
+            }
+        }
+    }
+    Ok(problems)
+}
@@ -1,4 +1,4 @@
-use crate::run;
+use crate::{config, run};
 use cranelift_entity::entity_impl;
 
 /// A State is a type of file that Operations produce or consume.
@@ -13,6 +13,10 @@ pub struct State {
     /// Pseudo-states can only be final outputs; they are appropraite for representing actions that
     /// interact directly with the user, for example.
     pub extensions: Vec<String>,
+
+    /// Tags describing the state, like `synthesizable`, that users can refer to when they
+    /// express preferences about plans.
+    pub tags: Vec<String>,
 }
 
 impl State {
@@ -28,16 +32,31 @@ impl State {
 }
 
 /// A reference to a State.
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct StateRef(u32);
 entity_impl!(StateRef, "state");
 
-/// An Operation transforms files from one State to another.
+/// An Operation transforms files in some States into files in other States.
+///
+/// Operations are hyperedges: they may consume and produce several files at once, for example a
+/// simulator that reads a program and its input data and produces both results and a waveform.
 pub struct Operation {
     pub name: String,
-    pub input: StateRef,
-    pub output: StateRef,
+    pub input: Vec<StateRef>,
+    pub output: Vec<StateRef>,
     pub setups: Vec<SetupRef>,
+    /// The relative expense of running the operation. The planner picks the cheapest plan.
+    pub cost: u32,
+    /// Tags describing the operation, like `fast` or `accurate`.
+    pub tags: Vec<String>,
+    /// Whether the operation's outputs may be reused from the cache. Interactive operations,
+    /// for example, should always run.
+    pub cacheable: bool,
+    /// Finds the files besides the inputs that the operation reads, which the cache must also
+    /// hash.
+    pub deps: Option<run::DepsFn>,
+    /// The configuration options that the operation reads.
+    pub config: Vec<config::ConfigKey>,
     pub emit: Box<dyn run::EmitBuild>,
 }
 
@@ -49,6 +68,8 @@ entity_impl!(OpRef, "op");
 /// A Setup runs at configuration time and produces Ninja machinery for Operations.
 pub struct Setup {
     pub name: String,
+    /// The configuration options that the setup reads.
+    pub config: Vec<config::ConfigKey>,
     pub emit: Box<dyn run::EmitSetup>,
 }
 
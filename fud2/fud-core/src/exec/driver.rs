@@ -1,14 +1,11 @@
-use super::{OpRef, Operation, Request, Setup, SetupRef, State, StateRef};
-use crate::{run, utils};
+use super::{
+    plugin, OpRef, Operation, Request, Setup, SetupRef, State, StateRef,
+};
+use crate::{config, run, utils};
 use camino::{Utf8Path, Utf8PathBuf};
-use cranelift_entity::{PrimaryMap, SecondaryMap};
-use std::collections::HashMap;
-
-#[derive(PartialEq)]
-enum Destination {
-    State(StateRef),
-    Op(OpRef),
-}
+use cranelift_entity::PrimaryMap;
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap, HashMap, HashSet};
 
 type FileData = HashMap<&'static str, &'static [u8]>;
 
@@ -21,96 +18,184 @@ pub struct Driver {
     pub ops: PrimaryMap<OpRef, Operation>,
     pub rsrc_dir: Option<Utf8PathBuf>,
     pub rsrc_files: Option<FileData>,
+    /// Resource files that live outside the resource directory, like those provided by plugins.
+    pub rsrc_paths: HashMap<String, Utf8PathBuf>,
 }
 
 impl Driver {
-    /// Find a chain of Operations from the `start` state to the `end`, which may be a state or the
-    /// final operation in the chain.
-    fn find_path_segment(
+    /// Get the contents of a resource file, or `None` if there is no such resource.
+    pub fn rsrc_data(&self, name: &str) -> Option<std::io::Result<Vec<u8>>> {
+        // Embedded resources take precedence, then plugin resources, then the resource directory.
+        if let Some(data) = self.rsrc_files.as_ref().and_then(|f| f.get(name)) {
+            return Some(Ok(data.to_vec()));
+        }
+        if let Some(path) = self.rsrc_paths.get(name) {
+            return Some(std::fs::read(path));
+        }
+        let path = self.rsrc_dir.as_ref()?.join(name);
+        path.exists().then(|| std::fs::read(path))
+    }
+
+    /// Check whether `name` refers to an operation: its name or one of its tags, or the name or
+    /// a tag of one of the states it produces.
+    pub fn op_matches(&self, op: OpRef, name: &str) -> bool {
+        let op = &self.ops[op];
+        op.name == name
+            || op.tags.iter().any(|t| t == name)
+            || op.output.iter().any(|s| {
+                let state = &self.states[*s];
+                state.name == name || state.tags.iter().any(|t| t == name)
+            })
+    }
+
+    /// Check whether `name` is an operation, state, or tag that preferences can refer to.
+    pub fn is_known_name(&self, name: &str) -> bool {
+        self.ops.keys().any(|op| self.op_matches(op, name))
+            || self.states.values().any(|s| s.name == name)
+    }
+
+    /// The cost of using an operation under the user's preferences. Operations matching an
+    /// `avoid` name cannot be used at all and operations matching a `prefer` name are free.
+    pub fn op_cost(
+        &self,
+        op: OpRef,
+        prefer: &[String],
+        avoid: &[String],
+    ) -> Option<u32> {
+        if avoid.iter().any(|name| self.op_matches(op, name)) {
+            None
+        } else if prefer.iter().any(|name| self.op_matches(op, name)) {
+            Some(0)
+        } else {
+            Some(self.ops[op].cost)
+        }
+    }
+
+    /// Find the cheapest sequence of Operations that produces every `end` state from the `start`
+    /// states, running each `through` operation in order. Returns the operations along with the
+    /// total cost of the sequence.
+    ///
+    /// An operation can run once all of its input states are available, either because they are
+    /// inputs to the request or because an earlier operation produced them. The search is a
+    /// uniform-cost search over the sets of states produced so far; ties go to the operations
+    /// that were registered first.
+    pub fn find_path(
         &self,
-        start: StateRef,
-        end: Destination,
-    ) -> Option<Vec<OpRef>> {
-        // Our start state is the input.
-        let mut visited = SecondaryMap::<StateRef, bool>::new();
-        visited[start] = true;
-
-        // Build the incoming edges for each vertex.
-        let mut breadcrumbs = SecondaryMap::<StateRef, Option<OpRef>>::new();
-
-        // Breadth-first search.
-        let mut state_queue: Vec<StateRef> = vec![start];
-        while !state_queue.is_empty() {
-            let cur_state = state_queue.remove(0);
-
-            // Finish when we reach the goal vertex.
-            if end == Destination::State(cur_state) {
-                break;
+        start: &[StateRef],
+        end: &[StateRef],
+        through: &[OpRef],
+        prefer: &[String],
+        avoid: &[String],
+    ) -> Option<(Vec<OpRef>, u32)> {
+        // Each search node is the set of states produced so far, the number of `through`
+        // operations that have been used, and the goal states produced since the last of them.
+        type Node = (BTreeSet<StateRef>, usize, BTreeSet<StateRef>);
+        let init: Node = (BTreeSet::new(), 0, BTreeSet::new());
+        let mut nodes: Vec<(Node, Vec<OpRef>)> = vec![(init.clone(), vec![])];
+        let mut best: HashMap<Node, u32> = HashMap::from([(init, 0)]);
+        let mut settled: HashSet<Node> = HashSet::new();
+
+        // The queue is ordered by cost and then by the order nodes were discovered.
+        let mut queue = BinaryHeap::from([Reverse((0, 0))]);
+        while let Some(Reverse((cost, idx))) = queue.pop() {
+            let (node, path) = nodes[idx].clone();
+            if !settled.insert(node.clone()) {
+                continue;
+            }
+            let (produced, used, done) = node;
+
+            // Finish when every goal state has been produced after the last `through` operation.
+            if used == through.len() && done.len() == end.len() {
+                return Some((path, cost));
             }
 
-            // Traverse any edge from the current state to an unvisited state.
             for (op_ref, op) in self.ops.iter() {
-                if op.input == cur_state && !visited[op.output] {
-                    state_queue.push(op.output);
-                    visited[op.output] = true;
-                    breadcrumbs[op.output] = Some(op_ref);
+                let ready = op
+                    .input
+                    .iter()
+                    .all(|s| start.contains(s) || produced.contains(s));
+                if !ready {
+                    continue;
                 }
 
-                // Finish when we reach the goal edge.
-                if end == Destination::Op(op_ref) {
-                    break;
+                // The `through` operations must run in order and are always allowed.
+                let (next_used, op_cost) = if through.get(used) == Some(&op_ref)
+                {
+                    (used + 1, self.ops[op_ref].cost)
+                } else if through.contains(&op_ref) {
+                    continue;
+                } else {
+                    match self.op_cost(op_ref, prefer, avoid) {
+                        Some(c) => (used, c),
+                        None => continue,
+                    }
+                };
+
+                let mut next = produced.clone();
+                next.extend(op.output.iter().copied());
+                let mut next_done = if next_used == used {
+                    done.clone()
+                } else {
+                    BTreeSet::new()
+                };
+                next_done.extend(op.output.iter().filter(|s| end.contains(s)));
+
+                // Only consider operations that make progress.
+                if next_used == used
+                    && next.len() == produced.len()
+                    && next_done.len() == done.len()
+                {
+                    continue;
                 }
-            }
-        }
 
-        // Traverse the breadcrumbs backward to build up the path back from output to input.
-        let mut op_path: Vec<OpRef> = vec![];
-        let mut cur_state = match end {
-            Destination::State(state) => state,
-            Destination::Op(op) => {
-                op_path.push(op);
-                self.ops[op].input
-            }
-        };
-        while cur_state != start {
-            match breadcrumbs[cur_state] {
-                Some(op) => {
-                    op_path.push(op);
-                    cur_state = self.ops[op].input;
+                let next_node = (next, next_used, next_done);
+                let next_cost = cost + op_cost;
+                if !matches!(best.get(&next_node), Some(&c) if c <= next_cost) {
+                    best.insert(next_node.clone(), next_cost);
+                    let mut next_path = path.clone();
+                    next_path.push(op_ref);
+                    nodes.push((next_node, next_path));
+                    queue.push(Reverse((next_cost, nodes.len() - 1)));
                 }
-                None => return None,
             }
         }
-        op_path.reverse();
 
-        Some(op_path)
+        None
     }
 
-    /// Find a chain of operations from the `start` state to the `end` state, passing through each
-    /// `through` operation in order.
-    pub fn find_path(
-        &self,
-        start: StateRef,
-        end: StateRef,
-        through: &[OpRef],
-    ) -> Option<Vec<OpRef>> {
-        let mut cur_state = start;
-        let mut op_path: Vec<OpRef> = vec![];
-
-        // Build path segments through each through required operation.
-        for op in through {
-            let segment =
-                self.find_path_segment(cur_state, Destination::Op(*op))?;
-            op_path.extend(segment);
-            cur_state = self.ops[*op].output;
+    /// Describe why each operation in `path` was chosen.
+    fn explain(&self, path: &[OpRef], req: &Request) -> Vec<String> {
+        let mut notes: Vec<String> = path
+            .iter()
+            .map(|&op| {
+                let op_data = &self.ops[op];
+                let mut note =
+                    format!("{}: cost {}", op_data.name, op_data.cost);
+                if req.through.contains(&op) {
+                    note.push_str(" (--through)");
+                } else if self.op_cost(op, &req.prefer, &req.avoid) == Some(0)
+                    && op_data.cost != 0
+                {
+                    note = format!("{}: cost 0 (preferred)", op_data.name);
+                }
+                if !op_data.tags.is_empty() {
+                    note.push_str(&format!(" [{}]", op_data.tags.join(", ")));
+                }
+                note
+            })
+            .collect();
+        let avoided: Vec<&str> = self
+            .ops
+            .iter()
+            .filter(|(op, _)| {
+                self.op_cost(*op, &req.prefer, &req.avoid).is_none()
+            })
+            .map(|(_, op)| op.name.as_str())
+            .collect();
+        if !avoided.is_empty() {
+            notes.push(format!("avoided: {}", avoided.join(", ")));
         }
-
-        // Build the final path segment to the destination state.
-        let segment =
-            self.find_path_segment(cur_state, Destination::State(end))?;
-        op_path.extend(segment);
-
-        Some(op_path)
+        notes
     }
 
     /// Generate a filename with an extension appropriate for the given State.
@@ -119,49 +204,103 @@ impl Driver {
         if state.is_pseudo() {
             Utf8PathBuf::from(format!("_pseudo_{}", state.name))
         } else {
-            // TODO avoid collisions in case we reuse extensions...
             Utf8PathBuf::from(stem).with_extension(&state.extensions[0])
         }
     }
 
     /// Concot a plan to carry out the requested build.
     ///
-    /// This works by searching for a path through the available operations from the input state
-    /// to the output state. If no such path exists in the operation graph, we return None.
+    /// This works by searching for the cheapest set of operations that produce the output states
+    /// from the input states. If no such set exists in the operation graph, we return None.
     pub fn plan(&self, req: Request) -> Option<Plan> {
-        // Find a path through the states.
-        let path =
-            self.find_path(req.start_state, req.end_state, &req.through)?;
-
-        let mut steps: Vec<(OpRef, Utf8PathBuf)> = vec![];
-
-        // Get the initial input filename and the stem to use to generate all intermediate filenames.
-        let (stdin, start_file) = match req.start_file {
-            Some(path) => (false, utils::relative_path(&path, &req.workdir)),
-            None => (true, "stdin".into()),
+        // Find the cheapest path through the states.
+        let (path, cost) = self.find_path(
+            &req.start_states,
+            &req.end_states,
+            &req.through,
+            &req.prefer,
+            &req.avoid,
+        )?;
+        let explanation = self.explain(&path, &req);
+
+        // Get the input filenames and the stem to use to generate all intermediate filenames.
+        let (stdin, inputs) = if req.start_files.is_empty() {
+            (true, vec![Utf8PathBuf::from("stdin")])
+        } else {
+            let inputs = req
+                .start_files
+                .iter()
+                .map(|path| utils::relative_path(path, &req.workdir))
+                .collect();
+            (false, inputs)
         };
-        let stem = start_file.file_stem().unwrap();
+        let stem = inputs[0].file_stem().unwrap().to_string();
+
+        // The file that holds each state as the plan progresses.
+        let mut files: HashMap<StateRef, Utf8PathBuf> = req
+            .start_states
+            .iter()
+            .copied()
+            .zip(inputs.iter().cloned())
+            .collect();
+        let mut used: HashSet<Utf8PathBuf> = inputs.iter().cloned().collect();
+
+        // Use the specified output filenames instead of generated ones.
+        let end_files: HashMap<StateRef, Utf8PathBuf> = req
+            .end_states
+            .iter()
+            .copied()
+            .zip(
+                req.end_files
+                    .iter()
+                    .map(|path| utils::relative_path(path, &req.workdir)),
+            )
+            .collect();
 
         // Generate filenames for each step.
-        steps.extend(path.into_iter().map(|op| {
-            let filename = self.gen_name(stem, self.ops[op].output);
-            (op, filename)
-        }));
-
-        // If we have a specified output filename, use that instead of the generated one.
-        let stdout = if let Some(end_file) = req.end_file {
-            // TODO Can we just avoid generating the unused filename in the first place?
-            let last_step = steps.last_mut().expect("no steps");
-            last_step.1 = utils::relative_path(&end_file, &req.workdir);
-            false
-        } else {
-            // Print to stdout if the last state is a real (non-pseudo) state.
-            !self.states[req.end_state].is_pseudo()
-        };
+        let mut steps = vec![];
+        for op in path {
+            let op_data = &self.ops[op];
+            let op_inputs =
+                op_data.input.iter().map(|s| files[s].clone()).collect();
+            let op_outputs = op_data
+                .output
+                .iter()
+                .map(|&state| {
+                    let mut filename = end_files
+                        .get(&state)
+                        .cloned()
+                        .unwrap_or_else(|| self.gen_name(&stem, state));
+                    // Avoid clobbering another file in the build, like an input with the same
+                    // extension.
+                    if used.contains(&filename)
+                        && !end_files.contains_key(&state)
+                    {
+                        filename = self.gen_name(
+                            &format!("{}_{}", stem, self.states[state].name),
+                            state,
+                        );
+                    }
+                    used.insert(filename.clone());
+                    files.insert(state, filename.clone());
+                    filename
+                })
+                .collect();
+            steps.push((op, op_inputs, op_outputs));
+        }
+
+        // Print to stdout if there is a single output with no filename and it is a real
+        // (non-pseudo) state.
+        let stdout = req.end_files.is_empty()
+            && req.end_states.len() == 1
+            && !self.states[req.end_states[0]].is_pseudo();
 
         Some(Plan {
-            start: start_file,
+            inputs,
             steps,
+            results: req.end_states.iter().map(|s| files[s].clone()).collect(),
+            cost,
+            explanation,
             workdir: req.workdir,
             stdin,
             stdout,
@@ -200,7 +339,29 @@ impl Driver {
         format!(".{}", &self.name).into()
     }
 
-    /// Print a list of registered states and operations to stdout.
+    /// A comma-separated list of the names of some states.
+    pub fn state_names(&self, states: &[StateRef]) -> String {
+        states
+            .iter()
+            .map(|s| self.states[*s].name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// All the configuration options that setups and operations declare. When several of them
+    /// declare the same option, the first declaration wins.
+    pub fn config_keys(&self) -> Vec<&config::ConfigKey> {
+        let mut keys: Vec<&config::ConfigKey> = vec![];
+        let decls = self.setups.values().flat_map(|s| &s.config);
+        for key in decls.chain(self.ops.values().flat_map(|o| &o.config)) {
+            if !keys.iter().any(|k| k.key == key.key) {
+                keys.push(key);
+            }
+        }
+        keys
+    }
+
+    /// Print a list of registered states, operations, and configuration options to stdout.
     pub fn print_info(&self) {
         println!("States:");
         for (_, state) in self.states.iter() {
@@ -208,18 +369,38 @@ impl Driver {
             for ext in &state.extensions {
                 print!(" .{}", ext);
             }
+            if !state.tags.is_empty() {
+                print!(" [{}]", state.tags.join(", "));
+            }
             println!();
         }
 
         println!();
         println!("Operations:");
         for (_, op) in self.ops.iter() {
-            println!(
-                "  {}: {} -> {}",
+            print!(
+                "  {}: {} -> {} (cost {})",
                 op.name,
-                self.states[op.input].name,
-                self.states[op.output].name
+                self.state_names(&op.input),
+                self.state_names(&op.output),
+                op.cost
             );
+            if !op.tags.is_empty() {
+                print!(" [{}]", op.tags.join(", "));
+            }
+            println!();
+            for key in &op.config {
+                println!("    {}", key);
+            }
+        }
+
+        println!();
+        println!("Setups:");
+        for (_, setup) in self.setups.iter() {
+            println!("  {}", setup.name);
+            for key in &setup.config {
+                println!("    {}", key);
+            }
         }
     }
 }
@@ -231,6 +412,7 @@ pub struct DriverBuilder {
     ops: PrimaryMap<OpRef, Operation>,
     rsrc_dir: Option<Utf8PathBuf>,
     rsrc_files: Option<FileData>,
+    rsrc_paths: HashMap<String, Utf8PathBuf>,
 }
 
 impl DriverBuilder {
@@ -242,6 +424,7 @@ impl DriverBuilder {
             ops: Default::default(),
             rsrc_dir: None,
             rsrc_files: None,
+            rsrc_paths: HashMap::new(),
         }
     }
 
@@ -249,22 +432,70 @@ impl DriverBuilder {
         self.states.push(State {
             name: name.to_string(),
             extensions: extensions.iter().map(|s| s.to_string()).collect(),
+            tags: vec![],
         })
     }
 
-    fn add_op<T: run::EmitBuild + 'static>(
+    /// Add tags to a state that users can refer to with `--prefer` and `--avoid`.
+    pub fn state_tags(&mut self, state: StateRef, tags: &[&str]) {
+        self.states[state]
+            .tags
+            .extend(tags.iter().map(|t| t.to_string()));
+    }
+
+    /// Add tags to an operation that users can refer to with `--prefer` and `--avoid`.
+    pub fn op_tags(&mut self, op: OpRef, tags: &[&str]) {
+        self.ops[op].tags.extend(tags.iter().map(|t| t.to_string()));
+    }
+
+    /// Set the cost of using an operation. Operations cost 1 by default.
+    pub fn op_cost(&mut self, op: OpRef, cost: u32) {
+        self.ops[op].cost = cost;
+    }
+
+    /// Never reuse an operation's outputs from the cache.
+    pub fn op_uncached(&mut self, op: OpRef) {
+        self.ops[op].cacheable = false;
+    }
+
+    /// Declare how to find the files that an operation reads besides its inputs. The cache
+    /// hashes these files too.
+    pub fn op_deps(&mut self, op: OpRef, deps: run::DepsFn) {
+        self.ops[op].deps = Some(deps);
+    }
+
+    /// Declare the configuration options that a setup reads.
+    pub fn setup_config(
+        &mut self,
+        setup: SetupRef,
+        keys: &[config::ConfigKey],
+    ) {
+        self.setups[setup].config.extend_from_slice(keys);
+    }
+
+    /// Declare the configuration options that an operation reads.
+    pub fn op_config(&mut self, op: OpRef, keys: &[config::ConfigKey]) {
+        self.ops[op].config.extend_from_slice(keys);
+    }
+
+    pub fn add_op<T: run::EmitBuild + 'static>(
         &mut self,
         name: &str,
         setups: &[SetupRef],
-        input: StateRef,
-        output: StateRef,
+        input: &[StateRef],
+        output: &[StateRef],
         emit: T,
     ) -> OpRef {
         self.ops.push(Operation {
             name: name.into(),
             setups: setups.into(),
-            input,
-            output,
+            input: input.into(),
+            output: output.into(),
+            cost: 1,
+            tags: vec![],
+            cacheable: true,
+            deps: None,
+            config: vec![],
             emit: Box::new(emit),
         })
     }
@@ -276,6 +507,7 @@ impl DriverBuilder {
     ) -> SetupRef {
         self.setups.push(Setup {
             name: name.into(),
+            config: vec![],
             emit: Box::new(emit),
         })
     }
@@ -288,8 +520,8 @@ impl DriverBuilder {
         &mut self,
         name: &str,
         setups: &[SetupRef],
-        input: StateRef,
-        output: StateRef,
+        input: &[StateRef],
+        output: &[StateRef],
         build: run::EmitBuildFn,
     ) -> OpRef {
         self.add_op(name, setups, input, output, build)
@@ -298,8 +530,8 @@ impl DriverBuilder {
     pub fn rule(
         &mut self,
         setups: &[SetupRef],
-        input: StateRef,
-        output: StateRef,
+        input: &[StateRef],
+        output: &[StateRef],
         rule_name: &str,
     ) -> OpRef {
         self.add_op(
@@ -321,6 +553,65 @@ impl DriverBuilder {
         self.rsrc_files = Some(files);
     }
 
+    /// Provide the resource file `name` by copying the file at `path`.
+    pub fn rsrc_path(&mut self, name: &str, path: Utf8PathBuf) {
+        self.rsrc_paths.insert(name.to_string(), path);
+    }
+
+    pub fn find_state(&self, name: &str) -> Option<StateRef> {
+        self.states
+            .iter()
+            .find(|(_, state)| state.name == name)
+            .map(|(state_ref, _)| state_ref)
+    }
+
+    pub fn find_setup(&self, name: &str) -> Option<SetupRef> {
+        self.setups
+            .iter()
+            .find(|(_, setup)| setup.name == name)
+            .map(|(setup_ref, _)| setup_ref)
+    }
+
+    pub fn find_op(&self, name: &str) -> Option<OpRef> {
+        self.ops
+            .iter()
+            .find(|(_, op)| op.name == name)
+            .map(|(op_ref, _)| op_ref)
+    }
+
+    /// Add the states, setups, and operations defined in a TOML plugin file.
+    pub fn load_plugin(&mut self, path: &Utf8Path) -> anyhow::Result<()> {
+        plugin::load(self, path)
+    }
+
+    /// Load every plugin in the driver's plugin directory, along with those listed in the
+    /// `plugins` configuration option.
+    pub fn load_plugins(&mut self) -> anyhow::Result<()> {
+        let mut paths = vec![];
+        let plugin_dir = config::plugin_dir(&self.name);
+        if let Ok(entries) = std::fs::read_dir(&plugin_dir) {
+            for entry in entries {
+                let path = Utf8PathBuf::try_from(entry?.path())?;
+                if path.extension() == Some("toml") {
+                    paths.push(path);
+                }
+            }
+        }
+        // Load plugins in a predictable order.
+        paths.sort();
+        if let Ok(extra) = config::load_config(&self.name)
+            .extract_inner::<Vec<String>>("plugins")
+        {
+            paths.extend(extra.into_iter().map(Utf8PathBuf::from));
+        }
+
+        for path in paths {
+            log::info!("Loading plugin {}", path);
+            self.load_plugin(&path)?;
+        }
+        Ok(())
+    }
+
     pub fn build(self) -> Driver {
         Driver {
             name: self.name,
@@ -329,17 +620,27 @@ impl DriverBuilder {
             ops: self.ops,
             rsrc_dir: self.rsrc_dir,
             rsrc_files: self.rsrc_files,
+            rsrc_paths: self.rsrc_paths,
         }
     }
 }
 
 #[derive(Debug)]
 pub struct Plan {
-    /// The input to the first step.
-    pub start: Utf8PathBuf,
+    /// The input files, one for each start state.
+    pub inputs: Vec<Utf8PathBuf>,
+
+    /// The chain of operations to run along with each step's input and output files.
+    pub steps: Vec<(OpRef, Vec<Utf8PathBuf>, Vec<Utf8PathBuf>)>,
+
+    /// The files that hold the requested outputs, one for each end state.
+    pub results: Vec<Utf8PathBuf>,
 
-    /// The chain of operations to run and each step's output file.
-    pub steps: Vec<(OpRef, Utf8PathBuf)>,
+    /// The total cost of the operations in the plan.
+    pub cost: u32,
+
+    /// A description of why the planner chose these operations.
+    pub explanation: Vec<String>,
 
     /// The directory that the build will happen in.
     pub workdir: Utf8PathBuf,
@@ -352,10 +653,19 @@ pub struct Plan {
 }
 
 impl Plan {
-    pub fn end(&self) -> &Utf8Path {
-        match self.steps.last() {
-            Some((_, path)) => path,
-            None => &self.start,
+    /// Move the files that the plan generates into `dir`, a subdirectory of the working directory,
+    /// so the plan's files don't collide with those of other plans in the same directory.
+    pub fn relocate(&mut self, dir: &Utf8Path) {
+        let inputs = &self.inputs;
+        let mv = |file: &mut Utf8PathBuf| {
+            if !inputs.contains(file) {
+                *file = dir.join(&*file);
+            }
+        };
+        for (_, step_inputs, step_outputs) in &mut self.steps {
+            step_inputs.iter_mut().for_each(mv);
+            step_outputs.iter_mut().for_each(mv);
         }
+        self.results.iter_mut().for_each(mv);
     }
 }
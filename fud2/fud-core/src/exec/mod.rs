@@ -1,5 +1,6 @@
 mod data;
 mod driver;
+mod plugin;
 mod request;
 
 pub use data::{OpRef, SetupRef, StateRef};
@@ -0,0 +1,321 @@
+//! Operations defined at runtime in TOML plugin files.
+//!
+//! A plugin file declares states, setups, and operations that get added to a driver alongside the
+//! ones built into it. Setups and operations use the same `Emitter` machinery as Rust ones, so a
+//! plugin can do anything that a simple Rust setup or operation can:
+//!
+//! ```toml
+//! [[state]]
+//! name = "netlist"
+//! extensions = ["json"]
+//!
+//! [[setup]]
+//! name = "Yosys"
+//! config_vars = [{ name = "yosys", key = "yosys.exe", default = "yosys", type = "path" }]
+//! rules = [{ name = "yosys-synth", command = "$yosys -p 'synth; write_json $out' $in" }]
+//!
+//! [[op]]
+//! name = "yosys"
+//! setups = ["Yosys"]
+//! input = ["verilog"]
+//! output = ["netlist"]
+//! rule = "yosys-synth"
+//! ```
+//!
+//! Operations that need more than one Ninja `build` command list them as `[[op.build]]` tables.
+//! The strings in these commands can refer to the operation's files with `{input0}`, `{output1}`,
+//! etc.; a list entry that is just `{input}` or `{output}` stands for all of them.
+
+use super::{DriverBuilder, OpRef, SetupRef, StateRef};
+use crate::config::{ConfigKey, ConfigType};
+use crate::run::{EmitBuild, EmitResult, EmitSetup, Emitter};
+use anyhow::{anyhow, bail, Context};
+use camino::Utf8Path;
+use figment::{
+    providers::{Format, Toml},
+    Figment,
+};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(Deserialize)]
+struct PluginFile {
+    #[serde(default)]
+    state: Vec<StateDecl>,
+    #[serde(default)]
+    setup: Vec<SetupDecl>,
+    #[serde(default)]
+    op: Vec<OpDecl>,
+}
+
+#[derive(Deserialize)]
+struct StateDecl {
+    name: String,
+    #[serde(default)]
+    extensions: Vec<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ConfigVar {
+    /// The Ninja variable to define.
+    name: String,
+    /// The configuration key to read it from.
+    key: String,
+    /// The value to use if the key is missing. Without one, a missing key is an error.
+    default: Option<String>,
+    /// The kind of value the key holds, for checking configurations.
+    #[serde(rename = "type", default = "default_type")]
+    ty: ConfigType,
+    /// A description of the key, for `list`.
+    #[serde(default)]
+    doc: String,
+}
+
+fn default_type() -> ConfigType {
+    ConfigType::String
+}
+
+#[derive(Deserialize)]
+struct Var {
+    name: String,
+    value: String,
+}
+
+#[derive(Deserialize)]
+struct Rule {
+    name: String,
+    command: String,
+}
+
+#[derive(Deserialize)]
+struct SetupDecl {
+    name: String,
+    #[serde(default)]
+    config_vars: Vec<ConfigVar>,
+    #[serde(default)]
+    vars: Vec<Var>,
+    #[serde(default)]
+    rules: Vec<Rule>,
+    /// Resource files, relative to the plugin file, to extract into the build directory.
+    #[serde(default)]
+    rsrc: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct BuildDecl {
+    rule: String,
+    /// The targets of the build command. Defaults to the operation's output files.
+    outputs: Option<Vec<String>>,
+    /// The dependencies of the build command. Defaults to the operation's input files.
+    inputs: Option<Vec<String>>,
+    #[serde(default)]
+    implicit: Vec<String>,
+    #[serde(default)]
+    args: BTreeMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct OpDecl {
+    name: String,
+    #[serde(default)]
+    setups: Vec<String>,
+    input: Vec<String>,
+    output: Vec<String>,
+    cost: Option<u32>,
+    #[serde(default)]
+    tags: Vec<String>,
+    /// Shorthand for a single build command that runs this rule.
+    rule: Option<String>,
+    #[serde(default)]
+    build: Vec<BuildDecl>,
+}
+
+impl EmitSetup for SetupDecl {
+    fn setup(&self, emitter: &mut Emitter) -> EmitResult {
+        for var in &self.config_vars {
+            match &var.default {
+                Some(default) => {
+                    emitter.config_var_or(&var.name, &var.key, default)?
+                }
+                None => emitter.config_var(&var.name, &var.key)?,
+            }
+        }
+        for var in &self.vars {
+            emitter.var(&var.name, &var.value)?;
+        }
+        for rule in &self.rules {
+            emitter.rule(&rule.name, &rule.command)?;
+        }
+        for filename in &self.rsrc {
+            emitter.rsrc(filename)?;
+        }
+        Ok(())
+    }
+}
+
+/// Replace `{inputN}` and `{outputN}` in `text` with the operation's filenames.
+fn substitute(text: &str, input: &[&str], output: &[&str]) -> String {
+    let mut text = text.to_string();
+    for (prefix, files) in [("input", input), ("output", output)] {
+        for (i, file) in files.iter().enumerate() {
+            text = text.replace(&format!("{{{}{}}}", prefix, i), file);
+        }
+    }
+    text
+}
+
+/// Expand a list of filenames that may refer to the operation's files.
+fn expand(names: &[String], input: &[&str], output: &[&str]) -> Vec<String> {
+    names
+        .iter()
+        .flat_map(|name| match name.as_str() {
+            "{input}" => input.iter().map(|s| s.to_string()).collect(),
+            "{output}" => output.iter().map(|s| s.to_string()).collect(),
+            _ => vec![substitute(name, input, output)],
+        })
+        .collect()
+}
+
+/// The Ninja build commands for a plugin operation.
+struct PluginBuild {
+    builds: Vec<BuildDecl>,
+}
+
+impl EmitBuild for PluginBuild {
+    fn build(
+        &self,
+        emitter: &mut Emitter,
+        input: &[&str],
+        output: &[&str],
+    ) -> EmitResult {
+        let owned = |s: &[&str]| s.iter().map(|s| s.to_string()).collect();
+        for build in &self.builds {
+            let targets: Vec<String> = match &build.outputs {
+                Some(names) => expand(names, input, output),
+                None => owned(output),
+            };
+            let deps: Vec<String> = match &build.inputs {
+                Some(names) => expand(names, input, output),
+                None => owned(input),
+            };
+            let implicit = expand(&build.implicit, input, output);
+            let targets: Vec<_> = targets.iter().map(|s| s.as_str()).collect();
+            let deps: Vec<_> = deps.iter().map(|s| s.as_str()).collect();
+            let implicit: Vec<_> =
+                implicit.iter().map(|s| s.as_str()).collect();
+            emitter.build_cmd(&targets, &build.rule, &deps, &implicit)?;
+            for (name, value) in &build.args {
+                emitter.arg(name, &substitute(value, input, output))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Add the states, setups, and operations declared in the plugin file at `path` to a driver.
+pub(super) fn load(
+    bld: &mut DriverBuilder,
+    path: &Utf8Path,
+) -> anyhow::Result<()> {
+    let plugin: PluginFile = Figment::from(Toml::file(path))
+        .extract()
+        .with_context(|| format!("could not parse plugin {}", path))?;
+    add_plugin(bld, plugin, path.parent().unwrap_or(Utf8Path::new(".")))
+        .with_context(|| format!("error in plugin {}", path))
+}
+
+fn add_plugin(
+    bld: &mut DriverBuilder,
+    plugin: PluginFile,
+    dir: &Utf8Path,
+) -> anyhow::Result<()> {
+    for decl in plugin.state {
+        if bld.find_state(&decl.name).is_some() {
+            bail!("state {} is already defined", decl.name);
+        }
+        let exts: Vec<_> = decl.extensions.iter().map(|s| s.as_str()).collect();
+        let state = bld.state(&decl.name, &exts);
+        let tags: Vec<_> = decl.tags.iter().map(|s| s.as_str()).collect();
+        bld.state_tags(state, &tags);
+    }
+
+    for decl in plugin.setup {
+        if bld.find_setup(&decl.name).is_some() {
+            bail!("setup {} is already defined", decl.name);
+        }
+        for filename in &decl.rsrc {
+            bld.rsrc_path(filename, dir.join(filename));
+        }
+        let keys: Vec<_> = decl
+            .config_vars
+            .iter()
+            .map(|var| ConfigKey {
+                key: var.key.clone(),
+                ty: var.ty,
+                default: var.default.clone(),
+                doc: var.doc.clone(),
+            })
+            .collect();
+        let setup = bld.add_setup(&decl.name.clone(), decl);
+        bld.setup_config(setup, &keys);
+    }
+
+    for decl in plugin.op {
+        if bld.find_op(&decl.name).is_some() {
+            bail!("op {} is already defined", decl.name);
+        }
+        let setups = decl
+            .setups
+            .iter()
+            .map(|name| {
+                bld.find_setup(name)
+                    .ok_or_else(|| anyhow!("unknown setup {}", name))
+            })
+            .collect::<anyhow::Result<Vec<SetupRef>>>()?;
+        let states = |names: &[String]| {
+            names
+                .iter()
+                .map(|name| {
+                    bld.find_state(name)
+                        .ok_or_else(|| anyhow!("unknown state {}", name))
+                })
+                .collect::<anyhow::Result<Vec<StateRef>>>()
+        };
+        let input = states(&decl.input)?;
+        let output = states(&decl.output)?;
+
+        let builds = match decl.rule {
+            Some(_) if !decl.build.is_empty() => {
+                bail!("op {} has both a rule and build commands", decl.name)
+            }
+            Some(rule) => vec![BuildDecl {
+                rule,
+                outputs: None,
+                inputs: None,
+                implicit: vec![],
+                args: BTreeMap::new(),
+            }],
+            None if decl.build.is_empty() => {
+                bail!("op {} needs a rule or build commands", decl.name)
+            }
+            None => decl.build,
+        };
+
+        let op: OpRef = bld.add_op(
+            &decl.name,
+            &setups,
+            &input,
+            &output,
+            PluginBuild { builds },
+        );
+        if let Some(cost) = decl.cost {
+            bld.op_cost(op, cost);
+        }
+        let tags: Vec<_> = decl.tags.iter().map(|s| s.as_str()).collect();
+        bld.op_tags(op, &tags);
+    }
+
+    Ok(())
+}
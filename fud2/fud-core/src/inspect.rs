@@ -0,0 +1,180 @@
+//! Look at the files of a build that was kept around with `--keep`, and pick the build back up
+//! from one of them.
+//!
+//! Builds record their plan in a manifest next to `build.ninja`. Inspecting a build loads that
+//! plan, and resuming a build narrows it down to the steps that depend on a changed file and
+//! emits and runs it like any other plan.
+
+use crate::exec::{Driver, Plan, StateRef};
+use anyhow::{anyhow, bail};
+use camino::{Utf8Path, Utf8PathBuf};
+use pathdiff::diff_utf8_paths;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// The file in the build directory that records the plan.
+pub const MANIFEST: &str = "plan.json";
+
+#[derive(Serialize, Deserialize)]
+struct Manifest {
+    inputs: Vec<String>,
+    steps: Vec<ManifestStep>,
+    results: Vec<String>,
+    stdin: bool,
+    stdout: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ManifestStep {
+    op: String,
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+}
+
+fn to_strings(paths: &[Utf8PathBuf]) -> Vec<String> {
+    paths.iter().map(|p| p.to_string()).collect()
+}
+
+fn to_paths(strings: Vec<String>) -> Vec<Utf8PathBuf> {
+    strings.into_iter().map(Utf8PathBuf::from).collect()
+}
+
+/// Record a plan in its working directory so the build can be inspected later.
+pub fn write_manifest(driver: &Driver, plan: &Plan) -> std::io::Result<()> {
+    let manifest = Manifest {
+        inputs: to_strings(&plan.inputs),
+        steps: plan
+            .steps
+            .iter()
+            .map(|(op, inputs, outputs)| ManifestStep {
+                op: driver.ops[*op].name.clone(),
+                inputs: to_strings(inputs),
+                outputs: to_strings(outputs),
+            })
+            .collect(),
+        results: to_strings(&plan.results),
+        stdin: plan.stdin,
+        stdout: plan.stdout,
+    };
+    let file = std::fs::File::create(plan.workdir.join(MANIFEST))?;
+    serde_json::to_writer_pretty(file, &manifest)?;
+    Ok(())
+}
+
+/// Load the plan of the build in `dir`.
+pub fn read_plan(driver: &Driver, dir: &Utf8Path) -> anyhow::Result<Plan> {
+    let data = std::fs::read_to_string(dir.join(MANIFEST)).map_err(|_| {
+        anyhow!("no build to inspect in {}; use --keep to keep one", dir)
+    })?;
+    let manifest: Manifest = serde_json::from_str(&data)?;
+    let steps = manifest
+        .steps
+        .into_iter()
+        .map(|step| {
+            let op = driver
+                .get_op(&step.op)
+                .ok_or_else(|| anyhow!("unknown op {}", step.op))?;
+            Ok((op, to_paths(step.inputs), to_paths(step.outputs)))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(Plan {
+        inputs: to_paths(manifest.inputs),
+        cost: steps.iter().map(|(op, _, _)| driver.ops[*op].cost).sum(),
+        steps,
+        results: to_paths(manifest.results),
+        explanation: vec![],
+        workdir: dir.into(),
+        stdin: manifest.stdin,
+        stdout: manifest.stdout,
+    })
+}
+
+/// Find the state of a file in a plan, and the operation that produces it if any.
+fn file_origin<'a>(
+    driver: &'a Driver,
+    plan: &Plan,
+    file: &Utf8Path,
+) -> (Option<StateRef>, Option<&'a str>) {
+    for (op, _, outputs) in &plan.steps {
+        if let Some(i) = outputs.iter().position(|f| f == file) {
+            let op = &driver.ops[*op];
+            return (Some(op.output[i]), Some(&op.name));
+        }
+    }
+    for (op, inputs, _) in &plan.steps {
+        if let Some(i) = inputs.iter().position(|f| f == file) {
+            return (Some(driver.ops[*op].input[i]), None);
+        }
+    }
+    (None, None)
+}
+
+/// Print every file in a plan along with the operation that produced it.
+pub fn print_files(driver: &Driver, plan: &Plan) {
+    let mut files: Vec<&Utf8PathBuf> = vec![];
+    let all = plan
+        .inputs
+        .iter()
+        .chain(plan.steps.iter().flat_map(|(_, _, outputs)| outputs));
+    for file in all {
+        if !files.contains(&file) {
+            files.push(file);
+        }
+    }
+
+    println!("Files in {}:", plan.workdir);
+    for file in files {
+        let (state, op) = file_origin(driver, plan, file);
+        print!("  {}", file);
+        if let Some(state) = state {
+            print!(" ({})", driver.states[state].name);
+        }
+        match op {
+            Some(op) => print!(": from {}", op),
+            None => print!(": input"),
+        }
+        match std::fs::metadata(plan.workdir.join(file)) {
+            Ok(meta) => print!(", {} bytes", meta.len()),
+            Err(_) => print!(", missing"),
+        }
+        if plan.results.contains(file) {
+            print!(", result");
+        }
+        println!();
+    }
+}
+
+/// Narrow a plan down to the steps that depend on `file`, so the build can pick up again after
+/// the user changes that file. `file` is either relative to the working directory, as
+/// `print_files` lists it, or to the current directory.
+pub fn resume_from(mut plan: Plan, file: &Utf8Path) -> anyhow::Result<Plan> {
+    let is_known = |f: &Utf8Path| {
+        plan.inputs
+            .iter()
+            .chain(plan.steps.iter().flat_map(|(_, _, outputs)| outputs))
+            .any(|p| p == f)
+    };
+    let file = match diff_utf8_paths(file, &plan.workdir) {
+        _ if is_known(file) => file.to_path_buf(),
+        Some(local) if is_known(&local) => local,
+        _ => bail!("{} is not a file in the build in {}", file, plan.workdir),
+    };
+
+    // Keep the steps that (transitively) consume the changed file.
+    let mut changed: HashSet<Utf8PathBuf> = HashSet::from([file.clone()]);
+    plan.steps.retain(|(_, inputs, outputs)| {
+        let depends = inputs.iter().any(|f| changed.contains(f));
+        if depends {
+            changed.extend(outputs.iter().cloned());
+        }
+        depends
+    });
+    plan.results.retain(|f| changed.contains(f));
+    if plan.results.is_empty() {
+        bail!("none of the build's results depend on {}", file);
+    }
+
+    // Any input from stdin was already captured in the first run.
+    plan.stdin = false;
+    Ok(plan)
+}
@@ -1,6 +1,9 @@
+pub mod batch;
+pub mod cache;
 pub mod cli;
 pub mod config;
 pub mod exec;
+pub mod inspect;
 pub mod run;
 pub mod utils;
 
@@ -1,8 +1,12 @@
+use crate::cache::{self, Cache, Hits};
 use crate::config;
 use crate::exec::{Driver, OpRef, Plan, SetupRef, StateRef};
 use crate::utils::relative_path;
 use camino::{Utf8Path, Utf8PathBuf};
-use std::collections::{HashMap, HashSet};
+use figment::value::Value;
+use serde::Serialize;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::io::Write;
 use std::process::Command;
 
@@ -35,23 +39,26 @@ impl std::error::Error for EmitError {}
 pub type EmitResult = std::result::Result<(), EmitError>;
 
 /// Code to emit a Ninja `build` command.
+///
+/// The `input` and `output` filenames correspond to the input and output states of the operation,
+/// in order.
 pub trait EmitBuild {
     fn build(
         &self,
         emitter: &mut Emitter,
-        input: &str,
-        output: &str,
+        input: &[&str],
+        output: &[&str],
     ) -> EmitResult;
 }
 
-pub type EmitBuildFn = fn(&mut Emitter, &str, &str) -> EmitResult;
+pub type EmitBuildFn = fn(&mut Emitter, &[&str], &[&str]) -> EmitResult;
 
 impl EmitBuild for EmitBuildFn {
     fn build(
         &self,
         emitter: &mut Emitter,
-        input: &str,
-        output: &str,
+        input: &[&str],
+        output: &[&str],
     ) -> EmitResult {
         self(emitter, input, output)
     }
@@ -67,14 +74,22 @@ impl EmitBuild for EmitRuleBuild {
     fn build(
         &self,
         emitter: &mut Emitter,
-        input: &str,
-        output: &str,
+        input: &[&str],
+        output: &[&str],
     ) -> EmitResult {
-        emitter.build(&self.rule_name, input, output)?;
+        emitter.build_cmd(output, &self.rule_name, input, &[])?;
         Ok(())
     }
 }
 
+/// Finds the files that an operation reads besides its inputs, like the files that a program
+/// imports. It gets the operation's input files and the configuration values that the plan uses,
+/// indexed by key.
+pub type DepsFn = fn(
+    &[Utf8PathBuf],
+    &HashMap<&str, &str>,
+) -> std::io::Result<Vec<Utf8PathBuf>>;
+
 /// Code to emit Ninja code at the setup stage.
 pub trait EmitSetup {
     fn setup(&self, emitter: &mut Emitter) -> EmitResult;
@@ -119,16 +134,25 @@ impl<'a> Run<'a> {
     /// Just print the plan for debugging purposes.
     pub fn show(self) {
         if self.plan.stdin {
-            println!("(stdin) -> {}", self.plan.start);
+            println!("(stdin) -> {}", self.plan.inputs[0]);
         } else {
-            println!("start: {}", self.plan.start);
+            println!("start: {}", join_paths(&self.plan.inputs));
         }
-        for (op, file) in self.plan.steps {
-            println!("{}: {} -> {}", op, self.driver.ops[op].name, file);
+        for (op, _, outputs) in self.plan.steps {
+            println!(
+                "{}: {} -> {}",
+                op,
+                self.driver.ops[op].name,
+                join_paths(&outputs)
+            );
         }
         if self.plan.stdout {
             println!("-> (stdout)");
         }
+        println!("cost: {}", self.plan.cost);
+        for note in &self.plan.explanation {
+            println!("  {}", note);
+        }
     }
 
     /// Print a GraphViz representation of the plan.
@@ -140,13 +164,14 @@ impl<'a> Run<'a> {
         // Record the states and ops that are actually used in the plan.
         let mut states: HashMap<StateRef, String> = HashMap::new();
         let mut ops: HashSet<OpRef> = HashSet::new();
-        let first_op = self.plan.steps[0].0;
-        states.insert(
-            self.driver.ops[first_op].input,
-            self.plan.start.to_string(),
-        );
-        for (op, file) in &self.plan.steps {
-            states.insert(self.driver.ops[*op].output, file.to_string());
+        for (op, inputs, outputs) in &self.plan.steps {
+            let op_data = &self.driver.ops[*op];
+            for (state, file) in op_data.input.iter().zip(inputs) {
+                states.entry(*state).or_insert_with(|| file.to_string());
+            }
+            for (state, file) in op_data.output.iter().zip(outputs) {
+                states.insert(*state, file.to_string());
+            }
             ops.insert(*op);
         }
 
@@ -164,18 +189,46 @@ impl<'a> Run<'a> {
             println!("];");
         }
 
-        // Show all operations.
+        // Show all operations, with an edge from each input to each output.
         for (op_ref, op) in self.driver.ops.iter() {
-            print!("  {} -> {} [label=\"{}\"", op.input, op.output, op.name);
-            if ops.contains(&op_ref) {
-                print!(" penwidth=3");
+            for input in &op.input {
+                for output in &op.output {
+                    print!("  {} -> {} [label=\"{}\"", input, output, op.name);
+                    if ops.contains(&op_ref) {
+                        print!(" penwidth=3");
+                    }
+                    println!("];");
+                }
             }
-            println!("];");
         }
 
         println!("}}");
     }
 
+    /// Print a JSON description of the plan, including everything the Ninja file would contain.
+    ///
+    /// Unlike emitting the Ninja file, missing configuration keys are not an error: they are
+    /// listed in the description instead.
+    pub fn show_json(&self) -> EmitResult {
+        let desc = self.describe()?;
+        serde_json::to_writer_pretty(std::io::stdout(), &desc)
+            .map_err(std::io::Error::from)?;
+        println!();
+        Ok(())
+    }
+
+    /// Describe the plan by running its setups and build steps with an emitter that records what
+    /// they produce.
+    pub fn describe(&self) -> Result<PlanDesc, EmitError> {
+        describe_plan(
+            self.driver,
+            &self.plan,
+            &self.config_data,
+            &self.global_config,
+            Utf8Path::new(""),
+        )
+    }
+
     /// Print the `build.ninja` file to stdout.
     pub fn emit_to_stdout(&self) -> EmitResult {
         self.emit(std::io::stdout())
@@ -183,23 +236,26 @@ impl<'a> Run<'a> {
 
     /// Ensure that a directory exists and write `build.ninja` inside it.
     pub fn emit_to_dir(&self, dir: &Utf8Path) -> EmitResult {
+        self.write_ninja(dir, &Hits::new())
+    }
+
+    fn write_ninja(&self, dir: &Utf8Path, hits: &Hits) -> EmitResult {
         std::fs::create_dir_all(dir)?;
         let ninja_path = dir.join("build.ninja");
         let ninja_file = std::fs::File::create(ninja_path)?;
 
-        self.emit(ninja_file)
+        self.emit_with_hits(ninja_file, hits)
     }
 
     /// Emit `build.ninja` to a temporary directory and then actually execute ninja.
     pub fn emit_and_run(&self, dir: &Utf8Path) -> EmitResult {
-        // Emit the Ninja file.
         let stale_dir = dir.exists();
-        self.emit_to_dir(dir)?;
+        std::fs::create_dir_all(dir)?;
 
         // Capture stdin.
         if self.plan.stdin {
             let stdin_file = std::fs::File::create(
-                self.plan.workdir.join(&self.plan.start),
+                self.plan.workdir.join(&self.plan.inputs[0]),
             )?;
             std::io::copy(
                 &mut std::io::stdin(),
@@ -207,6 +263,24 @@ impl<'a> Run<'a> {
             )?;
         }
 
+        // Look for steps whose outputs are already cached.
+        let cache = Cache::from_config(&self.driver.name, &self.global_config);
+        let mut keys = vec![];
+        let mut hits = Hits::new();
+        if let Some(cache) = &cache {
+            let desc = self.describe()?;
+            keys = cache::step_keys(
+                self.driver,
+                &self.plan,
+                &desc,
+                Utf8Path::new(""),
+            )?;
+            hits = cache.lookup(&self.plan, &keys);
+        }
+
+        // Emit the Ninja file.
+        self.write_ninja(dir, &hits)?;
+
         // Run `ninja` in the working directory.
         let mut cmd = Command::new(&self.global_config.ninja);
         cmd.current_dir(dir);
@@ -214,12 +288,20 @@ impl<'a> Run<'a> {
             // When we're printing to stdout, suppress Ninja's output by default.
             cmd.stdout(std::process::Stdio::null());
         }
-        cmd.status()?;
+        let status = cmd.status()?;
+
+        // Only a successful build is sure to have brought every output up to date.
+        if let Some(cache) = &cache {
+            if status.success() {
+                cache.store(&self.plan, &keys, &hits)?;
+            }
+        }
 
         // Emit stdout.
         if self.plan.stdout {
-            let stdout_file =
-                std::fs::File::open(self.plan.workdir.join(self.plan.end()))?;
+            let stdout_file = std::fs::File::open(
+                self.plan.workdir.join(&self.plan.results[0]),
+            )?;
             std::io::copy(
                 &mut std::io::BufReader::new(stdout_file),
                 &mut std::io::stdout(),
@@ -235,59 +317,363 @@ impl<'a> Run<'a> {
     }
 
     pub fn emit<T: Write + 'a>(&self, out: T) -> EmitResult {
+        self.emit_with_hits(out, &Hits::new())
+    }
+
+    /// Emit the Ninja file, copying the outputs of the steps in `hits` from the cache.
+    fn emit_with_hits<T: Write + 'a>(&self, out: T, hits: &Hits) -> EmitResult {
         let mut emitter = Emitter::new(
             out,
             self.config_data.clone(),
             self.plan.workdir.clone(),
         );
 
-        // Emit preamble.
-        emitter.var("build-tool", &self.global_config.exe)?;
-        emitter.rule("get-rsrc", "$build-tool get-rsrc $out")?;
+        emit_setups(
+            &mut emitter,
+            self.driver,
+            &self.global_config,
+            &[&self.plan],
+        )?;
+        if !hits.is_empty() {
+            emit_cache_rule(&mut emitter)?;
+        }
+
+        // Emit the build commands for each step in the plan.
+        emitter.comment("build targets")?;
+        emit_steps(&mut emitter, self.driver, &self.plan, hits)?;
         writeln!(emitter.out)?;
 
-        // Emit the setup for each operation used in the plan, only once.
-        let mut done_setups = HashSet::<SetupRef>::new();
-        for (op, _) in &self.plan.steps {
-            for setup in &self.driver.ops[*op].setups {
+        // Mark the requested outputs as the default targets.
+        writeln!(emitter.out, "default {}", join_paths(&self.plan.results))?;
+
+        Ok(())
+    }
+}
+
+/// Emit the preamble of a Ninja file followed by the setup for each operation used in `plans`. Each
+/// setup is emitted only once.
+pub(crate) fn emit_setups(
+    emitter: &mut Emitter,
+    driver: &Driver,
+    global_config: &config::GlobalConfig,
+    plans: &[&Plan],
+) -> EmitResult {
+    emit_preamble(emitter, global_config)?;
+    writeln!(emitter.out)?;
+
+    let mut done_setups = HashSet::<SetupRef>::new();
+    for plan in plans {
+        for (op, _, _) in &plan.steps {
+            for setup in &driver.ops[*op].setups {
                 if done_setups.insert(*setup) {
-                    let setup = &self.driver.setups[*setup];
+                    let setup = &driver.setups[*setup];
                     writeln!(emitter.out, "# {}", setup.name)?;
-                    setup.emit.setup(&mut emitter)?;
+                    setup.emit.setup(emitter)?;
                     writeln!(emitter.out)?;
                 }
             }
         }
+    }
+    Ok(())
+}
 
-        // Emit the build commands for each step in the plan.
-        emitter.comment("build targets")?;
-        let mut last_file = &self.plan.start;
-        for (op, out_file) in &self.plan.steps {
-            let op = &self.driver.ops[*op];
-            op.emit.build(
-                &mut emitter,
-                last_file.as_str(),
-                out_file.as_str(),
-            )?;
-            last_file = out_file;
+/// Describe a plan whose intermediate files live in `scratch_dir`.
+pub(crate) fn describe_plan(
+    driver: &Driver,
+    plan: &Plan,
+    config_data: &figment::Figment,
+    global_config: &config::GlobalConfig,
+    scratch_dir: &Utf8Path,
+) -> Result<PlanDesc, EmitError> {
+    let mut emitter = Emitter::new(
+        std::io::sink(),
+        config_data.clone(),
+        plan.workdir.clone(),
+    );
+    emitter.scratch_dir = scratch_dir.into();
+
+    emitter.record = Some(RefCell::default());
+    emit_preamble(&mut emitter, global_config)?;
+    let mut preamble = emitter.take_record();
+
+    let mut setups = vec![];
+    let mut done_setups = HashSet::<SetupRef>::new();
+    for (op, _, _) in &plan.steps {
+        for setup_ref in &driver.ops[*op].setups {
+            if done_setups.insert(*setup_ref) {
+                let setup = &driver.setups[*setup_ref];
+                emitter.record = Some(RefCell::default());
+                setup.emit.setup(&mut emitter)?;
+                setups.push(SetupDesc {
+                    name: setup.name.clone(),
+                    record: emitter.take_record(),
+                });
+            }
         }
-        writeln!(emitter.out)?;
+    }
 
-        // Mark the last file as the default target.
-        writeln!(emitter.out, "default {}", last_file)?;
+    let mut steps = vec![];
+    for (op_ref, inputs, outputs) in &plan.steps {
+        let op = &driver.ops[*op_ref];
+        let ins: Vec<_> = inputs.iter().map(|f| f.as_str()).collect();
+        let outs: Vec<_> = outputs.iter().map(|f| f.as_str()).collect();
+        emitter.record = Some(RefCell::default());
+        op.emit.build(&mut emitter, &ins, &outs)?;
+        steps.push(StepDesc {
+            op: op.name.clone(),
+            inputs: path_strings(inputs),
+            outputs: path_strings(outputs),
+            record: emitter.take_record(),
+        });
+    }
 
-        Ok(())
+    let missing_config = setups
+        .iter()
+        .map(|s| &s.record)
+        .chain(steps.iter().map(|s| &s.record))
+        .flat_map(|r| r.missing.iter().cloned())
+        .collect();
+
+    // Work out the command that each build will run, as Ninja would.
+    let mut vars = HashMap::new();
+    let mut rules = HashMap::new();
+    let mut records: Vec<_> = std::iter::once(&mut preamble)
+        .chain(setups.iter_mut().map(|s| &mut s.record))
+        .chain(steps.iter_mut().map(|s| &mut s.record))
+        .collect();
+    for record in &mut records {
+        for var in &record.vars {
+            let value = expand_vars(&var.value, |name| vars.get(name));
+            vars.insert(var.name.clone(), value);
+        }
+        for rule in &record.rules {
+            rules.insert(rule.name.clone(), rule.clone());
+        }
+        for build in &mut record.builds {
+            build.command = rules.get(&build.rule).map(|rule| {
+                let inputs = build.deps.join(" ");
+                let outputs = build.targets.join(" ");
+                expand_vars(&rule.command, |name| match name {
+                    "in" => Some(&inputs),
+                    "out" => Some(&outputs),
+                    _ => build
+                        .args
+                        .get(name)
+                        .or_else(|| rule.args.get(name))
+                        .or_else(|| vars.get(name)),
+                })
+            });
+        }
+    }
+
+    Ok(PlanDesc {
+        inputs: path_strings(&plan.inputs),
+        results: path_strings(&plan.results),
+        stdin: plan.stdin,
+        stdout: plan.stdout,
+        workdir: plan.workdir.to_string(),
+        cost: plan.cost,
+        preamble,
+        setups,
+        steps,
+        missing_config,
+    })
+}
+
+/// Emit the definitions that every Ninja file starts with.
+fn emit_preamble(
+    emitter: &mut Emitter,
+    global_config: &config::GlobalConfig,
+) -> EmitResult {
+    emitter.var("build-tool", &global_config.exe)?;
+    emitter.rule("get-rsrc", "$build-tool get-rsrc $out")?;
+    Ok(())
+}
+
+/// Emit the rule that copies outputs out of the cache.
+pub(crate) fn emit_cache_rule(emitter: &mut Emitter) -> EmitResult {
+    emitter.rule("cache-fetch", "cp $in $out")?;
+    writeln!(emitter.out)?;
+    Ok(())
+}
+
+/// Emit the build commands for each step in a plan.
+pub(crate) fn emit_steps(
+    emitter: &mut Emitter,
+    driver: &Driver,
+    plan: &Plan,
+    hits: &Hits,
+) -> EmitResult {
+    for (idx, (op, inputs, outputs)) in plan.steps.iter().enumerate() {
+        if let Some(cached) = hits.get(&idx) {
+            for (cached, output) in cached.iter().zip(outputs) {
+                emitter.build(
+                    "cache-fetch",
+                    cached.as_str(),
+                    output.as_str(),
+                )?;
+            }
+            continue;
+        }
+        let op = &driver.ops[*op];
+        let inputs: Vec<_> = inputs.iter().map(|f| f.as_str()).collect();
+        let outputs: Vec<_> = outputs.iter().map(|f| f.as_str()).collect();
+        op.emit.build(emitter, &inputs, &outputs)?;
+    }
+    Ok(())
+}
+
+/// Substitute Ninja variable references (`$name` and `${name}`) in `text`. Undefined variables
+/// expand to nothing, as in Ninja.
+fn expand_vars<'b>(
+    text: &str,
+    lookup: impl Fn(&str) -> Option<&'b String>,
+) -> String {
+    let is_name = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-';
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(idx) = rest.find('$') {
+        out.push_str(&rest[..idx]);
+        rest = &rest[idx + 1..];
+        let name = if let Some(braced) = rest.strip_prefix('{') {
+            let end = braced.find('}').unwrap_or(braced.len());
+            rest = braced.get(end + 1..).unwrap_or("");
+            &braced[..end]
+        } else {
+            let end = rest.find(|c| !is_name(c)).unwrap_or(rest.len());
+            if end == 0 {
+                // An escaped character, like `$$` or `$ `.
+                let mut chars = rest.chars();
+                out.extend(chars.next());
+                rest = chars.as_str();
+                continue;
+            }
+            let name = &rest[..end];
+            rest = &rest[end..];
+            name
+        };
+        if let Some(value) = lookup(name) {
+            out.push_str(value);
+        }
     }
+    out.push_str(rest);
+    out
+}
+
+fn path_strings(paths: &[Utf8PathBuf]) -> Vec<String> {
+    paths.iter().map(|p| p.to_string()).collect()
+}
+
+/// Join filenames with spaces, for display and for Ninja target lists.
+pub(crate) fn join_paths(paths: &[Utf8PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|p| p.as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A machine-readable description of a plan.
+#[derive(Serialize)]
+pub struct PlanDesc {
+    pub inputs: Vec<String>,
+    pub results: Vec<String>,
+    pub stdin: bool,
+    pub stdout: bool,
+    pub workdir: String,
+    pub cost: u32,
+    #[serde(flatten)]
+    pub preamble: Record,
+    pub setups: Vec<SetupDesc>,
+    pub steps: Vec<StepDesc>,
+
+    /// Configuration keys that the plan requires but that are not set.
+    pub missing_config: BTreeSet<String>,
+}
+
+#[derive(Serialize)]
+pub struct SetupDesc {
+    pub name: String,
+    #[serde(flatten)]
+    pub record: Record,
+}
+
+#[derive(Serialize)]
+pub struct StepDesc {
+    pub op: String,
+    pub inputs: Vec<String>,
+    pub outputs: Vec<String>,
+    #[serde(flatten)]
+    pub record: Record,
+}
+
+/// A Ninja variable declaration.
+#[derive(Serialize)]
+pub struct VarDesc {
+    pub name: String,
+    pub value: String,
+
+    /// The configuration key that the value came from, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+}
+
+/// A Ninja rule, which holds the command that runs an external tool.
+#[derive(Serialize, Clone)]
+pub struct RuleDesc {
+    pub name: String,
+    pub command: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub args: BTreeMap<String, String>,
+}
+
+/// A Ninja build command.
+#[derive(Serialize)]
+pub struct BuildDesc {
+    pub rule: String,
+    pub targets: Vec<String>,
+    pub deps: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub implicit: Vec<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub args: BTreeMap<String, String>,
+
+    /// The command that the build runs, with all of its variables filled in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+}
+
+/// Everything that an emitter produced for one setup or build step.
+#[derive(Serialize, Default)]
+pub struct Record {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub vars: Vec<VarDesc>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<RuleDesc>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub builds: Vec<BuildDesc>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub missing: Vec<String>,
+
+    /// Whether the most recent rule or build was a build, which is what new arguments apply to.
+    #[serde(skip)]
+    in_build: bool,
 }
 
 pub struct Emitter<'a> {
     pub out: Box<dyn Write + 'a>,
     pub config_data: figment::Figment,
     pub workdir: Utf8PathBuf,
+
+    /// The directory, relative to `workdir`, for the intermediate files of the current plan.
+    pub scratch_dir: Utf8PathBuf,
+
+    /// When present, a record of everything emitted, which is used to describe plans.
+    record: Option<RefCell<Record>>,
 }
 
 impl<'a> Emitter<'a> {
-    fn new<T: Write + 'a>(
+    pub(crate) fn new<T: Write + 'a>(
         out: T,
         config_data: figment::Figment,
         workdir: Utf8PathBuf,
@@ -296,26 +682,55 @@ impl<'a> Emitter<'a> {
             out: Box::new(out),
             config_data,
             workdir,
+            scratch_dir: Utf8PathBuf::new(),
+            record: None,
         }
     }
 
-    /// Fetch a configuration value, or panic if it's missing.
+    /// Stop recording and return everything recorded so far.
+    fn take_record(&mut self) -> Record {
+        self.record.take().unwrap_or_default().into_inner()
+    }
+
+    /// Fetch a configuration value, or fail if it's missing.
+    ///
+    /// While recording, a missing value is noted and replaced with a placeholder instead.
     pub fn config_val(&self, key: &str) -> Result<String, EmitError> {
-        self.config_data
-            .extract_inner::<String>(key)
-            .map_err(|_| EmitError::MissingConfig(key.to_string()))
+        match self.lookup(key) {
+            Some(val) => Ok(val),
+            None => match &self.record {
+                Some(record) => {
+                    record.borrow_mut().missing.push(key.to_string());
+                    Ok(format!("<{}>", key))
+                }
+                None => Err(EmitError::MissingConfig(key.to_string())),
+            },
+        }
     }
 
     /// Fetch a configuration value, using a default if it's missing.
     pub fn config_or(&self, key: &str, default: &str) -> String {
-        self.config_data
-            .extract_inner::<String>(key)
-            .unwrap_or_else(|_| default.into())
+        self.lookup(key).unwrap_or_else(|| default.into())
+    }
+
+    /// Look up a configuration value as a string. Numbers and booleans are converted, so that
+    /// options like `sim.cycle_limit` can be written as TOML integers.
+    fn lookup(&self, key: &str) -> Option<String> {
+        match self.config_data.find_value(key).ok()? {
+            Value::String(_, s) => Some(s),
+            Value::Num(_, n) => n
+                .to_i128()
+                .map(|i| i.to_string())
+                .or_else(|| n.to_f64().map(|f| f.to_string())),
+            Value::Bool(_, b) => Some(b.to_string()),
+            _ => None,
+        }
     }
 
     /// Emit a Ninja variable declaration for `name` based on the configured value for `key`.
     pub fn config_var(&mut self, name: &str, key: &str) -> EmitResult {
-        self.var(name, &self.config_val(key)?)?;
+        let value = self.config_val(key)?;
+        self.write_var(name, &value, Some(key))?;
         Ok(())
     }
 
@@ -327,16 +742,42 @@ impl<'a> Emitter<'a> {
         key: &str,
         default: &str,
     ) -> std::io::Result<()> {
-        self.var(name, &self.config_or(key, default))
+        let value = self.config_or(key, default);
+        self.write_var(name, &value, Some(key))
     }
 
     /// Emit a Ninja variable declaration.
     pub fn var(&mut self, name: &str, value: &str) -> std::io::Result<()> {
+        self.write_var(name, value, None)
+    }
+
+    fn write_var(
+        &mut self,
+        name: &str,
+        value: &str,
+        key: Option<&str>,
+    ) -> std::io::Result<()> {
+        if let Some(record) = &mut self.record {
+            record.get_mut().vars.push(VarDesc {
+                name: name.to_string(),
+                value: value.to_string(),
+                key: key.map(|k| k.to_string()),
+            });
+        }
         writeln!(self.out, "{} = {}", name, value)
     }
 
     /// Emit a Ninja rule definition.
     pub fn rule(&mut self, name: &str, command: &str) -> std::io::Result<()> {
+        if let Some(record) = &mut self.record {
+            let record = record.get_mut();
+            record.rules.push(RuleDesc {
+                name: name.to_string(),
+                command: command.to_string(),
+                args: BTreeMap::new(),
+            });
+            record.in_build = false;
+        }
         writeln!(self.out, "rule {}", name)?;
         writeln!(self.out, "  command = {}", command)
     }
@@ -359,6 +800,19 @@ impl<'a> Emitter<'a> {
         deps: &[&str],
         implicit_deps: &[&str],
     ) -> std::io::Result<()> {
+        if let Some(record) = &mut self.record {
+            let owned = |s: &[&str]| s.iter().map(|s| s.to_string()).collect();
+            let record = record.get_mut();
+            record.builds.push(BuildDesc {
+                rule: rule.to_string(),
+                targets: owned(targets),
+                deps: owned(deps),
+                implicit: owned(implicit_deps),
+                args: BTreeMap::new(),
+                command: None,
+            });
+            record.in_build = true;
+        }
         write!(self.out, "build")?;
         for target in targets {
             write!(self.out, " {}", target)?;
@@ -399,10 +853,29 @@ impl<'a> Emitter<'a> {
 
     /// Add a variable parameter to a rule or build command.
     pub fn arg(&mut self, name: &str, value: &str) -> std::io::Result<()> {
+        if let Some(record) = &mut self.record {
+            let record = record.get_mut();
+            let args = if record.in_build {
+                record.builds.last_mut().map(|b| &mut b.args)
+            } else {
+                record.rules.last_mut().map(|r| &mut r.args)
+            };
+            if let Some(args) = args {
+                args.insert(name.to_string(), value.to_string());
+            }
+        }
         writeln!(self.out, "  {} = {}", name, value)?;
         Ok(())
     }
 
+    /// Get the path for an intermediate file that only the current plan uses.
+    ///
+    /// Operations should use this for files they create along the way, other than their outputs,
+    /// so that several plans can share a build directory.
+    pub fn scratch(&self, name: &str) -> String {
+        self.scratch_dir.join(name).into_string()
+    }
+
     /// Add a build command to extract a resource file into the build directory.
     pub fn rsrc(&mut self, filename: &str) -> std::io::Result<()> {
         self.build_cmd(&[filename], "get-rsrc", &[], &[])
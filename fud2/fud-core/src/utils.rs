@@ -12,3 +12,76 @@ pub fn relative_path(path: &Utf8Path, base: &Utf8Path) -> Utf8PathBuf {
             .expect("could not get absolute path"),
     }
 }
+
+/// Check whether `name` matches a shell-style `pattern`, where `*` matches any sequence of
+/// characters and `?` matches any single character.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // Where to resume after the most recent `*` if the rest of the match fails.
+    let mut backtrack = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Find the files matching a shell-style glob `pattern`, in sorted order. Wildcards (`*` and `?`)
+/// can appear in any component of the path but do not match a leading `.`, as in the shell.
+pub fn glob(pattern: &str) -> std::io::Result<Vec<Utf8PathBuf>> {
+    let mut paths = vec![if pattern.starts_with('/') {
+        Utf8PathBuf::from("/")
+    } else {
+        Utf8PathBuf::new()
+    }];
+    for component in pattern.split('/').filter(|c| !c.is_empty()) {
+        if !component.contains(['*', '?']) {
+            for path in &mut paths {
+                path.push(component);
+            }
+            continue;
+        }
+        let mut matches = vec![];
+        for path in &paths {
+            let dir = if path.as_str().is_empty() {
+                Utf8Path::new(".")
+            } else {
+                path.as_path()
+            };
+            let Ok(entries) = dir.read_dir_utf8() else {
+                continue;
+            };
+            for entry in entries {
+                let name = entry?.file_name().to_string();
+                if name.starts_with('.') && !component.starts_with('.') {
+                    continue;
+                }
+                if wildcard_match(component, &name) {
+                    matches.push(path.join(name));
+                }
+            }
+        }
+        paths = matches;
+    }
+    paths.retain(|p| p.exists());
+    paths.sort();
+    Ok(paths)
+}
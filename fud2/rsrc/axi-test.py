@@ -0,0 +1,117 @@
+"""A cocotb testbench for Calyx programs wrapped in an AXI interface.
+
+The memories described in the program's YXI file are backed by cocotb AXI
+RAMs. The testbench fills them with the data from a fud-style JSON file, runs
+the wrapper to completion, and writes the final contents of the memories back
+out in the same JSON format that fud2's `simulate` op produces.
+
+The files are passed through environment variables:
+- `YXI`: the program's YXI description.
+- `DATA`: the JSON data file with the initial memory contents.
+- `OUT`: where to write the resulting JSON.
+- `CYCLE_LIMIT`: the number of cycles to wait for the program to finish.
+"""
+import json
+import mmap
+import os
+from decimal import Decimal
+
+import cocotb
+from cocotb.clock import Clock
+from cocotb.triggers import ClockCycles, RisingEdge
+from cocotbext.axi import AxiBus, AxiRam
+
+# The address where the wrapper expects each memory's data to start.
+BASE_ADDRESS = 0x1000
+
+
+def flatten(data):
+    """Flatten a (possibly multi-dimensional) memory into a list."""
+    if isinstance(data, list):
+        return [x for row in data for x in flatten(row)]
+    return [data]
+
+
+def reshape(values, like):
+    """Arrange `values` into the same shape as the memory `like`."""
+    if not isinstance(like, list):
+        return values.pop(0)
+    return [reshape(values, row) for row in like]
+
+
+def encode(value, fmt, width):
+    """Convert a value from the JSON file to the bits stored in memory."""
+    if fmt.get("numeric_type") == "fixed_point":
+        value = round(Decimal(str(value)) * 2 ** fmt["frac_width"])
+    return int(value) & ((1 << width) - 1)
+
+
+def decode(bits, fmt, width):
+    """Convert the bits stored in memory to a value for the JSON file."""
+    if fmt.get("is_signed") and bits >> (width - 1):
+        bits -= 1 << width
+    if fmt.get("numeric_type") == "fixed_point":
+        return float(Decimal(bits) / 2 ** fmt["frac_width"])
+    return bits
+
+
+@cocotb.test()
+async def run(top):
+    with open(os.environ["YXI"]) as f:
+        yxi = json.load(f)
+    with open(os.environ["DATA"]) as f:
+        data = json.load(f)
+    cycle_limit = int(os.environ.get("CYCLE_LIMIT", "500000000"))
+
+    cocotb.start_soon(Clock(top.clk, 2, units="ns").start())
+
+    # Back each memory with an AXI RAM that holds its initial contents.
+    rams = {}
+    for mem in yxi["memories"]:
+        name, width, size = mem["name"], mem["width"], mem["size"]
+        nbytes = width // 8
+        fmt = data[name].get("format", {})
+        values = flatten(data[name]["data"])
+        assert len(values) == size, f"{name}: expected {size} values"
+
+        backing = mmap.mmap(-1, BASE_ADDRESS + size * nbytes)
+        backing.seek(BASE_ADDRESS)
+        for value in values:
+            backing.write(encode(value, fmt, width).to_bytes(nbytes, "little"))
+        backing.seek(0)
+
+        rams[name] = AxiRam(
+            AxiBus.from_prefix(top, name),
+            top.clk,
+            top.reset,
+            size=BASE_ADDRESS + size * nbytes,
+            mem=backing,
+        )
+
+    # Calyx programs need to be held in reset for a few cycles.
+    top.reset.value = 1
+    await ClockCycles(top.clk, 5)
+    top.reset.value = 0
+
+    top.go.value = 1
+    cycles = 0
+    while not top.done.value:
+        await RisingEdge(top.clk)
+        cycles += 1
+        assert cycles <= cycle_limit, f"program did not finish in {cycle_limit} cycles"
+    top.go.value = 0
+
+    memories = {}
+    for mem in yxi["memories"]:
+        name, width, size = mem["name"], mem["width"], mem["size"]
+        nbytes = width // 8
+        fmt = data[name].get("format", {})
+        raw = rams[name].read(BASE_ADDRESS, size * nbytes)
+        values = [
+            decode(int.from_bytes(raw[i : i + nbytes], "little"), fmt, width)
+            for i in range(0, len(raw), nbytes)
+        ]
+        memories[name] = reshape(values, data[name]["data"])
+
+    with open(os.environ["OUT"], "w") as f:
+        json.dump({"cycles": cycles, "memories": memories}, f, indent=2, sort_keys=True)
@@ -4,15 +4,15 @@ import pathlib
 from fud.stages.interpreter import convert_to_json, parse_from_json
 
 
-def data2interp(in_file):
+def data2interp(in_file, out_file='data.json'):
     """Convert a fud-style JSON data file to Cider-ready JSON.
 
-    The output file is hard-coded to be `data.json`.
+    The output file must be named `data.json`, but it can be in any directory.
     """
     round_float_to_fixed = True
     with open(in_file) as f:
         convert_to_json(
-            '.',
+            pathlib.Path(out_file).parent,
             simplejson.load(f, use_decimal=True),
             round_float_to_fixed,
         )
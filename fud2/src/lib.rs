@@ -1,8 +1,72 @@
+use camino::{Utf8Path, Utf8PathBuf};
 use fud_core::{
+    config::{ConfigKey, ConfigType},
     exec::{SetupRef, StateRef},
-    run::{EmitResult, Emitter},
+    run::{EmitBuild, EmitResult, Emitter},
     DriverBuilder,
 };
+use std::collections::{HashMap, HashSet};
+
+/// The Python interpreter, which several setups use.
+fn python_key() -> ConfigKey {
+    ConfigKey::new("python", ConfigType::Path, "the Python interpreter")
+        .with_default("python3")
+}
+
+/// The simulation cycle limit, which all the simulators share.
+fn cycle_limit_key() -> ConfigKey {
+    ConfigKey::new(
+        "sim.cycle_limit",
+        ConfigType::Integer,
+        "the number of cycles to simulate before giving up",
+    )
+    .with_default("500000000")
+}
+
+/// Find the files that a Calyx program, the first input of an operation, reads through `import`
+/// and `extern`. Like the compiler, this looks for imports next to the importing file and then in
+/// the library at `calyx.base`, and for externs only next to the file.
+fn calyx_deps(
+    inputs: &[Utf8PathBuf],
+    config: &HashMap<&str, &str>,
+) -> std::io::Result<Vec<Utf8PathBuf>> {
+    let lib = config.get("calyx.base").map(Utf8Path::new);
+    let mut deps = vec![];
+    let mut seen = HashSet::new();
+    let mut queue = vec![inputs[0].clone()];
+    while let Some(file) = queue.pop() {
+        let parent = file.parent().unwrap_or(Utf8Path::new(""));
+        for line in std::fs::read_to_string(&file)?.lines() {
+            let line = line.trim_start();
+            let (is_import, rest) = match line.strip_prefix("import") {
+                Some(rest) => (true, rest),
+                None => match line.strip_prefix("extern") {
+                    Some(rest) => (false, rest),
+                    None => continue,
+                },
+            };
+            let Some(path) = rest
+                .trim_start()
+                .strip_prefix('"')
+                .and_then(|r| r.split('"').next())
+            else {
+                continue;
+            };
+            let found = std::iter::once(parent.join(path))
+                .chain(lib.filter(|_| is_import).map(|l| l.join(path)))
+                .find(|p| p.is_file());
+            if let Some(dep) = found {
+                if seen.insert(dep.clone()) {
+                    if is_import {
+                        queue.push(dep.clone());
+                    }
+                    deps.push(dep);
+                }
+            }
+        }
+    }
+    Ok(deps)
+}
 
 fn setup_calyx(
     bld: &mut DriverBuilder,
@@ -22,17 +86,34 @@ fn setup_calyx(
         )?;
         Ok(())
     });
-    bld.op(
+    bld.setup_config(
+        calyx_setup,
+        &[
+            ConfigKey::new(
+                "calyx.base",
+                ConfigType::Path,
+                "the root of the Calyx repository",
+            ),
+            ConfigKey::new(
+                "calyx.exe",
+                ConfigType::Path,
+                "the Calyx compiler executable",
+            )
+            .with_default("$calyx-base/target/debug/calyx"),
+        ],
+    );
+    let calyx_to_verilog = bld.op(
         "calyx-to-verilog",
         &[calyx_setup],
-        calyx,
-        verilog,
+        &[calyx],
+        &[verilog],
         |e, input, output| {
-            e.build_cmd(&[output], "calyx", &[input], &[])?;
+            e.build_cmd(output, "calyx", input, &[])?;
             e.arg("backend", "verilog")?;
             Ok(())
         },
     );
+    bld.op_deps(calyx_to_verilog, calyx_deps);
     (calyx, calyx_setup)
 }
 
@@ -49,7 +130,15 @@ fn setup_dahlia(
         )?;
         Ok(())
     });
-    bld.rule(&[dahlia_setup], dahlia, calyx, "dahlia-to-calyx");
+    bld.setup_config(
+        dahlia_setup,
+        &[ConfigKey::new(
+            "dahlia",
+            ConfigType::Path,
+            "the Dahlia compiler executable",
+        )],
+    );
+    bld.rule(&[dahlia_setup], &[dahlia], &[calyx], "dahlia-to-calyx");
     (dahlia, dahlia_setup)
 }
 
@@ -63,13 +152,242 @@ fn setup_mrxl(
         e.rule("mrxl-to-calyx", "$mrxl-exe $in > $out")?;
         Ok(())
     });
-    bld.rule(&[mrxl_setup], mrxl, calyx, "mrxl-to-calyx");
+    bld.rule(&[mrxl_setup], &[mrxl], &[calyx], "mrxl-to-calyx");
     (mrxl, mrxl_setup)
 }
 
+/// Simulate an AXI-wrapped program with cocotb.
+struct CocotbBuild {
+    /// The simulator for cocotb to use.
+    sim: &'static str,
+}
+
+impl EmitBuild for CocotbBuild {
+    fn build(
+        &self,
+        e: &mut Emitter,
+        input: &[&str],
+        output: &[&str],
+    ) -> EmitResult {
+        let verilog = e.scratch("axi-wrapper.sv");
+        let sim_build = e.scratch(&format!("{}-build", self.sim));
+        e.build_cmd(&[&verilog], "calyx", &[input[0]], &[])?;
+        e.arg("backend", "verilog")?;
+        e.arg("args", "--synthesis --disable-verify")?;
+        e.build_cmd(
+            output,
+            "cocotb-axi",
+            &[&verilog, input[1], input[2]],
+            &["axi-test.py"],
+        )?;
+        e.arg("verilog", &verilog)?;
+        e.arg("yxi", input[1])?;
+        e.arg("data", input[2])?;
+        e.arg("sim", self.sim)?;
+        e.arg("sim-build", &sim_build)?;
+        Ok(())
+    }
+}
+
+fn setup_cocotb(
+    bld: &mut DriverBuilder,
+    calyx: StateRef,
+    dat: StateRef,
+    calyx_setup: SetupRef,
+) {
+    let yxi = bld.state("yxi", &["yxi"]);
+    let calyx_axi = bld.state("calyx-axi", &["futil"]);
+    let calyx_to_yxi = bld.op(
+        "calyx-to-yxi",
+        &[calyx_setup],
+        &[calyx],
+        &[yxi],
+        |e, input, output| {
+            e.build_cmd(output, "calyx", input, &[])?;
+            e.arg("backend", "yxi")?;
+            Ok(())
+        },
+    );
+    bld.op_deps(calyx_to_yxi, calyx_deps);
+
+    // The `axi-wrapper` pass adds a `wrapper` component that serves the `@external` memories of
+    // `main` over AXI. Compiling in `file` mode keeps the imports.
+    let axi_wrapper = bld.op(
+        "axi-wrapper",
+        &[calyx_setup],
+        &[calyx],
+        &[calyx_axi],
+        |e, input, output| {
+            e.build_cmd(output, "calyx", input, &[])?;
+            e.arg("backend", "calyx")?;
+            e.arg("args", "-m file -p axi-wrapper")?;
+            Ok(())
+        },
+    );
+    bld.op_deps(axi_wrapper, calyx_deps);
+
+    // Run the `axi-test.py` testbench under either simulator that cocotb supports.
+    let cocotb_setup = bld.setup("cocotb", |e| {
+        e.rsrc("axi-test.py")?;
+        e.config_var_or(
+            "cocotb-makefiles",
+            "cocotb.makefiles",
+            "`cocotb-config --makefiles`",
+        )?;
+        e.rule(
+            "cocotb-axi",
+            "YXI=$$(realpath $yxi) DATA=$$(realpath $data) OUT=$$PWD/$sim-build/out.json \
+             CYCLE_LIMIT=$cycle-limit COCOTB_RESULTS_FILE=$$PWD/$sim-build/results.xml \
+             PYTHONPATH=$$PWD:$$PYTHONPATH make -s -f $cocotb-makefiles/Makefile.sim \
+             SIM=$sim TOPLEVEL_LANG=verilog TOPLEVEL=wrapper MODULE=axi-test \
+             VERILOG_SOURCES=$$PWD/$verilog SIM_BUILD=$$PWD/$sim-build \
+             && cp $sim-build/out.json $out",
+        )?;
+        e.config_var_or("cycle-limit", "sim.cycle_limit", "500000000")?;
+        Ok(())
+    });
+    bld.setup_config(
+        cocotb_setup,
+        &[
+            ConfigKey::new(
+                "cocotb.makefiles",
+                ConfigType::Path,
+                "the directory containing cocotb's makefiles",
+            )
+            .with_default("`cocotb-config --makefiles`"),
+            cycle_limit_key(),
+        ],
+    );
+    for (name, sim, tag, cost) in [
+        ("cocotb-icarus", "icarus", "accurate", 1),
+        ("cocotb-verilator", "verilator", "fast", 2),
+    ] {
+        let op = bld.add_op(
+            name,
+            &[calyx_setup, cocotb_setup],
+            &[calyx_axi, yxi, dat],
+            &[dat],
+            CocotbBuild { sim },
+        );
+        bld.op_tags(op, &["axi", tag]);
+        bld.op_deps(op, calyx_deps);
+        bld.op_cost(op, cost);
+    }
+}
+
+fn setup_yosys(bld: &mut DriverBuilder, verilog: StateRef) {
+    let netlist = bld.state("netlist", &["json"]);
+    let utilization = bld.state("utilization", &["txt"]);
+    let timing = bld.state("timing", &["json"]);
+
+    // The `yosys.target` option picks the synthesis script: `generic` for technology-independent
+    // synthesis, or an FPGA family like `ice40` or `ecp5`.
+    let yosys_setup = bld.setup("Yosys synthesis", |e| {
+        e.config_var_or("yosys", "yosys.exe", "yosys")?;
+        let target = e.config_or("yosys.target", "generic");
+        let synth = match target.as_str() {
+            "generic" => "synth".to_string(),
+            family => format!("synth_{}", family),
+        };
+        e.var("synth", &synth)?;
+        e.rule(
+            "yosys-synth",
+            "$yosys -q -p 'read_verilog -sv $in; hierarchy -auto-top; $synth; tee -q -o $report stat; write_json $netlist'",
+        )?;
+        Ok(())
+    });
+    bld.setup_config(
+        yosys_setup,
+        &[
+            ConfigKey::new(
+                "yosys.exe",
+                ConfigType::Path,
+                "the Yosys executable",
+            )
+            .with_default("yosys"),
+            ConfigKey::new(
+                "yosys.target",
+                ConfigType::String,
+                "`generic`, or an FPGA family like `ice40` or `ecp5`",
+            )
+            .with_default("generic"),
+        ],
+    );
+    let yosys = bld.op(
+        "yosys",
+        &[yosys_setup],
+        &[verilog],
+        &[netlist, utilization],
+        |e, input, output| {
+            e.build_cmd(output, "yosys-synth", input, &[])?;
+            e.arg("netlist", output[0])?;
+            e.arg("report", output[1])?;
+            Ok(())
+        },
+    );
+    bld.op_tags(yosys, &["synthesis", "open-source"]);
+    bld.op_cost(yosys, 5);
+
+    // nextpnr needs the netlist to target an FPGA family, so `yosys.target` is required here.
+    let nextpnr_setup = bld.setup("nextpnr place and route", |e| {
+        let target = e.config_val("yosys.target")?;
+        let default_args = match target.as_str() {
+            "ice40" => "--hx8k --package ct256 --pcf-allow-unconstrained",
+            "ecp5" => "--25k --package CABGA381",
+            _ => "",
+        };
+        e.config_var_or(
+            "nextpnr",
+            "nextpnr.exe",
+            &format!("nextpnr-{}", target),
+        )?;
+        e.config_var_or("nextpnr-args", "nextpnr.args", default_args)?;
+        e.rule(
+            "nextpnr",
+            "$nextpnr --json $in --report $out --timing-allow-fail $nextpnr-args",
+        )?;
+        Ok(())
+    });
+    bld.setup_config(
+        nextpnr_setup,
+        &[
+            ConfigKey::new(
+                "yosys.target",
+                ConfigType::String,
+                "the FPGA family to place and route for, like `ice40` or `ecp5`",
+            ),
+            ConfigKey::new(
+                "nextpnr.exe",
+                ConfigType::Path,
+                "the nextpnr executable",
+            )
+            .with_default("nextpnr-$target"),
+            ConfigKey::new(
+                "nextpnr.args",
+                ConfigType::String,
+                "extra arguments to nextpnr, like the device and package",
+            )
+            .with_default("depends on the target"),
+        ],
+    );
+    let nextpnr = bld.op(
+        "nextpnr",
+        &[nextpnr_setup],
+        &[netlist],
+        &[timing],
+        |e, input, output| {
+            e.build_cmd(output, "nextpnr", input, &[])?;
+            Ok(())
+        },
+    );
+    bld.op_tags(nextpnr, &["open-source"]);
+    bld.op_cost(nextpnr, 5);
+}
+
 pub fn build_driver(bld: &mut DriverBuilder) {
     // The verilog state
     let verilog = bld.state("verilog", &["sv", "v"]);
+    bld.state_tags(verilog, &["synthesizable"]);
     // Calyx.
     let (calyx, calyx_setup) = setup_calyx(bld, verilog);
     // Dahlia.
@@ -91,20 +409,6 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         // The Verilog testbench.
         e.rsrc("tb.sv")?;
 
-        // The input data file. `sim.data` is required.
-        let data_name = e.config_val("sim.data")?;
-        let data_path = e.external_path(data_name.as_ref());
-        e.var("sim_data", data_path.as_str())?;
-
-        // Produce the data directory.
-        e.var("datadir", "sim_data")?;
-        e.build_cmd(
-            &["$datadir"],
-            "hex-data",
-            &["$sim_data"],
-            &["json-dat.py"],
-        )?;
-
         // Rule for simulation execution.
         e.rule(
             "sim-run",
@@ -116,35 +420,68 @@ pub fn build_driver(bld: &mut DriverBuilder) {
 
         Ok(())
     });
+    bld.setup_config(sim_setup, &[python_key(), cycle_limit_key()]);
     bld.op(
         "simulate",
         &[sim_setup],
-        simulator,
-        dat,
+        &[simulator, dat],
+        &[dat],
         |e, input, output| {
-            e.build_cmd(&["sim.log"], "sim-run", &[input, "$datadir"], &[])?;
-            e.arg("bin", input)?;
+            // The data directory holds the memories in the simulator's format.
+            let datadir = e.scratch("sim_data");
+            let sim_log = e.scratch("sim.log");
+            e.build_cmd(
+                &[&datadir],
+                "hex-data",
+                &[input[1]],
+                &["json-dat.py"],
+            )?;
+            e.build_cmd(&[&sim_log], "sim-run", &[input[0], &datadir], &[])?;
+            e.arg("bin", input[0])?;
+            e.arg("datadir", &datadir)?;
             e.arg("args", "+NOTRACE=1")?;
             e.build_cmd(
-                &[output],
+                output,
                 "json-data",
-                &["$datadir", "sim.log"],
+                &[&datadir, &sim_log],
+                &["json-dat.py"],
+            )?;
+            Ok(())
+        },
+    );
+    // Tracing produces the simulation results along with the waveform.
+    bld.op(
+        "trace",
+        &[sim_setup],
+        &[simulator, dat],
+        &[dat, vcd],
+        |e, input, output| {
+            let datadir = e.scratch("sim_data");
+            let sim_log = e.scratch("sim.log");
+            e.build_cmd(
+                &[&datadir],
+                "hex-data",
+                &[input[1]],
+                &["json-dat.py"],
+            )?;
+            e.build_cmd(
+                &[&sim_log, output[1]],
+                "sim-run",
+                &[input[0], &datadir],
+                &[],
+            )?;
+            e.arg("bin", input[0])?;
+            e.arg("datadir", &datadir)?;
+            e.arg("args", &format!("+NOTRACE=0 +OUT={}", output[1]))?;
+            e.build_cmd(
+                &[output[0]],
+                "json-data",
+                &[&datadir, &sim_log],
                 &["json-dat.py"],
             )?;
             Ok(())
         },
     );
-    bld.op("trace", &[sim_setup], simulator, vcd, |e, input, output| {
-        e.build_cmd(
-            &["sim.log", output],
-            "sim-run",
-            &[input, "$datadir"],
-            &[],
-        )?;
-        e.arg("bin", input)?;
-        e.arg("args", &format!("+NOTRACE=0 +OUT={}", output))?;
-        Ok(())
-    });
 
     // Icarus Verilog.
     let verilog_noverify = bld.state("verilog-noverify", &["sv"]);
@@ -153,43 +490,46 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         e.rule("icarus-compile", "$iverilog -g2012 -o $out tb.sv $in")?;
         Ok(())
     });
-    bld.op(
+    let calyx_noverify = bld.op(
         "calyx-noverify",
         &[calyx_setup],
-        calyx,
-        verilog_noverify,
+        &[calyx],
+        &[verilog_noverify],
         |e, input, output| {
             // Icarus requires a special --disable-verify version of Calyx code.
-            e.build_cmd(&[output], "calyx", &[input], &[])?;
+            e.build_cmd(output, "calyx", input, &[])?;
             e.arg("backend", "verilog")?;
             e.arg("args", "--disable-verify")?;
             Ok(())
         },
     );
-    bld.op(
+    bld.op_deps(calyx_noverify, calyx_deps);
+    let icarus = bld.op(
         "icarus",
         &[sim_setup, icarus_setup],
-        verilog_noverify,
-        simulator,
+        &[verilog_noverify],
+        &[simulator],
         |e, input, output| {
-            e.build_cmd(&[output], "icarus-compile", &[input], &["tb.sv"])?;
+            e.build_cmd(output, "icarus-compile", input, &["tb.sv"])?;
             Ok(())
         },
     );
+    bld.op_tags(icarus, &["accurate"]);
 
     // Calyx to FIRRTL.
     let firrtl = bld.state("firrtl", &["fir"]);
-    bld.op(
+    let calyx_to_firrtl = bld.op(
         "calyx-to-firrtl",
         &[calyx_setup],
-        calyx,
-        firrtl,
+        &[calyx],
+        &[firrtl],
         |e, input, output| {
-            e.build_cmd(&[output], "calyx", &[input], &[])?;
+            e.build_cmd(output, "calyx", input, &[])?;
             e.arg("backend", "firrtl")?;
             Ok(())
         },
     );
+    bld.op_deps(calyx_to_firrtl, calyx_deps);
 
     // The FIRRTL compiler.
     let firrtl_setup = bld.setup("Firrtl to Verilog compiler", |e| {
@@ -204,45 +544,60 @@ pub fn build_driver(bld: &mut DriverBuilder) {
 
         Ok(())
     });
+    bld.setup_config(
+        firrtl_setup,
+        &[ConfigKey::new(
+            "firrtl.exe",
+            ConfigType::Path,
+            "the FIRRTL compiler executable",
+        )],
+    );
     fn firrtl_compile(
         e: &mut Emitter,
-        input: &str,
-        output: &str,
+        input: &[&str],
+        output: &[&str],
     ) -> EmitResult {
-        let tmp_verilog = "partial.sv";
-        e.build_cmd(&[tmp_verilog], "firrtl", &[input], &[])?;
+        let tmp_verilog = e.scratch("partial.sv");
+        e.build_cmd(&[&tmp_verilog], "firrtl", input, &[])?;
         e.build_cmd(
-            &[output],
+            output,
             "add-firrtl-prims",
-            &[tmp_verilog],
+            &[&tmp_verilog],
             &["primitives-for-firrtl.sv"],
         )?;
         Ok(())
     }
-    bld.op("firrtl", &[firrtl_setup], firrtl, verilog, firrtl_compile);
+    bld.op(
+        "firrtl",
+        &[firrtl_setup],
+        &[firrtl],
+        &[verilog],
+        firrtl_compile,
+    );
     // This is a bit of a hack, but the Icarus-friendly "noverify" state is identical for this path
     // (since FIRRTL compilation doesn't come with verification).
     bld.op(
         "firrtl-noverify",
         &[firrtl_setup],
-        firrtl,
-        verilog_noverify,
+        &[firrtl],
+        &[verilog_noverify],
         firrtl_compile,
     );
 
     // primitive-uses backend
     let primitive_uses_json = bld.state("primitive-uses-json", &["json"]);
-    bld.op(
+    let primitive_uses = bld.op(
         "primitive-uses",
         &[calyx_setup],
-        calyx,
-        primitive_uses_json,
+        &[calyx],
+        &[primitive_uses_json],
         |e, input, output| {
-            e.build_cmd(&[output], "calyx", &[input], &[])?;
+            e.build_cmd(output, "calyx", input, &[])?;
             e.arg("backend", "primitive-uses")?;
             Ok(())
         },
     );
+    bld.op_deps(primitive_uses, calyx_deps);
 
     // Verilator.
     let verilator_setup = bld.setup("Verilator", |e| {
@@ -255,25 +610,35 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         e.rule("cp", "cp $in $out")?;
         Ok(())
     });
-    bld.op(
+    bld.setup_config(
+        verilator_setup,
+        &[
+            ConfigKey::new(
+                "verilator.exe",
+                ConfigType::Path,
+                "the Verilator executable",
+            )
+            .with_default("verilator"),
+            cycle_limit_key(),
+        ],
+    );
+    let verilator = bld.op(
         "verilator",
         &[sim_setup, verilator_setup],
-        verilog,
-        simulator,
+        &[verilog],
+        &[simulator],
         |e, input, output| {
-            let out_dir = "verilator-out";
+            let out_dir = e.scratch("verilator-out");
             let sim_bin = format!("{}/VTOP", out_dir);
-            e.build_cmd(
-                &[&sim_bin],
-                "verilator-compile",
-                &[input],
-                &["tb.sv"],
-            )?;
-            e.arg("out-dir", out_dir)?;
-            e.build("cp", &sim_bin, output)?;
+            e.build_cmd(&[&sim_bin], "verilator-compile", input, &["tb.sv"])?;
+            e.arg("out-dir", &out_dir)?;
+            e.build("cp", &sim_bin, output[0])?;
             Ok(())
         },
     );
+    // Verilator simulates quickly but takes a while to compile.
+    bld.op_tags(verilator, &["fast"]);
+    bld.op_cost(verilator, 2);
 
     // Interpreter.
     let debug = bld.state("debug", &[]); // A pseudo-state.
@@ -285,57 +650,88 @@ pub fn build_driver(bld: &mut DriverBuilder) {
         )?;
         e.rule(
             "cider",
-            "$cider-exe -l $calyx-base --raw --data data.json $in > $out",
+            "$cider-exe -l $calyx-base --raw --data $data $in > $out",
         )?;
         e.rule(
             "cider-debug",
-            "$cider-exe -l $calyx-base --data data.json $in debug || true",
+            "$cider-exe -l $calyx-base --data $data $in debug || true",
         )?;
         e.arg("pool", "console")?;
 
         // TODO Can we reduce the duplication around and `$python`?
         e.rsrc("interp-dat.py")?;
         e.config_var_or("python", "python", "python3")?;
-        e.rule("dat-to-interp", "$python interp-dat.py --to-interp $in")?;
+        e.rule(
+            "dat-to-interp",
+            "$python interp-dat.py --to-interp $in $out",
+        )?;
         e.rule(
             "interp-to-dat",
             "$python interp-dat.py --from-interp $in $sim_data > $out",
         )?;
-        e.build_cmd(
-            &["data.json"],
-            "dat-to-interp",
-            &["$sim_data"],
-            &["interp-dat.py"],
-        )?;
         Ok(())
     });
-    bld.op(
+    bld.setup_config(
+        cider_setup,
+        &[
+            ConfigKey::new(
+                "cider.exe",
+                ConfigType::Path,
+                "the Cider interpreter executable",
+            )
+            .with_default("$calyx-base/target/debug/cider"),
+            python_key(),
+        ],
+    );
+    let interp = bld.op(
         "interp",
         &[sim_setup, calyx_setup, cider_setup],
-        calyx,
-        dat,
+        &[calyx, dat],
+        &[dat],
         |e, input, output| {
-            let out_file = "interp_out.json";
-            e.build_cmd(&[out_file], "cider", &[input], &["data.json"])?;
+            // The interpreter's data file must be named `data.json`.
+            let data = e.scratch("data.json");
             e.build_cmd(
-                &[output],
+                &[&data],
+                "dat-to-interp",
+                &[input[1]],
+                &["interp-dat.py"],
+            )?;
+            let out_file = e.scratch("interp_out.json");
+            e.build_cmd(&[&out_file], "cider", &[input[0]], &[&data])?;
+            e.arg("data", &data)?;
+            e.build_cmd(
+                output,
                 "interp-to-dat",
-                &[out_file],
-                &["$sim_data", "interp-dat.py"],
+                &[&out_file],
+                &[input[1], "interp-dat.py"],
             )?;
+            e.arg("sim_data", input[1])?;
             Ok(())
         },
     );
-    bld.op(
+    bld.op_tags(interp, &["interpreter"]);
+    bld.op_deps(interp, calyx_deps);
+    let debug_op = bld.op(
         "debug",
         &[sim_setup, calyx_setup, cider_setup],
-        calyx,
-        debug,
+        &[calyx, dat],
+        &[debug],
         |e, input, output| {
-            e.build_cmd(&[output], "cider-debug", &[input], &["data.json"])?;
+            let data = e.scratch("data.json");
+            e.build_cmd(
+                &[&data],
+                "dat-to-interp",
+                &[input[1]],
+                &["interp-dat.py"],
+            )?;
+            e.build_cmd(output, "cider-debug", &[input[0]], &[&data])?;
+            e.arg("data", &data)?;
             Ok(())
         },
     );
+    // Debugging is interactive, so it always needs to run.
+    bld.op_uncached(debug_op);
 
     // Xilinx compilation.
     let xo = bld.state("xo", &["xo"]);
@@ -360,26 +756,54 @@ pub fn build_driver(bld: &mut DriverBuilder) {
 
         Ok(())
     });
-    bld.op(
+    bld.setup_config(
+        xilinx_setup,
+        &[
+            ConfigKey::new(
+                "xilinx.vivado",
+                ConfigType::Path,
+                "the Vivado installation directory",
+            ),
+            ConfigKey::new(
+                "xilinx.vitis",
+                ConfigType::Path,
+                "the Vitis installation directory",
+            ),
+            python_key(),
+            ConfigKey::new(
+                "xilinx.mode",
+                ConfigType::String,
+                "the compilation target: `hw_emu` or `hw`",
+            )
+            .with_default("hw_emu"),
+            ConfigKey::new(
+                "xilinx.device",
+                ConfigType::String,
+                "the Xilinx platform to compile for",
+            )
+            .with_default("xilinx_u50_gen3x16_xdma_201920_3"),
+        ],
+    );
+    let xo_op = bld.op(
         "xo",
         &[calyx_setup, xilinx_setup],
-        calyx,
-        xo,
+        &[calyx],
+        &[xo],
         |e, input, output| {
             // Emit the Verilog itself in "synthesis mode."
-            e.build_cmd(&["main.sv"], "calyx", &[input], &[])?;
+            e.build_cmd(&["main.sv"], "calyx", input, &[])?;
             e.arg("backend", "verilog")?;
             e.arg("args", "--synthesis -p external")?;
 
             // Extra ingredients for the `.xo` package.
-            e.build_cmd(&["toplevel.v"], "calyx", &[input], &[])?;
+            e.build_cmd(&["toplevel.v"], "calyx", input, &[])?;
             e.arg("backend", "xilinx")?;
-            e.build_cmd(&["kernel.xml"], "calyx", &[input], &[])?;
+            e.build_cmd(&["kernel.xml"], "calyx", input, &[])?;
             e.arg("backend", "xilinx-xml")?;
 
             // Package the `.xo`.
             e.build_cmd(
-                &[output],
+                output,
                 "gen-xo",
                 &[],
                 &[
@@ -393,10 +817,22 @@ pub fn build_driver(bld: &mut DriverBuilder) {
             Ok(())
         },
     );
-    bld.op("xclbin", &[xilinx_setup], xo, xclbin, |e, input, output| {
-        e.build_cmd(&[output], "compile-xclbin", &[input], &[])?;
-        Ok(())
-    });
+    let xclbin_op = bld.op(
+        "xclbin",
+        &[xilinx_setup],
+        &[xo],
+        &[xclbin],
+        |e, input, output| {
+            e.build_cmd(output, "compile-xclbin", input, &[])?;
+            Ok(())
+        },
+    );
+    bld.op_deps(xo_op, calyx_deps);
+    // Synthesis for the FPGA is much slower than anything else.
+    for op in [xo_op, xclbin_op] {
+        bld.op_tags(op, &["fpga"]);
+        bld.op_cost(op, 10);
+    }
 
     // Xilinx execution.
     // TODO Only does `hw_emu` for now...
@@ -419,17 +855,25 @@ pub fn build_driver(bld: &mut DriverBuilder) {
 
         Ok(())
     });
+    bld.setup_config(
+        xrt_setup,
+        &[ConfigKey::new(
+            "xilinx.xrt",
+            ConfigType::Path,
+            "the XRT installation directory",
+        )],
+    );
     bld.op(
         "xrt",
         &[xilinx_setup, sim_setup, xrt_setup],
-        xclbin,
-        dat,
+        &[xclbin, dat],
+        &[dat],
         |e, input, output| {
             e.rsrc("xrt.ini")?;
             e.build_cmd(
-                &[output],
+                output,
                 "xclrun",
-                &[input, "$sim_data"],
+                input,
                 &["emconfig.json", "xrt.ini"],
             )?;
             e.arg("xrt_ini", "xrt.ini")?;
@@ -439,14 +883,14 @@ pub fn build_driver(bld: &mut DriverBuilder) {
     bld.op(
         "xrt-trace",
         &[xilinx_setup, sim_setup, xrt_setup],
-        xclbin,
-        vcd,
+        &[xclbin, dat],
+        &[vcd],
         |e, input, output| {
             e.rsrc("xrt_trace.ini")?;
             e.build_cmd(
-                &[output], // TODO not the VCD, yet...
+                output, // TODO not the VCD, yet...
                 "xclrun",
-                &[input, "$sim_data"],
+                input,
                 &[
                     "emconfig.json",
                     "pre_sim.tcl",
@@ -458,4 +902,10 @@ pub fn build_driver(bld: &mut DriverBuilder) {
             Ok(())
         },
     );
+
+    // AXI-wrapped designs tested with cocotb.
+    setup_cocotb(bld, calyx, dat, calyx_setup);
+
+    // Open-source synthesis. These states come last so that fud2 guesses `dat` for JSON files.
+    setup_yosys(bld, verilog);
 }
@@ -4,6 +4,7 @@ use fud_core::{cli, DriverBuilder};
 fn main() -> anyhow::Result<()> {
     let mut bld = DriverBuilder::new("fud2");
     build_driver(&mut bld);
+    bld.load_plugins()?;
 
     // In debug mode, get resources from the source directory.
     #[cfg(debug_assertions)]
@@ -1,6 +1,12 @@
 use fud2::build_driver;
 use fud_core::{
-    config::default_config, exec::Request, run::Run, Driver, DriverBuilder,
+    batch::Batch,
+    cache,
+    config::{self, default_config, ConfigProblem, ConfigType},
+    exec::{Request, StateRef},
+    inspect,
+    run::Run,
+    Driver, DriverBuilder,
 };
 
 fn test_driver() -> Driver {
@@ -11,32 +17,54 @@ fn test_driver() -> Driver {
 
 fn request(
     driver: &Driver,
-    start: &str,
-    end: &str,
+    start: &[&str],
+    end: &[&str],
     through: &[&str],
 ) -> Request {
+    let states = |names: &[&str]| {
+        names.iter().map(|s| driver.get_state(s).unwrap()).collect()
+    };
+    // Requests with a single input read it from stdin.
+    let files = |names: &[&str]| {
+        if names.len() > 1 {
+            names
+                .iter()
+                .map(|s| {
+                    let state = &driver.states[driver.get_state(s).unwrap()];
+                    format!("/test/{}.{}", s, state.extensions[0]).into()
+                })
+                .collect()
+        } else {
+            vec![]
+        }
+    };
     fud_core::exec::Request {
-        start_file: None,
-        start_state: driver.get_state(start).unwrap(),
-        end_file: None,
-        end_state: driver.get_state(end).unwrap(),
+        start_files: files(start),
+        start_states: states(start),
+        end_files: vec![],
+        end_states: states(end),
         through: through.iter().map(|s| driver.get_op(s).unwrap()).collect(),
+        prefer: vec![],
+        avoid: vec![],
         workdir: ".".into(),
     }
 }
 
-fn emit_ninja(driver: &Driver, req: Request) -> String {
-    let plan = driver.plan(req).unwrap();
-    let config = default_config()
+fn test_config() -> figment::Figment {
+    default_config()
         .merge(("exe", "fud2"))
         .merge(("calyx.base", "/test/calyx"))
         .merge(("firrtl.exe", "/test/bin/firrtl"))
-        .merge(("sim.data", "/test/data.json"))
         .merge(("xilinx.vivado", "/test/xilinx/vivado"))
         .merge(("xilinx.vitis", "/test/xilinx/vitis"))
         .merge(("xilinx.xrt", "/test/xilinx/xrt"))
-        .merge(("dahlia", "/test/bin/dahlia"));
-    let run = Run::with_config(driver, plan, config);
+        .merge(("dahlia", "/test/bin/dahlia"))
+        .merge(("yosys.target", "ice40"))
+}
+
+fn emit_ninja(driver: &Driver, req: Request) -> String {
+    let plan = driver.plan(req).unwrap();
+    let run = Run::with_config(driver, plan, test_config());
     let mut buf = vec![];
     run.emit(&mut buf).unwrap();
     String::from_utf8(buf).unwrap()
@@ -46,7 +74,8 @@ fn emit_ninja(driver: &Driver, req: Request) -> String {
 fn req_desc(driver: &Driver, req: &Request) -> String {
     let mut desc = format!(
         "emit {} -> {}",
-        driver.states[req.start_state].name, driver.states[req.end_state].name
+        driver.state_names(&req.start_states),
+        driver.state_names(&req.end_states)
     );
     if !req.through.is_empty() {
         desc.push_str(" through");
@@ -60,13 +89,20 @@ fn req_desc(driver: &Driver, req: &Request) -> String {
 
 /// Get a short string uniquely identifying a request.
 fn req_slug(driver: &Driver, req: &Request) -> String {
-    let mut desc = driver.states[req.start_state].name.to_string();
+    let names = |states: &[StateRef]| {
+        states
+            .iter()
+            .map(|s| driver.states[*s].name.as_str())
+            .collect::<Vec<_>>()
+            .join("+")
+    };
+    let mut desc = names(&req.start_states);
     for op in &req.through {
         desc.push('_');
         desc.push_str(&driver.ops[*op].name);
     }
     desc.push('_');
-    desc.push_str(&driver.states[req.end_state].name);
+    desc.push_str(&names(&req.end_states));
     desc
 }
 
@@ -86,13 +122,16 @@ fn test_emit(driver: &Driver, req: Request) {
 #[test]
 fn calyx_to_verilog() {
     let driver = test_driver();
-    test_emit(&driver, request(&driver, "calyx", "verilog", &[]));
+    test_emit(&driver, request(&driver, &["calyx"], &["verilog"], &[]));
 }
 
 #[test]
 fn calyx_via_firrtl() {
     let driver = test_driver();
-    test_emit(&driver, request(&driver, "calyx", "verilog", &["firrtl"]));
+    test_emit(
+        &driver,
+        request(&driver, &["calyx"], &["verilog"], &["firrtl"]),
+    );
 }
 
 #[test]
@@ -100,29 +139,360 @@ fn sim_tests() {
     let driver = test_driver();
     for dest in &["dat", "vcd"] {
         for sim in &["icarus", "verilator"] {
-            test_emit(&driver, request(&driver, "calyx", dest, &[sim]));
+            test_emit(
+                &driver,
+                request(&driver, &["calyx", "dat"], &[dest], &[sim]),
+            );
         }
     }
 }
 
+#[test]
+fn sim_multi_output() {
+    let driver = test_driver();
+    test_emit(
+        &driver,
+        request(&driver, &["calyx", "dat"], &["dat", "vcd"], &["verilator"]),
+    );
+}
+
 #[test]
 fn cider_tests() {
     let driver = test_driver();
-    test_emit(&driver, request(&driver, "calyx", "dat", &["interp"]));
-    test_emit(&driver, request(&driver, "calyx", "debug", &[]));
+    test_emit(
+        &driver,
+        request(&driver, &["calyx", "dat"], &["dat"], &["interp"]),
+    );
+    test_emit(
+        &driver,
+        request(&driver, &["calyx", "dat"], &["debug"], &[]),
+    );
 }
 
 #[test]
 fn xrt_tests() {
     let driver = test_driver();
-    test_emit(&driver, request(&driver, "calyx", "dat", &["xrt"]));
-    test_emit(&driver, request(&driver, "calyx", "vcd", &["xrt-trace"]));
+    test_emit(
+        &driver,
+        request(&driver, &["calyx", "dat"], &["dat"], &["xrt"]),
+    );
+    test_emit(
+        &driver,
+        request(&driver, &["calyx", "dat"], &["vcd"], &["xrt-trace"]),
+    );
+}
+
+#[test]
+fn cocotb_tests() {
+    let driver = test_driver();
+    for sim in &["cocotb-icarus", "cocotb-verilator"] {
+        test_emit(
+            &driver,
+            request(&driver, &["calyx", "dat"], &["dat"], &[sim]),
+        );
+    }
+}
+
+#[test]
+fn yosys_tests() {
+    let driver = test_driver();
+    test_emit(
+        &driver,
+        request(&driver, &["calyx"], &["netlist", "utilization"], &[]),
+    );
+    test_emit(&driver, request(&driver, &["calyx"], &["timing"], &[]));
 }
 
 #[test]
 fn frontend_tests() {
     let driver = test_driver();
     for frontend in &["dahlia", "mrxl"] {
-        test_emit(&driver, request(&driver, frontend, "calyx", &[]));
+        test_emit(&driver, request(&driver, &[frontend], &["calyx"], &[]));
     }
 }
+
+/// Get the names of the operations the planner picks for a request.
+fn planned_ops(driver: &Driver, req: Request) -> Vec<&str> {
+    let plan = driver.plan(req).unwrap();
+    plan.steps
+        .iter()
+        .map(|(op, _, _)| driver.ops[*op].name.as_str())
+        .collect()
+}
+
+#[test]
+fn prefer_and_avoid() {
+    let driver = test_driver();
+    let req = || request(&driver, &["calyx", "dat"], &["dat"], &[]);
+    assert_eq!(planned_ops(&driver, req()), ["interp"]);
+
+    let mut avoid_interp = req();
+    avoid_interp.avoid = vec!["interpreter".into()];
+    assert_eq!(
+        planned_ops(&driver, avoid_interp),
+        ["calyx-noverify", "icarus", "simulate"]
+    );
+
+    let mut prefer_fast = req();
+    prefer_fast.avoid = vec!["interp".into()];
+    prefer_fast.prefer = vec!["fast".into()];
+    assert_eq!(
+        planned_ops(&driver, prefer_fast),
+        ["calyx-to-verilog", "verilator", "simulate"]
+    );
+}
+
+#[test]
+fn plugin_ops() {
+    let mut bld = DriverBuilder::new("fud2");
+    build_driver(&mut bld);
+    let path =
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/plugins/estimate.toml");
+    bld.load_plugin(path.into()).unwrap();
+    let driver = bld.build();
+    let keys = driver.config_keys();
+    let key = keys.iter().find(|k| k.key == "estimate.exe").unwrap();
+    assert_eq!(key.ty, ConfigType::Path);
+    test_emit(&driver, request(&driver, &["calyx"], &["area-report"], &[]));
+}
+
+#[test]
+fn batch_emit() {
+    let driver = test_driver();
+    let plans = ["add", "mult"]
+        .iter()
+        .map(|name| {
+            let mut req =
+                request(&driver, &["calyx", "dat"], &["dat"], &["verilator"]);
+            req.start_files = vec![
+                format!("/test/{}.futil", name).into(),
+                format!("/test/{}.json", name).into(),
+            ];
+            driver.plan(req).unwrap()
+        })
+        .collect();
+    let batch = Batch::new(&driver, plans, ".".into(), test_config());
+    let mut buf = vec![];
+    batch.emit(&mut buf).unwrap();
+    insta::with_settings!({
+        description => "batch of calyx, dat -> dat through verilator",
+        omit_expression => true,
+    }, {
+        insta::assert_snapshot!(String::from_utf8(buf).unwrap());
+    });
+}
+
+#[test]
+fn describe_plan() {
+    let driver = test_driver();
+    let req = request(&driver, &["calyx"], &["verilog"], &[]);
+    let plan = driver.plan(req).unwrap();
+    let run = Run::with_config(&driver, plan, test_config());
+    let desc = run.describe().unwrap();
+    assert!(desc.missing_config.is_empty());
+    let steps: Vec<_> = desc.steps.iter().map(|s| s.op.as_str()).collect();
+    assert_eq!(steps, ["calyx-to-verilog"]);
+    assert_eq!(
+        desc.steps[0].record.builds[0].command.as_deref(),
+        Some("/test/calyx/target/debug/calyx -l /test/calyx -b verilog  stdin > stdin.sv")
+    );
+
+    // Missing configuration shows up in the description instead of failing.
+    let req = request(&driver, &["calyx", "dat"], &["dat"], &["xrt"]);
+    let plan = driver.plan(req).unwrap();
+    let run = Run::with_config(&driver, plan, default_config());
+    let desc = run.describe().unwrap();
+    let missing: Vec<_> =
+        desc.missing_config.iter().map(|s| s.as_str()).collect();
+    assert_eq!(
+        missing,
+        ["calyx.base", "xilinx.vitis", "xilinx.vivado", "xilinx.xrt"]
+    );
+}
+
+#[test]
+fn cache_keys() {
+    let driver = test_driver();
+    let dir = std::env::temp_dir()
+        .join(format!("fud2-cache-keys-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let keys = |name: &str, contents: &str| {
+        let path = dir.join(name);
+        std::fs::write(&path, contents).unwrap();
+        let req = Request {
+            start_files: vec![path.try_into().unwrap()],
+            start_states: vec![driver.get_state("calyx").unwrap()],
+            end_files: vec![],
+            end_states: vec![driver.get_state("sim").unwrap()],
+            through: vec![driver.get_op("verilator").unwrap()],
+            prefer: vec![],
+            avoid: vec![],
+            workdir: ".".into(),
+        };
+        let plan = driver.plan(req).unwrap();
+        let run = Run::with_config(&driver, plan, test_config());
+        let desc = run.describe().unwrap();
+        cache::step_keys(&driver, &run.plan, &desc, "".into()).unwrap()
+    };
+
+    // Keys depend on file contents, not names, and carry over to later steps.
+    let a = keys("a.futil", "component main() -> () {}");
+    let b = keys("b.futil", "component main() -> () {}");
+    let c = keys("c.futil", "component main<\"toplevel\"=1>() -> () {}");
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(a.len(), 2);
+    assert!(a.iter().all(|k| k.is_some()));
+    assert_eq!(a, b);
+    assert_ne!(a[0], c[0]);
+    assert_ne!(a[1], c[1]);
+}
+
+#[test]
+fn config_schema() {
+    let driver = test_driver();
+    let keys = driver.config_keys();
+
+    // Every option that a setup reads is declared.
+    for (op, decl) in driver.ops.iter() {
+        let states = |states: &[StateRef]| {
+            states
+                .iter()
+                .map(|s| driver.states[*s].name.as_str())
+                .collect::<Vec<_>>()
+        };
+        let req = request(
+            &driver,
+            &states(&decl.input),
+            &states(&decl.output),
+            &[&decl.name],
+        );
+        let Some(plan) = driver.plan(req) else {
+            continue;
+        };
+        let run = Run::with_config(&driver, plan, test_config());
+        let desc = run.describe().unwrap();
+        let vars = desc.setups.iter().flat_map(|s| &s.record.vars);
+        for key in vars.filter_map(|v| v.key.as_ref()) {
+            assert!(
+                keys.iter().any(|k| &k.key == key),
+                "{} reads undeclared option {}",
+                driver.ops[op].name,
+                key
+            );
+        }
+    }
+
+    // The test configuration is valid, but typos and bad values are not.
+    assert_eq!(config::check(&test_config(), &keys).unwrap(), []);
+    let bad = test_config()
+        .merge(("verilator.exee", "/test/bin/verilator"))
+        .merge(("sim.cycle_limit", "lots"))
+        .merge(("xilinx.mode", 3));
+    assert_eq!(
+        config::check(&bad, &keys).unwrap(),
+        [
+            ConfigProblem::WrongType {
+                key: "sim.cycle_limit".into(),
+                expected: ConfigType::Integer,
+            },
+            ConfigProblem::Unknown {
+                key: "verilator.exee".into(),
+                suggestion: Some("verilator.exe".into()),
+            },
+            ConfigProblem::WrongType {
+                key: "xilinx.mode".into(),
+                expected: ConfigType::String,
+            },
+        ]
+    );
+}
+
+#[test]
+fn inspect_resume() {
+    let driver = test_driver();
+    let dir = std::env::temp_dir()
+        .join(format!("fud2-inspect-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let mut req = request(&driver, &["dahlia"], &["verilog"], &[]);
+    req.workdir = dir.clone().try_into().unwrap();
+    let plan = driver.plan(req).unwrap();
+
+    // The plan survives a round trip through the build directory.
+    inspect::write_manifest(&driver, &plan).unwrap();
+    let loaded = inspect::read_plan(&driver, &plan.workdir).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(loaded.steps, plan.steps);
+    assert_eq!(loaded.results, plan.results);
+    assert!(loaded.stdin);
+
+    // Resuming from the intermediate Calyx file skips the Dahlia compiler.
+    let calyx_file = plan.steps[0].2[0].clone();
+    let resumed = inspect::resume_from(loaded, &calyx_file).unwrap();
+    assert_eq!(resumed.steps, plan.steps[1..]);
+    assert!(!resumed.stdin);
+    assert!(inspect::resume_from(plan, "nonexistent.futil".into()).is_err());
+}
+
+#[test]
+fn cache_keys_imports() {
+    let driver = test_driver();
+    let dir = std::env::temp_dir()
+        .join(format!("fud2-cache-imports-{}", std::process::id()));
+    let lib = dir.join("calyx");
+    std::fs::create_dir_all(lib.join("primitives")).unwrap();
+    let write = |name: &std::path::Path, contents: &str| {
+        std::fs::write(name, contents).unwrap();
+    };
+    write(
+        &lib.join("primitives/core.futil"),
+        "extern \"core.sv\" {\n  primitive std_id[WIDTH](in: WIDTH) -> (out: WIDTH);\n}\n",
+    );
+    write(
+        &lib.join("primitives/core.sv"),
+        "module std_id; endmodule\n",
+    );
+    write(
+        &dir.join("helper.futil"),
+        "import \"primitives/core.futil\";\ncomponent helper() -> () {}\n",
+    );
+    write(
+        &dir.join("main.futil"),
+        "import \"helper.futil\";\ncomponent main() -> () {}\n",
+    );
+    let keys = || {
+        let req = Request {
+            start_files: vec![dir.join("main.futil").try_into().unwrap()],
+            start_states: vec![driver.get_state("calyx").unwrap()],
+            end_files: vec![],
+            end_states: vec![driver.get_state("verilog").unwrap()],
+            through: vec![],
+            prefer: vec![],
+            avoid: vec![],
+            workdir: ".".into(),
+        };
+        let plan = driver.plan(req).unwrap();
+        let config = test_config().merge(("calyx.base", lib.to_str().unwrap()));
+        let run = Run::with_config(&driver, plan, config);
+        let desc = run.describe().unwrap();
+        cache::step_keys(&driver, &run.plan, &desc, "".into()).unwrap()
+    };
+
+    // Editing a file that the program imports, directly or from the library, changes the key.
+    let before = keys();
+    write(
+        &dir.join("helper.futil"),
+        "import \"primitives/core.futil\";\ncomponent helper() -> () {}\ncomponent other() -> () {}\n",
+    );
+    let edited_import = keys();
+    write(
+        &lib.join("primitives/core.sv"),
+        "module std_id(); endmodule\n",
+    );
+    let edited_lib = keys();
+    let again = keys();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(before[0].is_some());
+    assert_ne!(before, edited_import);
+    assert_ne!(edited_import, edited_lib);
+    assert_eq!(edited_lib, again);
+}
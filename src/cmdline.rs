@@ -3,12 +3,14 @@ use argh::FromArgs;
 #[cfg(feature = "serialize")]
 use calyx_backend::SexpBackend;
 use calyx_backend::{
+    intel::{IntelHwTclBackend, IntelInterfaceBackend},
     xilinx::{XilinxInterfaceBackend, XilinxXmlBackend},
     Backend, BackendOpt, FirrtlBackend, MlirBackend, PrimitiveUsesBackend,
     ResourcesBackend, VerilogBackend, YxiBackend,
 };
 use calyx_ir as ir;
 use calyx_utils::{CalyxResult, Error, OutputFile};
+use std::io::Read;
 use std::path::Path;
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -22,12 +24,78 @@ pub struct Help {
     pub name: Option<String>,
 }
 
+/// format calyx programs
+#[derive(FromArgs, PartialEq, Debug)]
+#[argh(subcommand, name = "fmt")]
+pub struct Fmt {
+    /// files to format in place. Reads from stdin and writes to stdout when
+    /// no files are given.
+    #[argh(positional)]
+    pub files: Vec<PathBuf>,
+
+    /// only check that the files are formatted
+    #[argh(switch)]
+    pub check: bool,
+}
+
+impl Fmt {
+    /// Format the files, or report the ones that aren't formatted in
+    /// `--check` mode.
+    pub fn run(self) -> CalyxResult<()> {
+        if self.files.is_empty() {
+            let mut src = String::new();
+            std::io::stdin().read_to_string(&mut src)?;
+            let formatted = calyx_fmt::format_source(&src)?;
+            if self.check {
+                return if formatted == src {
+                    Ok(())
+                } else {
+                    Err(Error::misc("<stdin> is not formatted"))
+                };
+            }
+            print!("{formatted}");
+            return Ok(());
+        }
+
+        let mut unformatted = vec![];
+        for file in &self.files {
+            let src = std::fs::read_to_string(file).map_err(|err| {
+                Error::invalid_file(format!("{}: {err}", file.display()))
+            })?;
+            let formatted = calyx_fmt::format_source(&src).map_err(|err| {
+                Error::misc(format!("{}: {}", file.display(), err.message()))
+            })?;
+            if formatted == src {
+                continue;
+            }
+            if self.check {
+                println!("{}", file.display());
+                unformatted.push(file);
+            } else {
+                std::fs::write(file, formatted).map_err(|err| {
+                    Error::write_error(format!("{}: {err}", file.display()))
+                })?;
+            }
+        }
+        if unformatted.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::misc(format!(
+                "{} file(s) are not formatted",
+                unformatted.len()
+            )))
+        }
+    }
+}
+
 /// supported subcommands
 #[derive(FromArgs, PartialEq, Debug)]
 #[argh(subcommand)]
 pub enum Subcommand {
     /// Help mode
     Help(Help),
+    /// Format mode
+    Fmt(Fmt),
 }
 
 #[derive(FromArgs)]
@@ -73,6 +141,21 @@ pub struct Opts {
     #[argh(switch, long = "emit-primitive-extmodules")]
     pub emit_primitive_extmodules: bool,
 
+    /// cost model in TOML used to estimate resources
+    /// (only relevant to the resources backend)
+    #[argh(option, long = "resource-model")]
+    pub resource_model: Option<PathBuf>,
+
+    /// emit comments with the source location of assignments
+    /// (only relevant to the Verilog backend)
+    #[argh(switch, long = "source-comments")]
+    pub source_comments: bool,
+
+    /// write a JSON file mapping generated signals to the source program
+    /// (only relevant to the Verilog backend)
+    #[argh(option, long = "source-map")]
+    pub source_map: Option<PathBuf>,
+
     /// select a backend
     #[argh(option, short = 'b', default = "BackendOpt::default()")]
     pub backend: BackendOpt,
@@ -93,6 +176,14 @@ pub struct Opts {
     #[argh(option, long = "log", default = "log::LevelFilter::Warn")]
     pub log_level: log::LevelFilter,
 
+    /// format of errors and warnings: `human` or `json`
+    #[argh(
+        option,
+        long = "diagnostics-format",
+        default = "DiagnosticsFormat::default()"
+    )]
+    pub diagnostics_format: DiagnosticsFormat,
+
     #[argh(switch, long = "dump-ir")]
     /// print out the IR after every pass
     pub dump_ir: bool,
@@ -128,6 +219,28 @@ impl FromStr for CompileMode {
     }
 }
 
+// Formats for errors and warnings
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiagnosticsFormat {
+    /// Messages that point at the source of the program.
+    #[default]
+    Human,
+    /// One JSON object per line, for editors and other tools.
+    Json,
+}
+
+impl FromStr for DiagnosticsFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(DiagnosticsFormat::Human),
+            "json" => Ok(DiagnosticsFormat::Json),
+            s => Err(format!("Unknown diagnostics format: {}. Valid options are `human` or `json`", s))
+        }
+    }
+}
+
 // ================== Backend Variant and Parsing ===================== //
 
 impl Opts {
@@ -167,6 +280,14 @@ impl Opts {
                 let backend = XilinxXmlBackend;
                 backend.run(context, self.output)
             }
+            BackendOpt::Intel => {
+                let backend = IntelInterfaceBackend;
+                backend.run(context, self.output)
+            }
+            BackendOpt::IntelHwTcl => {
+                let backend = IntelHwTclBackend;
+                backend.run(context, self.output)
+            }
             BackendOpt::Yxi => {
                 let backend = YxiBackend;
                 backend.run(context, self.output)
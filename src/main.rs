@@ -6,18 +6,32 @@
 //! Instead, depend on the crates that this crate depends: [`calyx_frontend`], [`calyx_ir`], [`calyx_opt`].
 
 mod cmdline;
-use calyx_backend::BackendOpt;
+use calyx_backend::{record_source_groups, BackendOpt};
 use calyx_frontend as frontend;
 use calyx_ir as ir;
 use calyx_opt::pass_manager::PassManager;
 use calyx_utils::CalyxResult;
-use cmdline::{CompileMode, Opts};
+use cmdline::{CompileMode, DiagnosticsFormat, Opts};
 use itertools::Itertools;
+use std::io::Write;
 
 fn main() -> CalyxResult<()> {
     // parse the command line arguments into Opts struct
-    let mut opts = Opts::get_opts()?;
+    let opts = Opts::get_opts()?;
+    let format = opts.diagnostics_format;
 
+    match run(opts) {
+        Err(err) if format == DiagnosticsFormat::Json => {
+            for err in err.into_errors() {
+                eprintln!("{}", err.to_json());
+            }
+            std::process::exit(1)
+        }
+        res => res,
+    }
+}
+
+fn run(mut opts: Opts) -> CalyxResult<()> {
     // Return the version and the git commit this was built on
     if opts.version {
         println!("Calyx compiler version {}", env!("CARGO_PKG_VERSION"));
@@ -29,11 +43,25 @@ fn main() -> CalyxResult<()> {
     }
 
     // enable tracing
-    env_logger::Builder::new()
+    let mut logger = env_logger::Builder::new();
+    logger
         .format_timestamp(None)
         .filter_level(opts.log_level)
-        .target(env_logger::Target::Stderr)
-        .init();
+        .target(env_logger::Target::Stderr);
+    if opts.diagnostics_format == DiagnosticsFormat::Json {
+        // log messages don't have a position in the program
+        logger.format(|buf, record| {
+            let msg = calyx_utils::diagnostic_json(
+                &record.level().as_str().to_lowercase(),
+                "Log",
+                &record.args().to_string(),
+                calyx_utils::GPosIdx::UNKNOWN,
+                None,
+            );
+            writeln!(buf, "{msg}")
+        });
+    }
+    logger.init();
 
     let pm = PassManager::default_passes()?;
 
@@ -53,6 +81,7 @@ fn main() -> CalyxResult<()> {
                 }
                 return Ok(());
             }
+            cmdline::Subcommand::Fmt(fmt) => return fmt.run(),
         }
     }
 
@@ -69,12 +98,31 @@ fn main() -> CalyxResult<()> {
         enable_verification: !opts.disable_verify,
         flat_assign: !opts.nested_assign,
         emit_primitive_extmodules: opts.emit_primitive_extmodules,
+        resource_model: opts.resource_model.take(),
+        source_comments: opts.source_comments,
+        source_map: opts.source_map.take(),
     };
     // Extra options for the passes
     ctx.extra_opts = opts.extra_opts.drain(..).collect();
+    // Groups are compiled away by the passes
+    if ctx.bc.source_comments || ctx.bc.source_map.is_some() {
+        record_source_groups(&mut ctx);
+    }
 
     // Run all passes specified by the command line
-    pm.execute_plan(&mut ctx, &opts.pass, &opts.disable_pass, opts.dump_ir)?;
+    let res =
+        pm.execute_plan(&mut ctx, &opts.pass, &opts.disable_pass, opts.dump_ir);
+    // Report the warnings before any errors.
+    for warning in ctx.diagnostics.take_warnings() {
+        if opts.diagnostics_format == DiagnosticsFormat::Json {
+            if log::log_enabled!(log::Level::Warn) {
+                eprintln!("{}", warning.to_json());
+            }
+        } else {
+            log::warn!("{warning}");
+        }
+    }
+    res?;
 
     // Print out the Calyx program after transformation.
     if opts.backend == BackendOpt::Calyx {